@@ -0,0 +1,82 @@
+use barter_data::{
+    event::{DataKind, MarketEvent},
+    flatbuffers::MarketEventEncoder,
+    subscription::{
+        book::{OrderBook, OrderBookSide},
+        trade::PublicTrade,
+    },
+};
+use barter_integration::model::{Exchange, Side};
+use chrono::Utc;
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use std::hint::black_box;
+
+fn market_events() -> Vec<(&'static str, MarketEvent<String, DataKind>)> {
+    let event = |kind| MarketEvent {
+        exchange_time: Utc::now(),
+        received_time: Utc::now(),
+        exchange: Exchange::from("binance_spot"),
+        instrument: "btc_usdt".to_owned(),
+        kind,
+    };
+
+    let levels = |side, start: f64| {
+        OrderBookSide::new(
+            side,
+            (0..50).map(|level| (start + level as f64 * 0.01, 1.0 + level as f64)),
+        )
+    };
+
+    vec![
+        (
+            "public_trade",
+            event(DataKind::Trade(PublicTrade {
+                id: "4156283746".to_owned(),
+                price: 42_000.5,
+                amount: 0.25,
+                side: Side::Buy,
+            })),
+        ),
+        (
+            "order_book_50",
+            event(DataKind::OrderBook(OrderBook {
+                last_update_time: Utc::now(),
+                bids: levels(Side::Buy, 41_999.0),
+                asks: levels(Side::Sell, 42_001.0),
+            })),
+        ),
+    ]
+}
+
+fn bench_encoding(c: &mut Criterion) {
+    let mut group = c.benchmark_group("encode_market_event");
+    let mut encoder = MarketEventEncoder::with_capacity(4096);
+
+    for (name, event) in market_events() {
+        group.throughput(Throughput::Elements(1));
+
+        group.bench_with_input(BenchmarkId::new("flatbuffers", name), &event, |b, event| {
+            b.iter(|| black_box(encoder.encode(black_box(event)).len()))
+        });
+
+        let mut buffer = Vec::with_capacity(4096);
+        group.bench_with_input(BenchmarkId::new("serde_json", name), &event, |b, event| {
+            b.iter(|| {
+                buffer.clear();
+                serde_json::to_writer(&mut buffer, black_box(event)).unwrap();
+                black_box(buffer.len())
+            })
+        });
+
+        println!(
+            "{name}: flatbuffers {} bytes, serde_json {} bytes",
+            encoder.encode(&event).len(),
+            serde_json::to_vec(&event).unwrap().len()
+        );
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_encoding);
+criterion_main!(benches);
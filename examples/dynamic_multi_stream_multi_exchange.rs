@@ -1,6 +1,7 @@
 use barter_data::event::{DataKind, MarketEvent};
 use barter_data::exchange::ExchangeId;
 use barter_data::streams::builder::dynamic::DynamicStreams;
+use barter_data::streams::options::StreamOptions;
 use barter_data::subscription::SubKind;
 use barter_integration::model::instrument::kind::InstrumentKind;
 use barter_integration::model::instrument::Instrument;
@@ -55,7 +56,7 @@ async fn main() {
             (Okx, "eth", "usdt", Perpetual, PublicTrades),
             (Bitmex, "eth", "usdt", Perpetual, PublicTrades),
         ],
-    ]).await.unwrap();
+    ], StreamOptions::default()).await.unwrap();
 
     // Select all streams, mapping each SubscriptionKind `MarketEvent<T>` into a unified
     // `Output` (eg/ `MarketEvent<Instrument, DataKind>`), where MarketEvent<T>: Into<Output>
@@ -60,10 +60,10 @@ async fn main() {
         // Add OrderBooksL2 Stream for various exchanges
         .add(Streams::<OrderBooksL2>::builder()
             .subscribe([
-                (BinanceSpot::default(), "btc", "usdt", InstrumentKind::Spot, OrderBooksL2),
+                (BinanceSpot::default(), "btc", "usdt", InstrumentKind::Spot, OrderBooksL2::default()),
             ])
             .subscribe([
-                (BinanceFuturesUsd::default(), "btc", "usdt", InstrumentKind::Perpetual, OrderBooksL2),
+                (BinanceFuturesUsd::default(), "btc", "usdt", InstrumentKind::Perpetual, OrderBooksL2::default()),
             ])
         )
         .init()
@@ -73,7 +73,7 @@ async fn main() {
     // Join all exchange Streams into a single tokio_stream::StreamMap
     // Notes:
     //  - Use `streams.select(ExchangeId)` to interact with the individual exchange streams!
-    //  - Use `streams.join()` to join all exchange streams into a single mpsc::UnboundedReceiver!
+    //  - Use `streams.join()` to join all exchange streams into a single Stream!
     let mut joined_stream = streams.join_map().await;
 
     while let Some((exchange, data)) = joined_stream.next().await {
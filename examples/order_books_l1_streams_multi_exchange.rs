@@ -40,7 +40,7 @@ async fn main() {
     // Join all exchange OrderBooksL1 streams into a single tokio_stream::StreamMap
     // Notes:
     //  - Use `streams.select(ExchangeId)` to interact with the individual exchange streams!
-    //  - Use `streams.join()` to join all exchange streams into a single mpsc::UnboundedReceiver!
+    //  - Use `streams.join()` to join all exchange streams into a single Stream!
     let mut joined_stream = streams.join_map().await;
 
     while let Some((exchange, order_book_l1)) = joined_stream.next().await {
@@ -18,20 +18,20 @@ async fn main() {
 
         // Separate WebSocket connection for BTC_USDT stream since it's very high volume
         .subscribe([
-            (BinanceSpot::default(), "btc", "usdt", InstrumentKind::Spot, OrderBooksL2),
+            (BinanceSpot::default(), "btc", "usdt", InstrumentKind::Spot, OrderBooksL2::default()),
         ])
 
         // Separate WebSocket connection for ETH_USDT stream since it's very high volume
         .subscribe([
-            (BinanceSpot::default(), "eth", "usdt", InstrumentKind::Spot, OrderBooksL2),
+            (BinanceSpot::default(), "eth", "usdt", InstrumentKind::Spot, OrderBooksL2::default()),
         ])
 
         // Lower volume Instruments can share a WebSocket connection
         .subscribe([
-            (BinanceSpot::default(), "xrp", "usdt", InstrumentKind::Spot, OrderBooksL2),
-            (BinanceSpot::default(), "sol", "usdt", InstrumentKind::Spot, OrderBooksL2),
-            (BinanceSpot::default(), "avax", "usdt", InstrumentKind::Spot, OrderBooksL2),
-            (BinanceSpot::default(), "ltc", "usdt", InstrumentKind::Spot, OrderBooksL2),
+            (BinanceSpot::default(), "xrp", "usdt", InstrumentKind::Spot, OrderBooksL2::default()),
+            (BinanceSpot::default(), "sol", "usdt", InstrumentKind::Spot, OrderBooksL2::default()),
+            (BinanceSpot::default(), "avax", "usdt", InstrumentKind::Spot, OrderBooksL2::default()),
+            (BinanceSpot::default(), "ltc", "usdt", InstrumentKind::Spot, OrderBooksL2::default()),
         ])
         .init()
         .await
@@ -40,7 +40,7 @@ async fn main() {
     // Select the ExchangeId::BinanceSpot stream
     // Notes:
     //  - Use `streams.select(ExchangeId)` to interact with the individual exchange streams!
-    //  - Use `streams.join()` to join all exchange streams into a single mpsc::UnboundedReceiver!
+    //  - Use `streams.join()` to join all exchange streams into a single Stream!
     let mut binance_stream = streams
         .select(ExchangeId::BinanceSpot)
         .unwrap();
@@ -40,7 +40,7 @@ async fn main() {
     // Select the ExchangeId::BinanceFuturesUsd stream
     // Notes:
     //  - Use `streams.select(ExchangeId)` to interact with the individual exchange streams!
-    //  - Use `streams.join()` to join all exchange streams into a single mpsc::UnboundedReceiver!
+    //  - Use `streams.join()` to join all exchange streams into a single Stream!
     let mut binance_stream = streams
         .select(ExchangeId::BinanceFuturesUsd)
         .unwrap();
@@ -0,0 +1,102 @@
+/* Barter-Data C ABI, generated by cbindgen from src/ffi.rs - do not edit. */
+
+#ifndef BARTER_DATA_H
+#define BARTER_DATA_H
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+// Opaque handle to a running stream of normalised [`MarketEvent`]s, delivered to a
+// [`BarterEventCallback`].
+//
+// Constructed via [`barter_stream_new`], and must be released via [`barter_stream_free`].
+typedef struct BarterStream BarterStream;
+
+// Callback invoked with each normalised [`MarketEvent`] of a [`BarterStream`], serialised as a
+// NUL terminated JSON string of `len` bytes (excluding the NUL terminator).
+//
+// The `event` pointer is only valid for the duration of the callback, so the JSON must be copied
+// if it is required afterwards. The callback is invoked sequentially from a background thread.
+//
+// A null callback is rejected by [`barter_stream_new`].
+typedef void (*BarterEventCallback)(void *user_data, const char *event, size_t len);
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+// Initialise a [`BarterStream`] for the provided JSON array of [`Subscription`] batches, invoking
+// the `callback` with each normalised [`MarketEvent`] until the stream is freed.
+//
+// Each batch opens at-least-one WebSocket connection (see [`DynamicStreams::init`]). For example:
+// ```json
+// [
+//   [
+//     {
+//       "exchange": "binance_spot",
+//       "base": "btc",
+//       "quote": "usdt",
+//       "instrument_kind": "spot",
+//       "kind": "PublicTrades"
+//     },
+//     {
+//       "exchange": "binance_spot",
+//       "base": "eth",
+//       "quote": "usdt",
+//       "instrument_kind": "spot",
+//       "kind": "OrderBooksL1"
+//     }
+//   ],
+//   [
+//     {
+//       "exchange": "okx",
+//       "base": "btc",
+//       "quote": "usdt",
+//       "instrument_kind": "perpetual",
+//       "kind": "PublicTrades"
+//     }
+//   ]
+// ]
+// ```
+//
+// Blocks until every WebSocket connection has been initialised. Returns null if the
+// subscriptions are invalid, the `callback` is null, the streams cannot be initialised, or this
+// function is called from within a Tokio runtime (eg/ from a [`BarterEventCallback`]), in which
+// case the error is described by [`barter_last_error`].
+//
+// # Safety
+// - `subscriptions` must be a valid pointer to a NUL terminated UTF-8 string.
+// - `user_data` must be safe to pass to the `callback` from another thread, and remain valid
+//   until [`barter_stream_free`] returns.
+struct BarterStream *barter_stream_new(const char *subscriptions,
+                                       BarterEventCallback callback,
+                                       void *user_data);
+
+// Stop the [`BarterStream`], closing every WebSocket connection and releasing the handle.
+//
+// Blocks until any in-flight [`BarterEventCallback`] has returned, after which the callback is
+// never invoked again. Freeing a null handle is a no-op.
+//
+// The handle may also be freed from within its own [`BarterEventCallback`], in which case the
+// background threads are shut down without blocking once the callback returns.
+//
+// # Safety
+// `stream` must be null, or a handle returned by [`barter_stream_new`] that has not already been
+// freed.
+void barter_stream_free(struct BarterStream *stream);
+
+// Message describing the last error that occurred on the calling thread, or null if no error
+// has occurred.
+//
+// The returned NUL terminated string is owned by barter-data, and is only valid until the next
+// barter-data call on the same thread.
+const char *barter_last_error(void);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
+
+#endif /* BARTER_DATA_H */
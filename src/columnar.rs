@@ -0,0 +1,544 @@
+use crate::{
+    event::MarketEvent,
+    subscription::{
+        book::OrderBookL1,
+        candle::Candle,
+        liquidation::Liquidation,
+        option::OptionSummary,
+        premium::PremiumIndex,
+        trade::{AggTrade, BlockTrade, PublicTrade},
+    },
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::{fmt::Display, marker::PhantomData};
+
+/// Logical data type of a [`Column`], mirroring the equivalent Apache Arrow `DataType`.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub enum ColumnType {
+    /// Arrow `Timestamp(Nanosecond, Some("UTC"))`.
+    TimestampNanosecondUtc,
+    /// Arrow `Utf8`.
+    Utf8,
+    /// Arrow `Float64`.
+    Float64,
+    /// Arrow `UInt64`.
+    UInt64,
+}
+
+/// Named & typed [`ColumnBatch`] field, mirroring the equivalent Apache Arrow `Field`.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Serialize)]
+pub struct Field {
+    pub name: &'static str,
+    pub data_type: ColumnType,
+}
+
+impl Field {
+    /// Construct a new non-nullable [`Field`].
+    pub const fn new(name: &'static str, data_type: ColumnType) -> Self {
+        Self { name, data_type }
+    }
+}
+
+/// Contiguous values of a single [`ColumnBatch`] field.
+///
+/// Each variant maps directly onto the equivalent Apache Arrow array
+/// (eg/ `Column::Float64(values)` -> `Float64Array::from(values)`).
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub enum Column {
+    TimestampNanosecondUtc(Vec<i64>),
+    Utf8(Vec<String>),
+    Float64(Vec<f64>),
+    UInt64(Vec<u64>),
+}
+
+impl Column {
+    /// Construct an empty [`Column`] of the provided [`ColumnType`] with the provided capacity.
+    pub fn with_capacity(data_type: ColumnType, capacity: usize) -> Self {
+        match data_type {
+            ColumnType::TimestampNanosecondUtc => {
+                Self::TimestampNanosecondUtc(Vec::with_capacity(capacity))
+            }
+            ColumnType::Utf8 => Self::Utf8(Vec::with_capacity(capacity)),
+            ColumnType::Float64 => Self::Float64(Vec::with_capacity(capacity)),
+            ColumnType::UInt64 => Self::UInt64(Vec::with_capacity(capacity)),
+        }
+    }
+
+    /// Number of values in this [`Column`].
+    pub fn len(&self) -> usize {
+        match self {
+            Self::TimestampNanosecondUtc(values) => values.len(),
+            Self::Utf8(values) => values.len(),
+            Self::Float64(values) => values.len(),
+            Self::UInt64(values) => values.len(),
+        }
+    }
+
+    /// Determine if this [`Column`] contains no values.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+/// In-memory columnar batch of normalised [`MarketEvent<T>`](MarketEvent)s, laid out the same way
+/// as an Apache Arrow `RecordBatch` so it can be handed to DataFusion / Polars pipelines without
+/// re-shaping.
+///
+/// Every batch starts with the [`MarketEvent`] metadata fields (see [`METADATA_FIELDS`]),
+/// followed by the [`Columnar::fields`] of the event kind.
+#[derive(Clone, PartialEq, Debug, Serialize)]
+pub struct ColumnBatch {
+    pub schema: Vec<Field>,
+    pub columns: Vec<Column>,
+}
+
+impl ColumnBatch {
+    /// Construct a [`ColumnBatch`] from a batch of [`MarketEvent<T>`](MarketEvent)s.
+    pub fn from_events<'a, InstrumentId, T, Iter>(events: Iter) -> Self
+    where
+        InstrumentId: Display + 'a,
+        T: Columnar + 'a,
+        Iter: IntoIterator<Item = &'a MarketEvent<InstrumentId, T>>,
+    {
+        let mut builder = ColumnBatchBuilder::<T>::default();
+        events.into_iter().for_each(|event| builder.push(event));
+        builder.finish()
+    }
+
+    /// Number of rows in this [`ColumnBatch`].
+    pub fn num_rows(&self) -> usize {
+        self.columns.first().map(Column::len).unwrap_or_default()
+    }
+
+    /// Find the [`Column`] associated with the provided [`Field`] name.
+    pub fn column(&self, name: &str) -> Option<&Column> {
+        self.schema
+            .iter()
+            .position(|field| field.name == name)
+            .map(|index| &self.columns[index])
+    }
+
+    /// Convert this [`ColumnBatch`] into the equivalent Arrow
+    /// [`RecordBatch`](arrow_array::RecordBatch).
+    #[cfg(feature = "arrow")]
+    pub fn to_record_batch(&self) -> Result<arrow_array::RecordBatch, arrow_schema::ArrowError> {
+        use arrow_array::{
+            ArrayRef, Float64Array, RecordBatch, StringArray, TimestampNanosecondArray, UInt64Array,
+        };
+        use arrow_schema::Schema;
+        use std::sync::Arc;
+
+        let fields = self
+            .schema
+            .iter()
+            .map(|field| {
+                arrow_schema::Field::new(
+                    field.name,
+                    arrow_schema::DataType::from(field.data_type),
+                    false,
+                )
+            })
+            .collect::<Vec<_>>();
+
+        let columns = self
+            .columns
+            .iter()
+            .map(|column| -> ArrayRef {
+                match column {
+                    Column::TimestampNanosecondUtc(values) => Arc::new(
+                        TimestampNanosecondArray::from(values.clone()).with_timezone("UTC"),
+                    ),
+                    Column::Utf8(values) => Arc::new(StringArray::from(values.clone())),
+                    Column::Float64(values) => Arc::new(Float64Array::from(values.clone())),
+                    Column::UInt64(values) => Arc::new(UInt64Array::from(values.clone())),
+                }
+            })
+            .collect();
+
+        RecordBatch::try_new(Arc::new(Schema::new(fields)), columns)
+    }
+}
+
+#[cfg(feature = "arrow")]
+impl From<ColumnType> for arrow_schema::DataType {
+    fn from(column_type: ColumnType) -> Self {
+        match column_type {
+            ColumnType::TimestampNanosecondUtc => {
+                Self::Timestamp(arrow_schema::TimeUnit::Nanosecond, Some("UTC".into()))
+            }
+            ColumnType::Utf8 => Self::Utf8,
+            ColumnType::Float64 => Self::Float64,
+            ColumnType::UInt64 => Self::UInt64,
+        }
+    }
+}
+
+/// [`Field`]s describing the [`MarketEvent`] metadata that prefixes every [`ColumnBatch`].
+pub const METADATA_FIELDS: [Field; 4] = [
+    Field::new("exchange_time", ColumnType::TimestampNanosecondUtc),
+    Field::new("received_time", ColumnType::TimestampNanosecondUtc),
+    Field::new("exchange", ColumnType::Utf8),
+    Field::new("instrument", ColumnType::Utf8),
+];
+
+/// Incrementally builds [`ColumnBatch`]es of [`MarketEvent<T>`](MarketEvent)s, suitable for use
+/// directly in a consumer loop (eg/ `finish()` every N events and forward the batch downstream).
+#[derive(Debug)]
+pub struct ColumnBatchBuilder<T> {
+    schema: Vec<Field>,
+    columns: Vec<Column>,
+    capacity: usize,
+    phantom: PhantomData<T>,
+}
+
+impl<T> Default for ColumnBatchBuilder<T>
+where
+    T: Columnar,
+{
+    fn default() -> Self {
+        Self::with_capacity(0)
+    }
+}
+
+impl<T> ColumnBatchBuilder<T>
+where
+    T: Columnar,
+{
+    /// Construct a new [`ColumnBatchBuilder`] that pre-allocates space for the provided number of
+    /// rows per [`ColumnBatch`].
+    pub fn with_capacity(capacity: usize) -> Self {
+        let schema = METADATA_FIELDS
+            .into_iter()
+            .chain(T::fields())
+            .collect::<Vec<_>>();
+
+        Self {
+            columns: empty_columns(&schema, capacity),
+            schema,
+            capacity,
+            phantom: PhantomData,
+        }
+    }
+
+    /// Append a [`MarketEvent<T>`](MarketEvent) row.
+    pub fn push<InstrumentId>(&mut self, event: &MarketEvent<InstrumentId, T>)
+    where
+        InstrumentId: Display,
+    {
+        let mut writer = ColumnWriter {
+            columns: self.columns.iter_mut(),
+        };
+        writer.timestamp(event.exchange_time);
+        writer.timestamp(event.received_time);
+        writer.utf8(event.exchange.to_string());
+        writer.utf8(event.instrument.to_string());
+        event.kind.append(&mut writer);
+    }
+
+    /// Number of rows appended since the last [`ColumnBatch`] was finished.
+    pub fn len(&self) -> usize {
+        self.columns.first().map(Column::len).unwrap_or_default()
+    }
+
+    /// Determine if no rows have been appended since the last [`ColumnBatch`] was finished.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Take the [`ColumnBatch`] of every row appended so far, resetting this builder.
+    pub fn finish(&mut self) -> ColumnBatch {
+        ColumnBatch {
+            schema: self.schema.clone(),
+            columns: std::mem::replace(
+                &mut self.columns,
+                empty_columns(&self.schema, self.capacity),
+            ),
+        }
+    }
+}
+
+fn empty_columns(schema: &[Field], capacity: usize) -> Vec<Column> {
+    schema
+        .iter()
+        .map(|field| Column::with_capacity(field.data_type, capacity))
+        .collect()
+}
+
+/// Writes the values of a single row into each [`Column`] in [`Field`] order.
+#[derive(Debug)]
+pub struct ColumnWriter<'a> {
+    columns: std::slice::IterMut<'a, Column>,
+}
+
+impl ColumnWriter<'_> {
+    fn next(&mut self) -> &mut Column {
+        self.columns
+            .next()
+            .expect("Columnar::append wrote more values than Columnar::fields")
+    }
+
+    /// Write a `DateTime<Utc>` value to the next [`ColumnType::TimestampNanosecondUtc`] column.
+    pub fn timestamp(&mut self, value: DateTime<Utc>) {
+        match self.next() {
+            Column::TimestampNanosecondUtc(values) => {
+                values.push(value.timestamp_nanos_opt().unwrap_or_default())
+            }
+            column => panic!("expected TimestampNanosecondUtc column, found: {column:?}"),
+        }
+    }
+
+    /// Write a `String` value to the next [`ColumnType::Utf8`] column.
+    pub fn utf8(&mut self, value: String) {
+        match self.next() {
+            Column::Utf8(values) => values.push(value),
+            column => panic!("expected Utf8 column, found: {column:?}"),
+        }
+    }
+
+    /// Write an `f64` value to the next [`ColumnType::Float64`] column.
+    pub fn f64(&mut self, value: f64) {
+        match self.next() {
+            Column::Float64(values) => values.push(value),
+            column => panic!("expected Float64 column, found: {column:?}"),
+        }
+    }
+
+    /// Write a `u64` value to the next [`ColumnType::UInt64`] column.
+    pub fn u64(&mut self, value: u64) {
+        match self.next() {
+            Column::UInt64(values) => values.push(value),
+            column => panic!("expected UInt64 column, found: {column:?}"),
+        }
+    }
+}
+
+/// Normalised [`MarketEvent<T>`](MarketEvent) kind that can be written to a [`ColumnBatch`].
+pub trait Columnar {
+    /// [`Field`]s of this event kind, excluding the [`METADATA_FIELDS`].
+    fn fields() -> Vec<Field>;
+
+    /// Write the values of this event using the [`ColumnWriter`], in [`Columnar::fields`] order.
+    fn append(&self, writer: &mut ColumnWriter<'_>);
+}
+
+impl Columnar for PublicTrade {
+    fn fields() -> Vec<Field> {
+        vec![
+            Field::new("id", ColumnType::Utf8),
+            Field::new("price", ColumnType::Float64),
+            Field::new("amount", ColumnType::Float64),
+            Field::new("side", ColumnType::Utf8),
+        ]
+    }
+
+    fn append(&self, writer: &mut ColumnWriter<'_>) {
+        writer.utf8(self.id.clone());
+        writer.f64(self.price);
+        writer.f64(self.amount);
+        writer.utf8(self.side.to_string());
+    }
+}
+
+impl Columnar for AggTrade {
+    fn fields() -> Vec<Field> {
+        vec![
+            Field::new("first_id", ColumnType::UInt64),
+            Field::new("last_id", ColumnType::UInt64),
+            Field::new("price", ColumnType::Float64),
+            Field::new("amount", ColumnType::Float64),
+            Field::new("side", ColumnType::Utf8),
+        ]
+    }
+
+    fn append(&self, writer: &mut ColumnWriter<'_>) {
+        writer.u64(self.first_id);
+        writer.u64(self.last_id);
+        writer.f64(self.price);
+        writer.f64(self.amount);
+        writer.utf8(self.side.to_string());
+    }
+}
+
+impl Columnar for BlockTrade {
+    fn fields() -> Vec<Field> {
+        PublicTrade::fields()
+    }
+
+    fn append(&self, writer: &mut ColumnWriter<'_>) {
+        writer.utf8(self.id.clone());
+        writer.f64(self.price);
+        writer.f64(self.amount);
+        writer.utf8(self.side.to_string());
+    }
+}
+
+impl Columnar for OrderBookL1 {
+    fn fields() -> Vec<Field> {
+        vec![
+            Field::new("last_update_time", ColumnType::TimestampNanosecondUtc),
+            Field::new("best_bid_price", ColumnType::Float64),
+            Field::new("best_bid_amount", ColumnType::Float64),
+            Field::new("best_ask_price", ColumnType::Float64),
+            Field::new("best_ask_amount", ColumnType::Float64),
+        ]
+    }
+
+    fn append(&self, writer: &mut ColumnWriter<'_>) {
+        writer.timestamp(self.last_update_time);
+        writer.f64(self.best_bid.price);
+        writer.f64(self.best_bid.amount);
+        writer.f64(self.best_ask.price);
+        writer.f64(self.best_ask.amount);
+    }
+}
+
+impl Columnar for Liquidation {
+    fn fields() -> Vec<Field> {
+        vec![
+            Field::new("side", ColumnType::Utf8),
+            Field::new("price", ColumnType::Float64),
+            Field::new("quantity", ColumnType::Float64),
+            Field::new("time", ColumnType::TimestampNanosecondUtc),
+        ]
+    }
+
+    fn append(&self, writer: &mut ColumnWriter<'_>) {
+        writer.utf8(self.side.to_string());
+        writer.f64(self.price);
+        writer.f64(self.quantity);
+        writer.timestamp(self.time);
+    }
+}
+
+impl Columnar for Candle {
+    fn fields() -> Vec<Field> {
+        vec![
+            Field::new("close_time", ColumnType::TimestampNanosecondUtc),
+            Field::new("open", ColumnType::Float64),
+            Field::new("high", ColumnType::Float64),
+            Field::new("low", ColumnType::Float64),
+            Field::new("close", ColumnType::Float64),
+            Field::new("volume", ColumnType::Float64),
+            Field::new("trade_count", ColumnType::UInt64),
+        ]
+    }
+
+    fn append(&self, writer: &mut ColumnWriter<'_>) {
+        writer.timestamp(self.close_time);
+        writer.f64(self.open);
+        writer.f64(self.high);
+        writer.f64(self.low);
+        writer.f64(self.close);
+        writer.f64(self.volume);
+        writer.u64(self.trade_count);
+    }
+}
+
+impl Columnar for OptionSummary {
+    fn fields() -> Vec<Field> {
+        vec![
+            Field::new("iv", ColumnType::Float64),
+            Field::new("delta", ColumnType::Float64),
+            Field::new("gamma", ColumnType::Float64),
+            Field::new("vega", ColumnType::Float64),
+            Field::new("theta", ColumnType::Float64),
+            Field::new("mark", ColumnType::Float64),
+            Field::new("open_interest", ColumnType::Float64),
+        ]
+    }
+
+    fn append(&self, writer: &mut ColumnWriter<'_>) {
+        writer.f64(self.iv);
+        writer.f64(self.delta);
+        writer.f64(self.gamma);
+        writer.f64(self.vega);
+        writer.f64(self.theta);
+        writer.f64(self.mark);
+        writer.f64(self.open_interest);
+    }
+}
+
+impl Columnar for PremiumIndex {
+    fn fields() -> Vec<Field> {
+        vec![
+            Field::new("mark_price", ColumnType::Float64),
+            Field::new("index_price", ColumnType::Float64),
+            Field::new("funding_rate", ColumnType::Float64),
+            Field::new("next_funding_time", ColumnType::TimestampNanosecondUtc),
+        ]
+    }
+
+    fn append(&self, writer: &mut ColumnWriter<'_>) {
+        writer.f64(self.mark_price);
+        writer.f64(self.index_price);
+        writer.f64(self.funding_rate);
+        writer.timestamp(self.next_funding_time);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use barter_integration::model::{Exchange, Side};
+
+    #[test]
+    fn test_column_batch_builder() {
+        let time = DateTime::<Utc>::from_timestamp(1, 0).unwrap();
+        let trade = |id: &str, price: f64| MarketEvent {
+            exchange_time: time,
+            received_time: time,
+            exchange: Exchange::from("exchange"),
+            instrument: "btc_usdt",
+            kind: PublicTrade {
+                id: id.to_string(),
+                price,
+                amount: 1.0,
+                side: Side::Buy,
+            },
+        };
+
+        let mut builder = ColumnBatchBuilder::<PublicTrade>::with_capacity(2);
+        builder.push(&trade("1", 100.0));
+        builder.push(&trade("2", 101.0));
+        assert_eq!(builder.len(), 2);
+
+        let batch = builder.finish();
+        assert!(builder.is_empty());
+        assert_eq!(batch.num_rows(), 2);
+        assert_eq!(batch.schema.len(), METADATA_FIELDS.len() + 4);
+        assert_eq!(
+            batch.column("exchange_time"),
+            Some(&Column::TimestampNanosecondUtc(vec![
+                1_000_000_000,
+                1_000_000_000
+            ]))
+        );
+        assert_eq!(
+            batch.column("instrument"),
+            Some(&Column::Utf8(vec![
+                "btc_usdt".to_string(),
+                "btc_usdt".to_string()
+            ]))
+        );
+        assert_eq!(
+            batch.column("price"),
+            Some(&Column::Float64(vec![100.0, 101.0]))
+        );
+        assert_eq!(
+            batch,
+            ColumnBatch::from_events(&[trade("1", 100.0), trade("2", 101.0)])
+        );
+
+        #[cfg(feature = "arrow")]
+        {
+            let record_batch = batch.to_record_batch().unwrap();
+            assert_eq!(record_batch.num_rows(), 2);
+            assert_eq!(record_batch.num_columns(), batch.schema.len());
+            assert_eq!(
+                record_batch.schema().field(0).data_type(),
+                &arrow_schema::DataType::from(ColumnType::TimestampNanosecondUtc)
+            );
+        }
+    }
+}
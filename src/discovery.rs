@@ -0,0 +1,209 @@
+use crate::{
+    error::DataError,
+    exchange::{Connector, ExchangeId},
+    subscription::Subscription,
+    Identifier,
+};
+use std::{
+    collections::{BTreeSet, HashMap},
+    sync::{Arc, RwLock},
+};
+
+/// Maximum number of nearest-match suggestions included in a [`DataError::UnknownMarket`].
+pub const MAX_SUGGESTIONS: usize = 3;
+
+/// Shared, cloneable cache of the markets listed by each exchange (eg/ "XBT/USD" for Kraken).
+///
+/// Once populated for an [`ExchangeId`] (eg/ from an exchange instruments REST endpoint, or a
+/// config file), [`Subscription`]s can be validated against the listed markets before any
+/// connection is made. Unknown markets generate a [`DataError::UnknownMarket`] that includes the
+/// nearest-matching listed markets (eg/ "did you mean XBT/USD?").
+///
+/// Exchanges without any cached markets are not validated.
+#[derive(Clone, Debug, Default)]
+pub struct InstrumentDiscovery {
+    markets: Arc<RwLock<HashMap<ExchangeId, BTreeSet<String>>>>,
+}
+
+impl InstrumentDiscovery {
+    /// Cache the provided collection of markets listed by an exchange.
+    pub fn insert<Iter, Market>(&self, exchange: ExchangeId, markets: Iter)
+    where
+        Iter: IntoIterator<Item = Market>,
+        Market: Into<String>,
+    {
+        self.markets
+            .write()
+            .expect("InstrumentDiscovery lock poisoned")
+            .entry(exchange)
+            .or_default()
+            .extend(markets.into_iter().map(Market::into));
+    }
+
+    /// Determine if markets have been cached for the provided [`ExchangeId`].
+    pub fn is_populated(&self, exchange: ExchangeId) -> bool {
+        self.markets
+            .read()
+            .expect("InstrumentDiscovery lock poisoned")
+            .get(&exchange)
+            .is_some_and(|markets| !markets.is_empty())
+    }
+
+    /// Determine if the provided market is listed by the exchange.
+    pub fn contains(&self, exchange: ExchangeId, market: &str) -> bool {
+        self.markets
+            .read()
+            .expect("InstrumentDiscovery lock poisoned")
+            .get(&exchange)
+            .is_some_and(|markets| markets.contains(market))
+    }
+
+    /// Find the listed exchange markets nearest to the provided market, ordered by similarity.
+    ///
+    /// Markets are compared case-insensitively, ignoring separators such as "/", "-" and "_", and
+    /// treating [`ASSET_ALIASES`] as equal (eg/ "BTC/USD" is nearest to "XBT/USD").
+    pub fn suggest(&self, exchange: ExchangeId, market: &str) -> Vec<String> {
+        let target = normalise(market);
+        let max_distance = (target.len() / 3).max(2);
+
+        let markets = self
+            .markets
+            .read()
+            .expect("InstrumentDiscovery lock poisoned");
+
+        let mut candidates = markets
+            .get(&exchange)
+            .into_iter()
+            .flatten()
+            .filter_map(|listed| {
+                let distance = edit_distance(&target, &normalise(listed));
+                (distance <= max_distance).then_some((distance, listed))
+            })
+            .collect::<Vec<_>>();
+
+        candidates.sort();
+        candidates
+            .into_iter()
+            .take(MAX_SUGGESTIONS)
+            .map(|(_, listed)| listed.clone())
+            .collect()
+    }
+
+    /// Validate that the exchange market of every [`Subscription`] is listed, returning a
+    /// [`DataError::UnknownMarket`] with nearest-match suggestions for the first that is not.
+    ///
+    /// [`Subscription`]s for exchanges without any cached markets are always valid.
+    pub fn validate<Exchange, Instrument, Kind>(
+        &self,
+        subscriptions: &[Subscription<Exchange, Instrument, Kind>],
+    ) -> Result<(), DataError>
+    where
+        Exchange: Connector,
+        Subscription<Exchange, Instrument, Kind>: Identifier<Exchange::Market>,
+    {
+        if !self.is_populated(Exchange::ID) {
+            return Ok(());
+        }
+
+        subscriptions.iter().try_for_each(|subscription| {
+            let market: Exchange::Market = subscription.id();
+            let market = market.as_ref();
+
+            if self.contains(Exchange::ID, market) {
+                Ok(())
+            } else {
+                Err(DataError::UnknownMarket {
+                    exchange: Exchange::ID,
+                    market: market.to_owned(),
+                    suggestions: self.suggest(Exchange::ID, market),
+                })
+            }
+        })
+    }
+}
+
+/// Exchange specific asset aliases, mapped to their common name when comparing markets.
+///
+/// eg/ Kraken lists "XBT/USD" rather than "BTC/USD".
+pub const ASSET_ALIASES: [(&str, &str); 2] = [("XBT", "BTC"), ("XDG", "DOGE")];
+
+/// Normalise a market for comparison by uppercasing, removing any separators, and replacing
+/// any [`ASSET_ALIASES`].
+fn normalise(market: &str) -> String {
+    let market = market
+        .chars()
+        .filter(char::is_ascii_alphanumeric)
+        .map(|char| char.to_ascii_uppercase())
+        .collect::<String>();
+
+    ASSET_ALIASES
+        .iter()
+        .fold(market, |market, (alias, name)| market.replace(alias, name))
+}
+
+/// Levenshtein edit distance between two strings.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b = b.as_bytes();
+    let mut previous = (0..=b.len()).collect::<Vec<_>>();
+    let mut current = vec![0; b.len() + 1];
+
+    for (i, a_byte) in a.bytes().enumerate() {
+        current[0] = i + 1;
+        for (j, b_byte) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(a_byte != *b_byte);
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+        std::mem::swap(&mut previous, &mut current);
+    }
+
+    previous[b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{exchange::kraken::Kraken, subscription::trade::PublicTrades};
+    use barter_integration::model::instrument::kind::InstrumentKind;
+
+    #[test]
+    fn test_edit_distance() {
+        assert_eq!(edit_distance("", ""), 0);
+        assert_eq!(edit_distance("BTCUSD", "BTCUSD"), 0);
+        assert_eq!(edit_distance("BTCUSD", "XBTUSD"), 2);
+        assert_eq!(edit_distance("ETHUSD", "BTCUSD"), 2);
+        assert_eq!(edit_distance("BTCUSD", "USDBTC"), 6);
+    }
+
+    #[test]
+    fn test_instrument_discovery_validate() {
+        let discovery = InstrumentDiscovery::default();
+        let subscription = |base: &str| {
+            Subscription::from((Kraken, base, "usd", InstrumentKind::Spot, PublicTrades))
+        };
+
+        // Exchanges without cached markets are not validated
+        assert!(discovery.validate(&[subscription("btc")]).is_ok());
+
+        discovery.insert(
+            ExchangeId::Kraken,
+            ["XBT/USD", "ETH/USD", "XBT/EUR", "SOL/USD"],
+        );
+        assert!(discovery.validate(&[subscription("eth")]).is_ok());
+
+        match discovery.validate(&[subscription("eth"), subscription("btc")]) {
+            Err(DataError::UnknownMarket {
+                exchange,
+                market,
+                suggestions,
+            }) => {
+                assert_eq!(exchange, ExchangeId::Kraken);
+                assert_eq!(market, "BTC/USD");
+                assert_eq!(
+                    suggestions,
+                    vec!["XBT/USD".to_string(), "ETH/USD".to_string()]
+                );
+            }
+            other => panic!("expected DataError::UnknownMarket, found: {other:?}"),
+        }
+    }
+}
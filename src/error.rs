@@ -1,6 +1,6 @@
 use crate::exchange::ExchangeId;
 use crate::subscription::SubKind;
-use barter_integration::error::SocketError;
+use barter_integration::{error::SocketError, model::SubscriptionId};
 use thiserror::Error;
 
 /// All errors generated in `barter-data`.
@@ -15,6 +15,16 @@ pub enum DataError {
         sub_kind: SubKind,
     },
 
+    #[error(
+        "unknown market: {market} for exchange: {exchange}{}",
+        did_you_mean(suggestions)
+    )]
+    UnknownMarket {
+        exchange: ExchangeId,
+        market: String,
+        suggestions: Vec<String>,
+    },
+
     #[error(
         "\
         InvalidSequence: first_update_id {first_update_id} does not follow on from the \
@@ -25,6 +35,45 @@ pub enum DataError {
         prev_last_update_id: u64,
         first_update_id: u64,
     },
+
+    #[error(
+        "InvalidChecksum: local OrderBook checksum {actual} does not match exchange checksum \
+        {expected}"
+    )]
+    InvalidChecksum { expected: u32, actual: u32 },
+
+    #[error(
+        "SequenceGap: OrderBook re-subscribed since sequence {actual} does not follow on from \
+        {expected}"
+    )]
+    SequenceGap { expected: u64, actual: u64 },
+
+    #[error("OrderBook for {subscription_id} re-initialising after: {reason}")]
+    OrderBookResync {
+        subscription_id: SubscriptionId,
+        reason: String,
+    },
+
+    #[error("OrderBook for {subscription_id} diverged from exchange snapshot: {discrepancy}")]
+    OrderBookDiscrepancy {
+        subscription_id: SubscriptionId,
+        discrepancy: String,
+    },
+
+    #[error("Subscription {subscription_id} rejected by exchange: {reason}")]
+    SubscriptionRejected {
+        subscription_id: SubscriptionId,
+        reason: String,
+    },
+
+    #[error("duplicate Subscription {subscription_id} for exchange: {exchange}")]
+    DuplicateSubscription {
+        exchange: ExchangeId,
+        subscription_id: SubscriptionId,
+    },
+
+    #[error("OrderBook desynchronised and must be re-initialised: {0}")]
+    BookDesynchronised(String),
 }
 
 impl DataError {
@@ -32,10 +81,39 @@ impl DataError {
     #[allow(clippy::match_like_matches_macro)]
     pub fn is_terminal(&self) -> bool {
         match self {
-            DataError::InvalidSequence { .. } => true,
+            DataError::InvalidSequence { .. } | DataError::BookDesynchronised(_) => true,
             _ => false,
         }
     }
+
+    /// [`SubscriptionId`] of the [`Subscription`](crate::subscription::Subscription) that
+    /// generated this error, if it is known.
+    pub fn subscription_id(&self) -> Option<&SubscriptionId> {
+        match self {
+            DataError::Socket(SocketError::Unidentifiable(subscription_id))
+            | DataError::OrderBookResync {
+                subscription_id, ..
+            }
+            | DataError::OrderBookDiscrepancy {
+                subscription_id, ..
+            }
+            | DataError::SubscriptionRejected {
+                subscription_id, ..
+            }
+            | DataError::DuplicateSubscription {
+                subscription_id, ..
+            } => Some(subscription_id),
+            _ => None,
+        }
+    }
+}
+
+/// Format nearest-match suggestions as a human readable hint (eg/ ", did you mean XBT/USD?").
+fn did_you_mean(suggestions: &[String]) -> String {
+    match suggestions {
+        [] => String::new(),
+        suggestions => format!(", did you mean {}?", suggestions.join(" or ")),
+    }
 }
 
 #[cfg(test)]
@@ -59,7 +137,44 @@ mod tests {
                 expected: true,
             },
             TestCase {
-                // TC1: is not terminal w/ DataError::Socket
+                // TC1: is terminal w/ DataError::BookDesynchronised
+                input: DataError::BookDesynchronised("checksum mismatch".to_string()),
+                expected: true,
+            },
+            TestCase {
+                // TC2: is not terminal w/ DataError::InvalidChecksum
+                input: DataError::InvalidChecksum {
+                    expected: 0,
+                    actual: 1,
+                },
+                expected: false,
+            },
+            TestCase {
+                // TC3: is not terminal w/ DataError::SequenceGap
+                input: DataError::SequenceGap {
+                    expected: 0,
+                    actual: 2,
+                },
+                expected: false,
+            },
+            TestCase {
+                // TC4: is not terminal w/ DataError::OrderBookResync
+                input: DataError::OrderBookResync {
+                    subscription_id: SubscriptionId::from("btcusdt@depth@100ms"),
+                    reason: "gap".to_string(),
+                },
+                expected: false,
+            },
+            TestCase {
+                // TC5: is not terminal w/ DataError::OrderBookDiscrepancy
+                input: DataError::OrderBookDiscrepancy {
+                    subscription_id: SubscriptionId::from("btcusdt@depth@100ms"),
+                    discrepancy: "bid level 0".to_string(),
+                },
+                expected: false,
+            },
+            TestCase {
+                // TC6: is not terminal w/ DataError::Socket
                 input: DataError::Socket(SocketError::Sink),
                 expected: false,
             },
@@ -70,4 +185,34 @@ mod tests {
             assert_eq!(actual, test.expected, "TC{} failed", index);
         }
     }
+
+    #[test]
+    fn test_data_error_unknown_market_display() {
+        let error = DataError::UnknownMarket {
+            exchange: ExchangeId::Kraken,
+            market: "BTC/USD".to_string(),
+            suggestions: vec!["XBT/USD".to_string(), "XBT/USDT".to_string()],
+        };
+        assert_eq!(
+            error.to_string(),
+            "unknown market: BTC/USD for exchange: kraken, did you mean XBT/USD or XBT/USDT?"
+        );
+    }
+
+    #[test]
+    fn test_data_error_subscription_id() {
+        let subscription_id = SubscriptionId::from("@trade|BTCUSDT");
+
+        let error = DataError::SubscriptionRejected {
+            subscription_id: subscription_id.clone(),
+            reason: "invalid symbol".to_owned(),
+        };
+        assert_eq!(error.subscription_id(), Some(&subscription_id));
+
+        let error = DataError::Socket(SocketError::Unidentifiable(subscription_id.clone()));
+        assert_eq!(error.subscription_id(), Some(&subscription_id));
+
+        let error = DataError::BookDesynchronised("checksum mismatch".to_owned());
+        assert_eq!(error.subscription_id(), None);
+    }
 }
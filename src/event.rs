@@ -1,10 +1,16 @@
 use crate::{
     error::DataError,
     subscription::{
-        book::{OrderBook, OrderBookL1},
+        book::{OrderBook, OrderBookEvent, OrderBookL1, OrderBookL3Event},
         candle::Candle,
+        funding::FundingRate,
         liquidation::Liquidation,
-        trade::PublicTrade,
+        open_interest::OpenInterest,
+        option::OptionSummary,
+        premium::PremiumIndex,
+        stats::MarketSentiment,
+        status::SystemStatus,
+        trade::{AggTrade, BlockTrade, PublicTrade},
     },
 };
 use barter_integration::model::instrument::Instrument;
@@ -27,6 +33,19 @@ impl<InstrumentId, T> FromIterator<Result<MarketEvent<InstrumentId, T>, DataErro
     }
 }
 
+impl<InstrumentId, T> MarketIter<InstrumentId, T> {
+    /// Map each [`MarketEvent<T>`](MarketEvent) into a [`MarketEvent<DataKind>`](MarketEvent).
+    pub fn into_data_kinds(self) -> MarketIter<InstrumentId, DataKind>
+    where
+        MarketEvent<InstrumentId, T>: Into<MarketEvent<InstrumentId, DataKind>>,
+    {
+        self.0
+            .into_iter()
+            .map(|event| event.map(MarketEvent::into))
+            .collect()
+    }
+}
+
 /// Normalised Barter [`MarketEvent<T>`](Self) wrapping the `T` data variant in metadata.
 ///
 /// Note: `T` can be an enum such as the [`DataKind`] if required.
@@ -46,24 +65,56 @@ pub struct MarketEvent<InstrumentId = Instrument, T = DataKind> {
     pub kind: T,
 }
 
+/// Type-erased [`MarketEvent`] able to convey every kind of normalised market data, allowing
+/// downstream frameworks (eg/ an engine or custom event bus) to route all data kinds through one
+/// channel type.
+///
+/// Every [`MarketEvent<SubscriptionKind::Event>`](MarketEvent) converts into a [`DynMarketEvent`]
+/// via [`From`], and [`Streams::erase`](crate::streams::Streams::erase) erases entire
+/// [`Streams`](crate::streams::Streams).
+pub type DynMarketEvent<InstrumentId = Instrument> = MarketEvent<InstrumentId, DataKind>;
+
+/// Determines if a [`MarketEvent`] conveys the initial state of a stateful
+/// [`Subscription`](crate::subscription::Subscription) (eg/ OrderBooks, candles) after
+/// (re)subscribing, or a live update of that state.
+///
+/// See [`Streams::with_snapshots`](crate::streams::Streams::with_snapshots).
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub enum EventPhase {
+    /// Initial state received immediately after subscription validation (ie/ warm-up state).
+    Snapshot,
+
+    /// Live update received after the initial state.
+    Live,
+}
+
 /// Available kinds of normalised Barter [`MarketEvent<T>`](MarketEvent).
 ///
 /// ### Notes
-/// - [`Self`] is only used as the [`MarketEvent<DataKind>`](MarketEvent) `Output` when combining
+/// - [`Self`] is used as the [`MarketEvent<DataKind>`](MarketEvent) `Output` when combining
 ///   several [`Streams<SubscriptionKind::Event>`](crate::streams::Streams) using the
 ///   [`MultiStreamBuilder<Output>`](crate::streams::builder::multi::MultiStreamBuilder), or via
 ///   the [`DynamicStreams::select_all`](crate::streams::builder::dynamic::DynamicStreams) method.
-/// - [`Self`] is purposefully not supported in any
-///   [`Subscription`](crate::subscription::Subscription)s directly, it is only used to
-///   make ergonomic [`Streams`](crate::streams::Streams) containing many
-///   [`MarketEvent<T>`](MarketEvent) kinds.
+/// - [`Self`] is also the output of the [`DataKinds`](crate::subscription::DataKinds)
+///   [`SubscriptionKind`](crate::subscription::SubscriptionKind), which combines
+///   [`Subscription`](crate::subscription::Subscription)s of mixed kinds into one stream.
 #[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
 pub enum DataKind {
     Trade(PublicTrade),
+    AggTrade(AggTrade),
+    BlockTrade(BlockTrade),
     OrderBookL1(OrderBookL1),
     OrderBook(OrderBook),
+    OrderBookEvent(OrderBookEvent),
+    OrderBookL3(OrderBookL3Event),
     Candle(Candle),
     Liquidation(Liquidation),
+    OptionSummary(OptionSummary),
+    PremiumIndex(PremiumIndex),
+    FundingRate(FundingRate),
+    OpenInterest(OpenInterest),
+    MarketSentiment(MarketSentiment),
+    SystemStatus(SystemStatus),
 }
 
 impl<InstrumentId> From<MarketEvent<InstrumentId, PublicTrade>>
@@ -80,6 +131,34 @@ impl<InstrumentId> From<MarketEvent<InstrumentId, PublicTrade>>
     }
 }
 
+impl<InstrumentId> From<MarketEvent<InstrumentId, AggTrade>>
+    for MarketEvent<InstrumentId, DataKind>
+{
+    fn from(event: MarketEvent<InstrumentId, AggTrade>) -> Self {
+        Self {
+            exchange_time: event.exchange_time,
+            received_time: event.received_time,
+            exchange: event.exchange,
+            instrument: event.instrument,
+            kind: DataKind::AggTrade(event.kind),
+        }
+    }
+}
+
+impl<InstrumentId> From<MarketEvent<InstrumentId, BlockTrade>>
+    for MarketEvent<InstrumentId, DataKind>
+{
+    fn from(event: MarketEvent<InstrumentId, BlockTrade>) -> Self {
+        Self {
+            exchange_time: event.exchange_time,
+            received_time: event.received_time,
+            exchange: event.exchange,
+            instrument: event.instrument,
+            kind: DataKind::BlockTrade(event.kind),
+        }
+    }
+}
+
 impl<InstrumentId> From<MarketEvent<InstrumentId, OrderBookL1>>
     for MarketEvent<InstrumentId, DataKind>
 {
@@ -108,6 +187,34 @@ impl<InstrumentId> From<MarketEvent<InstrumentId, OrderBook>>
     }
 }
 
+impl<InstrumentId> From<MarketEvent<InstrumentId, OrderBookEvent>>
+    for MarketEvent<InstrumentId, DataKind>
+{
+    fn from(event: MarketEvent<InstrumentId, OrderBookEvent>) -> Self {
+        Self {
+            exchange_time: event.exchange_time,
+            received_time: event.received_time,
+            exchange: event.exchange,
+            instrument: event.instrument,
+            kind: DataKind::OrderBookEvent(event.kind),
+        }
+    }
+}
+
+impl<InstrumentId> From<MarketEvent<InstrumentId, OrderBookL3Event>>
+    for MarketEvent<InstrumentId, DataKind>
+{
+    fn from(event: MarketEvent<InstrumentId, OrderBookL3Event>) -> Self {
+        Self {
+            exchange_time: event.exchange_time,
+            received_time: event.received_time,
+            exchange: event.exchange,
+            instrument: event.instrument,
+            kind: DataKind::OrderBookL3(event.kind),
+        }
+    }
+}
+
 impl<InstrumentId> From<MarketEvent<InstrumentId, Candle>> for MarketEvent<InstrumentId, DataKind> {
     fn from(event: MarketEvent<InstrumentId, Candle>) -> Self {
         Self {
@@ -133,3 +240,125 @@ impl<InstrumentId> From<MarketEvent<InstrumentId, Liquidation>>
         }
     }
 }
+
+impl<InstrumentId> From<MarketEvent<InstrumentId, OptionSummary>>
+    for MarketEvent<InstrumentId, DataKind>
+{
+    fn from(event: MarketEvent<InstrumentId, OptionSummary>) -> Self {
+        Self {
+            exchange_time: event.exchange_time,
+            received_time: event.received_time,
+            exchange: event.exchange,
+            instrument: event.instrument,
+            kind: DataKind::OptionSummary(event.kind),
+        }
+    }
+}
+
+impl<InstrumentId> From<MarketEvent<InstrumentId, PremiumIndex>>
+    for MarketEvent<InstrumentId, DataKind>
+{
+    fn from(event: MarketEvent<InstrumentId, PremiumIndex>) -> Self {
+        Self {
+            exchange_time: event.exchange_time,
+            received_time: event.received_time,
+            exchange: event.exchange,
+            instrument: event.instrument,
+            kind: DataKind::PremiumIndex(event.kind),
+        }
+    }
+}
+
+impl<InstrumentId> From<MarketEvent<InstrumentId, FundingRate>>
+    for MarketEvent<InstrumentId, DataKind>
+{
+    fn from(event: MarketEvent<InstrumentId, FundingRate>) -> Self {
+        Self {
+            exchange_time: event.exchange_time,
+            received_time: event.received_time,
+            exchange: event.exchange,
+            instrument: event.instrument,
+            kind: DataKind::FundingRate(event.kind),
+        }
+    }
+}
+
+impl<InstrumentId> From<MarketEvent<InstrumentId, OpenInterest>>
+    for MarketEvent<InstrumentId, DataKind>
+{
+    fn from(event: MarketEvent<InstrumentId, OpenInterest>) -> Self {
+        Self {
+            exchange_time: event.exchange_time,
+            received_time: event.received_time,
+            exchange: event.exchange,
+            instrument: event.instrument,
+            kind: DataKind::OpenInterest(event.kind),
+        }
+    }
+}
+
+impl<InstrumentId> From<MarketEvent<InstrumentId, MarketSentiment>>
+    for MarketEvent<InstrumentId, DataKind>
+{
+    fn from(event: MarketEvent<InstrumentId, MarketSentiment>) -> Self {
+        Self {
+            exchange_time: event.exchange_time,
+            received_time: event.received_time,
+            exchange: event.exchange,
+            instrument: event.instrument,
+            kind: DataKind::MarketSentiment(event.kind),
+        }
+    }
+}
+
+impl<InstrumentId> From<MarketEvent<InstrumentId, SystemStatus>>
+    for MarketEvent<InstrumentId, DataKind>
+{
+    fn from(event: MarketEvent<InstrumentId, SystemStatus>) -> Self {
+        Self {
+            exchange_time: event.exchange_time,
+            received_time: event.received_time,
+            exchange: event.exchange,
+            instrument: event.instrument,
+            kind: DataKind::SystemStatus(event.kind),
+        }
+    }
+}
+
+/// Terminal event signalling that a [`MarketEvent<T>`](MarketEvent) feed has ended, allowing
+/// consumers to distinguish a feed that has finished from a channel that closed unexpectedly.
+#[derive(Clone, Eq, PartialEq, Hash, Debug, Deserialize, Serialize)]
+pub struct StreamEnded {
+    pub reason: StreamEndReason,
+}
+
+/// Reason a [`MarketEvent<T>`](MarketEvent) feed ended.
+#[derive(Clone, Eq, PartialEq, Hash, Debug, Deserialize, Serialize)]
+pub enum StreamEndReason {
+    /// Finite feed (eg/ a replay) yielded every available event.
+    Finished,
+
+    /// Feed was shut down gracefully (eg/ the downstream receiver was dropped).
+    Shutdown,
+
+    /// Feed was terminated by an unrecoverable error.
+    Error(String),
+}
+
+impl StreamEnded {
+    /// Construct a new [`StreamEnded`] with the provided [`StreamEndReason`].
+    pub fn new(reason: StreamEndReason) -> Self {
+        Self { reason }
+    }
+}
+
+/// Item of a [`MarketEvent<T>`](MarketEvent) feed that is terminated in-stream by its
+/// [`StreamEnded`] event (see [`Streams::with_end`](crate::streams::Streams::with_end)).
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub enum FeedEvent<T> {
+    /// Event yielded by the feed.
+    Item(T),
+
+    /// Terminal event, after which the feed yields nothing else.
+    Ended(StreamEnded),
+}
@@ -75,6 +75,27 @@ where
         .map(|market| ExchangeSub::from((BinanceChannel::ORDER_BOOK_L2, market)).id())
 }
 
+/// Normalise the subscribed [`SubscriptionId`] of any OrderBook Level2 depth stream variant
+/// (eg/ "@depth10@100ms|BTCUSDT") to the [`SubscriptionId`] yielded by
+/// [`de_ob_l2_subscription_id`] (eg/ "@depth@100ms|BTCUSDT"), since every variant delivers
+/// messages identified by the market alone.
+///
+/// Also returns if the variant is a partial depth stream (ie/ top N level snapshots).
+pub fn normalise_ob_l2_subscription_id(subscription_id: SubscriptionId) -> (SubscriptionId, bool) {
+    let Some((channel, market)) = subscription_id.as_ref().split_once('|') else {
+        return (subscription_id, false);
+    };
+
+    let partial = channel
+        .strip_prefix("@depth")
+        .is_some_and(|variant| variant.starts_with(|c: char| c.is_ascii_digit()));
+
+    (
+        ExchangeSub::from((BinanceChannel::ORDER_BOOK_L2, market)).id(),
+        partial,
+    )
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -166,4 +187,40 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn test_normalise_ob_l2_subscription_id() {
+        struct TestCase {
+            input: &'static str,
+            expected: (SubscriptionId, bool),
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: 100ms diff stream is unchanged
+                input: "@depth@100ms|BTCUSDT",
+                expected: (SubscriptionId::from("@depth@100ms|BTCUSDT"), false),
+            },
+            TestCase {
+                // TC1: standard diff stream is normalised
+                input: "@depth|BTCUSDT",
+                expected: (SubscriptionId::from("@depth@100ms|BTCUSDT"), false),
+            },
+            TestCase {
+                // TC2: partial stream is normalised & identified as partial
+                input: "@depth10@100ms|BTCUSDT",
+                expected: (SubscriptionId::from("@depth@100ms|BTCUSDT"), true),
+            },
+            TestCase {
+                // TC3: standard partial stream is normalised & identified as partial
+                input: "@depth5|BTCUSDT",
+                expected: (SubscriptionId::from("@depth@100ms|BTCUSDT"), true),
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = normalise_ob_l2_subscription_id(SubscriptionId::from(test.input));
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
 }
@@ -1,13 +1,17 @@
-use super::{futures::BinanceFuturesUsd, Binance};
+use super::{futures::BinanceFuturesUsd, spot::BinanceSpot, Binance};
 use crate::{
     subscription::{
-        book::{OrderBooksL1, OrderBooksL2},
+        book::{OrderBookDeltas, OrderBooksL1, OrderBooksL2, OrderBooksL2Events},
         liquidation::Liquidations,
-        trade::PublicTrades,
-        Subscription,
+        open_interest::OpenInterests,
+        premium::PremiumIndexes,
+        stats::MarketStats,
+        trade::{AggTrades, PublicTrades},
+        DataKinds, SubKind, Subscription,
     },
     Identifier,
 };
+use barter_integration::error::SocketError;
 use serde::Serialize;
 
 /// Type that defines how to translate a Barter [`Subscription`] into a [`Binance`]
@@ -30,6 +34,12 @@ impl BinanceChannel {
     /// See discord: <https://discord.com/channels/910237311332151317/923160222711812126/975712874582388757>
     pub const TRADES: Self = Self("@trade");
 
+    /// [`Binance`] real-time aggregated trades channel name.
+    ///
+    /// See docs: <https://binance-docs.github.io/apidocs/spot/en/#aggregate-trade-streams>
+    /// See docs: <https://binance-docs.github.io/apidocs/futures/en/#aggregate-trade-streams>
+    pub const AGG_TRADES: Self = Self("@aggTrade");
+
     /// [`Binance`] real-time OrderBook Level1 (top of book) channel name.
     ///
     /// See docs:<https://binance-docs.github.io/apidocs/spot/en/#individual-symbol-book-ticker-streams>
@@ -42,10 +52,51 @@ impl BinanceChannel {
     /// See docs: <https://binance-docs.github.io/apidocs/futures/en/#diff-book-depth-streams>
     pub const ORDER_BOOK_L2: Self = Self("@depth@100ms");
 
+    /// [`Binance`] OrderBook Level2 channel name (standard speed delta updates: 1000ms for
+    /// [`BinanceSpot`], 250ms for [`BinanceFuturesUsd`]).
+    ///
+    /// See docs: <https://binance-docs.github.io/apidocs/spot/en/#diff-depth-stream>
+    /// See docs: <https://binance-docs.github.io/apidocs/futures/en/#diff-book-depth-streams>
+    pub const ORDER_BOOK_L2_STANDARD: Self = Self("@depth");
+
+    /// [`BinanceFuturesUsd`] partial OrderBook Level2 channel names (100ms top 5, 10 & 20 level
+    /// snapshots).
+    ///
+    /// See docs: <https://binance-docs.github.io/apidocs/futures/en/#partial-book-depth-streams>
+    pub const ORDER_BOOK_L2_PARTIAL_5: Self = Self("@depth5@100ms");
+    pub const ORDER_BOOK_L2_PARTIAL_10: Self = Self("@depth10@100ms");
+    pub const ORDER_BOOK_L2_PARTIAL_20: Self = Self("@depth20@100ms");
+
+    /// [`BinanceFuturesUsd`] partial OrderBook Level2 channel names (250ms top 5, 10 & 20 level
+    /// snapshots).
+    ///
+    /// See docs: <https://binance-docs.github.io/apidocs/futures/en/#partial-book-depth-streams>
+    pub const ORDER_BOOK_L2_PARTIAL_5_STANDARD: Self = Self("@depth5");
+    pub const ORDER_BOOK_L2_PARTIAL_10_STANDARD: Self = Self("@depth10");
+    pub const ORDER_BOOK_L2_PARTIAL_20_STANDARD: Self = Self("@depth20");
+
     /// [`BinanceFuturesUsd`] liquidation orders channel name.
     ///
     /// See docs: <https://binance-docs.github.io/apidocs/futures/en/#liquidation-order-streams>
     pub const LIQUIDATIONS: Self = Self("@forceOrder");
+
+    /// [`BinanceFuturesUsd`] mark price & premium index channel name (1s updates).
+    ///
+    /// See docs: <https://binance-docs.github.io/apidocs/futures/en/#mark-price-stream>
+    pub const PREMIUM_INDEX: Self = Self("@markPrice@1s");
+
+    /// [`BinanceFuturesUsd`] open interest REST endpoint, polled since there is no equivalent
+    /// WebSocket channel.
+    ///
+    /// See docs: <https://binance-docs.github.io/apidocs/futures/en/#open-interest>
+    pub const OPEN_INTEREST: Self = Self("openInterest");
+
+    /// [`BinanceFuturesUsd`] top trader long/short ratio & taker buy/sell volume REST endpoints,
+    /// polled since there are no equivalent WebSocket channels.
+    ///
+    /// See docs: <https://binance-docs.github.io/apidocs/futures/en/#top-trader-long-short-ratio-positions>
+    /// See docs: <https://binance-docs.github.io/apidocs/futures/en/#taker-buy-sell-volume>
+    pub const MARKET_STATS: Self = Self("marketStats");
 }
 
 impl<Server, Instrument> Identifier<BinanceChannel>
@@ -56,6 +107,14 @@ impl<Server, Instrument> Identifier<BinanceChannel>
     }
 }
 
+impl<Server, Instrument> Identifier<BinanceChannel>
+    for Subscription<Binance<Server>, Instrument, AggTrades>
+{
+    fn id(&self) -> BinanceChannel {
+        BinanceChannel::AGG_TRADES
+    }
+}
+
 impl<Server, Instrument> Identifier<BinanceChannel>
     for Subscription<Binance<Server>, Instrument, OrderBooksL1>
 {
@@ -64,8 +123,52 @@ impl<Server, Instrument> Identifier<BinanceChannel>
     }
 }
 
+impl<Instrument> Identifier<BinanceChannel>
+    for Subscription<BinanceSpot, Instrument, OrderBooksL2>
+{
+    fn id(&self) -> BinanceChannel {
+        // BinanceSpot partial depth messages omit the market, so they cannot be associated with
+        // a Subscription & the requested depth is ignored
+        match self.kind.interval_ms {
+            Some(1000..) => BinanceChannel::ORDER_BOOK_L2_STANDARD,
+            _ => BinanceChannel::ORDER_BOOK_L2,
+        }
+    }
+}
+
+impl<Instrument> Identifier<BinanceChannel>
+    for Subscription<BinanceFuturesUsd, Instrument, OrderBooksL2>
+{
+    fn id(&self) -> BinanceChannel {
+        // Map the requested depth & interval to the closest supported partial or diff stream
+        let standard = self
+            .kind
+            .interval_ms
+            .is_some_and(|interval| interval >= 250);
+
+        match (self.kind.depth, standard) {
+            (Some(0..=5), false) => BinanceChannel::ORDER_BOOK_L2_PARTIAL_5,
+            (Some(0..=5), true) => BinanceChannel::ORDER_BOOK_L2_PARTIAL_5_STANDARD,
+            (Some(6..=10), false) => BinanceChannel::ORDER_BOOK_L2_PARTIAL_10,
+            (Some(6..=10), true) => BinanceChannel::ORDER_BOOK_L2_PARTIAL_10_STANDARD,
+            (Some(11..=20), false) => BinanceChannel::ORDER_BOOK_L2_PARTIAL_20,
+            (Some(11..=20), true) => BinanceChannel::ORDER_BOOK_L2_PARTIAL_20_STANDARD,
+            (_, false) => BinanceChannel::ORDER_BOOK_L2,
+            (_, true) => BinanceChannel::ORDER_BOOK_L2_STANDARD,
+        }
+    }
+}
+
 impl<Server, Instrument> Identifier<BinanceChannel>
-    for Subscription<Binance<Server>, Instrument, OrderBooksL2>
+    for Subscription<Binance<Server>, Instrument, OrderBooksL2Events>
+{
+    fn id(&self) -> BinanceChannel {
+        BinanceChannel::ORDER_BOOK_L2
+    }
+}
+
+impl<Server, Instrument> Identifier<BinanceChannel>
+    for Subscription<Binance<Server>, Instrument, OrderBookDeltas>
 {
     fn id(&self) -> BinanceChannel {
         BinanceChannel::ORDER_BOOK_L2
@@ -80,8 +183,194 @@ impl<Instrument> Identifier<BinanceChannel>
     }
 }
 
+impl<Instrument> Identifier<BinanceChannel>
+    for Subscription<BinanceFuturesUsd, Instrument, PremiumIndexes>
+{
+    fn id(&self) -> BinanceChannel {
+        BinanceChannel::PREMIUM_INDEX
+    }
+}
+
+impl<Instrument> Identifier<BinanceChannel>
+    for Subscription<BinanceFuturesUsd, Instrument, OpenInterests>
+{
+    fn id(&self) -> BinanceChannel {
+        BinanceChannel::OPEN_INTEREST
+    }
+}
+
+impl<Instrument> Identifier<BinanceChannel>
+    for Subscription<BinanceFuturesUsd, Instrument, MarketStats>
+{
+    fn id(&self) -> BinanceChannel {
+        BinanceChannel::MARKET_STATS
+    }
+}
+
+impl<Server, Instrument> Identifier<BinanceChannel>
+    for Subscription<Binance<Server>, Instrument, DataKinds>
+{
+    /// Note that unsupported [`SubKind`]s map to [`BinanceChannel::UNSUPPORTED`], since the
+    /// [`Subscriber`](crate::subscriber::Subscriber) rejects them with a [`SocketError`] before
+    /// any subscription request is sent.
+    fn id(&self) -> BinanceChannel {
+        BinanceChannel::try_from_sub_kind(self.kind.0).unwrap_or(BinanceChannel::UNSUPPORTED)
+    }
+}
+
+impl BinanceChannel {
+    /// Placeholder [`BinanceChannel`] of a [`DataKinds`] [`SubKind`] that [`Binance`] does not
+    /// support, which is never subscribed to.
+    pub const UNSUPPORTED: Self = Self("@unsupported");
+
+    /// Determine the [`BinanceChannel`] of the provided [`DataKinds`] [`SubKind`].
+    pub fn try_from_sub_kind(sub_kind: SubKind) -> Result<Self, SocketError> {
+        match sub_kind {
+            SubKind::PublicTrades => Ok(Self::TRADES),
+            SubKind::AggTrades => Ok(Self::AGG_TRADES),
+            SubKind::OrderBooksL1 => Ok(Self::ORDER_BOOK_L1),
+            SubKind::OrderBooksL2 => Ok(Self::ORDER_BOOK_L2),
+            SubKind::Liquidations => Ok(Self::LIQUIDATIONS),
+            other => Err(SocketError::Unsupported {
+                entity: "binance",
+                item: format!("DataKinds({other})"),
+            }),
+        }
+    }
+}
+
 impl AsRef<str> for BinanceChannel {
     fn as_ref(&self) -> &str {
         self.0
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{exchange::ExchangeId, subscription::SubscriptionKind};
+    use barter_integration::model::instrument::{kind::InstrumentKind, Instrument};
+
+    #[test]
+    fn test_order_books_l2_channel() {
+        struct TestCase {
+            spot: bool,
+            input: OrderBooksL2,
+            expected: BinanceChannel,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: BinanceSpot default uses the 100ms diff stream
+                spot: true,
+                input: OrderBooksL2::default(),
+                expected: BinanceChannel::ORDER_BOOK_L2,
+            },
+            TestCase {
+                // TC1: BinanceSpot 1000ms interval uses the standard diff stream
+                spot: true,
+                input: OrderBooksL2::default().interval_ms(1000),
+                expected: BinanceChannel::ORDER_BOOK_L2_STANDARD,
+            },
+            TestCase {
+                // TC2: BinanceSpot ignores depth
+                spot: true,
+                input: OrderBooksL2::with_depth(5),
+                expected: BinanceChannel::ORDER_BOOK_L2,
+            },
+            TestCase {
+                // TC3: BinanceFuturesUsd default uses the 100ms diff stream
+                spot: false,
+                input: OrderBooksL2::default(),
+                expected: BinanceChannel::ORDER_BOOK_L2,
+            },
+            TestCase {
+                // TC4: BinanceFuturesUsd 500ms interval uses the standard diff stream
+                spot: false,
+                input: OrderBooksL2::default().interval_ms(500),
+                expected: BinanceChannel::ORDER_BOOK_L2_STANDARD,
+            },
+            TestCase {
+                // TC5: BinanceFuturesUsd depth 8 is rounded up to the 100ms top 10 partial stream
+                spot: false,
+                input: OrderBooksL2::with_depth(8),
+                expected: BinanceChannel::ORDER_BOOK_L2_PARTIAL_10,
+            },
+            TestCase {
+                // TC6: BinanceFuturesUsd depth 20 & 250ms interval uses the standard top 20
+                // partial stream
+                spot: false,
+                input: OrderBooksL2::with_depth(20).interval_ms(250),
+                expected: BinanceChannel::ORDER_BOOK_L2_PARTIAL_20_STANDARD,
+            },
+            TestCase {
+                // TC7: BinanceFuturesUsd depth beyond 20 uses the 100ms diff stream
+                spot: false,
+                input: OrderBooksL2::with_depth(1000),
+                expected: BinanceChannel::ORDER_BOOK_L2,
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = if test.spot {
+                Identifier::<BinanceChannel>::id(&Subscription::<
+                    BinanceSpot,
+                    Instrument,
+                    OrderBooksL2,
+                >::from((
+                    BinanceSpot::default(),
+                    "btc",
+                    "usdt",
+                    InstrumentKind::Spot,
+                    test.input,
+                )))
+            } else {
+                Identifier::<BinanceChannel>::id(&Subscription::<
+                    BinanceFuturesUsd,
+                    Instrument,
+                    OrderBooksL2,
+                >::from((
+                    BinanceFuturesUsd::default(),
+                    "btc",
+                    "usdt",
+                    InstrumentKind::Perpetual,
+                    test.input,
+                )))
+            };
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
+
+    #[test]
+    fn test_data_kinds_channel() {
+        // Every DataKinds SubKind supported by Binance must map to a BinanceChannel
+        for (exchange, instrument_kind) in [
+            (ExchangeId::BinanceSpot, InstrumentKind::Spot),
+            (ExchangeId::BinanceFuturesUsd, InstrumentKind::Perpetual),
+        ] {
+            for sub_kind in DataKinds::SUB_KINDS {
+                if DataKinds(sub_kind).supported_by(exchange, instrument_kind) {
+                    assert!(
+                        BinanceChannel::try_from_sub_kind(sub_kind).is_ok(),
+                        "{exchange} supports DataKinds({sub_kind}) without a BinanceChannel"
+                    );
+                }
+            }
+        }
+
+        // Binance Candles are not supported, so yield an error rather than a channel
+        assert!(!DataKinds(SubKind::Candles)
+            .supported_by(ExchangeId::BinanceSpot, InstrumentKind::Spot));
+        assert!(BinanceChannel::try_from_sub_kind(SubKind::Candles).is_err());
+
+        let subscription = Subscription::<_, Instrument, _>::new(
+            BinanceSpot::default(),
+            Instrument::from(("btc", "usdt", InstrumentKind::Spot)),
+            DataKinds(SubKind::Candles),
+        );
+        assert_eq!(
+            Identifier::<BinanceChannel>::id(&subscription),
+            BinanceChannel::UNSUPPORTED
+        );
+    }
+}
@@ -1,14 +1,18 @@
-use super::super::book::{l2::BinanceOrderBookL2Snapshot, BinanceLevel};
+use super::super::book::{
+    l2::{normalise_ob_l2_subscription_id, BinanceOrderBookL2Snapshot},
+    BinanceLevel,
+};
 use crate::{
     error::DataError,
-    subscription::book::OrderBook,
-    transformer::book::{InstrumentOrderBook, OrderBookUpdater},
+    event::{MarketEvent, MarketIter},
+    exchange::ExchangeId,
+    subscription::book::{pool::LevelPool, Level, OrderBook, OrderBookDelta, OrderBookSide},
+    transformer::book::{snapshot::SnapshotFetcher, InstrumentOrderBook, OrderBookUpdater},
     Identifier,
 };
 use async_trait::async_trait;
 use barter_integration::{
-    error::SocketError,
-    model::{instrument::Instrument, SubscriptionId},
+    model::{instrument::Instrument, Exchange, Side, SubscriptionId},
     protocol::websocket::WsMessage,
 };
 use chrono::Utc;
@@ -66,6 +70,33 @@ impl Identifier<Option<SubscriptionId>> for BinanceFuturesOrderBookL2Delta {
     }
 }
 
+impl<InstrumentId> From<(ExchangeId, InstrumentId, BinanceFuturesOrderBookL2Delta)>
+    for MarketIter<InstrumentId, OrderBookDelta>
+{
+    fn from(
+        (exchange_id, instrument, delta): (
+            ExchangeId,
+            InstrumentId,
+            BinanceFuturesOrderBookL2Delta,
+        ),
+    ) -> Self {
+        let time = Utc::now();
+        Self(vec![Ok(MarketEvent {
+            exchange_time: time,
+            received_time: time,
+            exchange: Exchange::from(exchange_id),
+            instrument,
+            kind: OrderBookDelta {
+                first_update_id: delta.first_update_id,
+                last_update_id: delta.last_update_id,
+                prev_last_update_id: Some(delta.prev_last_update_id),
+                bids: delta.bids.into_iter().map(Level::from).collect(),
+                asks: delta.asks.into_iter().map(Level::from).collect(),
+            },
+        })])
+    }
+}
+
 /// [`Binance`](super::super::Binance) [`BinanceServerFuturesUsd`](super::BinanceServerFuturesUsd)
 /// [`OrderBookUpdater`].
 ///
@@ -91,10 +122,19 @@ impl Identifier<Option<SubscriptionId>> for BinanceFuturesOrderBookL2Delta {
 ///  - Lowercase pu => prev_last_update_id
 ///
 /// See docs: <https://binance-docs.github.io/apidocs/futures/en/#how-to-manage-a-local-order-book-correctly>
+///
+/// BinanceFuturesUsd: Partial Depth Streams (eg/ `@depth10@100ms`)
+///
+/// Each event is a snapshot of the top N levels, so it replaces the local OrderBook rather than
+/// being applied as a delta. Any event where u is <= the last processed u is dropped, but since
+/// events are sampled, sequence gaps are expected and not validated.
+///
+/// See docs: <https://binance-docs.github.io/apidocs/futures/en/#partial-book-depth-streams>
 #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
 pub struct BinanceFuturesBookUpdater {
     pub updates_processed: u64,
     pub last_update_id: u64,
+    pub partial: bool,
 }
 
 impl BinanceFuturesBookUpdater {
@@ -104,6 +144,7 @@ impl BinanceFuturesBookUpdater {
         Self {
             updates_processed: 0,
             last_update_id,
+            partial: false,
         }
     }
 
@@ -155,10 +196,53 @@ impl BinanceFuturesBookUpdater {
     }
 }
 
+impl BinanceFuturesBookUpdater {
+    /// BinanceFuturesUsd: Partial Depth Streams:
+    /// Replace the OrderBook with the top N levels snapshot, dropping any stale event.
+    ///
+    /// See docs: <https://binance-docs.github.io/apidocs/futures/en/#partial-book-depth-streams>
+    fn replace(
+        &mut self,
+        book: &mut OrderBook,
+        update: BinanceFuturesOrderBookL2Delta,
+        pool: &mut LevelPool,
+    ) -> Option<OrderBook> {
+        if update.last_update_id <= self.last_update_id {
+            return None;
+        }
+
+        *book = OrderBook {
+            last_update_time: Utc::now(),
+            bids: OrderBookSide::new(Side::Buy, update.bids),
+            asks: OrderBookSide::new(Side::Sell, update.asks),
+        };
+
+        self.updates_processed += 1;
+        self.last_update_id = update.last_update_id;
+
+        Some(book.snapshot_in(pool))
+    }
+}
+
+impl SnapshotFetcher for BinanceFuturesBookUpdater {
+    type Snapshot = BinanceOrderBookL2Snapshot;
+
+    fn url(instrument: &Instrument) -> String {
+        format!(
+            "{}?symbol={}{}&limit=100",
+            HTTP_BOOK_L2_SNAPSHOT_URL_BINANCE_SPOT,
+            instrument.base.as_ref().to_uppercase(),
+            instrument.quote.as_ref().to_uppercase()
+        )
+    }
+}
+
 #[async_trait]
 impl OrderBookUpdater for BinanceFuturesBookUpdater {
     type OrderBook = OrderBook;
     type Update = BinanceFuturesOrderBookL2Delta;
+    const RESYNC_ON_GAP: bool = true;
+    const AUDIT: bool = true;
 
     async fn init<Exchange, Kind>(
         _: mpsc::UnboundedSender<WsMessage>,
@@ -168,21 +252,8 @@ impl OrderBookUpdater for BinanceFuturesBookUpdater {
         Exchange: Send,
         Kind: Send,
     {
-        // Construct initial OrderBook snapshot GET url
-        let snapshot_url = format!(
-            "{}?symbol={}{}&limit=100",
-            HTTP_BOOK_L2_SNAPSHOT_URL_BINANCE_SPOT,
-            instrument.base.as_ref().to_uppercase(),
-            instrument.quote.as_ref().to_uppercase()
-        );
-
         // Fetch initial OrderBook snapshot via HTTP
-        let snapshot = reqwest::get(snapshot_url)
-            .await
-            .map_err(SocketError::Http)?
-            .json::<BinanceOrderBookL2Snapshot>()
-            .await
-            .map_err(SocketError::Http)?;
+        let snapshot = Self::fetch(&instrument).await?;
 
         Ok(InstrumentOrderBook {
             instrument,
@@ -191,11 +262,22 @@ impl OrderBookUpdater for BinanceFuturesBookUpdater {
         })
     }
 
+    fn configure(&mut self, subscription_id: SubscriptionId) -> SubscriptionId {
+        let (subscription_id, partial) = normalise_ob_l2_subscription_id(subscription_id);
+        self.partial = partial;
+        subscription_id
+    }
+
     fn update(
         &mut self,
         book: &mut Self::OrderBook,
         update: Self::Update,
+        pool: &mut LevelPool,
     ) -> Result<Option<Self::OrderBook>, DataError> {
+        if self.partial {
+            return Ok(self.replace(book, update, pool));
+        }
+
         // BinanceFuturesUsd: How To Manage A Local OrderBook Correctly
         // See Self's Rust Docs for more information on each numbered step
         // See docs: <https://binance-docs.github.io/apidocs/futures/en/#how-to-manage-a-local-order-book-correctly>
@@ -224,7 +306,7 @@ impl OrderBookUpdater for BinanceFuturesBookUpdater {
         self.updates_processed += 1;
         self.last_update_id = update.last_update_id;
 
-        Ok(Some(book.snapshot()))
+        Ok(Some(book.snapshot_in(pool)))
     }
 }
 
@@ -281,6 +363,49 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_order_book_delta_from_binance_futures_order_book_l2_delta() {
+        let delta = BinanceFuturesOrderBookL2Delta {
+            subscription_id: SubscriptionId::from("@depth@100ms|BTCUSDT"),
+            first_update_id: 157,
+            last_update_id: 160,
+            prev_last_update_id: 149,
+            bids: vec![BinanceLevel {
+                price: 0.0024,
+                amount: 10.0,
+            }],
+            asks: vec![BinanceLevel {
+                price: 0.0026,
+                amount: 0.0,
+            }],
+        };
+
+        let actual = MarketIter::<&str, OrderBookDelta>::from((
+            ExchangeId::BinanceFuturesUsd,
+            "btc_usdt",
+            delta,
+        ))
+        .0
+        .pop()
+        .unwrap()
+        .unwrap();
+
+        assert_eq!(
+            actual.exchange,
+            Exchange::from(ExchangeId::BinanceFuturesUsd)
+        );
+        assert_eq!(
+            actual.kind,
+            OrderBookDelta {
+                first_update_id: 157,
+                last_update_id: 160,
+                prev_last_update_id: Some(149),
+                bids: vec![Level::new(0.0024, 10.0)],
+                asks: vec![Level::new(0.0026, 0.0)],
+            }
+        );
+    }
+
     mod binance_futures_book_updater {
         use super::*;
         use crate::subscription::book::{Level, OrderBookSide};
@@ -304,6 +429,7 @@ mod tests {
                     updater: BinanceFuturesBookUpdater {
                         updates_processed: 10,
                         last_update_id: 100,
+                        partial: false,
                     },
                     expected: false,
                 },
@@ -333,6 +459,7 @@ mod tests {
                     updater: BinanceFuturesBookUpdater {
                         updates_processed: 0,
                         last_update_id: 100,
+                        partial: false,
                     },
                     input: BinanceFuturesOrderBookL2Delta {
                         subscription_id: SubscriptionId::from("subscription_id"),
@@ -349,6 +476,7 @@ mod tests {
                     updater: BinanceFuturesBookUpdater {
                         updates_processed: 0,
                         last_update_id: 100,
+                        partial: false,
                     },
                     input: BinanceFuturesOrderBookL2Delta {
                         subscription_id: SubscriptionId::from("subscription_id"),
@@ -368,6 +496,7 @@ mod tests {
                     updater: BinanceFuturesBookUpdater {
                         updates_processed: 0,
                         last_update_id: 100,
+                        partial: false,
                     },
                     input: BinanceFuturesOrderBookL2Delta {
                         subscription_id: SubscriptionId::from("subscription_id"),
@@ -387,6 +516,7 @@ mod tests {
                     updater: BinanceFuturesBookUpdater {
                         updates_processed: 0,
                         last_update_id: 100,
+                        partial: false,
                     },
                     input: BinanceFuturesOrderBookL2Delta {
                         subscription_id: SubscriptionId::from("subscription_id"),
@@ -406,10 +536,7 @@ mod tests {
             for (index, test) in tests.into_iter().enumerate() {
                 let actual = test.updater.validate_first_update(&test.input);
                 match (actual, test.expected) {
-                    (Ok(actual), Ok(expected)) => {
-                        assert_eq!(actual, expected, "TC{} failed", index)
-                    }
-                    (Err(_), Err(_)) => {
+                    (Ok(()), Ok(())) | (Err(_), Err(_)) => {
                         // Test passed
                     }
                     (actual, expected) => {
@@ -434,6 +561,7 @@ mod tests {
                     updater: BinanceFuturesBookUpdater {
                         updates_processed: 100,
                         last_update_id: 100,
+                        partial: false,
                     },
                     input: BinanceFuturesOrderBookL2Delta {
                         subscription_id: SubscriptionId::from("subscription_id"),
@@ -450,6 +578,7 @@ mod tests {
                     updater: BinanceFuturesBookUpdater {
                         updates_processed: 100,
                         last_update_id: 100,
+                        partial: false,
                     },
                     input: BinanceFuturesOrderBookL2Delta {
                         subscription_id: SubscriptionId::from("subscription_id"),
@@ -469,10 +598,7 @@ mod tests {
             for (index, test) in tests.into_iter().enumerate() {
                 let actual = test.updater.validate_next_update(&test.input);
                 match (actual, test.expected) {
-                    (Ok(actual), Ok(expected)) => {
-                        assert_eq!(actual, expected, "TC{} failed", index)
-                    }
-                    (Err(_), Err(_)) => {
+                    (Ok(()), Ok(())) | (Err(_), Err(_)) => {
                         // Test passed
                     }
                     (actual, expected) => {
@@ -500,6 +626,7 @@ mod tests {
                     updater: BinanceFuturesBookUpdater {
                         updates_processed: 100,
                         last_update_id: 100,
+                        partial: false,
                     },
                     book: OrderBook {
                         last_update_time: time,
@@ -521,6 +648,7 @@ mod tests {
                     updater: BinanceFuturesBookUpdater {
                         updates_processed: 100,
                         last_update_id: 100,
+                        partial: false,
                     },
                     book: OrderBook {
                         last_update_time: time,
@@ -580,10 +708,71 @@ mod tests {
                         ),
                     })),
                 },
+                TestCase {
+                    // TC2: partial depth stream drops stale event
+                    updater: BinanceFuturesBookUpdater {
+                        updates_processed: 100,
+                        last_update_id: 100,
+                        partial: true,
+                    },
+                    book: OrderBook {
+                        last_update_time: time,
+                        bids: OrderBookSide::new(Side::Buy, vec![Level::new(50, 1)]),
+                        asks: OrderBookSide::new(Side::Sell, vec![Level::new(100, 1)]),
+                    },
+                    input_update: BinanceFuturesOrderBookL2Delta {
+                        subscription_id: SubscriptionId::from("subscription_id"),
+                        first_update_id: 90,
+                        last_update_id: 100,
+                        prev_last_update_id: 89,
+                        bids: vec![],
+                        asks: vec![],
+                    },
+                    expected: Ok(None),
+                },
+                TestCase {
+                    // TC3: partial depth stream replaces OrderBook despite sequence gap
+                    updater: BinanceFuturesBookUpdater {
+                        updates_processed: 100,
+                        last_update_id: 100,
+                        partial: true,
+                    },
+                    book: OrderBook {
+                        last_update_time: time,
+                        bids: OrderBookSide::new(
+                            Side::Buy,
+                            vec![Level::new(50, 1), Level::new(40, 1)],
+                        ),
+                        asks: OrderBookSide::new(Side::Sell, vec![Level::new(100, 1)]),
+                    },
+                    input_update: BinanceFuturesOrderBookL2Delta {
+                        subscription_id: SubscriptionId::from("subscription_id"),
+                        first_update_id: 150,
+                        last_update_id: 160,
+                        prev_last_update_id: 140,
+                        bids: vec![BinanceLevel {
+                            price: 60.0,
+                            amount: 2.0,
+                        }],
+                        asks: vec![BinanceLevel {
+                            price: 90.0,
+                            amount: 3.0,
+                        }],
+                    },
+                    expected: Ok(Some(OrderBook {
+                        last_update_time: time,
+                        bids: OrderBookSide::new(Side::Buy, vec![Level::new(60, 2)]),
+                        asks: OrderBookSide::new(Side::Sell, vec![Level::new(90, 3)]),
+                    })),
+                },
             ];
 
             for (index, mut test) in tests.into_iter().enumerate() {
-                let actual = test.updater.update(&mut test.book, test.input_update);
+                let actual = test.updater.update(
+                    &mut test.book,
+                    test.input_update,
+                    &mut LevelPool::default(),
+                );
 
                 match (actual, test.expected) {
                     (Ok(Some(actual)), Ok(Some(expected))) => {
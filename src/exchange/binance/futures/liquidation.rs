@@ -70,7 +70,7 @@ pub struct BinanceLiquidationOrder {
     pub quantity: f64,
     #[serde(
         alias = "T",
-        deserialize_with = "barter_integration::de::de_u64_epoch_ms_as_datetime_utc"
+        deserialize_with = "crate::util::time::de_u64_epoch_ms_as_datetime_utc"
     )]
     pub time: DateTime<Utc>,
 }
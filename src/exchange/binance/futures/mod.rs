@@ -1,13 +1,31 @@
-use self::{l2::BinanceFuturesBookUpdater, liquidation::BinanceLiquidation};
-use super::{Binance, ExchangeServer};
+use self::{
+    l2::{BinanceFuturesBookUpdater, BinanceFuturesOrderBookL2Delta},
+    liquidation::BinanceLiquidation,
+    premium::BinancePremiumIndex,
+};
+use super::{kinds::BinanceDataKind, Binance, ExchangeServer};
 use crate::instrument::InstrumentData;
 use crate::{
+    error::DataError,
     exchange::{ExchangeId, StreamSelector},
-    subscription::{book::OrderBooksL2, liquidation::Liquidations},
-    transformer::{book::MultiBookTransformer, stateless::StatelessTransformer},
+    poll::PollStream,
+    subscriber::pacing::RequestRate,
+    subscription::{
+        book::{OrderBookDeltas, OrderBooksL2, OrderBooksL2Events},
+        liquidation::Liquidations,
+        open_interest::{OpenInterest, OpenInterests},
+        premium::PremiumIndexes,
+        stats::{MarketSentiment, MarketStats},
+        DataKinds,
+    },
+    transformer::{
+        book::MultiBookTransformer, kinds::DataKindsTransformer, stateless::StatelessTransformer,
+    },
     ExchangeWsStream,
 };
-use barter_integration::model::instrument::Instrument;
+use barter_integration::{error::SocketError, model::instrument::Instrument};
+use serde::de::DeserializeOwned;
+use std::time::Duration;
 
 /// Level 2 OrderBook types (top of book) and perpetual
 /// [`OrderBookUpdater`](crate::transformer::book::OrderBookUpdater) implementation.
@@ -16,11 +34,32 @@ pub mod l2;
 /// Liquidation types.
 pub mod liquidation;
 
+/// Open interest types and REST polling [`MarketStream`](crate::MarketStream) implementation.
+pub mod open_interest;
+
+/// Mark price & premium index types.
+pub mod premium;
+
+/// Long/short ratio & taker buy/sell volume types and REST polling
+/// [`MarketStream`](crate::MarketStream) implementation.
+pub mod stats;
+
 /// [`BinanceFuturesUsd`] WebSocket server base url.
 ///
 /// See docs: <https://binance-docs.github.io/apidocs/futures/en/#websocket-market-streams>
 pub const WEBSOCKET_BASE_URL_BINANCE_FUTURES_USD: &str = "wss://fstream.binance.com/ws";
 
+/// Maximum number of streams a single [`BinanceFuturesUsd`] WebSocket connection can listen to.
+///
+/// See docs: <https://binance-docs.github.io/apidocs/futures/en/#websocket-market-streams>
+pub const SUBSCRIPTION_LIMIT_BINANCE_FUTURES_USD: usize = 200;
+
+/// Maximum rate of incoming messages a single [`BinanceFuturesUsd`] WebSocket connection accepts.
+///
+/// See docs: <https://binance-docs.github.io/apidocs/futures/en/#websocket-market-streams>
+pub const REQUEST_RATE_BINANCE_FUTURES_USD: RequestRate =
+    RequestRate::new(10, Duration::from_secs(1));
+
 /// [`Binance`] perpetual usd exchange.
 pub type BinanceFuturesUsd = Binance<BinanceServerFuturesUsd>;
 
@@ -34,6 +73,19 @@ impl ExchangeServer for BinanceServerFuturesUsd {
     fn websocket_url() -> &'static str {
         WEBSOCKET_BASE_URL_BINANCE_FUTURES_USD
     }
+
+    fn subscription_limit() -> Option<usize> {
+        Some(SUBSCRIPTION_LIMIT_BINANCE_FUTURES_USD)
+    }
+
+    fn request_rate() -> Option<RequestRate> {
+        Some(REQUEST_RATE_BINANCE_FUTURES_USD)
+    }
+}
+
+impl StreamSelector<Instrument, DataKinds> for BinanceFuturesUsd {
+    type Stream =
+        ExchangeWsStream<DataKindsTransformer<Self, BinanceDataKind, BinanceFuturesBookUpdater>>;
 }
 
 impl StreamSelector<Instrument, OrderBooksL2> for BinanceFuturesUsd {
@@ -42,6 +94,21 @@ impl StreamSelector<Instrument, OrderBooksL2> for BinanceFuturesUsd {
     >;
 }
 
+impl StreamSelector<Instrument, OrderBooksL2Events> for BinanceFuturesUsd {
+    type Stream = ExchangeWsStream<
+        MultiBookTransformer<Self, Instrument, OrderBooksL2Events, BinanceFuturesBookUpdater>,
+    >;
+}
+
+impl<Instrument> StreamSelector<Instrument, OrderBookDeltas> for BinanceFuturesUsd
+where
+    Instrument: InstrumentData,
+{
+    type Stream = ExchangeWsStream<
+        StatelessTransformer<Self, Instrument::Id, OrderBookDeltas, BinanceFuturesOrderBookL2Delta>,
+    >;
+}
+
 impl<Instrument> StreamSelector<Instrument, Liquidations> for BinanceFuturesUsd
 where
     Instrument: InstrumentData,
@@ -50,3 +117,41 @@ where
         StatelessTransformer<Self, Instrument::Id, Liquidations, BinanceLiquidation>,
     >;
 }
+
+impl<Instrument> StreamSelector<Instrument, PremiumIndexes> for BinanceFuturesUsd
+where
+    Instrument: InstrumentData,
+{
+    type Stream = ExchangeWsStream<
+        StatelessTransformer<Self, Instrument::Id, PremiumIndexes, BinancePremiumIndex>,
+    >;
+}
+
+impl<Instrument> StreamSelector<Instrument, OpenInterests> for BinanceFuturesUsd
+where
+    Instrument: InstrumentData + 'static,
+{
+    type Stream = PollStream<Instrument::Id, OpenInterest>;
+}
+
+impl<Instrument> StreamSelector<Instrument, MarketStats> for BinanceFuturesUsd
+where
+    Instrument: InstrumentData + 'static,
+{
+    type Stream = PollStream<Instrument::Id, MarketSentiment>;
+}
+
+/// Send the provided HTTP request, deserialising the response body as `T`.
+async fn fetch<T>(request: reqwest::RequestBuilder) -> Result<T, DataError>
+where
+    T: DeserializeOwned,
+{
+    request
+        .send()
+        .await
+        .and_then(reqwest::Response::error_for_status)
+        .map_err(SocketError::Http)?
+        .json::<T>()
+        .await
+        .map_err(|error| DataError::from(SocketError::Http(error)))
+}
@@ -0,0 +1,141 @@
+use super::{fetch, BinanceFuturesUsd};
+use crate::{
+    error::DataError,
+    event::{MarketEvent, MarketIter},
+    exchange::{binance::market::BinanceMarket, Connector, ExchangeId},
+    instrument::InstrumentData,
+    poll::PollStream,
+    streams::options::StreamOptions,
+    subscription::{
+        open_interest::{OpenInterest, OpenInterests},
+        Subscription,
+    },
+    Identifier, MarketStream,
+};
+use async_trait::async_trait;
+use barter_integration::model::Exchange;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// [`BinanceFuturesUsd`] HTTP open interest url.
+///
+/// See docs: <https://binance-docs.github.io/apidocs/futures/en/#open-interest>
+pub const HTTP_OPEN_INTEREST_URL_BINANCE_FUTURES_USD: &str =
+    "https://fapi.binance.com/fapi/v1/openInterest";
+
+/// [`BinanceFuturesUsd`] open interest HTTP response.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://binance-docs.github.io/apidocs/futures/en/#open-interest>
+/// ```json
+/// {
+///     "openInterest": "10659.509",
+///     "symbol": "BTCUSDT",
+///     "time": 1589437530011
+/// }
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct BinanceOpenInterest {
+    pub symbol: String,
+    #[serde(
+        alias = "openInterest",
+        deserialize_with = "barter_integration::de::de_str"
+    )]
+    pub open_interest: f64,
+    #[serde(deserialize_with = "crate::util::time::de_u64_epoch_ms_as_datetime_utc")]
+    pub time: DateTime<Utc>,
+}
+
+impl<InstrumentId> From<(ExchangeId, InstrumentId, BinanceOpenInterest)>
+    for MarketIter<InstrumentId, OpenInterest>
+{
+    fn from(
+        (exchange_id, instrument, open_interest): (ExchangeId, InstrumentId, BinanceOpenInterest),
+    ) -> Self {
+        Self(vec![Ok(MarketEvent {
+            exchange_time: open_interest.time,
+            received_time: Utc::now(),
+            exchange: Exchange::from(exchange_id),
+            instrument,
+            kind: OpenInterest {
+                contracts: open_interest.open_interest,
+            },
+        })])
+    }
+}
+
+#[async_trait]
+impl<Instrument> MarketStream<BinanceFuturesUsd, Instrument, OpenInterests>
+    for PollStream<Instrument::Id, OpenInterest>
+where
+    Instrument: InstrumentData + 'static,
+{
+    async fn init(
+        subscriptions: &[Subscription<BinanceFuturesUsd, Instrument, OpenInterests>],
+        options: &StreamOptions,
+    ) -> Result<Self, DataError>
+    where
+        Subscription<BinanceFuturesUsd, Instrument, OpenInterests>: Identifier<<BinanceFuturesUsd as Connector>::Channel>
+            + Identifier<<BinanceFuturesUsd as Connector>::Market>,
+    {
+        let client = reqwest::Client::new();
+
+        // Construct a poller for each Subscription, fetching the open interest via HTTP
+        let pollers = subscriptions.iter().map(|subscription| {
+            let market: BinanceMarket = subscription.id();
+            let url = format!(
+                "{HTTP_OPEN_INTEREST_URL_BINANCE_FUTURES_USD}?symbol={}",
+                market.0
+            );
+            let instrument = subscription.instrument.id().clone();
+            let client = client.clone();
+
+            let poller = move || {
+                let open_interest = fetch::<BinanceOpenInterest>(client.get(&url));
+                let instrument = instrument.clone();
+                async move {
+                    Ok(MarketIter::from((
+                        BinanceFuturesUsd::ID,
+                        instrument,
+                        open_interest.await?,
+                    )))
+                }
+            };
+
+            (subscription.kind.poll_interval, poller)
+        });
+
+        PollStream::init(pollers.collect::<Vec<_>>(), &options.spawner).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod de {
+        use super::*;
+        use barter_integration::de::datetime_utc_from_epoch_duration;
+        use std::time::Duration;
+
+        #[test]
+        fn test_binance_open_interest() {
+            let input = r#"
+            {
+                "openInterest": "10659.509",
+                "symbol": "BTCUSDT",
+                "time": 1589437530011
+            }
+            "#;
+
+            assert_eq!(
+                serde_json::from_str::<BinanceOpenInterest>(input).unwrap(),
+                BinanceOpenInterest {
+                    symbol: "BTCUSDT".to_string(),
+                    open_interest: 10659.509,
+                    time: datetime_utc_from_epoch_duration(Duration::from_millis(1589437530011)),
+                }
+            );
+        }
+    }
+}
@@ -0,0 +1,132 @@
+use super::super::BinanceChannel;
+use crate::{
+    event::{MarketEvent, MarketIter},
+    exchange::ExchangeId,
+    subscription::premium::PremiumIndex,
+    Identifier,
+};
+use barter_integration::model::{Exchange, SubscriptionId};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// [`BinanceFuturesUsd`](super::BinanceFuturesUsd) mark price & premium index message.
+///
+/// Used in preference to the `@indexPriceKline` stream since it provides both the mark price and
+/// the index price, from which the premium (basis) is derived.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://binance-docs.github.io/apidocs/futures/en/#mark-price-stream>
+/// ```json
+/// {
+///     "e": "markPriceUpdate",
+///     "E": 1562305380000,
+///     "s": "BTCUSDT",
+///     "p": "11794.15000000",
+///     "i": "11784.62659091",
+///     "P": "11784.25641265",
+///     "r": "0.00038167",
+///     "T": 1562306400000
+/// }
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct BinancePremiumIndex {
+    #[serde(alias = "s", deserialize_with = "de_premium_index_subscription_id")]
+    pub subscription_id: SubscriptionId,
+    #[serde(
+        alias = "E",
+        deserialize_with = "crate::util::time::de_u64_epoch_ms_as_datetime_utc"
+    )]
+    pub time: DateTime<Utc>,
+    #[serde(alias = "p", deserialize_with = "barter_integration::de::de_str")]
+    pub mark_price: f64,
+    #[serde(alias = "i", deserialize_with = "barter_integration::de::de_str")]
+    pub index_price: f64,
+    #[serde(alias = "r", deserialize_with = "barter_integration::de::de_str")]
+    pub funding_rate: f64,
+    #[serde(
+        alias = "T",
+        deserialize_with = "crate::util::time::de_u64_epoch_ms_as_datetime_utc"
+    )]
+    pub next_funding_time: DateTime<Utc>,
+}
+
+impl Identifier<Option<SubscriptionId>> for BinancePremiumIndex {
+    fn id(&self) -> Option<SubscriptionId> {
+        Some(self.subscription_id.clone())
+    }
+}
+
+impl<InstrumentId> From<(ExchangeId, InstrumentId, BinancePremiumIndex)>
+    for MarketIter<InstrumentId, PremiumIndex>
+{
+    fn from(
+        (exchange_id, instrument, premium): (ExchangeId, InstrumentId, BinancePremiumIndex),
+    ) -> Self {
+        Self(vec![Ok(MarketEvent {
+            exchange_time: premium.time,
+            received_time: Utc::now(),
+            exchange: Exchange::from(exchange_id),
+            instrument,
+            kind: PremiumIndex {
+                mark_price: premium.mark_price,
+                index_price: premium.index_price,
+                funding_rate: premium.funding_rate,
+                next_funding_time: premium.next_funding_time,
+            },
+        })])
+    }
+}
+
+/// Deserialize a [`BinancePremiumIndex`] "s" (eg/ "BTCUSDT") as the associated
+/// [`SubscriptionId`].
+///
+/// eg/ "@markPrice@1s|BTCUSDT"
+pub fn de_premium_index_subscription_id<'de, D>(deserializer: D) -> Result<SubscriptionId, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+{
+    Deserialize::deserialize(deserializer).map(|market: String| {
+        SubscriptionId::from(format!("{}|{}", BinanceChannel::PREMIUM_INDEX.0, market))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod de {
+        use super::*;
+        use barter_integration::de::datetime_utc_from_epoch_duration;
+        use std::time::Duration;
+
+        #[test]
+        fn test_binance_premium_index() {
+            let input = r#"
+            {
+                "e": "markPriceUpdate",
+                "E": 1562305380000,
+                "s": "BTCUSDT",
+                "p": "11794.15000000",
+                "i": "11784.62659091",
+                "P": "11784.25641265",
+                "r": "0.00038167",
+                "T": 1562306400000
+            }
+            "#;
+
+            assert_eq!(
+                serde_json::from_str::<BinancePremiumIndex>(input).unwrap(),
+                BinancePremiumIndex {
+                    subscription_id: SubscriptionId::from("@markPrice@1s|BTCUSDT"),
+                    time: datetime_utc_from_epoch_duration(Duration::from_millis(1562305380000)),
+                    mark_price: 11794.15,
+                    index_price: 11784.62659091,
+                    funding_rate: 0.00038167,
+                    next_funding_time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                        1562306400000
+                    )),
+                }
+            );
+        }
+    }
+}
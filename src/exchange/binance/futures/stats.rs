@@ -0,0 +1,261 @@
+use super::{fetch, BinanceFuturesUsd};
+use crate::{
+    error::DataError,
+    event::{MarketEvent, MarketIter},
+    exchange::{binance::market::BinanceMarket, Connector, ExchangeId},
+    instrument::InstrumentData,
+    poll::PollStream,
+    streams::options::StreamOptions,
+    subscription::{
+        stats::{MarketSentiment, MarketStats},
+        Subscription,
+    },
+    Identifier, MarketStream,
+};
+use async_trait::async_trait;
+use barter_integration::model::Exchange;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// [`BinanceFuturesUsd`] HTTP top trader long/short ratio (positions) url.
+///
+/// See docs: <https://binance-docs.github.io/apidocs/futures/en/#top-trader-long-short-ratio-positions>
+pub const HTTP_TOP_LONG_SHORT_RATIO_URL_BINANCE_FUTURES_USD: &str =
+    "https://fapi.binance.com/futures/data/topLongShortPositionRatio";
+
+/// [`BinanceFuturesUsd`] HTTP taker buy/sell volume url.
+///
+/// See docs: <https://binance-docs.github.io/apidocs/futures/en/#taker-buy-sell-volume>
+pub const HTTP_TAKER_LONG_SHORT_RATIO_URL_BINANCE_FUTURES_USD: &str =
+    "https://fapi.binance.com/futures/data/takerlongshortRatio";
+
+/// Period over which [`BinanceFuturesUsd`] market statistics are aggregated.
+///
+/// The most granular period supported is used, since each poll only fetches the latest value.
+pub const BINANCE_MARKET_STATS_PERIOD: &str = "5m";
+
+/// [`BinanceFuturesUsd`] top trader long/short ratio (positions) HTTP response element.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://binance-docs.github.io/apidocs/futures/en/#top-trader-long-short-ratio-positions>
+/// ```json
+/// [
+///     {
+///         "symbol": "BTCUSDT",
+///         "longShortRatio": "1.4342",
+///         "longAccount": "0.5891",
+///         "shortAccount": "0.4108",
+///         "timestamp": "1583139600000"
+///     }
+/// ]
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct BinanceTopLongShortRatio {
+    pub symbol: String,
+    #[serde(
+        alias = "longShortRatio",
+        deserialize_with = "barter_integration::de::de_str"
+    )]
+    pub long_short_ratio: f64,
+    #[serde(
+        alias = "longAccount",
+        deserialize_with = "barter_integration::de::de_str"
+    )]
+    pub long_account: f64,
+    #[serde(
+        alias = "shortAccount",
+        deserialize_with = "barter_integration::de::de_str"
+    )]
+    pub short_account: f64,
+    #[serde(deserialize_with = "crate::util::time::de_str_or_u64_epoch_ms_as_datetime_utc")]
+    pub timestamp: DateTime<Utc>,
+}
+
+/// [`BinanceFuturesUsd`] taker buy/sell volume HTTP response element.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://binance-docs.github.io/apidocs/futures/en/#taker-buy-sell-volume>
+/// ```json
+/// [
+///     {
+///         "buySellRatio": "1.5586",
+///         "buyVol": "387.3300",
+///         "sellVol": "248.5030",
+///         "timestamp": "1585614900000"
+///     }
+/// ]
+/// ```
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct BinanceTakerLongShortRatio {
+    #[serde(
+        alias = "buySellRatio",
+        deserialize_with = "barter_integration::de::de_str"
+    )]
+    pub buy_sell_ratio: f64,
+    #[serde(alias = "buyVol", deserialize_with = "barter_integration::de::de_str")]
+    pub buy_volume: f64,
+    #[serde(alias = "sellVol", deserialize_with = "barter_integration::de::de_str")]
+    pub sell_volume: f64,
+    #[serde(deserialize_with = "crate::util::time::de_str_or_u64_epoch_ms_as_datetime_utc")]
+    pub timestamp: DateTime<Utc>,
+}
+
+/// Latest [`BinanceTopLongShortRatio`] and [`BinanceTakerLongShortRatio`] for a market, combined
+/// into a single [`MarketSentiment`].
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct BinanceMarketStats {
+    pub top: BinanceTopLongShortRatio,
+    pub taker: BinanceTakerLongShortRatio,
+}
+
+impl<InstrumentId> From<(ExchangeId, InstrumentId, BinanceMarketStats)>
+    for MarketIter<InstrumentId, MarketSentiment>
+{
+    fn from(
+        (exchange_id, instrument, stats): (ExchangeId, InstrumentId, BinanceMarketStats),
+    ) -> Self {
+        Self(vec![Ok(MarketEvent {
+            exchange_time: stats.top.timestamp.max(stats.taker.timestamp),
+            received_time: Utc::now(),
+            exchange: Exchange::from(exchange_id),
+            instrument,
+            kind: MarketSentiment {
+                long_short_ratio: stats.top.long_short_ratio,
+                long_share: stats.top.long_account,
+                short_share: stats.top.short_account,
+                taker_buy_sell_ratio: stats.taker.buy_sell_ratio,
+                taker_buy_volume: stats.taker.buy_volume,
+                taker_sell_volume: stats.taker.sell_volume,
+            },
+        })])
+    }
+}
+
+#[async_trait]
+impl<Instrument> MarketStream<BinanceFuturesUsd, Instrument, MarketStats>
+    for PollStream<Instrument::Id, MarketSentiment>
+where
+    Instrument: InstrumentData + 'static,
+{
+    async fn init(
+        subscriptions: &[Subscription<BinanceFuturesUsd, Instrument, MarketStats>],
+        options: &StreamOptions,
+    ) -> Result<Self, DataError>
+    where
+        Subscription<BinanceFuturesUsd, Instrument, MarketStats>: Identifier<<BinanceFuturesUsd as Connector>::Channel>
+            + Identifier<<BinanceFuturesUsd as Connector>::Market>,
+    {
+        let client = reqwest::Client::new();
+
+        // Construct a poller for each Subscription, fetching the latest statistics via HTTP
+        let pollers = subscriptions.iter().map(|subscription| {
+            let market: BinanceMarket = subscription.id();
+            let query = format!(
+                "?symbol={}&period={BINANCE_MARKET_STATS_PERIOD}&limit=1",
+                market.0
+            );
+            let top_url = format!("{HTTP_TOP_LONG_SHORT_RATIO_URL_BINANCE_FUTURES_USD}{query}");
+            let taker_url = format!("{HTTP_TAKER_LONG_SHORT_RATIO_URL_BINANCE_FUTURES_USD}{query}");
+            let instrument = subscription.instrument.id().clone();
+            let client = client.clone();
+
+            let poller = move || {
+                let top = fetch::<Vec<BinanceTopLongShortRatio>>(client.get(&top_url));
+                let taker = fetch::<Vec<BinanceTakerLongShortRatio>>(client.get(&taker_url));
+                let instrument = instrument.clone();
+                async move {
+                    let (top, taker) = futures::try_join!(top, taker)?;
+
+                    // Statistics may be unavailable for newly listed markets
+                    Ok(match (top.into_iter().last(), taker.into_iter().last()) {
+                        (Some(top), Some(taker)) => MarketIter::from((
+                            BinanceFuturesUsd::ID,
+                            instrument,
+                            BinanceMarketStats { top, taker },
+                        )),
+                        _ => MarketIter(vec![]),
+                    })
+                }
+            };
+
+            (subscription.kind.poll_interval, poller)
+        });
+
+        PollStream::init(pollers.collect::<Vec<_>>(), &options.spawner).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod de {
+        use super::*;
+        use crate::util::time::EpochUnit;
+
+        #[test]
+        fn test_binance_top_long_short_ratio() {
+            struct TestCase {
+                input: &'static str,
+                expected: Result<Vec<BinanceTopLongShortRatio>, ()>,
+            }
+
+            let expected = BinanceTopLongShortRatio {
+                symbol: "BTCUSDT".to_string(),
+                long_short_ratio: 1.4342,
+                long_account: 0.5891,
+                short_account: 0.4108,
+                timestamp: EpochUnit::Millis.datetime(1583139600000),
+            };
+
+            let tests = vec![
+                TestCase {
+                    // TC0: valid response w/ String timestamp
+                    input: r#"[{"symbol":"BTCUSDT","longShortRatio":"1.4342","longAccount":"0.5891","shortAccount":"0.4108","timestamp":"1583139600000"}]"#,
+                    expected: Ok(vec![expected.clone()]),
+                },
+                TestCase {
+                    // TC1: valid response w/ u64 timestamp
+                    input: r#"[{"symbol":"BTCUSDT","longShortRatio":"1.4342","longAccount":"0.5891","shortAccount":"0.4108","timestamp":1583139600000}]"#,
+                    expected: Ok(vec![expected]),
+                },
+                TestCase {
+                    // TC2: invalid response w/ non-numeric timestamp
+                    input: r#"[{"symbol":"BTCUSDT","longShortRatio":"1.4342","longAccount":"0.5891","shortAccount":"0.4108","timestamp":"now"}]"#,
+                    expected: Err(()),
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                let actual = serde_json::from_str::<Vec<BinanceTopLongShortRatio>>(test.input);
+                match (actual, test.expected) {
+                    (Ok(actual), Ok(expected)) => {
+                        assert_eq!(actual, expected, "TC{} failed", index)
+                    }
+                    (Err(_), Err(_)) => {
+                        // Test passed
+                    }
+                    (actual, expected) => {
+                        // Test failed
+                        panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                    }
+                }
+            }
+        }
+
+        #[test]
+        fn test_binance_taker_long_short_ratio() {
+            let input = r#"[{"buySellRatio":"1.5586","buyVol":"387.3300","sellVol":"248.5030","timestamp":"1585614900000"}]"#;
+
+            assert_eq!(
+                serde_json::from_str::<Vec<BinanceTakerLongShortRatio>>(input).unwrap(),
+                vec![BinanceTakerLongShortRatio {
+                    buy_sell_ratio: 1.5586,
+                    buy_volume: 387.33,
+                    sell_volume: 248.503,
+                    timestamp: EpochUnit::Millis.datetime(1585614900000),
+                }]
+            );
+        }
+    }
+}
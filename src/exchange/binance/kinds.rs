@@ -0,0 +1,145 @@
+use super::{
+    book::l1::BinanceOrderBookL1,
+    futures::liquidation::BinanceLiquidation,
+    trade::{BinanceAggTrade, BinanceTrade},
+};
+use crate::{
+    event::{DataKind, MarketIter},
+    exchange::ExchangeId,
+    subscription::{
+        book::OrderBookL1,
+        liquidation::Liquidation,
+        trade::{AggTrade, PublicTrade},
+    },
+    Identifier,
+};
+use barter_integration::model::SubscriptionId;
+use serde::{Deserialize, Serialize};
+
+/// [`Binance`](super::Binance) message variants that can be received over
+/// [`WebSocket`](barter_integration::protocol::websocket::WebSocket) relating to active
+/// [`DataKinds`](crate::subscription::DataKinds) subscriptions.
+///
+/// ### Notes
+/// Variants are distinguished by their fields, since spot "bookTicker" messages do not contain an
+/// event type. See each variant for full raw payload examples.
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+#[serde(untagged)]
+pub enum BinanceDataKind {
+    Trade(BinanceTrade),
+    AggTrade(BinanceAggTrade),
+    OrderBookL1(BinanceOrderBookL1),
+    Liquidation(BinanceLiquidation),
+}
+
+impl Identifier<Option<SubscriptionId>> for BinanceDataKind {
+    fn id(&self) -> Option<SubscriptionId> {
+        match self {
+            Self::Trade(trade) => trade.id(),
+            Self::AggTrade(trade) => trade.id(),
+            Self::OrderBookL1(book) => book.id(),
+            Self::Liquidation(liquidation) => liquidation.id(),
+        }
+    }
+}
+
+impl<InstrumentId> From<(ExchangeId, InstrumentId, BinanceDataKind)>
+    for MarketIter<InstrumentId, DataKind>
+{
+    fn from(
+        (exchange_id, instrument, message): (ExchangeId, InstrumentId, BinanceDataKind),
+    ) -> Self {
+        match message {
+            BinanceDataKind::Trade(trade) => {
+                MarketIter::<InstrumentId, PublicTrade>::from((exchange_id, instrument, trade))
+                    .into_data_kinds()
+            }
+            BinanceDataKind::AggTrade(trade) => {
+                MarketIter::<InstrumentId, AggTrade>::from((exchange_id, instrument, trade))
+                    .into_data_kinds()
+            }
+            BinanceDataKind::OrderBookL1(book) => {
+                MarketIter::<InstrumentId, OrderBookL1>::from((exchange_id, instrument, book))
+                    .into_data_kinds()
+            }
+            BinanceDataKind::Liquidation(liquidation) => {
+                MarketIter::<InstrumentId, Liquidation>::from((
+                    exchange_id,
+                    instrument,
+                    liquidation,
+                ))
+                .into_data_kinds()
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod de {
+        use super::*;
+
+        #[test]
+        fn test_binance_data_kind() {
+            struct TestCase {
+                input: &'static str,
+                expected: Option<SubscriptionId>,
+            }
+
+            let tests = vec![
+                TestCase {
+                    // TC0: valid spot Trade
+                    input: r#"
+                    {
+                        "e":"trade","E":1649324825173,"s":"ETHUSDT","t":1000000000,
+                        "p":"10000.19","q":"0.239000","b":10108767791,"a":10108764858,
+                        "T":1749354825200,"m":false,"M":true
+                    }
+                    "#,
+                    expected: Some(SubscriptionId::from("@trade|ETHUSDT")),
+                },
+                TestCase {
+                    // TC1: valid AggTrade
+                    input: r#"
+                    {
+                        "e":"aggTrade","E":1672515782136,"s":"BNBBTC","a":12345,"p":"0.001",
+                        "q":"100","f":100,"l":105,"T":1672515782136,"m":false,"M":true
+                    }
+                    "#,
+                    expected: Some(SubscriptionId::from("@aggTrade|BNBBTC")),
+                },
+                TestCase {
+                    // TC2: valid spot OrderBookL1 w/o event type
+                    input: r#"
+                    {
+                        "u":22606535573,"s":"ETHUSDT","b":"1215.27000000","B":"32.49110000",
+                        "a":"1215.28000000","A":"13.93900000"
+                    }
+                    "#,
+                    expected: Some(SubscriptionId::from("@bookTicker|ETHUSDT")),
+                },
+                TestCase {
+                    // TC3: valid Liquidation
+                    input: r#"
+                    {
+                        "e": "forceOrder", "E": 1665523974222,
+                        "o": {
+                            "s": "BTCUSDT", "S": "SELL", "o": "LIMIT", "f": "IOC", "q": "0.009",
+                            "p": "18917.15", "ap": "18990.00", "X": "FILLED", "l": "0.009",
+                            "z": "0.009", "T": 1665523974217
+                        }
+                    }
+                    "#,
+                    expected: Some(SubscriptionId::from("@forceOrder|BTCUSDT")),
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                let actual = serde_json::from_str::<BinanceDataKind>(test.input).unwrap();
+                assert_eq!(actual.id(), test.expected, "TC{index} failed");
+            }
+        }
+    }
+}
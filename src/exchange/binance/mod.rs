@@ -1,12 +1,20 @@
 use self::{
-    book::l1::BinanceOrderBookL1, channel::BinanceChannel, market::BinanceMarket,
-    subscription::BinanceSubResponse, trade::BinanceTrade,
+    book::l1::BinanceOrderBookL1,
+    channel::BinanceChannel,
+    kinds::BinanceDataKind,
+    market::BinanceMarket,
+    subscription::BinanceSubResponse,
+    trade::{BinanceAggTrade, BinanceTrade},
 };
-use crate::instrument::InstrumentData;
+use crate::instrument::{InstrumentData, InstrumentId, KeyedInstrument, MarketInstrumentData};
 use crate::{
     exchange::{Connector, ExchangeId, ExchangeServer, ExchangeSub, StreamSelector},
-    subscriber::{validator::WebSocketSubValidator, WebSocketSubscriber},
-    subscription::{book::OrderBooksL1, trade::PublicTrades, Map},
+    subscriber::{pacing::RequestRate, validator::WebSocketSubValidator, WebSocketSubscriber},
+    subscription::{
+        book::OrderBooksL1,
+        trade::{AggTrades, PublicTrades},
+        DataKinds, Map,
+    },
     transformer::stateless::StatelessTransformer,
     ExchangeWsStream,
 };
@@ -26,6 +34,10 @@ pub mod channel;
 /// [`BinanceFuturesUsd`](futures::BinanceFuturesUsd).
 pub mod futures;
 
+/// [`DataKinds`] message types common to both [`BinanceSpot`](spot::BinanceSpot) and
+/// [`BinanceFuturesUsd`](futures::BinanceFuturesUsd).
+pub mod kinds;
+
 /// Defines the type that translates a Barter [`Subscription`](crate::subscription::Subscription)
 /// into an exchange [`Connector`] specific market used for generating [`Connector::requests`].
 pub mod market;
@@ -96,6 +108,14 @@ where
     fn expected_responses<InstrumentId>(_: &Map<InstrumentId>) -> usize {
         1
     }
+
+    fn subscription_limit() -> Option<usize> {
+        Server::subscription_limit()
+    }
+
+    fn request_rate() -> Option<RequestRate> {
+        Server::request_rate()
+    }
 }
 
 impl<Instrument, Server> StreamSelector<Instrument, PublicTrades> for Binance<Server>
@@ -107,6 +127,15 @@ where
         ExchangeWsStream<StatelessTransformer<Self, Instrument::Id, PublicTrades, BinanceTrade>>;
 }
 
+impl<Instrument, Server> StreamSelector<Instrument, AggTrades> for Binance<Server>
+where
+    Instrument: InstrumentData,
+    Server: ExchangeServer + Debug + Send + Sync,
+{
+    type Stream =
+        ExchangeWsStream<StatelessTransformer<Self, Instrument::Id, AggTrades, BinanceAggTrade>>;
+}
+
 impl<Instrument, Server> StreamSelector<Instrument, OrderBooksL1> for Binance<Server>
 where
     Instrument: InstrumentData,
@@ -117,6 +146,30 @@ where
     >;
 }
 
+/// [`DataKinds`] [`StreamSelector`] for [`KeyedInstrument`]s, which supports only stateless
+/// [`SubKind`](crate::subscription::SubKind)s.
+///
+/// See the [`BinanceSpot`](spot::BinanceSpot) & [`BinanceFuturesUsd`](futures::BinanceFuturesUsd)
+/// [`Instrument`](barter_integration::model::instrument::Instrument) implementations, which also
+/// support [`SubKind::OrderBooksL2`](crate::subscription::SubKind::OrderBooksL2).
+impl<Id, Server> StreamSelector<KeyedInstrument<Id>, DataKinds> for Binance<Server>
+where
+    Id: Debug + Clone + Send + Sync,
+    Server: ExchangeServer + Debug + Send + Sync,
+{
+    type Stream = ExchangeWsStream<StatelessTransformer<Self, Id, DataKinds, BinanceDataKind>>;
+}
+
+/// [`DataKinds`] [`StreamSelector`] for [`MarketInstrumentData`], which supports only stateless
+/// [`SubKind`](crate::subscription::SubKind)s.
+impl<Server> StreamSelector<MarketInstrumentData, DataKinds> for Binance<Server>
+where
+    Server: ExchangeServer + Debug + Send + Sync,
+{
+    type Stream =
+        ExchangeWsStream<StatelessTransformer<Self, InstrumentId, DataKinds, BinanceDataKind>>;
+}
+
 impl<'de, Server> serde::Deserialize<'de> for Binance<Server>
 where
     Server: ExchangeServer,
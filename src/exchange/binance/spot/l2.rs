@@ -1,14 +1,18 @@
-use super::super::book::{l2::BinanceOrderBookL2Snapshot, BinanceLevel};
+use super::super::book::{
+    l2::{normalise_ob_l2_subscription_id, BinanceOrderBookL2Snapshot},
+    BinanceLevel,
+};
 use crate::{
     error::DataError,
-    subscription::book::OrderBook,
-    transformer::book::{InstrumentOrderBook, OrderBookUpdater},
+    event::{MarketEvent, MarketIter},
+    exchange::ExchangeId,
+    subscription::book::{pool::LevelPool, Level, OrderBook, OrderBookDelta},
+    transformer::book::{snapshot::SnapshotFetcher, InstrumentOrderBook, OrderBookUpdater},
     Identifier,
 };
 use async_trait::async_trait;
 use barter_integration::{
-    error::SocketError,
-    model::{instrument::Instrument, SubscriptionId},
+    model::{instrument::Instrument, Exchange, SubscriptionId},
     protocol::websocket::WsMessage,
 };
 use chrono::Utc;
@@ -61,6 +65,29 @@ impl Identifier<Option<SubscriptionId>> for BinanceSpotOrderBookL2Delta {
     }
 }
 
+impl<InstrumentId> From<(ExchangeId, InstrumentId, BinanceSpotOrderBookL2Delta)>
+    for MarketIter<InstrumentId, OrderBookDelta>
+{
+    fn from(
+        (exchange_id, instrument, delta): (ExchangeId, InstrumentId, BinanceSpotOrderBookL2Delta),
+    ) -> Self {
+        let time = Utc::now();
+        Self(vec![Ok(MarketEvent {
+            exchange_time: time,
+            received_time: time,
+            exchange: Exchange::from(exchange_id),
+            instrument,
+            kind: OrderBookDelta {
+                first_update_id: delta.first_update_id,
+                last_update_id: delta.last_update_id,
+                prev_last_update_id: None,
+                bids: delta.bids.into_iter().map(Level::from).collect(),
+                asks: delta.asks.into_iter().map(Level::from).collect(),
+            },
+        })])
+    }
+}
+
 /// [`Binance`](super::super::Binance) [`BinanceServerSpot`](super::BinanceServerSpot)
 /// [`OrderBookUpdater`].
 ///
@@ -83,6 +110,8 @@ impl Identifier<Option<SubscriptionId>> for BinanceSpotOrderBookL2Delta {
 ///  - Receiving an event that removes a price level that is not in your local order book can happen and is normal.
 ///  - Uppercase U => first_update_id
 ///  - Lowercase u => last_update_id,
+///  - The `@depth@100ms` & `@depth` (1000ms) streams share these rules. Partial depth streams are
+///    not supported since their messages omit the market.
 ///
 /// See docs: <https://binance-docs.github.io/apidocs/spot/en/#how-to-manage-a-local-order-book-correctly>
 #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
@@ -151,10 +180,25 @@ impl BinanceSpotBookUpdater {
     }
 }
 
+impl SnapshotFetcher for BinanceSpotBookUpdater {
+    type Snapshot = BinanceOrderBookL2Snapshot;
+
+    fn url(instrument: &Instrument) -> String {
+        format!(
+            "{}?symbol={}{}&limit=100",
+            HTTP_BOOK_L2_SNAPSHOT_URL_BINANCE_SPOT,
+            instrument.base.as_ref().to_uppercase(),
+            instrument.quote.as_ref().to_uppercase()
+        )
+    }
+}
+
 #[async_trait]
 impl OrderBookUpdater for BinanceSpotBookUpdater {
     type OrderBook = OrderBook;
     type Update = BinanceSpotOrderBookL2Delta;
+    const RESYNC_ON_GAP: bool = true;
+    const AUDIT: bool = true;
 
     async fn init<Exchange, Kind>(
         _: mpsc::UnboundedSender<WsMessage>,
@@ -164,21 +208,8 @@ impl OrderBookUpdater for BinanceSpotBookUpdater {
         Exchange: Send,
         Kind: Send,
     {
-        // Construct initial OrderBook snapshot GET url
-        let snapshot_url = format!(
-            "{}?symbol={}{}&limit=100",
-            HTTP_BOOK_L2_SNAPSHOT_URL_BINANCE_SPOT,
-            instrument.base.as_ref().to_uppercase(),
-            instrument.quote.as_ref().to_uppercase()
-        );
-
         // Fetch initial OrderBook snapshot via HTTP
-        let snapshot = reqwest::get(snapshot_url)
-            .await
-            .map_err(SocketError::Http)?
-            .json::<BinanceOrderBookL2Snapshot>()
-            .await
-            .map_err(SocketError::Http)?;
+        let snapshot = Self::fetch(&instrument).await?;
 
         Ok(InstrumentOrderBook {
             instrument,
@@ -187,10 +218,15 @@ impl OrderBookUpdater for BinanceSpotBookUpdater {
         })
     }
 
+    fn configure(&mut self, subscription_id: SubscriptionId) -> SubscriptionId {
+        normalise_ob_l2_subscription_id(subscription_id).0
+    }
+
     fn update(
         &mut self,
         book: &mut Self::OrderBook,
         update: Self::Update,
+        pool: &mut LevelPool,
     ) -> Result<Option<Self::OrderBook>, DataError> {
         // BinanceSpot: How To Manage A Local OrderBook Correctly
         // See Self's Rust Docs for more information on each numbered step
@@ -221,7 +257,7 @@ impl OrderBookUpdater for BinanceSpotBookUpdater {
         self.prev_last_update_id = self.last_update_id;
         self.last_update_id = update.last_update_id;
 
-        Ok(Some(book.snapshot()))
+        Ok(Some(book.snapshot_in(pool)))
     }
 }
 
@@ -397,10 +433,7 @@ mod tests {
             for (index, test) in tests.into_iter().enumerate() {
                 let actual = test.updater.validate_first_update(&test.input);
                 match (actual, test.expected) {
-                    (Ok(actual), Ok(expected)) => {
-                        assert_eq!(actual, expected, "TC{} failed", index)
-                    }
-                    (Err(_), Err(_)) => {
+                    (Ok(()), Ok(())) | (Err(_), Err(_)) => {
                         // Test passed
                     }
                     (actual, expected) => {
@@ -460,10 +493,7 @@ mod tests {
             for (index, test) in tests.into_iter().enumerate() {
                 let actual = test.updater.validate_next_update(&test.input);
                 match (actual, test.expected) {
-                    (Ok(actual), Ok(expected)) => {
-                        assert_eq!(actual, expected, "TC{} failed", index)
-                    }
-                    (Err(_), Err(_)) => {
+                    (Ok(()), Ok(())) | (Err(_), Err(_)) => {
                         // Test passed
                     }
                     (actual, expected) => {
@@ -574,7 +604,11 @@ mod tests {
             ];
 
             for (index, mut test) in tests.into_iter().enumerate() {
-                let actual = test.updater.update(&mut test.book, test.input_update);
+                let actual = test.updater.update(
+                    &mut test.book,
+                    test.input_update,
+                    &mut LevelPool::default(),
+                );
 
                 match (actual, test.expected) {
                     (Ok(Some(actual)), Ok(Some(expected))) => {
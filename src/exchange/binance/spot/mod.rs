@@ -1,12 +1,20 @@
-use self::l2::BinanceSpotBookUpdater;
-use super::{Binance, ExchangeServer};
+use self::l2::{BinanceSpotBookUpdater, BinanceSpotOrderBookL2Delta};
+use super::{kinds::BinanceDataKind, Binance, ExchangeServer};
 use crate::{
     exchange::{ExchangeId, StreamSelector},
-    subscription::book::OrderBooksL2,
-    transformer::book::MultiBookTransformer,
+    instrument::InstrumentData,
+    subscriber::pacing::RequestRate,
+    subscription::{
+        book::{OrderBookDeltas, OrderBooksL2, OrderBooksL2Events},
+        DataKinds,
+    },
+    transformer::{
+        book::MultiBookTransformer, kinds::DataKindsTransformer, stateless::StatelessTransformer,
+    },
     ExchangeWsStream,
 };
 use barter_integration::model::instrument::Instrument;
+use std::time::Duration;
 
 /// Level 2 OrderBook types (top of book) and spot
 /// [`OrderBookUpdater`](crate::transformer::book::OrderBookUpdater) implementation.
@@ -17,6 +25,16 @@ pub mod l2;
 /// See docs: <https://binance-docs.github.io/apidocs/spot/en/#websocket-market-streams>
 pub const WEBSOCKET_BASE_URL_BINANCE_SPOT: &str = "wss://stream.binance.com:9443/ws";
 
+/// Maximum number of streams a single [`BinanceSpot`] WebSocket connection can listen to.
+///
+/// See docs: <https://binance-docs.github.io/apidocs/spot/en/#websocket-limits>
+pub const SUBSCRIPTION_LIMIT_BINANCE_SPOT: usize = 1024;
+
+/// Maximum rate of incoming messages a single [`BinanceSpot`] WebSocket connection accepts.
+///
+/// See docs: <https://binance-docs.github.io/apidocs/spot/en/#websocket-limits>
+pub const REQUEST_RATE_BINANCE_SPOT: RequestRate = RequestRate::new(5, Duration::from_secs(1));
+
 /// [`Binance`] spot exchange.
 pub type BinanceSpot = Binance<BinanceServerSpot>;
 
@@ -30,6 +48,19 @@ impl ExchangeServer for BinanceServerSpot {
     fn websocket_url() -> &'static str {
         WEBSOCKET_BASE_URL_BINANCE_SPOT
     }
+
+    fn subscription_limit() -> Option<usize> {
+        Some(SUBSCRIPTION_LIMIT_BINANCE_SPOT)
+    }
+
+    fn request_rate() -> Option<RequestRate> {
+        Some(REQUEST_RATE_BINANCE_SPOT)
+    }
+}
+
+impl StreamSelector<Instrument, DataKinds> for BinanceSpot {
+    type Stream =
+        ExchangeWsStream<DataKindsTransformer<Self, BinanceDataKind, BinanceSpotBookUpdater>>;
 }
 
 impl StreamSelector<Instrument, OrderBooksL2> for BinanceSpot {
@@ -37,3 +68,18 @@ impl StreamSelector<Instrument, OrderBooksL2> for BinanceSpot {
         MultiBookTransformer<Self, Instrument, OrderBooksL2, BinanceSpotBookUpdater>,
     >;
 }
+
+impl StreamSelector<Instrument, OrderBooksL2Events> for BinanceSpot {
+    type Stream = ExchangeWsStream<
+        MultiBookTransformer<Self, Instrument, OrderBooksL2Events, BinanceSpotBookUpdater>,
+    >;
+}
+
+impl<Instrument> StreamSelector<Instrument, OrderBookDeltas> for BinanceSpot
+where
+    Instrument: InstrumentData,
+{
+    type Stream = ExchangeWsStream<
+        StatelessTransformer<Self, Instrument::Id, OrderBookDeltas, BinanceSpotOrderBookL2Delta>,
+    >;
+}
@@ -5,7 +5,7 @@ use serde::{Deserialize, Serialize};
 use crate::{
     event::{MarketEvent, MarketIter},
     exchange::{ExchangeId, ExchangeSub},
-    subscription::trade::PublicTrade,
+    subscription::trade::{AggTrade, PublicTrade},
     Identifier,
 };
 
@@ -58,7 +58,7 @@ pub struct BinanceTrade {
     pub subscription_id: SubscriptionId,
     #[serde(
         alias = "T",
-        deserialize_with = "barter_integration::de::de_u64_epoch_ms_as_datetime_utc"
+        deserialize_with = "crate::util::time::de_u64_epoch_ms_as_datetime_utc"
     )]
     pub time: DateTime<Utc>,
     #[serde(alias = "t")]
@@ -106,6 +106,86 @@ where
         .map(|market| ExchangeSub::from((BinanceChannel::TRADES, market)).id())
 }
 
+/// Binance real-time aggregated trade message.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://binance-docs.github.io/apidocs/spot/en/#aggregate-trade-streams>
+/// See docs: <https://binance-docs.github.io/apidocs/futures/en/#aggregate-trade-streams>
+/// #### Spot Side::Buy AggTrade
+/// ```json
+/// {
+///     "e":"aggTrade",
+///     "E":1672515782136,
+///     "s":"BNBBTC",
+///     "a":12345,
+///     "p":"0.001",
+///     "q":"100",
+///     "f":100,
+///     "l":105,
+///     "T":1672515782136,
+///     "m":false,
+///     "M":true
+/// }
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct BinanceAggTrade {
+    #[serde(alias = "s", deserialize_with = "de_agg_trade_subscription_id")]
+    pub subscription_id: SubscriptionId,
+    #[serde(
+        alias = "T",
+        deserialize_with = "crate::util::time::de_u64_epoch_ms_as_datetime_utc"
+    )]
+    pub time: DateTime<Utc>,
+    #[serde(alias = "a")]
+    pub id: u64,
+    #[serde(alias = "f")]
+    pub first_id: u64,
+    #[serde(alias = "l")]
+    pub last_id: u64,
+    #[serde(alias = "p", deserialize_with = "barter_integration::de::de_str")]
+    pub price: f64,
+    #[serde(alias = "q", deserialize_with = "barter_integration::de::de_str")]
+    pub amount: f64,
+    #[serde(alias = "m", deserialize_with = "de_side_from_buyer_is_maker")]
+    pub side: Side,
+}
+
+impl Identifier<Option<SubscriptionId>> for BinanceAggTrade {
+    fn id(&self) -> Option<SubscriptionId> {
+        Some(self.subscription_id.clone())
+    }
+}
+
+impl<InstrumentId> From<(ExchangeId, InstrumentId, BinanceAggTrade)>
+    for MarketIter<InstrumentId, AggTrade>
+{
+    fn from((exchange_id, instrument, trade): (ExchangeId, InstrumentId, BinanceAggTrade)) -> Self {
+        Self(vec![Ok(MarketEvent {
+            exchange_time: trade.time,
+            received_time: Utc::now(),
+            exchange: Exchange::from(exchange_id),
+            instrument,
+            kind: AggTrade {
+                first_id: trade.first_id,
+                last_id: trade.last_id,
+                price: trade.price,
+                amount: trade.amount,
+                side: trade.side,
+            },
+        })])
+    }
+}
+
+/// Deserialize a [`BinanceAggTrade`] "s" (eg/ "BTCUSDT") as the associated [`SubscriptionId`]
+/// (eg/ "@aggTrade|BTCUSDT").
+pub fn de_agg_trade_subscription_id<'de, D>(deserializer: D) -> Result<SubscriptionId, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+{
+    <&str as Deserialize>::deserialize(deserializer)
+        .map(|market| ExchangeSub::from((BinanceChannel::AGG_TRADES, market)).id())
+}
+
 /// Deserialize a [`BinanceTrade`] "buyer_is_maker" boolean field to a Barter [`Side`].
 ///
 /// Variants:
@@ -127,6 +207,7 @@ where
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::subscription::trade::tests::assert_duplicate_frame_dedup_keys;
 
     mod de {
         use std::time::Duration;
@@ -249,5 +330,99 @@ mod tests {
                 }
             }
         }
+
+        #[test]
+        fn test_binance_agg_trade() {
+            struct TestCase {
+                input: &'static str,
+                expected: Result<BinanceAggTrade, SocketError>,
+            }
+
+            let tests = vec![
+                TestCase {
+                    // TC0: Spot AggTrade valid
+                    input: r#"
+                    {
+                        "e":"aggTrade","E":1672515782136,"s":"BNBBTC","a":12345,"p":"0.001",
+                        "q":"100","f":100,"l":105,"T":1672515782136,"m":true,"M":true
+                    }
+                    "#,
+                    expected: Ok(BinanceAggTrade {
+                        subscription_id: SubscriptionId::from("@aggTrade|BNBBTC"),
+                        time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                            1672515782136,
+                        )),
+                        id: 12345,
+                        first_id: 100,
+                        last_id: 105,
+                        price: 0.001,
+                        amount: 100.0,
+                        side: Side::Sell,
+                    }),
+                },
+                TestCase {
+                    // TC1: FuturePerpetual AggTrade valid
+                    input: r#"
+                    {
+                        "e":"aggTrade","E":123456789,"s":"BTCUSDT","a":5933014,"p":"0.001",
+                        "q":"100","f":100,"l":105,"T":123456785,"m":false
+                    }
+                    "#,
+                    expected: Ok(BinanceAggTrade {
+                        subscription_id: SubscriptionId::from("@aggTrade|BTCUSDT"),
+                        time: datetime_utc_from_epoch_duration(Duration::from_millis(123456785)),
+                        id: 5933014,
+                        first_id: 100,
+                        last_id: 105,
+                        price: 0.001,
+                        amount: 100.0,
+                        side: Side::Buy,
+                    }),
+                },
+                TestCase {
+                    // TC2: AggTrade malformed w/ missing last_id field
+                    input: r#"
+                    {
+                        "e":"aggTrade","E":123456789,"s":"BTCUSDT","a":5933014,"p":"0.001",
+                        "q":"100","f":100,"T":123456785,"m":false
+                    }
+                    "#,
+                    expected: Err(SocketError::Deserialise {
+                        error: serde_json::Error::custom(""),
+                        payload: "".to_owned(),
+                    }),
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                let actual = serde_json::from_str::<BinanceAggTrade>(test.input);
+                match (actual, test.expected) {
+                    (Ok(actual), Ok(expected)) => {
+                        assert_eq!(actual, expected, "TC{} failed", index)
+                    }
+                    (Err(_), Err(_)) => {
+                        // Test passed
+                    }
+                    (actual, expected) => {
+                        // Test failed
+                        panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_binance_trade_duplicate_frame_dedup_keys() {
+        assert_duplicate_frame_dedup_keys::<BinanceTrade>(
+            ExchangeId::BinanceSpot,
+            r#"
+            {
+                "e":"trade","E":1649324825173,"s":"ETHUSDT","t":1000000000,
+                "p":"10000.19","q":"0.239000","b":10108767791,"a":10108764858,
+                "T":1749354825200,"m":false,"M":true
+            }
+            "#,
+        );
     }
 }
@@ -0,0 +1,276 @@
+use crate::{
+    event::{MarketEvent, MarketIter},
+    exchange::ExchangeId,
+    subscription::book::{Level, OrderBookL1},
+    Identifier,
+};
+use barter_integration::{
+    de::extract_next,
+    model::{Exchange, SubscriptionId},
+};
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+
+/// [`Bitfinex`](super::super::Bitfinex) ticker message received over
+/// [`WebSocket`](barter_integration::protocol::websocket::WebSocket) relating to an active
+/// [`OrderBooksL1`](crate::subscription::book::OrderBooksL1) subscription.
+///
+/// The message is associated with the original [`Subscription`](crate::Subscription) using the
+/// `channel_id` field as the [`SubscriptionId`].
+///
+/// ### Raw Payload Examples
+/// See docs: <https://docs.bitfinex.com/reference/ws-public-ticker>
+/// #### Heartbeat
+/// ```json
+/// [17470,"hb"]
+/// ```
+///
+/// #### Ticker
+/// ```json
+/// [17470,[7254.7,37.58,7254.8,44.29,-28.9,-0.0039,7254.8,4463.21,7313.8,7218.5]]
+/// ```
+#[derive(Clone, Copy, PartialEq, PartialOrd, Debug, Serialize)]
+pub struct BitfinexOrderBookL1 {
+    pub channel_id: u32,
+    pub payload: BitfinexOrderBookL1Payload,
+}
+
+/// [`Bitfinex`](super::super::Bitfinex) ticker variants associated with an active
+/// [`Subscription`](crate::Subscription).
+///
+/// See [`BitfinexOrderBookL1`] for full raw payload examples.
+#[derive(Clone, Copy, PartialEq, PartialOrd, Debug, Serialize)]
+pub enum BitfinexOrderBookL1Payload {
+    Heartbeat,
+    Ticker(BitfinexTicker),
+}
+
+/// [`Bitfinex`](super::super::Bitfinex) ticker top of book fields.
+///
+/// Format: \[BID, BID_SIZE, ASK, ASK_SIZE, DAILY_CHANGE, ...\], <br> where the trailing daily
+/// statistics are ignored.
+///
+/// Note that Bitfinex documents the BID_SIZE & ASK_SIZE as the sum of the 25 best levels, rather
+/// than the amount available at the best price.
+///
+/// See docs: <https://docs.bitfinex.com/reference/ws-public-ticker>
+#[derive(Clone, Copy, PartialEq, PartialOrd, Debug, Serialize)]
+pub struct BitfinexTicker {
+    pub best_bid_price: f64,
+    pub best_bid_amount: f64,
+    pub best_ask_price: f64,
+    pub best_ask_amount: f64,
+}
+
+impl Identifier<Option<SubscriptionId>> for BitfinexOrderBookL1 {
+    fn id(&self) -> Option<SubscriptionId> {
+        match self.payload {
+            BitfinexOrderBookL1Payload::Heartbeat => None,
+            BitfinexOrderBookL1Payload::Ticker(_) => {
+                Some(SubscriptionId::from(self.channel_id.to_string()))
+            }
+        }
+    }
+}
+
+impl<InstrumentId> From<(ExchangeId, InstrumentId, BitfinexOrderBookL1)>
+    for MarketIter<InstrumentId, OrderBookL1>
+{
+    fn from(
+        (exchange_id, instrument, message): (ExchangeId, InstrumentId, BitfinexOrderBookL1),
+    ) -> Self {
+        let BitfinexOrderBookL1Payload::Ticker(ticker) = message.payload else {
+            return Self(vec![]);
+        };
+
+        // Ticker messages do not contain an exchange timestamp
+        let time = Utc::now();
+
+        Self(vec![Ok(MarketEvent {
+            exchange_time: time,
+            received_time: time,
+            exchange: Exchange::from(exchange_id),
+            instrument,
+            kind: OrderBookL1 {
+                last_update_time: time,
+                best_bid: Level::new(ticker.best_bid_price, ticker.best_bid_amount),
+                best_ask: Level::new(ticker.best_ask_price, ticker.best_ask_amount),
+            },
+        })])
+    }
+}
+
+impl<'de> Deserialize<'de> for BitfinexOrderBookL1 {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::de::Deserializer<'de>,
+    {
+        /// [`BitfinexOrderBookL1`] 2nd element variants.
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Payload {
+            Tag(String),
+            Ticker(BitfinexTicker),
+        }
+
+        struct SeqVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for SeqVisitor {
+            type Value = BitfinexOrderBookL1;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("BitfinexOrderBookL1 struct from the Bitfinex WebSocket API")
+            }
+
+            fn visit_seq<SeqAccessor>(
+                self,
+                mut seq: SeqAccessor,
+            ) -> Result<Self::Value, SeqAccessor::Error>
+            where
+                SeqAccessor: serde::de::SeqAccess<'de>,
+            {
+                // Ticker: [CHANNEL_ID, [BID, BID_SIZE, ASK, ASK_SIZE, ...]]
+                // Heartbeat: [CHANNEL_ID, "hb"]
+
+                // Extract CHANNEL_ID used to identify SubscriptionId: 1st element of the sequence
+                let channel_id: u32 = extract_next(&mut seq, "channel_id")?;
+
+                // Extract payload: 2nd element of the sequence
+                let payload = match extract_next(&mut seq, "payload")? {
+                    Payload::Tag(tag) if tag == "hb" => BitfinexOrderBookL1Payload::Heartbeat,
+                    Payload::Tag(other) => {
+                        return Err(serde::de::Error::unknown_variant(
+                            &other,
+                            &["heartbeat (hb)"],
+                        ))
+                    }
+                    Payload::Ticker(ticker) => BitfinexOrderBookL1Payload::Ticker(ticker),
+                };
+
+                // Ignore any additional elements or SerDe will fail
+                //  '--> Bitfinex may add fields without warning
+                while seq.next_element::<serde::de::IgnoredAny>()?.is_some() {}
+                Ok(BitfinexOrderBookL1 {
+                    channel_id,
+                    payload,
+                })
+            }
+        }
+
+        // Use Visitor implementation to deserialise the WebSocket BitfinexOrderBookL1
+        deserializer.deserialize_seq(SeqVisitor)
+    }
+}
+
+impl<'de> Deserialize<'de> for BitfinexTicker {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::de::Deserializer<'de>,
+    {
+        struct SeqVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for SeqVisitor {
+            type Value = BitfinexTicker;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("BitfinexTicker struct from the Bitfinex WebSocket API")
+            }
+
+            fn visit_seq<SeqAccessor>(
+                self,
+                mut seq: SeqAccessor,
+            ) -> Result<Self::Value, SeqAccessor::Error>
+            where
+                SeqAccessor: serde::de::SeqAccess<'de>,
+            {
+                let ticker = BitfinexTicker {
+                    best_bid_price: extract_next(&mut seq, "bid")?,
+                    best_bid_amount: extract_next(&mut seq, "bid_size")?,
+                    best_ask_price: extract_next(&mut seq, "ask")?,
+                    best_ask_amount: extract_next(&mut seq, "ask_size")?,
+                };
+
+                // Ignore the trailing daily statistics
+                while seq.next_element::<serde::de::IgnoredAny>()?.is_some() {}
+                Ok(ticker)
+            }
+        }
+
+        deserializer.deserialize_seq(SeqVisitor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod de {
+        use super::*;
+        use barter_integration::error::SocketError;
+        use serde::de::Error;
+
+        #[test]
+        fn test_bitfinex_order_book_l1() {
+            struct TestCase {
+                input: &'static str,
+                expected: Result<BitfinexOrderBookL1, SocketError>,
+            }
+
+            let tests = vec![
+                TestCase {
+                    // TC0: valid Heartbeat
+                    input: r#"[17470,"hb"]"#,
+                    expected: Ok(BitfinexOrderBookL1 {
+                        channel_id: 17470,
+                        payload: BitfinexOrderBookL1Payload::Heartbeat,
+                    }),
+                },
+                TestCase {
+                    // TC1: valid Ticker
+                    input: r#"[17470,[7254.7,37.58,7254.8,44.29,-28.9,-0.0039,7254.8,4463.21,7313.8,7218.5]]"#,
+                    expected: Ok(BitfinexOrderBookL1 {
+                        channel_id: 17470,
+                        payload: BitfinexOrderBookL1Payload::Ticker(BitfinexTicker {
+                            best_bid_price: 7254.7,
+                            best_bid_amount: 37.58,
+                            best_ask_price: 7254.8,
+                            best_ask_amount: 44.29,
+                        }),
+                    }),
+                },
+                TestCase {
+                    // TC2: invalid Ticker w/ missing ASK_SIZE
+                    input: r#"[17470,[7254.7,37.58,7254.8]]"#,
+                    expected: Err(SocketError::Deserialise {
+                        error: serde_json::Error::custom(""),
+                        payload: "".to_owned(),
+                    }),
+                },
+                TestCase {
+                    // TC3: invalid message w/ unknown tag
+                    input: r#"[17470,"te",[7254.7,37.58,7254.8,44.29]]"#,
+                    expected: Err(SocketError::Deserialise {
+                        error: serde_json::Error::custom(""),
+                        payload: "".to_owned(),
+                    }),
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                let actual = serde_json::from_str::<BitfinexOrderBookL1>(test.input);
+                match (actual, test.expected) {
+                    (Ok(actual), Ok(expected)) => {
+                        assert_eq!(actual, expected, "TC{} failed", index)
+                    }
+                    (Err(_), Err(_)) => {
+                        // Test passed
+                    }
+                    (actual, expected) => {
+                        // Test failed
+                        panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                    }
+                }
+            }
+        }
+    }
+}
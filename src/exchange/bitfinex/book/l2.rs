@@ -0,0 +1,466 @@
+use crate::{
+    error::DataError,
+    subscription::book::{pool::LevelPool, Level, OrderBook, OrderBookSide},
+    transformer::book::{
+        checksum::{BitfinexChecksum, BookChecksum},
+        InstrumentOrderBook, OrderBookUpdater,
+    },
+    Identifier,
+};
+use async_trait::async_trait;
+use barter_integration::{
+    de::extract_next,
+    model::{instrument::Instrument, Side, SubscriptionId},
+    protocol::websocket::WsMessage,
+};
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use tokio::sync::mpsc;
+
+/// Number of [`Level`]s on each side of the [`Bitfinex`](super::super::Bitfinex) OrderBook used
+/// to calculate the CRC32 checksum.
+///
+/// See docs: <https://docs.bitfinex.com/docs/ws-websocket-checksum>
+pub const BOOK_L2_CHECKSUM_DEPTH_BITFINEX: usize = 25;
+
+/// [`Bitfinex`](super::super::Bitfinex) aggregated (`prec=P0`) OrderBook message received over
+/// [`WebSocket`](barter_integration::protocol::websocket::WebSocket) relating to an active
+/// [`OrderBooksL2`](crate::subscription::book::OrderBooksL2) subscription.
+///
+/// The message is associated with the original [`Subscription`](crate::Subscription) using the
+/// `channel_id` field as the [`SubscriptionId`].
+///
+/// ### Raw Payload Examples
+/// See docs: <https://docs.bitfinex.com/reference/ws-public-books>
+/// #### Heartbeat
+/// ```json
+/// [17082,"hb"]
+/// ```
+///
+/// #### Snapshot
+/// ```json
+/// [17082,[[7254.7,3,3.3],[7254.8,1,-0.5]]]
+/// ```
+///
+/// #### Update
+/// ```json
+/// [17082,[7254.7,4,3.5]]
+/// ```
+///
+/// #### Update Removing An Ask Level (COUNT = 0)
+/// ```json
+/// [17082,[7254.8,0,-1]]
+/// ```
+///
+/// #### Checksum
+/// See docs: <https://docs.bitfinex.com/docs/ws-websocket-checksum>
+/// ```json
+/// [17082,"cs",-1759005123]
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Serialize)]
+pub struct BitfinexOrderBookL2 {
+    pub channel_id: u32,
+    pub payload: BitfinexOrderBookL2Payload,
+}
+
+/// [`Bitfinex`](super::super::Bitfinex) aggregated OrderBook variants associated with an
+/// active [`Subscription`](crate::Subscription).
+///
+/// See [`BitfinexOrderBookL2`] for full raw payload examples.
+#[derive(Clone, PartialEq, PartialOrd, Debug, Serialize)]
+pub enum BitfinexOrderBookL2Payload {
+    Heartbeat,
+    Snapshot(Vec<BitfinexLevel>),
+    Update(BitfinexLevel),
+    Checksum(i32),
+}
+
+impl Identifier<Option<SubscriptionId>> for BitfinexOrderBookL2 {
+    fn id(&self) -> Option<SubscriptionId> {
+        match self.payload {
+            BitfinexOrderBookL2Payload::Heartbeat => None,
+            BitfinexOrderBookL2Payload::Snapshot(_)
+            | BitfinexOrderBookL2Payload::Update(_)
+            | BitfinexOrderBookL2Payload::Checksum(_) => {
+                Some(SubscriptionId::from(self.channel_id.to_string()))
+            }
+        }
+    }
+}
+
+/// [`Bitfinex`](super::super::Bitfinex) aggregated OrderBook level.
+///
+/// Format: \[PRICE, COUNT, AMOUNT\], <br> where +/- of amount indicates bid/ask, and a COUNT of
+/// 0 indicates the level has been removed from the OrderBook.
+///
+/// See docs: <https://docs.bitfinex.com/reference/ws-public-books>
+#[derive(Clone, Copy, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct BitfinexLevel {
+    pub price: f64,
+    pub count: u64,
+    pub amount: f64,
+}
+
+impl BitfinexLevel {
+    /// Determine the [`Side`] of this [`BitfinexLevel`] using the sign of the amount.
+    pub fn side(&self) -> Side {
+        match self.amount.is_sign_positive() {
+            true => Side::Buy,
+            false => Side::Sell,
+        }
+    }
+}
+
+impl From<BitfinexLevel> for Level {
+    fn from(level: BitfinexLevel) -> Self {
+        // COUNT of 0 => remove Level, which is represented by an amount of 0
+        let amount = match level.count {
+            0 => 0.0,
+            _ => level.amount.abs(),
+        };
+
+        Self {
+            price: level.price,
+            amount,
+        }
+    }
+}
+
+/// [`Bitfinex`](super::super::Bitfinex) [`OrderBookUpdater`] that maintains a local
+/// aggregated OrderBook from the WebSocket snapshot & updates.
+///
+/// Bitfinex: Maintaining The Local OrderBook
+/// 1. Send a "conf" event enabling checksums, and subscribe to the "book" channel with `prec=P0`.
+/// 2. The first message is a snapshot of the OrderBook.
+/// 3. Each update with a COUNT > 0 adds or replaces the price level, where AMOUNT > 0 is a bid
+///    and AMOUNT < 0 is an ask.
+/// 4. Each update with a COUNT of 0 removes the price level, where AMOUNT of 1 is a bid and
+///    AMOUNT of -1 is an ask.
+/// 5. The CRC32 checksum of the top 25 bids & asks must equal the (signed) checksum of each
+///    "cs" message, otherwise the OrderBook must be re-initialised.
+///
+/// See docs: <https://docs.bitfinex.com/docs/ws-websocket-checksum>
+#[derive(
+    Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default, Deserialize, Serialize,
+)]
+pub struct BitfinexBookUpdater {
+    pub updates_processed: u64,
+}
+
+#[async_trait]
+impl OrderBookUpdater for BitfinexBookUpdater {
+    type OrderBook = OrderBook;
+    type Update = BitfinexOrderBookL2;
+
+    async fn init<Exchange, Kind>(
+        _: mpsc::UnboundedSender<WsMessage>,
+        instrument: Instrument,
+    ) -> Result<InstrumentOrderBook<Instrument, Self>, DataError>
+    where
+        Exchange: Send,
+        Kind: Send,
+    {
+        // Bitfinex sends the initial OrderBook snapshot over the WebSocket after subscribing
+        Ok(InstrumentOrderBook {
+            instrument,
+            updater: Self::default(),
+            book: OrderBook {
+                last_update_time: Utc::now(),
+                bids: OrderBookSide::new(Side::Buy, Vec::<Level>::new()),
+                asks: OrderBookSide::new(Side::Sell, Vec::<Level>::new()),
+            },
+        })
+    }
+
+    fn update(
+        &mut self,
+        book: &mut Self::OrderBook,
+        update: Self::Update,
+        pool: &mut LevelPool,
+    ) -> Result<Option<Self::OrderBook>, DataError> {
+        // Aggregated OrderBook messages do not contain an exchange timestamp
+        match update.payload {
+            BitfinexOrderBookL2Payload::Heartbeat => return Ok(None),
+            BitfinexOrderBookL2Payload::Snapshot(levels) => {
+                let (bids, asks): (Vec<_>, Vec<_>) = levels
+                    .into_iter()
+                    .partition(|level| level.side() == Side::Buy);
+
+                *book = OrderBook {
+                    last_update_time: Utc::now(),
+                    bids: OrderBookSide::new(Side::Buy, bids),
+                    asks: OrderBookSide::new(Side::Sell, asks),
+                };
+            }
+            BitfinexOrderBookL2Payload::Update(level) => {
+                book.last_update_time = Utc::now();
+                match level.side() {
+                    Side::Buy => book.bids.upsert_single(level),
+                    Side::Sell => book.asks.upsert_single(level),
+                }
+            }
+            // Checksum is validated by the MultiBookTransformer via Self::book_checksum
+            BitfinexOrderBookL2Payload::Checksum(_) => return Ok(None),
+        }
+
+        self.updates_processed += 1;
+
+        Ok(Some(book.snapshot_in(pool)))
+    }
+
+    fn expected_checksum(&self, update: &Self::Update) -> Option<u32> {
+        match update.payload {
+            // Bitfinex checksums are signed 32-bit integers
+            BitfinexOrderBookL2Payload::Checksum(checksum) => Some(checksum as u32),
+            _ => None,
+        }
+    }
+
+    fn book_checksum(&self) -> Option<&dyn BookChecksum> {
+        Some(&BitfinexChecksum)
+    }
+
+    fn checksum_mismatch(&mut self, expected: u32, actual: u32) -> DataError {
+        // Re-subscribing yields a new CHANNEL_ID, so the OrderBook must be re-initialised
+        DataError::BookDesynchronised(format!(
+            "checksum {actual} does not match exchange checksum {expected}"
+        ))
+    }
+}
+
+impl<'de> Deserialize<'de> for BitfinexOrderBookL2 {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::de::Deserializer<'de>,
+    {
+        /// [`BitfinexOrderBookL2`] 2nd element variants.
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Payload {
+            Tag(String),
+            Snapshot(Vec<BitfinexLevel>),
+            Update(BitfinexLevel),
+        }
+
+        struct SeqVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for SeqVisitor {
+            type Value = BitfinexOrderBookL2;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("BitfinexOrderBookL2 struct from the Bitfinex WebSocket API")
+            }
+
+            fn visit_seq<SeqAccessor>(
+                self,
+                mut seq: SeqAccessor,
+            ) -> Result<Self::Value, SeqAccessor::Error>
+            where
+                SeqAccessor: serde::de::SeqAccess<'de>,
+            {
+                // Snapshot: [CHANNEL_ID, [[PRICE, COUNT, AMOUNT], ...]]
+                // Update: [CHANNEL_ID, [PRICE, COUNT, AMOUNT]]
+                // Heartbeat: [CHANNEL_ID, "hb"]
+                // Checksum: [CHANNEL_ID, "cs", CHECKSUM]
+
+                // Extract CHANNEL_ID used to identify SubscriptionId: 1st element of the sequence
+                let channel_id: u32 = extract_next(&mut seq, "channel_id")?;
+
+                // Extract payload: 2nd (& 3rd for checksums) element of the sequence
+                let payload = match extract_next(&mut seq, "payload")? {
+                    Payload::Tag(tag) => match tag.as_str() {
+                        "hb" => BitfinexOrderBookL2Payload::Heartbeat,
+                        "cs" => BitfinexOrderBookL2Payload::Checksum(extract_next(
+                            &mut seq, "checksum",
+                        )?),
+                        other => {
+                            return Err(serde::de::Error::unknown_variant(
+                                other,
+                                &["heartbeat (hb)", "checksum (cs)"],
+                            ))
+                        }
+                    },
+                    Payload::Snapshot(levels) => BitfinexOrderBookL2Payload::Snapshot(levels),
+                    Payload::Update(level) => BitfinexOrderBookL2Payload::Update(level),
+                };
+
+                // Ignore any additional elements or SerDe will fail
+                //  '--> Bitfinex may add fields without warning
+                while seq.next_element::<serde::de::IgnoredAny>()?.is_some() {}
+                Ok(BitfinexOrderBookL2 {
+                    channel_id,
+                    payload,
+                })
+            }
+        }
+
+        // Use Visitor implementation to deserialise the WebSocket BitfinexOrderBookL2
+        deserializer.deserialize_seq(SeqVisitor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod de {
+        use super::*;
+        use barter_integration::error::SocketError;
+        use serde::de::Error;
+
+        #[test]
+        fn test_bitfinex_order_book_l2() {
+            struct TestCase {
+                input: &'static str,
+                expected: Result<BitfinexOrderBookL2, SocketError>,
+            }
+
+            let tests = vec![
+                TestCase {
+                    // TC0: valid Heartbeat
+                    input: r#"[17082,"hb"]"#,
+                    expected: Ok(BitfinexOrderBookL2 {
+                        channel_id: 17082,
+                        payload: BitfinexOrderBookL2Payload::Heartbeat,
+                    }),
+                },
+                TestCase {
+                    // TC1: valid Snapshot
+                    input: r#"[17082,[[7254.7,3,3.3],[7254.8,1,-0.5]]]"#,
+                    expected: Ok(BitfinexOrderBookL2 {
+                        channel_id: 17082,
+                        payload: BitfinexOrderBookL2Payload::Snapshot(vec![
+                            BitfinexLevel {
+                                price: 7254.7,
+                                count: 3,
+                                amount: 3.3,
+                            },
+                            BitfinexLevel {
+                                price: 7254.8,
+                                count: 1,
+                                amount: -0.5,
+                            },
+                        ]),
+                    }),
+                },
+                TestCase {
+                    // TC2: valid Update removing an ask level
+                    input: r#"[17082,[7254.8,0,-1]]"#,
+                    expected: Ok(BitfinexOrderBookL2 {
+                        channel_id: 17082,
+                        payload: BitfinexOrderBookL2Payload::Update(BitfinexLevel {
+                            price: 7254.8,
+                            count: 0,
+                            amount: -1.0,
+                        }),
+                    }),
+                },
+                TestCase {
+                    // TC3: valid Checksum
+                    input: r#"[17082,"cs",-1759005123]"#,
+                    expected: Ok(BitfinexOrderBookL2 {
+                        channel_id: 17082,
+                        payload: BitfinexOrderBookL2Payload::Checksum(-1759005123),
+                    }),
+                },
+                TestCase {
+                    // TC4: invalid message w/ unknown tag
+                    input: r#"[17082,"te",[7254.7,3,3.3]]"#,
+                    expected: Err(SocketError::Deserialise {
+                        error: serde_json::Error::custom(""),
+                        payload: "".to_owned(),
+                    }),
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                let actual = serde_json::from_str::<BitfinexOrderBookL2>(test.input);
+                match (actual, test.expected) {
+                    (Ok(actual), Ok(expected)) => {
+                        assert_eq!(actual, expected, "TC{} failed", index)
+                    }
+                    (Err(_), Err(_)) => {
+                        // Test passed
+                    }
+                    (actual, expected) => {
+                        // Test failed
+                        panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                    }
+                }
+            }
+        }
+    }
+
+    mod bitfinex_book_updater {
+        use super::*;
+        use crate::transformer::book::checksum;
+
+        fn message(payload: BitfinexOrderBookL2Payload) -> BitfinexOrderBookL2 {
+            BitfinexOrderBookL2 {
+                channel_id: 17082,
+                payload,
+            }
+        }
+
+        fn level(price: f64, count: u64, amount: f64) -> BitfinexLevel {
+            BitfinexLevel {
+                price,
+                count,
+                amount,
+            }
+        }
+
+        #[test]
+        fn test_update() {
+            let mut updater = BitfinexBookUpdater::default();
+            let mut book = OrderBook {
+                last_update_time: Utc::now(),
+                bids: OrderBookSide::new(Side::Buy, Vec::<Level>::new()),
+                asks: OrderBookSide::new(Side::Sell, Vec::<Level>::new()),
+            };
+
+            // Snapshot initialises the OrderBook
+            let snapshot = message(BitfinexOrderBookL2Payload::Snapshot(vec![
+                level(7254.7, 3, 3.3),
+                level(7254.6, 1, 1.0),
+                level(7254.8, 1, -0.5),
+            ]));
+            let actual =
+                checksum::update(&mut updater, &mut book, snapshot, &mut LevelPool::default())
+                    .unwrap()
+                    .unwrap();
+            assert_eq!(actual.bids.levels()[0], Level::new(7254.7, 3.3));
+            assert_eq!(actual.asks.levels(), &[Level::new(7254.8, 0.5)]);
+
+            // Update w/ COUNT > 0 replaces the bid level
+            let update = message(BitfinexOrderBookL2Payload::Update(level(7254.7, 4, 3.5)));
+            let actual =
+                checksum::update(&mut updater, &mut book, update, &mut LevelPool::default())
+                    .unwrap()
+                    .unwrap();
+            assert_eq!(actual.bids.levels()[0], Level::new(7254.7, 3.5));
+
+            // Update w/ COUNT of 0 removes the bid level
+            let update = message(BitfinexOrderBookL2Payload::Update(level(7254.6, 0, 1.0)));
+            let actual =
+                checksum::update(&mut updater, &mut book, update, &mut LevelPool::default())
+                    .unwrap()
+                    .unwrap();
+            assert_eq!(actual.bids.levels(), &[Level::new(7254.7, 3.5)]);
+
+            // Valid checksum: crc32("7254.7:3.5:7254.8:-0.5") as i32
+            let checksum = message(BitfinexOrderBookL2Payload::Checksum(23593583));
+            assert!(matches!(
+                checksum::update(&mut updater, &mut book, checksum, &mut LevelPool::default()),
+                Ok(None)
+            ));
+
+            // Invalid checksum requires the OrderBook to be re-initialised
+            let checksum = message(BitfinexOrderBookL2Payload::Checksum(1));
+            let actual =
+                checksum::update(&mut updater, &mut book, checksum, &mut LevelPool::default());
+            assert!(matches!(actual, Err(DataError::BookDesynchronised(_))));
+            assert!(actual.unwrap_err().is_terminal());
+        }
+    }
+}
@@ -0,0 +1,337 @@
+use crate::{
+    event::{MarketEvent, MarketIter},
+    exchange::ExchangeId,
+    subscription::book::{OrderBookL3DoneReason, OrderBookL3Event},
+    Identifier,
+};
+use barter_integration::{
+    de::extract_next,
+    model::{Exchange, Side, SubscriptionId},
+};
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+
+/// [`Bitfinex`](super::super::Bitfinex) raw (`prec=R0`) OrderBook message received over
+/// [`WebSocket`](barter_integration::protocol::websocket::WebSocket) relating to an active
+/// [`OrderBooksL3`](crate::subscription::book::OrderBooksL3) subscription.
+///
+/// The message is associated with the original [`Subscription`](crate::Subscription) using the
+/// `channel_id` field as the [`SubscriptionId`].
+///
+/// ### Raw Payload Examples
+/// See docs: <https://docs.bitfinex.com/reference/ws-public-raw-books>
+/// #### Heartbeat
+/// ```json
+/// [17470,"hb"]
+/// ```
+///
+/// #### Snapshot
+/// ```json
+/// [17470,[[1234567890,7254.7,0.5],[1234567891,7254.8,-0.25]]]
+/// ```
+///
+/// #### Update
+/// ```json
+/// [17470,[1234567890,7254.7,0.75]]
+/// ```
+///
+/// #### Update Removing An Order (PRICE = 0)
+/// ```json
+/// [17470,[1234567890,0,1]]
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Serialize)]
+pub struct BitfinexOrderBookL3 {
+    pub channel_id: u32,
+    pub payload: BitfinexOrderBookL3Payload,
+}
+
+/// [`Bitfinex`](super::super::Bitfinex) raw OrderBook variants associated with an
+/// active [`Subscription`](crate::Subscription).
+///
+/// See [`BitfinexOrderBookL3`] for full raw payload examples.
+#[derive(Clone, PartialEq, PartialOrd, Debug, Serialize)]
+pub enum BitfinexOrderBookL3Payload {
+    Heartbeat,
+    Snapshot(Vec<BitfinexRawOrder>),
+    Update(BitfinexRawOrder),
+}
+
+/// [`Bitfinex`](super::super::Bitfinex) raw OrderBook order.
+///
+/// Format: \[ORDER_ID, PRICE, AMOUNT\], <br> where +/- of amount indicates bid/ask, and a
+/// PRICE of 0 indicates the order has been removed from the OrderBook.
+///
+/// See docs: <https://docs.bitfinex.com/reference/ws-public-raw-books>
+#[derive(Clone, Copy, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct BitfinexRawOrder {
+    pub order_id: u64,
+    pub price: f64,
+    pub amount: f64,
+}
+
+impl BitfinexRawOrder {
+    /// Determine the [`Side`] of this [`BitfinexRawOrder`] using the sign of the amount.
+    pub fn side(&self) -> Side {
+        match self.amount.is_sign_positive() {
+            true => Side::Buy,
+            false => Side::Sell,
+        }
+    }
+
+    /// Normalise this [`BitfinexRawOrder`] into an [`OrderBookL3Event`].
+    ///
+    /// Bitfinex does not provide a sequence number, nor distinguish new orders from updated
+    /// orders, so each open order is an [`OrderBookL3Event::Open`] upsert with a `sequence` of 0.
+    pub fn into_event(self) -> OrderBookL3Event {
+        if self.price == 0.0 {
+            OrderBookL3Event::Done {
+                sequence: 0,
+                order_id: self.order_id.to_string(),
+                side: self.side(),
+                price: None,
+                remaining: None,
+                reason: OrderBookL3DoneReason::Unknown,
+            }
+        } else {
+            OrderBookL3Event::Open {
+                sequence: 0,
+                order_id: self.order_id.to_string(),
+                side: self.side(),
+                price: self.price,
+                amount: self.amount.abs(),
+            }
+        }
+    }
+}
+
+impl Identifier<Option<SubscriptionId>> for BitfinexOrderBookL3 {
+    fn id(&self) -> Option<SubscriptionId> {
+        match self.payload {
+            BitfinexOrderBookL3Payload::Heartbeat => None,
+            BitfinexOrderBookL3Payload::Snapshot(_) | BitfinexOrderBookL3Payload::Update(_) => {
+                Some(SubscriptionId::from(self.channel_id.to_string()))
+            }
+        }
+    }
+}
+
+impl<InstrumentId> From<(ExchangeId, InstrumentId, BitfinexOrderBookL3)>
+    for MarketIter<InstrumentId, OrderBookL3Event>
+where
+    InstrumentId: Clone,
+{
+    fn from(
+        (exchange_id, instrument, message): (ExchangeId, InstrumentId, BitfinexOrderBookL3),
+    ) -> Self {
+        let orders = match message.payload {
+            BitfinexOrderBookL3Payload::Heartbeat => vec![],
+            BitfinexOrderBookL3Payload::Snapshot(orders) => orders,
+            BitfinexOrderBookL3Payload::Update(order) => vec![order],
+        };
+
+        // Raw OrderBook messages do not contain an exchange timestamp
+        let time = Utc::now();
+
+        orders
+            .into_iter()
+            .map(|order| {
+                Ok(MarketEvent {
+                    exchange_time: time,
+                    received_time: time,
+                    exchange: Exchange::from(exchange_id),
+                    instrument: instrument.clone(),
+                    kind: order.into_event(),
+                })
+            })
+            .collect()
+    }
+}
+
+impl<'de> Deserialize<'de> for BitfinexOrderBookL3 {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::de::Deserializer<'de>,
+    {
+        /// [`BitfinexOrderBookL3`] 2nd element variants.
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Payload {
+            Tag(String),
+            Snapshot(Vec<BitfinexRawOrder>),
+            Update(BitfinexRawOrder),
+        }
+
+        struct SeqVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for SeqVisitor {
+            type Value = BitfinexOrderBookL3;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("BitfinexOrderBookL3 struct from the Bitfinex WebSocket API")
+            }
+
+            fn visit_seq<SeqAccessor>(
+                self,
+                mut seq: SeqAccessor,
+            ) -> Result<Self::Value, SeqAccessor::Error>
+            where
+                SeqAccessor: serde::de::SeqAccess<'de>,
+            {
+                // Snapshot: [CHANNEL_ID, [[ORDER_ID, PRICE, AMOUNT], ...]]
+                // Update: [CHANNEL_ID, [ORDER_ID, PRICE, AMOUNT]]
+                // Heartbeat: [CHANNEL_ID, "hb"]
+                // Checksum: [CHANNEL_ID, "cs", CHECKSUM]
+
+                // Extract CHANNEL_ID used to identify SubscriptionId: 1st element of the sequence
+                let channel_id: u32 = extract_next(&mut seq, "channel_id")?;
+
+                // Extract payload: 2nd element of the sequence
+                let payload = match extract_next(&mut seq, "payload")? {
+                    Payload::Tag(tag) => match tag.as_str() {
+                        "hb" | "cs" => BitfinexOrderBookL3Payload::Heartbeat,
+                        other => {
+                            return Err(serde::de::Error::unknown_variant(
+                                other,
+                                &["heartbeat (hb)", "checksum (cs)"],
+                            ))
+                        }
+                    },
+                    Payload::Snapshot(orders) => BitfinexOrderBookL3Payload::Snapshot(orders),
+                    Payload::Update(order) => BitfinexOrderBookL3Payload::Update(order),
+                };
+
+                // Ignore any additional elements or SerDe will fail
+                //  '--> Bitfinex may add fields without warning
+                while seq.next_element::<serde::de::IgnoredAny>()?.is_some() {}
+                Ok(BitfinexOrderBookL3 {
+                    channel_id,
+                    payload,
+                })
+            }
+        }
+
+        // Use Visitor implementation to deserialise the WebSocket BitfinexOrderBookL3
+        deserializer.deserialize_seq(SeqVisitor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod de {
+        use super::*;
+        use barter_integration::error::SocketError;
+        use serde::de::Error;
+
+        #[test]
+        fn test_bitfinex_order_book_l3() {
+            struct TestCase {
+                input: &'static str,
+                expected: Result<BitfinexOrderBookL3, SocketError>,
+            }
+
+            let tests = vec![
+                TestCase {
+                    // TC0: valid Heartbeat
+                    input: r#"[17470,"hb"]"#,
+                    expected: Ok(BitfinexOrderBookL3 {
+                        channel_id: 17470,
+                        payload: BitfinexOrderBookL3Payload::Heartbeat,
+                    }),
+                },
+                TestCase {
+                    // TC1: valid Snapshot
+                    input: r#"[17470,[[1234567890,7254.7,0.5],[1234567891,7254.8,-0.25]]]"#,
+                    expected: Ok(BitfinexOrderBookL3 {
+                        channel_id: 17470,
+                        payload: BitfinexOrderBookL3Payload::Snapshot(vec![
+                            BitfinexRawOrder {
+                                order_id: 1234567890,
+                                price: 7254.7,
+                                amount: 0.5,
+                            },
+                            BitfinexRawOrder {
+                                order_id: 1234567891,
+                                price: 7254.8,
+                                amount: -0.25,
+                            },
+                        ]),
+                    }),
+                },
+                TestCase {
+                    // TC2: valid Update removing an order
+                    input: r#"[17470,[1234567890,0,1]]"#,
+                    expected: Ok(BitfinexOrderBookL3 {
+                        channel_id: 17470,
+                        payload: BitfinexOrderBookL3Payload::Update(BitfinexRawOrder {
+                            order_id: 1234567890,
+                            price: 0.0,
+                            amount: 1.0,
+                        }),
+                    }),
+                },
+                TestCase {
+                    // TC3: invalid message w/ unknown tag
+                    input: r#"[17470,"te",[1234567890,7254.7,0.5]]"#,
+                    expected: Err(SocketError::Deserialise {
+                        error: serde_json::Error::custom(""),
+                        payload: "".to_owned(),
+                    }),
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                let actual = serde_json::from_str::<BitfinexOrderBookL3>(test.input);
+                match (actual, test.expected) {
+                    (Ok(actual), Ok(expected)) => {
+                        assert_eq!(actual, expected, "TC{} failed", index)
+                    }
+                    (Err(_), Err(_)) => {
+                        // Test passed
+                    }
+                    (actual, expected) => {
+                        // Test failed
+                        panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_bitfinex_raw_order_into_event() {
+        assert_eq!(
+            BitfinexRawOrder {
+                order_id: 1,
+                price: 7254.8,
+                amount: -0.25,
+            }
+            .into_event(),
+            OrderBookL3Event::Open {
+                sequence: 0,
+                order_id: "1".to_string(),
+                side: Side::Sell,
+                price: 7254.8,
+                amount: 0.25,
+            }
+        );
+
+        assert_eq!(
+            BitfinexRawOrder {
+                order_id: 1,
+                price: 0.0,
+                amount: 1.0,
+            }
+            .into_event(),
+            OrderBookL3Event::Done {
+                sequence: 0,
+                order_id: "1".to_string(),
+                side: Side::Buy,
+                price: None,
+                remaining: None,
+                reason: OrderBookL3DoneReason::Unknown,
+            }
+        );
+    }
+}
@@ -0,0 +1,8 @@
+/// Level 1 OrderBook types (top of book).
+pub mod l1;
+
+/// Level 2 OrderBook types (aggregated price levels).
+pub mod l2;
+
+/// Level 3 OrderBook types (raw order-by-order).
+pub mod l3;
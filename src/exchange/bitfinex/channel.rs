@@ -1,6 +1,11 @@
 use super::Bitfinex;
 use crate::{
-    subscription::{trade::PublicTrades, Subscription},
+    subscription::{
+        book::{OrderBooksL1, OrderBooksL2, OrderBooksL3},
+        status::ExchangeStatus,
+        trade::PublicTrades,
+        Subscription,
+    },
     Identifier,
 };
 use serde::Serialize;
@@ -17,6 +22,28 @@ impl BitfinexChannel {
     ///
     /// See docs: <https://docs.bitfinex.com/reference/ws-public-trades>
     pub const TRADES: Self = Self("trades");
+
+    /// [`Bitfinex`] real-time OrderBook channel, subscribed to with aggregated precision
+    /// (`prec=P0`) to receive price level updates.
+    ///
+    /// See docs: <https://docs.bitfinex.com/reference/ws-public-books>
+    pub const ORDER_BOOK_L2: Self = Self("book:P0");
+
+    /// [`Bitfinex`] real-time OrderBook channel, subscribed to with raw precision (`prec=R0`) to
+    /// receive order-by-order updates.
+    ///
+    /// See docs: <https://docs.bitfinex.com/reference/ws-public-raw-books>
+    pub const ORDER_BOOK_L3: Self = Self("book:R0");
+
+    /// [`Bitfinex`] real-time ticker channel, providing the best bid & ask.
+    ///
+    /// See docs: <https://docs.bitfinex.com/reference/ws-public-ticker>
+    pub const ORDER_BOOK_L1: Self = Self("ticker");
+
+    /// OrderBook precision (eg/ "P0") this [`BitfinexChannel`] is subscribed to with, if any.
+    pub fn precision(&self) -> Option<&'static str> {
+        self.0.split_once(':').map(|(_, precision)| precision)
+    }
 }
 
 impl<Instrument> Identifier<BitfinexChannel> for Subscription<Bitfinex, Instrument, PublicTrades> {
@@ -25,8 +52,37 @@ impl<Instrument> Identifier<BitfinexChannel> for Subscription<Bitfinex, Instrume
     }
 }
 
+impl<Instrument> Identifier<BitfinexChannel> for Subscription<Bitfinex, Instrument, OrderBooksL1> {
+    fn id(&self) -> BitfinexChannel {
+        BitfinexChannel::ORDER_BOOK_L1
+    }
+}
+
+impl<Instrument> Identifier<BitfinexChannel> for Subscription<Bitfinex, Instrument, OrderBooksL2> {
+    fn id(&self) -> BitfinexChannel {
+        BitfinexChannel::ORDER_BOOK_L2
+    }
+}
+
+impl<Instrument> Identifier<BitfinexChannel> for Subscription<Bitfinex, Instrument, OrderBooksL3> {
+    fn id(&self) -> BitfinexChannel {
+        BitfinexChannel::ORDER_BOOK_L3
+    }
+}
+
+impl<Instrument> Identifier<BitfinexChannel>
+    for Subscription<Bitfinex, Instrument, ExchangeStatus>
+{
+    fn id(&self) -> BitfinexChannel {
+        BitfinexChannel::ORDER_BOOK_L1
+    }
+}
+
 impl AsRef<str> for BitfinexChannel {
     fn as_ref(&self) -> &str {
+        // Channel name excluding any OrderBook precision (eg/ "book:P0" -> "book")
         self.0
+            .split_once(':')
+            .map_or(self.0, |(channel, _)| channel)
     }
 }
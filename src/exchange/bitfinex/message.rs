@@ -70,6 +70,15 @@ impl<InstrumentId> From<(ExchangeId, InstrumentId, BitfinexMessage)>
     }
 }
 
+/// [`BitfinexMessage`] 2nd element, which is either a message tag (eg/ "te"), or an untagged initial
+/// snapshot.
+#[derive(serde::Deserialize)]
+#[serde(untagged)]
+enum BitfinexMessageTag {
+    Tag(String),
+    Snapshot(serde::de::IgnoredAny),
+}
+
 impl<'de> serde::Deserialize<'de> for BitfinexMessage {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
@@ -99,7 +108,11 @@ impl<'de> serde::Deserialize<'de> for BitfinexMessage {
                 let channel_id: u32 = extract_next(&mut seq, "channel_id")?;
 
                 // Extract message tag to identify payload type: 2nd element of the sequence
-                let message_tag: String = extract_next(&mut seq, "message_tag")?;
+                // '--> initial trade snapshots have no tag, so use them as an additional Heartbeat
+                let message_tag = match extract_next(&mut seq, "message_tag")? {
+                    BitfinexMessageTag::Tag(message_tag) => message_tag,
+                    BitfinexMessageTag::Snapshot(_) => "hb".to_string(),
+                };
 
                 // Use message tag to extract the payload: 3rd element of sequence
                 let payload = match message_tag.as_str() {
@@ -133,6 +146,7 @@ impl<'de> serde::Deserialize<'de> for BitfinexMessage {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::subscription::trade::tests::assert_duplicate_frame_dedup_keys;
     use barter_integration::{
         de::datetime_utc_from_epoch_duration, error::SocketError, model::Side,
     };
@@ -198,6 +212,14 @@ mod tests {
                     payload: BitfinexPayload::Heartbeat,
                 }),
             },
+            // TC4: Initial trades snapshot --> Should be marked as a heartbeat
+            TestCase {
+                input: r#"[420191,[[1225484398,1665452200022,-0.08980641,19027.02807752]]]"#,
+                expected: Ok(BitfinexMessage {
+                    channel_id: 420191,
+                    payload: BitfinexPayload::Heartbeat,
+                }),
+            },
         ];
 
         for (index, test) in cases.into_iter().enumerate() {
@@ -216,4 +238,12 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn test_bitfinex_trade_duplicate_frame_dedup_keys() {
+        assert_duplicate_frame_dedup_keys::<BitfinexMessage>(
+            ExchangeId::Bitfinex,
+            r#"[420191,"te",[1225484398,1665452200022,-0.08980641,19027.02807752]]"#,
+        );
+    }
 }
@@ -14,28 +14,60 @@
 //! - The user is allowed up to 20 connections per minute on the public API.
 //! - Each connection can be used to connect up to 25 different channels.
 //!
+//! #### Ticker OrderBooks
+//! - [`OrderBooksL1`] subscriptions use the "ticker" channel, which contains no exchange timestamp.
+//! - The ticker bid & ask sizes are documented as the sum of the 25 best levels on each side.
+//!
+//! #### Aggregated OrderBooks
+//! - [`OrderBooksL2`] subscriptions use the "book" channel with aggregated precision (`prec=P0`).
+//! - A "conf" event enabling checksums is sent before subscribing, and each "cs" checksum message
+//!   is validated against the local OrderBook.
+//! - Re-subscribing yields a new `CHANNEL_ID`, so a checksum mismatch re-initialises the stream.
+//!
+//! #### Raw OrderBooks
+//! - [`OrderBooksL3`] subscriptions use the "book" channel with raw precision (`prec=R0`).
+//! - Raw OrderBook messages contain no exchange timestamp or sequence number.
+//! - Bitfinex does not distinguish new orders from updated orders, so both are normalised as
+//!   [`OrderBookL3Event::Open`](crate::subscription::book::OrderBookL3Event::Open) upserts.
+//!
 //! #### Trade Variants
 //! - Bitfinex trades subscriptions results in receiving tag="te" & tag="tu" trades.
 //! - Both appear to be identical payloads, but "te" arriving marginally faster.
 //! - Therefore, tag="tu" trades are filtered out and considered only as additional Heartbeats.
 
 use self::{
-    channel::BitfinexChannel, market::BitfinexMarket, message::BitfinexMessage,
-    subscription::BitfinexPlatformEvent, validator::BitfinexWebSocketSubValidator,
+    book::{l1::BitfinexOrderBookL1, l2::BitfinexBookUpdater, l3::BitfinexOrderBookL3},
+    channel::BitfinexChannel,
+    market::BitfinexMarket,
+    message::BitfinexMessage,
+    status::BitfinexStatusMessage,
+    subscription::BitfinexPlatformEvent,
+    validator::BitfinexWebSocketSubValidator,
 };
 use crate::instrument::InstrumentData;
 use crate::{
     exchange::{Connector, ExchangeId, ExchangeSub, StreamSelector},
     subscriber::WebSocketSubscriber,
-    subscription::trade::PublicTrades,
-    transformer::stateless::StatelessTransformer,
+    subscription::{
+        book::{OrderBooksL1, OrderBooksL2, OrderBooksL3},
+        status::ExchangeStatus,
+        trade::PublicTrades,
+    },
+    transformer::{
+        book::MultiBookTransformer, stateless::StatelessTransformer, status::StatusTransformer,
+    },
     ExchangeWsStream,
 };
-use barter_integration::{error::SocketError, protocol::websocket::WsMessage};
+use barter_integration::{
+    error::SocketError, model::instrument::Instrument, protocol::websocket::WsMessage,
+};
 use barter_macro::{DeExchange, SerExchange};
 use serde_json::json;
 use url::Url;
 
+/// OrderBook types for [`Bitfinex`].
+pub mod book;
+
 /// Defines the type that translates a Barter [`Subscription`](crate::subscription::Subscription)
 /// into an exchange [`Connector`] specific channel used for generating [`Connector::requests`].
 pub mod channel;
@@ -47,6 +79,9 @@ pub mod market;
 /// [`BitfinexMessage`](message::BitfinexMessage) type for [`Bitfinex`].
 pub mod message;
 
+/// System status types for [`Bitfinex`].
+pub mod status;
+
 /// [`Subscription`](crate::subscription::Subscription) response types and response
 /// [`Validator`](barter_integration::Validator) for [`Bitfinex`].
 pub mod subscription;
@@ -63,6 +98,21 @@ pub mod validator;
 /// See docs: <https://docs.bitfinex.com/docs/ws-general>
 pub const BASE_URL_BITFINEX: &str = "wss://api-pub.bitfinex.com/ws/2";
 
+/// [`Bitfinex`] raw OrderBook number of orders on each side of the initial snapshot.
+///
+/// See docs: <https://docs.bitfinex.com/reference/ws-public-raw-books>
+pub const BITFINEX_RAW_BOOK_LEN: &str = "250";
+
+/// [`Bitfinex`] aggregated OrderBook number of price levels on each side of the initial snapshot.
+///
+/// See docs: <https://docs.bitfinex.com/reference/ws-public-books>
+pub const BITFINEX_BOOK_L2_LEN: &str = "25";
+
+/// [`Bitfinex`] "conf" flag enabling OrderBook checksum messages.
+///
+/// See docs: <https://docs.bitfinex.com/docs/ws-general#configuration>
+pub const BITFINEX_CONF_FLAG_CHECKSUM: u64 = 131072;
+
 /// [`Bitfinex`] exchange.
 ///
 /// See docs: <https://docs.bitfinex.com/docs/ws-general>
@@ -84,19 +134,49 @@ impl Connector for Bitfinex {
     }
 
     fn requests(exchange_subs: Vec<ExchangeSub<Self::Channel, Self::Market>>) -> Vec<WsMessage> {
-        exchange_subs
-            .into_iter()
-            .map(|ExchangeSub { channel, market }| {
+        // Aggregated OrderBooks require checksums to be enabled before subscribing
+        let conf = exchange_subs
+            .iter()
+            .any(|sub| sub.channel == BitfinexChannel::ORDER_BOOK_L2)
+            .then(|| {
                 WsMessage::Text(
                     json!({
-                        "event": "subscribe",
-                        "channel": channel.as_ref(),
-                        "symbol": market.as_ref(),
+                        "event": "conf",
+                        "flags": BITFINEX_CONF_FLAG_CHECKSUM,
                     })
                     .to_string(),
                 )
-            })
-            .collect()
+            });
+
+        let subscriptions = exchange_subs
+            .into_iter()
+            .map(|ExchangeSub { channel, market }| {
+                let request = match channel {
+                    BitfinexChannel::ORDER_BOOK_L2 => json!({
+                        "event": "subscribe",
+                        "channel": channel.as_ref(),
+                        "symbol": market.as_ref(),
+                        "prec": channel.precision(),
+                        "len": BITFINEX_BOOK_L2_LEN,
+                    }),
+                    BitfinexChannel::ORDER_BOOK_L3 => json!({
+                        "event": "subscribe",
+                        "channel": channel.as_ref(),
+                        "symbol": market.as_ref(),
+                        "prec": channel.precision(),
+                        "len": BITFINEX_RAW_BOOK_LEN,
+                    }),
+                    _ => json!({
+                        "event": "subscribe",
+                        "channel": channel.as_ref(),
+                        "symbol": market.as_ref(),
+                    }),
+                };
+
+                WsMessage::Text(request.to_string())
+            });
+
+        conf.into_iter().chain(subscriptions).collect()
     }
 }
 
@@ -107,3 +187,33 @@ where
     type Stream =
         ExchangeWsStream<StatelessTransformer<Self, Instrument::Id, PublicTrades, BitfinexMessage>>;
 }
+
+impl<Instrument> StreamSelector<Instrument, OrderBooksL1> for Bitfinex
+where
+    Instrument: InstrumentData,
+{
+    type Stream = ExchangeWsStream<
+        StatelessTransformer<Self, Instrument::Id, OrderBooksL1, BitfinexOrderBookL1>,
+    >;
+}
+
+impl StreamSelector<Instrument, OrderBooksL2> for Bitfinex {
+    type Stream =
+        ExchangeWsStream<MultiBookTransformer<Self, Instrument, OrderBooksL2, BitfinexBookUpdater>>;
+}
+
+impl<Instrument> StreamSelector<Instrument, OrderBooksL3> for Bitfinex
+where
+    Instrument: InstrumentData,
+{
+    type Stream = ExchangeWsStream<
+        StatelessTransformer<Self, Instrument::Id, OrderBooksL3, BitfinexOrderBookL3>,
+    >;
+}
+
+impl<Instrument> StreamSelector<Instrument, ExchangeStatus> for Bitfinex
+where
+    Instrument: InstrumentData,
+{
+    type Stream = ExchangeWsStream<StatusTransformer<Self, Instrument::Id, BitfinexStatusMessage>>;
+}
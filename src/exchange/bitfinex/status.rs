@@ -0,0 +1,171 @@
+use crate::{
+    event::{MarketEvent, MarketIter},
+    exchange::ExchangeId,
+    subscription::status::{SystemState, SystemStatus},
+};
+use barter_integration::model::Exchange;
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+
+/// [`Bitfinex`](super::Bitfinex) message received over
+/// [`WebSocket`](barter_integration::protocol::websocket::WebSocket) relating to an active
+/// [`ExchangeStatus`](crate::subscription::status::ExchangeStatus) subscription.
+///
+/// ### Notes
+/// Bitfinex pushes platform "info" events containing a code when the WebSocket server restarts
+/// or enters & exits maintenance. Since there is no dedicated channel, the subscription is carried
+/// by the [`BitfinexChannel::ORDER_BOOK_L1`](super::channel::BitfinexChannel::ORDER_BOOK_L1)
+/// "ticker" channel, and every other message is ignored.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://docs.bitfinex.com/docs/ws-general#info-messages>
+/// ```json
+/// {
+///   "event": "info",
+///   "code": 20060,
+///   "msg": "Entering in Maintenance mode. Please pause any activity and resume after receiving the info message 20061"
+/// }
+/// ```
+#[derive(Clone, PartialEq, Debug, Deserialize)]
+#[serde(untagged)]
+pub enum BitfinexStatusMessage {
+    Info(BitfinexInfo),
+    Other(serde::de::IgnoredAny),
+}
+
+/// [`Bitfinex`](super::Bitfinex) platform "info" event containing a code.
+///
+/// See [`BitfinexStatusMessage`] for full raw payload examples.
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+pub enum BitfinexInfo {
+    Info {
+        code: u32,
+        #[serde(default)]
+        msg: String,
+    },
+}
+
+impl BitfinexInfo {
+    /// [`Bitfinex`](super::Bitfinex) WebSocket server is restarting, and clients should
+    /// reconnect.
+    pub const CODE_RESTARTING: u32 = 20051;
+
+    /// [`Bitfinex`](super::Bitfinex) is entering maintenance mode.
+    pub const CODE_MAINTENANCE_START: u32 = 20060;
+
+    /// [`Bitfinex`](super::Bitfinex) maintenance has ended.
+    pub const CODE_MAINTENANCE_END: u32 = 20061;
+
+    /// Determine the normalised [`SystemState`] associated with this info event code, if any.
+    pub fn state(&self) -> Option<SystemState> {
+        let Self::Info { code, .. } = self;
+        match *code {
+            Self::CODE_RESTARTING => Some(SystemState::Restarting),
+            Self::CODE_MAINTENANCE_START => Some(SystemState::Maintenance),
+            Self::CODE_MAINTENANCE_END => Some(SystemState::Online),
+            _ => None,
+        }
+    }
+}
+
+impl<InstrumentId> From<(ExchangeId, InstrumentId, BitfinexStatusMessage)>
+    for MarketIter<InstrumentId, SystemStatus>
+{
+    fn from(
+        (exchange_id, instrument, message): (ExchangeId, InstrumentId, BitfinexStatusMessage),
+    ) -> Self {
+        let BitfinexStatusMessage::Info(info) = message else {
+            return Self(vec![]);
+        };
+
+        let Some(state) = info.state() else {
+            return Self(vec![]);
+        };
+
+        // Bitfinex info events do not contain an exchange timestamp
+        let time = Utc::now();
+        let BitfinexInfo::Info { msg, .. } = info;
+
+        Self(vec![Ok(MarketEvent {
+            exchange_time: time,
+            received_time: time,
+            exchange: Exchange::from(exchange_id),
+            instrument,
+            kind: SystemStatus {
+                state,
+                message: Some(msg),
+                start_time: None,
+                end_time: None,
+            },
+        })])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod de {
+        use super::*;
+        use barter_integration::error::SocketError;
+
+        #[test]
+        fn test_bitfinex_status_message() {
+            struct TestCase {
+                input: &'static str,
+                expected: Result<BitfinexStatusMessage, SocketError>,
+            }
+
+            let tests = vec![
+                TestCase {
+                    // TC0: valid maintenance info event
+                    input: r#"{"event":"info","code":20060,"msg":"Entering in Maintenance mode"}"#,
+                    expected: Ok(BitfinexStatusMessage::Info(BitfinexInfo::Info {
+                        code: 20060,
+                        msg: "Entering in Maintenance mode".to_string(),
+                    })),
+                },
+                TestCase {
+                    // TC1: initial platform info event w/o code is ignored
+                    input: r#"{"event":"info","version":2,"serverId":"abc","platform":{"status":1}}"#,
+                    expected: Ok(BitfinexStatusMessage::Other(serde::de::IgnoredAny)),
+                },
+                TestCase {
+                    // TC2: ticker data is ignored
+                    input: r#"[2,[7616.5,31.89055171,7617.5,43.35811863,-550.8,-0.0674,7617.1,8314.71200815,8257.8,7500]]"#,
+                    expected: Ok(BitfinexStatusMessage::Other(serde::de::IgnoredAny)),
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                let actual = serde_json::from_str::<BitfinexStatusMessage>(test.input);
+                match (actual, test.expected) {
+                    (Ok(actual), Ok(expected)) => {
+                        assert_eq!(actual, expected, "TC{} failed", index)
+                    }
+                    (Err(_), Err(_)) => {
+                        // Test passed
+                    }
+                    (actual, expected) => {
+                        // Test failed
+                        panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_bitfinex_info_state() {
+        let info = |code| BitfinexInfo::Info {
+            code,
+            msg: String::new(),
+        };
+
+        assert_eq!(info(20051).state(), Some(SystemState::Restarting));
+        assert_eq!(info(20060).state(), Some(SystemState::Maintenance));
+        assert_eq!(info(20061).state(), Some(SystemState::Online));
+        assert_eq!(info(10000).state(), None);
+    }
+}
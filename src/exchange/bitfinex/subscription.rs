@@ -28,6 +28,15 @@ use serde::{Deserialize, Serialize};
 /// }
 /// ```
 ///
+/// #### Configuration Success (eg/ OrderBook checksums enabled)
+/// ``` json
+/// {
+///   "event": "conf",
+///   "status": "OK",
+///   "flags": 131072
+/// }
+/// ```
+///
 /// #### Subscription Failure
 /// ``` json
 /// {
@@ -40,6 +49,7 @@ use serde::{Deserialize, Serialize};
 pub enum BitfinexPlatformEvent {
     #[serde(rename = "info")]
     PlatformStatus(BitfinexPlatformStatus),
+    Conf(BitfinexConf),
     Subscribed(BitfinexSubResponse),
     Error(BitfinexError),
 }
@@ -57,7 +67,13 @@ impl Validator for BitfinexPlatformEvent {
                     status.api_version, status.server_id,
                 ))),
             },
-            BitfinexPlatformEvent::Subscribed(_) => Ok(self),
+            BitfinexPlatformEvent::Conf(conf) if conf.status != "OK" => {
+                Err(SocketError::Subscribe(format!(
+                    "received failure conf response for flags: {} with status: {}",
+                    conf.flags, conf.status,
+                )))
+            }
+            BitfinexPlatformEvent::Conf(_) | BitfinexPlatformEvent::Subscribed(_) => Ok(self),
             BitfinexPlatformEvent::Error(error) => Err(SocketError::Subscribe(format!(
                 "received failure subscription response code: {} with message: {}",
                 error.code, error.msg,
@@ -115,6 +131,18 @@ pub enum Status {
     Operative,
 }
 
+/// [`Bitfinex`](super::Bitfinex) connection configuration response, received after sending a
+/// "conf" event (eg/ to enable OrderBook checksums).
+///
+/// See [`BitfinexPlatformEvent`] for full raw payload examples.
+///
+/// See docs: <https://docs.bitfinex.com/docs/ws-general#configuration>
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct BitfinexConf {
+    pub status: String,
+    pub flags: u64,
+}
+
 /// [`Bitfinex`](super::Bitfinex) subscription success response variants for each channel.
 ///
 /// ### Raw Payload Examples
@@ -2,9 +2,10 @@ use crate::{
     event::{MarketEvent, MarketIter},
     exchange::ExchangeId,
     subscription::trade::PublicTrade,
+    util::time::EpochUnit,
 };
 use barter_integration::{
-    de::{datetime_utc_from_epoch_duration, extract_next},
+    de::extract_next,
     model::{Exchange, Side},
 };
 use chrono::{DateTime, Utc};
@@ -31,7 +32,7 @@ use serde::Serialize;
 ///
 /// ## Notes:
 /// - [`Bitfinex`](super::Bitfinex) trades subscriptions results in receiving tag="te" & tag="tu"
-/// trades, both of which are identical.
+///   trades, both of which are identical.
 /// - "te" trades arrive marginally faster.
 /// - Therefore, tag="tu" trades are filtered out and considered only as additional Heartbeats.
 ///
@@ -101,9 +102,7 @@ impl<'de> serde::Deserialize<'de> for BitfinexTrade {
 
                 Ok(BitfinexTrade {
                     id,
-                    time: datetime_utc_from_epoch_duration(std::time::Duration::from_millis(
-                        time_millis,
-                    )),
+                    time: EpochUnit::Millis.datetime(time_millis),
                     price,
                     amount: amount.abs(),
                     side,
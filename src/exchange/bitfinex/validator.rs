@@ -2,7 +2,9 @@ use super::subscription::{BitfinexPlatformEvent, BitfinexSubResponse};
 use crate::instrument::InstrumentData;
 use crate::{
     exchange::{Connector, ExchangeSub},
-    subscriber::validator::SubscriptionValidator,
+    subscriber::validator::{
+        SubscriptionFailurePolicy, SubscriptionRejection, SubscriptionValidator,
+    },
     subscription::{Map, SubscriptionKind},
     Identifier,
 };
@@ -11,7 +13,7 @@ use barter_integration::{
     error::SocketError,
     model::SubscriptionId,
     protocol::{
-        websocket::{WebSocket, WebSocketParser},
+        websocket::{WebSocket, WebSocketParser, WsMessage},
         StreamParser,
     },
     Validator,
@@ -31,6 +33,8 @@ use tracing::debug;
 /// - Therefore the [`SubscriptionId`] format must change during [`BitfinexWebSocketSubValidator::validate`]
 ///   to use the [`BitfinexChannelId`](super::subscription::BitfinexChannelId)
 ///   (see module level "SubscriptionId" documentation notes for more details).
+/// - Initial snapshots received during validation are returned so that they can be transformed
+///   (eg/ raw OrderBook snapshots), rather than dropped.
 #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
 pub struct BitfinexWebSocketSubValidator;
 
@@ -41,7 +45,15 @@ impl SubscriptionValidator for BitfinexWebSocketSubValidator {
     async fn validate<Exchange, Instrument, Kind>(
         mut map: Map<Instrument::Id>,
         websocket: &mut WebSocket,
-    ) -> Result<Map<Instrument::Id>, SocketError>
+        _: SubscriptionFailurePolicy,
+    ) -> Result<
+        (
+            Map<Instrument::Id>,
+            Vec<WsMessage>,
+            Vec<SubscriptionRejection>,
+        ),
+        SocketError,
+    >
     where
         Exchange: Connector + Send,
         Instrument: InstrumentData,
@@ -56,13 +68,16 @@ impl SubscriptionValidator for BitfinexWebSocketSubValidator {
         let mut success_responses = 0usize;
         let mut init_snapshots_received = 0usize;
 
+        // Initial snapshots are buffered so they can be transformed (eg/ raw OrderBook snapshots)
+        let mut init_snapshots = Vec::with_capacity(expected_responses);
+
         loop {
             // Break if all Subscriptions were a success
             if success_responses == expected_responses
                 && init_snapshots_received == expected_responses
             {
                 debug!(exchange = %Exchange::ID, "validated exchange WebSocket subscriptions");
-                break Ok(map);
+                break Ok((map, init_snapshots, Vec::new()));
             }
 
             tokio::select! {
@@ -92,6 +107,15 @@ impl SubscriptionValidator for BitfinexWebSocketSubValidator {
                                 );
                             }
 
+                            // Connection configuration applied (eg/ OrderBook checksums)
+                            Ok(BitfinexPlatformEvent::Conf(conf)) => {
+                                debug!(
+                                    exchange = %Exchange::ID,
+                                    payload = ?conf,
+                                    "received Bitfinex conf response",
+                                );
+                            }
+
                             // Subscription success
                             Ok(BitfinexPlatformEvent::Subscribed(response)) => {
                                 // Determine SubscriptionId associated with the success response
@@ -130,6 +154,7 @@ impl SubscriptionValidator for BitfinexWebSocketSubValidator {
                                 %payload,
                                 "failed to deserialise non SubResponse payload"
                             );
+                            init_snapshots.push(WsMessage::Text(payload));
                             continue
                         }
                         Some(Err(SocketError::Terminated(close_frame))) => {
@@ -0,0 +1,437 @@
+use crate::{
+    error::DataError,
+    subscription::book::{pool::LevelPool, Level, OrderBook, OrderBookSide},
+    transformer::book::{InstrumentOrderBook, OrderBookUpdater},
+    Identifier,
+};
+use async_trait::async_trait;
+use barter_integration::{
+    model::{instrument::Instrument, Side, SubscriptionId},
+    protocol::websocket::WsMessage,
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use tokio::sync::mpsc;
+
+/// [`Bitmex`](super::super::Bitmex) real-time `orderBookL2` table WebSocket message.
+///
+/// ### Notes
+/// Each row is keyed by a price level id, and only the initial `partial` & `insert` rows are
+/// guaranteed to contain the level price. Subsequent `update` & `delete` rows must therefore be
+/// translated back into a price via the id.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://www.bitmex.com/app/wsAPI#OrderBookL2>
+/// ```json
+/// {
+///     "table": "orderBookL2",
+///     "action": "partial",
+///     "keys": ["symbol", "id", "side"],
+///     "data": [
+///         {
+///             "symbol": "XBTUSD",
+///             "id": 8799030550,
+///             "side": "Sell",
+///             "size": 100,
+///             "price": 9694.5,
+///             "timestamp": "2023-02-18T09:27:59.701Z"
+///         },
+///         {
+///             "symbol": "XBTUSD",
+///             "id": 8799030600,
+///             "side": "Buy",
+///             "size": 2500,
+///             "price": 9694,
+///             "timestamp": "2023-02-18T09:27:59.701Z"
+///         }
+///     ]
+/// }
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct BitmexOrderBookL2 {
+    pub table: String,
+    pub action: BitmexBookAction,
+    pub data: Vec<BitmexOrderBookL2Row>,
+}
+
+impl Identifier<Option<SubscriptionId>> for BitmexOrderBookL2 {
+    fn id(&self) -> Option<SubscriptionId> {
+        self.data
+            .first()
+            .map(|row| SubscriptionId(format!("{}|{}", self.table, row.symbol)))
+    }
+}
+
+/// [`Bitmex`](super::super::Bitmex) table action.
+///
+/// See docs: <https://www.bitmex.com/app/wsAPI#Response-Format>
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum BitmexBookAction {
+    Partial,
+    Insert,
+    Update,
+    Delete,
+}
+
+/// [`Bitmex`](super::super::Bitmex) `orderBookL2` table row, identifying a price level by id.
+///
+/// See [`BitmexOrderBookL2`] for full raw payload examples.
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct BitmexOrderBookL2Row {
+    pub symbol: String,
+    pub id: u64,
+    pub side: Side,
+    #[serde(default)]
+    pub size: Option<f64>,
+    #[serde(default)]
+    pub price: Option<f64>,
+    pub timestamp: DateTime<Utc>,
+}
+
+/// [`Bitmex`](super::super::Bitmex) [`OrderBookUpdater`] that maintains a local OrderBook Level2
+/// from the id-keyed `orderBookL2` table.
+///
+/// Bitmex: Maintaining The Local OrderBook
+/// 1. Subscribe to the "orderBookL2" table.
+/// 2. The first message is a `partial` containing every price level of the OrderBook, which
+///    replaces the local OrderBook. Messages received beforehand are dropped.
+/// 3. An `insert` adds a new price level id, price & size.
+/// 4. An `update` replaces the size of an existing price level id.
+/// 5. A `delete` removes an existing price level id.
+/// 6. An `update` or `delete` for an unknown price level id means the OrderBook is out of sync,
+///    and must be re-initialised.
+///
+/// See docs: <https://www.bitmex.com/app/wsAPI#OrderBookL2>
+#[derive(Clone, PartialEq, Debug)]
+pub struct BitmexBookUpdater {
+    pub awaiting_partial: bool,
+    pub prices: HashMap<u64, f64>,
+}
+
+impl Default for BitmexBookUpdater {
+    fn default() -> Self {
+        Self {
+            awaiting_partial: true,
+            prices: HashMap::new(),
+        }
+    }
+}
+
+impl BitmexBookUpdater {
+    /// Determine the price of the provided [`BitmexOrderBookL2Row`], using the price of the
+    /// known price level id if the row does not contain one.
+    pub fn price(&self, row: &BitmexOrderBookL2Row) -> Result<f64, DataError> {
+        row.price
+            .or_else(|| self.prices.get(&row.id).copied())
+            .ok_or_else(|| {
+                DataError::BookDesynchronised(format!(
+                    "Bitmex {} price level id {} not found",
+                    row.symbol, row.id
+                ))
+            })
+    }
+
+    /// Apply a single [`BitmexOrderBookL2Row`] to the provided [`OrderBook`].
+    pub fn apply(
+        &mut self,
+        book: &mut OrderBook,
+        action: BitmexBookAction,
+        row: BitmexOrderBookL2Row,
+    ) -> Result<(), DataError> {
+        let price = self.price(&row)?;
+
+        let amount = match action {
+            BitmexBookAction::Delete => {
+                self.prices.remove(&row.id);
+                0.0
+            }
+            _ => {
+                self.prices.insert(row.id, price);
+                row.size.unwrap_or_default()
+            }
+        };
+
+        let level = Level::new(price, amount);
+        match row.side {
+            Side::Buy => book.bids.upsert_single(level),
+            Side::Sell => book.asks.upsert_single(level),
+        }
+
+        book.last_update_time = row.timestamp;
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl OrderBookUpdater for BitmexBookUpdater {
+    type OrderBook = OrderBook;
+    type Update = BitmexOrderBookL2;
+
+    async fn init<Exchange, Kind>(
+        _: mpsc::UnboundedSender<WsMessage>,
+        instrument: Instrument,
+    ) -> Result<InstrumentOrderBook<Instrument, Self>, DataError>
+    where
+        Exchange: Send,
+        Kind: Send,
+    {
+        // Bitmex sends the initial OrderBook partial over the WebSocket after subscribing
+        Ok(InstrumentOrderBook {
+            instrument,
+            updater: Self::default(),
+            book: OrderBook {
+                last_update_time: Utc::now(),
+                bids: OrderBookSide::new(Side::Buy, Vec::<Level>::new()),
+                asks: OrderBookSide::new(Side::Sell, Vec::<Level>::new()),
+            },
+        })
+    }
+
+    fn update(
+        &mut self,
+        book: &mut Self::OrderBook,
+        update: Self::Update,
+        pool: &mut LevelPool,
+    ) -> Result<Option<Self::OrderBook>, DataError> {
+        let BitmexOrderBookL2 { action, data, .. } = update;
+
+        match action {
+            BitmexBookAction::Partial => {
+                // 2. A partial replaces the local OrderBook
+                *book = OrderBook {
+                    last_update_time: Utc::now(),
+                    bids: OrderBookSide::new(Side::Buy, Vec::<Level>::new()),
+                    asks: OrderBookSide::new(Side::Sell, Vec::<Level>::new()),
+                };
+                self.prices.clear();
+                self.awaiting_partial = false;
+            }
+            _ if self.awaiting_partial => return Ok(None),
+            _ => {}
+        }
+
+        data.into_iter()
+            .try_for_each(|row| self.apply(book, action, row))?;
+
+        Ok(Some(book.snapshot_in(pool)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod de {
+        use super::*;
+        use barter_integration::error::SocketError;
+        use chrono::{Duration, TimeZone};
+
+        #[test]
+        fn test_bitmex_order_book_l2() {
+            struct TestCase {
+                input: &'static str,
+                expected: Result<BitmexOrderBookL2, SocketError>,
+            }
+
+            let timestamp =
+                Utc.with_ymd_and_hms(2023, 2, 18, 9, 27, 59).unwrap() + Duration::milliseconds(701);
+
+            let tests = vec![
+                TestCase {
+                    // TC0: valid partial
+                    input: r#"
+                    {
+                        "table": "orderBookL2",
+                        "action": "partial",
+                        "keys": ["symbol", "id", "side"],
+                        "data": [
+                            {
+                                "symbol": "XBTUSD",
+                                "id": 8799030550,
+                                "side": "Sell",
+                                "size": 100,
+                                "price": 9694.5,
+                                "timestamp": "2023-02-18T09:27:59.701Z"
+                            }
+                        ]
+                    }
+                    "#,
+                    expected: Ok(BitmexOrderBookL2 {
+                        table: "orderBookL2".to_string(),
+                        action: BitmexBookAction::Partial,
+                        data: vec![BitmexOrderBookL2Row {
+                            symbol: "XBTUSD".to_string(),
+                            id: 8799030550,
+                            side: Side::Sell,
+                            size: Some(100.0),
+                            price: Some(9694.5),
+                            timestamp,
+                        }],
+                    }),
+                },
+                TestCase {
+                    // TC1: valid delete w/o size & price
+                    input: r#"
+                    {
+                        "table": "orderBookL2",
+                        "action": "delete",
+                        "data": [
+                            {
+                                "symbol": "XBTUSD",
+                                "id": 8799030550,
+                                "side": "Sell",
+                                "timestamp": "2023-02-18T09:27:59.701Z"
+                            }
+                        ]
+                    }
+                    "#,
+                    expected: Ok(BitmexOrderBookL2 {
+                        table: "orderBookL2".to_string(),
+                        action: BitmexBookAction::Delete,
+                        data: vec![BitmexOrderBookL2Row {
+                            symbol: "XBTUSD".to_string(),
+                            id: 8799030550,
+                            side: Side::Sell,
+                            size: None,
+                            price: None,
+                            timestamp,
+                        }],
+                    }),
+                },
+                TestCase {
+                    // TC2: invalid message w/ unknown action
+                    input: r#"{"table": "orderBookL2", "action": "unknown", "data": []}"#,
+                    expected: Err(SocketError::Unsupported {
+                        entity: "",
+                        item: "".to_string(),
+                    }),
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                let actual = serde_json::from_str::<BitmexOrderBookL2>(test.input);
+                match (actual, test.expected) {
+                    (Ok(actual), Ok(expected)) => {
+                        assert_eq!(actual, expected, "TC{} failed", index)
+                    }
+                    (Err(_), Err(_)) => {
+                        // Test passed
+                    }
+                    (actual, expected) => {
+                        // Test failed
+                        panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                    }
+                }
+            }
+        }
+    }
+
+    mod bitmex_book_updater {
+        use super::*;
+
+        fn message(
+            action: BitmexBookAction,
+            rows: Vec<(u64, Side, Option<f64>, Option<f64>)>,
+        ) -> BitmexOrderBookL2 {
+            BitmexOrderBookL2 {
+                table: "orderBookL2".to_string(),
+                action,
+                data: rows
+                    .into_iter()
+                    .map(|(id, side, size, price)| BitmexOrderBookL2Row {
+                        symbol: "XBTUSD".to_string(),
+                        id,
+                        side,
+                        size,
+                        price,
+                        timestamp: Utc::now(),
+                    })
+                    .collect(),
+            }
+        }
+
+        #[test]
+        fn test_update() {
+            let mut updater = BitmexBookUpdater::default();
+            let mut book = OrderBook {
+                last_update_time: Utc::now(),
+                bids: OrderBookSide::new(Side::Buy, Vec::<Level>::new()),
+                asks: OrderBookSide::new(Side::Sell, Vec::<Level>::new()),
+            };
+
+            // Messages received before the initial partial are dropped
+            let insert = message(
+                BitmexBookAction::Insert,
+                vec![(1, Side::Buy, Some(10.0), Some(100.0))],
+            );
+            assert_eq!(
+                updater
+                    .update(&mut book, insert, &mut LevelPool::default())
+                    .unwrap(),
+                None
+            );
+
+            // Partial initialises the OrderBook
+            let partial = message(
+                BitmexBookAction::Partial,
+                vec![
+                    (1, Side::Buy, Some(10.0), Some(100.0)),
+                    (2, Side::Buy, Some(20.0), Some(99.5)),
+                    (3, Side::Sell, Some(30.0), Some(100.5)),
+                ],
+            );
+            let actual = updater
+                .update(&mut book, partial, &mut LevelPool::default())
+                .unwrap()
+                .unwrap();
+            assert_eq!(
+                actual.bids.levels(),
+                &[Level::new(100.0, 10.0), Level::new(99.5, 20.0)]
+            );
+            assert_eq!(actual.asks.levels(), &[Level::new(100.5, 30.0)]);
+
+            // Update w/o price is translated via the price level id
+            let update = message(
+                BitmexBookAction::Update,
+                vec![(3, Side::Sell, Some(5.0), None)],
+            );
+            let actual = updater
+                .update(&mut book, update, &mut LevelPool::default())
+                .unwrap()
+                .unwrap();
+            assert_eq!(actual.asks.levels(), &[Level::new(100.5, 5.0)]);
+
+            // Insert adds a new price level id
+            let insert = message(
+                BitmexBookAction::Insert,
+                vec![(4, Side::Sell, Some(7.0), Some(101.0))],
+            );
+            let actual = updater
+                .update(&mut book, insert, &mut LevelPool::default())
+                .unwrap()
+                .unwrap();
+            assert_eq!(
+                actual.asks.levels(),
+                &[Level::new(100.5, 5.0), Level::new(101.0, 7.0)]
+            );
+
+            // Delete removes the price level id
+            let delete = message(BitmexBookAction::Delete, vec![(1, Side::Buy, None, None)]);
+            let actual = updater
+                .update(&mut book, delete, &mut LevelPool::default())
+                .unwrap()
+                .unwrap();
+            assert_eq!(actual.bids.levels(), &[Level::new(99.5, 20.0)]);
+            assert!(!updater.prices.contains_key(&1));
+
+            // Delete of an unknown price level id is a desynchronised OrderBook
+            let delete = message(BitmexBookAction::Delete, vec![(1, Side::Buy, None, None)]);
+            assert!(matches!(
+                updater.update(&mut book, delete, &mut LevelPool::default()),
+                Err(DataError::BookDesynchronised(_))
+            ));
+        }
+    }
+}
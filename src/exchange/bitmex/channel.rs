@@ -1,6 +1,10 @@
 use crate::{
     exchange::bitmex::Bitmex,
-    subscription::{trade::PublicTrades, Subscription},
+    subscription::{
+        book::{OrderBooksL1, OrderBooksL2},
+        trade::PublicTrades,
+        Subscription,
+    },
     Identifier,
 };
 use serde::Serialize;
@@ -17,6 +21,11 @@ impl BitmexChannel {
     ///
     /// See docs: <https://www.bitmex.com/app/wsAPI>
     pub const TRADES: Self = Self("trade");
+
+    /// [`Bitmex`] real-time OrderBook Level2 (full depth) table name.
+    ///
+    /// See docs: <https://www.bitmex.com/app/wsAPI#OrderBookL2>
+    pub const ORDER_BOOK_L2: Self = Self("orderBookL2");
 }
 
 impl<Instrument> Identifier<BitmexChannel> for Subscription<Bitmex, Instrument, PublicTrades> {
@@ -25,6 +34,20 @@ impl<Instrument> Identifier<BitmexChannel> for Subscription<Bitmex, Instrument,
     }
 }
 
+/// [`OrderBooksL1`] are derived from the OrderBook Level2 channel, since [`Bitmex`] has no
+/// supported bookTicker-style channel.
+impl<Instrument> Identifier<BitmexChannel> for Subscription<Bitmex, Instrument, OrderBooksL1> {
+    fn id(&self) -> BitmexChannel {
+        BitmexChannel::ORDER_BOOK_L2
+    }
+}
+
+impl<Instrument> Identifier<BitmexChannel> for Subscription<Bitmex, Instrument, OrderBooksL2> {
+    fn id(&self) -> BitmexChannel {
+        BitmexChannel::ORDER_BOOK_L2
+    }
+}
+
 impl AsRef<str> for BitmexChannel {
     fn as_ref(&self) -> &str {
         self.0
@@ -2,22 +2,34 @@ use crate::instrument::InstrumentData;
 use crate::{
     exchange::{
         bitmex::{
-            channel::BitmexChannel, market::BitmexMarket, subscription::BitmexSubResponse,
-            trade::BitmexTrade,
+            book::l2::BitmexBookUpdater, channel::BitmexChannel, market::BitmexMarket,
+            subscription::BitmexSubResponse, trade::BitmexTrade,
         },
         subscription::ExchangeSub,
         Connector, ExchangeId, StreamSelector,
     },
     subscriber::{validator::WebSocketSubValidator, WebSocketSubscriber},
-    subscription::{trade::PublicTrades, Map},
-    transformer::stateless::StatelessTransformer,
+    subscription::{
+        book::{OrderBooksL1, OrderBooksL2},
+        trade::PublicTrades,
+        Map,
+    },
+    transformer::{
+        book::{l1::OrderBookL1Transformer, MultiBookTransformer},
+        stateless::StatelessTransformer,
+    },
     ExchangeWsStream,
 };
-use barter_integration::{error::SocketError, protocol::websocket::WsMessage};
+use barter_integration::{
+    error::SocketError, model::instrument::Instrument, protocol::websocket::WsMessage,
+};
 use serde::de::{Error, Unexpected};
 use std::fmt::Debug;
 use url::Url;
 
+/// OrderBook types for [`Bitmex`].
+pub mod book;
+
 /// Defines the type that translates a Barter [`Subscription`](crate::subscription::Subscription)
 /// into an exchange [`Connector`] specific channel used for generating [`Connector::requests`].
 pub mod channel;
@@ -84,6 +96,21 @@ where
         ExchangeWsStream<StatelessTransformer<Self, Instrument::Id, PublicTrades, BitmexTrade>>;
 }
 
+impl StreamSelector<Instrument, OrderBooksL2> for Bitmex {
+    type Stream =
+        ExchangeWsStream<MultiBookTransformer<Self, Instrument, OrderBooksL2, BitmexBookUpdater>>;
+}
+
+impl StreamSelector<Instrument, OrderBooksL1> for Bitmex {
+    type Stream = ExchangeWsStream<
+        OrderBookL1Transformer<
+            Self,
+            Instrument,
+            MultiBookTransformer<Self, Instrument, OrderBooksL2, BitmexBookUpdater>,
+        >,
+    >;
+}
+
 impl<'de> serde::Deserialize<'de> for Bitmex {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
@@ -79,6 +79,7 @@ impl<InstrumentId: Clone> From<(ExchangeId, InstrumentId, BitmexTrade)>
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::subscription::trade::tests::assert_duplicate_frame_dedup_keys;
 
     mod de {
         use super::*;
@@ -202,4 +203,35 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn test_bitmex_trade_duplicate_frame_dedup_keys() {
+        assert_duplicate_frame_dedup_keys::<BitmexTrade>(
+            ExchangeId::Bitmex,
+            r#"
+            {
+                "table": "trade",
+                "action": "insert",
+                "data": [
+                    {
+                        "timestamp": "2023-02-18T09:27:59.701Z",
+                        "symbol": "XBTUSD",
+                        "side": "Sell",
+                        "size": 200,
+                        "price": 24564.5,
+                        "trdMatchID": "31e50cb7-e005-a44e-f354-86e88dff52eb"
+                    },
+                    {
+                        "timestamp": "2023-02-18T09:27:59.701Z",
+                        "symbol": "XBTUSD",
+                        "side": "Sell",
+                        "size": 200,
+                        "price": 24564.5,
+                        "trdMatchID": "9a1ae5c2-2a1f-8c59-1d2b-5cf3c1ab2e11"
+                    }
+                ]
+            }
+            "#,
+        );
+    }
 }
@@ -0,0 +1,366 @@
+use super::BybitLevel;
+use crate::{
+    error::DataError,
+    event::MarketEvent,
+    exchange::{
+        bybit::message::{BybitMessage, BybitPayload},
+        Connector,
+    },
+    streams::options::StreamOptions,
+    subscription::{
+        book::{Level, OrderBookL1, OrderBooksL1},
+        Map,
+    },
+    transformer::ExchangeTransformer,
+    Identifier,
+};
+use async_trait::async_trait;
+use barter_integration::{
+    model::Exchange as ExchangeName, protocol::websocket::WsMessage, Transformer,
+};
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use std::marker::PhantomData;
+use tokio::sync::mpsc;
+
+/// Terse type alias for a [`Bybit`](super::super::Bybit) real-time OrderBook Level1 (top of book)
+/// WebSocket message.
+pub type BybitOrderBookL1 = BybitPayload<BybitOrderBookL1Inner>;
+
+/// [`Bybit`](super::super::Bybit) real-time OrderBook Level1 (top of book) data.
+///
+/// ### Notes
+/// An initial "snapshot" is followed by "delta" messages that only contain the sides that have
+/// changed, where a level with a zero amount indicates it has been removed. A "snapshot" is
+/// re-sent if the top of book has not changed for 3 seconds.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://bybit-exchange.github.io/docs/v5/websocket/public/orderbook>
+/// ```json
+/// {
+///     "topic": "orderbook.1.BTCUSDT",
+///     "type": "snapshot",
+///     "ts": 1672304484978,
+///     "data": {
+///         "s": "BTCUSDT",
+///         "b": [["16493.50", "0.006"]],
+///         "a": [["16611.00", "0.029"]],
+///         "u": 18521288,
+///         "seq": 7961638724
+///     },
+///     "cts": 1672304484976
+/// }
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct BybitOrderBookL1Inner {
+    #[serde(alias = "b")]
+    pub bids: Vec<BybitLevel>,
+    #[serde(alias = "a")]
+    pub asks: Vec<BybitLevel>,
+    #[serde(alias = "u")]
+    pub update_id: u64,
+}
+
+/// Latest known top of book for an instrument, maintained by the [`BybitBookL1Transformer`].
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct InstrumentBookL1<InstrumentId> {
+    pub instrument: InstrumentId,
+    pub best_bid: Option<Level>,
+    pub best_ask: Option<Level>,
+}
+
+impl<InstrumentId> InstrumentBookL1<InstrumentId> {
+    /// Construct a new [`InstrumentBookL1`] with an empty top of book.
+    pub fn new(instrument: InstrumentId) -> Self {
+        Self {
+            instrument,
+            best_bid: None,
+            best_ask: None,
+        }
+    }
+
+    /// Apply a [`BybitOrderBookL1`] snapshot or delta to the top of book.
+    pub fn update(&mut self, message: &BybitOrderBookL1) {
+        let is_snapshot = message.r#type == "snapshot";
+        update_side(&mut self.best_bid, &message.data.bids, is_snapshot);
+        update_side(&mut self.best_ask, &message.data.asks, is_snapshot);
+    }
+
+    /// Construct an [`OrderBookL1`] from the current top of book, if both sides are known.
+    pub fn snapshot(&self, message: &BybitOrderBookL1) -> Option<OrderBookL1> {
+        Some(OrderBookL1 {
+            last_update_time: message.time,
+            best_bid: self.best_bid?,
+            best_ask: self.best_ask?,
+        })
+    }
+}
+
+/// Apply the provided [`BybitLevel`]s to one side of the top of book.
+///
+/// Snapshots replace the side entirely, whereas delta levels with a zero amount only remove the
+/// side if it is the current best level.
+fn update_side(best: &mut Option<Level>, levels: &[BybitLevel], is_snapshot: bool) {
+    if is_snapshot {
+        *best = None;
+    }
+
+    for level in levels {
+        if level.amount == 0.0 {
+            if best.is_some_and(|best| best.price == level.price) {
+                *best = None;
+            }
+        } else {
+            *best = Some(Level::from(*level));
+        }
+    }
+}
+
+/// Stateful [`Bybit`](super::super::Bybit) [`ExchangeTransformer`] that maintains the top of book
+/// for each instrument, since "delta" messages may only contain one side of the book.
+#[derive(Clone, PartialEq, Debug, Serialize)]
+pub struct BybitBookL1Transformer<Exchange, InstrumentId> {
+    book_map: Map<InstrumentBookL1<InstrumentId>>,
+    phantom: PhantomData<Exchange>,
+}
+
+#[async_trait]
+impl<Exchange, InstrumentId> ExchangeTransformer<Exchange, InstrumentId, OrderBooksL1>
+    for BybitBookL1Transformer<Exchange, InstrumentId>
+where
+    Exchange: Connector + Send,
+    InstrumentId: Clone + Send,
+{
+    async fn new(
+        _: mpsc::UnboundedSender<WsMessage>,
+        instrument_map: Map<InstrumentId>,
+        _: &StreamOptions,
+    ) -> Result<Self, DataError> {
+        Ok(Self {
+            book_map: instrument_map
+                .0
+                .into_iter()
+                .map(|(sub_id, instrument)| (sub_id, InstrumentBookL1::new(instrument)))
+                .collect(),
+            phantom: PhantomData,
+        })
+    }
+}
+
+impl<Exchange, InstrumentId> Transformer for BybitBookL1Transformer<Exchange, InstrumentId>
+where
+    Exchange: Connector,
+    InstrumentId: Clone,
+{
+    type Error = DataError;
+    type Input = BybitMessage<BybitOrderBookL1Inner>;
+    type Output = MarketEvent<InstrumentId, OrderBookL1>;
+    type OutputIter = Vec<Result<Self::Output, Self::Error>>;
+
+    fn transform(&mut self, input: Self::Input) -> Self::OutputIter {
+        // Determine if the message has an identifiable SubscriptionId
+        let subscription_id = match input.id() {
+            Some(subscription_id) => subscription_id,
+            None => return vec![],
+        };
+        let BybitMessage::Payload(message) = input else {
+            return vec![];
+        };
+
+        // Find InstrumentBookL1 associated with Input and apply the update
+        let book = match self.book_map.find_mut(&subscription_id) {
+            Ok(book) => book,
+            Err(unidentifiable) => return vec![Err(DataError::Socket(unidentifiable))],
+        };
+        book.update(&message);
+
+        // Only yield an OrderBookL1 once both sides of the book are known
+        book.snapshot(&message)
+            .map(|snapshot| {
+                Ok(MarketEvent {
+                    exchange_time: message.time,
+                    received_time: Utc::now(),
+                    exchange: ExchangeName::from(Exchange::ID),
+                    instrument: book.instrument.clone(),
+                    kind: snapshot,
+                })
+            })
+            .into_iter()
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod de {
+        use super::*;
+        use barter_integration::{
+            de::datetime_utc_from_epoch_duration, error::SocketError, model::SubscriptionId,
+        };
+        use std::time::Duration;
+
+        #[test]
+        fn test_bybit_order_book_l1() {
+            struct TestCase {
+                input: &'static str,
+                expected: Result<BybitOrderBookL1, SocketError>,
+            }
+
+            let tests = vec![
+                TestCase {
+                    // TC0: valid snapshot
+                    input: r#"
+                    {
+                        "topic": "orderbook.1.BTCUSDT",
+                        "type": "snapshot",
+                        "ts": 1672304484978,
+                        "data": {
+                            "s": "BTCUSDT",
+                            "b": [["16493.50", "0.006"]],
+                            "a": [["16611.00", "0.029"]],
+                            "u": 18521288,
+                            "seq": 7961638724
+                        },
+                        "cts": 1672304484976
+                    }
+                    "#,
+                    expected: Ok(BybitOrderBookL1 {
+                        subscription_id: SubscriptionId::from("orderbook.1|BTCUSDT"),
+                        r#type: "snapshot".to_string(),
+                        time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                            1672304484978,
+                        )),
+                        data: BybitOrderBookL1Inner {
+                            bids: vec![BybitLevel {
+                                price: 16493.50,
+                                amount: 0.006,
+                            }],
+                            asks: vec![BybitLevel {
+                                price: 16611.00,
+                                amount: 0.029,
+                            }],
+                            update_id: 18521288,
+                        },
+                    }),
+                },
+                TestCase {
+                    // TC1: valid delta w/ unchanged asks
+                    input: r#"
+                    {
+                        "topic": "orderbook.1.BTCUSDT",
+                        "type": "delta",
+                        "ts": 1672304484988,
+                        "data": {
+                            "s": "BTCUSDT",
+                            "b": [["16493.50", "0"], ["16493.00", "0.100"]],
+                            "a": [],
+                            "u": 18521289,
+                            "seq": 7961638725
+                        },
+                        "cts": 1672304484986
+                    }
+                    "#,
+                    expected: Ok(BybitOrderBookL1 {
+                        subscription_id: SubscriptionId::from("orderbook.1|BTCUSDT"),
+                        r#type: "delta".to_string(),
+                        time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                            1672304484988,
+                        )),
+                        data: BybitOrderBookL1Inner {
+                            bids: vec![
+                                BybitLevel {
+                                    price: 16493.50,
+                                    amount: 0.0,
+                                },
+                                BybitLevel {
+                                    price: 16493.00,
+                                    amount: 0.1,
+                                },
+                            ],
+                            asks: vec![],
+                            update_id: 18521289,
+                        },
+                    }),
+                },
+                TestCase {
+                    // TC2: invalid topic w/o market
+                    input: r#"
+                    {
+                        "topic": "orderbook.1",
+                        "type": "snapshot",
+                        "ts": 1672304484978,
+                        "data": {"s": "BTCUSDT", "b": [], "a": [], "u": 1, "seq": 1}
+                    }
+                    "#,
+                    expected: Err(SocketError::Unsupported {
+                        entity: "",
+                        item: "".to_string(),
+                    }),
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                let actual = serde_json::from_str::<BybitOrderBookL1>(test.input);
+                match (actual, test.expected) {
+                    (Ok(actual), Ok(expected)) => {
+                        assert_eq!(actual, expected, "TC{} failed", index)
+                    }
+                    (Err(_), Err(_)) => {
+                        // Test passed
+                    }
+                    (actual, expected) => {
+                        // Test failed
+                        panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_instrument_book_l1_update() {
+        let message =
+            |r#type: &str, bids: Vec<(f64, f64)>, asks: Vec<(f64, f64)>| BybitOrderBookL1 {
+                subscription_id: "orderbook.1|BTCUSDT".into(),
+                r#type: r#type.to_string(),
+                time: Utc::now(),
+                data: BybitOrderBookL1Inner {
+                    bids: bids
+                        .into_iter()
+                        .map(|(price, amount)| BybitLevel { price, amount })
+                        .collect(),
+                    asks: asks
+                        .into_iter()
+                        .map(|(price, amount)| BybitLevel { price, amount })
+                        .collect(),
+                    update_id: 0,
+                },
+            };
+
+        let mut book = InstrumentBookL1::new("btc_usdt");
+
+        // Snapshot w/ both sides yields an OrderBookL1
+        let snapshot = message("snapshot", vec![(100.0, 1.0)], vec![(101.0, 2.0)]);
+        book.update(&snapshot);
+        assert_eq!(
+            book.snapshot(&snapshot)
+                .map(|l1| (l1.best_bid, l1.best_ask)),
+            Some((Level::new(100.0, 1.0), Level::new(101.0, 2.0)))
+        );
+
+        // Delta replacing the best bid retains the unchanged best ask
+        let delta = message("delta", vec![(100.0, 0.0), (99.5, 3.0)], vec![]);
+        book.update(&delta);
+        assert_eq!(
+            book.snapshot(&delta).map(|l1| (l1.best_bid, l1.best_ask)),
+            Some((Level::new(99.5, 3.0), Level::new(101.0, 2.0)))
+        );
+
+        // Delta removing the best ask w/o a replacement yields no OrderBookL1
+        let delta = message("delta", vec![], vec![(101.0, 0.0)]);
+        book.update(&delta);
+        assert_eq!(book.snapshot(&delta), None);
+    }
+}
@@ -0,0 +1,392 @@
+use super::BybitLevel;
+use crate::{
+    error::DataError,
+    exchange::bybit::message::{BybitMessage, BybitPayload},
+    subscription::book::{pool::LevelPool, Level, OrderBook, OrderBookSide},
+    transformer::book::{InstrumentOrderBook, OrderBookUpdater},
+};
+use async_trait::async_trait;
+use barter_integration::{
+    model::{instrument::Instrument, Side},
+    protocol::websocket::WsMessage,
+};
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use tokio::sync::mpsc;
+
+/// Terse type alias for a [`Bybit`](super::super::Bybit) real-time OrderBook Level2 WebSocket
+/// message.
+pub type BybitOrderBookL2 = BybitMessage<BybitOrderBookL2Inner>;
+
+/// [`Bybit`](super::super::Bybit) real-time OrderBook Level2 (depth 50) data.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://bybit-exchange.github.io/docs/v5/websocket/public/orderbook>
+/// ```json
+/// {
+///     "topic": "orderbook.50.BTCUSDT",
+///     "type": "snapshot",
+///     "ts": 1672304484978,
+///     "data": {
+///         "s": "BTCUSDT",
+///         "b": [["16493.50", "0.006"], ["16493.00", "0.100"]],
+///         "a": [["16611.00", "0.029"], ["16612.00", "0.213"]],
+///         "u": 18521288,
+///         "seq": 7961638724
+///     },
+///     "cts": 1672304484976
+/// }
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct BybitOrderBookL2Inner {
+    #[serde(alias = "b")]
+    pub bids: Vec<BybitLevel>,
+    #[serde(alias = "a")]
+    pub asks: Vec<BybitLevel>,
+    #[serde(alias = "u")]
+    pub update_id: u64,
+    #[serde(alias = "seq")]
+    pub sequence: u64,
+}
+
+/// [`Bybit`](super::super::Bybit) [`OrderBookUpdater`] that maintains a local OrderBook Level2
+/// from the WebSocket "snapshot" & "delta" messages.
+///
+/// Bybit: How To Maintain A Local OrderBook
+/// 1. Subscribe to the "orderbook.50.{symbol}" topic.
+/// 2. The first message is a "snapshot" of the OrderBook. A new "snapshot" (eg/ after a service
+///    restart, where u is reset to 1) must replace the local OrderBook.
+/// 3. Each "delta" contains the absolute amount for a price level, where an amount of 0 removes
+///    the price level.
+/// 4. Each "delta" u should be equal to the previous message's u+1, and the cross sequence
+///    (seq) should be greater than the previous message's seq, otherwise the OrderBook must be
+///    re-initialised.
+///
+/// See docs: <https://bybit-exchange.github.io/docs/v5/websocket/public/orderbook>
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct BybitBookUpdater {
+    pub awaiting_snapshot: bool,
+    pub last_update_id: u64,
+    pub last_sequence: u64,
+}
+
+impl Default for BybitBookUpdater {
+    fn default() -> Self {
+        Self {
+            awaiting_snapshot: true,
+            last_update_id: 0,
+            last_sequence: 0,
+        }
+    }
+}
+
+impl BybitBookUpdater {
+    /// Bybit: How To Maintain A Local OrderBook: Step 4:
+    /// "Each delta u should be equal to the previous message's u+1, and the cross sequence
+    ///  should be greater than the previous message's seq."
+    ///
+    /// See docs: <https://bybit-exchange.github.io/docs/v5/websocket/public/orderbook>
+    pub fn validate_next_update(&self, update: &BybitOrderBookL2Inner) -> Result<(), DataError> {
+        if update.update_id != self.last_update_id + 1 {
+            Err(DataError::InvalidSequence {
+                prev_last_update_id: self.last_update_id,
+                first_update_id: update.update_id,
+            })
+        } else if update.sequence <= self.last_sequence {
+            Err(DataError::InvalidSequence {
+                prev_last_update_id: self.last_sequence,
+                first_update_id: update.sequence,
+            })
+        } else {
+            Ok(())
+        }
+    }
+}
+
+#[async_trait]
+impl OrderBookUpdater for BybitBookUpdater {
+    type OrderBook = OrderBook;
+    type Update = BybitOrderBookL2;
+
+    async fn init<Exchange, Kind>(
+        _: mpsc::UnboundedSender<WsMessage>,
+        instrument: Instrument,
+    ) -> Result<InstrumentOrderBook<Instrument, Self>, DataError>
+    where
+        Exchange: Send,
+        Kind: Send,
+    {
+        // Bybit sends the initial OrderBook snapshot over the WebSocket after subscribing
+        Ok(InstrumentOrderBook {
+            instrument,
+            updater: Self::default(),
+            book: OrderBook {
+                last_update_time: Utc::now(),
+                bids: OrderBookSide::new(Side::Buy, Vec::<Level>::new()),
+                asks: OrderBookSide::new(Side::Sell, Vec::<Level>::new()),
+            },
+        })
+    }
+
+    fn update(
+        &mut self,
+        book: &mut Self::OrderBook,
+        update: Self::Update,
+        pool: &mut LevelPool,
+    ) -> Result<Option<Self::OrderBook>, DataError> {
+        let BybitPayload {
+            r#type, time, data, ..
+        } = match update {
+            BybitMessage::Payload(payload) => payload,
+            BybitMessage::Response(_) => return Ok(None),
+        };
+
+        if r#type == "snapshot" {
+            // 2. A snapshot replaces the local OrderBook
+            *book = OrderBook {
+                last_update_time: time,
+                bids: OrderBookSide::new(Side::Buy, data.bids),
+                asks: OrderBookSide::new(Side::Sell, data.asks),
+            };
+            self.awaiting_snapshot = false;
+        } else if self.awaiting_snapshot {
+            // Drop deltas received before the initial snapshot
+            return Ok(None);
+        } else {
+            // 4. Validate u & seq continuity
+            self.validate_next_update(&data)?;
+
+            // 3. Upsert absolute amounts, where an amount of 0 removes the price level
+            book.last_update_time = time;
+            book.bids.upsert(data.bids);
+            book.asks.upsert(data.asks);
+        }
+
+        self.last_update_id = data.update_id;
+        self.last_sequence = data.sequence;
+
+        Ok(Some(book.snapshot_in(pool)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod de {
+        use super::*;
+        use barter_integration::{
+            de::datetime_utc_from_epoch_duration, error::SocketError, model::SubscriptionId,
+        };
+        use std::time::Duration;
+
+        #[test]
+        fn test_bybit_order_book_l2() {
+            struct TestCase {
+                input: &'static str,
+                expected: Result<BybitPayload<BybitOrderBookL2Inner>, SocketError>,
+            }
+
+            let tests = vec![
+                TestCase {
+                    // TC0: valid snapshot
+                    input: r#"
+                    {
+                        "topic": "orderbook.50.BTCUSDT",
+                        "type": "snapshot",
+                        "ts": 1672304484978,
+                        "data": {
+                            "s": "BTCUSDT",
+                            "b": [["16493.50", "0.006"]],
+                            "a": [["16611.00", "0.029"]],
+                            "u": 18521288,
+                            "seq": 7961638724
+                        },
+                        "cts": 1672304484976
+                    }
+                    "#,
+                    expected: Ok(BybitPayload {
+                        subscription_id: SubscriptionId::from("orderbook.50|BTCUSDT"),
+                        r#type: "snapshot".to_string(),
+                        time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                            1672304484978,
+                        )),
+                        data: BybitOrderBookL2Inner {
+                            bids: vec![BybitLevel {
+                                price: 16493.50,
+                                amount: 0.006,
+                            }],
+                            asks: vec![BybitLevel {
+                                price: 16611.00,
+                                amount: 0.029,
+                            }],
+                            update_id: 18521288,
+                            sequence: 7961638724,
+                        },
+                    }),
+                },
+                TestCase {
+                    // TC1: invalid delta w/o update_id
+                    input: r#"
+                    {
+                        "topic": "orderbook.50.BTCUSDT",
+                        "type": "delta",
+                        "ts": 1672304484978,
+                        "data": {
+                            "s": "BTCUSDT",
+                            "b": [],
+                            "a": [["16611.00", "0"]],
+                            "seq": 7961638725
+                        }
+                    }
+                    "#,
+                    expected: Err(SocketError::Unsupported {
+                        entity: "",
+                        item: "".to_string(),
+                    }),
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                let actual =
+                    serde_json::from_str::<BybitPayload<BybitOrderBookL2Inner>>(test.input);
+                match (actual, test.expected) {
+                    (Ok(actual), Ok(expected)) => {
+                        assert_eq!(actual, expected, "TC{} failed", index)
+                    }
+                    (Err(_), Err(_)) => {
+                        // Test passed
+                    }
+                    (actual, expected) => {
+                        // Test failed
+                        panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                    }
+                }
+            }
+        }
+    }
+
+    mod bybit_book_updater {
+        use super::*;
+        use barter_integration::model::SubscriptionId;
+
+        fn message(
+            r#type: &str,
+            update_id: u64,
+            sequence: u64,
+            bids: Vec<(f64, f64)>,
+            asks: Vec<(f64, f64)>,
+        ) -> BybitOrderBookL2 {
+            let levels = |levels: Vec<(f64, f64)>| {
+                levels
+                    .into_iter()
+                    .map(|(price, amount)| BybitLevel { price, amount })
+                    .collect()
+            };
+
+            BybitMessage::Payload(BybitPayload {
+                subscription_id: SubscriptionId::from("orderbook.50|BTCUSDT"),
+                r#type: r#type.to_string(),
+                time: Utc::now(),
+                data: BybitOrderBookL2Inner {
+                    bids: levels(bids),
+                    asks: levels(asks),
+                    update_id,
+                    sequence,
+                },
+            })
+        }
+
+        #[test]
+        fn test_update() {
+            struct TestCase {
+                updater: BybitBookUpdater,
+                input: BybitOrderBookL2,
+                // Expected total number of Levels in the OrderBook snapshot, if any
+                expected: Result<Option<usize>, DataError>,
+            }
+
+            let initialised = BybitBookUpdater {
+                awaiting_snapshot: false,
+                last_update_id: 100,
+                last_sequence: 1000,
+            };
+
+            let tests = vec![
+                TestCase {
+                    // TC0: delta received before the initial snapshot is dropped
+                    updater: BybitBookUpdater::default(),
+                    input: message("delta", 101, 1001, vec![(10.0, 1.0)], vec![]),
+                    expected: Ok(None),
+                },
+                TestCase {
+                    // TC1: snapshot initialises the OrderBook
+                    updater: BybitBookUpdater::default(),
+                    input: message("snapshot", 1, 1001, vec![(10.0, 1.0)], vec![(11.0, 1.0)]),
+                    expected: Ok(Some(2)),
+                },
+                TestCase {
+                    // TC2: valid next delta is applied
+                    updater: initialised,
+                    input: message("delta", 101, 1001, vec![(9.0, 1.0)], vec![]),
+                    expected: Ok(Some(3)),
+                },
+                TestCase {
+                    // TC3: delta w/ update_id gap is invalid
+                    updater: initialised,
+                    input: message("delta", 102, 1001, vec![(9.0, 1.0)], vec![]),
+                    expected: Err(DataError::InvalidSequence {
+                        prev_last_update_id: 100,
+                        first_update_id: 102,
+                    }),
+                },
+                TestCase {
+                    // TC4: delta w/ stale cross sequence is invalid
+                    updater: initialised,
+                    input: message("delta", 101, 999, vec![(9.0, 1.0)], vec![]),
+                    expected: Err(DataError::InvalidSequence {
+                        prev_last_update_id: 1000,
+                        first_update_id: 999,
+                    }),
+                },
+                TestCase {
+                    // TC5: subscription response is ignored
+                    updater: initialised,
+                    input: serde_json::from_str(
+                        r#"{"success":true,"ret_msg":"subscribe","conn_id":"x","op":"subscribe"}"#,
+                    )
+                    .unwrap(),
+                    expected: Ok(None),
+                },
+            ];
+
+            for (index, mut test) in tests.into_iter().enumerate() {
+                let mut book = OrderBook {
+                    last_update_time: Utc::now(),
+                    bids: OrderBookSide::new(Side::Buy, vec![Level::new(10.0, 1.0)]),
+                    asks: OrderBookSide::new(Side::Sell, vec![Level::new(11.0, 1.0)]),
+                };
+
+                let actual = test
+                    .updater
+                    .update(&mut book, test.input, &mut LevelPool::default())
+                    .map(|book| {
+                        book.map(|book| book.bids.levels().len() + book.asks.levels().len())
+                    });
+
+                match (actual, test.expected) {
+                    (Ok(actual), Ok(expected)) => {
+                        assert_eq!(actual, expected, "TC{} failed", index)
+                    }
+                    (Err(_), Err(_)) => {
+                        // Test passed
+                    }
+                    (actual, expected) => {
+                        // Test failed
+                        panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                    }
+                }
+            }
+        }
+    }
+}
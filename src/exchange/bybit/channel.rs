@@ -1,6 +1,12 @@
 use crate::{
-    exchange::bybit::Bybit,
-    subscription::{trade::PublicTrades, Subscription},
+    exchange::bybit::{futures::BybitPerpetualsUsd, Bybit},
+    subscription::{
+        book::{OrderBooksL1, OrderBooksL2},
+        funding::FundingRates,
+        liquidation::Liquidations,
+        trade::PublicTrades,
+        Subscription,
+    },
     Identifier,
 };
 use serde::Serialize;
@@ -17,6 +23,31 @@ impl BybitChannel {
     ///
     /// See docs: <https://bybit-exchange.github.io/docs/v5/websocket/public/trade>
     pub const TRADES: Self = Self("publicTrade");
+
+    /// [`Bybit`] real-time liquidations channel name.
+    ///
+    /// See docs: <https://bybit-exchange.github.io/docs/v5/websocket/public/all-liquidation>
+    pub const LIQUIDATIONS: Self = Self("allLiquidation");
+
+    /// [`Bybit`] real-time tickers channel name.
+    ///
+    /// See docs: <https://bybit-exchange.github.io/docs/v5/websocket/public/ticker>
+    pub const TICKERS: Self = Self("tickers");
+
+    /// [`Bybit`] real-time OrderBook Level1 (top of book) channel name.
+    ///
+    /// See docs: <https://bybit-exchange.github.io/docs/v5/websocket/public/orderbook>
+    pub const ORDER_BOOK_L1: Self = Self("orderbook.1");
+
+    /// [`Bybit`] real-time OrderBook Level2 (depth 50) channel name.
+    ///
+    /// See docs: <https://bybit-exchange.github.io/docs/v5/websocket/public/orderbook>
+    pub const ORDER_BOOK_L2: Self = Self("orderbook.50");
+
+    /// [`Bybit`] real-time OrderBook Level2 (depth 200) channel name.
+    ///
+    /// See docs: <https://bybit-exchange.github.io/docs/v5/websocket/public/orderbook>
+    pub const ORDER_BOOK_L2_200: Self = Self("orderbook.200");
 }
 
 impl<Server, Instrument> Identifier<BybitChannel>
@@ -27,8 +58,104 @@ impl<Server, Instrument> Identifier<BybitChannel>
     }
 }
 
+impl<Server, Instrument> Identifier<BybitChannel>
+    for Subscription<Bybit<Server>, Instrument, Liquidations>
+{
+    fn id(&self) -> BybitChannel {
+        BybitChannel::LIQUIDATIONS
+    }
+}
+
+impl<Server, Instrument> Identifier<BybitChannel>
+    for Subscription<Bybit<Server>, Instrument, OrderBooksL1>
+{
+    fn id(&self) -> BybitChannel {
+        BybitChannel::ORDER_BOOK_L1
+    }
+}
+
+impl<Server, Instrument> Identifier<BybitChannel>
+    for Subscription<Bybit<Server>, Instrument, OrderBooksL2>
+{
+    fn id(&self) -> BybitChannel {
+        // Map the requested depth to the closest depth supported by both spot & derivatives
+        match self.kind.depth {
+            Some(1) => BybitChannel::ORDER_BOOK_L1,
+            None | Some(0..=50) => BybitChannel::ORDER_BOOK_L2,
+            Some(_) => BybitChannel::ORDER_BOOK_L2_200,
+        }
+    }
+}
+
+impl<Instrument> Identifier<BybitChannel>
+    for Subscription<BybitPerpetualsUsd, Instrument, FundingRates>
+{
+    fn id(&self) -> BybitChannel {
+        BybitChannel::TICKERS
+    }
+}
+
 impl AsRef<str> for BybitChannel {
     fn as_ref(&self) -> &str {
         self.0
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::exchange::bybit::spot::BybitSpot;
+    use barter_integration::model::instrument::{kind::InstrumentKind, Instrument};
+
+    #[test]
+    fn test_order_books_l2_depth_channel() {
+        struct TestCase {
+            input: OrderBooksL2,
+            expected: BybitChannel,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: default depth uses the depth 50 channel
+                input: OrderBooksL2::default(),
+                expected: BybitChannel::ORDER_BOOK_L2,
+            },
+            TestCase {
+                // TC1: depth 1 uses the depth 1 channel
+                input: OrderBooksL2::with_depth(1),
+                expected: BybitChannel("orderbook.1"),
+            },
+            TestCase {
+                // TC2: depth 20 is rounded up to the depth 50 channel
+                input: OrderBooksL2::with_depth(20),
+                expected: BybitChannel::ORDER_BOOK_L2,
+            },
+            TestCase {
+                // TC3: depth 200 uses the depth 200 channel
+                input: OrderBooksL2::with_depth(200),
+                expected: BybitChannel::ORDER_BOOK_L2_200,
+            },
+            TestCase {
+                // TC4: depth beyond 200 is capped to the depth 200 channel
+                input: OrderBooksL2::with_depth(1000),
+                expected: BybitChannel::ORDER_BOOK_L2_200,
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let subscription = Subscription::<BybitSpot, Instrument, OrderBooksL2>::from((
+                BybitSpot::default(),
+                "btc",
+                "usdt",
+                InstrumentKind::Spot,
+                test.input,
+            ));
+            assert_eq!(
+                Identifier::<BybitChannel>::id(&subscription),
+                test.expected,
+                "TC{} failed",
+                index
+            );
+        }
+    }
+}
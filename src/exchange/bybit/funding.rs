@@ -0,0 +1,175 @@
+use crate::{
+    event::{MarketEvent, MarketIter},
+    exchange::{bybit::message::BybitPayload, ExchangeId},
+    subscription::funding::FundingRate,
+};
+use barter_integration::model::Exchange;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Terse type alias for a [`BybitPerpetualsUsd`](super::futures::BybitPerpetualsUsd) real-time
+/// tickers WebSocket message.
+pub type BybitFundingRate = BybitPayload<BybitTicker>;
+
+/// [`BybitPerpetualsUsd`](super::futures::BybitPerpetualsUsd) real-time ticker, of which only the
+/// funding fields are used.
+///
+/// ### Notes
+/// Bybit sends an initial "snapshot" ticker followed by "delta" tickers that only contain the
+/// fields that have changed, so the funding fields are optional.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://bybit-exchange.github.io/docs/v5/websocket/public/ticker>
+/// ```json
+/// {
+///     "symbol": "BTCUSDT",
+///     "tickDirection": "PlusTick",
+///     "lastPrice": "17216.00",
+///     "markPrice": "17217.33",
+///     "indexPrice": "17227.36",
+///     "nextFundingTime": "1673280000000",
+///     "fundingRate": "-0.000212",
+///     "bid1Price": "17215.50",
+///     "ask1Price": "17216.00"
+/// }
+/// ```
+#[derive(Clone, Copy, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BybitTicker {
+    #[serde(
+        default,
+        deserialize_with = "crate::exchange::coinbase::book::l3::de_option_str_f64"
+    )]
+    pub funding_rate: Option<f64>,
+
+    #[serde(
+        default,
+        deserialize_with = "crate::util::time::de_option_str_epoch_ms_as_datetime_utc"
+    )]
+    pub next_funding_time: Option<DateTime<Utc>>,
+}
+
+impl<InstrumentId> From<(ExchangeId, InstrumentId, BybitFundingRate)>
+    for MarketIter<InstrumentId, FundingRate>
+{
+    fn from(
+        (exchange_id, instrument, ticker): (ExchangeId, InstrumentId, BybitFundingRate),
+    ) -> Self {
+        // Delta tickers without a funding rate change do not generate a FundingRate
+        let Some(rate) = ticker.data.funding_rate else {
+            return Self(vec![]);
+        };
+
+        Self(vec![Ok(MarketEvent {
+            exchange_time: ticker.time,
+            received_time: Utc::now(),
+            exchange: Exchange::from(exchange_id),
+            instrument,
+            kind: FundingRate {
+                rate,
+                next_funding_time: ticker.data.next_funding_time,
+            },
+        })])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod de {
+        use super::*;
+        use barter_integration::{
+            de::datetime_utc_from_epoch_duration, error::SocketError, model::SubscriptionId,
+        };
+        use std::time::Duration;
+
+        #[test]
+        fn test_bybit_funding_rate() {
+            struct TestCase {
+                input: &'static str,
+                expected: Result<BybitFundingRate, SocketError>,
+            }
+
+            let tests = vec![
+                TestCase {
+                    // TC0: snapshot ticker w/ funding fields
+                    input: r#"
+                    {
+                        "topic": "tickers.BTCUSDT",
+                        "type": "snapshot",
+                        "data": {
+                            "symbol": "BTCUSDT",
+                            "tickDirection": "PlusTick",
+                            "lastPrice": "17216.00",
+                            "markPrice": "17217.33",
+                            "indexPrice": "17227.36",
+                            "nextFundingTime": "1673280000000",
+                            "fundingRate": "-0.000212",
+                            "bid1Price": "17215.50",
+                            "ask1Price": "17216.00"
+                        },
+                        "cs": 24987956059,
+                        "ts": 1673272861686
+                    }
+                    "#,
+                    expected: Ok(BybitFundingRate {
+                        subscription_id: SubscriptionId::from("tickers|BTCUSDT"),
+                        r#type: "snapshot".to_string(),
+                        time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                            1673272861686,
+                        )),
+                        data: BybitTicker {
+                            funding_rate: Some(-0.000212),
+                            next_funding_time: Some(datetime_utc_from_epoch_duration(
+                                Duration::from_millis(1673280000000),
+                            )),
+                        },
+                    }),
+                },
+                TestCase {
+                    // TC1: delta ticker w/o funding fields
+                    input: r#"
+                    {
+                        "topic": "tickers.BTCUSDT",
+                        "type": "delta",
+                        "data": {
+                            "symbol": "BTCUSDT",
+                            "bid1Price": "17215.00"
+                        },
+                        "cs": 24987956060,
+                        "ts": 1673272861786
+                    }
+                    "#,
+                    expected: Ok(BybitFundingRate {
+                        subscription_id: SubscriptionId::from("tickers|BTCUSDT"),
+                        r#type: "delta".to_string(),
+                        time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                            1673272861786,
+                        )),
+                        data: BybitTicker {
+                            funding_rate: None,
+                            next_funding_time: None,
+                        },
+                    }),
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                let actual = serde_json::from_str::<BybitFundingRate>(test.input);
+                match (actual, test.expected) {
+                    (Ok(actual), Ok(expected)) => {
+                        assert_eq!(actual, expected, "TC{} failed", index)
+                    }
+                    (Err(_), Err(_)) => {
+                        // Test passed
+                    }
+                    (actual, expected) => {
+                        // Test failed
+                        panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                    }
+                }
+            }
+        }
+    }
+}
@@ -1,5 +1,14 @@
-use super::{Bybit, ExchangeServer};
-use crate::exchange::ExchangeId;
+use super::{
+    funding::BybitTicker, liquidation::BybitLiquidationInner, message::BybitMessage, Bybit,
+    ExchangeServer,
+};
+use crate::{
+    exchange::{ExchangeId, StreamSelector},
+    instrument::InstrumentData,
+    subscription::{funding::FundingRates, liquidation::Liquidations},
+    transformer::stateless::StatelessTransformer,
+    ExchangeWsStream,
+};
 
 /// [`BybitPerpetualsUsd`] WebSocket server base url.
 ///
@@ -20,3 +29,26 @@ impl ExchangeServer for BybitServerPerpetualsUsd {
         WEBSOCKET_BASE_URL_BYBIT_PERPETUALS_USD
     }
 }
+
+impl<Instrument> StreamSelector<Instrument, Liquidations> for BybitPerpetualsUsd
+where
+    Instrument: InstrumentData,
+{
+    type Stream = ExchangeWsStream<
+        StatelessTransformer<
+            Self,
+            Instrument::Id,
+            Liquidations,
+            BybitMessage<Vec<BybitLiquidationInner>>,
+        >,
+    >;
+}
+
+impl<Instrument> StreamSelector<Instrument, FundingRates> for BybitPerpetualsUsd
+where
+    Instrument: InstrumentData,
+{
+    type Stream = ExchangeWsStream<
+        StatelessTransformer<Self, Instrument::Id, FundingRates, BybitMessage<BybitTicker>>,
+    >;
+}
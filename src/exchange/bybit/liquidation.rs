@@ -0,0 +1,197 @@
+use crate::{
+    event::{MarketEvent, MarketIter},
+    exchange::{bybit::message::BybitPayload, ExchangeId},
+    subscription::liquidation::Liquidation,
+};
+use barter_integration::model::{Exchange, Side};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Terse type alias for a [`BybitPerpetualsUsd`](super::futures::BybitPerpetualsUsd) real-time
+/// liquidations WebSocket message.
+pub type BybitLiquidation = BybitPayload<Vec<BybitLiquidationInner>>;
+
+/// [`BybitPerpetualsUsd`](super::futures::BybitPerpetualsUsd) real-time liquidation.
+///
+/// ### Notes
+/// The "S" field is the side of the liquidated position, ie/ "Buy" indicates a long position
+/// was liquidated. This is the opposite of the liquidation order side, which is the [`Side`] used
+/// by the normalised Barter [`Liquidation`].
+///
+/// ### Raw Payload Examples
+/// See docs: <https://bybit-exchange.github.io/docs/v5/websocket/public/all-liquidation>
+/// ```json
+/// {
+///     "T": 1739502302929,
+///     "s": "ROSEUSDT",
+///     "S": "Sell",
+///     "v": "20000",
+///     "p": "0.04499"
+/// }
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct BybitLiquidationInner {
+    #[serde(
+        alias = "T",
+        deserialize_with = "crate::util::time::de_u64_epoch_ms_as_datetime_utc"
+    )]
+    pub time: DateTime<Utc>,
+
+    #[serde(rename = "s")]
+    pub market: String,
+
+    /// [`Side`] of the liquidated position.
+    #[serde(rename = "S")]
+    pub position_side: Side,
+
+    #[serde(alias = "v", deserialize_with = "barter_integration::de::de_str")]
+    pub quantity: f64,
+
+    #[serde(alias = "p", deserialize_with = "barter_integration::de::de_str")]
+    pub price: f64,
+}
+
+impl<InstrumentId: Clone> From<(ExchangeId, InstrumentId, BybitLiquidation)>
+    for MarketIter<InstrumentId, Liquidation>
+{
+    fn from(
+        (exchange_id, instrument, liquidations): (ExchangeId, InstrumentId, BybitLiquidation),
+    ) -> Self {
+        liquidations
+            .data
+            .into_iter()
+            .map(|liquidation| {
+                Ok(MarketEvent {
+                    exchange_time: liquidation.time,
+                    received_time: Utc::now(),
+                    exchange: Exchange::from(exchange_id),
+                    instrument: instrument.clone(),
+                    kind: Liquidation {
+                        side: match liquidation.position_side {
+                            Side::Buy => Side::Sell,
+                            Side::Sell => Side::Buy,
+                        },
+                        price: liquidation.price,
+                        quantity: liquidation.quantity,
+                        time: liquidation.time,
+                    },
+                })
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod de {
+        use super::*;
+        use barter_integration::{
+            de::datetime_utc_from_epoch_duration, error::SocketError, model::SubscriptionId,
+        };
+        use std::time::Duration;
+
+        #[test]
+        fn test_bybit_liquidation() {
+            struct TestCase {
+                input: &'static str,
+                expected: Result<BybitLiquidation, SocketError>,
+            }
+
+            let tests = vec![
+                // TC0: input BybitLiquidation is deserialised
+                TestCase {
+                    input: r#"
+                        {
+                            "topic": "allLiquidation.ROSEUSDT",
+                            "type": "snapshot",
+                            "ts": 1739502303204,
+                            "data": [
+                                {
+                                    "T": 1739502302929,
+                                    "s": "ROSEUSDT",
+                                    "S": "Sell",
+                                    "v": "20000",
+                                    "p": "0.04499"
+                                }
+                            ]
+                        }
+                    "#,
+                    expected: Ok(BybitLiquidation {
+                        subscription_id: SubscriptionId::from("allLiquidation|ROSEUSDT"),
+                        r#type: "snapshot".to_string(),
+                        time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                            1739502303204,
+                        )),
+                        data: vec![BybitLiquidationInner {
+                            time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                                1739502302929,
+                            )),
+                            market: "ROSEUSDT".to_string(),
+                            position_side: Side::Sell,
+                            quantity: 20000.0,
+                            price: 0.04499,
+                        }],
+                    }),
+                },
+                // TC1: input BybitLiquidation is invalid w/ unknown topic
+                TestCase {
+                    input: r#"
+                        {
+                            "topic": "liquidation.ROSEUSDT",
+                            "type": "snapshot",
+                            "ts": 1739502303204,
+                            "data": []
+                        }
+                    "#,
+                    expected: Err(SocketError::Unsupported {
+                        entity: "",
+                        item: "".to_string(),
+                    }),
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                let actual = serde_json::from_str::<BybitLiquidation>(test.input);
+                match (actual, test.expected) {
+                    (Ok(actual), Ok(expected)) => {
+                        assert_eq!(actual, expected, "TC{} failed", index)
+                    }
+                    (Err(_), Err(_)) => {
+                        // Test passed
+                    }
+                    (actual, expected) => {
+                        // Test failed
+                        panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_bybit_liquidation_side_is_order_side() {
+        use barter_integration::model::SubscriptionId;
+
+        let liquidation = BybitLiquidation {
+            subscription_id: SubscriptionId::from("allLiquidation|ROSEUSDT"),
+            r#type: "snapshot".to_string(),
+            time: Utc::now(),
+            data: vec![BybitLiquidationInner {
+                time: Utc::now(),
+                market: "ROSEUSDT".to_string(),
+                position_side: Side::Buy,
+                quantity: 1.0,
+                price: 1.0,
+            }],
+        };
+
+        let events =
+            MarketIter::<u64, Liquidation>::from((ExchangeId::BybitPerpetualsUsd, 0, liquidation))
+                .0;
+
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].as_ref().unwrap().kind.side, Side::Sell);
+    }
+}
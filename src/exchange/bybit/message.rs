@@ -1,10 +1,9 @@
 use crate::{
     event::MarketIter,
     exchange::{
-        bybit::{channel::BybitChannel, subscription::BybitResponse, trade::BybitTrade},
+        bybit::{channel::BybitChannel, subscription::BybitResponse},
         ExchangeId,
     },
-    subscription::trade::PublicTrade,
     Identifier,
 };
 use barter_integration::model::SubscriptionId;
@@ -14,12 +13,13 @@ use serde::{
     Deserialize, Serialize,
 };
 
-/// [`Bybit`](super::Bybit) websocket message supports both [`BybitTrade`](BybitTrade) and [`BybitResponse`](BybitResponse) .
-#[derive(Debug, Serialize, Deserialize)]
+/// [`Bybit`](super::Bybit) websocket message supports both a market data [`BybitPayload<T>`]
+/// (eg/ [`BybitTrade`](super::trade::BybitTrade)) and a [`BybitResponse`](BybitResponse).
+#[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(untagged)]
-pub enum BybitMessage {
+pub enum BybitMessage<T> {
     Response(BybitResponse),
-    Trade(BybitTrade),
+    Payload(BybitPayload<T>),
 }
 
 /// ### Raw Payload Examples
@@ -54,28 +54,35 @@ pub struct BybitPayload<T> {
 
     #[serde(
         alias = "ts",
-        deserialize_with = "barter_integration::de::de_u64_epoch_ms_as_datetime_utc"
+        deserialize_with = "crate::util::time::de_u64_epoch_ms_as_datetime_utc"
     )]
     pub time: DateTime<Utc>,
     pub data: T,
 }
 
-/// Deserialize a [`BybitPayload`] "s" (eg/ "publicTrade.BTCUSDT") as the associated
+/// Deserialize a [`BybitPayload`] "topic" (eg/ "publicTrade.BTCUSDT") as the associated
 /// [`SubscriptionId`].
 ///
 /// eg/ "publicTrade|BTCUSDT"
+///
+/// Note that some channels contain a '.' themselves (eg/ "orderbook.1.BTCUSDT"), so the market is
+/// always the final token.
 pub fn de_message_subscription_id<'de, D>(deserializer: D) -> Result<SubscriptionId, D::Error>
 where
     D: serde::de::Deserializer<'de>,
 {
     let input = <&str as serde::Deserialize>::deserialize(deserializer)?;
-    let mut tokens = input.split('.');
 
-    match (tokens.next(), tokens.next(), tokens.next()) {
-        (Some("publicTrade"), Some(market), None) => Ok(SubscriptionId::from(format!(
-            "{}|{market}",
-            BybitChannel::TRADES.0
-        ))),
+    match input.rsplit_once('.') {
+        Some((channel, market))
+            if channel == BybitChannel::TRADES.0
+                || channel == BybitChannel::LIQUIDATIONS.0
+                || channel == BybitChannel::TICKERS.0
+                || channel == BybitChannel::ORDER_BOOK_L1.0
+                || channel == BybitChannel::ORDER_BOOK_L2.0 =>
+        {
+            Ok(SubscriptionId::from(format!("{channel}|{market}")))
+        }
         _ => Err(Error::invalid_value(
             Unexpected::Str(input),
             &"invalid message type expected pattern: <type>.<symbol>",
@@ -83,22 +90,26 @@ where
     }
 }
 
-impl Identifier<Option<SubscriptionId>> for BybitMessage {
+impl<T> Identifier<Option<SubscriptionId>> for BybitMessage<T> {
     fn id(&self) -> Option<SubscriptionId> {
         match self {
-            BybitMessage::Trade(trade) => Some(trade.subscription_id.clone()),
-            _ => None,
+            BybitMessage::Payload(payload) => Some(payload.subscription_id.clone()),
+            BybitMessage::Response(_) => None,
         }
     }
 }
 
-impl<InstrumentId: Clone> From<(ExchangeId, InstrumentId, BybitMessage)>
-    for MarketIter<InstrumentId, PublicTrade>
+impl<InstrumentId, T, Output> From<(ExchangeId, InstrumentId, BybitMessage<T>)>
+    for MarketIter<InstrumentId, Output>
+where
+    MarketIter<InstrumentId, Output>: From<(ExchangeId, InstrumentId, BybitPayload<T>)>,
 {
-    fn from((exchange_id, instrument, message): (ExchangeId, InstrumentId, BybitMessage)) -> Self {
+    fn from(
+        (exchange_id, instrument, message): (ExchangeId, InstrumentId, BybitMessage<T>),
+    ) -> Self {
         match message {
             BybitMessage::Response(_) => Self(vec![]),
-            BybitMessage::Trade(trade) => Self::from((exchange_id, instrument, trade)),
+            BybitMessage::Payload(payload) => Self::from((exchange_id, instrument, payload)),
         }
     }
 }
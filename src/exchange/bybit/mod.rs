@@ -2,27 +2,43 @@ use crate::instrument::InstrumentData;
 use crate::{
     exchange::{
         bybit::{
-            channel::BybitChannel, market::BybitMarket, message::BybitMessage,
-            subscription::BybitResponse,
+            book::{l1::BybitBookL1Transformer, l2::BybitBookUpdater},
+            channel::BybitChannel,
+            market::BybitMarket,
+            message::BybitMessage,
+            subscription::{BybitResponse, BybitReturnMessage},
+            trade::BybitTradeInner,
         },
         subscription::ExchangeSub,
-        Connector, ExchangeId, ExchangeServer, PingInterval, StreamSelector,
+        Connector, ExchangeId, ExchangeServer, Keepalive, PongTimeout, StreamSelector,
     },
     subscriber::{validator::WebSocketSubValidator, WebSocketSubscriber},
-    subscription::{trade::PublicTrades, Map},
-    transformer::stateless::StatelessTransformer,
+    subscription::{
+        book::{OrderBooksL1, OrderBooksL2},
+        trade::PublicTrades,
+        Map,
+    },
+    transformer::{book::MultiBookTransformer, stateless::StatelessTransformer},
     ExchangeWsStream,
 };
-use barter_integration::{error::SocketError, protocol::websocket::WsMessage};
+use barter_integration::{
+    error::SocketError, model::instrument::Instrument, protocol::websocket::WsMessage,
+};
 use serde::de::{Error, Unexpected};
 use std::{fmt::Debug, marker::PhantomData, time::Duration};
-use tokio::time;
 use url::Url;
 
+/// OrderBook types common to both [`BybitSpot`](spot::BybitSpot) and
+/// [`BybitFuturesUsd`](futures::BybitPerpetualsUsd).
+pub mod book;
+
 /// Defines the type that translates a Barter [`Subscription`](crate::subscription::Subscription)
 /// into an exchange [`Connector`] specific channel used for generating [`Connector::requests`].
 pub mod channel;
 
+/// Funding rate types for [`BybitFuturesUsd`](futures::BybitPerpetualsUsd).
+pub mod funding;
+
 /// [`ExchangeServer`] and [`StreamSelector`] implementations for
 /// [`BybitFuturesUsd`](futures::BybitPerpetualsUsd).
 pub mod futures;
@@ -31,6 +47,9 @@ pub mod futures;
 /// into an exchange [`Connector`] specific market used for generating [`Connector::requests`].
 pub mod market;
 
+/// Liquidation types for [`BybitFuturesUsd`](futures::BybitPerpetualsUsd).
+pub mod liquidation;
+
 /// Generic [`BybitPayload<T>`](message::BybitPayload) type common to
 /// [`BybitSpot`](spot::BybitSpot)
 pub mod message;
@@ -48,6 +67,16 @@ pub mod subscription;
 /// [`BybitFuturesUsd`](futures::BybitPerpetualsUsd).
 pub mod trade;
 
+/// [`Bybit`] server [`Keepalive`] ping interval.
+///
+/// See docs: <https://bybit-exchange.github.io/docs/v5/ws/connect#how-to-send-the-heartbeat-packet>
+pub const PING_INTERVAL_BYBIT: Duration = Duration::from_millis(5_000);
+
+/// [`Bybit`] server [`PongTimeout`] duration, allowing for several missed pong responses.
+///
+/// See docs: <https://bybit-exchange.github.io/docs/v5/ws/connect#how-to-send-the-heartbeat-packet>
+pub const PONG_TIMEOUT_BYBIT: Duration = Duration::from_secs(20);
+
 /// Generic [`Bybit<Server>`](Bybit) exchange.
 ///
 /// ### Notes
@@ -73,9 +102,9 @@ where
         Url::parse(Server::websocket_url()).map_err(SocketError::UrlParse)
     }
 
-    fn ping_interval() -> Option<PingInterval> {
-        Some(PingInterval {
-            interval: time::interval(Duration::from_millis(5_000)),
+    fn keepalive() -> Keepalive {
+        Keepalive::ApplicationJson {
+            interval: PING_INTERVAL_BYBIT,
             ping: || {
                 WsMessage::Text(
                     serde_json::json!({
@@ -84,6 +113,17 @@ where
                     .to_string(),
                 )
             },
+        }
+    }
+
+    fn pong_timeout() -> Option<PongTimeout> {
+        Some(PongTimeout {
+            timeout: PONG_TIMEOUT_BYBIT,
+            is_pong: |message| match message {
+                WsMessage::Text(text) => serde_json::from_str::<BybitResponse>(text)
+                    .is_ok_and(|response| response.ret_msg == BybitReturnMessage::Pong),
+                _ => false,
+            },
         })
     }
 
@@ -111,9 +151,31 @@ impl<Instrument, Server> StreamSelector<Instrument, PublicTrades> for Bybit<Serv
 where
     Instrument: InstrumentData,
     Server: ExchangeServer + Debug + Send + Sync,
+{
+    type Stream = ExchangeWsStream<
+        StatelessTransformer<
+            Self,
+            Instrument::Id,
+            PublicTrades,
+            BybitMessage<Vec<BybitTradeInner>>,
+        >,
+    >;
+}
+
+impl<Instrument, Server> StreamSelector<Instrument, OrderBooksL1> for Bybit<Server>
+where
+    Instrument: InstrumentData,
+    Server: ExchangeServer + Debug + Send + Sync,
+{
+    type Stream = ExchangeWsStream<BybitBookL1Transformer<Self, Instrument::Id>>;
+}
+
+impl<Server> StreamSelector<Instrument, OrderBooksL2> for Bybit<Server>
+where
+    Server: ExchangeServer + Debug + Send + Sync + 'static,
 {
     type Stream =
-        ExchangeWsStream<StatelessTransformer<Self, Instrument::Id, PublicTrades, BybitMessage>>;
+        ExchangeWsStream<MultiBookTransformer<Self, Instrument, OrderBooksL2, BybitBookUpdater>>;
 }
 
 impl<'de, Server> serde::Deserialize<'de> for Bybit<Server>
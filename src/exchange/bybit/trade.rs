@@ -29,7 +29,7 @@ pub type BybitTrade = BybitPayload<Vec<BybitTradeInner>>;
 pub struct BybitTradeInner {
     #[serde(
         alias = "T",
-        deserialize_with = "barter_integration::de::de_u64_epoch_ms_as_datetime_utc"
+        deserialize_with = "crate::util::time::de_u64_epoch_ms_as_datetime_utc"
     )]
     pub time: DateTime<Utc>,
 
@@ -79,6 +79,7 @@ impl<InstrumentId: Clone> From<(ExchangeId, InstrumentId, BybitTrade)>
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::subscription::trade::tests::assert_duplicate_frame_dedup_keys;
 
     mod de {
         use super::*;
@@ -330,4 +331,40 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn test_bybit_trade_duplicate_frame_dedup_keys() {
+        assert_duplicate_frame_dedup_keys::<BybitTrade>(
+            ExchangeId::BybitSpot,
+            r#"
+            {
+                "topic": "publicTrade.BTCUSDT",
+                "type": "snapshot",
+                "ts": 1672304486868,
+                "data": [
+                    {
+                        "T": 1672304486865,
+                        "s": "BTCUSDT",
+                        "S": "Buy",
+                        "v": "0.001",
+                        "p": "16578.50",
+                        "L": "PlusTick",
+                        "i": "20f43950-d8dd-5b31-9112-a178eb6023af",
+                        "BT": false
+                    },
+                    {
+                        "T": 1672304486865,
+                        "s": "BTCUSDT",
+                        "S": "Buy",
+                        "v": "0.001",
+                        "p": "16578.50",
+                        "L": "ZeroPlusTick",
+                        "i": "6bd6e6e6-3a3d-5a1c-8c1e-6a0a4d3f9b27",
+                        "BT": false
+                    }
+                ]
+            }
+            "#,
+        );
+    }
 }
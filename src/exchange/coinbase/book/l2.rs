@@ -0,0 +1,431 @@
+use super::super::CoinbaseChannel;
+use crate::{
+    error::DataError,
+    exchange::ExchangeSub,
+    subscription::book::{pool::LevelPool, Level, OrderBook, OrderBookSide},
+    transformer::book::{InstrumentOrderBook, OrderBookUpdater},
+    Identifier,
+};
+use async_trait::async_trait;
+use barter_integration::{
+    model::{instrument::Instrument, Side, SubscriptionId},
+    protocol::websocket::WsMessage,
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use tokio::sync::mpsc;
+
+/// [`Coinbase`](super::super::Coinbase) real-time level2 channel WebSocket message.
+///
+/// The first message received after subscribing is a `snapshot` of the OrderBook, followed by
+/// `l2update` messages containing the changed price levels.
+///
+/// See docs: <https://docs.cloud.coinbase.com/exchange/docs/websocket-channels#level2-channel>
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum CoinbaseOrderBookL2 {
+    Snapshot(CoinbaseOrderBookL2Snapshot),
+    L2update(CoinbaseOrderBookL2Update),
+}
+
+impl Identifier<Option<SubscriptionId>> for CoinbaseOrderBookL2 {
+    fn id(&self) -> Option<SubscriptionId> {
+        match self {
+            Self::Snapshot(snapshot) => Some(snapshot.subscription_id.clone()),
+            Self::L2update(update) => Some(update.subscription_id.clone()),
+        }
+    }
+}
+
+/// [`Coinbase`](super::super::Coinbase) level2 channel `snapshot` message.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://docs.cloud.coinbase.com/exchange/docs/websocket-channels#level2-channel>
+/// ```json
+/// {
+///     "type": "snapshot",
+///     "product_id": "BTC-USD",
+///     "bids": [["10101.10", "0.45054140"]],
+///     "asks": [["10102.55", "0.57753524"]]
+/// }
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct CoinbaseOrderBookL2Snapshot {
+    #[serde(alias = "product_id", deserialize_with = "de_ob_l2_subscription_id")]
+    pub subscription_id: SubscriptionId,
+    pub bids: Vec<CoinbaseLevel>,
+    pub asks: Vec<CoinbaseLevel>,
+}
+
+/// [`Coinbase`](super::super::Coinbase) level2 channel `l2update` message.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://docs.cloud.coinbase.com/exchange/docs/websocket-channels#level2-channel>
+/// ```json
+/// {
+///     "type": "l2update",
+///     "product_id": "BTC-USD",
+///     "time": "2019-08-14T20:42:27.265Z",
+///     "changes": [["buy", "10101.80000000", "0.162567"]]
+/// }
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct CoinbaseOrderBookL2Update {
+    #[serde(alias = "product_id", deserialize_with = "de_ob_l2_subscription_id")]
+    pub subscription_id: SubscriptionId,
+    pub time: DateTime<Utc>,
+    pub changes: Vec<CoinbaseLevelChange>,
+}
+
+/// [`Coinbase`](super::super::Coinbase) level2 `snapshot` price level.
+///
+/// ### Raw Payload Examples
+/// ```json
+/// ["10101.10", "0.45054140"]
+/// ```
+#[derive(Clone, Copy, PartialEq, PartialOrd, Debug, Serialize)]
+pub struct CoinbaseLevel {
+    pub price: f64,
+    pub amount: f64,
+}
+
+impl From<CoinbaseLevel> for Level {
+    fn from(level: CoinbaseLevel) -> Self {
+        Self {
+            price: level.price,
+            amount: level.amount,
+        }
+    }
+}
+
+/// [`Coinbase`](super::super::Coinbase) level2 `l2update` price level change, where an amount
+/// of 0 indicates the price level should be removed.
+///
+/// ### Raw Payload Examples
+/// ```json
+/// ["buy", "10101.80000000", "0.162567"]
+/// ```
+#[derive(Clone, Copy, PartialEq, PartialOrd, Debug, Serialize)]
+pub struct CoinbaseLevelChange {
+    pub side: Side,
+    pub price: f64,
+    pub amount: f64,
+}
+
+impl From<CoinbaseLevelChange> for Level {
+    fn from(change: CoinbaseLevelChange) -> Self {
+        Self {
+            price: change.price,
+            amount: change.amount,
+        }
+    }
+}
+
+/// [`Coinbase`](super::super::Coinbase) [`OrderBookUpdater`] that maintains a local OrderBook
+/// Level2 from the WebSocket `snapshot` & `l2update` messages.
+///
+/// Coinbase: Maintaining The Local OrderBook
+/// 1. Subscribe to the "level2_batch" channel.
+/// 2. The first message is a `snapshot` of the OrderBook.
+/// 3. Each `l2update` contains the absolute amount for a price level, where an amount of 0
+///    removes the price level.
+///
+/// See docs: <https://docs.cloud.coinbase.com/exchange/docs/websocket-channels#level2-channel>
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct CoinbaseBookUpdater {
+    pub awaiting_snapshot: bool,
+}
+
+impl Default for CoinbaseBookUpdater {
+    fn default() -> Self {
+        Self {
+            awaiting_snapshot: true,
+        }
+    }
+}
+
+#[async_trait]
+impl OrderBookUpdater for CoinbaseBookUpdater {
+    type OrderBook = OrderBook;
+    type Update = CoinbaseOrderBookL2;
+
+    async fn init<Exchange, Kind>(
+        _: mpsc::UnboundedSender<WsMessage>,
+        instrument: Instrument,
+    ) -> Result<InstrumentOrderBook<Instrument, Self>, DataError>
+    where
+        Exchange: Send,
+        Kind: Send,
+    {
+        // Coinbase sends the initial OrderBook snapshot over the WebSocket after subscribing
+        Ok(InstrumentOrderBook {
+            instrument,
+            updater: Self::default(),
+            book: OrderBook {
+                last_update_time: Utc::now(),
+                bids: OrderBookSide::new(Side::Buy, Vec::<Level>::new()),
+                asks: OrderBookSide::new(Side::Sell, Vec::<Level>::new()),
+            },
+        })
+    }
+
+    fn update(
+        &mut self,
+        book: &mut Self::OrderBook,
+        update: Self::Update,
+        pool: &mut LevelPool,
+    ) -> Result<Option<Self::OrderBook>, DataError> {
+        match update {
+            CoinbaseOrderBookL2::Snapshot(snapshot) => {
+                // Coinbase snapshots do not contain an exchange timestamp
+                *book = OrderBook {
+                    last_update_time: Utc::now(),
+                    bids: OrderBookSide::new(Side::Buy, snapshot.bids),
+                    asks: OrderBookSide::new(Side::Sell, snapshot.asks),
+                };
+                self.awaiting_snapshot = false;
+            }
+            CoinbaseOrderBookL2::L2update(_) if self.awaiting_snapshot => {
+                return Ok(None);
+            }
+            CoinbaseOrderBookL2::L2update(update) => {
+                book.last_update_time = update.time;
+                for change in update.changes {
+                    match change.side {
+                        Side::Buy => book.bids.upsert_single(change),
+                        Side::Sell => book.asks.upsert_single(change),
+                    }
+                }
+            }
+        }
+
+        Ok(Some(book.snapshot_in(pool)))
+    }
+}
+
+/// Deserialize a [`CoinbaseOrderBookL2`] "product_id" (eg/ "BTC-USD") as the associated
+/// [`SubscriptionId`] (eg/ "level2_batch|BTC-USD").
+pub fn de_ob_l2_subscription_id<'de, D>(deserializer: D) -> Result<SubscriptionId, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+{
+    <&str as Deserialize>::deserialize(deserializer)
+        .map(|product_id| ExchangeSub::from((CoinbaseChannel::ORDER_BOOK_L2, product_id)).id())
+}
+
+impl<'de> Deserialize<'de> for CoinbaseLevel {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let (price, amount) = <(&str, &str)>::deserialize(deserializer)?;
+
+        Ok(Self {
+            price: price.parse().map_err(serde::de::Error::custom)?,
+            amount: amount.parse().map_err(serde::de::Error::custom)?,
+        })
+    }
+}
+
+impl<'de> Deserialize<'de> for CoinbaseLevelChange {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let (side, price, amount) = <(Side, &str, &str)>::deserialize(deserializer)?;
+
+        Ok(Self {
+            side,
+            price: price.parse().map_err(serde::de::Error::custom)?,
+            amount: amount.parse().map_err(serde::de::Error::custom)?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod de {
+        use super::*;
+        use barter_integration::{de::datetime_utc_from_epoch_duration, error::SocketError};
+        use std::time::Duration;
+
+        #[test]
+        fn test_coinbase_order_book_l2() {
+            struct TestCase {
+                input: &'static str,
+                expected: Result<CoinbaseOrderBookL2, SocketError>,
+            }
+
+            let tests = vec![
+                TestCase {
+                    // TC0: valid snapshot
+                    input: r#"
+                    {
+                        "type": "snapshot",
+                        "product_id": "BTC-USD",
+                        "bids": [["10101.10", "0.45054140"]],
+                        "asks": [["10102.55", "0.57753524"]]
+                    }
+                    "#,
+                    expected: Ok(CoinbaseOrderBookL2::Snapshot(CoinbaseOrderBookL2Snapshot {
+                        subscription_id: SubscriptionId::from("level2_batch|BTC-USD"),
+                        bids: vec![CoinbaseLevel {
+                            price: 10101.10,
+                            amount: 0.45054140,
+                        }],
+                        asks: vec![CoinbaseLevel {
+                            price: 10102.55,
+                            amount: 0.57753524,
+                        }],
+                    })),
+                },
+                TestCase {
+                    // TC1: valid l2update
+                    input: r#"
+                    {
+                        "type": "l2update",
+                        "product_id": "BTC-USD",
+                        "time": "2019-08-14T20:42:27.265Z",
+                        "changes": [["buy", "10101.80000000", "0.162567"], ["sell", "10102.55", "0"]]
+                    }
+                    "#,
+                    expected: Ok(CoinbaseOrderBookL2::L2update(CoinbaseOrderBookL2Update {
+                        subscription_id: SubscriptionId::from("level2_batch|BTC-USD"),
+                        time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                            1565815347265,
+                        )),
+                        changes: vec![
+                            CoinbaseLevelChange {
+                                side: Side::Buy,
+                                price: 10101.80,
+                                amount: 0.162567,
+                            },
+                            CoinbaseLevelChange {
+                                side: Side::Sell,
+                                price: 10102.55,
+                                amount: 0.0,
+                            },
+                        ],
+                    })),
+                },
+                TestCase {
+                    // TC2: invalid l2update w/ non-numeric price
+                    input: r#"
+                    {
+                        "type": "l2update",
+                        "product_id": "BTC-USD",
+                        "time": "2019-08-14T20:42:27.265Z",
+                        "changes": [["buy", "not a price", "0.162567"]]
+                    }
+                    "#,
+                    expected: Err(SocketError::Unsupported {
+                        entity: "",
+                        item: "".to_string(),
+                    }),
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                let actual = serde_json::from_str::<CoinbaseOrderBookL2>(test.input);
+                match (actual, test.expected) {
+                    (Ok(actual), Ok(expected)) => {
+                        assert_eq!(actual, expected, "TC{} failed", index)
+                    }
+                    (Err(_), Err(_)) => {
+                        // Test passed
+                    }
+                    (actual, expected) => {
+                        // Test failed
+                        panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                    }
+                }
+            }
+        }
+    }
+
+    mod coinbase_book_updater {
+        use super::*;
+
+        #[test]
+        fn test_update() {
+            let subscription_id = SubscriptionId::from("level2_batch|BTC-USD");
+            let mut updater = CoinbaseBookUpdater::default();
+            let mut book = OrderBook {
+                last_update_time: Utc::now(),
+                bids: OrderBookSide::new(Side::Buy, Vec::<Level>::new()),
+                asks: OrderBookSide::new(Side::Sell, Vec::<Level>::new()),
+            };
+
+            let l2update = |changes: Vec<(Side, f64, f64)>| {
+                CoinbaseOrderBookL2::L2update(CoinbaseOrderBookL2Update {
+                    subscription_id: subscription_id.clone(),
+                    time: Utc::now(),
+                    changes: changes
+                        .into_iter()
+                        .map(|(side, price, amount)| CoinbaseLevelChange {
+                            side,
+                            price,
+                            amount,
+                        })
+                        .collect(),
+                })
+            };
+
+            // Updates received before the initial snapshot are dropped
+            let update = l2update(vec![(Side::Buy, 100.0, 1.0)]);
+            assert_eq!(
+                updater
+                    .update(&mut book, update, &mut LevelPool::default())
+                    .unwrap(),
+                None
+            );
+
+            // Snapshot initialises the OrderBook
+            let snapshot = CoinbaseOrderBookL2::Snapshot(CoinbaseOrderBookL2Snapshot {
+                subscription_id: subscription_id.clone(),
+                bids: vec![
+                    CoinbaseLevel {
+                        price: 99.0,
+                        amount: 1.0,
+                    },
+                    CoinbaseLevel {
+                        price: 100.0,
+                        amount: 2.0,
+                    },
+                ],
+                asks: vec![CoinbaseLevel {
+                    price: 101.0,
+                    amount: 3.0,
+                }],
+            });
+            let actual = updater
+                .update(&mut book, snapshot, &mut LevelPool::default())
+                .unwrap()
+                .unwrap();
+            assert!(!updater.awaiting_snapshot);
+            assert_eq!(actual.bids.levels()[0], Level::new(100.0, 2.0));
+            assert_eq!(actual.asks.levels(), &[Level::new(101.0, 3.0)]);
+
+            // Update upserts & removes levels on the relevant side
+            let update = l2update(vec![
+                (Side::Buy, 100.0, 0.0),
+                (Side::Buy, 99.5, 4.0),
+                (Side::Sell, 102.0, 5.0),
+            ]);
+            let actual = updater
+                .update(&mut book, update, &mut LevelPool::default())
+                .unwrap()
+                .unwrap();
+            assert_eq!(
+                actual.bids.levels(),
+                &[Level::new(99.5, 4.0), Level::new(99.0, 1.0)]
+            );
+            assert_eq!(
+                actual.asks.levels(),
+                &[Level::new(101.0, 3.0), Level::new(102.0, 5.0)]
+            );
+        }
+    }
+}
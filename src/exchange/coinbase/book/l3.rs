@@ -0,0 +1,434 @@
+use super::super::CoinbaseChannel;
+use crate::{
+    event::{MarketEvent, MarketIter},
+    exchange::{ExchangeId, ExchangeSub},
+    subscription::book::{OrderBookL3DoneReason, OrderBookL3Event},
+    Identifier,
+};
+use barter_integration::model::{Exchange, Side, SubscriptionId};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// [`Coinbase`](super::super::Coinbase) real-time full channel WebSocket message.
+///
+/// Only the `open`, `change`, `done` & `match` messages describe changes to the OrderBook, so
+/// every other message type (eg/ `received`, `activate`) is deserialised as
+/// [`CoinbaseOrderBookL3::Other`] and ignored.
+///
+/// See docs: <https://docs.cloud.coinbase.com/exchange/docs/websocket-channels#full-channel>
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum CoinbaseOrderBookL3 {
+    Open(CoinbaseOrderOpen),
+    Change(CoinbaseOrderChange),
+    Done(CoinbaseOrderDone),
+    Match(CoinbaseOrderMatch),
+    #[serde(other)]
+    Other,
+}
+
+/// [`Coinbase`](super::super::Coinbase) full channel `open` message.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://docs.cloud.coinbase.com/exchange/docs/websocket-channels#open>
+/// ```json
+/// {
+///     "type": "open",
+///     "time": "2014-11-07T08:19:27.028459Z",
+///     "product_id": "BTC-USD",
+///     "sequence": 10,
+///     "order_id": "d50ec984-77a8-460a-b958-66f114b0de9b",
+///     "price": "200.2",
+///     "remaining_size": "1.00",
+///     "side": "sell"
+/// }
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct CoinbaseOrderOpen {
+    #[serde(alias = "product_id", deserialize_with = "de_ob_l3_subscription_id")]
+    pub subscription_id: SubscriptionId,
+    pub time: DateTime<Utc>,
+    pub sequence: u64,
+    pub order_id: String,
+    #[serde(deserialize_with = "barter_integration::de::de_str")]
+    pub price: f64,
+    #[serde(
+        alias = "remaining_size",
+        deserialize_with = "barter_integration::de::de_str"
+    )]
+    pub amount: f64,
+    pub side: Side,
+}
+
+/// [`Coinbase`](super::super::Coinbase) full channel `change` message.
+///
+/// Market order changes do not contain a price or size, and are therefore ignored.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://docs.cloud.coinbase.com/exchange/docs/websocket-channels#change>
+/// ```json
+/// {
+///     "type": "change",
+///     "reason": "STP",
+///     "time": "2014-11-07T08:19:27.028459Z",
+///     "sequence": 80,
+///     "order_id": "ac928c66-ca53-498f-9c13-a110027a60e8",
+///     "side": "sell",
+///     "product_id": "BTC-USD",
+///     "old_size": "12.234412",
+///     "new_size": "5.23512",
+///     "price": "400.23"
+/// }
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct CoinbaseOrderChange {
+    #[serde(alias = "product_id", deserialize_with = "de_ob_l3_subscription_id")]
+    pub subscription_id: SubscriptionId,
+    pub time: DateTime<Utc>,
+    pub sequence: u64,
+    pub order_id: String,
+    pub side: Side,
+    #[serde(default, deserialize_with = "de_option_str_f64")]
+    pub price: Option<f64>,
+    #[serde(default, deserialize_with = "de_option_str_f64")]
+    pub new_price: Option<f64>,
+    #[serde(default, deserialize_with = "de_option_str_f64")]
+    pub new_size: Option<f64>,
+}
+
+/// [`Coinbase`](super::super::Coinbase) full channel `done` message.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://docs.cloud.coinbase.com/exchange/docs/websocket-channels#done>
+/// ```json
+/// {
+///     "type": "done",
+///     "time": "2014-11-07T08:19:27.028459Z",
+///     "product_id": "BTC-USD",
+///     "sequence": 10,
+///     "price": "200.2",
+///     "order_id": "d50ec984-77a8-460a-b958-66f114b0de9b",
+///     "reason": "filled",
+///     "side": "sell",
+///     "remaining_size": "0"
+/// }
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct CoinbaseOrderDone {
+    #[serde(alias = "product_id", deserialize_with = "de_ob_l3_subscription_id")]
+    pub subscription_id: SubscriptionId,
+    pub time: DateTime<Utc>,
+    pub sequence: u64,
+    pub order_id: String,
+    pub side: Side,
+    #[serde(default, deserialize_with = "de_option_str_f64")]
+    pub price: Option<f64>,
+    #[serde(default, deserialize_with = "de_option_str_f64")]
+    pub remaining_size: Option<f64>,
+    pub reason: CoinbaseDoneReason,
+}
+
+/// [`Coinbase`](super::super::Coinbase) full channel `done` message reason.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CoinbaseDoneReason {
+    Filled,
+    Canceled,
+}
+
+/// [`Coinbase`](super::super::Coinbase) full channel `match` message.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://docs.cloud.coinbase.com/exchange/docs/websocket-channels#match>
+/// ```json
+/// {
+///     "type": "match",
+///     "trade_id": 10,
+///     "sequence": 50,
+///     "maker_order_id": "ac928c66-ca53-498f-9c13-a110027a60e8",
+///     "taker_order_id": "132fb6ae-456b-4654-b4e0-d681ac05cea1",
+///     "time": "2014-11-07T08:19:27.028459Z",
+///     "product_id": "BTC-USD",
+///     "size": "5.23512",
+///     "price": "400.23",
+///     "side": "sell"
+/// }
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct CoinbaseOrderMatch {
+    #[serde(alias = "product_id", deserialize_with = "de_ob_l3_subscription_id")]
+    pub subscription_id: SubscriptionId,
+    pub time: DateTime<Utc>,
+    pub sequence: u64,
+    pub trade_id: u64,
+    pub maker_order_id: String,
+    pub taker_order_id: String,
+    #[serde(deserialize_with = "barter_integration::de::de_str")]
+    pub price: f64,
+    #[serde(alias = "size", deserialize_with = "barter_integration::de::de_str")]
+    pub amount: f64,
+    pub side: Side,
+}
+
+impl Identifier<Option<SubscriptionId>> for CoinbaseOrderBookL3 {
+    fn id(&self) -> Option<SubscriptionId> {
+        match self {
+            Self::Open(open) => Some(open.subscription_id.clone()),
+            Self::Change(change) => Some(change.subscription_id.clone()),
+            Self::Done(done) => Some(done.subscription_id.clone()),
+            Self::Match(trade) => Some(trade.subscription_id.clone()),
+            Self::Other => None,
+        }
+    }
+}
+
+impl From<CoinbaseDoneReason> for OrderBookL3DoneReason {
+    fn from(reason: CoinbaseDoneReason) -> Self {
+        match reason {
+            CoinbaseDoneReason::Filled => Self::Filled,
+            CoinbaseDoneReason::Canceled => Self::Cancelled,
+        }
+    }
+}
+
+impl<InstrumentId> From<(ExchangeId, InstrumentId, CoinbaseOrderBookL3)>
+    for MarketIter<InstrumentId, OrderBookL3Event>
+{
+    fn from(
+        (exchange_id, instrument, message): (ExchangeId, InstrumentId, CoinbaseOrderBookL3),
+    ) -> Self {
+        let (exchange_time, event) = match message {
+            CoinbaseOrderBookL3::Open(open) => (
+                open.time,
+                OrderBookL3Event::Open {
+                    sequence: open.sequence,
+                    order_id: open.order_id,
+                    side: open.side,
+                    price: open.price,
+                    amount: open.amount,
+                },
+            ),
+            CoinbaseOrderBookL3::Change(change) => {
+                match (change.new_price.or(change.price), change.new_size) {
+                    (Some(price), Some(amount)) => (
+                        change.time,
+                        OrderBookL3Event::Change {
+                            sequence: change.sequence,
+                            order_id: change.order_id,
+                            side: change.side,
+                            price,
+                            amount,
+                        },
+                    ),
+                    _ => return Self(vec![]),
+                }
+            }
+            CoinbaseOrderBookL3::Done(done) => (
+                done.time,
+                OrderBookL3Event::Done {
+                    sequence: done.sequence,
+                    order_id: done.order_id,
+                    side: done.side,
+                    price: done.price,
+                    remaining: done.remaining_size,
+                    reason: OrderBookL3DoneReason::from(done.reason),
+                },
+            ),
+            CoinbaseOrderBookL3::Match(trade) => (
+                trade.time,
+                OrderBookL3Event::Match {
+                    sequence: trade.sequence,
+                    trade_id: trade.trade_id.to_string(),
+                    maker_order_id: trade.maker_order_id,
+                    taker_order_id: trade.taker_order_id,
+                    side: trade.side,
+                    price: trade.price,
+                    amount: trade.amount,
+                },
+            ),
+            CoinbaseOrderBookL3::Other => return Self(vec![]),
+        };
+
+        Self(vec![Ok(MarketEvent {
+            exchange_time,
+            received_time: Utc::now(),
+            exchange: Exchange::from(exchange_id),
+            instrument,
+            kind: event,
+        })])
+    }
+}
+
+/// Deserialize a [`CoinbaseOrderBookL3`] "product_id" (eg/ "BTC-USD") as the associated
+/// [`SubscriptionId`] (eg/ SubscriptionId("full|BTC-USD").
+pub fn de_ob_l3_subscription_id<'de, D>(deserializer: D) -> Result<SubscriptionId, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+{
+    <&str as Deserialize>::deserialize(deserializer)
+        .map(|product_id| ExchangeSub::from((CoinbaseChannel::ORDER_BOOK_L3, product_id)).id())
+}
+
+/// Deserialize an optional `String` (eg/ "400.23") as an `Option<f64>`.
+pub fn de_option_str_f64<'de, D>(deserializer: D) -> Result<Option<f64>, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+{
+    <Option<&str> as Deserialize>::deserialize(deserializer)?
+        .map(|value| value.parse::<f64>().map_err(serde::de::Error::custom))
+        .transpose()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod de {
+        use super::*;
+        use barter_integration::error::SocketError;
+        use serde::de::Error;
+
+        fn time() -> DateTime<Utc> {
+            "2014-11-07T08:19:27.028459Z".parse().unwrap()
+        }
+
+        #[test]
+        fn test_coinbase_order_book_l3() {
+            struct TestCase {
+                input: &'static str,
+                expected: Result<CoinbaseOrderBookL3, SocketError>,
+            }
+
+            let tests = vec![
+                TestCase {
+                    // TC0: valid open
+                    input: r#"
+                    {
+                        "type": "open", "time": "2014-11-07T08:19:27.028459Z",
+                        "product_id": "BTC-USD", "sequence": 10,
+                        "order_id": "d50ec984-77a8-460a-b958-66f114b0de9b", "price": "200.2",
+                        "remaining_size": "1.00", "side": "sell"
+                    }
+                    "#,
+                    expected: Ok(CoinbaseOrderBookL3::Open(CoinbaseOrderOpen {
+                        subscription_id: SubscriptionId::from("full|BTC-USD"),
+                        time: time(),
+                        sequence: 10,
+                        order_id: "d50ec984-77a8-460a-b958-66f114b0de9b".to_string(),
+                        price: 200.2,
+                        amount: 1.0,
+                        side: Side::Sell,
+                    })),
+                },
+                TestCase {
+                    // TC1: valid limit order change
+                    input: r#"
+                    {
+                        "type": "change", "reason": "STP", "time": "2014-11-07T08:19:27.028459Z",
+                        "sequence": 80, "order_id": "ac928c66-ca53-498f-9c13-a110027a60e8",
+                        "side": "sell", "product_id": "BTC-USD", "old_size": "12.234412",
+                        "new_size": "5.23512", "price": "400.23"
+                    }
+                    "#,
+                    expected: Ok(CoinbaseOrderBookL3::Change(CoinbaseOrderChange {
+                        subscription_id: SubscriptionId::from("full|BTC-USD"),
+                        time: time(),
+                        sequence: 80,
+                        order_id: "ac928c66-ca53-498f-9c13-a110027a60e8".to_string(),
+                        side: Side::Sell,
+                        price: Some(400.23),
+                        new_price: None,
+                        new_size: Some(5.23512),
+                    })),
+                },
+                TestCase {
+                    // TC2: valid market order done w/o price & remaining_size
+                    input: r#"
+                    {
+                        "type": "done", "time": "2014-11-07T08:19:27.028459Z",
+                        "product_id": "BTC-USD", "sequence": 10,
+                        "order_id": "d50ec984-77a8-460a-b958-66f114b0de9b", "reason": "canceled",
+                        "side": "buy"
+                    }
+                    "#,
+                    expected: Ok(CoinbaseOrderBookL3::Done(CoinbaseOrderDone {
+                        subscription_id: SubscriptionId::from("full|BTC-USD"),
+                        time: time(),
+                        sequence: 10,
+                        order_id: "d50ec984-77a8-460a-b958-66f114b0de9b".to_string(),
+                        side: Side::Buy,
+                        price: None,
+                        remaining_size: None,
+                        reason: CoinbaseDoneReason::Canceled,
+                    })),
+                },
+                TestCase {
+                    // TC3: valid match
+                    input: r#"
+                    {
+                        "type": "match", "trade_id": 10, "sequence": 50,
+                        "maker_order_id": "ac928c66-ca53-498f-9c13-a110027a60e8",
+                        "taker_order_id": "132fb6ae-456b-4654-b4e0-d681ac05cea1",
+                        "time": "2014-11-07T08:19:27.028459Z", "product_id": "BTC-USD",
+                        "size": "5.23512", "price": "400.23", "side": "sell"
+                    }
+                    "#,
+                    expected: Ok(CoinbaseOrderBookL3::Match(CoinbaseOrderMatch {
+                        subscription_id: SubscriptionId::from("full|BTC-USD"),
+                        time: time(),
+                        sequence: 50,
+                        trade_id: 10,
+                        maker_order_id: "ac928c66-ca53-498f-9c13-a110027a60e8".to_string(),
+                        taker_order_id: "132fb6ae-456b-4654-b4e0-d681ac05cea1".to_string(),
+                        price: 400.23,
+                        amount: 5.23512,
+                        side: Side::Sell,
+                    })),
+                },
+                TestCase {
+                    // TC4: received message is ignored
+                    input: r#"
+                    {
+                        "type": "received", "time": "2014-11-07T08:19:27.028459Z",
+                        "product_id": "BTC-USD", "sequence": 10,
+                        "order_id": "d50ec984-77a8-460a-b958-66f114b0de9b", "size": "1.34",
+                        "price": "502.1", "side": "buy", "order_type": "limit"
+                    }
+                    "#,
+                    expected: Ok(CoinbaseOrderBookL3::Other),
+                },
+                TestCase {
+                    // TC5: invalid open w/ missing price
+                    input: r#"
+                    {
+                        "type": "open", "time": "2014-11-07T08:19:27.028459Z",
+                        "product_id": "BTC-USD", "sequence": 10,
+                        "order_id": "d50ec984-77a8-460a-b958-66f114b0de9b",
+                        "remaining_size": "1.00", "side": "sell"
+                    }
+                    "#,
+                    expected: Err(SocketError::Deserialise {
+                        error: serde_json::Error::custom(""),
+                        payload: "".to_owned(),
+                    }),
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                let actual = serde_json::from_str::<CoinbaseOrderBookL3>(test.input);
+                match (actual, test.expected) {
+                    (Ok(actual), Ok(expected)) => {
+                        assert_eq!(actual, expected, "TC{} failed", index)
+                    }
+                    (Err(_), Err(_)) => {
+                        // Test passed
+                    }
+                    (actual, expected) => {
+                        // Test failed
+                        panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                    }
+                }
+            }
+        }
+    }
+}
@@ -1,20 +1,34 @@
 use self::{
-    channel::CoinbaseChannel, market::CoinbaseMarket, subscription::CoinbaseSubResponse,
+    book::{l2::CoinbaseBookUpdater, l3::CoinbaseOrderBookL3},
+    channel::CoinbaseChannel,
+    market::CoinbaseMarket,
+    subscription::CoinbaseSubResponse,
     trade::CoinbaseTrade,
 };
 use crate::instrument::InstrumentData;
 use crate::{
     exchange::{Connector, ExchangeId, ExchangeSub, StreamSelector},
     subscriber::{validator::WebSocketSubValidator, WebSocketSubscriber},
-    subscription::trade::PublicTrades,
-    transformer::stateless::StatelessTransformer,
+    subscription::{
+        book::{OrderBooksL1, OrderBooksL2, OrderBooksL3},
+        trade::PublicTrades,
+    },
+    transformer::{
+        book::{l1::OrderBookL1Transformer, MultiBookTransformer},
+        stateless::StatelessTransformer,
+    },
     ExchangeWsStream,
 };
-use barter_integration::{error::SocketError, protocol::websocket::WsMessage};
+use barter_integration::{
+    error::SocketError, model::instrument::Instrument, protocol::websocket::WsMessage,
+};
 use barter_macro::{DeExchange, SerExchange};
 use serde_json::json;
 use url::Url;
 
+/// OrderBook types for [`Coinbase`].
+pub mod book;
+
 /// Defines the type that translates a Barter [`Subscription`](crate::subscription::Subscription)
 /// into an exchange [`Connector`] specific channel used for generating [`Connector::requests`].
 pub mod channel;
@@ -79,3 +93,27 @@ where
     type Stream =
         ExchangeWsStream<StatelessTransformer<Self, Instrument::Id, PublicTrades, CoinbaseTrade>>;
 }
+
+impl StreamSelector<Instrument, OrderBooksL2> for Coinbase {
+    type Stream =
+        ExchangeWsStream<MultiBookTransformer<Self, Instrument, OrderBooksL2, CoinbaseBookUpdater>>;
+}
+
+impl StreamSelector<Instrument, OrderBooksL1> for Coinbase {
+    type Stream = ExchangeWsStream<
+        OrderBookL1Transformer<
+            Self,
+            Instrument,
+            MultiBookTransformer<Self, Instrument, OrderBooksL2, CoinbaseBookUpdater>,
+        >,
+    >;
+}
+
+impl<Instrument> StreamSelector<Instrument, OrderBooksL3> for Coinbase
+where
+    Instrument: InstrumentData,
+{
+    type Stream = ExchangeWsStream<
+        StatelessTransformer<Self, Instrument::Id, OrderBooksL3, CoinbaseOrderBookL3>,
+    >;
+}
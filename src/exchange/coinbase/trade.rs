@@ -80,6 +80,7 @@ where
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::subscription::trade::tests::assert_duplicate_frame_dedup_keys;
     use barter_integration::error::SocketError;
     use chrono::NaiveDateTime;
     use serde::de::Error;
@@ -117,7 +118,7 @@ mod tests {
                     price: 400.23,
                     amount: 5.23512,
                     side: Side::Sell,
-                    time: DateTime::from_utc(
+                    time: DateTime::from_naive_utc_and_offset(
                         NaiveDateTime::from_str("2014-11-07T08:19:27.028459").unwrap(),
                         Utc,
                     ),
@@ -141,4 +142,20 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn test_coinbase_trade_duplicate_frame_dedup_keys() {
+        assert_duplicate_frame_dedup_keys::<CoinbaseTrade>(
+            ExchangeId::Coinbase,
+            r#"
+            {
+                "type": "match","trade_id": 10,"sequence": 50,
+                "maker_order_id": "ac928c66-ca53-498f-9c13-a110027a60e8",
+                "taker_order_id": "132fb6ae-456b-4654-b4e0-d681ac05cea1",
+                "time": "2014-11-07T08:19:27.028459Z",
+                "product_id": "BTC-USD", "size": "5.23512", "price": "400.23", "side": "sell"
+            }
+            "#,
+        );
+    }
 }
@@ -0,0 +1,521 @@
+use super::super::{
+    channel::DeribitChannel,
+    market::{deribit_market, DeribitMarket},
+    message::DeribitMessage,
+    DERIBIT_INTERVAL,
+};
+use crate::{
+    error::DataError,
+    subscription::book::{pool::LevelPool, Level, OrderBook, OrderBookSide},
+    transformer::book::{InstrumentOrderBook, OrderBookUpdater},
+};
+use async_trait::async_trait;
+use barter_integration::{
+    de::extract_next,
+    error::SocketError,
+    model::{instrument::Instrument, Side},
+    protocol::websocket::WsMessage,
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use tokio::sync::mpsc;
+
+/// Terse type alias for a [`Deribit`](super::super::Deribit) real-time OrderBook Level2
+/// [`DeribitMessage`].
+pub type DeribitOrderBookL2 = DeribitMessage<DeribitOrderBookL2Inner>;
+
+/// [`Deribit`](super::super::Deribit) real-time incremental OrderBook Level2 notification.
+///
+/// ### Notes
+/// The first notification is a "snapshot" of the OrderBook, followed by "change" notifications
+/// where each prev_change_id equals the change_id of the previous notification.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://docs.deribit.com/#book-instrument_name-interval>
+/// ```json
+/// {
+///     "type": "change",
+///     "timestamp": 1554373962454,
+///     "prev_change_id": 297217,
+///     "instrument_name": "BTC-PERPETUAL",
+///     "change_id": 297218,
+///     "bids": [["delete", 5041.94, 0], ["change", 5042.34, 10020]],
+///     "asks": [["new", 5042.64, 20]]
+/// }
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct DeribitOrderBookL2Inner {
+    pub r#type: DeribitBookType,
+    #[serde(
+        alias = "timestamp",
+        deserialize_with = "crate::util::time::de_u64_epoch_ms_as_datetime_utc"
+    )]
+    pub time: DateTime<Utc>,
+    #[serde(default)]
+    pub prev_change_id: Option<u64>,
+    pub change_id: u64,
+    pub bids: Vec<DeribitLevel>,
+    pub asks: Vec<DeribitLevel>,
+}
+
+/// [`Deribit`](super::super::Deribit) OrderBook Level2 notification type.
+///
+/// See [`DeribitOrderBookL2Inner`] for full raw payload examples.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DeribitBookType {
+    Snapshot,
+    Change,
+}
+
+/// [`Deribit`](super::super::Deribit) OrderBook Level2 level, where the action is ignored since
+/// a "delete" level always contains an amount of 0.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://docs.deribit.com/#book-instrument_name-interval>
+/// ```json
+/// ["change", 5042.34, 10020]
+/// ```
+#[derive(Clone, Copy, PartialEq, PartialOrd, Debug, Serialize)]
+pub struct DeribitLevel {
+    pub price: f64,
+    pub amount: f64,
+}
+
+impl From<DeribitLevel> for Level {
+    fn from(level: DeribitLevel) -> Self {
+        Self {
+            price: level.price,
+            amount: level.amount,
+        }
+    }
+}
+
+/// [`Deribit`](super::super::Deribit) [`OrderBookUpdater`] that maintains a local OrderBook
+/// Level2 from the incremental "book" channel.
+///
+/// Deribit: Maintaining The Local OrderBook
+/// 1. Subscribe to the "book.{instrument_name}.{interval}" channel.
+/// 2. The first notification is a "snapshot" of the OrderBook.
+/// 3. Each "change" notification contains the absolute amount for a price level, where an amount
+///    of 0 removes the price level.
+/// 4. Each "change" prev_change_id should be equal to the previous notification's change_id,
+///    otherwise the channel must be re-subscribed to in order to receive a new "snapshot".
+///
+/// Note that the "raw" interval is only available to authorised connections, so the
+/// [`DERIBIT_INTERVAL`] is used.
+///
+/// See docs: <https://docs.deribit.com/#book-instrument_name-interval>
+#[derive(Clone, Debug)]
+pub struct DeribitBookUpdater {
+    pub market: DeribitMarket,
+    pub awaiting_snapshot: bool,
+    pub last_change_id: u64,
+    ws_sink_tx: mpsc::UnboundedSender<WsMessage>,
+}
+
+impl DeribitBookUpdater {
+    /// Construct a new [`Deribit`](super::super::Deribit) [`OrderBookUpdater`] that waits for
+    /// the initial WebSocket snapshot.
+    pub fn new(market: DeribitMarket, ws_sink_tx: mpsc::UnboundedSender<WsMessage>) -> Self {
+        Self {
+            market,
+            awaiting_snapshot: true,
+            last_change_id: 0,
+            ws_sink_tx,
+        }
+    }
+
+    /// Unsubscribe & re-subscribe to the OrderBook Level2 channel in order to receive a fresh
+    /// snapshot. Changes received in the meantime are dropped.
+    pub fn resubscribe(&mut self) -> Result<(), DataError> {
+        self.awaiting_snapshot = true;
+
+        ["public/unsubscribe", "public/subscribe"]
+            .into_iter()
+            .try_for_each(|method| {
+                self.ws_sink_tx
+                    .send(book_l2_request(method, &self.market))
+                    .map_err(|_| DataError::Socket(SocketError::Sink))
+            })
+    }
+
+    /// Deribit: Maintaining The Local OrderBook: Step 4:
+    /// "Each change prev_change_id should be equal to the previous notification's change_id"
+    ///
+    /// Re-subscribes to re-initialise the OrderBook if there is a gap.
+    pub fn validate_next_change(
+        &mut self,
+        change: &DeribitOrderBookL2Inner,
+    ) -> Result<(), DataError> {
+        match change.prev_change_id {
+            Some(prev_change_id) if prev_change_id == self.last_change_id => Ok(()),
+            prev_change_id => {
+                self.resubscribe()?;
+                Err(DataError::SequenceGap {
+                    expected: self.last_change_id,
+                    actual: prev_change_id.unwrap_or_default(),
+                })
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl OrderBookUpdater for DeribitBookUpdater {
+    type OrderBook = OrderBook;
+    type Update = DeribitOrderBookL2;
+
+    async fn init<Exchange, Kind>(
+        ws_sink_tx: mpsc::UnboundedSender<WsMessage>,
+        instrument: Instrument,
+    ) -> Result<InstrumentOrderBook<Instrument, Self>, DataError>
+    where
+        Exchange: Send,
+        Kind: Send,
+    {
+        // Deribit sends the initial OrderBook snapshot over the WebSocket after subscribing
+        let market = deribit_market(&instrument);
+
+        Ok(InstrumentOrderBook {
+            instrument,
+            updater: Self::new(market, ws_sink_tx),
+            book: OrderBook {
+                last_update_time: Utc::now(),
+                bids: OrderBookSide::new(Side::Buy, Vec::<Level>::new()),
+                asks: OrderBookSide::new(Side::Sell, Vec::<Level>::new()),
+            },
+        })
+    }
+
+    fn update(
+        &mut self,
+        book: &mut Self::OrderBook,
+        update: Self::Update,
+        pool: &mut LevelPool,
+    ) -> Result<Option<Self::OrderBook>, DataError> {
+        let update = update.params.data;
+
+        match update.r#type {
+            DeribitBookType::Snapshot => {
+                // 2. A snapshot replaces the local OrderBook
+                *book = OrderBook {
+                    last_update_time: update.time,
+                    bids: OrderBookSide::new(Side::Buy, update.bids),
+                    asks: OrderBookSide::new(Side::Sell, update.asks),
+                };
+                self.awaiting_snapshot = false;
+            }
+            DeribitBookType::Change if self.awaiting_snapshot => {
+                // Drop changes received while awaiting a (re)subscription snapshot
+                return Ok(None);
+            }
+            DeribitBookType::Change => {
+                // 4. Validate prev_change_id continuity
+                self.validate_next_change(&update)?;
+
+                // 3. Upsert absolute amounts, where an amount of 0 removes the price level
+                book.last_update_time = update.time;
+                book.bids.upsert(update.bids);
+                book.asks.upsert(update.asks);
+            }
+        }
+
+        self.last_change_id = update.change_id;
+
+        Ok(Some(book.snapshot_in(pool)))
+    }
+}
+
+/// Construct a [`Deribit`](super::super::Deribit) OrderBook Level2 (un)subscribe JSON-RPC
+/// request for the provided [`DeribitMarket`].
+pub fn book_l2_request(method: &str, market: &DeribitMarket) -> WsMessage {
+    WsMessage::Text(
+        json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "method": method,
+            "params": {
+                "channels": [format!(
+                    "{}.{}.{DERIBIT_INTERVAL}",
+                    DeribitChannel::ORDER_BOOK_L2.as_ref(),
+                    market.as_ref()
+                )],
+            },
+        })
+        .to_string(),
+    )
+}
+
+impl<'de> Deserialize<'de> for DeribitLevel {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        struct SeqVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for SeqVisitor {
+            type Value = DeribitLevel;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("DeribitLevel struct from the Deribit WebSocket API")
+            }
+
+            fn visit_seq<SeqAccessor>(
+                self,
+                mut seq: SeqAccessor,
+            ) -> Result<Self::Value, SeqAccessor::Error>
+            where
+                SeqAccessor: serde::de::SeqAccess<'de>,
+            {
+                // DeribitLevel Sequence Format:
+                // [action, price, amount]
+                let _action =
+                    extract_next::<SeqAccessor, serde::de::IgnoredAny>(&mut seq, "action")?;
+                let price = extract_next::<SeqAccessor, f64>(&mut seq, "price")?;
+                let amount = extract_next::<SeqAccessor, f64>(&mut seq, "amount")?;
+
+                // Ignore any additional elements or SerDe will fail
+                while seq.next_element::<serde::de::IgnoredAny>()?.is_some() {}
+
+                Ok(DeribitLevel { price, amount })
+            }
+        }
+
+        deserializer.deserialize_seq(SeqVisitor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod de {
+        use super::*;
+        use barter_integration::{de::datetime_utc_from_epoch_duration, model::SubscriptionId};
+        use std::time::Duration;
+
+        #[test]
+        fn test_deribit_order_book_l2() {
+            struct TestCase {
+                input: &'static str,
+                expected: Result<DeribitOrderBookL2, SocketError>,
+            }
+
+            let tests = vec![
+                TestCase {
+                    // TC0: valid snapshot w/o prev_change_id
+                    input: r#"
+                    {
+                        "jsonrpc": "2.0",
+                        "method": "subscription",
+                        "params": {
+                            "channel": "book.BTC-PERPETUAL.100ms",
+                            "data": {
+                                "type": "snapshot",
+                                "timestamp": 1554373962454,
+                                "instrument_name": "BTC-PERPETUAL",
+                                "change_id": 297217,
+                                "bids": [["new", 5042.34, 30]],
+                                "asks": [["new", 5042.64, 40]]
+                            }
+                        }
+                    }
+                    "#,
+                    expected: Ok(DeribitMessage {
+                        params: crate::exchange::deribit::message::DeribitParams {
+                            subscription_id: SubscriptionId::from("book|BTC-PERPETUAL"),
+                            data: DeribitOrderBookL2Inner {
+                                r#type: DeribitBookType::Snapshot,
+                                time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                                    1554373962454,
+                                )),
+                                prev_change_id: None,
+                                change_id: 297217,
+                                bids: vec![DeribitLevel {
+                                    price: 5042.34,
+                                    amount: 30.0,
+                                }],
+                                asks: vec![DeribitLevel {
+                                    price: 5042.64,
+                                    amount: 40.0,
+                                }],
+                            },
+                        },
+                    }),
+                },
+                TestCase {
+                    // TC1: valid change w/ deleted level
+                    input: r#"
+                    {
+                        "jsonrpc": "2.0",
+                        "method": "subscription",
+                        "params": {
+                            "channel": "book.BTC-PERPETUAL.100ms",
+                            "data": {
+                                "type": "change",
+                                "timestamp": 1554373962454,
+                                "prev_change_id": 297217,
+                                "instrument_name": "BTC-PERPETUAL",
+                                "change_id": 297218,
+                                "bids": [["delete", 5041.94, 0]],
+                                "asks": []
+                            }
+                        }
+                    }
+                    "#,
+                    expected: Ok(DeribitMessage {
+                        params: crate::exchange::deribit::message::DeribitParams {
+                            subscription_id: SubscriptionId::from("book|BTC-PERPETUAL"),
+                            data: DeribitOrderBookL2Inner {
+                                r#type: DeribitBookType::Change,
+                                time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                                    1554373962454,
+                                )),
+                                prev_change_id: Some(297217),
+                                change_id: 297218,
+                                bids: vec![DeribitLevel {
+                                    price: 5041.94,
+                                    amount: 0.0,
+                                }],
+                                asks: vec![],
+                            },
+                        },
+                    }),
+                },
+                TestCase {
+                    // TC2: invalid change w/ malformed level
+                    input: r#"
+                    {
+                        "jsonrpc": "2.0",
+                        "method": "subscription",
+                        "params": {
+                            "channel": "book.BTC-PERPETUAL.100ms",
+                            "data": {
+                                "type": "change",
+                                "timestamp": 1554373962454,
+                                "prev_change_id": 297217,
+                                "change_id": 297218,
+                                "bids": [["delete", 5041.94]],
+                                "asks": []
+                            }
+                        }
+                    }
+                    "#,
+                    expected: Err(SocketError::Unsupported {
+                        entity: "",
+                        item: "".to_string(),
+                    }),
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                let actual = serde_json::from_str::<DeribitOrderBookL2>(test.input);
+                match (actual, test.expected) {
+                    (Ok(actual), Ok(expected)) => {
+                        assert_eq!(actual, expected, "TC{} failed", index)
+                    }
+                    (Err(_), Err(_)) => {
+                        // Test passed
+                    }
+                    (actual, expected) => {
+                        // Test failed
+                        panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                    }
+                }
+            }
+        }
+    }
+
+    mod deribit_book_updater {
+        use super::*;
+        use crate::exchange::deribit::message::DeribitParams;
+        use barter_integration::model::SubscriptionId;
+
+        fn message(
+            r#type: DeribitBookType,
+            prev_change_id: Option<u64>,
+            change_id: u64,
+            bids: Vec<(f64, f64)>,
+        ) -> DeribitOrderBookL2 {
+            DeribitMessage {
+                params: DeribitParams {
+                    subscription_id: SubscriptionId::from("book|BTC-PERPETUAL"),
+                    data: DeribitOrderBookL2Inner {
+                        r#type,
+                        time: Utc::now(),
+                        prev_change_id,
+                        change_id,
+                        bids: bids
+                            .into_iter()
+                            .map(|(price, amount)| DeribitLevel { price, amount })
+                            .collect(),
+                        asks: vec![],
+                    },
+                },
+            }
+        }
+
+        #[test]
+        fn test_update() {
+            let (ws_sink_tx, mut ws_sink_rx) = mpsc::unbounded_channel();
+            let mut updater =
+                DeribitBookUpdater::new(DeribitMarket("BTC-PERPETUAL".to_string()), ws_sink_tx);
+            let mut book = OrderBook {
+                last_update_time: Utc::now(),
+                bids: OrderBookSide::new(Side::Buy, Vec::<Level>::new()),
+                asks: OrderBookSide::new(Side::Sell, Vec::<Level>::new()),
+            };
+
+            // Changes received before the initial snapshot are dropped
+            let change = message(DeribitBookType::Change, Some(9), 10, vec![(100.0, 1.0)]);
+            assert_eq!(
+                updater
+                    .update(&mut book, change, &mut LevelPool::default())
+                    .unwrap(),
+                None
+            );
+
+            // Snapshot initialises the OrderBook
+            let snapshot = message(DeribitBookType::Snapshot, None, 10, vec![(100.0, 1.0)]);
+            let actual = updater
+                .update(&mut book, snapshot, &mut LevelPool::default())
+                .unwrap()
+                .unwrap();
+            assert!(!updater.awaiting_snapshot);
+            assert_eq!(actual.bids.levels(), &[Level::new(100.0, 1.0)]);
+
+            // Change w/ prev_change_id == last change_id is applied
+            let change = message(
+                DeribitBookType::Change,
+                Some(10),
+                11,
+                vec![(100.0, 0.0), (99.0, 2.0)],
+            );
+            let actual = updater
+                .update(&mut book, change, &mut LevelPool::default())
+                .unwrap()
+                .unwrap();
+            assert_eq!(actual.bids.levels(), &[Level::new(99.0, 2.0)]);
+            assert_eq!(updater.last_change_id, 11);
+
+            // Change w/ gap triggers a re-subscription
+            let change = message(DeribitBookType::Change, Some(12), 13, vec![]);
+            assert!(matches!(
+                updater.update(&mut book, change, &mut LevelPool::default()),
+                Err(DataError::SequenceGap {
+                    expected: 11,
+                    actual: 12
+                })
+            ));
+            assert!(updater.awaiting_snapshot);
+            assert!(
+                matches!(ws_sink_rx.try_recv(), Ok(WsMessage::Text(text)) if text.contains("public/unsubscribe"))
+            );
+            assert!(
+                matches!(ws_sink_rx.try_recv(), Ok(WsMessage::Text(text)) if text.contains("public/subscribe"))
+            );
+        }
+    }
+}
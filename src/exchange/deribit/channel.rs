@@ -0,0 +1,67 @@
+use super::Deribit;
+use crate::{
+    subscription::{
+        book::{OrderBooksL1, OrderBooksL2},
+        option::OptionSummaries,
+        trade::PublicTrades,
+        Subscription,
+    },
+    Identifier,
+};
+use serde::Serialize;
+
+/// Type that defines how to translate a Barter [`Subscription`] into a [`Deribit`]
+/// channel to be subscribed to.
+///
+/// See docs: <https://docs.deribit.com/#subscriptions>
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Serialize)]
+pub struct DeribitChannel(pub &'static str);
+
+impl DeribitChannel {
+    /// [`Deribit`] ticker channel name, including the mark price, implied volatility & greeks.
+    ///
+    /// See docs: <https://docs.deribit.com/#ticker-instrument_name-interval>
+    pub const TICKER: Self = Self("ticker");
+
+    /// [`Deribit`] real-time trades channel name.
+    ///
+    /// See docs: <https://docs.deribit.com/#trades-instrument_name-interval>
+    pub const TRADES: Self = Self("trades");
+
+    /// [`Deribit`] incremental OrderBook Level2 channel name.
+    ///
+    /// See docs: <https://docs.deribit.com/#book-instrument_name-interval>
+    pub const ORDER_BOOK_L2: Self = Self("book");
+}
+
+impl<Instrument> Identifier<DeribitChannel> for Subscription<Deribit, Instrument, PublicTrades> {
+    fn id(&self) -> DeribitChannel {
+        DeribitChannel::TRADES
+    }
+}
+
+impl<Instrument> Identifier<DeribitChannel> for Subscription<Deribit, Instrument, OptionSummaries> {
+    fn id(&self) -> DeribitChannel {
+        DeribitChannel::TICKER
+    }
+}
+
+/// [`OrderBooksL1`] are derived from the OrderBook Level2 channel, since [`Deribit`] has no
+/// supported bookTicker-style channel.
+impl<Instrument> Identifier<DeribitChannel> for Subscription<Deribit, Instrument, OrderBooksL1> {
+    fn id(&self) -> DeribitChannel {
+        DeribitChannel::ORDER_BOOK_L2
+    }
+}
+
+impl<Instrument> Identifier<DeribitChannel> for Subscription<Deribit, Instrument, OrderBooksL2> {
+    fn id(&self) -> DeribitChannel {
+        DeribitChannel::ORDER_BOOK_L2
+    }
+}
+
+impl AsRef<str> for DeribitChannel {
+    fn as_ref(&self) -> &str {
+        self.0
+    }
+}
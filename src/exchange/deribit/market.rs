@@ -0,0 +1,136 @@
+use super::Deribit;
+use crate::instrument::{KeyedInstrument, MarketInstrumentData};
+use crate::{subscription::Subscription, Identifier};
+use barter_integration::model::instrument::{
+    kind::{InstrumentKind, OptionKind},
+    Instrument,
+};
+use chrono::{
+    format::{DelayedFormat, StrftimeItems},
+    DateTime, Utc,
+};
+use serde::{Deserialize, Serialize};
+
+/// Type that defines how to translate a Barter [`Subscription`] into a
+/// [`Deribit`] market that can be subscribed to.
+///
+/// See docs: <https://docs.deribit.com/#naming>
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct DeribitMarket(pub String);
+
+impl<Kind> Identifier<DeribitMarket> for Subscription<Deribit, Instrument, Kind> {
+    fn id(&self) -> DeribitMarket {
+        deribit_market(&self.instrument)
+    }
+}
+
+impl<Kind> Identifier<DeribitMarket> for Subscription<Deribit, KeyedInstrument, Kind> {
+    fn id(&self) -> DeribitMarket {
+        deribit_market(&self.instrument.data)
+    }
+}
+
+impl<Kind> Identifier<DeribitMarket> for Subscription<Deribit, MarketInstrumentData, Kind> {
+    fn id(&self) -> DeribitMarket {
+        DeribitMarket(self.instrument.name_exchange.clone())
+    }
+}
+
+impl AsRef<str> for DeribitMarket {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+pub(super) fn deribit_market(instrument: &Instrument) -> DeribitMarket {
+    use InstrumentKind::*;
+    let Instrument { base, quote, kind } = instrument;
+
+    // Notes:
+    // - Inverse (USD quoted) derivatives are named by currency only (eg/ "BTC-PERPETUAL").
+    // - Linear derivatives include the quote currency (eg/ "BTC_USDC-PERPETUAL").
+    let currency = match quote.as_ref().eq_ignore_ascii_case("usd") {
+        true => base.to_string(),
+        false => format!("{base}_{quote}"),
+    };
+
+    DeribitMarket(
+        match kind {
+            Spot => format!("{base}_{quote}"),
+            Future(future) => format!("{currency}-{}", format_expiry(future.expiry)),
+            Perpetual => format!("{currency}-PERPETUAL"),
+            Option(option) => format!(
+                "{currency}-{}-{}-{}",
+                format_expiry(option.expiry),
+                option.strike.normalize(),
+                match option.kind {
+                    OptionKind::Call => "C",
+                    OptionKind::Put => "P",
+                },
+            ),
+        }
+        .to_uppercase(),
+    )
+}
+
+/// Format the expiry DateTime<Utc> to be Deribit API compatible.
+///
+/// eg/ "7JUN24" (7th of June 2024)
+///
+/// See docs: <https://docs.deribit.com/#naming>
+fn format_expiry<'a>(expiry: DateTime<Utc>) -> DelayedFormat<StrftimeItems<'a>> {
+    expiry.date_naive().format("%-d%b%y")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use barter_integration::model::instrument::kind::{OptionContract, OptionExercise};
+    use chrono::TimeZone;
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn test_deribit_market() {
+        let expiry = Utc.with_ymd_and_hms(2024, 6, 7, 8, 0, 0).unwrap();
+
+        struct TestCase {
+            input: Instrument,
+            expected: &'static str,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: inverse perpetual
+                input: Instrument::from(("btc", "usd", InstrumentKind::Perpetual)),
+                expected: "BTC-PERPETUAL",
+            },
+            TestCase {
+                // TC1: linear perpetual
+                input: Instrument::from(("sol", "usdc", InstrumentKind::Perpetual)),
+                expected: "SOL_USDC-PERPETUAL",
+            },
+            TestCase {
+                // TC2: inverse call option
+                input: Instrument::from((
+                    "btc",
+                    "usd",
+                    InstrumentKind::Option(OptionContract {
+                        kind: OptionKind::Call,
+                        exercise: OptionExercise::European,
+                        expiry,
+                        strike: dec!(70000.0),
+                    }),
+                )),
+                expected: "BTC-7JUN24-70000-C",
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            assert_eq!(
+                deribit_market(&test.input).0,
+                test.expected,
+                "TC{index} failed"
+            );
+        }
+    }
+}
@@ -0,0 +1,104 @@
+use super::DERIBIT_INTERVAL;
+use crate::Identifier;
+use barter_integration::model::SubscriptionId;
+use serde::{
+    de::{Error, Unexpected},
+    Deserialize, Serialize,
+};
+
+/// [`Deribit`](super::Deribit) JSON-RPC subscription notification containing a market data
+/// payload `T` (eg/ [`DeribitOptionTicker`](super::option::DeribitOptionTicker)).
+///
+/// ### Raw Payload Examples
+/// See docs: <https://docs.deribit.com/#subscriptions>
+/// ```json
+/// {
+///     "jsonrpc": "2.0",
+///     "method": "subscription",
+///     "params": {
+///         "channel": "ticker.BTC-7JUN24-70000-C.100ms",
+///         "data": {}
+///     }
+/// }
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct DeribitMessage<T> {
+    pub params: DeribitParams<T>,
+}
+
+/// [`DeribitMessage`] params, containing the originating channel and the data payload.
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct DeribitParams<T> {
+    #[serde(alias = "channel", deserialize_with = "de_message_subscription_id")]
+    pub subscription_id: SubscriptionId,
+    pub data: T,
+}
+
+/// Deserialize a [`DeribitParams`] "channel" (eg/ "ticker.BTC-7JUN24-70000-C.100ms") as the
+/// associated [`SubscriptionId`].
+///
+/// eg/ "ticker|BTC-7JUN24-70000-C"
+pub fn de_message_subscription_id<'de, D>(deserializer: D) -> Result<SubscriptionId, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+{
+    let input = <&str as serde::Deserialize>::deserialize(deserializer)?;
+    let mut tokens = input.split('.');
+
+    match (tokens.next(), tokens.next(), tokens.next(), tokens.next()) {
+        (Some(channel), Some(market), Some(DERIBIT_INTERVAL), None) => {
+            Ok(SubscriptionId::from(format!("{channel}|{market}")))
+        }
+        _ => Err(Error::invalid_value(
+            Unexpected::Str(input),
+            &"invalid channel expected pattern: <channel>.<instrument_name>.<interval>",
+        )),
+    }
+}
+
+impl<T> Identifier<Option<SubscriptionId>> for DeribitMessage<T> {
+    fn id(&self) -> Option<SubscriptionId> {
+        Some(self.params.subscription_id.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod de {
+        use super::*;
+
+        #[test]
+        fn test_de_message_subscription_id() {
+            struct TestCase {
+                input: &'static str,
+                expected: Option<SubscriptionId>,
+            }
+
+            let tests = vec![
+                TestCase {
+                    // TC0: valid ticker channel
+                    input: r#""ticker.BTC-7JUN24-70000-C.100ms""#,
+                    expected: Some(SubscriptionId::from("ticker|BTC-7JUN24-70000-C")),
+                },
+                TestCase {
+                    // TC1: invalid channel w/ unexpected interval
+                    input: r#""ticker.BTC-7JUN24-70000-C.raw""#,
+                    expected: None,
+                },
+                TestCase {
+                    // TC2: invalid channel w/o interval
+                    input: r#""ticker.BTC-7JUN24-70000-C""#,
+                    expected: None,
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                let mut deserializer = serde_json::Deserializer::from_str(test.input);
+                let actual = de_message_subscription_id(&mut deserializer).ok();
+                assert_eq!(actual, test.expected, "TC{index} failed");
+            }
+        }
+    }
+}
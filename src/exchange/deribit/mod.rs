@@ -0,0 +1,145 @@
+use self::{
+    book::l2::DeribitBookUpdater, channel::DeribitChannel, market::DeribitMarket,
+    option::DeribitOptionTickers, subscription::DeribitSubResponse, trade::DeribitTrades,
+};
+use crate::{
+    exchange::{Connector, ExchangeId, ExchangeSub, StreamSelector},
+    instrument::InstrumentData,
+    subscriber::{validator::WebSocketSubValidator, WebSocketSubscriber},
+    subscription::{
+        book::{OrderBooksL1, OrderBooksL2},
+        option::OptionSummaries,
+        trade::PublicTrades,
+        Map,
+    },
+    transformer::{
+        book::{l1::OrderBookL1Transformer, MultiBookTransformer},
+        stateless::StatelessTransformer,
+    },
+    ExchangeWsStream,
+};
+use barter_integration::{
+    error::SocketError, model::instrument::Instrument, protocol::websocket::WsMessage,
+};
+use barter_macro::{DeExchange, SerExchange};
+use serde_json::json;
+use url::Url;
+
+/// OrderBook types for [`Deribit`].
+pub mod book;
+
+/// Defines the type that translates a Barter [`Subscription`](crate::subscription::Subscription)
+/// into an exchange [`Connector`] specific channel used for generating [`Connector::requests`].
+pub mod channel;
+
+/// Defines the type that translates a Barter [`Subscription`](crate::subscription::Subscription)
+/// into an exchange [`Connector`] specific market used for generating [`Connector::requests`].
+pub mod market;
+
+/// Generic [`DeribitMessage<T>`](message::DeribitMessage) notification type common to all
+/// [`Deribit`] channels.
+pub mod message;
+
+/// Option ticker types for [`Deribit`].
+pub mod option;
+
+/// Public trade types for [`Deribit`].
+pub mod trade;
+
+/// [`Subscription`](crate::subscription::Subscription) response type and response
+/// [`Validator`](barter_integration::Validator) for [`Deribit`].
+pub mod subscription;
+
+/// [`Deribit`] server base url.
+///
+/// See docs: <https://docs.deribit.com/#json-rpc>
+pub const BASE_URL_DERIBIT: &str = "wss://www.deribit.com/ws/api/v2";
+
+/// [`Deribit`] notification interval used for every channel.
+///
+/// Note that "raw" notifications are only available to authorised connections.
+///
+/// See docs: <https://docs.deribit.com/#subscriptions>
+pub const DERIBIT_INTERVAL: &str = "100ms";
+
+/// [`Deribit`] exchange.
+///
+/// See docs: <https://docs.deribit.com/>
+#[derive(
+    Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default, DeExchange, SerExchange,
+)]
+pub struct Deribit;
+
+impl Connector for Deribit {
+    const ID: ExchangeId = ExchangeId::Deribit;
+    type Channel = DeribitChannel;
+    type Market = DeribitMarket;
+    type Subscriber = WebSocketSubscriber;
+    type SubValidator = WebSocketSubValidator;
+    type SubResponse = DeribitSubResponse;
+
+    fn url() -> Result<Url, SocketError> {
+        Url::parse(BASE_URL_DERIBIT).map_err(SocketError::UrlParse)
+    }
+
+    fn requests(exchange_subs: Vec<ExchangeSub<Self::Channel, Self::Market>>) -> Vec<WsMessage> {
+        let channels = exchange_subs
+            .into_iter()
+            .map(|ExchangeSub { channel, market }| {
+                format!(
+                    "{}.{}.{DERIBIT_INTERVAL}",
+                    channel.as_ref(),
+                    market.as_ref()
+                )
+            })
+            .collect::<Vec<_>>();
+
+        vec![WsMessage::Text(
+            json!({
+                "jsonrpc": "2.0",
+                "id": 1,
+                "method": "public/subscribe",
+                "params": {
+                    "channels": channels,
+                },
+            })
+            .to_string(),
+        )]
+    }
+
+    fn expected_responses<InstrumentId>(_: &Map<InstrumentId>) -> usize {
+        1
+    }
+}
+
+impl<Instrument> StreamSelector<Instrument, PublicTrades> for Deribit
+where
+    Instrument: InstrumentData,
+{
+    type Stream =
+        ExchangeWsStream<StatelessTransformer<Self, Instrument::Id, PublicTrades, DeribitTrades>>;
+}
+
+impl<Instrument> StreamSelector<Instrument, OptionSummaries> for Deribit
+where
+    Instrument: InstrumentData,
+{
+    type Stream = ExchangeWsStream<
+        StatelessTransformer<Self, Instrument::Id, OptionSummaries, DeribitOptionTickers>,
+    >;
+}
+
+impl StreamSelector<Instrument, OrderBooksL2> for Deribit {
+    type Stream =
+        ExchangeWsStream<MultiBookTransformer<Self, Instrument, OrderBooksL2, DeribitBookUpdater>>;
+}
+
+impl StreamSelector<Instrument, OrderBooksL1> for Deribit {
+    type Stream = ExchangeWsStream<
+        OrderBookL1Transformer<
+            Self,
+            Instrument,
+            MultiBookTransformer<Self, Instrument, OrderBooksL2, DeribitBookUpdater>,
+        >,
+    >;
+}
@@ -0,0 +1,178 @@
+use super::message::DeribitMessage;
+use crate::{
+    event::{MarketEvent, MarketIter},
+    exchange::ExchangeId,
+    subscription::option::OptionSummary,
+};
+use barter_integration::model::Exchange;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Terse type alias for a [`Deribit`](super::Deribit) option ticker [`DeribitMessage`].
+pub type DeribitOptionTickers = DeribitMessage<DeribitOptionTicker>;
+
+/// [`Deribit`](super::Deribit) option ticker data, containing the mark price, implied volatility
+/// & greeks of an option contract.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://docs.deribit.com/#ticker-instrument_name-interval>
+/// ```json
+/// {
+///     "timestamp": 1717718400000,
+///     "state": "open",
+///     "open_interest": 1024.5,
+///     "mark_price": 0.0625,
+///     "mark_iv": 54.32,
+///     "last_price": 0.062,
+///     "instrument_name": "BTC-7JUN24-70000-C",
+///     "index_price": 64000.12,
+///     "greeks": {
+///         "vega": 21.243,
+///         "theta": -40.12,
+///         "rho": 1.201,
+///         "gamma": 0.00002,
+///         "delta": 0.4321
+///     },
+///     "bid_iv": 53.1,
+///     "best_bid_price": 0.061,
+///     "best_bid_amount": 10.0,
+///     "best_ask_price": 0.064,
+///     "best_ask_amount": 5.0,
+///     "ask_iv": 55.2,
+///     "underlying_price": 64500.5,
+///     "underlying_index": "BTC-7JUN24"
+/// }
+/// ```
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct DeribitOptionTicker {
+    #[serde(
+        alias = "timestamp",
+        deserialize_with = "crate::util::time::de_u64_epoch_ms_as_datetime_utc"
+    )]
+    pub time: DateTime<Utc>,
+    pub mark_price: f64,
+    pub mark_iv: f64,
+    pub open_interest: f64,
+    pub greeks: DeribitGreeks,
+}
+
+/// [`Deribit`](super::Deribit) option greeks.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct DeribitGreeks {
+    pub delta: f64,
+    pub gamma: f64,
+    pub vega: f64,
+    pub theta: f64,
+}
+
+impl<InstrumentId> From<(ExchangeId, InstrumentId, DeribitOptionTickers)>
+    for MarketIter<InstrumentId, OptionSummary>
+{
+    fn from(
+        (exchange_id, instrument, message): (ExchangeId, InstrumentId, DeribitOptionTickers),
+    ) -> Self {
+        let ticker = message.params.data;
+        Self(vec![Ok(MarketEvent {
+            exchange_time: ticker.time,
+            received_time: Utc::now(),
+            exchange: Exchange::from(exchange_id),
+            instrument,
+            kind: OptionSummary {
+                // Deribit mark_iv is a percentage (eg/ 54.32 for 54.32%)
+                iv: ticker.mark_iv / 100.0,
+                delta: ticker.greeks.delta,
+                gamma: ticker.greeks.gamma,
+                vega: ticker.greeks.vega,
+                theta: ticker.greeks.theta,
+                mark: ticker.mark_price,
+                open_interest: ticker.open_interest,
+            },
+        })])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod de {
+        use super::*;
+        use crate::exchange::deribit::message::DeribitParams;
+        use barter_integration::{
+            de::datetime_utc_from_epoch_duration, error::SocketError, model::SubscriptionId,
+        };
+        use std::time::Duration;
+
+        #[test]
+        fn test_deribit_option_tickers() {
+            struct TestCase {
+                input: &'static str,
+                expected: Result<DeribitOptionTickers, SocketError>,
+            }
+
+            let tests = vec![TestCase {
+                // TC0: valid option ticker notification
+                input: r#"
+                {
+                    "jsonrpc": "2.0",
+                    "method": "subscription",
+                    "params": {
+                        "channel": "ticker.BTC-7JUN24-70000-C.100ms",
+                        "data": {
+                            "timestamp": 1717718400000,
+                            "state": "open",
+                            "open_interest": 1024.5,
+                            "mark_price": 0.0625,
+                            "mark_iv": 54.32,
+                            "instrument_name": "BTC-7JUN24-70000-C",
+                            "greeks": {
+                                "vega": 21.243,
+                                "theta": -40.12,
+                                "rho": 1.201,
+                                "gamma": 0.00002,
+                                "delta": 0.4321
+                            },
+                            "underlying_price": 64500.5
+                        }
+                    }
+                }
+                "#,
+                expected: Ok(DeribitMessage {
+                    params: DeribitParams {
+                        subscription_id: SubscriptionId::from("ticker|BTC-7JUN24-70000-C"),
+                        data: DeribitOptionTicker {
+                            time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                                1717718400000,
+                            )),
+                            mark_price: 0.0625,
+                            mark_iv: 54.32,
+                            open_interest: 1024.5,
+                            greeks: DeribitGreeks {
+                                delta: 0.4321,
+                                gamma: 0.00002,
+                                vega: 21.243,
+                                theta: -40.12,
+                            },
+                        },
+                    },
+                }),
+            }];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                let actual = serde_json::from_str::<DeribitOptionTickers>(test.input);
+                match (actual, test.expected) {
+                    (Ok(actual), Ok(expected)) => {
+                        assert_eq!(actual, expected, "TC{} failed", index)
+                    }
+                    (Err(_), Err(_)) => {
+                        // Test passed
+                    }
+                    (actual, expected) => {
+                        // Test failed
+                        panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                    }
+                }
+            }
+        }
+    }
+}
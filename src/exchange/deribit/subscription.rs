@@ -0,0 +1,171 @@
+use barter_integration::{error::SocketError, Validator};
+use serde::{Deserialize, Serialize};
+
+/// [`Deribit`](super::Deribit) JSON-RPC subscription response.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://docs.deribit.com/#public-subscribe>
+/// #### Subscription Ok Response
+/// ```json
+/// {
+///     "jsonrpc": "2.0",
+///     "id": 1,
+///     "result": ["ticker.BTC-7JUN24-70000-C.100ms"],
+///     "usIn": 1717718400000000,
+///     "usOut": 1717718400000100,
+///     "usDiff": 100,
+///     "testnet": false
+/// }
+/// ```
+///
+/// #### Subscription Error Response
+/// ```json
+/// {
+///     "jsonrpc": "2.0",
+///     "id": 1,
+///     "error": {
+///         "code": -32602,
+///         "message": "Invalid params"
+///     }
+/// }
+/// ```
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+#[serde(untagged)]
+pub enum DeribitSubResponse {
+    Subscribed { result: Vec<String> },
+    Error { error: DeribitError },
+}
+
+/// [`Deribit`](super::Deribit) JSON-RPC error.
+///
+/// See docs: <https://docs.deribit.com/#json-rpc>
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct DeribitError {
+    pub code: i64,
+    pub message: String,
+}
+
+impl Validator for DeribitSubResponse {
+    fn validate(self) -> Result<Self, SocketError>
+    where
+        Self: Sized,
+    {
+        match self {
+            Self::Subscribed { result } if result.is_empty() => Err(SocketError::Subscribe(
+                "received empty subscription result".to_owned(),
+            )),
+            Self::Subscribed { .. } => Ok(self),
+            Self::Error { error } => Err(SocketError::Subscribe(format!(
+                "received failure subscription response code: {} with message: {}",
+                error.code, error.message,
+            ))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod de {
+        use super::*;
+
+        #[test]
+        fn test_deribit_sub_response() {
+            struct TestCase {
+                input: &'static str,
+                expected: Result<DeribitSubResponse, SocketError>,
+            }
+
+            let cases = vec![
+                TestCase {
+                    // TC0: input response is Subscribed
+                    input: r#"
+                    {
+                        "jsonrpc": "2.0",
+                        "id": 1,
+                        "result": ["ticker.BTC-7JUN24-70000-C.100ms"],
+                        "usIn": 1717718400000000,
+                        "usOut": 1717718400000100,
+                        "usDiff": 100,
+                        "testnet": false
+                    }
+                    "#,
+                    expected: Ok(DeribitSubResponse::Subscribed {
+                        result: vec!["ticker.BTC-7JUN24-70000-C.100ms".to_string()],
+                    }),
+                },
+                TestCase {
+                    // TC1: input response is Error
+                    input: r#"
+                    {
+                        "jsonrpc": "2.0",
+                        "id": 1,
+                        "error": {"code": -32602, "message": "Invalid params"}
+                    }
+                    "#,
+                    expected: Ok(DeribitSubResponse::Error {
+                        error: DeribitError {
+                            code: -32602,
+                            message: "Invalid params".to_string(),
+                        },
+                    }),
+                },
+            ];
+
+            for (index, test) in cases.into_iter().enumerate() {
+                let actual = serde_json::from_str::<DeribitSubResponse>(test.input);
+                match (actual, test.expected) {
+                    (Ok(actual), Ok(expected)) => {
+                        assert_eq!(actual, expected, "TC{} failed", index)
+                    }
+                    (Err(_), Err(_)) => {
+                        // Test passed
+                    }
+                    (actual, expected) => {
+                        // Test failed
+                        panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_validate_deribit_sub_response() {
+        struct TestCase {
+            input_response: DeribitSubResponse,
+            is_valid: bool,
+        }
+
+        let cases = vec![
+            TestCase {
+                // TC0: input response is Subscribed
+                input_response: DeribitSubResponse::Subscribed {
+                    result: vec!["ticker.BTC-7JUN24-70000-C.100ms".to_string()],
+                },
+                is_valid: true,
+            },
+            TestCase {
+                // TC1: input response is Subscribed to nothing
+                input_response: DeribitSubResponse::Subscribed { result: vec![] },
+                is_valid: false,
+            },
+            TestCase {
+                // TC2: input response is Error
+                input_response: DeribitSubResponse::Error {
+                    error: DeribitError {
+                        code: -32602,
+                        message: "Invalid params".to_string(),
+                    },
+                },
+                is_valid: false,
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = test.input_response.validate().is_ok();
+            assert_eq!(actual, test.is_valid, "TestCase {} failed", index);
+        }
+    }
+}
@@ -0,0 +1,247 @@
+use super::message::DeribitMessage;
+use crate::{
+    event::{MarketEvent, MarketIter},
+    exchange::ExchangeId,
+    subscription::trade::PublicTrade,
+};
+use barter_integration::model::{Exchange, Side};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Terse type alias for a [`Deribit`](super::Deribit) real-time trades [`DeribitMessage`].
+pub type DeribitTrades = DeribitMessage<Vec<DeribitTrade>>;
+
+/// [`Deribit`](super::Deribit) real-time trade.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://docs.deribit.com/#trades-instrument_name-interval>
+/// ```json
+/// {
+///     "trade_seq": 30289442,
+///     "trade_id": "48079269",
+///     "timestamp": 1590484156350,
+///     "tick_direction": 2,
+///     "price": 8950.0,
+///     "mark_price": 8948.9,
+///     "instrument_name": "BTC-PERPETUAL",
+///     "index_price": 8955.88,
+///     "direction": "sell",
+///     "amount": 10.0
+/// }
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct DeribitTrade {
+    #[serde(alias = "trade_id")]
+    pub id: String,
+    #[serde(
+        alias = "timestamp",
+        deserialize_with = "crate::util::time::de_u64_epoch_ms_as_datetime_utc"
+    )]
+    pub time: DateTime<Utc>,
+    pub price: f64,
+    pub amount: f64,
+    /// Taker [`Side`] of the trade.
+    #[serde(alias = "direction")]
+    pub side: Side,
+}
+
+impl<InstrumentId> From<(ExchangeId, InstrumentId, DeribitTrades)>
+    for MarketIter<InstrumentId, PublicTrade>
+where
+    InstrumentId: Clone,
+{
+    fn from((exchange_id, instrument, trades): (ExchangeId, InstrumentId, DeribitTrades)) -> Self {
+        trades
+            .params
+            .data
+            .into_iter()
+            .map(|trade| {
+                Ok(MarketEvent {
+                    exchange_time: trade.time,
+                    received_time: Utc::now(),
+                    exchange: Exchange::from(exchange_id),
+                    instrument: instrument.clone(),
+                    kind: PublicTrade {
+                        id: trade.id,
+                        price: trade.price,
+                        amount: trade.amount,
+                        side: trade.side,
+                    },
+                })
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::subscription::trade::tests::assert_duplicate_frame_dedup_keys;
+
+    mod de {
+        use super::*;
+        use crate::exchange::deribit::message::DeribitParams;
+        use barter_integration::{
+            de::datetime_utc_from_epoch_duration, error::SocketError, model::SubscriptionId,
+        };
+        use serde::de::Error;
+        use std::time::Duration;
+
+        #[test]
+        fn test_deribit_trades() {
+            struct TestCase {
+                input: &'static str,
+                expected: Result<DeribitTrades, SocketError>,
+            }
+
+            let tests = vec![
+                TestCase {
+                    // TC0: valid perpetual trades notification w/ Side::Sell & Side::Buy
+                    input: r#"
+                    {
+                        "jsonrpc": "2.0",
+                        "method": "subscription",
+                        "params": {
+                            "channel": "trades.BTC-PERPETUAL.100ms",
+                            "data": [
+                                {
+                                    "trade_seq": 30289442,
+                                    "trade_id": "48079269",
+                                    "timestamp": 1590484156350,
+                                    "tick_direction": 2,
+                                    "price": 8950.0,
+                                    "mark_price": 8948.9,
+                                    "instrument_name": "BTC-PERPETUAL",
+                                    "index_price": 8955.88,
+                                    "direction": "sell",
+                                    "amount": 10.0
+                                },
+                                {
+                                    "trade_seq": 30289443,
+                                    "trade_id": "48079270",
+                                    "timestamp": 1590484156351,
+                                    "tick_direction": 0,
+                                    "price": 8950.5,
+                                    "mark_price": 8948.9,
+                                    "instrument_name": "BTC-PERPETUAL",
+                                    "index_price": 8955.88,
+                                    "direction": "buy",
+                                    "amount": 20.0
+                                }
+                            ]
+                        }
+                    }
+                    "#,
+                    expected: Ok(DeribitMessage {
+                        params: DeribitParams {
+                            subscription_id: SubscriptionId::from("trades|BTC-PERPETUAL"),
+                            data: vec![
+                                DeribitTrade {
+                                    id: "48079269".to_string(),
+                                    time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                                        1590484156350,
+                                    )),
+                                    price: 8950.0,
+                                    amount: 10.0,
+                                    side: Side::Sell,
+                                },
+                                DeribitTrade {
+                                    id: "48079270".to_string(),
+                                    time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                                        1590484156351,
+                                    )),
+                                    price: 8950.5,
+                                    amount: 20.0,
+                                    side: Side::Buy,
+                                },
+                            ],
+                        },
+                    }),
+                },
+                TestCase {
+                    // TC1: invalid trades notification w/ unknown direction
+                    input: r#"
+                    {
+                        "jsonrpc": "2.0",
+                        "method": "subscription",
+                        "params": {
+                            "channel": "trades.BTC-PERPETUAL.100ms",
+                            "data": [
+                                {
+                                    "trade_id": "48079269",
+                                    "timestamp": 1590484156350,
+                                    "price": 8950.0,
+                                    "direction": "sideways",
+                                    "amount": 10.0
+                                }
+                            ]
+                        }
+                    }
+                    "#,
+                    expected: Err(SocketError::Deserialise {
+                        error: serde_json::Error::custom(""),
+                        payload: "".to_owned(),
+                    }),
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                let actual = serde_json::from_str::<DeribitTrades>(test.input);
+                match (actual, test.expected) {
+                    (Ok(actual), Ok(expected)) => {
+                        assert_eq!(actual, expected, "TC{} failed", index)
+                    }
+                    (Err(_), Err(_)) => {
+                        // Test passed
+                    }
+                    (actual, expected) => {
+                        // Test failed
+                        panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_deribit_trades_duplicate_frame_dedup_keys() {
+        assert_duplicate_frame_dedup_keys::<DeribitTrades>(
+            ExchangeId::Deribit,
+            r#"
+            {
+                "jsonrpc": "2.0",
+                "method": "subscription",
+                "params": {
+                    "channel": "trades.BTC-PERPETUAL.100ms",
+                    "data": [
+                        {
+                            "trade_seq": 30289442,
+                            "trade_id": "48079269",
+                            "timestamp": 1590484156350,
+                            "tick_direction": 2,
+                            "price": 8950.0,
+                            "mark_price": 8948.9,
+                            "instrument_name": "BTC-PERPETUAL",
+                            "index_price": 8955.88,
+                            "direction": "sell",
+                            "amount": 10.0
+                        },
+                        {
+                            "trade_seq": 30289443,
+                            "trade_id": "48079270",
+                            "timestamp": 1590484156351,
+                            "tick_direction": 0,
+                            "price": 8950.5,
+                            "mark_price": 8948.9,
+                            "instrument_name": "BTC-PERPETUAL",
+                            "index_price": 8955.88,
+                            "direction": "buy",
+                            "amount": 20.0
+                        }
+                    ]
+                }
+            }
+            "#,
+        );
+    }
+}
@@ -0,0 +1,68 @@
+use super::Connector;
+use barter_integration::error::SocketError;
+use url::Url;
+
+/// [`Url`] of the provided exchange [`Connector`] at the provided endpoint index, where `0` is the
+/// primary endpoint. Indexes beyond the last backup wrap back to the primary.
+pub fn url<Exchange>(endpoint: usize) -> Result<Url, SocketError>
+where
+    Exchange: Connector,
+{
+    let mut urls = Exchange::urls()?;
+    if urls.is_empty() {
+        return Exchange::url();
+    }
+
+    let index = endpoint % urls.len();
+    Ok(urls.swap_remove(index))
+}
+
+/// Number of endpoints advertised by the provided exchange [`Connector`] (primary + backups).
+pub fn endpoints<Exchange>() -> usize
+where
+    Exchange: Connector,
+{
+    Exchange::urls().map_or(1, |urls| urls.len().max(1))
+}
+
+/// Rotate the provided endpoint index of the exchange [`Connector`] to the next advertised
+/// [`Url`], wrapping back to the primary after the last backup. Returns the new endpoint index.
+///
+/// Each [`consumer`](crate::streams::consumer) loop tracks its own active endpoint, so
+/// connections of distinct [`StreamBuilder`](crate::streams::builder::StreamBuilder)s fail over
+/// independently.
+pub fn rotate<Exchange>(endpoint: usize) -> usize
+where
+    Exchange: Connector,
+{
+    (endpoint + 1) % endpoints::<Exchange>()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::exchange::okx::{Okx, BACKUP_URL_OKX, BASE_URL_OKX};
+
+    #[test]
+    fn test_okx_urls() {
+        let actual = Okx::urls()
+            .unwrap()
+            .into_iter()
+            .map(String::from)
+            .collect::<Vec<_>>();
+        assert_eq!(actual, vec![BASE_URL_OKX, BACKUP_URL_OKX]);
+        assert_eq!(endpoints::<Okx>(), 2);
+    }
+
+    #[test]
+    fn test_rotate() {
+        // Rotates through the backups, wrapping back to the primary
+        assert_eq!(rotate::<Okx>(0), 1);
+        assert_eq!(rotate::<Okx>(1), 0);
+
+        // Endpoint indexes resolve to the advertised urls, wrapping back to the primary
+        assert_eq!(url::<Okx>(0).unwrap().as_str(), BASE_URL_OKX);
+        assert_eq!(url::<Okx>(1).unwrap().as_str(), BACKUP_URL_OKX);
+        assert_eq!(url::<Okx>(2).unwrap().as_str(), BASE_URL_OKX);
+    }
+}
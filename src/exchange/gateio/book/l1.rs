@@ -0,0 +1,216 @@
+use super::{super::message::GateioMessage, de_str_or_f64};
+use crate::{
+    event::{MarketEvent, MarketIter},
+    exchange::{ExchangeId, ExchangeSub},
+    subscription::book::{Level, OrderBookL1},
+    Identifier,
+};
+use barter_integration::model::{Exchange, SubscriptionId};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Terse type alias for a [`Gateio`](super::super::Gateio) real-time best bid & ask WebSocket
+/// message.
+pub type GateioOrderBookL1 = GateioMessage<GateioOrderBookL1Inner>;
+
+/// [`Gateio`](super::super::Gateio) real-time best bid & ask, sent via the `spot.book_ticker` &
+/// `futures.book_ticker` channels.
+///
+/// ### Raw Payload Examples
+/// #### GateioSpot
+/// See docs: <https://www.gate.io/docs/developers/apiv4/ws/en/#best-bid-or-ask-price>
+/// ```json
+/// {
+///   "t": 1606293275123,
+///   "u": 48733182,
+///   "s": "BTC_USDT",
+///   "b": "19177.79",
+///   "B": "0.0003341504",
+///   "a": "19179.38",
+///   "A": "0.09"
+/// }
+/// ```
+///
+/// #### GateioFuturesUsd
+/// See docs: <https://www.gate.io/docs/developers/delivery/ws/en/#best-ask-bid-subscription>
+/// ```json
+/// {
+///   "t": 1615366379123,
+///   "u": 2517661076,
+///   "s": "BTC_USDT_20230630",
+///   "b": "54696.6",
+///   "B": 37000,
+///   "a": "54696.7",
+///   "A": 47061
+/// }
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct GateioOrderBookL1Inner {
+    #[serde(alias = "s")]
+    pub market: String,
+    #[serde(
+        alias = "t",
+        deserialize_with = "crate::util::time::de_u64_epoch_ms_as_datetime_utc"
+    )]
+    pub time: DateTime<Utc>,
+    #[serde(alias = "b", deserialize_with = "barter_integration::de::de_str")]
+    pub best_bid_price: f64,
+    #[serde(alias = "B", deserialize_with = "de_str_or_f64")]
+    pub best_bid_amount: f64,
+    #[serde(alias = "a", deserialize_with = "barter_integration::de::de_str")]
+    pub best_ask_price: f64,
+    #[serde(alias = "A", deserialize_with = "de_str_or_f64")]
+    pub best_ask_amount: f64,
+}
+
+impl Identifier<Option<SubscriptionId>> for GateioOrderBookL1 {
+    fn id(&self) -> Option<SubscriptionId> {
+        Some(ExchangeSub::from((&self.channel, &self.data.market)).id())
+    }
+}
+
+impl<InstrumentId> From<(ExchangeId, InstrumentId, GateioOrderBookL1)>
+    for MarketIter<InstrumentId, OrderBookL1>
+{
+    fn from(
+        (exchange_id, instrument, book): (ExchangeId, InstrumentId, GateioOrderBookL1),
+    ) -> Self {
+        Self(vec![Ok(MarketEvent {
+            exchange_time: book.data.time,
+            received_time: Utc::now(),
+            exchange: Exchange::from(exchange_id),
+            instrument,
+            kind: OrderBookL1 {
+                last_update_time: book.data.time,
+                best_bid: Level::new(book.data.best_bid_price, book.data.best_bid_amount),
+                best_ask: Level::new(book.data.best_ask_price, book.data.best_ask_amount),
+            },
+        })])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod de {
+        use super::*;
+        use barter_integration::{de::datetime_utc_from_epoch_duration, error::SocketError};
+        use serde::de::Error;
+        use std::time::Duration;
+
+        #[test]
+        fn test_gateio_order_book_l1() {
+            struct TestCase {
+                input: &'static str,
+                expected: Result<GateioOrderBookL1, SocketError>,
+            }
+
+            let tests = vec![
+                TestCase {
+                    // TC0: valid GateioSpot book ticker w/ String amounts
+                    input: r#"
+                    {
+                        "time": 1606293275,
+                        "time_ms": 1606293275723,
+                        "channel": "spot.book_ticker",
+                        "event": "update",
+                        "result": {
+                            "t": 1606293275123,
+                            "u": 48733182,
+                            "s": "BTC_USDT",
+                            "b": "19177.79",
+                            "B": "0.0003341504",
+                            "a": "19179.38",
+                            "A": "0.09"
+                        }
+                    }
+                    "#,
+                    expected: Ok(GateioOrderBookL1 {
+                        channel: "spot.book_ticker".to_string(),
+                        error: None,
+                        data: GateioOrderBookL1Inner {
+                            market: "BTC_USDT".to_string(),
+                            time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                                1606293275123,
+                            )),
+                            best_bid_price: 19177.79,
+                            best_bid_amount: 0.0003341504,
+                            best_ask_price: 19179.38,
+                            best_ask_amount: 0.09,
+                        },
+                    }),
+                },
+                TestCase {
+                    // TC1: valid GateioFuturesUsd book ticker w/ integer amounts
+                    input: r#"
+                    {
+                        "time": 1615366379,
+                        "time_ms": 1615366379123,
+                        "channel": "futures.book_ticker",
+                        "event": "update",
+                        "error": null,
+                        "result": {
+                            "t": 1615366379123,
+                            "u": 2517661076,
+                            "s": "BTC_USDT_20230630",
+                            "b": "54696.6",
+                            "B": 37000,
+                            "a": "54696.7",
+                            "A": 47061
+                        }
+                    }
+                    "#,
+                    expected: Ok(GateioOrderBookL1 {
+                        channel: "futures.book_ticker".to_string(),
+                        error: None,
+                        data: GateioOrderBookL1Inner {
+                            market: "BTC_USDT_20230630".to_string(),
+                            time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                                1615366379123,
+                            )),
+                            best_bid_price: 54696.6,
+                            best_bid_amount: 37000.0,
+                            best_ask_price: 54696.7,
+                            best_ask_amount: 47061.0,
+                        },
+                    }),
+                },
+                TestCase {
+                    // TC2: invalid book ticker w/ non-numeric amount
+                    input: r#"
+                    {
+                        "time": 1606293275,
+                        "channel": "spot.book_ticker",
+                        "event": "update",
+                        "result": {
+                            "t": 1606293275123, "u": 48733182, "s": "BTC_USDT",
+                            "b": "19177.79", "B": "many", "a": "19179.38", "A": "0.09"
+                        }
+                    }
+                    "#,
+                    expected: Err(SocketError::Deserialise {
+                        error: serde_json::Error::custom(""),
+                        payload: "".to_owned(),
+                    }),
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                let actual = serde_json::from_str::<GateioOrderBookL1>(test.input);
+                match (actual, test.expected) {
+                    (Ok(actual), Ok(expected)) => {
+                        assert_eq!(actual, expected, "TC{} failed", index)
+                    }
+                    (Err(_), Err(_)) => {
+                        // Test passed
+                    }
+                    (actual, expected) => {
+                        // Test failed
+                        panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                    }
+                }
+            }
+        }
+    }
+}
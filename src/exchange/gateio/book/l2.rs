@@ -0,0 +1,727 @@
+use super::{
+    super::{
+        market::{gateio_market, GateioMarket},
+        message::GateioMessage,
+    },
+    de_str_or_f64,
+};
+use crate::{
+    error::DataError,
+    exchange::{ExchangeServer, ExchangeSub},
+    subscription::book::{pool::LevelPool, Level, OrderBook, OrderBookSide},
+    transformer::book::{snapshot::SnapshotFetcher, InstrumentOrderBook, OrderBookUpdater},
+    Identifier,
+};
+use async_trait::async_trait;
+use barter_integration::{
+    model::{instrument::Instrument, Side, SubscriptionId},
+    protocol::websocket::WsMessage,
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::marker::PhantomData;
+use tokio::sync::mpsc;
+
+/// [`Gateio`](super::super::Gateio) [`ExchangeServer`] that supports OrderBook Level2
+/// subscriptions initialised from a HTTP OrderBook snapshot.
+pub trait GateioServerBookL2: ExchangeServer {
+    /// HTTP OrderBook Level2 snapshot url (w/ id) for the provided [`GateioMarket`].
+    fn http_book_l2_snapshot_url(market: &GateioMarket) -> String;
+}
+
+/// [`Gateio`](super::super::Gateio) OrderBook Level2 snapshot HTTP message.
+///
+/// Used as the starting [`OrderBook`] before OrderBook Level2 delta WebSocket updates are
+/// applied.
+///
+/// ### Payload Examples
+/// #### GateioSpot
+/// See docs: <https://www.gate.io/docs/developers/apiv4/en/#retrieve-order-book>
+/// ```json
+/// {
+///     "id": 123456,
+///     "current": 1623898993123,
+///     "update": 1623898993121,
+///     "asks": [["1.52", "1.151"], ["1.53", "1.218"]],
+///     "bids": [["1.17", "201.863"], ["1.16", "725.464"]]
+/// }
+/// ```
+///
+/// #### GateioPerpetualsUsd
+/// See docs: <https://www.gate.io/docs/developers/apiv4/en/#futures-order-book>
+/// ```json
+/// {
+///     "id": 123456,
+///     "current": 1623898993.123,
+///     "update": 1623898993.121,
+///     "asks": [{"p": "1.52", "s": 100}, {"p": "1.53", "s": 40}],
+///     "bids": [{"p": "1.17", "s": 150}, {"p": "1.16", "s": 203}]
+/// }
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct GateioOrderBookL2Snapshot {
+    #[serde(rename = "id")]
+    pub last_update_id: u64,
+    pub bids: Vec<GateioLevel>,
+    pub asks: Vec<GateioLevel>,
+}
+
+impl From<GateioOrderBookL2Snapshot> for OrderBook {
+    fn from(snapshot: GateioOrderBookL2Snapshot) -> Self {
+        Self {
+            last_update_time: Utc::now(),
+            bids: OrderBookSide::new(Side::Buy, snapshot.bids),
+            asks: OrderBookSide::new(Side::Sell, snapshot.asks),
+        }
+    }
+}
+
+/// Terse type alias for a [`Gateio`](super::super::Gateio) OrderBook Level2 deltas WebSocket
+/// message.
+pub type GateioOrderBookL2Delta = GateioMessage<GateioOrderBookL2DeltaInner>;
+
+/// [`Gateio`](super::super::Gateio) OrderBook Level2 deltas, sent via the
+/// `spot.order_book_update` & `futures.order_book_update` channels.
+///
+/// ### Raw Payload Examples
+/// #### GateioSpot
+/// See docs: <https://www.gate.io/docs/developers/apiv4/ws/en/#changed-order-book-levels>
+/// ```json
+/// {
+///     "time": 1606294781,
+///     "time_ms": 1606294781236,
+///     "channel": "spot.order_book_update",
+///     "event": "update",
+///     "result": {
+///         "t": 1606294781123,
+///         "e": "depthUpdate",
+///         "E": 1606294781,
+///         "s": "BTC_USDT",
+///         "U": 48776301,
+///         "u": 48776306,
+///         "b": [["19137.74", "0.0001"], ["19088.37", "0"]],
+///         "a": [["19137.75", "0.6135"]]
+///     }
+/// }
+/// ```
+///
+/// #### GateioPerpetualsUsd
+/// See docs: <https://www.gate.io/docs/developers/futures/ws/en/#order-book-update-subscription>
+/// ```json
+/// {
+///     "time": 1615366381,
+///     "time_ms": 1615366381123,
+///     "channel": "futures.order_book_update",
+///     "event": "update",
+///     "result": {
+///         "t": 1615366381417,
+///         "s": "BTC_USDT",
+///         "U": 2517661101,
+///         "u": 2517661113,
+///         "b": [{"p": "54672.1", "s": 0}, {"p": "54664.5", "s": 58794}],
+///         "a": [{"p": "54743.6", "s": 0}, {"p": "54742", "s": 95}]
+///     }
+/// }
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct GateioOrderBookL2DeltaInner {
+    #[serde(alias = "s")]
+    pub market: String,
+    #[serde(
+        alias = "t",
+        deserialize_with = "crate::util::time::de_u64_epoch_ms_as_datetime_utc"
+    )]
+    pub time: DateTime<Utc>,
+    #[serde(alias = "U")]
+    pub first_update_id: u64,
+    #[serde(alias = "u")]
+    pub last_update_id: u64,
+    #[serde(alias = "b")]
+    pub bids: Vec<GateioLevel>,
+    #[serde(alias = "a")]
+    pub asks: Vec<GateioLevel>,
+}
+
+impl Identifier<Option<SubscriptionId>> for GateioOrderBookL2Delta {
+    fn id(&self) -> Option<SubscriptionId> {
+        Some(ExchangeSub::from((&self.channel, &self.data.market)).id())
+    }
+}
+
+/// [`Gateio`](super::super::Gateio) OrderBook level.
+///
+/// Spot levels are sent as a `[price, amount]` sequence of `String`s, whereas futures levels are
+/// sent as a `{"p": price, "s": contracts}` object.
+///
+/// #### Raw Payload Examples
+/// See docs: <https://www.gate.io/docs/developers/apiv4/ws/en/#changed-order-book-levels>
+/// ```json
+/// ["19137.74", "0.0001"]
+/// ```
+///
+/// See docs: <https://www.gate.io/docs/developers/futures/ws/en/#order-book-update-subscription>
+/// ```json
+/// {"p": "54664.5", "s": 58794}
+/// ```
+#[derive(Clone, Copy, PartialEq, PartialOrd, Debug, Serialize)]
+pub struct GateioLevel {
+    pub price: f64,
+    pub amount: f64,
+}
+
+impl From<GateioLevel> for Level {
+    fn from(level: GateioLevel) -> Self {
+        Self {
+            price: level.price,
+            amount: level.amount,
+        }
+    }
+}
+
+/// Generic [`Gateio`](super::super::Gateio) [`OrderBookUpdater`] shared by every
+/// [`GateioServerBookL2`].
+///
+/// Gateio: How To Maintain A Local OrderBook
+///
+/// 1. Subscribe to the `spot.order_book_update` or `futures.order_book_update` channel.
+/// 2. Buffer the events you receive from the stream.
+/// 3. Get a depth snapshot (w/ id) from the HTTP OrderBook endpoint (eg/
+///    <https://api.gateio.ws/api/v4/spot/order_book?currency_pair=BTC_USDT&limit=100&with_id=true>).
+/// 4. Drop any event where u is < id+1 in the snapshot.
+/// 5. The first processed event should have U <= id+1 AND u >= id+1.
+/// 6. While listening to the stream, each new event's U should be equal to the previous event's
+///    u+1, otherwise initialize the process from step 3.
+/// 7. The data in each event is the absolute quantity for a price level.
+/// 8. If the quantity is 0, remove the price level.
+///
+/// Notes:
+///  - Uppercase U => first_update_id
+///  - Lowercase u => last_update_id,
+///
+/// See docs: <https://www.gate.io/docs/developers/apiv4/ws/en/#changed-order-book-levels>
+/// See docs: <https://www.gate.io/docs/developers/futures/ws/en/#order-book-update-subscription>
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct GateioBookUpdater<Server> {
+    pub updates_processed: u64,
+    pub last_update_id: u64,
+    server: PhantomData<Server>,
+}
+
+impl<Server> GateioBookUpdater<Server> {
+    /// Construct a new Gateio [`OrderBookUpdater`] using the provided last_update_id from
+    /// a HTTP snapshot.
+    pub fn new(last_update_id: u64) -> Self {
+        Self {
+            updates_processed: 0,
+            last_update_id,
+            server: PhantomData,
+        }
+    }
+
+    /// Gateio: How To Maintain A Local OrderBook: Step 5:
+    /// "The first processed event should have U <= id+1 AND u >= id+1"
+    ///
+    /// See docs: <https://www.gate.io/docs/developers/apiv4/ws/en/#changed-order-book-levels>
+    pub fn is_first_update(&self) -> bool {
+        self.updates_processed == 0
+    }
+
+    /// Gateio: How To Maintain A Local OrderBook: Step 5:
+    /// "The first processed event should have U <= id+1 AND u >= id+1"
+    ///
+    /// See docs: <https://www.gate.io/docs/developers/apiv4/ws/en/#changed-order-book-levels>
+    pub fn validate_first_update(
+        &self,
+        update: &GateioOrderBookL2DeltaInner,
+    ) -> Result<(), DataError> {
+        let expected_next_id = self.last_update_id + 1;
+        if update.first_update_id <= expected_next_id && update.last_update_id >= expected_next_id {
+            Ok(())
+        } else {
+            Err(DataError::InvalidSequence {
+                prev_last_update_id: self.last_update_id,
+                first_update_id: update.first_update_id,
+            })
+        }
+    }
+
+    /// Gateio: How To Maintain A Local OrderBook: Step 6:
+    /// "While listening to the stream, each new event's U should be equal to the
+    ///  previous event's u+1, otherwise initialize the process from step 3."
+    ///
+    /// See docs: <https://www.gate.io/docs/developers/apiv4/ws/en/#changed-order-book-levels>
+    pub fn validate_next_update(
+        &self,
+        update: &GateioOrderBookL2DeltaInner,
+    ) -> Result<(), DataError> {
+        let expected_next_id = self.last_update_id + 1;
+        if update.first_update_id == expected_next_id {
+            Ok(())
+        } else {
+            Err(DataError::InvalidSequence {
+                prev_last_update_id: self.last_update_id,
+                first_update_id: update.first_update_id,
+            })
+        }
+    }
+}
+
+impl<Server> SnapshotFetcher for GateioBookUpdater<Server>
+where
+    Server: GateioServerBookL2,
+{
+    type Snapshot = GateioOrderBookL2Snapshot;
+
+    fn url(instrument: &Instrument) -> String {
+        Server::http_book_l2_snapshot_url(&gateio_market(instrument))
+    }
+}
+
+#[async_trait]
+impl<Server> OrderBookUpdater for GateioBookUpdater<Server>
+where
+    Server: GateioServerBookL2 + Send,
+{
+    type OrderBook = OrderBook;
+    type Update = GateioOrderBookL2Delta;
+    const RESYNC_ON_GAP: bool = true;
+    const AUDIT: bool = true;
+
+    async fn init<Exchange, Kind>(
+        _: mpsc::UnboundedSender<WsMessage>,
+        instrument: Instrument,
+    ) -> Result<InstrumentOrderBook<Instrument, Self>, DataError>
+    where
+        Exchange: Send,
+        Kind: Send,
+    {
+        // Fetch initial OrderBook snapshot via HTTP
+        let snapshot = Self::fetch(&instrument).await?;
+
+        Ok(InstrumentOrderBook {
+            instrument,
+            updater: Self::new(snapshot.last_update_id),
+            book: OrderBook::from(snapshot),
+        })
+    }
+
+    fn update(
+        &mut self,
+        book: &mut Self::OrderBook,
+        update: Self::Update,
+        pool: &mut LevelPool,
+    ) -> Result<Option<Self::OrderBook>, DataError> {
+        // Gateio: How To Maintain A Local OrderBook
+        // See Self's Rust Docs for more information on each numbered step
+        // See docs: <https://www.gate.io/docs/developers/apiv4/ws/en/#changed-order-book-levels>
+        let update = update.data;
+
+        // 4. Drop any event where u is < id+1 in the snapshot:
+        if update.last_update_id <= self.last_update_id {
+            return Ok(None);
+        }
+
+        if self.is_first_update() {
+            // 5. The first processed event should have U <= id+1 AND u >= id+1:
+            self.validate_first_update(&update)?;
+        } else {
+            // 6. Each new event's U should be equal to the previous event's u+1:
+            self.validate_next_update(&update)?;
+        }
+
+        // Update OrderBook metadata & Levels:
+        // 7. The data in each event is the absolute quantity for a price level.
+        // 8. If the quantity is 0, remove the price level.
+        book.last_update_time = update.time;
+        book.bids.upsert(update.bids);
+        book.asks.upsert(update.asks);
+
+        // Update OrderBookUpdater metadata
+        self.updates_processed += 1;
+        self.last_update_id = update.last_update_id;
+
+        Ok(Some(book.snapshot_in(pool)))
+    }
+}
+
+impl<'de> Deserialize<'de> for GateioLevel {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum GateioLevelRaw {
+            Spot(
+                #[serde(deserialize_with = "de_str_or_f64")] f64,
+                #[serde(deserialize_with = "de_str_or_f64")] f64,
+            ),
+            Futures {
+                #[serde(rename = "p", deserialize_with = "de_str_or_f64")]
+                price: f64,
+                #[serde(rename = "s", deserialize_with = "de_str_or_f64")]
+                amount: f64,
+            },
+        }
+
+        Ok(match GateioLevelRaw::deserialize(deserializer)? {
+            GateioLevelRaw::Spot(price, amount) | GateioLevelRaw::Futures { price, amount } => {
+                Self { price, amount }
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod de {
+        use super::*;
+        use barter_integration::{de::datetime_utc_from_epoch_duration, error::SocketError};
+        use std::time::Duration;
+
+        #[test]
+        fn test_gateio_level() {
+            struct TestCase {
+                input: &'static str,
+                expected: Result<GateioLevel, SocketError>,
+            }
+
+            let tests = vec![
+                TestCase {
+                    // TC0: valid spot level
+                    input: r#"["19137.74", "0.0001"]"#,
+                    expected: Ok(GateioLevel {
+                        price: 19137.74,
+                        amount: 0.0001,
+                    }),
+                },
+                TestCase {
+                    // TC1: valid futures level
+                    input: r#"{"p": "54664.5", "s": 58794}"#,
+                    expected: Ok(GateioLevel {
+                        price: 54664.5,
+                        amount: 58794.0,
+                    }),
+                },
+                TestCase {
+                    // TC2: invalid level w/ non-numeric price
+                    input: r#"["not a price", "0.0001"]"#,
+                    expected: Err(SocketError::Unsupported {
+                        entity: "",
+                        item: "".to_string(),
+                    }),
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                let actual = serde_json::from_str::<GateioLevel>(test.input);
+                match (actual, test.expected) {
+                    (Ok(actual), Ok(expected)) => {
+                        assert_eq!(actual, expected, "TC{} failed", index)
+                    }
+                    (Err(_), Err(_)) => {
+                        // Test passed
+                    }
+                    (actual, expected) => {
+                        // Test failed
+                        panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                    }
+                }
+            }
+        }
+
+        #[test]
+        fn test_gateio_order_book_l2_snapshot() {
+            let input = r#"
+            {
+                "id": 123456,
+                "current": 1623898993123,
+                "update": 1623898993121,
+                "asks": [["1.52", "1.151"]],
+                "bids": [["1.17", "201.863"]]
+            }
+            "#;
+
+            assert_eq!(
+                serde_json::from_str::<GateioOrderBookL2Snapshot>(input).unwrap(),
+                GateioOrderBookL2Snapshot {
+                    last_update_id: 123456,
+                    bids: vec![GateioLevel {
+                        price: 1.17,
+                        amount: 201.863
+                    }],
+                    asks: vec![GateioLevel {
+                        price: 1.52,
+                        amount: 1.151
+                    }],
+                }
+            );
+        }
+
+        #[test]
+        fn test_gateio_order_book_l2_delta() {
+            struct TestCase {
+                input: &'static str,
+                expected: (SubscriptionId, GateioOrderBookL2DeltaInner),
+            }
+
+            let tests = vec![
+                TestCase {
+                    // TC0: valid GateioSpot delta
+                    input: r#"
+                    {
+                        "time": 1606294781,
+                        "time_ms": 1606294781236,
+                        "channel": "spot.order_book_update",
+                        "event": "update",
+                        "result": {
+                            "t": 1606294781123,
+                            "e": "depthUpdate",
+                            "E": 1606294781,
+                            "s": "BTC_USDT",
+                            "U": 48776301,
+                            "u": 48776306,
+                            "b": [["19137.74", "0.0001"], ["19088.37", "0"]],
+                            "a": [["19137.75", "0.6135"]]
+                        }
+                    }
+                    "#,
+                    expected: (
+                        SubscriptionId::from("spot.order_book_update|BTC_USDT"),
+                        GateioOrderBookL2DeltaInner {
+                            market: "BTC_USDT".to_string(),
+                            time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                                1606294781123,
+                            )),
+                            first_update_id: 48776301,
+                            last_update_id: 48776306,
+                            bids: vec![
+                                GateioLevel {
+                                    price: 19137.74,
+                                    amount: 0.0001,
+                                },
+                                GateioLevel {
+                                    price: 19088.37,
+                                    amount: 0.0,
+                                },
+                            ],
+                            asks: vec![GateioLevel {
+                                price: 19137.75,
+                                amount: 0.6135,
+                            }],
+                        },
+                    ),
+                },
+                TestCase {
+                    // TC1: valid GateioPerpetualsUsd delta
+                    input: r#"
+                    {
+                        "time": 1615366381,
+                        "time_ms": 1615366381123,
+                        "channel": "futures.order_book_update",
+                        "event": "update",
+                        "result": {
+                            "t": 1615366381417,
+                            "s": "BTC_USDT",
+                            "U": 2517661101,
+                            "u": 2517661113,
+                            "b": [{"p": "54672.1", "s": 0}],
+                            "a": [{"p": "54742", "s": 95}]
+                        }
+                    }
+                    "#,
+                    expected: (
+                        SubscriptionId::from("futures.order_book_update|BTC_USDT"),
+                        GateioOrderBookL2DeltaInner {
+                            market: "BTC_USDT".to_string(),
+                            time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                                1615366381417,
+                            )),
+                            first_update_id: 2517661101,
+                            last_update_id: 2517661113,
+                            bids: vec![GateioLevel {
+                                price: 54672.1,
+                                amount: 0.0,
+                            }],
+                            asks: vec![GateioLevel {
+                                price: 54742.0,
+                                amount: 95.0,
+                            }],
+                        },
+                    ),
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                let actual = serde_json::from_str::<GateioOrderBookL2Delta>(test.input).unwrap();
+                assert_eq!(
+                    (actual.id().unwrap(), actual.data),
+                    test.expected,
+                    "TC{} failed",
+                    index
+                );
+            }
+        }
+    }
+
+    mod gateio_book_updater {
+        use super::*;
+        use crate::{
+            exchange::{
+                gateio::{
+                    channel::GateioChannel,
+                    perpetual::GateioPerpetualsUsd,
+                    spot::{GateioServerSpot, GateioSpot},
+                },
+                StreamSelector,
+            },
+            subscription::{
+                book::{OrderBooksL2, OrderBooksL2Events},
+                Subscription, SubscriptionKind,
+            },
+        };
+        use barter_integration::model::instrument::kind::InstrumentKind;
+
+        #[test]
+        fn test_order_books_l2_events_subscription_id() {
+            fn assert_selector<Exchange, Kind>()
+            where
+                Exchange: StreamSelector<Instrument, Kind>,
+                Kind: SubscriptionKind,
+            {
+            }
+
+            // OrderBooksL2Events snapshot-then-delta streams are available for every Gateio
+            // server w/ an OrderBook Level2 updater
+            assert_selector::<GateioSpot, OrderBooksL2Events>();
+            assert_selector::<GateioPerpetualsUsd, OrderBooksL2Events>();
+
+            // OrderBooksL2Events deltas are routed identically to OrderBooksL2 deltas
+            let instrument = Instrument::from(("btc", "usdt", InstrumentKind::Spot));
+            let events =
+                ExchangeSub::<GateioChannel, GateioMarket>::new(
+                    &Subscription::<_, Instrument, _>::new(
+                        GateioSpot::default(),
+                        instrument.clone(),
+                        OrderBooksL2Events,
+                    ),
+                )
+                .id();
+            let books =
+                ExchangeSub::<GateioChannel, GateioMarket>::new(
+                    &Subscription::<_, Instrument, _>::new(
+                        GateioSpot::default(),
+                        instrument,
+                        OrderBooksL2::default(),
+                    ),
+                )
+                .id();
+
+            assert_eq!(events, books);
+            assert_eq!(
+                events,
+                SubscriptionId::from("spot.order_book_update|BTC_USDT")
+            );
+        }
+
+        fn delta(first_update_id: u64, last_update_id: u64) -> GateioOrderBookL2Delta {
+            GateioMessage {
+                channel: "spot.order_book_update".to_string(),
+                error: None,
+                data: GateioOrderBookL2DeltaInner {
+                    market: "BTC_USDT".to_string(),
+                    time: Utc::now(),
+                    first_update_id,
+                    last_update_id,
+                    bids: vec![GateioLevel {
+                        price: 100.0,
+                        amount: 1.0,
+                    }],
+                    asks: vec![],
+                },
+            }
+        }
+
+        #[test]
+        fn test_update() {
+            struct TestCase {
+                updater: GateioBookUpdater<GateioServerSpot>,
+                input: GateioOrderBookL2Delta,
+                expected: Result<Option<u64>, DataError>,
+            }
+
+            let processed = |last_update_id| GateioBookUpdater {
+                updates_processed: 10,
+                last_update_id,
+                server: PhantomData,
+            };
+
+            let tests = vec![
+                TestCase {
+                    // TC0: drop any event where u is < id+1 in the snapshot
+                    updater: GateioBookUpdater::new(100),
+                    input: delta(90, 100),
+                    expected: Ok(None),
+                },
+                TestCase {
+                    // TC1: valid first update w/ U <= id+1 AND u >= id+1
+                    updater: GateioBookUpdater::new(100),
+                    input: delta(95, 105),
+                    expected: Ok(Some(105)),
+                },
+                TestCase {
+                    // TC2: invalid first update w/ U > id+1
+                    updater: GateioBookUpdater::new(100),
+                    input: delta(102, 105),
+                    expected: Err(DataError::InvalidSequence {
+                        prev_last_update_id: 100,
+                        first_update_id: 102,
+                    }),
+                },
+                TestCase {
+                    // TC3: valid next update w/ U == prev u+1
+                    updater: processed(100),
+                    input: delta(101, 110),
+                    expected: Ok(Some(110)),
+                },
+                TestCase {
+                    // TC4: invalid next update w/ U != prev u+1
+                    updater: processed(100),
+                    input: delta(103, 110),
+                    expected: Err(DataError::InvalidSequence {
+                        prev_last_update_id: 100,
+                        first_update_id: 103,
+                    }),
+                },
+            ];
+
+            for (index, mut test) in tests.into_iter().enumerate() {
+                let mut book = OrderBook {
+                    last_update_time: Utc::now(),
+                    bids: OrderBookSide::new(Side::Buy, Vec::<Level>::new()),
+                    asks: OrderBookSide::new(Side::Sell, Vec::<Level>::new()),
+                };
+
+                let actual = test
+                    .updater
+                    .update(&mut book, test.input, &mut LevelPool::default())
+                    .map(|snapshot| snapshot.map(|_| test.updater.last_update_id));
+
+                match (actual, test.expected) {
+                    (Ok(actual), Ok(expected)) => {
+                        assert_eq!(actual, expected, "TC{} failed", index)
+                    }
+                    (Err(_), Err(_)) => {
+                        // Test passed
+                    }
+                    (actual, expected) => {
+                        // Test failed
+                        panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                    }
+                }
+            }
+        }
+    }
+}
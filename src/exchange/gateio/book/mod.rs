@@ -0,0 +1,29 @@
+/// Level 1 OrderBook types (top of book) common to [`GateioSpot`](super::spot::GateioSpot) and
+/// [`GateioFuturesUsd`](super::future::GateioFuturesUsd).
+pub mod l1;
+
+/// Level 2 OrderBook types common to [`GateioSpot`](super::spot::GateioSpot),
+/// [`GateioPerpetualsUsd`](super::perpetual::GateioPerpetualsUsd) and
+/// [`GateioPerpetualsBtc`](super::perpetual::GateioPerpetualsBtc).
+pub mod l2;
+
+/// Deserialize a [`Gateio`](super::Gateio) OrderBook amount as an `f64`.
+///
+/// Spot amounts are sent as a `String` (eg/ "0.0003341504"), whereas futures amounts are sent as
+/// an integer number of contracts (eg/ 37000).
+pub fn de_str_or_f64<'de, D>(deserializer: D) -> Result<f64, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+{
+    #[derive(serde::Deserialize)]
+    #[serde(untagged)]
+    enum Amount<'a> {
+        Number(f64),
+        Str(&'a str),
+    }
+
+    match <Amount<'_> as serde::Deserialize>::deserialize(deserializer)? {
+        Amount::Number(amount) => Ok(amount),
+        Amount::Str(amount) => amount.parse().map_err(serde::de::Error::custom),
+    }
+}
@@ -1,34 +1,121 @@
+use super::{
+    future::GateioFuturesUsd,
+    perpetual::{GateioPerpetualsBtc, GateioPerpetualsUsd},
+    spot::GateioSpot,
+};
 use crate::instrument::InstrumentData;
 use crate::{
-    subscription::{trade::PublicTrades, Subscription},
+    subscription::{
+        book::{OrderBooksL1, OrderBooksL2, OrderBooksL2Events},
+        candle::{Candles, Interval},
+        funding::FundingRates,
+        trade::PublicTrades,
+        Subscription,
+    },
     Identifier,
 };
 use barter_integration::model::instrument::kind::InstrumentKind;
 use serde::Serialize;
 
+/// Gateio OrderBook Level2 update interval used for [`OrderBooksL2`] subscriptions, sent as the
+/// second element of the compound `[market, interval]` subscription payload.
+///
+/// See docs: <https://www.gate.io/docs/developers/apiv4/ws/en/#changed-order-book-levels>
+pub const GATEIO_BOOK_L2_INTERVAL: &str = "100ms";
+
 /// Type that defines how to translate a Barter [`Subscription`] into a
 /// [`Gateio`](super::Gateio) channel to be subscribed to.
 ///
 /// See docs: <https://www.okx.com/docs-v5/en/#websocket-api-public-channel>
 #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Serialize)]
-pub struct GateioChannel(pub &'static str);
+pub struct GateioChannel {
+    /// Gateio channel name (eg/ "spot.trades").
+    pub name: &'static str,
+    /// Candlestick [`Interval`], only present for candlestick channels.
+    pub interval: Option<Interval>,
+}
 
 impl GateioChannel {
     /// Gateio [`InstrumentKind::Spot`] real-time trades channel.
     ///
     /// See docs: <https://www.gate.io/docs/developers/apiv4/ws/en/#public-trades-channel>
-    pub const SPOT_TRADES: Self = Self("spot.trades");
+    pub const SPOT_TRADES: Self = Self::new("spot.trades");
 
     /// Gateio [`InstrumentKind::Future`] & [`InstrumentKind::Perpetual`] real-time trades channel.
     ///
     /// See docs: <https://www.gate.io/docs/developers/futures/ws/en/#trades-subscription>
     /// See docs: <https://www.gate.io/docs/developers/delivery/ws/en/#trades-subscription>
-    pub const FUTURE_TRADES: Self = Self("futures.trades");
+    pub const FUTURE_TRADES: Self = Self::new("futures.trades");
 
     /// Gateio [`InstrumentKind::Option`] real-time trades channel.
     ///
     /// See docs: <https://www.gate.io/docs/developers/options/ws/en/#public-contract-trades-channel>
-    pub const OPTION_TRADES: Self = Self("options.trades");
+    pub const OPTION_TRADES: Self = Self::new("options.trades");
+
+    /// Gateio [`InstrumentKind::Spot`] real-time candlesticks channel.
+    ///
+    /// See docs: <https://www.gate.io/docs/developers/apiv4/ws/en/#candlesticks-channel>
+    pub const SPOT_CANDLES: Self = Self::new("spot.candlesticks");
+
+    /// Gateio [`InstrumentKind::Future`] & [`InstrumentKind::Perpetual`] real-time candlesticks
+    /// channel.
+    ///
+    /// See docs: <https://www.gate.io/docs/developers/futures/ws/en/#candlesticks-subscription>
+    /// See docs: <https://www.gate.io/docs/developers/delivery/ws/en/#candlesticks-subscription>
+    pub const FUTURE_CANDLES: Self = Self::new("futures.candlesticks");
+
+    /// Gateio [`InstrumentKind::Option`] real-time contract candlesticks channel.
+    ///
+    /// See docs: <https://www.gate.io/docs/developers/options/ws/en/#contract-candlesticks-channel>
+    pub const OPTION_CANDLES: Self = Self::new("options.contract_candlesticks");
+
+    /// Gateio [`InstrumentKind::Perpetual`] real-time tickers channel.
+    ///
+    /// See docs: <https://www.gate.io/docs/developers/futures/ws/en/#tickers-api>
+    pub const FUTURE_TICKERS: Self = Self::new("futures.tickers");
+
+    /// Gateio [`InstrumentKind::Spot`] real-time best bid & ask channel.
+    ///
+    /// See docs: <https://www.gate.io/docs/developers/apiv4/ws/en/#best-bid-or-ask-price>
+    pub const SPOT_BOOK_TICKER: Self = Self::new("spot.book_ticker");
+
+    /// Gateio [`InstrumentKind::Future`] real-time best bid & ask channel.
+    ///
+    /// See docs: <https://www.gate.io/docs/developers/delivery/ws/en/#best-ask-bid-subscription>
+    pub const FUTURE_BOOK_TICKER: Self = Self::new("futures.book_ticker");
+
+    /// Gateio [`InstrumentKind::Spot`] real-time OrderBook Level2 deltas channel.
+    ///
+    /// See docs: <https://www.gate.io/docs/developers/apiv4/ws/en/#changed-order-book-levels>
+    pub const SPOT_ORDER_BOOK_L2: Self = Self::new("spot.order_book_update");
+
+    /// Gateio [`InstrumentKind::Perpetual`] real-time OrderBook Level2 deltas channel.
+    ///
+    /// See docs: <https://www.gate.io/docs/developers/futures/ws/en/#order-book-update-subscription>
+    pub const FUTURE_ORDER_BOOK_L2: Self = Self::new("futures.order_book_update");
+
+    /// Construct a [`GateioChannel`] with the provided name and no candlestick [`Interval`].
+    const fn new(name: &'static str) -> Self {
+        Self {
+            name,
+            interval: None,
+        }
+    }
+
+    /// Set the candlestick [`Interval`] of this [`GateioChannel`], which is sent as the first
+    /// element of the compound `[interval, market]` subscription payload.
+    pub fn with_interval(self, interval: Interval) -> Self {
+        Self {
+            interval: Some(interval),
+            ..self
+        }
+    }
+
+    /// Determines if this [`GateioChannel`] is an OrderBook Level2 channel, which requires a
+    /// compound `[market, interval]` subscription payload.
+    pub fn is_order_book_l2(&self) -> bool {
+        matches!(*self, Self::SPOT_ORDER_BOOK_L2 | Self::FUTURE_ORDER_BOOK_L2)
+    }
 }
 
 impl<GateioExchange, Instrument> Identifier<GateioChannel>
@@ -45,8 +132,116 @@ where
     }
 }
 
+impl<GateioExchange, Instrument> Identifier<GateioChannel>
+    for Subscription<GateioExchange, Instrument, Candles>
+where
+    Instrument: InstrumentData,
+{
+    /// Note that the [`Interval`] is not part of the channel name, so only one [`Candles`]
+    /// [`Interval`] can be subscribed to per market on a single connection.
+    fn id(&self) -> GateioChannel {
+        match self.instrument.kind() {
+            InstrumentKind::Spot => GateioChannel::SPOT_CANDLES,
+            InstrumentKind::Future(_) | InstrumentKind::Perpetual => GateioChannel::FUTURE_CANDLES,
+            InstrumentKind::Option(_) => GateioChannel::OPTION_CANDLES,
+        }
+        .with_interval(self.kind.0)
+    }
+}
+
+impl<GateioExchange, Instrument> Identifier<GateioChannel>
+    for Subscription<GateioExchange, Instrument, FundingRates>
+{
+    fn id(&self) -> GateioChannel {
+        GateioChannel::FUTURE_TICKERS
+    }
+}
+
+impl<Instrument> Identifier<GateioChannel> for Subscription<GateioSpot, Instrument, OrderBooksL1> {
+    fn id(&self) -> GateioChannel {
+        GateioChannel::SPOT_BOOK_TICKER
+    }
+}
+
+impl<Instrument> Identifier<GateioChannel> for Subscription<GateioSpot, Instrument, OrderBooksL2> {
+    fn id(&self) -> GateioChannel {
+        GateioChannel::SPOT_ORDER_BOOK_L2
+    }
+}
+
+impl<Instrument> Identifier<GateioChannel>
+    for Subscription<GateioPerpetualsUsd, Instrument, OrderBooksL2>
+{
+    fn id(&self) -> GateioChannel {
+        GateioChannel::FUTURE_ORDER_BOOK_L2
+    }
+}
+
+impl<Instrument> Identifier<GateioChannel>
+    for Subscription<GateioPerpetualsBtc, Instrument, OrderBooksL2>
+{
+    fn id(&self) -> GateioChannel {
+        GateioChannel::FUTURE_ORDER_BOOK_L2
+    }
+}
+
+impl<Instrument> Identifier<GateioChannel>
+    for Subscription<GateioSpot, Instrument, OrderBooksL2Events>
+{
+    fn id(&self) -> GateioChannel {
+        GateioChannel::SPOT_ORDER_BOOK_L2
+    }
+}
+
+impl<Instrument> Identifier<GateioChannel>
+    for Subscription<GateioPerpetualsUsd, Instrument, OrderBooksL2Events>
+{
+    fn id(&self) -> GateioChannel {
+        GateioChannel::FUTURE_ORDER_BOOK_L2
+    }
+}
+
+impl<Instrument> Identifier<GateioChannel>
+    for Subscription<GateioPerpetualsBtc, Instrument, OrderBooksL2Events>
+{
+    fn id(&self) -> GateioChannel {
+        GateioChannel::FUTURE_ORDER_BOOK_L2
+    }
+}
+
+impl<Instrument> Identifier<GateioChannel>
+    for Subscription<GateioFuturesUsd, Instrument, OrderBooksL1>
+{
+    fn id(&self) -> GateioChannel {
+        GateioChannel::FUTURE_BOOK_TICKER
+    }
+}
+
 impl AsRef<str> for GateioChannel {
     fn as_ref(&self) -> &str {
-        self.0
+        self.name
+    }
+}
+
+/// [`Gateio`](super::Gateio) candlestick interval of the provided [`Interval`].
+///
+/// Gateio does not offer every [`Interval`] (eg/ [`Interval::M3`]), and rejects subscriptions to
+/// those it does not support.
+///
+/// See docs: <https://www.gate.io/docs/developers/apiv4/ws/en/#candlesticks-channel>
+pub fn gateio_interval(interval: Interval) -> &'static str {
+    match interval {
+        Interval::M1 => "1m",
+        Interval::M3 => "3m",
+        Interval::M5 => "5m",
+        Interval::M15 => "15m",
+        Interval::M30 => "30m",
+        Interval::H1 => "1h",
+        Interval::H2 => "2h",
+        Interval::H4 => "4h",
+        Interval::H6 => "6h",
+        Interval::H12 => "12h",
+        Interval::D1 => "1d",
+        Interval::W1 => "7d",
     }
 }
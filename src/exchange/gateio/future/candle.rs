@@ -0,0 +1,153 @@
+use super::super::{market::GateioCandleName, message::GateioMessage};
+use crate::{
+    event::{MarketEvent, MarketIter},
+    exchange::{ExchangeId, ExchangeSub},
+    subscription::candle::Candle,
+    Identifier,
+};
+use barter_integration::model::{Exchange, SubscriptionId};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Terse type alias for a [`GateioFuturesUsd`](super::GateioFuturesUsd) real-time candlesticks
+/// WebSocket message.
+pub type GateioFuturesCandles = GateioMessage<Vec<GateioFuturesCandleInner>>;
+
+/// [`GateioFuturesUsd`](super::GateioFuturesUsd) real-time candlestick WebSocket message.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://www.gate.io/docs/developers/delivery/ws/en/#candlesticks-notification>
+/// ```json
+/// {
+///   "t": 1545129300,
+///   "v": 27525555,
+///   "c": "95.4",
+///   "h": "96.9",
+///   "l": "89.5",
+///   "o": "94.3",
+///   "n": "1m_BTC_USDT_20230630"
+/// }
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct GateioFuturesCandleInner {
+    #[serde(rename = "n")]
+    pub name: GateioCandleName,
+    #[serde(
+        rename = "t",
+        deserialize_with = "crate::util::time::de_u64_epoch_s_as_datetime_utc"
+    )]
+    pub open_time: DateTime<Utc>,
+    #[serde(rename = "o", deserialize_with = "barter_integration::de::de_str")]
+    pub open: f64,
+    #[serde(rename = "h", deserialize_with = "barter_integration::de::de_str")]
+    pub high: f64,
+    #[serde(rename = "l", deserialize_with = "barter_integration::de::de_str")]
+    pub low: f64,
+    #[serde(rename = "c", deserialize_with = "barter_integration::de::de_str")]
+    pub close: f64,
+    /// Trading volume in number of contracts.
+    #[serde(rename = "v")]
+    pub volume: f64,
+}
+
+impl Identifier<Option<SubscriptionId>> for GateioFuturesCandles {
+    fn id(&self) -> Option<SubscriptionId> {
+        self.data
+            .first()
+            .map(|candle| ExchangeSub::from((&self.channel, &candle.name.market)).id())
+    }
+}
+
+impl<InstrumentId: Clone> From<(ExchangeId, InstrumentId, GateioFuturesCandles)>
+    for MarketIter<InstrumentId, Candle>
+{
+    fn from(
+        (exchange_id, instrument, candles): (ExchangeId, InstrumentId, GateioFuturesCandles),
+    ) -> Self {
+        candles
+            .data
+            .into_iter()
+            .map(|candle| {
+                let close_time = candle.open_time + candle.name.interval.duration();
+
+                Ok(MarketEvent {
+                    exchange_time: close_time,
+                    received_time: Utc::now(),
+                    exchange: Exchange::from(exchange_id),
+                    instrument: instrument.clone(),
+                    kind: Candle {
+                        close_time,
+                        open: candle.open,
+                        high: candle.high,
+                        low: candle.low,
+                        close: candle.close,
+                        volume: candle.volume,
+                        trade_count: 0,
+                    },
+                })
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod de {
+        use super::*;
+        use crate::subscription::candle::Interval;
+        use barter_integration::de::datetime_utc_from_epoch_duration;
+        use std::time::Duration;
+
+        #[test]
+        fn test_gateio_message_futures_candles() {
+            let input = r#"
+            {
+                "time": 1542162490,
+                "time_ms": 1542162490123,
+                "channel": "futures.candlesticks",
+                "event": "update",
+                "error": null,
+                "result": [
+                    {
+                        "t": 1545129300,
+                        "v": 27525555,
+                        "c": "95.4",
+                        "h": "96.9",
+                        "l": "89.5",
+                        "o": "94.3",
+                        "n": "1m_BTC_USDT_20230630"
+                    }
+                ]
+            }
+            "#;
+
+            let actual = serde_json::from_str::<GateioFuturesCandles>(input).unwrap();
+            let expected = GateioFuturesCandles {
+                channel: "futures.candlesticks".to_string(),
+                error: None,
+                data: vec![GateioFuturesCandleInner {
+                    name: GateioCandleName {
+                        interval: Interval::M1,
+                        market: "BTC_USDT_20230630".to_string(),
+                    },
+                    open_time: datetime_utc_from_epoch_duration(Duration::from_secs(1545129300)),
+                    open: 94.3,
+                    high: 96.9,
+                    low: 89.5,
+                    close: 95.4,
+                    volume: 27525555.0,
+                }],
+            };
+
+            assert_eq!(actual, expected);
+            assert_eq!(
+                actual.id(),
+                Some(SubscriptionId::from(
+                    "futures.candlesticks|BTC_USDT_20230630"
+                ))
+            );
+        }
+    }
+}
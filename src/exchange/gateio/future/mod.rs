@@ -1,14 +1,18 @@
+use self::candle::GateioFuturesCandles;
 use crate::instrument::InstrumentData;
 use crate::{
     exchange::{
-        gateio::{perpetual::trade::GateioFuturesTrades, Gateio},
+        gateio::{book::l1::GateioOrderBookL1, perpetual::trade::GateioFuturesTrades, Gateio},
         ExchangeId, ExchangeServer, StreamSelector,
     },
-    subscription::trade::PublicTrades,
+    subscription::{book::OrderBooksL1, candle::Candles, trade::PublicTrades},
     transformer::stateless::StatelessTransformer,
     ExchangeWsStream,
 };
 
+/// Candlestick types.
+pub mod candle;
+
 /// [`GateioFuturesUsd`] WebSocket server base url.
 ///
 /// See docs: <https://www.gate.io/docs/developers/delivery/ws/en/>
@@ -38,6 +42,23 @@ where
     >;
 }
 
+impl<Instrument> StreamSelector<Instrument, Candles> for GateioFuturesUsd
+where
+    Instrument: InstrumentData,
+{
+    type Stream =
+        ExchangeWsStream<StatelessTransformer<Self, Instrument::Id, Candles, GateioFuturesCandles>>;
+}
+
+impl<Instrument> StreamSelector<Instrument, OrderBooksL1> for GateioFuturesUsd
+where
+    Instrument: InstrumentData,
+{
+    type Stream = ExchangeWsStream<
+        StatelessTransformer<Self, Instrument::Id, OrderBooksL1, GateioOrderBookL1>,
+    >;
+}
+
 /// [`GateioFuturesBtc`] WebSocket server base url.
 ///
 /// See docs: <https://www.gate.io/docs/developers/delivery/ws/en/>
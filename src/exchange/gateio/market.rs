@@ -1,6 +1,9 @@
-use super::Gateio;
+use super::{channel::gateio_interval, Gateio};
 use crate::instrument::{KeyedInstrument, MarketInstrumentData};
-use crate::{subscription::Subscription, Identifier};
+use crate::{
+    subscription::{candle::Interval, Subscription},
+    Identifier,
+};
 use barter_integration::model::instrument::{
     kind::{InstrumentKind, OptionKind},
     Instrument,
@@ -46,7 +49,7 @@ impl AsRef<str> for GateioMarket {
     }
 }
 
-fn gateio_market(instrument: &Instrument) -> GateioMarket {
+pub(super) fn gateio_market(instrument: &Instrument) -> GateioMarket {
     use InstrumentKind::*;
     let Instrument { base, quote, kind } = instrument;
 
@@ -78,3 +81,36 @@ fn gateio_market(instrument: &Instrument) -> GateioMarket {
 fn format_expiry<'a>(expiry: DateTime<Utc>) -> DelayedFormat<StrftimeItems<'a>> {
     expiry.date_naive().format("%Y%m%d")
 }
+
+/// [`Gateio`](super::Gateio) candlestick name (eg/ "1m_BTC_USDT"), composed of the candlestick
+/// [`Interval`] and the associated market.
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Serialize)]
+pub struct GateioCandleName {
+    pub interval: Interval,
+    pub market: String,
+}
+
+impl<'de> Deserialize<'de> for GateioCandleName {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::de::Deserializer<'de>,
+    {
+        let name = <&str as Deserialize>::deserialize(deserializer)?;
+        name.split_once('_')
+            .and_then(|(interval, market)| {
+                Interval::ALL
+                    .into_iter()
+                    .find(|candidate| gateio_interval(*candidate) == interval)
+                    .map(|interval| Self {
+                        interval,
+                        market: market.to_owned(),
+                    })
+            })
+            .ok_or_else(|| {
+                serde::de::Error::invalid_value(
+                    serde::de::Unexpected::Str(name),
+                    &"Gateio candlestick name with format: {interval}_{market}",
+                )
+            })
+    }
+}
@@ -1,13 +1,23 @@
-use self::{channel::GateioChannel, market::GateioMarket, subscription::GateioSubResponse};
+use self::{
+    channel::{gateio_interval, GateioChannel, GATEIO_BOOK_L2_INTERVAL},
+    market::GateioMarket,
+    subscription::GateioSubResponse,
+};
 use crate::{
-    exchange::{subscription::ExchangeSub, Connector, ExchangeId, ExchangeServer},
+    exchange::{
+        subscription::ExchangeSub, Connector, ExchangeId, ExchangeServer, Keepalive, PongTimeout,
+    },
     subscriber::{validator::WebSocketSubValidator, WebSocketSubscriber},
 };
 use barter_integration::{error::SocketError, protocol::websocket::WsMessage};
 use serde_json::json;
-use std::{fmt::Debug, marker::PhantomData};
+use std::{fmt::Debug, marker::PhantomData, time::Duration};
 use url::Url;
 
+/// OrderBook types common to [`GateioSpot`](spot::GateioSpot) and
+/// [`GateioFuturesUsd`](future::GateioFuturesUsd).
+pub mod book;
+
 /// Defines the type that translates a Barter [`Subscription`](crate::subscription::Subscription)
 /// into an exchange [`Connector`] specific channel used for generating [`Connector::requests`].
 pub mod channel;
@@ -45,6 +55,14 @@ pub mod message;
 /// [`GateioPerpetualBtc`](perpetual::GateioPerpetualsBtc).
 pub mod subscription;
 
+/// [`Gateio`] server protocol-level [`Keepalive`] ping interval.
+///
+/// See docs: <https://www.gate.io/docs/developers/apiv4/ws/en/#application-ping-pong>
+pub const PING_INTERVAL_GATEIO: Duration = Duration::from_secs(10);
+
+/// [`Gateio`] server [`PongTimeout`] duration, allowing for several missed pong responses.
+pub const PONG_TIMEOUT_GATEIO: Duration = Duration::from_secs(30);
+
 /// Generic [`Gateio<Server>`](Gateio) exchange.
 ///
 /// ### Notes
@@ -71,16 +89,39 @@ where
         Url::parse(Server::websocket_url()).map_err(SocketError::UrlParse)
     }
 
+    fn keepalive() -> Keepalive {
+        Keepalive::Protocol {
+            interval: PING_INTERVAL_GATEIO,
+        }
+    }
+
+    fn pong_timeout() -> Option<PongTimeout> {
+        Some(PongTimeout {
+            timeout: PONG_TIMEOUT_GATEIO,
+            is_pong: |message| matches!(message, WsMessage::Pong(_)),
+        })
+    }
+
     fn requests(exchange_subs: Vec<ExchangeSub<Self::Channel, Self::Market>>) -> Vec<WsMessage> {
         exchange_subs
             .into_iter()
             .map(|ExchangeSub { channel, market }| {
+                // Candlestick channels require a compound [interval, market] payload, and
+                // OrderBook Level2 channels require a compound [market, interval] payload
+                let payload = if let Some(interval) = channel.interval {
+                    json!([gateio_interval(interval), market.as_ref()])
+                } else if channel.is_order_book_l2() {
+                    json!([market.as_ref(), GATEIO_BOOK_L2_INTERVAL])
+                } else {
+                    json!([market.as_ref()])
+                };
+
                 WsMessage::Text(
                     json!({
                         "time": chrono::Utc::now().timestamp_millis(),
                         "channel": channel.as_ref(),
                         "event": "subscribe",
-                        "payload": [market.as_ref()]
+                        "payload": payload
                     })
                     .to_string(),
                 )
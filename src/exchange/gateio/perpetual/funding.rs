@@ -0,0 +1,152 @@
+use crate::{
+    event::{MarketEvent, MarketIter},
+    exchange::{ExchangeId, ExchangeSub},
+    subscription::funding::FundingRate,
+    Identifier,
+};
+use barter_integration::model::{Exchange, SubscriptionId};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// [`GateioPerpetualsUsd`](super::GateioPerpetualsUsd) and
+/// [`GateioPerpetualsBtc`](super::GateioPerpetualsBtc) real-time tickers WebSocket message.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://www.gate.io/docs/developers/futures/ws/en/#tickers-api>
+/// ```json
+/// {
+///   "time": 1541659086,
+///   "time_ms": 1541659086123,
+///   "channel": "futures.tickers",
+///   "event": "update",
+///   "result": [
+///     {
+///       "contract": "BTC_USD",
+///       "last": "118.4",
+///       "change_percentage": "0.77",
+///       "funding_rate": "-0.000114",
+///       "funding_rate_indicative": "0.01875",
+///       "mark_price": "118.35",
+///       "index_price": "118.36",
+///       "total_size": "73648",
+///       "volume_24h": "745487577",
+///       "low_24h": "99.2",
+///       "high_24h": "132.5"
+///     }
+///   ]
+/// }
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct GateioFuturesTickers {
+    pub channel: String,
+    #[serde(
+        rename = "time_ms",
+        deserialize_with = "crate::util::time::de_u64_epoch_ms_as_datetime_utc"
+    )]
+    pub time: DateTime<Utc>,
+    #[serde(rename = "result")]
+    pub data: Vec<GateioFuturesTickerInner>,
+}
+
+/// [`GateioPerpetualsUsd`](super::GateioPerpetualsUsd) and
+/// [`GateioPerpetualsBtc`](super::GateioPerpetualsBtc) real-time ticker, of which only the
+/// funding fields are used.
+///
+/// Gateio does not provide the next funding time via the tickers channel.
+///
+/// See [`GateioFuturesTickers`] for full raw payload examples.
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct GateioFuturesTickerInner {
+    #[serde(rename = "contract")]
+    pub market: String,
+    #[serde(deserialize_with = "barter_integration::de::de_str")]
+    pub funding_rate: f64,
+}
+
+impl Identifier<Option<SubscriptionId>> for GateioFuturesTickers {
+    fn id(&self) -> Option<SubscriptionId> {
+        self.data
+            .first()
+            .map(|ticker| ExchangeSub::from((&self.channel, &ticker.market)).id())
+    }
+}
+
+impl<InstrumentId: Clone> From<(ExchangeId, InstrumentId, GateioFuturesTickers)>
+    for MarketIter<InstrumentId, FundingRate>
+{
+    fn from(
+        (exchange_id, instrument, tickers): (ExchangeId, InstrumentId, GateioFuturesTickers),
+    ) -> Self {
+        tickers
+            .data
+            .into_iter()
+            .map(|ticker| {
+                Ok(MarketEvent {
+                    exchange_time: tickers.time,
+                    received_time: Utc::now(),
+                    exchange: Exchange::from(exchange_id),
+                    instrument: instrument.clone(),
+                    kind: FundingRate {
+                        rate: ticker.funding_rate,
+                        next_funding_time: None,
+                    },
+                })
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod de {
+        use super::*;
+        use barter_integration::de::datetime_utc_from_epoch_duration;
+        use std::time::Duration;
+
+        #[test]
+        fn test_gateio_futures_tickers() {
+            let input = r#"
+            {
+                "time": 1541659086,
+                "time_ms": 1541659086123,
+                "channel": "futures.tickers",
+                "event": "update",
+                "result": [
+                    {
+                        "contract": "BTC_USD",
+                        "last": "118.4",
+                        "change_percentage": "0.77",
+                        "funding_rate": "-0.000114",
+                        "funding_rate_indicative": "0.01875",
+                        "mark_price": "118.35",
+                        "index_price": "118.36",
+                        "total_size": "73648",
+                        "volume_24h": "745487577",
+                        "low_24h": "99.2",
+                        "high_24h": "132.5"
+                    }
+                ]
+            }
+            "#;
+
+            let actual = serde_json::from_str::<GateioFuturesTickers>(input).unwrap();
+            assert_eq!(
+                actual,
+                GateioFuturesTickers {
+                    channel: "futures.tickers".to_string(),
+                    time: datetime_utc_from_epoch_duration(Duration::from_millis(1541659086123)),
+                    data: vec![GateioFuturesTickerInner {
+                        market: "BTC_USD".to_string(),
+                        funding_rate: -0.000114,
+                    }],
+                }
+            );
+            assert_eq!(
+                actual.id(),
+                Some(SubscriptionId::from("futures.tickers|BTC_USD"))
+            );
+        }
+    }
+}
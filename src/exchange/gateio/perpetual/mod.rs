@@ -1,12 +1,24 @@
-use self::trade::GateioFuturesTrades;
-use super::Gateio;
+use self::{funding::GateioFuturesTickers, trade::GateioFuturesTrades};
+use super::{
+    book::l2::{GateioBookUpdater, GateioServerBookL2},
+    market::GateioMarket,
+    Gateio,
+};
 use crate::instrument::InstrumentData;
 use crate::{
     exchange::{ExchangeId, ExchangeServer, StreamSelector},
-    subscription::trade::PublicTrades,
-    transformer::stateless::StatelessTransformer,
+    subscription::{
+        book::{OrderBooksL2, OrderBooksL2Events},
+        funding::FundingRates,
+        trade::PublicTrades,
+    },
+    transformer::{book::MultiBookTransformer, stateless::StatelessTransformer},
     ExchangeWsStream,
 };
+use barter_integration::model::instrument::Instrument;
+
+/// Funding rate types.
+pub mod funding;
 
 /// Public trades types.
 pub mod trade;
@@ -16,6 +28,12 @@ pub mod trade;
 /// See docs: <https://www.gate.io/docs/developers/futures/ws/en/>
 pub const WEBSOCKET_BASE_URL_GATEIO_PERPETUALS_USD: &str = "wss://fx-ws.gateio.ws/v4/ws/usdt";
 
+/// [`GateioPerpetualsUsd`] HTTP OrderBook L2 snapshot url.
+///
+/// See docs: <https://www.gate.io/docs/developers/apiv4/en/#futures-order-book>
+pub const HTTP_BOOK_L2_SNAPSHOT_URL_GATEIO_PERPETUALS_USD: &str =
+    "https://api.gateio.ws/api/v4/futures/usdt/order_book";
+
 /// [`Gateio`] perpetual usd exchange.
 pub type GateioPerpetualsUsd = Gateio<GateioServerPerpetualsUsd>;
 
@@ -31,6 +49,15 @@ impl ExchangeServer for GateioServerPerpetualsUsd {
     }
 }
 
+impl GateioServerBookL2 for GateioServerPerpetualsUsd {
+    fn http_book_l2_snapshot_url(market: &GateioMarket) -> String {
+        format!(
+            "{HTTP_BOOK_L2_SNAPSHOT_URL_GATEIO_PERPETUALS_USD}?contract={}&limit=100&with_id=true",
+            market.as_ref()
+        )
+    }
+}
+
 impl<Instrument> StreamSelector<Instrument, PublicTrades> for GateioPerpetualsUsd
 where
     Instrument: InstrumentData,
@@ -40,11 +67,48 @@ where
     >;
 }
 
+impl<Instrument> StreamSelector<Instrument, FundingRates> for GateioPerpetualsUsd
+where
+    Instrument: InstrumentData,
+{
+    type Stream = ExchangeWsStream<
+        StatelessTransformer<Self, Instrument::Id, FundingRates, GateioFuturesTickers>,
+    >;
+}
+
+impl StreamSelector<Instrument, OrderBooksL2> for GateioPerpetualsUsd {
+    type Stream = ExchangeWsStream<
+        MultiBookTransformer<
+            Self,
+            Instrument,
+            OrderBooksL2,
+            GateioBookUpdater<GateioServerPerpetualsUsd>,
+        >,
+    >;
+}
+
+impl StreamSelector<Instrument, OrderBooksL2Events> for GateioPerpetualsUsd {
+    type Stream = ExchangeWsStream<
+        MultiBookTransformer<
+            Self,
+            Instrument,
+            OrderBooksL2Events,
+            GateioBookUpdater<GateioServerPerpetualsUsd>,
+        >,
+    >;
+}
+
 /// [`GateioPerpetualsBtc`] WebSocket server base url.
 ///
 /// See docs: <https://www.gate.io/docs/developers/futures/ws/en/>
 pub const WEBSOCKET_BASE_URL_GATEIO_PERPETUALS_BTC: &str = "wss://fx-ws.gateio.ws/v4/ws/btc";
 
+/// [`GateioPerpetualsBtc`] HTTP OrderBook L2 snapshot url.
+///
+/// See docs: <https://www.gate.io/docs/developers/apiv4/en/#futures-order-book>
+pub const HTTP_BOOK_L2_SNAPSHOT_URL_GATEIO_PERPETUALS_BTC: &str =
+    "https://api.gateio.ws/api/v4/futures/btc/order_book";
+
 /// [`Gateio`] perpetual btc exchange.
 pub type GateioPerpetualsBtc = Gateio<GateioServerPerpetualsBtc>;
 
@@ -60,6 +124,15 @@ impl ExchangeServer for GateioServerPerpetualsBtc {
     }
 }
 
+impl GateioServerBookL2 for GateioServerPerpetualsBtc {
+    fn http_book_l2_snapshot_url(market: &GateioMarket) -> String {
+        format!(
+            "{HTTP_BOOK_L2_SNAPSHOT_URL_GATEIO_PERPETUALS_BTC}?contract={}&limit=100&with_id=true",
+            market.as_ref()
+        )
+    }
+}
+
 impl<Instrument> StreamSelector<Instrument, PublicTrades> for GateioPerpetualsBtc
 where
     Instrument: InstrumentData,
@@ -68,3 +141,34 @@ where
         StatelessTransformer<Self, Instrument::Id, PublicTrades, GateioFuturesTrades>,
     >;
 }
+
+impl<Instrument> StreamSelector<Instrument, FundingRates> for GateioPerpetualsBtc
+where
+    Instrument: InstrumentData,
+{
+    type Stream = ExchangeWsStream<
+        StatelessTransformer<Self, Instrument::Id, FundingRates, GateioFuturesTickers>,
+    >;
+}
+
+impl StreamSelector<Instrument, OrderBooksL2> for GateioPerpetualsBtc {
+    type Stream = ExchangeWsStream<
+        MultiBookTransformer<
+            Self,
+            Instrument,
+            OrderBooksL2,
+            GateioBookUpdater<GateioServerPerpetualsBtc>,
+        >,
+    >;
+}
+
+impl StreamSelector<Instrument, OrderBooksL2Events> for GateioPerpetualsBtc {
+    type Stream = ExchangeWsStream<
+        MultiBookTransformer<
+            Self,
+            Instrument,
+            OrderBooksL2Events,
+            GateioBookUpdater<GateioServerPerpetualsBtc>,
+        >,
+    >;
+}
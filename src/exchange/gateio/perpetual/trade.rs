@@ -53,7 +53,7 @@ pub struct GateioFuturesTradeInner {
     pub market: String,
     #[serde(
         rename = "create_time_ms",
-        deserialize_with = "barter_integration::de::de_u64_epoch_ms_as_datetime_utc"
+        deserialize_with = "crate::util::time::de_u64_epoch_ms_as_datetime_utc"
     )]
     pub time: DateTime<Utc>,
     pub id: u64,
@@ -105,6 +105,7 @@ impl<InstrumentId: Clone> From<(ExchangeId, InstrumentId, GateioFuturesTrades)>
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::subscription::trade::tests::assert_duplicate_frame_dedup_keys;
 
     mod de {
         use super::*;
@@ -137,4 +138,36 @@ mod tests {
             serde_json::from_str::<GateioFuturesTrades>(input).unwrap();
         }
     }
+
+    #[test]
+    fn test_gateio_perpetual_trades_duplicate_frame_dedup_keys() {
+        assert_duplicate_frame_dedup_keys::<GateioFuturesTrades>(
+            ExchangeId::GateioPerpetualsUsd,
+            r#"
+            {
+                "channel": "futures.trades",
+                "event": "update",
+                "time": 1541503698,
+                "result": [
+                    {
+                        "size": -108,
+                        "id": 27753479,
+                        "create_time": 1545136464,
+                        "create_time_ms": 1545136464123,
+                        "price": "96.4",
+                        "contract": "BTC_USDT"
+                    },
+                    {
+                        "size": -108,
+                        "id": 27753480,
+                        "create_time": 1545136464,
+                        "create_time_ms": 1545136464123,
+                        "price": "96.4",
+                        "contract": "BTC_USDT"
+                    }
+                ]
+            }
+            "#,
+        );
+    }
 }
@@ -0,0 +1,219 @@
+use super::super::{market::GateioCandleName, message::GateioMessage};
+use crate::{
+    event::{MarketEvent, MarketIter},
+    exchange::{ExchangeId, ExchangeSub},
+    subscription::candle::Candle,
+    Identifier,
+};
+use barter_integration::model::{Exchange, SubscriptionId};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Terse type alias for an [`GateioSpot`](super::GateioSpot) real-time candlestick WebSocket
+/// message.
+pub type GateioSpotCandle = GateioMessage<GateioSpotCandleInner>;
+
+/// [`GateioSpot`](super::GateioSpot) real-time candlestick WebSocket message.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://www.gate.io/docs/developers/apiv4/ws/en/#candlesticks-channel>
+/// ```json
+/// {
+///   "t": "1606292580",
+///   "v": "2362.32035",
+///   "c": "19128.1",
+///   "h": "19128.1",
+///   "l": "19128.1",
+///   "o": "19128.1",
+///   "n": "1m_BTC_USDT",
+///   "a": "3.8283",
+///   "w": true
+/// }
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct GateioSpotCandleInner {
+    #[serde(rename = "n")]
+    pub name: GateioCandleName,
+    #[serde(
+        rename = "t",
+        deserialize_with = "crate::util::time::de_str_epoch_s_as_datetime_utc"
+    )]
+    pub open_time: DateTime<Utc>,
+    #[serde(rename = "o", deserialize_with = "barter_integration::de::de_str")]
+    pub open: f64,
+    #[serde(rename = "h", deserialize_with = "barter_integration::de::de_str")]
+    pub high: f64,
+    #[serde(rename = "l", deserialize_with = "barter_integration::de::de_str")]
+    pub low: f64,
+    #[serde(rename = "c", deserialize_with = "barter_integration::de::de_str")]
+    pub close: f64,
+    /// Base currency trading amount.
+    #[serde(rename = "a", deserialize_with = "barter_integration::de::de_str")]
+    pub volume: f64,
+}
+
+impl Identifier<Option<SubscriptionId>> for GateioSpotCandle {
+    fn id(&self) -> Option<SubscriptionId> {
+        Some(ExchangeSub::from((&self.channel, &self.data.name.market)).id())
+    }
+}
+
+impl<InstrumentId> From<(ExchangeId, InstrumentId, GateioSpotCandle)>
+    for MarketIter<InstrumentId, Candle>
+{
+    fn from(
+        (exchange_id, instrument, candle): (ExchangeId, InstrumentId, GateioSpotCandle),
+    ) -> Self {
+        let close_time = candle.data.open_time + candle.data.name.interval.duration();
+
+        Self(vec![Ok(MarketEvent {
+            exchange_time: close_time,
+            received_time: Utc::now(),
+            exchange: Exchange::from(exchange_id),
+            instrument,
+            kind: Candle {
+                close_time,
+                open: candle.data.open,
+                high: candle.data.high,
+                low: candle.data.low,
+                close: candle.data.close,
+                volume: candle.data.volume,
+                trade_count: 0,
+            },
+        })])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod de {
+        use super::*;
+        use crate::subscription::candle::Interval;
+        use barter_integration::de::datetime_utc_from_epoch_duration;
+        use std::time::Duration;
+
+        #[test]
+        fn test_gateio_message_spot_candle() {
+            let input = r#"
+            {
+                "time": 1606292600,
+                "time_ms": 1606292600376,
+                "channel": "spot.candlesticks",
+                "event": "update",
+                "result": {
+                    "t": "1606292580",
+                    "v": "2362.32035",
+                    "c": "19128.1",
+                    "h": "19128.1",
+                    "l": "19128.1",
+                    "o": "19128.1",
+                    "n": "1m_BTC_USDT",
+                    "a": "3.8283",
+                    "w": true
+                }
+            }
+            "#;
+
+            let actual = serde_json::from_str::<GateioSpotCandle>(input).unwrap();
+            let expected = GateioSpotCandle {
+                channel: "spot.candlesticks".to_string(),
+                error: None,
+                data: GateioSpotCandleInner {
+                    name: GateioCandleName {
+                        interval: Interval::M1,
+                        market: "BTC_USDT".to_string(),
+                    },
+                    open_time: datetime_utc_from_epoch_duration(Duration::from_secs(1606292580)),
+                    open: 19128.1,
+                    high: 19128.1,
+                    low: 19128.1,
+                    close: 19128.1,
+                    volume: 3.8283,
+                },
+            };
+
+            assert_eq!(actual, expected);
+            assert_eq!(
+                actual.id(),
+                Some(SubscriptionId::from("spot.candlesticks|BTC_USDT"))
+            );
+        }
+
+        #[test]
+        fn test_gateio_spot_candle_close_time_from_interval() {
+            let input = r#"
+            {
+                "time": 1606292600,
+                "time_ms": 1606292600376,
+                "channel": "spot.candlesticks",
+                "event": "update",
+                "result": {
+                    "t": "1606291200",
+                    "v": "2362.32035",
+                    "c": "19128.1",
+                    "h": "19128.1",
+                    "l": "19128.1",
+                    "o": "19128.1",
+                    "n": "1h_BTC_USDT",
+                    "a": "3.8283",
+                    "w": true
+                }
+            }
+            "#;
+
+            let candle = serde_json::from_str::<GateioSpotCandle>(input).unwrap();
+            let MarketIter(events) =
+                MarketIter::<&str, Candle>::from((ExchangeId::GateioSpot, "btc_usdt", candle));
+
+            let event = events.into_iter().next().unwrap().unwrap();
+            assert_eq!(
+                event.kind.close_time,
+                datetime_utc_from_epoch_duration(Duration::from_secs(1606291200 + 60 * 60))
+            );
+        }
+
+        #[test]
+        fn test_gateio_candle_name() {
+            struct TestCase {
+                input: &'static str,
+                expected: Option<GateioCandleName>,
+            }
+
+            let tests = vec![
+                TestCase {
+                    // TC0: 1 hour interval
+                    input: r#""1h_BTC_USDT""#,
+                    expected: Some(GateioCandleName {
+                        interval: Interval::H1,
+                        market: "BTC_USDT".to_string(),
+                    }),
+                },
+                TestCase {
+                    // TC1: 7 day interval maps to Interval::W1
+                    input: r#""7d_ETH_USDT_20230630""#,
+                    expected: Some(GateioCandleName {
+                        interval: Interval::W1,
+                        market: "ETH_USDT_20230630".to_string(),
+                    }),
+                },
+                TestCase {
+                    // TC2: unknown interval
+                    input: r#""10s_BTC_USDT""#,
+                    expected: None,
+                },
+                TestCase {
+                    // TC3: missing interval prefix
+                    input: r#""BTCUSDT""#,
+                    expected: None,
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                let actual = serde_json::from_str::<GateioCandleName>(test.input).ok();
+                assert_eq!(actual, test.expected, "TC{index} failed");
+            }
+        }
+    }
+}
@@ -1,22 +1,43 @@
-use self::trade::GateioSpotTrade;
-use super::Gateio;
+use self::{candle::GateioSpotCandle, trade::GateioSpotTrade};
+use super::{
+    book::{
+        l1::GateioOrderBookL1,
+        l2::{GateioBookUpdater, GateioServerBookL2},
+    },
+    market::GateioMarket,
+    Gateio,
+};
 use crate::instrument::InstrumentData;
 use crate::{
     exchange::{ExchangeId, ExchangeServer, StreamSelector},
-    subscription::trade::PublicTrades,
-    transformer::stateless::StatelessTransformer,
+    subscription::{
+        book::{OrderBooksL1, OrderBooksL2, OrderBooksL2Events},
+        candle::Candles,
+        trade::PublicTrades,
+    },
+    transformer::{book::MultiBookTransformer, stateless::StatelessTransformer},
     ExchangeWsStream,
 };
+use barter_integration::model::instrument::Instrument;
 use barter_macro::{DeExchange, SerExchange};
 
 /// Public trades types.
 pub mod trade;
 
+/// Candlestick types.
+pub mod candle;
+
 /// [`GateioSpot`] WebSocket server base url.
 ///
 /// See docs: <https://www.gate.io/docs/developers/apiv4/ws/en/>
 pub const WEBSOCKET_BASE_URL_GATEIO_SPOT: &str = "wss://api.gateio.ws/ws/v4/";
 
+/// [`GateioSpot`] HTTP OrderBook L2 snapshot url.
+///
+/// See docs: <https://www.gate.io/docs/developers/apiv4/en/#retrieve-order-book>
+pub const HTTP_BOOK_L2_SNAPSHOT_URL_GATEIO_SPOT: &str =
+    "https://api.gateio.ws/api/v4/spot/order_book";
+
 /// [`Gateio`] spot exchange.
 pub type GateioSpot = Gateio<GateioServerSpot>;
 
@@ -34,6 +55,15 @@ impl ExchangeServer for GateioServerSpot {
     }
 }
 
+impl GateioServerBookL2 for GateioServerSpot {
+    fn http_book_l2_snapshot_url(market: &GateioMarket) -> String {
+        format!(
+            "{HTTP_BOOK_L2_SNAPSHOT_URL_GATEIO_SPOT}?currency_pair={}&limit=100&with_id=true",
+            market.as_ref()
+        )
+    }
+}
+
 impl<Instrument> StreamSelector<Instrument, PublicTrades> for GateioSpot
 where
     Instrument: InstrumentData,
@@ -41,3 +71,37 @@ where
     type Stream =
         ExchangeWsStream<StatelessTransformer<Self, Instrument::Id, PublicTrades, GateioSpotTrade>>;
 }
+
+impl<Instrument> StreamSelector<Instrument, Candles> for GateioSpot
+where
+    Instrument: InstrumentData,
+{
+    type Stream =
+        ExchangeWsStream<StatelessTransformer<Self, Instrument::Id, Candles, GateioSpotCandle>>;
+}
+
+impl<Instrument> StreamSelector<Instrument, OrderBooksL1> for GateioSpot
+where
+    Instrument: InstrumentData,
+{
+    type Stream = ExchangeWsStream<
+        StatelessTransformer<Self, Instrument::Id, OrderBooksL1, GateioOrderBookL1>,
+    >;
+}
+
+impl StreamSelector<Instrument, OrderBooksL2> for GateioSpot {
+    type Stream = ExchangeWsStream<
+        MultiBookTransformer<Self, Instrument, OrderBooksL2, GateioBookUpdater<GateioServerSpot>>,
+    >;
+}
+
+impl StreamSelector<Instrument, OrderBooksL2Events> for GateioSpot {
+    type Stream = ExchangeWsStream<
+        MultiBookTransformer<
+            Self,
+            Instrument,
+            OrderBooksL2Events,
+            GateioBookUpdater<GateioServerSpot>,
+        >,
+    >;
+}
@@ -33,7 +33,7 @@ pub struct GateioSpotTradeInner {
     pub market: String,
     #[serde(
         rename = "create_time_ms",
-        deserialize_with = "barter_integration::de::de_str_f64_epoch_ms_as_datetime_utc"
+        deserialize_with = "crate::util::time::de_str_epoch_ms_as_datetime_utc"
     )]
     pub time: DateTime<Utc>,
     pub id: u64,
@@ -75,6 +75,7 @@ impl<InstrumentId> From<(ExchangeId, InstrumentId, GateioSpotTrade)>
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::subscription::trade::tests::assert_duplicate_frame_dedup_keys;
 
     mod de {
         use super::*;
@@ -101,4 +102,28 @@ mod tests {
             serde_json::from_str::<GateioSpotTrade>(input).unwrap();
         }
     }
+
+    #[test]
+    fn test_gateio_spot_trade_duplicate_frame_dedup_keys() {
+        assert_duplicate_frame_dedup_keys::<GateioSpotTrade>(
+            ExchangeId::GateioSpot,
+            r#"
+            {
+                "time": 1606292218,
+                "time_ms": 1606292218231,
+                "channel": "spot.trades",
+                "event": "update",
+                "result": {
+                    "id": 309143071,
+                    "create_time": 1606292218,
+                    "create_time_ms": "1606292218213.4578",
+                    "side": "sell",
+                    "currency_pair": "GT_USDT",
+                    "amount": "16.4700000000",
+                    "price": "0.4705000000"
+                }
+            }
+            "#,
+        );
+    }
 }
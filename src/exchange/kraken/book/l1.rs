@@ -39,7 +39,7 @@ pub struct KrakenSpread {
     pub best_bid_price: f64,
     #[serde(deserialize_with = "barter_integration::de::de_str")]
     pub best_ask_price: f64,
-    #[serde(deserialize_with = "barter_integration::de::de_str_f64_epoch_s_as_datetime_utc")]
+    #[serde(deserialize_with = "crate::util::time::de_str_epoch_s_as_datetime_utc")]
     pub time: DateTime<Utc>,
     #[serde(deserialize_with = "barter_integration::de::de_str")]
     pub best_bid_amount: f64,
@@ -168,8 +168,8 @@ mod tests {
                     spread: KrakenSpread {
                         best_bid_price: 5698.4,
                         best_bid_amount: 1.01234567,
-                        time: datetime_utc_from_epoch_duration(std::time::Duration::from_secs_f64(
-                            1542057299.545897,
+                        time: datetime_utc_from_epoch_duration(std::time::Duration::new(
+                            1542057299, 545897000,
                         )),
                         best_ask_price: 5700.0,
                         best_ask_amount: 0.98765432,
@@ -0,0 +1,676 @@
+use super::super::{
+    channel::KrakenChannel,
+    market::{kraken_market, KrakenMarket},
+    KrakenMessage,
+};
+use crate::{
+    error::DataError,
+    exchange::subscription::ExchangeSub,
+    subscription::book::{pool::LevelPool, Level, OrderBook, OrderBookSide},
+    transformer::book::{
+        checksum::{BookChecksum, KrakenChecksum},
+        InstrumentOrderBook, OrderBookUpdater,
+    },
+    Identifier,
+};
+use async_trait::async_trait;
+use barter_integration::{
+    de::extract_next,
+    error::SocketError,
+    model::{instrument::Instrument, Side, SubscriptionId},
+    protocol::websocket::WsMessage,
+};
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use tokio::sync::mpsc;
+
+/// Number of [`Level`]s on each side of the [`Kraken`](super::super::Kraken) OrderBook Level2
+/// subscription.
+///
+/// See docs: <https://docs.kraken.com/websockets/#message-subscribe>
+pub const BOOK_L2_DEPTH_KRAKEN: usize = 10;
+
+/// Number of [`Level`]s on each side of the [`Kraken`](super::super::Kraken) OrderBook used to
+/// calculate the CRC32 checksum.
+///
+/// See docs: <https://docs.kraken.com/websockets/#book-checksum>
+pub const BOOK_L2_CHECKSUM_DEPTH_KRAKEN: usize = 10;
+
+/// Terse type alias for an [`Kraken`](super::super::Kraken) real-time OrderBook Level2
+/// WebSocket message.
+pub type KrakenOrderBookL2 = KrakenMessage<KrakenOrderBookL2Inner>;
+
+/// [`Kraken`](super::super::Kraken) real-time OrderBook Level2 data and the associated
+/// [`SubscriptionId`].
+///
+/// ### Raw Payload Examples
+/// See docs: <https://docs.kraken.com/websockets/#message-book>
+/// #### Snapshot
+/// ```json
+/// [
+///     0,
+///     {
+///         "as": [["5541.30000", "2.50700000", "1534614248.123678"]],
+///         "bs": [["5541.20000", "1.52900000", "1534614248.765567"]]
+///     },
+///     "book-10",
+///     "XBT/USD"
+/// ]
+/// ```
+///
+/// #### Update
+/// ```json
+/// [
+///     1234,
+///     {"a": [["5541.30000", "2.50700000", "1534614248.456738", "r"]]},
+///     {"b": [["5541.20000", "0.00000000", "1534614248.456738"]], "c": "974942666"},
+///     "book-10",
+///     "XBT/USD"
+/// ]
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Serialize)]
+pub struct KrakenOrderBookL2Inner {
+    pub subscription_id: SubscriptionId,
+    pub data: KrakenOrderBookL2Data,
+}
+
+impl Identifier<Option<SubscriptionId>> for KrakenOrderBookL2Inner {
+    fn id(&self) -> Option<SubscriptionId> {
+        Some(self.subscription_id.clone())
+    }
+}
+
+/// [`Kraken`](super::super::Kraken) OrderBook Level2 snapshot or update.
+///
+/// See [`KrakenOrderBookL2Inner`] for full raw payload examples.
+#[derive(Clone, PartialEq, PartialOrd, Debug, Serialize)]
+pub enum KrakenOrderBookL2Data {
+    Snapshot {
+        asks: Vec<KrakenLevel>,
+        bids: Vec<KrakenLevel>,
+    },
+    Update {
+        asks: Vec<KrakenLevel>,
+        bids: Vec<KrakenLevel>,
+        checksum: u32,
+    },
+}
+
+/// [`Kraken`](super::super::Kraken) OrderBook Level2 level.
+///
+/// The number of decimals used in the original price & amount `String`s is retained since it is
+/// required to re-construct the checksum input.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://docs.kraken.com/websockets/#message-book>
+/// ```json
+/// ["5541.30000", "2.50700000", "1534614248.456738", "r"]
+/// ```
+#[derive(Clone, Copy, PartialEq, PartialOrd, Debug, Serialize)]
+pub struct KrakenLevel {
+    pub price: f64,
+    pub amount: f64,
+    pub price_decimals: usize,
+    pub amount_decimals: usize,
+}
+
+impl From<KrakenLevel> for Level {
+    fn from(level: KrakenLevel) -> Self {
+        Self {
+            price: level.price,
+            amount: level.amount,
+        }
+    }
+}
+
+/// [`Kraken`](super::super::Kraken) [`OrderBookUpdater`] that maintains a local OrderBook Level2
+/// from the WebSocket snapshot & updates.
+///
+/// Kraken: Maintaining The Local OrderBook
+/// 1. Subscribe to the "book" channel with the desired depth.
+/// 2. The first message is a snapshot of the OrderBook.
+/// 3. Each update contains the absolute amount for a price level, where an amount of 0 removes
+///    the price level.
+/// 4. After applying an update, levels beyond the subscribed depth must be removed.
+/// 5. The CRC32 checksum of the top 10 asks & bids must equal the checksum of the update,
+///    otherwise the OrderBook must be re-subscribed to receive a new snapshot.
+///
+/// See docs: <https://docs.kraken.com/websockets/#book-checksum>
+#[derive(Clone, Debug)]
+pub struct KrakenBookUpdater {
+    pub market: KrakenMarket,
+    pub checksum: KrakenChecksum,
+    pub awaiting_snapshot: bool,
+    ws_sink_tx: mpsc::UnboundedSender<WsMessage>,
+}
+
+impl KrakenBookUpdater {
+    /// Construct a new [`Kraken`](super::super::Kraken) [`OrderBookUpdater`] that waits for the
+    /// initial WebSocket snapshot.
+    pub fn new(market: KrakenMarket, ws_sink_tx: mpsc::UnboundedSender<WsMessage>) -> Self {
+        Self {
+            market,
+            checksum: KrakenChecksum::default(),
+            awaiting_snapshot: true,
+            ws_sink_tx,
+        }
+    }
+
+    /// Unsubscribe & re-subscribe to the OrderBook Level2 channel in order to receive a fresh
+    /// snapshot. Updates received in the meantime are dropped.
+    pub fn resubscribe(&mut self) -> Result<(), DataError> {
+        self.awaiting_snapshot = true;
+
+        ["unsubscribe", "subscribe"]
+            .into_iter()
+            .try_for_each(|event| {
+                self.ws_sink_tx
+                    .send(book_l2_request(event, &self.market))
+                    .map_err(|_| DataError::Socket(SocketError::Sink))
+            })
+    }
+}
+
+#[async_trait]
+impl OrderBookUpdater for KrakenBookUpdater {
+    type OrderBook = OrderBook;
+    type Update = KrakenOrderBookL2;
+
+    async fn init<Exchange, Kind>(
+        ws_sink_tx: mpsc::UnboundedSender<WsMessage>,
+        instrument: Instrument,
+    ) -> Result<InstrumentOrderBook<Instrument, Self>, DataError>
+    where
+        Exchange: Send,
+        Kind: Send,
+    {
+        // Kraken sends the initial OrderBook snapshot over the WebSocket after subscribing
+        let market = kraken_market(&instrument.base, &instrument.quote);
+
+        Ok(InstrumentOrderBook {
+            instrument,
+            updater: Self::new(market, ws_sink_tx),
+            book: OrderBook {
+                last_update_time: Utc::now(),
+                bids: OrderBookSide::new(Side::Buy, Vec::<Level>::new()),
+                asks: OrderBookSide::new(Side::Sell, Vec::<Level>::new()),
+            },
+        })
+    }
+
+    fn update(
+        &mut self,
+        book: &mut Self::OrderBook,
+        update: Self::Update,
+        pool: &mut LevelPool,
+    ) -> Result<Option<Self::OrderBook>, DataError> {
+        let data = match update {
+            KrakenOrderBookL2::Data(KrakenOrderBookL2Inner { data, .. }) => data,
+            KrakenOrderBookL2::Event(_) => return Ok(None),
+        };
+
+        match data {
+            KrakenOrderBookL2Data::Snapshot { asks, bids } => {
+                // Retain the price & amount decimals required to construct the checksum input
+                if let Some(level) = asks.first().or(bids.first()) {
+                    self.checksum = KrakenChecksum {
+                        price_decimals: level.price_decimals,
+                        amount_decimals: level.amount_decimals,
+                    };
+                }
+
+                *book = OrderBook {
+                    last_update_time: Utc::now(),
+                    bids: OrderBookSide::new(Side::Buy, bids),
+                    asks: OrderBookSide::new(Side::Sell, asks),
+                };
+                book.bids.truncate(BOOK_L2_DEPTH_KRAKEN);
+                book.asks.truncate(BOOK_L2_DEPTH_KRAKEN);
+                self.awaiting_snapshot = false;
+
+                Ok(Some(book.snapshot_in(pool)))
+            }
+            KrakenOrderBookL2Data::Update { .. } if self.awaiting_snapshot => Ok(None),
+            KrakenOrderBookL2Data::Update { asks, bids, .. } => {
+                // Apply update & remove levels beyond the subscribed depth
+                book.last_update_time = Utc::now();
+                book.bids.upsert(bids);
+                book.asks.upsert(asks);
+                book.bids.truncate(BOOK_L2_DEPTH_KRAKEN);
+                book.asks.truncate(BOOK_L2_DEPTH_KRAKEN);
+
+                Ok(Some(book.snapshot_in(pool)))
+            }
+        }
+    }
+
+    fn expected_checksum(&self, update: &Self::Update) -> Option<u32> {
+        match update {
+            KrakenOrderBookL2::Data(KrakenOrderBookL2Inner {
+                data: KrakenOrderBookL2Data::Update { checksum, .. },
+                ..
+            }) if !self.awaiting_snapshot => Some(*checksum),
+            _ => None,
+        }
+    }
+
+    fn book_checksum(&self) -> Option<&dyn BookChecksum> {
+        Some(&self.checksum)
+    }
+
+    fn checksum_mismatch(&mut self, expected: u32, actual: u32) -> DataError {
+        // Re-subscribe to re-initialise the OrderBook with a fresh snapshot
+        match self.resubscribe() {
+            Ok(()) => DataError::InvalidChecksum { expected, actual },
+            Err(error) => error,
+        }
+    }
+}
+
+/// Construct a [`Kraken`](super::super::Kraken) OrderBook Level2 (un)subscribe request for the
+/// provided [`KrakenMarket`].
+pub fn book_l2_request(event: &str, market: &KrakenMarket) -> WsMessage {
+    WsMessage::Text(
+        json!({
+            "event": event,
+            "pair": [market.as_ref()],
+            "subscription": {
+                "name": KrakenChannel::ORDER_BOOK_L2.as_ref(),
+                "depth": BOOK_L2_DEPTH_KRAKEN,
+            }
+        })
+        .to_string(),
+    )
+}
+
+/// Number of decimals in a numeric `String` (eg/ "5541.30000" -> 5).
+fn decimals(value: &str) -> usize {
+    value
+        .split_once('.')
+        .map(|(_, decimals)| decimals.len())
+        .unwrap_or_default()
+}
+
+impl<'de> Deserialize<'de> for KrakenLevel {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        struct SeqVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for SeqVisitor {
+            type Value = KrakenLevel;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("KrakenLevel struct from the Kraken WebSocket API")
+            }
+
+            fn visit_seq<SeqAccessor>(
+                self,
+                mut seq: SeqAccessor,
+            ) -> Result<Self::Value, SeqAccessor::Error>
+            where
+                SeqAccessor: serde::de::SeqAccess<'de>,
+            {
+                // KrakenLevel Sequence Format:
+                // [price, volume, timestamp, (updateType)]
+                // <https://docs.kraken.com/websockets/#message-book>
+                let price = extract_next::<SeqAccessor, String>(&mut seq, "price")?;
+                let amount = extract_next::<SeqAccessor, String>(&mut seq, "volume")?;
+
+                // Ignore any additional elements (eg/ timestamp & "r" republish) or SerDe will fail
+                while seq.next_element::<serde::de::IgnoredAny>()?.is_some() {}
+
+                Ok(KrakenLevel {
+                    price: price.parse().map_err(serde::de::Error::custom)?,
+                    amount: amount.parse().map_err(serde::de::Error::custom)?,
+                    price_decimals: decimals(&price),
+                    amount_decimals: decimals(&amount),
+                })
+            }
+        }
+
+        deserializer.deserialize_seq(SeqVisitor)
+    }
+}
+
+impl<'de> Deserialize<'de> for KrakenOrderBookL2Inner {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        /// Element of a [`KrakenOrderBookL2Inner`] sequence following the channelID.
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Element {
+            Levels(Levels),
+            Name(String),
+        }
+
+        /// Object containing snapshot or update levels, and optionally the update checksum.
+        #[derive(Default, Deserialize)]
+        struct Levels {
+            #[serde(rename = "as")]
+            snapshot_asks: Option<Vec<KrakenLevel>>,
+            #[serde(rename = "bs")]
+            snapshot_bids: Option<Vec<KrakenLevel>>,
+            #[serde(rename = "a")]
+            asks: Option<Vec<KrakenLevel>>,
+            #[serde(rename = "b")]
+            bids: Option<Vec<KrakenLevel>>,
+            #[serde(rename = "c")]
+            checksum: Option<String>,
+        }
+
+        struct SeqVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for SeqVisitor {
+            type Value = KrakenOrderBookL2Inner;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("KrakenOrderBookL2Inner struct from the Kraken WebSocket API")
+            }
+
+            fn visit_seq<SeqAccessor>(
+                self,
+                mut seq: SeqAccessor,
+            ) -> Result<Self::Value, SeqAccessor::Error>
+            where
+                SeqAccessor: serde::de::SeqAccess<'de>,
+            {
+                // KrakenOrderBookL2Inner Sequence Format:
+                // [channelID, {asks}, ({bids}), channelName, pair]
+                // <https://docs.kraken.com/websockets/#message-book>
+
+                // Extract deprecated channelID & ignore
+                let _: serde::de::IgnoredAny = extract_next(&mut seq, "channelID")?;
+
+                // Merge level objects until channelName (eg/ "book-10") & pair are reached
+                let mut levels = Levels::default();
+                let mut names = Vec::with_capacity(2);
+                while names.len() < 2 {
+                    match extract_next::<SeqAccessor, Element>(&mut seq, "levels")? {
+                        Element::Levels(next) => {
+                            levels.snapshot_asks = levels.snapshot_asks.or(next.snapshot_asks);
+                            levels.snapshot_bids = levels.snapshot_bids.or(next.snapshot_bids);
+                            levels.asks = levels.asks.or(next.asks);
+                            levels.bids = levels.bids.or(next.bids);
+                            levels.checksum = levels.checksum.or(next.checksum);
+                        }
+                        Element::Name(name) => names.push(name),
+                    }
+                }
+
+                // Map pair (eg/ "XBT/USD") to SubscriptionId (ie/ "book|{pair}")
+                let pair = names.pop().unwrap_or_default();
+                let subscription_id = ExchangeSub::from((KrakenChannel::ORDER_BOOK_L2, pair)).id();
+
+                // Ignore any additional elements or SerDe will fail
+                //  '--> Exchange may add fields without warning
+                while seq.next_element::<serde::de::IgnoredAny>()?.is_some() {}
+
+                let data = match levels {
+                    Levels {
+                        snapshot_asks: None,
+                        snapshot_bids: None,
+                        asks,
+                        bids,
+                        checksum,
+                    } => KrakenOrderBookL2Data::Update {
+                        asks: asks.unwrap_or_default(),
+                        bids: bids.unwrap_or_default(),
+                        checksum: checksum
+                            .ok_or_else(|| serde::de::Error::missing_field("c"))?
+                            .parse()
+                            .map_err(serde::de::Error::custom)?,
+                    },
+                    Levels {
+                        snapshot_asks,
+                        snapshot_bids,
+                        ..
+                    } => KrakenOrderBookL2Data::Snapshot {
+                        asks: snapshot_asks.unwrap_or_default(),
+                        bids: snapshot_bids.unwrap_or_default(),
+                    },
+                };
+
+                Ok(KrakenOrderBookL2Inner {
+                    subscription_id,
+                    data,
+                })
+            }
+        }
+
+        // Use Visitor implementation to deserialize the KrakenOrderBookL2Inner
+        deserializer.deserialize_seq(SeqVisitor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod de {
+        use super::*;
+        use crate::exchange::kraken::message::KrakenEvent;
+
+        #[test]
+        fn test_kraken_message_order_book_l2() {
+            struct TestCase {
+                input: &'static str,
+                expected: Result<KrakenOrderBookL2, SocketError>,
+            }
+
+            let level = |price, amount, price_decimals, amount_decimals| KrakenLevel {
+                price,
+                amount,
+                price_decimals,
+                amount_decimals,
+            };
+
+            let tests = vec![
+                TestCase {
+                    // TC0: valid snapshot
+                    input: r#"
+                    [
+                        0,
+                        {
+                            "as": [["5541.30000", "2.50700000", "1534614248.123678"]],
+                            "bs": [["5541.20000", "1.52900000", "1534614248.765567"]]
+                        },
+                        "book-10",
+                        "XBT/USD"
+                    ]
+                    "#,
+                    expected: Ok(KrakenOrderBookL2::Data(KrakenOrderBookL2Inner {
+                        subscription_id: SubscriptionId::from("book|XBT/USD"),
+                        data: KrakenOrderBookL2Data::Snapshot {
+                            asks: vec![level(5541.3, 2.507, 5, 8)],
+                            bids: vec![level(5541.2, 1.529, 5, 8)],
+                        },
+                    })),
+                },
+                TestCase {
+                    // TC1: valid update w/ asks & bids in separate objects
+                    input: r#"
+                    [
+                        1234,
+                        {"a": [["5541.30000", "2.50700000", "1534614248.456738", "r"]]},
+                        {"b": [["5541.20000", "0.00000000", "1534614248.456738"]], "c": "974942666"},
+                        "book-10",
+                        "XBT/USD"
+                    ]
+                    "#,
+                    expected: Ok(KrakenOrderBookL2::Data(KrakenOrderBookL2Inner {
+                        subscription_id: SubscriptionId::from("book|XBT/USD"),
+                        data: KrakenOrderBookL2Data::Update {
+                            asks: vec![level(5541.3, 2.507, 5, 8)],
+                            bids: vec![level(5541.2, 0.0, 5, 8)],
+                            checksum: 974942666,
+                        },
+                    })),
+                },
+                TestCase {
+                    // TC2: invalid update w/o checksum
+                    input: r#"
+                    [
+                        1234,
+                        {"a": [["5541.30000", "2.50700000", "1534614248.456738"]]},
+                        "book-10",
+                        "XBT/USD"
+                    ]
+                    "#,
+                    expected: Err(SocketError::Unsupported {
+                        entity: "",
+                        item: "".to_string(),
+                    }),
+                },
+                TestCase {
+                    // TC3: valid subscriptionStatus event
+                    input: r#"
+                    {
+                        "channelID": 10001,
+                        "channelName": "book-10",
+                        "event": "subscriptionStatus",
+                        "pair": "XBT/USD",
+                        "status": "subscribed",
+                        "subscription": {"depth": 10, "name": "book"}
+                    }
+                    "#,
+                    expected: Ok(KrakenOrderBookL2::Event(KrakenEvent::SubscriptionStatus)),
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                let actual = serde_json::from_str::<KrakenOrderBookL2>(test.input);
+                match (actual, test.expected) {
+                    (Ok(actual), Ok(expected)) => {
+                        assert_eq!(actual, expected, "TC{} failed", index)
+                    }
+                    (Err(_), Err(_)) => {
+                        // Test passed
+                    }
+                    (actual, expected) => {
+                        // Test failed
+                        panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                    }
+                }
+            }
+        }
+    }
+
+    mod kraken_book_updater {
+        use super::*;
+        use crate::transformer::book::checksum;
+
+        fn updater() -> (KrakenBookUpdater, mpsc::UnboundedReceiver<WsMessage>) {
+            let (ws_sink_tx, ws_sink_rx) = mpsc::unbounded_channel();
+            let updater = KrakenBookUpdater::new(KrakenMarket("XBT/USD".to_string()), ws_sink_tx);
+            (updater, ws_sink_rx)
+        }
+
+        fn message(data: KrakenOrderBookL2Data) -> KrakenOrderBookL2 {
+            KrakenOrderBookL2::Data(KrakenOrderBookL2Inner {
+                subscription_id: SubscriptionId::from("book|XBT/USD"),
+                data,
+            })
+        }
+
+        fn level(price: f64, amount: f64) -> KrakenLevel {
+            KrakenLevel {
+                price,
+                amount,
+                price_decimals: 5,
+                amount_decimals: 8,
+            }
+        }
+
+        fn empty_book() -> OrderBook {
+            OrderBook {
+                last_update_time: Utc::now(),
+                bids: OrderBookSide::new(Side::Buy, Vec::<Level>::new()),
+                asks: OrderBookSide::new(Side::Sell, Vec::<Level>::new()),
+            }
+        }
+
+        #[test]
+        fn test_checksum() {
+            let (mut updater, _rx) = updater();
+            updater.checksum = KrakenChecksum {
+                price_decimals: 5,
+                amount_decimals: 8,
+            };
+
+            let mut book = empty_book();
+            book.asks
+                .upsert([level(0.05005, 0.00000500), level(0.05010, 0.00000100)]);
+            book.bids.upsert([level(0.05000, 0.00000300)]);
+            book.asks.sort();
+            book.bids.sort();
+
+            // crc32("5005" + "500" + "5010" + "100" + "5000" + "300")
+            assert_eq!(updater.checksum.checksum(&book), 1_807_088_029);
+        }
+
+        #[test]
+        fn test_update() {
+            let (mut updater, mut ws_sink_rx) = updater();
+            let mut book = empty_book();
+
+            // Updates received before the initial snapshot are dropped
+            let update = message(KrakenOrderBookL2Data::Update {
+                asks: vec![level(0.05005, 0.00000500)],
+                bids: vec![],
+                checksum: 0,
+            });
+            assert!(matches!(
+                checksum::update(&mut updater, &mut book, update, &mut LevelPool::default()),
+                Ok(None)
+            ));
+
+            // Snapshot initialises the OrderBook
+            let snapshot = message(KrakenOrderBookL2Data::Snapshot {
+                asks: vec![level(0.05010, 0.00000100), level(0.05005, 0.00000500)],
+                bids: vec![level(0.05000, 0.00000300)],
+            });
+            let actual =
+                checksum::update(&mut updater, &mut book, snapshot, &mut LevelPool::default())
+                    .unwrap()
+                    .unwrap();
+            assert!(!updater.awaiting_snapshot);
+            assert_eq!(actual.asks.levels()[0], Level::new(0.05005, 0.00000500));
+
+            // Update w/ valid checksum is applied
+            let update = message(KrakenOrderBookL2Data::Update {
+                asks: vec![level(0.05010, 0.0)],
+                bids: vec![],
+                // crc32("5005" + "500" + "5000" + "300")
+                checksum: 3_776_718_161,
+            });
+            let actual =
+                checksum::update(&mut updater, &mut book, update, &mut LevelPool::default())
+                    .unwrap()
+                    .unwrap();
+            assert_eq!(actual.asks.levels(), &[Level::new(0.05005, 0.00000500)]);
+
+            // Update w/ invalid checksum triggers a re-subscription
+            let update = message(KrakenOrderBookL2Data::Update {
+                asks: vec![],
+                bids: vec![level(0.04995, 0.00000100)],
+                checksum: 1,
+            });
+            assert!(matches!(
+                checksum::update(&mut updater, &mut book, update, &mut LevelPool::default()),
+                Err(DataError::InvalidChecksum { expected: 1, .. })
+            ));
+            assert!(updater.awaiting_snapshot);
+            assert!(
+                matches!(ws_sink_rx.try_recv(), Ok(WsMessage::Text(text)) if text.contains("unsubscribe"))
+            );
+            assert!(
+                matches!(ws_sink_rx.try_recv(), Ok(WsMessage::Text(text)) if text.contains("\"subscribe\""))
+            );
+        }
+    }
+}
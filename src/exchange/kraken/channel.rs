@@ -1,6 +1,11 @@
 use super::Kraken;
 use crate::{
-    subscription::{book::OrderBooksL1, trade::PublicTrades, Subscription},
+    subscription::{
+        book::{OrderBooksL1, OrderBooksL2},
+        status::ExchangeStatus,
+        trade::PublicTrades,
+        Subscription,
+    },
     Identifier,
 };
 use serde::Serialize;
@@ -22,6 +27,17 @@ impl KrakenChannel {
     ///
     /// See docs: <https://docs.kraken.com/websockets/#message-subscribe>
     pub const ORDER_BOOK_L1: Self = Self("spread");
+
+    /// [`Kraken`] real-time OrderBook Level2 channel name.
+    ///
+    /// See docs: <https://docs.kraken.com/websockets/#message-book>
+    pub const ORDER_BOOK_L2: Self = Self("book");
+
+    /// [`Kraken`] real-time ticker channel name, used to carry
+    /// [`ExchangeStatus`] subscriptions since "systemStatus" events have no dedicated channel.
+    ///
+    /// See docs: <https://docs.kraken.com/websockets/#message-ticker>
+    pub const TICKER: Self = Self("ticker");
 }
 
 impl<Instrument> Identifier<KrakenChannel> for Subscription<Kraken, Instrument, PublicTrades> {
@@ -36,6 +52,18 @@ impl<Instrument> Identifier<KrakenChannel> for Subscription<Kraken, Instrument,
     }
 }
 
+impl<Instrument> Identifier<KrakenChannel> for Subscription<Kraken, Instrument, OrderBooksL2> {
+    fn id(&self) -> KrakenChannel {
+        KrakenChannel::ORDER_BOOK_L2
+    }
+}
+
+impl<Instrument> Identifier<KrakenChannel> for Subscription<Kraken, Instrument, ExchangeStatus> {
+    fn id(&self) -> KrakenChannel {
+        KrakenChannel::TICKER
+    }
+}
+
 impl AsRef<str> for KrakenChannel {
     fn as_ref(&self) -> &str {
         self.0
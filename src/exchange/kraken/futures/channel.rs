@@ -0,0 +1,43 @@
+use super::KrakenFutures;
+use crate::{
+    subscription::{funding::FundingRates, premium::PremiumIndexes, Subscription},
+    Identifier,
+};
+use serde::Serialize;
+
+/// Type that defines how to translate a Barter [`Subscription`] into a
+/// [`KrakenFutures`] feed to be subscribed to.
+///
+/// See docs: <https://docs.futures.kraken.com/#websocket-api-public-feeds>
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Serialize)]
+pub struct KrakenFuturesChannel(pub &'static str);
+
+impl KrakenFuturesChannel {
+    /// [`KrakenFutures`] real-time ticker feed name, containing the mark price, index price and
+    /// funding rate of each product.
+    ///
+    /// See docs: <https://docs.futures.kraken.com/#websocket-api-public-feeds-ticker>
+    pub const TICKER: Self = Self("ticker");
+}
+
+impl<Instrument> Identifier<KrakenFuturesChannel>
+    for Subscription<KrakenFutures, Instrument, PremiumIndexes>
+{
+    fn id(&self) -> KrakenFuturesChannel {
+        KrakenFuturesChannel::TICKER
+    }
+}
+
+impl<Instrument> Identifier<KrakenFuturesChannel>
+    for Subscription<KrakenFutures, Instrument, FundingRates>
+{
+    fn id(&self) -> KrakenFuturesChannel {
+        KrakenFuturesChannel::TICKER
+    }
+}
+
+impl AsRef<str> for KrakenFuturesChannel {
+    fn as_ref(&self) -> &str {
+        self.0
+    }
+}
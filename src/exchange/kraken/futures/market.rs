@@ -0,0 +1,101 @@
+use super::KrakenFutures;
+use crate::{
+    instrument::{KeyedInstrument, MarketInstrumentData},
+    subscription::Subscription,
+    Identifier,
+};
+use barter_integration::model::instrument::{kind::InstrumentKind, Instrument};
+use serde::{Deserialize, Serialize};
+
+/// Type that defines how to translate a Barter [`Subscription`] into a
+/// [`KrakenFutures`] product that can be subscribed to.
+///
+/// See docs: <https://docs.futures.kraken.com/#websocket-api-public-feeds-ticker>
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct KrakenFuturesMarket(pub String);
+
+impl<Kind> Identifier<KrakenFuturesMarket> for Subscription<KrakenFutures, Instrument, Kind> {
+    fn id(&self) -> KrakenFuturesMarket {
+        kraken_futures_market(&self.instrument)
+    }
+}
+
+impl<Kind> Identifier<KrakenFuturesMarket> for Subscription<KrakenFutures, KeyedInstrument, Kind> {
+    fn id(&self) -> KrakenFuturesMarket {
+        kraken_futures_market(&self.instrument.data)
+    }
+}
+
+impl<Kind> Identifier<KrakenFuturesMarket>
+    for Subscription<KrakenFutures, MarketInstrumentData, Kind>
+{
+    fn id(&self) -> KrakenFuturesMarket {
+        KrakenFuturesMarket(self.instrument.name_exchange.clone())
+    }
+}
+
+impl AsRef<str> for KrakenFuturesMarket {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+fn kraken_futures_market(instrument: &Instrument) -> KrakenFuturesMarket {
+    let Instrument { base, quote, kind } = instrument;
+
+    // Notes:
+    // - Multi-collateral perpetuals are prefixed with "PF_" (eg/ "PF_XBTUSD").
+    // - Multi-collateral fixed maturity futures are prefixed with "FF_", and suffixed with the
+    //   expiry date (eg/ "FF_XBTUSD_240628").
+    KrakenFuturesMarket(
+        match kind {
+            InstrumentKind::Future(future) => {
+                format!("FF_{base}{quote}_{}", future.expiry.format("%y%m%d"))
+            }
+            _ => format!("PF_{base}{quote}"),
+        }
+        .to_uppercase(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use barter_integration::model::instrument::kind::FutureContract;
+    use chrono::{TimeZone, Utc};
+
+    #[test]
+    fn test_kraken_futures_market() {
+        struct TestCase {
+            input: Instrument,
+            expected: &'static str,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: perpetual
+                input: Instrument::from(("xbt", "usd", InstrumentKind::Perpetual)),
+                expected: "PF_XBTUSD",
+            },
+            TestCase {
+                // TC1: fixed maturity future
+                input: Instrument::from((
+                    "eth",
+                    "usd",
+                    InstrumentKind::Future(FutureContract {
+                        expiry: Utc.with_ymd_and_hms(2024, 6, 28, 16, 0, 0).unwrap(),
+                    }),
+                )),
+                expected: "FF_ETHUSD_240628",
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            assert_eq!(
+                kraken_futures_market(&test.input).0,
+                test.expected,
+                "TC{index} failed"
+            );
+        }
+    }
+}
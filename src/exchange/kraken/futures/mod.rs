@@ -0,0 +1,105 @@
+use self::{
+    channel::KrakenFuturesChannel, market::KrakenFuturesMarket,
+    subscription::KrakenFuturesSubResponse, ticker::KrakenFuturesTicker,
+};
+use crate::{
+    exchange::{Connector, ExchangeId, ExchangeSub, Keepalive, StreamSelector},
+    instrument::InstrumentData,
+    subscriber::{validator::WebSocketSubValidator, WebSocketSubscriber},
+    subscription::{funding::FundingRates, premium::PremiumIndexes},
+    transformer::stateless::StatelessTransformer,
+    ExchangeWsStream,
+};
+use barter_integration::{error::SocketError, protocol::websocket::WsMessage};
+use barter_macro::{DeExchange, SerExchange};
+use serde_json::json;
+use std::time::Duration;
+use url::Url;
+
+/// Defines the type that translates a Barter [`Subscription`](crate::subscription::Subscription)
+/// into an exchange [`Connector`] specific channel used for generating [`Connector::requests`].
+pub mod channel;
+
+/// Defines the type that translates a Barter [`Subscription`](crate::subscription::Subscription)
+/// into an exchange [`Connector`] specific market used for generating [`Connector::requests`].
+pub mod market;
+
+/// [`Subscription`](crate::subscription::Subscription) response type and response
+/// [`Validator`](barter_integration) for [`KrakenFutures`].
+pub mod subscription;
+
+/// Ticker types for [`KrakenFutures`], normalised into [`PremiumIndexes`] and [`FundingRates`].
+pub mod ticker;
+
+/// [`KrakenFutures`] server base url.
+///
+/// See docs: <https://docs.futures.kraken.com/#websocket-api-websocket-api-introduction>
+pub const BASE_URL_KRAKEN_FUTURES: &str = "wss://futures.kraken.com/ws/v1";
+
+/// [`KrakenFutures`] server protocol-level [`Keepalive`] ping interval.
+///
+/// The server closes connections that do not send a ping at least every 60 seconds.
+///
+/// See docs: <https://docs.futures.kraken.com/#websocket-api-websocket-api-introduction-subscriptions>
+pub const PING_INTERVAL_KRAKEN_FUTURES: Duration = Duration::from_secs(30);
+
+/// [`KrakenFutures`] exchange.
+///
+/// See docs: <https://docs.futures.kraken.com/#websocket-api-public-feeds>
+#[derive(
+    Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default, DeExchange, SerExchange,
+)]
+pub struct KrakenFutures;
+
+impl Connector for KrakenFutures {
+    const ID: ExchangeId = ExchangeId::KrakenFutures;
+    type Channel = KrakenFuturesChannel;
+    type Market = KrakenFuturesMarket;
+    type Subscriber = WebSocketSubscriber;
+    type SubValidator = WebSocketSubValidator;
+    type SubResponse = KrakenFuturesSubResponse;
+
+    fn url() -> Result<Url, SocketError> {
+        Url::parse(BASE_URL_KRAKEN_FUTURES).map_err(SocketError::UrlParse)
+    }
+
+    fn keepalive() -> Keepalive {
+        Keepalive::Protocol {
+            interval: PING_INTERVAL_KRAKEN_FUTURES,
+        }
+    }
+
+    fn requests(exchange_subs: Vec<ExchangeSub<Self::Channel, Self::Market>>) -> Vec<WsMessage> {
+        exchange_subs
+            .into_iter()
+            .map(|ExchangeSub { channel, market }| {
+                WsMessage::Text(
+                    json!({
+                        "event": "subscribe",
+                        "feed": channel.as_ref(),
+                        "product_ids": [market.as_ref()],
+                    })
+                    .to_string(),
+                )
+            })
+            .collect()
+    }
+}
+
+impl<Instrument> StreamSelector<Instrument, PremiumIndexes> for KrakenFutures
+where
+    Instrument: InstrumentData,
+{
+    type Stream = ExchangeWsStream<
+        StatelessTransformer<Self, Instrument::Id, PremiumIndexes, KrakenFuturesTicker>,
+    >;
+}
+
+impl<Instrument> StreamSelector<Instrument, FundingRates> for KrakenFutures
+where
+    Instrument: InstrumentData,
+{
+    type Stream = ExchangeWsStream<
+        StatelessTransformer<Self, Instrument::Id, FundingRates, KrakenFuturesTicker>,
+    >;
+}
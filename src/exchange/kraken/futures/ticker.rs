@@ -0,0 +1,234 @@
+use super::channel::KrakenFuturesChannel;
+use crate::{
+    event::{MarketEvent, MarketIter},
+    exchange::{ExchangeId, ExchangeSub},
+    subscription::{funding::FundingRate, premium::PremiumIndex},
+    Identifier,
+};
+use barter_integration::model::{Exchange, SubscriptionId};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// [`KrakenFutures`](super::KrakenFutures) real-time ticker message, of which only the mark
+/// price, index price and funding fields are used.
+///
+/// ### Notes
+/// - Funding fields are only populated for perpetual products, so fixed maturity futures do not
+///   generate [`PremiumIndex`] or [`FundingRate`] events.
+/// - The `relative_funding_rate` is used as the normalised funding rate, and is quoted per
+///   hourly funding period. The `funding_rate` field is the absolute funding rate in the
+///   quote currency, and is ignored.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://docs.futures.kraken.com/#websocket-api-public-feeds-ticker>
+/// ```json
+/// {
+///   "time": 1612270825253,
+///   "feed": "ticker",
+///   "product_id": "PF_XBTUSD",
+///   "bid": 34832.5,
+///   "ask": 34847.5,
+///   "index": 34840.31,
+///   "markPrice": 34841.35,
+///   "funding_rate": 2.4271856e-10,
+///   "relative_funding_rate": 8.4584034e-06,
+///   "next_funding_rate_time": 1612281600000,
+///   "tag": "perpetual",
+///   "pair": "XBT:USD"
+/// }
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct KrakenFuturesTicker {
+    #[serde(rename = "product_id", deserialize_with = "de_ticker_subscription_id")]
+    pub subscription_id: SubscriptionId,
+    #[serde(deserialize_with = "crate::util::time::de_u64_epoch_ms_as_datetime_utc")]
+    pub time: DateTime<Utc>,
+    #[serde(rename = "markPrice")]
+    pub mark_price: f64,
+    #[serde(rename = "index")]
+    pub index_price: f64,
+    #[serde(default, rename = "relative_funding_rate")]
+    pub funding_rate: Option<f64>,
+    #[serde(
+        default,
+        rename = "next_funding_rate_time",
+        deserialize_with = "crate::util::time::de_option_u64_epoch_ms_as_datetime_utc"
+    )]
+    pub next_funding_time: Option<DateTime<Utc>>,
+}
+
+impl Identifier<Option<SubscriptionId>> for KrakenFuturesTicker {
+    fn id(&self) -> Option<SubscriptionId> {
+        Some(self.subscription_id.clone())
+    }
+}
+
+impl<InstrumentId> From<(ExchangeId, InstrumentId, KrakenFuturesTicker)>
+    for MarketIter<InstrumentId, PremiumIndex>
+{
+    fn from(
+        (exchange_id, instrument, ticker): (ExchangeId, InstrumentId, KrakenFuturesTicker),
+    ) -> Self {
+        // Fixed maturity futures tickers do not contain funding fields
+        let (Some(funding_rate), Some(next_funding_time)) =
+            (ticker.funding_rate, ticker.next_funding_time)
+        else {
+            return Self(vec![]);
+        };
+
+        Self(vec![Ok(MarketEvent {
+            exchange_time: ticker.time,
+            received_time: Utc::now(),
+            exchange: Exchange::from(exchange_id),
+            instrument,
+            kind: PremiumIndex {
+                mark_price: ticker.mark_price,
+                index_price: ticker.index_price,
+                funding_rate,
+                next_funding_time,
+            },
+        })])
+    }
+}
+
+impl<InstrumentId> From<(ExchangeId, InstrumentId, KrakenFuturesTicker)>
+    for MarketIter<InstrumentId, FundingRate>
+{
+    fn from(
+        (exchange_id, instrument, ticker): (ExchangeId, InstrumentId, KrakenFuturesTicker),
+    ) -> Self {
+        // Fixed maturity futures tickers do not contain funding fields
+        let Some(rate) = ticker.funding_rate else {
+            return Self(vec![]);
+        };
+
+        Self(vec![Ok(MarketEvent {
+            exchange_time: ticker.time,
+            received_time: Utc::now(),
+            exchange: Exchange::from(exchange_id),
+            instrument,
+            kind: FundingRate {
+                rate,
+                next_funding_time: ticker.next_funding_time,
+            },
+        })])
+    }
+}
+
+/// Deserialize a [`KrakenFuturesTicker`] "product_id" (eg/ "PF_XBTUSD") as the associated
+/// [`SubscriptionId`] (eg/ "ticker|PF_XBTUSD").
+pub fn de_ticker_subscription_id<'de, D>(deserializer: D) -> Result<SubscriptionId, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+{
+    <&str as Deserialize>::deserialize(deserializer)
+        .map(|product_id| ExchangeSub::from((KrakenFuturesChannel::TICKER, product_id)).id())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod de {
+        use super::*;
+        use barter_integration::{de::datetime_utc_from_epoch_duration, error::SocketError};
+        use std::time::Duration;
+
+        #[test]
+        fn test_kraken_futures_ticker() {
+            struct TestCase {
+                input: &'static str,
+                expected: Result<KrakenFuturesTicker, SocketError>,
+            }
+
+            let tests = vec![
+                TestCase {
+                    // TC0: valid perpetual ticker w/ funding fields
+                    input: r#"
+                    {
+                        "time": 1612270825253,
+                        "feed": "ticker",
+                        "product_id": "PF_XBTUSD",
+                        "bid": 34832.5,
+                        "ask": 34847.5,
+                        "index": 34840.31,
+                        "markPrice": 34841.35,
+                        "funding_rate": 2.4271856e-10,
+                        "relative_funding_rate": 8.4584034e-06,
+                        "next_funding_rate_time": 1612281600000,
+                        "tag": "perpetual",
+                        "pair": "XBT:USD"
+                    }
+                    "#,
+                    expected: Ok(KrakenFuturesTicker {
+                        subscription_id: SubscriptionId::from("ticker|PF_XBTUSD"),
+                        time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                            1612270825253,
+                        )),
+                        mark_price: 34841.35,
+                        index_price: 34840.31,
+                        funding_rate: Some(8.4584034e-06),
+                        next_funding_time: Some(datetime_utc_from_epoch_duration(
+                            Duration::from_millis(1612281600000),
+                        )),
+                    }),
+                },
+                TestCase {
+                    // TC1: valid fixed maturity futures ticker w/o funding fields
+                    input: r#"
+                    {
+                        "time": 1612270825253,
+                        "feed": "ticker",
+                        "product_id": "FF_XBTUSD_240628",
+                        "index": 34840.31,
+                        "markPrice": 34901.5,
+                        "tag": "month",
+                        "pair": "XBT:USD"
+                    }
+                    "#,
+                    expected: Ok(KrakenFuturesTicker {
+                        subscription_id: SubscriptionId::from("ticker|FF_XBTUSD_240628"),
+                        time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                            1612270825253,
+                        )),
+                        mark_price: 34901.5,
+                        index_price: 34840.31,
+                        funding_rate: None,
+                        next_funding_time: None,
+                    }),
+                },
+                TestCase {
+                    // TC2: invalid ticker w/o mark price
+                    input: r#"
+                    {
+                        "time": 1612270825253,
+                        "feed": "ticker",
+                        "product_id": "PF_XBTUSD",
+                        "index": 34840.31
+                    }
+                    "#,
+                    expected: Err(SocketError::Unsupported {
+                        entity: "",
+                        item: "".to_string(),
+                    }),
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                let actual = serde_json::from_str::<KrakenFuturesTicker>(test.input);
+                match (actual, test.expected) {
+                    (Ok(actual), Ok(expected)) => {
+                        assert_eq!(actual, expected, "TC{} failed", index)
+                    }
+                    (Err(_), Err(_)) => {
+                        // Test passed
+                    }
+                    (actual, expected) => {
+                        // Test failed
+                        panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                    }
+                }
+            }
+        }
+    }
+}
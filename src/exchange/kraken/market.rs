@@ -36,6 +36,6 @@ impl AsRef<str> for KrakenMarket {
     }
 }
 
-fn kraken_market(base: &Symbol, quote: &Symbol) -> KrakenMarket {
+pub(super) fn kraken_market(base: &Symbol, quote: &Symbol) -> KrakenMarket {
     KrakenMarket(format!("{base}/{quote}").to_uppercase())
 }
@@ -91,7 +91,8 @@ where
 /// [`Kraken`](super::Kraken) messages received over the WebSocket which are not subscription data.
 ///
 /// eg/ [`Kraken`](super::Kraken) sends a [`KrakenEvent::Heartbeat`] if no subscription traffic
-/// has been sent within the last second.
+/// has been sent within the last second, and a [`KrakenEvent::SubscriptionStatus`] when a channel
+/// is (re)subscribed after the initial subscription validation.
 ///
 /// See [`KrakenMessage`] for full raw payload examples.
 ///
@@ -100,6 +101,7 @@ where
 #[serde(tag = "event", rename_all = "camelCase")]
 pub enum KrakenEvent {
     Heartbeat,
+    SubscriptionStatus,
     Error(KrakenError),
 }
 
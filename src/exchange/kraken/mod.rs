@@ -1,16 +1,34 @@
 use self::{
-    book::l1::KrakenOrderBookL1, channel::KrakenChannel, market::KrakenMarket,
-    message::KrakenMessage, subscription::KrakenSubResponse, trade::KrakenTrades,
+    book::{
+        l1::KrakenOrderBookL1,
+        l2::{book_l2_request, KrakenBookUpdater},
+    },
+    channel::KrakenChannel,
+    market::KrakenMarket,
+    message::KrakenMessage,
+    status::KrakenStatusMessage,
+    subscription::KrakenSubResponse,
+    trade::KrakenTrades,
 };
 use crate::instrument::InstrumentData;
 use crate::{
     exchange::{Connector, ExchangeId, ExchangeSub, StreamSelector},
     subscriber::{validator::WebSocketSubValidator, WebSocketSubscriber},
-    subscription::{book::OrderBooksL1, trade::PublicTrades},
-    transformer::stateless::StatelessTransformer,
+    subscription::{
+        book::{OrderBooksL1, OrderBooksL2},
+        status::ExchangeStatus,
+        trade::PublicTrades,
+    },
+    transformer::{
+        book::MultiBookTransformer, stateless::StatelessTransformer, status::StatusTransformer,
+    },
     ExchangeWsStream,
 };
-use barter_integration::{error::SocketError, protocol::websocket::WsMessage};
+use barter_integration::{
+    error::SocketError,
+    model::{instrument::Instrument, SubscriptionId},
+    protocol::websocket::WsMessage,
+};
 use barter_macro::{DeExchange, SerExchange};
 use serde_json::json;
 use url::Url;
@@ -26,9 +44,16 @@ pub mod channel;
 /// into an exchange [`Connector`]  specific market used for generating [`Connector::requests`].
 pub mod market;
 
+/// [`KrakenFutures`](futures::KrakenFutures) [`Connector`] and [`StreamSelector`]
+/// implementations.
+pub mod futures;
+
 /// [`KrakenMessage`](message::KrakenMessage) type for [`Kraken`].
 pub mod message;
 
+/// System status types for [`Kraken`].
+pub mod status;
+
 /// [`Subscription`](crate::subscription::Subscription) response type and response
 /// [`Validator`](barter_integration) for [`Kraken`].
 pub mod subscription;
@@ -65,6 +90,11 @@ impl Connector for Kraken {
         exchange_subs
             .into_iter()
             .map(|ExchangeSub { channel, market }| {
+                // OrderBook Level2 subscriptions also require the book depth
+                if channel == KrakenChannel::ORDER_BOOK_L2 {
+                    return book_l2_request("subscribe", &market);
+                }
+
                 WsMessage::Text(
                     json!({
                         "event": "subscribe",
@@ -78,6 +108,9 @@ impl Connector for Kraken {
             })
             .collect()
     }
+    fn rejected_subscription(response: &Self::SubResponse) -> Option<SubscriptionId> {
+        response.rejected_subscription_id()
+    }
 }
 
 impl<Instrument> StreamSelector<Instrument, PublicTrades> for Kraken
@@ -96,3 +129,15 @@ where
         StatelessTransformer<Self, Instrument::Id, OrderBooksL1, KrakenOrderBookL1>,
     >;
 }
+
+impl StreamSelector<Instrument, OrderBooksL2> for Kraken {
+    type Stream =
+        ExchangeWsStream<MultiBookTransformer<Self, Instrument, OrderBooksL2, KrakenBookUpdater>>;
+}
+
+impl<Instrument> StreamSelector<Instrument, ExchangeStatus> for Kraken
+where
+    Instrument: InstrumentData,
+{
+    type Stream = ExchangeWsStream<StatusTransformer<Self, Instrument::Id, KrakenStatusMessage>>;
+}
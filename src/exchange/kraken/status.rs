@@ -0,0 +1,166 @@
+use crate::{
+    event::{MarketEvent, MarketIter},
+    exchange::ExchangeId,
+    subscription::status::{SystemState, SystemStatus},
+};
+use barter_integration::model::Exchange;
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+
+/// [`Kraken`](super::Kraken) message received over
+/// [`WebSocket`](barter_integration::protocol::websocket::WebSocket) relating to an active
+/// [`ExchangeStatus`](crate::subscription::status::ExchangeStatus) subscription.
+///
+/// ### Notes
+/// Kraken pushes a "systemStatus" event upon connecting and whenever the status changes. Since
+/// there is no dedicated channel, the subscription is carried by the
+/// [`KrakenChannel::TICKER`](super::channel::KrakenChannel::TICKER) channel, and every other
+/// message is ignored.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://docs.kraken.com/websockets/#message-systemStatus>
+/// ```json
+/// {
+///   "connectionID": 8628615390848610000,
+///   "event": "systemStatus",
+///   "status": "online",
+///   "version": "1.0.0"
+/// }
+/// ```
+#[derive(Copy, Clone, PartialEq, Debug, Deserialize)]
+#[serde(untagged)]
+pub enum KrakenStatusMessage {
+    Status(KrakenSystemStatus),
+    Other(serde::de::IgnoredAny),
+}
+
+/// [`Kraken`](super::Kraken) "systemStatus" event.
+///
+/// See [`KrakenStatusMessage`] for full raw payload examples.
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Debug, Deserialize, Serialize)]
+#[serde(tag = "event", rename_all = "camelCase")]
+pub enum KrakenSystemStatus {
+    SystemStatus { status: KrakenSystemState },
+}
+
+/// [`Kraken`](super::Kraken) system state.
+///
+/// See docs: <https://docs.kraken.com/websockets/#message-systemStatus>
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum KrakenSystemState {
+    Online,
+    Maintenance,
+    CancelOnly,
+    LimitOnly,
+    PostOnly,
+}
+
+impl From<KrakenSystemState> for SystemState {
+    fn from(state: KrakenSystemState) -> Self {
+        match state {
+            KrakenSystemState::Online => SystemState::Online,
+            KrakenSystemState::Maintenance => SystemState::Maintenance,
+            KrakenSystemState::CancelOnly => SystemState::CancelOnly,
+            KrakenSystemState::LimitOnly => SystemState::LimitOnly,
+            KrakenSystemState::PostOnly => SystemState::PostOnly,
+        }
+    }
+}
+
+impl<InstrumentId> From<(ExchangeId, InstrumentId, KrakenStatusMessage)>
+    for MarketIter<InstrumentId, SystemStatus>
+{
+    fn from(
+        (exchange_id, instrument, message): (ExchangeId, InstrumentId, KrakenStatusMessage),
+    ) -> Self {
+        match message {
+            KrakenStatusMessage::Status(KrakenSystemStatus::SystemStatus { status }) => {
+                // Kraken systemStatus events do not contain an exchange timestamp
+                let time = Utc::now();
+                Self(vec![Ok(MarketEvent {
+                    exchange_time: time,
+                    received_time: time,
+                    exchange: Exchange::from(exchange_id),
+                    instrument,
+                    kind: SystemStatus::new(SystemState::from(status)),
+                })])
+            }
+            KrakenStatusMessage::Other(_) => Self(vec![]),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod de {
+        use super::*;
+        use barter_integration::error::SocketError;
+
+        #[test]
+        fn test_kraken_status_message() {
+            struct TestCase {
+                input: &'static str,
+                expected: Result<KrakenStatusMessage, SocketError>,
+            }
+
+            let tests = vec![
+                TestCase {
+                    // TC0: valid systemStatus
+                    input: r#"
+                    {
+                        "connectionID": 8628615390848610000,
+                        "event": "systemStatus",
+                        "status": "cancel_only",
+                        "version": "1.0.0"
+                    }
+                    "#,
+                    expected: Ok(KrakenStatusMessage::Status(
+                        KrakenSystemStatus::SystemStatus {
+                            status: KrakenSystemState::CancelOnly,
+                        },
+                    )),
+                },
+                TestCase {
+                    // TC1: subscriptionStatus is ignored
+                    input: r#"
+                    {
+                        "channelID": 10001,
+                        "channelName": "ticker",
+                        "event": "subscriptionStatus",
+                        "pair": "XBT/EUR",
+                        "status": "subscribed",
+                        "subscription": {
+                            "name": "ticker"
+                        }
+                    }
+                    "#,
+                    expected: Ok(KrakenStatusMessage::Other(serde::de::IgnoredAny)),
+                },
+                TestCase {
+                    // TC2: ticker data is ignored
+                    input: r#"[340,{"a":["5525.40000",1,"1.000"]},"ticker","XBT/USD"]"#,
+                    expected: Ok(KrakenStatusMessage::Other(serde::de::IgnoredAny)),
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                let actual = serde_json::from_str::<KrakenStatusMessage>(test.input);
+                match (actual, test.expected) {
+                    (Ok(actual), Ok(expected)) => {
+                        assert_eq!(actual, expected, "TC{} failed", index)
+                    }
+                    (Err(_), Err(_)) => {
+                        // Test passed
+                    }
+                    (actual, expected) => {
+                        // Test failed
+                        panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                    }
+                }
+            }
+        }
+    }
+}
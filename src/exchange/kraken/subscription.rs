@@ -1,5 +1,5 @@
 use super::message::KrakenError;
-use barter_integration::{error::SocketError, Validator};
+use barter_integration::{error::SocketError, model::SubscriptionId, Validator};
 use serde::{Deserialize, Serialize};
 
 /// [`Kraken`](super::Kraken) message received in response to WebSocket subscription requests.
@@ -42,7 +42,38 @@ pub enum KrakenSubResponse {
         channel_name: String,
         pair: String,
     },
-    Error(KrakenError),
+    Error {
+        #[serde(flatten)]
+        error: KrakenError,
+        pair: Option<String>,
+        subscription: Option<KrakenSubscriptionName>,
+    },
+}
+
+/// Name of the [`Kraken`](super::Kraken) channel a [`KrakenSubResponse`] relates to.
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct KrakenSubscriptionName {
+    pub name: String,
+}
+
+impl KrakenSubResponse {
+    /// Determine the [`SubscriptionId`] of the subscription rejected by a
+    /// [`KrakenSubResponse::Error`], if the response identifies it.
+    ///
+    /// eg/ "trade|XBT/USD"
+    pub fn rejected_subscription_id(&self) -> Option<SubscriptionId> {
+        match self {
+            Self::Error {
+                pair: Some(pair),
+                subscription: Some(subscription),
+                ..
+            } => Some(SubscriptionId::from(format!(
+                "{}|{}",
+                subscription.name, pair
+            ))),
+            _ => None,
+        }
+    }
 }
 
 impl Validator for KrakenSubResponse {
@@ -52,7 +83,7 @@ impl Validator for KrakenSubResponse {
     {
         match &self {
             KrakenSubResponse::Subscribed { .. } => Ok(self),
-            KrakenSubResponse::Error(error) => Err(SocketError::Subscribe(format!(
+            KrakenSubResponse::Error { error, .. } => Err(SocketError::Subscribe(format!(
                 "received failure subscription response: {}",
                 error.message
             ))),
@@ -108,9 +139,15 @@ mod tests {
                         }
                     }
                     "#,
-                    expected: Ok(KrakenSubResponse::Error(KrakenError {
-                        message: "Subscription name invalid".to_string(),
-                    })),
+                    expected: Ok(KrakenSubResponse::Error {
+                        error: KrakenError {
+                            message: "Subscription name invalid".to_string(),
+                        },
+                        pair: Some("XBT/USD".to_string()),
+                        subscription: Some(KrakenSubscriptionName {
+                            name: "trades".to_string(),
+                        }),
+                    }),
                 },
             ];
 
@@ -151,9 +188,13 @@ mod tests {
             },
             TestCase {
                 // TC1: input response is failed subscription
-                input_response: KrakenSubResponse::Error(KrakenError {
-                    message: "Subscription name invalid".to_string(),
-                }),
+                input_response: KrakenSubResponse::Error {
+                    error: KrakenError {
+                        message: "Subscription name invalid".to_string(),
+                    },
+                    pair: None,
+                    subscription: None,
+                },
                 is_valid: false,
             },
         ];
@@ -163,4 +204,30 @@ mod tests {
             assert_eq!(actual, test.is_valid, "TestCase {} failed", index);
         }
     }
+
+    #[test]
+    fn test_kraken_sub_response_rejected_subscription_id() {
+        let response = serde_json::from_str::<KrakenSubResponse>(
+            r#"
+            {
+                "errorMessage": "Currency pair not supported XBT/USDX",
+                "event": "subscriptionStatus",
+                "pair": "XBT/USDX",
+                "status": "error",
+                "subscription": {"name": "trade"}
+            }
+            "#,
+        )
+        .unwrap();
+        assert_eq!(
+            response.rejected_subscription_id(),
+            Some(SubscriptionId::from("trade|XBT/USDX"))
+        );
+
+        let response = serde_json::from_str::<KrakenSubResponse>(
+            r#"{"errorMessage": "Malformed request", "event": "subscriptionStatus", "status": "error"}"#,
+        )
+        .unwrap();
+        assert_eq!(response.rejected_subscription_id(), None);
+    }
 }
@@ -3,14 +3,16 @@ use crate::{
     event::{MarketEvent, MarketIter},
     exchange::ExchangeId,
     subscription::trade::PublicTrade,
+    util::time::EpochUnit,
     Identifier,
 };
 use barter_integration::{
-    de::{datetime_utc_from_epoch_duration, extract_next},
+    de::extract_next,
     model::{Exchange, Side, SubscriptionId},
 };
 use chrono::{DateTime, Utc};
 use serde::Serialize;
+use std::collections::HashMap;
 
 /// Terse type alias for an [`Kraken`](super::Kraken) real-time trades WebSocket message.
 pub type KrakenTrades = KrakenMessage<KrakenTradesInner>;
@@ -48,10 +50,13 @@ impl Identifier<Option<SubscriptionId>> for KrakenTradesInner {
 
 /// Generate a custom [`Kraken`](super::Kraken) trade identifier since it is not provided in the
 /// [`KrakenTrade`] model.
+///
+/// Kraken can publish several identical fills (same time, side, price & amount) in one message,
+/// so the caller suffixes repeated identifiers with their occurrence within the message.
 fn custom_kraken_trade_id(trade: &KrakenTrade) -> String {
     format!(
         "{}_{}_{}_{}",
-        trade.time.timestamp_nanos(),
+        trade.time.timestamp_nanos_opt().unwrap_or_default(),
         trade.side,
         trade.price,
         trade.amount
@@ -63,24 +68,36 @@ impl<InstrumentId: Clone> From<(ExchangeId, InstrumentId, KrakenTrades)>
 {
     fn from((exchange_id, instrument, trades): (ExchangeId, InstrumentId, KrakenTrades)) -> Self {
         match trades {
-            KrakenTrades::Data(trades) => trades
-                .trades
-                .into_iter()
-                .map(|trade| {
-                    Ok(MarketEvent {
-                        exchange_time: trade.time,
-                        received_time: Utc::now(),
-                        exchange: Exchange::from(exchange_id),
-                        instrument: instrument.clone(),
-                        kind: PublicTrade {
-                            id: custom_kraken_trade_id(&trade),
-                            price: trade.price,
-                            amount: trade.amount,
-                            side: trade.side,
-                        },
+            KrakenTrades::Data(trades) => {
+                let mut occurrences = HashMap::<String, usize>::new();
+
+                trades
+                    .trades
+                    .into_iter()
+                    .map(|trade| {
+                        let id = custom_kraken_trade_id(&trade);
+                        let occurrence = occurrences.entry(id.clone()).or_default();
+                        let id = match *occurrence {
+                            0 => id,
+                            occurrence => format!("{id}_{occurrence}"),
+                        };
+                        *occurrence += 1;
+
+                        Ok(MarketEvent {
+                            exchange_time: trade.time,
+                            received_time: Utc::now(),
+                            exchange: Exchange::from(exchange_id),
+                            instrument: instrument.clone(),
+                            kind: PublicTrade {
+                                id,
+                                price: trade.price,
+                                amount: trade.amount,
+                                side: trade.side,
+                            },
+                        })
                     })
-                })
-                .collect(),
+                    .collect()
+            }
             KrakenTrades::Event(_) => Self(vec![]),
         }
     }
@@ -175,12 +192,9 @@ impl<'de> serde::de::Deserialize<'de> for KrakenTrade {
                     .parse()
                     .map_err(serde::de::Error::custom)?;
 
-                // Extract String price, parse to f64, map to DateTime<Utc>
-                let time = extract_next::<SeqAccessor, String>(&mut seq, "time")?
-                    .parse()
-                    .map(|time| {
-                        datetime_utc_from_epoch_duration(std::time::Duration::from_secs_f64(time))
-                    })
+                // Extract String epoch seconds w/ fraction & parse exactly to DateTime<Utc>
+                let time = EpochUnit::Seconds
+                    .parse(&extract_next::<SeqAccessor, String>(&mut seq, "time")?)
                     .map_err(serde::de::Error::custom)?;
 
                 // Extract Side
@@ -207,6 +221,7 @@ impl<'de> serde::de::Deserialize<'de> for KrakenTrade {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::subscription::trade::tests::assert_duplicate_frame_dedup_keys;
 
     mod de {
         use super::*;
@@ -256,17 +271,17 @@ mod tests {
                         KrakenTrade {
                             price: 5541.2,
                             amount: 0.15850568,
-                            time: datetime_utc_from_epoch_duration(
-                                std::time::Duration::from_secs_f64(1534614057.321597),
-                            ),
+                            time: datetime_utc_from_epoch_duration(std::time::Duration::new(
+                                1534614057, 321597000,
+                            )),
                             side: Side::Sell,
                         },
                         KrakenTrade {
                             price: 6060.0,
                             amount: 0.02455000,
-                            time: datetime_utc_from_epoch_duration(
-                                std::time::Duration::from_secs_f64(1534614057.324998),
-                            ),
+                            time: datetime_utc_from_epoch_duration(std::time::Duration::new(
+                                1534614057, 324998000,
+                            )),
                             side: Side::Buy,
                         },
                     ],
@@ -290,4 +305,43 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn test_kraken_trades_duplicate_frame_dedup_keys() {
+        // Kraken does not provide a trade id, so identical fills within a message must still
+        // yield distinct keys
+        let frame = r#"
+            [
+                0,
+                [
+                    ["5541.20000", "0.15850568", "1534614057.321597", "s", "l", ""],
+                    ["5541.20000", "0.15850568", "1534614057.321597", "s", "l", ""],
+                    ["6060.00000", "0.02455000", "1534614057.324998", "b", "l", ""]
+                ],
+                "trade",
+                "XBT/USD"
+            ]
+        "#;
+
+        assert_duplicate_frame_dedup_keys::<KrakenTrades>(ExchangeId::Kraken, frame);
+
+        let MarketIter(events) = MarketIter::<u64, PublicTrade>::from((
+            ExchangeId::Kraken,
+            1,
+            serde_json::from_str::<KrakenTrades>(frame).unwrap(),
+        ));
+        let ids = events
+            .into_iter()
+            .map(|event| event.unwrap().kind.id)
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            ids,
+            vec![
+                "1534614057321597000_sell_5541.2_0.15850568",
+                "1534614057321597000_sell_5541.2_0.15850568_1",
+                "1534614057324998000_buy_6060_0.02455",
+            ]
+        );
+    }
 }
@@ -0,0 +1,590 @@
+use super::super::{market::kucoin_market, subscriber::KUCOIN_SUCCESS_CODE};
+use crate::{
+    error::DataError,
+    exchange::ExchangeSub,
+    subscription::book::{pool::LevelPool, Level, OrderBook, OrderBookSide},
+    transformer::book::{snapshot::SnapshotFetcher, InstrumentOrderBook, OrderBookUpdater},
+    Identifier,
+};
+use async_trait::async_trait;
+use barter_integration::{
+    de::de_str,
+    error::SocketError,
+    model::{instrument::Instrument, Side, SubscriptionId},
+    protocol::websocket::WsMessage,
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use tokio::sync::mpsc;
+
+/// [`Kucoin`](super::super::Kucoin) HTTP OrderBook L2 snapshot url.
+///
+/// See docs: <https://www.kucoin.com/docs/rest/spot-trading/market-data/get-part-order-book-aggregated->
+pub const HTTP_BOOK_L2_SNAPSHOT_URL_KUCOIN: &str =
+    "https://api.kucoin.com/api/v1/market/orderbook/level2_100";
+
+/// [`Kucoin`](super::super::Kucoin) OrderBook Level2 snapshot HTTP message.
+///
+/// Used as the starting [`OrderBook`] before OrderBook Level2 delta WebSocket updates are
+/// applied.
+///
+/// ### Payload Examples
+/// See docs: <https://www.kucoin.com/docs/rest/spot-trading/market-data/get-part-order-book-aggregated->
+/// ```json
+/// {
+///     "code": "200000",
+///     "data": {
+///         "time": 1550653727731,
+///         "sequence": "1550653727731",
+///         "bids": [["6500.12", "0.45054140"], ["6500.11", "0.45054140"]],
+///         "asks": [["6500.16", "0.57753524"], ["6500.15", "0.57753524"]]
+///     }
+/// }
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct KucoinOrderBookL2Snapshot {
+    pub code: String,
+    pub data: Option<KucoinOrderBookL2SnapshotData>,
+}
+
+/// [`KucoinOrderBookL2Snapshot`] data containing the OrderBook sequence and [`KucoinLevel`]s.
+///
+/// See [`KucoinOrderBookL2Snapshot`] for full raw payload examples.
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct KucoinOrderBookL2SnapshotData {
+    #[serde(deserialize_with = "de_str")]
+    pub sequence: u64,
+    #[serde(deserialize_with = "crate::util::time::de_u64_epoch_ms_as_datetime_utc")]
+    pub time: DateTime<Utc>,
+    pub bids: Vec<KucoinLevel>,
+    pub asks: Vec<KucoinLevel>,
+}
+
+impl TryFrom<KucoinOrderBookL2Snapshot> for KucoinOrderBookL2SnapshotData {
+    type Error = DataError;
+
+    fn try_from(snapshot: KucoinOrderBookL2Snapshot) -> Result<Self, Self::Error> {
+        match snapshot.data {
+            Some(data) if snapshot.code == KUCOIN_SUCCESS_CODE => Ok(data),
+            _ => Err(DataError::from(SocketError::Unsupported {
+                entity: "kucoin",
+                item: format!("OrderBook snapshot response code: {}", snapshot.code),
+            })),
+        }
+    }
+}
+
+impl From<KucoinOrderBookL2SnapshotData> for OrderBook {
+    fn from(snapshot: KucoinOrderBookL2SnapshotData) -> Self {
+        Self {
+            last_update_time: snapshot.time,
+            bids: OrderBookSide::new(Side::Buy, snapshot.bids),
+            asks: OrderBookSide::new(Side::Sell, snapshot.asks),
+        }
+    }
+}
+
+/// [`Kucoin`](super::super::Kucoin) OrderBook level.
+///
+/// #### Raw Payload Examples
+/// See docs: <https://www.kucoin.com/docs/rest/spot-trading/market-data/get-part-order-book-aggregated->
+/// ```json
+/// ["6500.12", "0.45054140"]
+/// ```
+#[derive(Clone, Copy, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct KucoinLevel {
+    #[serde(deserialize_with = "de_str")]
+    pub price: f64,
+    #[serde(deserialize_with = "de_str")]
+    pub amount: f64,
+}
+
+impl From<KucoinLevel> for Level {
+    fn from(level: KucoinLevel) -> Self {
+        Self {
+            price: level.price,
+            amount: level.amount,
+        }
+    }
+}
+
+/// [`Kucoin`](super::super::Kucoin) OrderBook Level2 deltas WebSocket message, sent via the
+/// `/market/level2` topic.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://www.kucoin.com/docs/websocket/spot-trading/public-channels/level2-market-data>
+/// ```json
+/// {
+///     "type": "message",
+///     "topic": "/market/level2:BTC-USDT",
+///     "subject": "trade.l2update",
+///     "data": {
+///         "changes": {
+///             "asks": [["18906", "0.00331", "14103845"], ["18907.3", "0.58751503", "14103844"]],
+///             "bids": [["18891.9", "0.15688", "14103847"]]
+///         },
+///         "sequenceEnd": 14103847,
+///         "sequenceStart": 14103844,
+///         "symbol": "BTC-USDT",
+///         "time": 1663747970273
+///     }
+/// }
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct KucoinOrderBookL2Delta {
+    #[serde(
+        rename = "topic",
+        deserialize_with = "de_kucoin_topic_as_subscription_id"
+    )]
+    pub subscription_id: SubscriptionId,
+    pub data: KucoinOrderBookL2DeltaInner,
+}
+
+impl Identifier<Option<SubscriptionId>> for KucoinOrderBookL2Delta {
+    fn id(&self) -> Option<SubscriptionId> {
+        Some(self.subscription_id.clone())
+    }
+}
+
+/// [`KucoinOrderBookL2Delta`] data containing the sequence range and changed levels.
+///
+/// See [`KucoinOrderBookL2Delta`] for full raw payload examples.
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct KucoinOrderBookL2DeltaInner {
+    pub sequence_start: u64,
+    pub sequence_end: u64,
+    #[serde(deserialize_with = "crate::util::time::de_u64_epoch_ms_as_datetime_utc")]
+    pub time: DateTime<Utc>,
+    pub changes: KucoinChanges,
+}
+
+/// [`Kucoin`](super::super::Kucoin) OrderBook Level2 changes for each side of the book.
+///
+/// See [`KucoinOrderBookL2Delta`] for full raw payload examples.
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct KucoinChanges {
+    pub bids: Vec<KucoinChange>,
+    pub asks: Vec<KucoinChange>,
+}
+
+/// [`Kucoin`](super::super::Kucoin) OrderBook level change, tagged with the sequence it was
+/// applied at.
+///
+/// #### Raw Payload Examples
+/// See docs: <https://www.kucoin.com/docs/websocket/spot-trading/public-channels/level2-market-data>
+/// ```json
+/// ["18906", "0.00331", "14103845"]
+/// ```
+#[derive(Clone, Copy, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct KucoinChange {
+    #[serde(deserialize_with = "de_str")]
+    pub price: f64,
+    #[serde(deserialize_with = "de_str")]
+    pub amount: f64,
+    #[serde(deserialize_with = "de_str")]
+    pub sequence: u64,
+}
+
+impl From<KucoinChange> for Level {
+    fn from(change: KucoinChange) -> Self {
+        Self {
+            price: change.price,
+            amount: change.amount,
+        }
+    }
+}
+
+/// Deserialize a [`KucoinOrderBookL2Delta`] "topic" (eg/ "/market/level2:BTC-USDT") as the
+/// associated [`SubscriptionId`] (eg/ "/market/level2|BTC-USDT").
+pub fn de_kucoin_topic_as_subscription_id<'de, D>(
+    deserializer: D,
+) -> Result<SubscriptionId, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+{
+    let topic = <&str as Deserialize>::deserialize(deserializer)?;
+
+    topic
+        .split_once(':')
+        .map(|(channel, market)| ExchangeSub::from((channel, market)).id())
+        .ok_or_else(|| {
+            serde::de::Error::invalid_value(
+                serde::de::Unexpected::Str(topic),
+                &"topic w/ format: <channel>:<market>",
+            )
+        })
+}
+
+/// [`Kucoin`](super::super::Kucoin) [`OrderBookUpdater`].
+///
+/// Kucoin: Calibration Procedure For Maintaining A Local OrderBook
+///
+/// 1. After receiving the WebSocket Level 2 data flow, cache the data.
+/// 2. Initiate a HTTP request to get the Level 2 OrderBook snapshot.
+/// 3. Playback the cached Level 2 data flow.
+/// 4. Apply the new Level 2 data flow to the local snapshot to ensure that the sequence of the
+///    new Level 2 update lines up with the sequence of the previous Level 2 data. Discard the
+///    changes with a sequence less than or equal to the snapshot sequence, and update the
+///    level according to the price & size.
+/// 5. If the price is 0, ignore the message, which only advances the sequence.
+/// 6. If the size is 0, remove the price level.
+///
+/// Notes:
+///  - The WebSocket buffers deltas while the snapshot is fetched (steps 1 & 3).
+///  - A gap between the `sequenceStart` of a delta and the previously applied `sequenceEnd`
+///    fails with [`DataError::InvalidSequence`], triggering a resync from step 2.
+///
+/// See docs: <https://www.kucoin.com/docs/websocket/spot-trading/public-channels/level2-market-data>
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct KucoinBookUpdater {
+    pub updates_processed: u64,
+    pub last_sequence: u64,
+}
+
+impl KucoinBookUpdater {
+    /// Construct a new Kucoin [`OrderBookUpdater`] using the provided sequence from a HTTP
+    /// snapshot.
+    pub fn new(last_sequence: u64) -> Self {
+        Self {
+            updates_processed: 0,
+            last_sequence,
+        }
+    }
+
+    /// Determines if the next delta is the first to be applied to the HTTP snapshot.
+    pub fn is_first_update(&self) -> bool {
+        self.updates_processed == 0
+    }
+
+    /// Kucoin: Calibration Procedure: Step 4:
+    /// The first applied delta must span the snapshot sequence + 1, with any earlier changes
+    /// discarded.
+    ///
+    /// See docs: <https://www.kucoin.com/docs/websocket/spot-trading/public-channels/level2-market-data>
+    pub fn validate_first_update(
+        &self,
+        update: &KucoinOrderBookL2DeltaInner,
+    ) -> Result<(), DataError> {
+        let expected_next = self.last_sequence + 1;
+        if update.sequence_start <= expected_next && update.sequence_end >= expected_next {
+            Ok(())
+        } else {
+            Err(DataError::InvalidSequence {
+                prev_last_update_id: self.last_sequence,
+                first_update_id: update.sequence_start,
+            })
+        }
+    }
+
+    /// Kucoin: Calibration Procedure: Step 4:
+    /// Each subsequent delta must line up with the sequence of the previous delta.
+    ///
+    /// See docs: <https://www.kucoin.com/docs/websocket/spot-trading/public-channels/level2-market-data>
+    pub fn validate_next_update(
+        &self,
+        update: &KucoinOrderBookL2DeltaInner,
+    ) -> Result<(), DataError> {
+        if update.sequence_start == self.last_sequence + 1 {
+            Ok(())
+        } else {
+            Err(DataError::InvalidSequence {
+                prev_last_update_id: self.last_sequence,
+                first_update_id: update.sequence_start,
+            })
+        }
+    }
+
+    /// Kucoin: Calibration Procedure: Steps 4 & 5:
+    /// Filter the [`KucoinChange`]s that have not yet been applied, and that are not sequence
+    /// only changes w/ a price of 0.
+    fn pending(&self, changes: Vec<KucoinChange>) -> impl Iterator<Item = KucoinChange> {
+        let last_sequence = self.last_sequence;
+        changes
+            .into_iter()
+            .filter(move |change| change.sequence > last_sequence && change.price != 0.0)
+    }
+}
+
+impl SnapshotFetcher for KucoinBookUpdater {
+    type Snapshot = KucoinOrderBookL2Snapshot;
+
+    fn url(instrument: &Instrument) -> String {
+        format!(
+            "{HTTP_BOOK_L2_SNAPSHOT_URL_KUCOIN}?symbol={}",
+            kucoin_market(instrument).0
+        )
+    }
+}
+
+#[async_trait]
+impl OrderBookUpdater for KucoinBookUpdater {
+    type OrderBook = OrderBook;
+    type Update = KucoinOrderBookL2Delta;
+    const RESYNC_ON_GAP: bool = true;
+    const AUDIT: bool = true;
+
+    async fn init<Exchange, Kind>(
+        _: mpsc::UnboundedSender<WsMessage>,
+        instrument: Instrument,
+    ) -> Result<InstrumentOrderBook<Instrument, Self>, DataError>
+    where
+        Exchange: Send,
+        Kind: Send,
+    {
+        // Fetch initial OrderBook snapshot via HTTP
+        let snapshot = KucoinOrderBookL2SnapshotData::try_from(Self::fetch(&instrument).await?)?;
+
+        Ok(InstrumentOrderBook {
+            instrument,
+            updater: Self::new(snapshot.sequence),
+            book: OrderBook::from(snapshot),
+        })
+    }
+
+    fn update(
+        &mut self,
+        book: &mut Self::OrderBook,
+        update: Self::Update,
+        pool: &mut LevelPool,
+    ) -> Result<Option<Self::OrderBook>, DataError> {
+        // Kucoin: Calibration Procedure For Maintaining A Local OrderBook
+        // See Self's Rust Docs for more information on each numbered step
+        // See docs: <https://www.kucoin.com/docs/websocket/spot-trading/public-channels/level2-market-data>
+        let update = update.data;
+
+        // 4. Discard deltas that only contain changes already included in the snapshot
+        if update.sequence_end <= self.last_sequence {
+            return Ok(None);
+        }
+
+        if self.is_first_update() {
+            self.validate_first_update(&update)?;
+        } else {
+            self.validate_next_update(&update)?;
+        }
+
+        // Update OrderBook metadata & Levels:
+        // 5. If the price is 0, ignore the change.
+        // 6. If the size is 0, remove the price level.
+        let KucoinChanges { bids, asks } = update.changes;
+        book.last_update_time = update.time;
+        book.bids.upsert(self.pending(bids).collect::<Vec<_>>());
+        book.asks.upsert(self.pending(asks).collect::<Vec<_>>());
+
+        // Update OrderBookUpdater metadata
+        self.updates_processed += 1;
+        self.last_sequence = update.sequence_end;
+
+        Ok(Some(book.snapshot_in(pool)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod de {
+        use super::*;
+        use barter_integration::de::datetime_utc_from_epoch_duration;
+        use std::time::Duration;
+
+        #[test]
+        fn test_kucoin_order_book_l2_snapshot() {
+            let input = r#"
+            {
+                "code": "200000",
+                "data": {
+                    "time": 1550653727731,
+                    "sequence": "1550653727731",
+                    "bids": [["6500.12", "0.45054140"]],
+                    "asks": [["6500.16", "0.57753524"]]
+                }
+            }
+            "#;
+
+            assert_eq!(
+                serde_json::from_str::<KucoinOrderBookL2Snapshot>(input).unwrap(),
+                KucoinOrderBookL2Snapshot {
+                    code: "200000".to_string(),
+                    data: Some(KucoinOrderBookL2SnapshotData {
+                        sequence: 1550653727731,
+                        time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                            1550653727731
+                        )),
+                        bids: vec![KucoinLevel {
+                            price: 6500.12,
+                            amount: 0.4505414
+                        }],
+                        asks: vec![KucoinLevel {
+                            price: 6500.16,
+                            amount: 0.57753524
+                        }],
+                    }),
+                }
+            );
+        }
+
+        #[test]
+        fn test_kucoin_order_book_l2_delta() {
+            let input = r#"
+            {
+                "type": "message",
+                "topic": "/market/level2:BTC-USDT",
+                "subject": "trade.l2update",
+                "data": {
+                    "changes": {
+                        "asks": [["18906", "0.00331", "14103845"], ["18907.3", "0.58751503", "14103844"]],
+                        "bids": [["18891.9", "0.15688", "14103847"]]
+                    },
+                    "sequenceEnd": 14103847,
+                    "sequenceStart": 14103844,
+                    "symbol": "BTC-USDT",
+                    "time": 1663747970273
+                }
+            }
+            "#;
+
+            let actual = serde_json::from_str::<KucoinOrderBookL2Delta>(input).unwrap();
+
+            assert_eq!(
+                actual.id(),
+                Some(SubscriptionId::from("/market/level2|BTC-USDT"))
+            );
+            assert_eq!(
+                actual.data,
+                KucoinOrderBookL2DeltaInner {
+                    sequence_start: 14103844,
+                    sequence_end: 14103847,
+                    time: datetime_utc_from_epoch_duration(Duration::from_millis(1663747970273)),
+                    changes: KucoinChanges {
+                        bids: vec![KucoinChange {
+                            price: 18891.9,
+                            amount: 0.15688,
+                            sequence: 14103847,
+                        }],
+                        asks: vec![
+                            KucoinChange {
+                                price: 18906.0,
+                                amount: 0.00331,
+                                sequence: 14103845,
+                            },
+                            KucoinChange {
+                                price: 18907.3,
+                                amount: 0.58751503,
+                                sequence: 14103844,
+                            },
+                        ],
+                    },
+                }
+            );
+        }
+    }
+
+    mod kucoin_book_updater {
+        use super::*;
+
+        fn delta(sequence_start: u64, bids: Vec<KucoinChange>) -> KucoinOrderBookL2Delta {
+            KucoinOrderBookL2Delta {
+                subscription_id: SubscriptionId::from("/market/level2|BTC-USDT"),
+                data: KucoinOrderBookL2DeltaInner {
+                    sequence_start,
+                    sequence_end: bids
+                        .iter()
+                        .map(|change| change.sequence)
+                        .max()
+                        .unwrap_or(sequence_start),
+                    time: Utc::now(),
+                    changes: KucoinChanges { bids, asks: vec![] },
+                },
+            }
+        }
+
+        fn change(price: f64, amount: f64, sequence: u64) -> KucoinChange {
+            KucoinChange {
+                price,
+                amount,
+                sequence,
+            }
+        }
+
+        #[test]
+        fn test_update() {
+            struct TestCase {
+                updater: KucoinBookUpdater,
+                input: KucoinOrderBookL2Delta,
+                expected: Result<Option<Vec<Level>>, DataError>,
+            }
+
+            let processed = |last_sequence| KucoinBookUpdater {
+                updates_processed: 10,
+                last_sequence,
+            };
+
+            let tests = vec![
+                TestCase {
+                    // TC0: discard delta already included in the snapshot
+                    updater: KucoinBookUpdater::new(100),
+                    input: delta(99, vec![change(90.0, 1.0, 100)]),
+                    expected: Ok(None),
+                },
+                TestCase {
+                    // TC1: valid first delta discards changes included in the snapshot
+                    updater: KucoinBookUpdater::new(100),
+                    input: delta(99, vec![change(90.0, 1.0, 99), change(95.0, 2.0, 101)]),
+                    expected: Ok(Some(vec![Level::new(100.0, 1.0), Level::new(95.0, 2.0)])),
+                },
+                TestCase {
+                    // TC2: invalid first delta w/ sequenceStart > sequence + 1
+                    updater: KucoinBookUpdater::new(100),
+                    input: delta(102, vec![change(95.0, 2.0, 102)]),
+                    expected: Err(DataError::InvalidSequence {
+                        prev_last_update_id: 100,
+                        first_update_id: 102,
+                    }),
+                },
+                TestCase {
+                    // TC3: valid next delta removes level w/ size 0 & ignores price 0
+                    updater: processed(100),
+                    input: delta(101, vec![change(100.0, 0.0, 101), change(0.0, 0.0, 102)]),
+                    expected: Ok(Some(vec![])),
+                },
+                TestCase {
+                    // TC4: invalid next delta w/ sequenceStart != prev sequenceEnd + 1
+                    updater: processed(100),
+                    input: delta(103, vec![change(95.0, 2.0, 103)]),
+                    expected: Err(DataError::InvalidSequence {
+                        prev_last_update_id: 100,
+                        first_update_id: 103,
+                    }),
+                },
+            ];
+
+            for (index, mut test) in tests.into_iter().enumerate() {
+                let mut book = OrderBook {
+                    last_update_time: Utc::now(),
+                    bids: OrderBookSide::new(Side::Buy, vec![Level::new(100.0, 1.0)]),
+                    asks: OrderBookSide::new(Side::Sell, Vec::<Level>::new()),
+                };
+
+                let actual = test
+                    .updater
+                    .update(&mut book, test.input, &mut LevelPool::default())
+                    .map(|snapshot| snapshot.map(|snapshot| snapshot.bids.levels().to_vec()));
+
+                match (actual, test.expected) {
+                    (Ok(actual), Ok(expected)) => {
+                        assert_eq!(actual, expected, "TC{} failed", index)
+                    }
+                    (Err(_), Err(_)) => {
+                        // Test passed
+                    }
+                    (actual, expected) => {
+                        // Test failed
+                        panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                    }
+                }
+            }
+        }
+    }
+}
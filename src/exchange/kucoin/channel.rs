@@ -0,0 +1,32 @@
+use super::Kucoin;
+use crate::{
+    subscription::{book::OrderBooksL2, Subscription},
+    Identifier,
+};
+use serde::Serialize;
+
+/// Type that defines how to translate a Barter [`Subscription`] into a
+/// [`Kucoin`] channel to be subscribed to.
+///
+/// See docs: <https://www.kucoin.com/docs/websocket/spot-trading/public-channels/level2-market-data>
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Serialize)]
+pub struct KucoinChannel(pub &'static str);
+
+impl KucoinChannel {
+    /// [`Kucoin`] real-time OrderBook Level2 incremental updates channel.
+    ///
+    /// See docs: <https://www.kucoin.com/docs/websocket/spot-trading/public-channels/level2-market-data>
+    pub const ORDER_BOOK_L2: Self = Self("/market/level2");
+}
+
+impl<Instrument> Identifier<KucoinChannel> for Subscription<Kucoin, Instrument, OrderBooksL2> {
+    fn id(&self) -> KucoinChannel {
+        KucoinChannel::ORDER_BOOK_L2
+    }
+}
+
+impl AsRef<str> for KucoinChannel {
+    fn as_ref(&self) -> &str {
+        self.0
+    }
+}
@@ -0,0 +1,40 @@
+use super::Kucoin;
+use crate::instrument::{KeyedInstrument, MarketInstrumentData};
+use crate::{subscription::Subscription, Identifier};
+use barter_integration::model::instrument::Instrument;
+use serde::{Deserialize, Serialize};
+
+/// Type that defines how to translate a Barter [`Subscription`] into a
+/// [`Kucoin`] market that can be subscribed to.
+///
+/// See docs: <https://www.kucoin.com/docs/websocket/spot-trading/public-channels/level2-market-data>
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct KucoinMarket(pub String);
+
+impl<Kind> Identifier<KucoinMarket> for Subscription<Kucoin, Instrument, Kind> {
+    fn id(&self) -> KucoinMarket {
+        kucoin_market(&self.instrument)
+    }
+}
+
+impl<Kind> Identifier<KucoinMarket> for Subscription<Kucoin, KeyedInstrument, Kind> {
+    fn id(&self) -> KucoinMarket {
+        kucoin_market(&self.instrument.data)
+    }
+}
+
+impl<Kind> Identifier<KucoinMarket> for Subscription<Kucoin, MarketInstrumentData, Kind> {
+    fn id(&self) -> KucoinMarket {
+        KucoinMarket(self.instrument.name_exchange.clone())
+    }
+}
+
+impl AsRef<str> for KucoinMarket {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+pub(super) fn kucoin_market(instrument: &Instrument) -> KucoinMarket {
+    KucoinMarket(format!("{}-{}", instrument.base, instrument.quote).to_uppercase())
+}
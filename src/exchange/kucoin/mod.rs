@@ -0,0 +1,145 @@
+use self::{
+    book::l2::KucoinBookUpdater, channel::KucoinChannel, market::KucoinMarket,
+    subscriber::KucoinSubscriber, subscription::KucoinSubResponse,
+};
+use crate::{
+    exchange::{Connector, ExchangeId, ExchangeSub, Keepalive, PongTimeout, StreamSelector},
+    subscriber::validator::WebSocketSubValidator,
+    subscription::book::OrderBooksL2,
+    transformer::book::MultiBookTransformer,
+    ExchangeWsStream, Identifier,
+};
+use barter_integration::{
+    error::SocketError,
+    model::{instrument::Instrument, SubscriptionId},
+    protocol::websocket::WsMessage,
+};
+use barter_macro::{DeExchange, SerExchange};
+use serde_json::json;
+use std::time::Duration;
+use url::Url;
+
+/// OrderBook types for [`Kucoin`].
+pub mod book;
+
+/// Defines the type that translates a Barter [`Subscription`](crate::subscription::Subscription)
+/// into an exchange [`Connector`] specific channel used for generating [`Connector::requests`].
+pub mod channel;
+
+/// Defines the type that translates a Barter [`Subscription`](crate::subscription::Subscription)
+/// into an exchange [`Connector`] specific market used for generating [`Connector::requests`].
+pub mod market;
+
+/// [`Subscriber`](crate::subscriber::Subscriber) for [`Kucoin`] that requests a public
+/// connection token before connecting to the WebSocket server.
+pub mod subscriber;
+
+/// [`Subscription`](crate::subscription::Subscription) response type and response
+/// [`Validator`](barter_integration::Validator) for [`Kucoin`].
+pub mod subscription;
+
+/// [`Kucoin`] default server base url.
+///
+/// Note that the [`KucoinSubscriber`] connects to the server endpoint returned alongside the
+/// public connection token, using this url only if none is provided.
+///
+/// See docs: <https://www.kucoin.com/docs/websocket/basic-info/apply-connect-token/public-token-no-authentication-required->
+pub const BASE_URL_KUCOIN: &str = "wss://ws-api-spot.kucoin.com/";
+
+/// [`Kucoin`] HTTP public connection token url.
+///
+/// See docs: <https://www.kucoin.com/docs/websocket/basic-info/apply-connect-token/public-token-no-authentication-required->
+pub const HTTP_TOKEN_URL_KUCOIN: &str = "https://api.kucoin.com/api/v1/bullet-public";
+
+/// [`Kucoin`] server [`Keepalive`] ping interval.
+///
+/// See docs: <https://www.kucoin.com/docs/websocket/basic-info/ping>
+pub const PING_INTERVAL_KUCOIN: Duration = Duration::from_secs(18);
+
+/// [`Kucoin`] server [`PongTimeout`] duration, allowing for one missed pong response.
+///
+/// See docs: <https://www.kucoin.com/docs/websocket/basic-info/ping>
+pub const PONG_TIMEOUT_KUCOIN: Duration = Duration::from_secs(40);
+
+/// [`Kucoin`] spot exchange.
+///
+/// See docs: <https://www.kucoin.com/docs/websocket/introduction>
+#[derive(
+    Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default, DeExchange, SerExchange,
+)]
+pub struct Kucoin;
+
+impl Connector for Kucoin {
+    const ID: ExchangeId = ExchangeId::Kucoin;
+    type Channel = KucoinChannel;
+    type Market = KucoinMarket;
+    type Subscriber = KucoinSubscriber;
+    type SubValidator = WebSocketSubValidator;
+    type SubResponse = KucoinSubResponse;
+
+    fn url() -> Result<Url, SocketError> {
+        Url::parse(BASE_URL_KUCOIN).map_err(SocketError::UrlParse)
+    }
+
+    fn keepalive() -> Keepalive {
+        Keepalive::ApplicationJson {
+            interval: PING_INTERVAL_KUCOIN,
+            ping: || {
+                WsMessage::Text(
+                    json!({
+                        "id": chrono::Utc::now().timestamp_millis().to_string(),
+                        "type": "ping",
+                    })
+                    .to_string(),
+                )
+            },
+        }
+    }
+
+    fn pong_timeout() -> Option<PongTimeout> {
+        Some(PongTimeout {
+            timeout: PONG_TIMEOUT_KUCOIN,
+            is_pong: |message| matches!(message, WsMessage::Text(text) if text.contains(r#""type":"pong""#)),
+        })
+    }
+
+    fn requests(exchange_subs: Vec<ExchangeSub<Self::Channel, Self::Market>>) -> Vec<WsMessage> {
+        exchange_subs
+            .into_iter()
+            .map(|exchange_sub| {
+                // Request id is the SubscriptionId, so that rejections can be attributed
+                let SubscriptionId(id) = exchange_sub.id();
+                WsMessage::Text(
+                    json!({
+                        "id": id,
+                        "type": "subscribe",
+                        "topic": kucoin_topic(&exchange_sub.channel, &exchange_sub.market),
+                        "privateChannel": false,
+                        "response": true,
+                    })
+                    .to_string(),
+                )
+            })
+            .collect()
+    }
+
+    fn rejected_subscription(response: &Self::SubResponse) -> Option<SubscriptionId> {
+        match response {
+            KucoinSubResponse::Error { id, .. } => Some(SubscriptionId::from(id.as_str())),
+            KucoinSubResponse::Ack { .. } => None,
+        }
+    }
+}
+
+/// Construct the [`Kucoin`] subscription topic for the provided [`KucoinChannel`] and
+/// [`KucoinMarket`].
+///
+/// eg/ "/market/level2:BTC-USDT"
+pub fn kucoin_topic(channel: &KucoinChannel, market: &KucoinMarket) -> String {
+    format!("{}:{}", channel.as_ref(), market.as_ref())
+}
+
+impl StreamSelector<Instrument, OrderBooksL2> for Kucoin {
+    type Stream =
+        ExchangeWsStream<MultiBookTransformer<Self, Instrument, OrderBooksL2, KucoinBookUpdater>>;
+}
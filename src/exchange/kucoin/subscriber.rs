@@ -0,0 +1,224 @@
+use super::HTTP_TOKEN_URL_KUCOIN;
+use crate::{
+    exchange::{failover, Connector},
+    instrument::InstrumentData,
+    streams::options::StreamOptions,
+    subscriber::{
+        mapper::WebSocketSubMapper, validator::SubscriptionRejection, Subscriber,
+        WebSocketSubscriber,
+    },
+    subscription::{Map, Subscription, SubscriptionKind},
+    Identifier,
+};
+use async_trait::async_trait;
+use barter_integration::{
+    error::SocketError,
+    protocol::websocket::{WebSocket, WsMessage},
+};
+use serde::{Deserialize, Serialize};
+use tracing::debug;
+use url::Url;
+
+/// [`Kucoin`](super::Kucoin) HTTP response code signifying a successful request.
+pub const KUCOIN_SUCCESS_CODE: &str = "200000";
+
+/// [`Subscriber`] for [`Kucoin`](super::Kucoin) [`WebSocket`]s.
+///
+/// Kucoin requires a public connection token before connecting, so this requests one via HTTP
+/// and then connects to the returned server endpoint using the [`WebSocketSubscriber`].
+///
+/// See docs: <https://www.kucoin.com/docs/websocket/basic-info/apply-connect-token/public-token-no-authentication-required->
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct KucoinSubscriber;
+
+#[async_trait]
+impl Subscriber for KucoinSubscriber {
+    type SubMapper = WebSocketSubMapper;
+
+    async fn subscribe<Exchange, Instrument, Kind>(
+        subscriptions: &[Subscription<Exchange, Instrument, Kind>],
+        options: &StreamOptions,
+    ) -> Result<
+        (
+            WebSocket,
+            Map<Instrument::Id>,
+            Vec<WsMessage>,
+            Vec<SubscriptionRejection>,
+        ),
+        SocketError,
+    >
+    where
+        Exchange: Connector + Send + Sync,
+        Kind: SubscriptionKind + Send + Sync,
+        Instrument: InstrumentData,
+        Subscription<Exchange, Instrument, Kind>:
+            Identifier<Exchange::Channel> + Identifier<Exchange::Market>,
+    {
+        let token = KucoinToken::fetch().await?;
+        debug!(exchange = %Exchange::ID, "fetched public WebSocket connection token");
+
+        let url = token.url(failover::url::<Exchange>(options.endpoint)?)?;
+
+        WebSocketSubscriber::subscribe_to(url, subscriptions, options).await
+    }
+}
+
+/// [`Kucoin`](super::Kucoin) public connection token HTTP response.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://www.kucoin.com/docs/websocket/basic-info/apply-connect-token/public-token-no-authentication-required->
+/// ```json
+/// {
+///   "code": "200000",
+///   "data": {
+///     "token": "2neAiuYvAU61ZDXANAGAsiL4-iAExhsBXZxftpOeh_55i3Ysy2q2LEsEWU64mdzUOPusi34M_wGoSf7iNyEWJ4aBZXpWhrmY9jKtqkdWoFa75w3istPvPtiYB9J6i9GjsxUuhPw3BlrzazF6ghq4L_1QVHhMvQ8BdOyMmWDWE-A=.Ts_4Xz8iMRp9ZAbqPuhnbA==",
+///     "instanceServers": [
+///       {
+///         "endpoint": "wss://ws-api-spot.kucoin.com/",
+///         "encrypt": true,
+///         "protocol": "websocket",
+///         "pingInterval": 18000,
+///         "pingTimeout": 10000
+///       }
+///     ]
+///   }
+/// }
+/// ```
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct KucoinToken {
+    pub code: String,
+    pub data: Option<KucoinTokenData>,
+}
+
+/// [`KucoinToken`] data containing the connection token and available server endpoints.
+///
+/// See [`KucoinToken`] for full raw payload examples.
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct KucoinTokenData {
+    pub token: String,
+    #[serde(rename = "instanceServers")]
+    pub instance_servers: Vec<KucoinInstanceServer>,
+}
+
+/// [`Kucoin`](super::Kucoin) WebSocket server endpoint that accepts a [`KucoinToken`].
+///
+/// See [`KucoinToken`] for full raw payload examples.
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct KucoinInstanceServer {
+    pub endpoint: String,
+}
+
+impl KucoinToken {
+    /// Request a new public [`KucoinToken`] via HTTP.
+    pub async fn fetch() -> Result<Self, SocketError> {
+        reqwest::Client::new()
+            .post(HTTP_TOKEN_URL_KUCOIN)
+            .send()
+            .await?
+            .error_for_status()?
+            .json::<Self>()
+            .await
+            .map_err(SocketError::Http)
+    }
+
+    /// Construct the WebSocket [`Url`] authenticated with this [`KucoinToken`].
+    ///
+    /// Connects to the first server endpoint provided with the token, or the `fallback` [`Url`]
+    /// if there are none.
+    pub fn url(self, fallback: Url) -> Result<Url, SocketError> {
+        let data = match self.data {
+            Some(data) if self.code == KUCOIN_SUCCESS_CODE => data,
+            _ => {
+                return Err(SocketError::Subscribe(format!(
+                    "failed to fetch public WebSocket connection token, code: {}",
+                    self.code
+                )))
+            }
+        };
+
+        let mut url = match data.instance_servers.first() {
+            Some(server) => Url::parse(&server.endpoint).map_err(SocketError::UrlParse)?,
+            None => fallback,
+        };
+
+        url.query_pairs_mut()
+            .append_pair("token", &data.token)
+            .append_pair(
+                "connectId",
+                &chrono::Utc::now()
+                    .timestamp_nanos_opt()
+                    .unwrap_or_default()
+                    .to_string(),
+            );
+
+        Ok(url)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_kucoin_token_url() {
+        struct TestCase {
+            input: &'static str,
+            expected: Result<&'static str, SocketError>,
+        }
+
+        let fallback = Url::parse("wss://fallback.kucoin.com/").unwrap();
+
+        let tests = vec![
+            TestCase {
+                // TC0: token w/ server endpoint
+                input: r#"
+                {
+                    "code": "200000",
+                    "data": {
+                        "token": "abc",
+                        "instanceServers": [
+                            {
+                                "endpoint": "wss://ws-api-spot.kucoin.com/",
+                                "encrypt": true,
+                                "protocol": "websocket",
+                                "pingInterval": 18000,
+                                "pingTimeout": 10000
+                            }
+                        ]
+                    }
+                }
+                "#,
+                expected: Ok("wss://ws-api-spot.kucoin.com/?token=abc&connectId="),
+            },
+            TestCase {
+                // TC1: token w/o server endpoint uses fallback
+                input: r#"{"code": "200000", "data": {"token": "abc", "instanceServers": []}}"#,
+                expected: Ok("wss://fallback.kucoin.com/?token=abc&connectId="),
+            },
+            TestCase {
+                // TC2: failure response code
+                input: r#"{"code": "429000", "msg": "Too Many Requests"}"#,
+                expected: Err(SocketError::Subscribe(String::new())),
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = serde_json::from_str::<KucoinToken>(test.input)
+                .unwrap()
+                .url(fallback.clone());
+
+            match (actual, test.expected) {
+                (Ok(actual), Ok(expected)) => {
+                    assert!(actual.as_str().starts_with(expected), "TC{} failed", index)
+                }
+                (Err(_), Err(_)) => {
+                    // Test passed
+                }
+                (actual, expected) => {
+                    // Test failed
+                    panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,155 @@
+use barter_integration::{error::SocketError, Validator};
+use serde::{Deserialize, Serialize};
+
+/// [`Kucoin`](super::Kucoin) WebSocket subscription response.
+///
+/// Note that the `welcome` message sent by the server upon connecting is not a
+/// [`KucoinSubResponse`], and so is skipped by the
+/// [`WebSocketSubValidator`](crate::subscriber::validator::WebSocketSubValidator).
+///
+/// ### Raw Payload Examples
+/// #### Subscription OrderBook Level2 Ok Response
+/// ```json
+/// {
+///   "id": "/market/level2|BTC-USDT",
+///   "type": "ack"
+/// }
+/// ```
+///
+/// #### Subscription OrderBook Level2 Error Response
+/// ```json
+/// {
+///   "id": "/market/level2|BTC-USDTT",
+///   "type": "error",
+///   "code": 404,
+///   "data": "topic /market/level2:BTC-USDTT is not found"
+/// }
+/// ```
+///
+/// See docs: <https://www.kucoin.com/docs/websocket/basic-info/subscribe/introduction>
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+#[serde(tag = "type", rename_all = "lowercase")]
+pub enum KucoinSubResponse {
+    Ack {
+        id: String,
+    },
+    Error {
+        id: String,
+        code: i64,
+        #[serde(rename = "data")]
+        message: String,
+    },
+}
+
+impl Validator for KucoinSubResponse {
+    fn validate(self) -> Result<Self, SocketError>
+    where
+        Self: Sized,
+    {
+        match self {
+            Self::Ack { .. } => Ok(self),
+            Self::Error { code, message, .. } => Err(SocketError::Subscribe(format!(
+                "received failure subscription response code: {code} with message: {message}",
+            ))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod de {
+        use super::*;
+
+        #[test]
+        fn test_kucoin_subscription_response() {
+            struct TestCase {
+                input: &'static str,
+                expected: Result<KucoinSubResponse, SocketError>,
+            }
+
+            let cases = vec![
+                TestCase {
+                    // TC0: input response is subscription success
+                    input: r#"{"id": "/market/level2|BTC-USDT", "type": "ack"}"#,
+                    expected: Ok(KucoinSubResponse::Ack {
+                        id: "/market/level2|BTC-USDT".to_string(),
+                    }),
+                },
+                TestCase {
+                    // TC1: input response is failed subscription
+                    input: r#"
+                    {
+                        "id": "/market/level2|BTC-USDTT",
+                        "type": "error",
+                        "code": 404,
+                        "data": "topic /market/level2:BTC-USDTT is not found"
+                    }
+                    "#,
+                    expected: Ok(KucoinSubResponse::Error {
+                        id: "/market/level2|BTC-USDTT".to_string(),
+                        code: 404,
+                        message: "topic /market/level2:BTC-USDTT is not found".to_string(),
+                    }),
+                },
+                TestCase {
+                    // TC2: input welcome message is not a subscription response
+                    input: r#"{"id": "hQvf8jkno", "type": "welcome"}"#,
+                    expected: Err(SocketError::Unsupported {
+                        entity: "",
+                        item: "".to_string(),
+                    }),
+                },
+            ];
+
+            for (index, test) in cases.into_iter().enumerate() {
+                let actual = serde_json::from_str::<KucoinSubResponse>(test.input);
+                match (actual, test.expected) {
+                    (Ok(actual), Ok(expected)) => {
+                        assert_eq!(actual, expected, "TC{} failed", index)
+                    }
+                    (Err(_), Err(_)) => {
+                        // Test passed
+                    }
+                    (actual, expected) => {
+                        // Test failed
+                        panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_validate_kucoin_sub_response() {
+        struct TestCase {
+            input_response: KucoinSubResponse,
+            is_valid: bool,
+        }
+
+        let cases = vec![
+            TestCase {
+                // TC0: input response is subscription success
+                input_response: KucoinSubResponse::Ack {
+                    id: "/market/level2|BTC-USDT".to_string(),
+                },
+                is_valid: true,
+            },
+            TestCase {
+                // TC1: input response is failed subscription
+                input_response: KucoinSubResponse::Error {
+                    id: "/market/level2|BTC-USDTT".to_string(),
+                    code: 404,
+                    message: "topic /market/level2:BTC-USDTT is not found".to_string(),
+                },
+                is_valid: false,
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = test.input_response.validate().is_ok();
+            assert_eq!(actual, test.is_valid, "TC{} failed", index);
+        }
+    }
+}
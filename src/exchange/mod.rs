@@ -2,12 +2,19 @@ use self::subscription::ExchangeSub;
 use crate::instrument::InstrumentData;
 use crate::subscription::SubKind;
 use crate::{
-    subscriber::{validator::SubscriptionValidator, Subscriber},
+    subscriber::{pacing::RequestRate, validator::SubscriptionValidator, Subscriber},
     subscription::{Map, SubscriptionKind},
     MarketStream,
 };
 use barter_integration::{
-    error::SocketError, model::instrument::kind::InstrumentKind, protocol::websocket::WsMessage,
+    error::SocketError,
+    model::{
+        instrument::kind::{
+            FutureContract, InstrumentKind, OptionContract, OptionExercise, OptionKind,
+        },
+        SubscriptionId,
+    },
+    protocol::websocket::WsMessage,
     Validator,
 };
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
@@ -32,6 +39,13 @@ pub mod bybit;
 /// `Coinbase` [`Connector`] and [`StreamSelector`] implementations.
 pub mod coinbase;
 
+/// `Deribit` [`Connector`] and [`StreamSelector`] implementations.
+pub mod deribit;
+
+/// Rotation of the active [`Connector::urls`] endpoint, used to fail over to backup exchange
+/// servers on repeated connection failures.
+pub mod failover;
+
 /// `GateioSpot`, `GateioFuturesUsd` & `GateioFuturesBtc` [`Connector`] and [`StreamSelector`]
 /// implementations.
 pub mod gateio;
@@ -39,6 +53,9 @@ pub mod gateio;
 /// `Kraken` [`Connector`] and [`StreamSelector`] implementations.
 pub mod kraken;
 
+/// `Kucoin` [`Connector`] and [`StreamSelector`] implementations.
+pub mod kucoin;
+
 /// `Okx` [`Connector`] and [`StreamSelector`] implementations.
 pub mod okx;
 
@@ -114,12 +131,46 @@ where
     /// Base [`Url`] of the exchange server being connected with.
     fn url() -> Result<Url, SocketError>;
 
-    /// Defines [`PingInterval`] of custom application-level
-    /// [`WebSocket`](barter_integration::protocol::websocket::WebSocket) pings for the exchange
-    /// server being connected with.
+    /// Every [`Url`] of the exchange servers that can be connected with, starting with the
+    /// primary [`Self::url`] followed by any backups (eg/ alternative regional endpoints).
+    ///
+    /// The [`consumer`](crate::streams::consumer) loops rotate through these on connection
+    /// failures via the [`failover`] module. Defaults to only the primary [`Self::url`].
+    fn urls() -> Result<Vec<Url>, SocketError> {
+        Self::url().map(|url| vec![url])
+    }
+
+    /// Defines the [`Keepalive`] scheme used to keep the
+    /// [`WebSocket`](barter_integration::protocol::websocket::WebSocket) connection with the
+    /// exchange server alive.
+    ///
+    /// Defaults to [`Keepalive::None`], meaning that no client pings are sent.
+    fn keepalive() -> Keepalive {
+        Keepalive::None
+    }
+
+    /// Defines the [`PongTimeout`] deadline within which the exchange server must respond to
+    /// [`Keepalive`] pings. If the deadline is missed the
+    /// [`MarketStream`] is ended so that it can be re-initialised, rather than waiting for the
+    /// exchange server to drop the socket while data silently stalls.
+    ///
+    /// May be overridden per [`StreamBuilder`](crate::streams::builder::StreamBuilder) via
+    /// [`with_pong_timeout`](crate::streams::builder::StreamBuilder::with_pong_timeout).
+    ///
+    /// Defaults to `None`, meaning that pong responses are not tracked.
+    fn pong_timeout() -> Option<PongTimeout> {
+        None
+    }
+
+    /// Defines the maximum [`Duration`] allowed without receiving any message (including pings)
+    /// from the exchange server. If exceeded, the [`MarketStream`] is ended so that it can be
+    /// re-initialised, catching half-open connections that would otherwise hang forever.
+    ///
+    /// May be overridden per [`StreamBuilder`](crate::streams::builder::StreamBuilder) via
+    /// [`with_idle_timeout`](crate::streams::builder::StreamBuilder::with_idle_timeout).
     ///
-    /// Defaults to `None`, meaning that no custom pings are sent.
-    fn ping_interval() -> Option<PingInterval> {
+    /// Defaults to `None`, meaning that the no-data watchdog is disabled.
+    fn idle_timeout() -> Option<Duration> {
         None
     }
 
@@ -139,6 +190,38 @@ where
     fn subscription_timeout() -> Duration {
         DEFAULT_SUBSCRIPTION_TIMEOUT
     }
+
+    /// Determine the [`SubscriptionId`] of the [`Subscription`](subscription::Subscription)
+    /// that the provided [`Self::SubResponse`] rejected, if it can be attributed to one.
+    ///
+    /// Used to continue with the accepted [`Subscription`](subscription::Subscription)s under
+    /// the [`SubscriptionFailurePolicy::ContinueWithAccepted`](crate::subscriber::validator::SubscriptionFailurePolicy)
+    /// policy. Defaults to `None`, meaning that any rejection fails the whole connection.
+    fn rejected_subscription(_response: &Self::SubResponse) -> Option<SubscriptionId> {
+        None
+    }
+
+    /// Maximum number of [`Subscription`](subscription::Subscription)s the exchange server
+    /// allows over a single [`WebSocket`](barter_integration::protocol::websocket::WebSocket)
+    /// connection. Larger collections of [`Subscription`](subscription::Subscription)s are
+    /// sharded across multiple connections by the
+    /// [`StreamBuilder`](crate::streams::builder::StreamBuilder).
+    ///
+    /// Defaults to `None`, meaning that there is no limit.
+    fn subscription_limit() -> Option<usize> {
+        None
+    }
+
+    /// Maximum [`RequestRate`] at which subscription [`WsMessage`]s may be sent to the exchange
+    /// server without the connection being dropped. Used by the [`Subscriber`] to pace outgoing
+    /// requests, and may be overridden per
+    /// [`StreamBuilder`](crate::streams::builder::StreamBuilder) via
+    /// [`with_request_rate`](crate::streams::builder::StreamBuilder::with_request_rate).
+    ///
+    /// Defaults to `None`, meaning that requests are not paced.
+    fn request_rate() -> Option<RequestRate> {
+        None
+    }
 }
 
 /// Used when an exchange has servers different
@@ -151,15 +234,66 @@ where
 pub trait ExchangeServer: Default + Debug + Clone + Send {
     const ID: ExchangeId;
     fn websocket_url() -> &'static str;
+
+    /// Maximum number of subscriptions allowed over a single connection to this server, if any.
+    ///
+    /// See [`Connector::subscription_limit`].
+    fn subscription_limit() -> Option<usize> {
+        None
+    }
+
+    /// Maximum rate at which subscription requests may be sent to this server, if any.
+    ///
+    /// See [`Connector::request_rate`].
+    fn request_rate() -> Option<RequestRate> {
+        None
+    }
 }
 
-/// Defines the frequency and construction function for custom
-/// [`WebSocket`](barter_integration::protocol::websocket::WebSocket) pings - used for exchanges
-/// that require additional application-level pings.
-#[derive(Debug)]
-pub struct PingInterval {
-    pub interval: tokio::time::Interval,
-    pub ping: fn() -> WsMessage,
+/// Keepalive scheme an exchange server requires to keep a
+/// [`WebSocket`](barter_integration::protocol::websocket::WebSocket) connection alive.
+///
+/// Consumed by the shared connection management that drives every
+/// [`MarketStream`], so [`Connector`]s only declare which scheme they require.
+#[derive(Copy, Clone, Debug)]
+pub enum Keepalive {
+    /// WebSocket protocol-level ping frames are sent at the provided interval.
+    Protocol { interval: Duration },
+
+    /// Custom application-level pings (eg/ `{"op": "ping"}`) constructed by the provided function
+    /// are sent at the provided interval.
+    ApplicationJson {
+        interval: Duration,
+        ping: fn() -> WsMessage,
+    },
+
+    /// No client pings are sent.
+    ///
+    /// Note that protocol-level pings sent by the exchange server are still responded to by
+    /// `tokio_tungstenite`.
+    None,
+}
+
+impl Keepalive {
+    /// Construct the ping [`WsMessage`] and interval [`Duration`] of this [`Keepalive`] scheme,
+    /// returning `None` if no client pings are required.
+    pub fn ping(&self) -> Option<(Duration, WsMessage)> {
+        match self {
+            Self::Protocol { interval } => Some((*interval, WsMessage::Ping(Vec::new()))),
+            Self::ApplicationJson { interval, ping } => Some((*interval, ping())),
+            Self::None => None,
+        }
+    }
+}
+
+/// Defines the deadline within which an exchange server must respond to [`Keepalive`] pings,
+/// and how to identify a pong response [`WsMessage`].
+#[derive(Copy, Clone, Debug)]
+pub struct PongTimeout {
+    /// Maximum [`Duration`] allowed between pong responses (or between connecting and the first
+    /// pong response) before the connection is considered stale.
+    pub timeout: Duration,
+    pub is_pong: fn(&WsMessage) -> bool,
 }
 
 /// Unique identifier an exchange server [`Connector`].
@@ -178,6 +312,7 @@ pub enum ExchangeId {
     BybitSpot,
     BybitPerpetualsUsd,
     Coinbase,
+    Deribit,
     GateioSpot,
     GateioFuturesUsd,
     GateioFuturesBtc,
@@ -185,6 +320,8 @@ pub enum ExchangeId {
     GateioPerpetualsUsd,
     GateioOptions,
     Kraken,
+    KrakenFutures,
+    Kucoin,
     Okx,
 }
 
@@ -211,6 +348,7 @@ impl ExchangeId {
             ExchangeId::BybitSpot => "bybit_spot",
             ExchangeId::BybitPerpetualsUsd => "bybit_perpetuals_usd",
             ExchangeId::Coinbase => "coinbase",
+            ExchangeId::Deribit => "deribit",
             ExchangeId::GateioSpot => "gateio_spot",
             ExchangeId::GateioFuturesUsd => "gateio_futures_usd",
             ExchangeId::GateioFuturesBtc => "gateio_futures_btc",
@@ -218,6 +356,8 @@ impl ExchangeId {
             ExchangeId::GateioPerpetualsBtc => "gateio_perpetuals_btc",
             ExchangeId::GateioOptions => "gateio_options",
             ExchangeId::Kraken => "kraken",
+            ExchangeId::KrakenFutures => "kraken_futures",
+            ExchangeId::Kucoin => "kucoin",
             ExchangeId::Okx => "okx",
         }
     }
@@ -228,26 +368,77 @@ impl ExchangeId {
         use InstrumentKind::*;
 
         match (self, instrument_kind, sub_kind) {
-            (BinanceSpot, Spot, PublicTrades | OrderBooksL1) => true,
-            (BinanceFuturesUsd, Perpetual, PublicTrades | OrderBooksL1 | Liquidations) => true,
-            (Bitfinex, Spot, PublicTrades) => true,
-            (Bitmex, Perpetual, PublicTrades) => true,
-            (BybitSpot, Spot, PublicTrades) => true,
-            (BybitPerpetualsUsd, Perpetual, PublicTrades) => true,
-            (Coinbase, Spot, PublicTrades) => true,
-            (GateioSpot, Spot, PublicTrades) => true,
-            (GateioFuturesUsd, Future(_), PublicTrades) => true,
+            (
+                BinanceSpot,
+                Spot,
+                PublicTrades | AggTrades | OrderBooksL1 | OrderBooksL2 | OrderBookDeltas,
+            ) => true,
+            (
+                BinanceFuturesUsd,
+                Perpetual,
+                PublicTrades | AggTrades | OrderBooksL1 | OrderBooksL2 | OrderBookDeltas
+                | Liquidations | PremiumIndexes | OpenInterests | MarketStats,
+            ) => true,
+            (
+                Bitfinex,
+                Spot,
+                PublicTrades | OrderBooksL1 | OrderBooksL2 | OrderBooksL3 | ExchangeStatus,
+            ) => true,
+            (Bitmex, Perpetual, PublicTrades | OrderBooksL1 | OrderBooksL2) => true,
+            (BybitSpot, Spot, PublicTrades | OrderBooksL1 | OrderBooksL2) => true,
+            (
+                BybitPerpetualsUsd,
+                Perpetual,
+                PublicTrades | OrderBooksL1 | OrderBooksL2 | Liquidations | FundingRates,
+            ) => true,
+            (Coinbase, Spot, PublicTrades | OrderBooksL1 | OrderBooksL2 | OrderBooksL3) => true,
+            (Deribit, Future(_) | Perpetual, PublicTrades | OrderBooksL1 | OrderBooksL2) => true,
+            (Deribit, Option(_), PublicTrades | OrderBooksL1 | OrderBooksL2 | OptionSummaries) => {
+                true
+            }
+            (GateioSpot, Spot, PublicTrades | OrderBooksL1 | OrderBooksL2 | Candles) => true,
+            (GateioFuturesUsd, Future(_), PublicTrades | OrderBooksL1 | Candles) => true,
             (GateioFuturesBtc, Future(_), PublicTrades) => true,
-            (GateioPerpetualsUsd, Perpetual, PublicTrades) => true,
-            (GateioPerpetualsBtc, Perpetual, PublicTrades) => true,
+            (GateioPerpetualsUsd, Perpetual, PublicTrades | OrderBooksL2 | FundingRates) => true,
+            (GateioPerpetualsBtc, Perpetual, PublicTrades | OrderBooksL2 | FundingRates) => true,
             (GateioOptions, Option(_), PublicTrades) => true,
-            (Kraken, Spot, PublicTrades | OrderBooksL1) => true,
-            (Okx, Spot | Future(_) | Perpetual | Option(_), PublicTrades) => true,
+            (Kraken, Spot, PublicTrades | OrderBooksL1 | OrderBooksL2 | ExchangeStatus) => true,
+            (KrakenFutures, Perpetual, PremiumIndexes | FundingRates) => true,
+            (Kucoin, Spot, OrderBooksL2) => true,
+            (Okx, Spot | Future(_) | Perpetual | Option(_), PublicTrades | BlockTrades) => true,
+            (Okx, Spot | Future(_) | Perpetual | Option(_), MarkPriceCandles) => true,
+            (Okx, Spot | Future(_) | Perpetual | Option(_), OrderBooksL1 | OrderBooksL2) => true,
+            (Okx, Spot | Future(_) | Perpetual | Option(_), ExchangeStatus) => true,
 
             (_, _, _) => false,
         }
     }
 
+    /// Names of every [`InstrumentKind`] supported by the [`Connector`] associated with this
+    /// [`ExchangeId`] (eg/ ["spot", "perpetual"]). Used to suggest alternatives when a
+    /// [`Subscription`](subscription::Subscription) [`InstrumentKind`] is unsupported.
+    pub fn supported_instrument_kinds(&self) -> Vec<&'static str> {
+        let expiry = chrono::DateTime::<chrono::Utc>::MIN_UTC;
+        [
+            ("spot", InstrumentKind::Spot),
+            ("future", InstrumentKind::Future(FutureContract { expiry })),
+            ("perpetual", InstrumentKind::Perpetual),
+            (
+                "option",
+                InstrumentKind::Option(OptionContract {
+                    kind: OptionKind::Call,
+                    exercise: OptionExercise::European,
+                    expiry,
+                    strike: Default::default(),
+                }),
+            ),
+        ]
+        .into_iter()
+        .filter(|(_, kind)| self.supports_instrument_kind(*kind))
+        .map(|(name, _)| name)
+        .collect()
+    }
+
     /// Determines whether the [`Connector`] associated with this [`ExchangeId`] supports the
     /// ingestion of market data for the provided [`InstrumentKind`].
     #[allow(clippy::match_like_matches_macro)]
@@ -259,25 +450,25 @@ impl ExchangeId {
             // Spot
             (
                 BinanceFuturesUsd | Bitmex | BybitPerpetualsUsd | GateioPerpetualsUsd
-                | GateioPerpetualsBtc,
+                | GateioPerpetualsBtc | KrakenFutures,
                 Spot,
             ) => false,
             (_, Spot) => true,
 
             // Future
-            (GateioFuturesUsd | GateioFuturesBtc | Okx, Future(_)) => true,
+            (Deribit | GateioFuturesUsd | GateioFuturesBtc | Okx, Future(_)) => true,
             (_, Future(_)) => false,
 
             // Future Perpetual Swaps
             (
-                BinanceFuturesUsd | Bitmex | Okx | BybitPerpetualsUsd | GateioPerpetualsUsd
-                | GateioPerpetualsBtc,
+                BinanceFuturesUsd | Bitmex | Deribit | Okx | BybitPerpetualsUsd
+                | GateioPerpetualsUsd | GateioPerpetualsBtc | KrakenFutures,
                 Perpetual,
             ) => true,
             (_, Perpetual) => false,
 
             // Option
-            (GateioOptions | Okx, Option(_)) => true,
+            (Deribit | GateioOptions | Okx, Option(_)) => true,
             (_, Option(_)) => false,
         }
     }
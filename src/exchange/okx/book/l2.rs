@@ -0,0 +1,573 @@
+use super::super::{
+    channel::OkxChannel,
+    market::{okx_market, OkxMarket},
+};
+use crate::{
+    error::DataError,
+    subscription::book::{pool::LevelPool, Level, OrderBook, OrderBookSide},
+    transformer::book::{
+        checksum::{BookChecksum, OkxChecksum},
+        InstrumentOrderBook, OrderBookUpdater,
+    },
+    Identifier,
+};
+use async_trait::async_trait;
+use barter_integration::{
+    de::extract_next,
+    error::SocketError,
+    model::{instrument::Instrument, Side, SubscriptionId},
+    protocol::websocket::WsMessage,
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use tokio::sync::mpsc;
+
+/// Number of [`Level`]s on each side of the [`Okx`](super::super::Okx) OrderBook used to
+/// calculate the CRC32 checksum.
+///
+/// See docs: <https://www.okx.com/docs-v5/en/#order-book-trading-market-data-ws-order-book-channel>
+pub const BOOK_L2_CHECKSUM_DEPTH_OKX: usize = 25;
+
+/// [`Okx`](super::super::Okx) real-time OrderBook Level2 WebSocket message.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://www.okx.com/docs-v5/en/#order-book-trading-market-data-ws-order-book-channel>
+/// ```json
+/// {
+///   "arg": {
+///     "channel": "books",
+///     "instId": "BTC-USDT"
+///   },
+///   "action": "snapshot",
+///   "data": [
+///     {
+///       "asks": [
+///         ["3366.8", "9", "10", "3"],
+///         ["3368", "8", "3", "4"]
+///       ],
+///       "bids": [
+///         ["3366.1", "7", "0", "3"],
+///         ["3366", "6", "3", "4"]
+///       ],
+///       "ts": "1597026383085",
+///       "checksum": -1881014294,
+///       "prevSeqId": -1,
+///       "seqId": 123456
+///     }
+///   ]
+/// }
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct OkxOrderBookL2 {
+    #[serde(
+        rename = "arg",
+        deserialize_with = "super::super::trade::de_okx_message_arg_as_subscription_id"
+    )]
+    pub subscription_id: SubscriptionId,
+    pub action: OkxBookAction,
+    pub data: Vec<OkxOrderBookL2Data>,
+}
+
+impl Identifier<Option<SubscriptionId>> for OkxOrderBookL2 {
+    fn id(&self) -> Option<SubscriptionId> {
+        Some(self.subscription_id.clone())
+    }
+}
+
+/// [`Okx`](super::super::Okx) OrderBook Level2 message action.
+///
+/// See [`OkxOrderBookL2`] for full raw payload examples.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum OkxBookAction {
+    Snapshot,
+    Update,
+}
+
+/// [`Okx`](super::super::Okx) OrderBook Level2 snapshot or update data.
+///
+/// See [`OkxOrderBookL2`] for full raw payload examples.
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct OkxOrderBookL2Data {
+    pub asks: Vec<OkxLevel>,
+    pub bids: Vec<OkxLevel>,
+    #[serde(
+        rename = "ts",
+        deserialize_with = "crate::util::time::de_str_epoch_ms_as_datetime_utc"
+    )]
+    pub time: DateTime<Utc>,
+    pub checksum: i32,
+}
+
+/// [`Okx`](super::super::Okx) OrderBook Level2 level.
+///
+/// The original price & amount `String`s are retained since they are required to re-construct
+/// the checksum input exactly (eg/ trailing zeros).
+///
+/// ### Raw Payload Examples
+/// See docs: <https://www.okx.com/docs-v5/en/#order-book-trading-market-data-ws-order-book-channel>
+/// ```json
+/// ["3366.8", "9", "10", "3"]
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Serialize)]
+pub struct OkxLevel {
+    pub price: f64,
+    pub amount: f64,
+    pub raw_price: String,
+    pub raw_amount: String,
+}
+
+impl From<OkxLevel> for Level {
+    fn from(level: OkxLevel) -> Self {
+        Self {
+            price: level.price,
+            amount: level.amount,
+        }
+    }
+}
+
+/// [`Okx`](super::super::Okx) [`OrderBookUpdater`] that maintains a local OrderBook Level2
+/// from the WebSocket snapshot & updates.
+///
+/// Okx: Maintaining The Local OrderBook
+/// 1. Subscribe to the "books" channel.
+/// 2. The first message is a snapshot of the OrderBook.
+/// 3. Each update contains the absolute amount for a price level, where an amount of 0 removes
+///    the price level.
+/// 4. After applying a snapshot or update, the CRC32 checksum of the top 25 bids & asks must
+///    equal the (signed) checksum of the message, otherwise the OrderBook must be re-subscribed
+///    to receive a new snapshot.
+///
+/// See docs: <https://www.okx.com/docs-v5/en/#order-book-trading-market-data-ws-order-book-channel>
+#[derive(Clone, Debug)]
+pub struct OkxBookUpdater {
+    pub market: OkxMarket,
+    pub awaiting_snapshot: bool,
+    pub checksum: OkxChecksum,
+    ws_sink_tx: mpsc::UnboundedSender<WsMessage>,
+}
+
+impl OkxBookUpdater {
+    /// Construct a new [`Okx`](super::super::Okx) [`OrderBookUpdater`] that waits for the
+    /// initial WebSocket snapshot.
+    pub fn new(market: OkxMarket, ws_sink_tx: mpsc::UnboundedSender<WsMessage>) -> Self {
+        Self {
+            market,
+            awaiting_snapshot: true,
+            checksum: OkxChecksum::default(),
+            ws_sink_tx,
+        }
+    }
+
+    /// Unsubscribe & re-subscribe to the OrderBook Level2 channel in order to receive a fresh
+    /// snapshot. Updates received in the meantime are dropped.
+    pub fn resubscribe(&mut self) -> Result<(), DataError> {
+        self.awaiting_snapshot = true;
+
+        ["unsubscribe", "subscribe"].into_iter().try_for_each(|op| {
+            self.ws_sink_tx
+                .send(book_l2_request(op, &self.market))
+                .map_err(|_| DataError::Socket(SocketError::Sink))
+        })
+    }
+}
+
+#[async_trait]
+impl OrderBookUpdater for OkxBookUpdater {
+    type OrderBook = OrderBook;
+    type Update = OkxOrderBookL2;
+
+    async fn init<Exchange, Kind>(
+        ws_sink_tx: mpsc::UnboundedSender<WsMessage>,
+        instrument: Instrument,
+    ) -> Result<InstrumentOrderBook<Instrument, Self>, DataError>
+    where
+        Exchange: Send,
+        Kind: Send,
+    {
+        // Okx sends the initial OrderBook snapshot over the WebSocket after subscribing
+        let market = okx_market(&instrument);
+
+        Ok(InstrumentOrderBook {
+            instrument,
+            updater: Self::new(market, ws_sink_tx),
+            book: OrderBook {
+                last_update_time: Utc::now(),
+                bids: OrderBookSide::new(Side::Buy, Vec::<Level>::new()),
+                asks: OrderBookSide::new(Side::Sell, Vec::<Level>::new()),
+            },
+        })
+    }
+
+    fn update(
+        &mut self,
+        book: &mut Self::OrderBook,
+        update: Self::Update,
+        pool: &mut LevelPool,
+    ) -> Result<Option<Self::OrderBook>, DataError> {
+        let OkxOrderBookL2 { action, data, .. } = update;
+
+        // Drop updates received while awaiting a (re)subscription snapshot
+        if action == OkxBookAction::Update && self.awaiting_snapshot {
+            return Ok(None);
+        }
+
+        for data in data {
+            // Retain the raw price & amount Strings required to construct the checksum input
+            if action == OkxBookAction::Snapshot {
+                self.checksum.clear();
+            }
+            for (is_bid, level) in data
+                .bids
+                .iter()
+                .map(|level| (true, level))
+                .chain(data.asks.iter().map(|level| (false, level)))
+            {
+                self.checksum.upsert(
+                    is_bid,
+                    level.price,
+                    level.amount,
+                    (level.raw_price.clone(), level.raw_amount.clone()),
+                );
+            }
+
+            match action {
+                OkxBookAction::Snapshot => {
+                    *book = OrderBook {
+                        last_update_time: data.time,
+                        bids: OrderBookSide::new(Side::Buy, data.bids),
+                        asks: OrderBookSide::new(Side::Sell, data.asks),
+                    };
+                    self.awaiting_snapshot = false;
+                }
+                OkxBookAction::Update => {
+                    book.last_update_time = data.time;
+                    book.bids.upsert(data.bids);
+                    book.asks.upsert(data.asks);
+                }
+            }
+
+            book.bids.sort();
+            book.asks.sort();
+        }
+
+        Ok(Some(book.snapshot_in(pool)))
+    }
+
+    fn expected_checksum(&self, update: &Self::Update) -> Option<u32> {
+        // Updates received while awaiting a (re)subscription snapshot are dropped
+        if update.action == OkxBookAction::Update && self.awaiting_snapshot {
+            return None;
+        }
+
+        // Okx checksums are signed 32-bit integers
+        update.data.last().map(|data| data.checksum as u32)
+    }
+
+    fn book_checksum(&self) -> Option<&dyn BookChecksum> {
+        Some(&self.checksum)
+    }
+
+    fn checksum_mismatch(&mut self, expected: u32, actual: u32) -> DataError {
+        // Re-subscribe to re-initialise the OrderBook with a fresh snapshot
+        match self.resubscribe() {
+            Ok(()) => DataError::InvalidChecksum { expected, actual },
+            Err(error) => error,
+        }
+    }
+}
+
+/// Construct an [`Okx`](super::super::Okx) OrderBook Level2 (un)subscribe request for the
+/// provided [`OkxMarket`].
+pub fn book_l2_request(op: &str, market: &OkxMarket) -> WsMessage {
+    WsMessage::Text(
+        json!({
+            "op": op,
+            "args": [{
+                "channel": OkxChannel::ORDER_BOOK_L2.as_ref(),
+                "instId": market.as_ref(),
+            }],
+        })
+        .to_string(),
+    )
+}
+
+impl<'de> Deserialize<'de> for OkxLevel {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        struct SeqVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for SeqVisitor {
+            type Value = OkxLevel;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("OkxLevel struct from the Okx WebSocket API")
+            }
+
+            fn visit_seq<SeqAccessor>(
+                self,
+                mut seq: SeqAccessor,
+            ) -> Result<Self::Value, SeqAccessor::Error>
+            where
+                SeqAccessor: serde::de::SeqAccess<'de>,
+            {
+                // OkxLevel Sequence Format:
+                // [price, amount, deprecated, number_of_orders]
+                let price = extract_next::<SeqAccessor, String>(&mut seq, "price")?;
+                let amount = extract_next::<SeqAccessor, String>(&mut seq, "amount")?;
+
+                // Ignore any additional elements or SerDe will fail
+                while seq.next_element::<serde::de::IgnoredAny>()?.is_some() {}
+
+                Ok(OkxLevel {
+                    price: price.parse().map_err(serde::de::Error::custom)?,
+                    amount: amount.parse().map_err(serde::de::Error::custom)?,
+                    raw_price: price,
+                    raw_amount: amount,
+                })
+            }
+        }
+
+        deserializer.deserialize_seq(SeqVisitor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod de {
+        use super::*;
+        use barter_integration::de::datetime_utc_from_epoch_duration;
+        use std::time::Duration;
+
+        #[test]
+        fn test_okx_order_book_l2() {
+            struct TestCase {
+                input: &'static str,
+                expected: Result<OkxOrderBookL2, SocketError>,
+            }
+
+            let tests = vec![
+                TestCase {
+                    // TC0: valid snapshot
+                    input: r#"
+                    {
+                        "arg": {"channel": "books", "instId": "BTC-USDT"},
+                        "action": "snapshot",
+                        "data": [
+                            {
+                                "asks": [["3366.8", "9", "10", "3"], ["3368", "8", "3", "4"]],
+                                "bids": [["3366.1", "7", "0", "3"], ["3366", "6", "3", "4"]],
+                                "ts": "1597026383085",
+                                "checksum": -1881014294,
+                                "prevSeqId": -1,
+                                "seqId": 123456
+                            }
+                        ]
+                    }
+                    "#,
+                    expected: Ok(OkxOrderBookL2 {
+                        subscription_id: SubscriptionId::from("books|BTC-USDT"),
+                        action: OkxBookAction::Snapshot,
+                        data: vec![OkxOrderBookL2Data {
+                            asks: vec![
+                                OkxLevel {
+                                    price: 3366.8,
+                                    amount: 9.0,
+                                    raw_price: "3366.8".to_owned(),
+                                    raw_amount: "9".to_owned(),
+                                },
+                                OkxLevel {
+                                    price: 3368.0,
+                                    amount: 8.0,
+                                    raw_price: "3368".to_owned(),
+                                    raw_amount: "8".to_owned(),
+                                },
+                            ],
+                            bids: vec![
+                                OkxLevel {
+                                    price: 3366.1,
+                                    amount: 7.0,
+                                    raw_price: "3366.1".to_owned(),
+                                    raw_amount: "7".to_owned(),
+                                },
+                                OkxLevel {
+                                    price: 3366.0,
+                                    amount: 6.0,
+                                    raw_price: "3366".to_owned(),
+                                    raw_amount: "6".to_owned(),
+                                },
+                            ],
+                            time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                                1597026383085,
+                            )),
+                            checksum: -1881014294,
+                        }],
+                    }),
+                },
+                TestCase {
+                    // TC1: invalid update w/ unknown action
+                    input: r#"
+                    {
+                        "arg": {"channel": "books", "instId": "BTC-USDT"},
+                        "action": "unknown",
+                        "data": []
+                    }
+                    "#,
+                    expected: Err(SocketError::Unsupported {
+                        entity: "",
+                        item: "".to_string(),
+                    }),
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                let actual = serde_json::from_str::<OkxOrderBookL2>(test.input);
+                match (actual, test.expected) {
+                    (Ok(actual), Ok(expected)) => {
+                        assert_eq!(actual, expected, "TC{} failed", index)
+                    }
+                    (Err(_), Err(_)) => {
+                        // Test passed
+                    }
+                    (actual, expected) => {
+                        // Test failed
+                        panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                    }
+                }
+            }
+        }
+    }
+
+    mod okx_book_updater {
+        use super::*;
+        use crate::transformer::book::checksum;
+
+        fn message(
+            action: OkxBookAction,
+            bids: Vec<(f64, f64)>,
+            asks: Vec<(f64, f64)>,
+            checksum: i32,
+        ) -> OkxOrderBookL2 {
+            let levels = |levels: Vec<(f64, f64)>| {
+                levels
+                    .into_iter()
+                    .map(|(price, amount)| OkxLevel {
+                        price,
+                        amount,
+                        raw_price: price.to_string(),
+                        raw_amount: amount.to_string(),
+                    })
+                    .collect()
+            };
+
+            OkxOrderBookL2 {
+                subscription_id: SubscriptionId::from("books|BTC-USDT"),
+                action,
+                data: vec![OkxOrderBookL2Data {
+                    asks: levels(asks),
+                    bids: levels(bids),
+                    time: Utc::now(),
+                    checksum,
+                }],
+            }
+        }
+
+        #[test]
+        fn test_update() {
+            let (ws_sink_tx, mut ws_sink_rx) = mpsc::unbounded_channel();
+            let mut updater = OkxBookUpdater::new(OkxMarket("BTC-USDT".to_string()), ws_sink_tx);
+            let mut book = OrderBook {
+                last_update_time: Utc::now(),
+                bids: OrderBookSide::new(Side::Buy, Vec::<Level>::new()),
+                asks: OrderBookSide::new(Side::Sell, Vec::<Level>::new()),
+            };
+
+            // Updates received before the initial snapshot are dropped
+            let update = message(OkxBookAction::Update, vec![(3366.1, 7.0)], vec![], 0);
+            assert!(matches!(
+                checksum::update(&mut updater, &mut book, update, &mut LevelPool::default()),
+                Ok(None)
+            ));
+
+            // Snapshot w/ valid checksum initialises the OrderBook
+            // crc32("3366.1:7:3366.8:9:3366:6:3368:8") as i32
+            let snapshot = message(
+                OkxBookAction::Snapshot,
+                vec![(3366.0, 6.0), (3366.1, 7.0)],
+                vec![(3368.0, 8.0), (3366.8, 9.0)],
+                -1881014294,
+            );
+            let actual =
+                checksum::update(&mut updater, &mut book, snapshot, &mut LevelPool::default())
+                    .unwrap()
+                    .unwrap();
+            assert!(!updater.awaiting_snapshot);
+            assert_eq!(actual.bids.levels()[0], Level::new(3366.1, 7.0));
+
+            // Update w/ valid checksum is applied, where the longer side is appended
+            // crc32("3366.1:7:3366.8:9:3366:6:3368:8:3372:1")
+            let update = message(
+                OkxBookAction::Update,
+                vec![],
+                vec![(3372.0, 1.0)],
+                686728965,
+            );
+            let actual =
+                checksum::update(&mut updater, &mut book, update, &mut LevelPool::default())
+                    .unwrap()
+                    .unwrap();
+            assert_eq!(actual.asks.levels().len(), 3);
+
+            // Update w/ invalid checksum triggers a re-subscription
+            let update = message(OkxBookAction::Update, vec![], vec![(3372.0, 0.0)], 1);
+            assert!(matches!(
+                checksum::update(&mut updater, &mut book, update, &mut LevelPool::default()),
+                Err(DataError::InvalidChecksum { expected: 1, .. })
+            ));
+            assert!(updater.awaiting_snapshot);
+            assert!(
+                matches!(ws_sink_rx.try_recv(), Ok(WsMessage::Text(text)) if text.contains("unsubscribe"))
+            );
+            assert!(
+                matches!(ws_sink_rx.try_recv(), Ok(WsMessage::Text(text)) if text.contains("\"subscribe\""))
+            );
+        }
+
+        #[test]
+        fn test_update_checksum_uses_raw_strings() {
+            let (ws_sink_tx, _ws_sink_rx) = mpsc::unbounded_channel();
+            let mut updater = OkxBookUpdater::new(OkxMarket("BTC-USDT".to_string()), ws_sink_tx);
+            let mut book = OrderBook {
+                last_update_time: Utc::now(),
+                bids: OrderBookSide::new(Side::Buy, Vec::<Level>::new()),
+                asks: OrderBookSide::new(Side::Sell, Vec::<Level>::new()),
+            };
+
+            // Raw Strings w/ trailing zeros cannot be reconstructed by formatting an f64
+            let snapshot = serde_json::from_str::<OkxOrderBookL2>(&format!(
+                r#"{{
+                    "arg": {{"channel": "books", "instId": "BTC-USDT"}},
+                    "action": "snapshot",
+                    "data": [{{
+                        "asks": [["3366.80", "9.000", "0", "1"]],
+                        "bids": [["3366.10", "7.0", "0", "1"]],
+                        "ts": "1597026383085",
+                        "checksum": {}
+                    }}]
+                }}"#,
+                crc32fast::hash(b"3366.10:7.0:3366.80:9.000") as i32
+            ))
+            .unwrap();
+
+            assert!(
+                checksum::update(&mut updater, &mut book, snapshot, &mut LevelPool::default())
+                    .is_ok()
+            );
+        }
+    }
+}
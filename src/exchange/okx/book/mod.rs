@@ -0,0 +1,2 @@
+/// Level 2 OrderBook types.
+pub mod l2;
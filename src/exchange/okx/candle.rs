@@ -0,0 +1,230 @@
+use super::channel::OkxChannel;
+use crate::{
+    event::{MarketEvent, MarketIter},
+    exchange::{ExchangeId, ExchangeSub},
+    subscription::candle::{Candle, Interval},
+    Identifier,
+};
+use barter_integration::model::{Exchange, SubscriptionId};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// [`Okx`](super::Okx) real-time mark price candlesticks WebSocket message.
+///
+/// The candlestick [`Interval`] is determined from the "mark-price-candle{bar}" channel, since
+/// each data element only contains the interval start time.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://www.okx.com/docs-v5/en/#public-data-websocket-mark-price-candlesticks-channel>
+/// ```json
+/// {
+///   "arg": {
+///     "channel": "mark-price-candle1D",
+///     "instId": "BTC-USD-190628"
+///   },
+///   "data": [
+///     ["1597026383085", "3.721", "3.743", "3.677", "3.708", "0"]
+///   ]
+/// }
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+#[serde(try_from = "OkxMarkPriceCandlesRaw")]
+pub struct OkxMarkPriceCandles {
+    pub subscription_id: SubscriptionId,
+    pub interval: Interval,
+    pub data: Vec<OkxMarkPriceCandle>,
+}
+
+/// [`Okx`](super::Okx) mark price candlestick.
+///
+/// Format: \[TS, OPEN, HIGH, LOW, CLOSE, CONFIRM\], <br> where TS is the interval start time,
+/// and a CONFIRM of "1" indicates the candlestick is complete.
+///
+/// See docs: <https://www.okx.com/docs-v5/en/#public-data-websocket-mark-price-candlesticks-channel>
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct OkxMarkPriceCandle {
+    #[serde(deserialize_with = "crate::util::time::de_str_epoch_ms_as_datetime_utc")]
+    pub start_time: DateTime<Utc>,
+    #[serde(deserialize_with = "barter_integration::de::de_str")]
+    pub open: f64,
+    #[serde(deserialize_with = "barter_integration::de::de_str")]
+    pub high: f64,
+    #[serde(deserialize_with = "barter_integration::de::de_str")]
+    pub low: f64,
+    #[serde(deserialize_with = "barter_integration::de::de_str")]
+    pub close: f64,
+    #[serde(deserialize_with = "de_okx_confirm")]
+    pub confirm: bool,
+}
+
+/// Raw [`OkxMarkPriceCandles`] used to determine the candlestick [`Interval`] from the channel.
+#[derive(Deserialize)]
+struct OkxMarkPriceCandlesRaw {
+    arg: OkxArg,
+    data: Vec<OkxMarkPriceCandle>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct OkxArg {
+    channel: String,
+    inst_id: String,
+}
+
+impl TryFrom<OkxMarkPriceCandlesRaw> for OkxMarkPriceCandles {
+    type Error = String;
+
+    fn try_from(raw: OkxMarkPriceCandlesRaw) -> Result<Self, Self::Error> {
+        let interval = Interval::ALL
+            .into_iter()
+            .find(|interval| OkxChannel::mark_price_candles(*interval).0 == raw.arg.channel)
+            .ok_or_else(|| {
+                format!(
+                    "unsupported Okx mark price candle channel: {}",
+                    raw.arg.channel
+                )
+            })?;
+
+        Ok(Self {
+            subscription_id: ExchangeSub::from((raw.arg.channel, raw.arg.inst_id)).id(),
+            interval,
+            data: raw.data,
+        })
+    }
+}
+
+impl Identifier<Option<SubscriptionId>> for OkxMarkPriceCandles {
+    fn id(&self) -> Option<SubscriptionId> {
+        Some(self.subscription_id.clone())
+    }
+}
+
+impl<InstrumentId: Clone> From<(ExchangeId, InstrumentId, OkxMarkPriceCandles)>
+    for MarketIter<InstrumentId, Candle>
+{
+    fn from(
+        (exchange_id, instrument, candles): (ExchangeId, InstrumentId, OkxMarkPriceCandles),
+    ) -> Self {
+        let interval = candles.interval.duration();
+
+        candles
+            .data
+            .into_iter()
+            .map(|candle| {
+                let close_time = candle.start_time + interval;
+                Ok(MarketEvent {
+                    exchange_time: close_time,
+                    received_time: Utc::now(),
+                    exchange: Exchange::from(exchange_id),
+                    instrument: instrument.clone(),
+                    kind: Candle {
+                        close_time,
+                        open: candle.open,
+                        high: candle.high,
+                        low: candle.low,
+                        close: candle.close,
+                        volume: 0.0,
+                        trade_count: 0,
+                    },
+                })
+            })
+            .collect()
+    }
+}
+
+/// Deserialize an [`OkxMarkPriceCandle`] "confirm" string ("0" or "1") as a `bool`.
+fn de_okx_confirm<'de, D>(deserializer: D) -> Result<bool, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+{
+    let confirm: &str = Deserialize::deserialize(deserializer)?;
+    match confirm {
+        "0" => Ok(false),
+        "1" => Ok(true),
+        other => Err(serde::de::Error::invalid_value(
+            serde::de::Unexpected::Str(other),
+            &"\"0\" or \"1\"",
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod de {
+        use super::*;
+        use barter_integration::de::datetime_utc_from_epoch_duration;
+        use std::time::Duration;
+
+        #[test]
+        fn test_okx_mark_price_candles() {
+            struct TestCase {
+                input: &'static str,
+                expected: Result<OkxMarkPriceCandles, ()>,
+            }
+
+            let tests = vec![
+                TestCase {
+                    // TC0: valid mark price candle w/ 1D interval
+                    input: r#"
+                    {
+                        "arg": {
+                            "channel": "mark-price-candle1D",
+                            "instId": "BTC-USD-190628"
+                        },
+                        "data": [
+                            ["1597026383085", "3.721", "3.743", "3.677", "3.708", "0"]
+                        ]
+                    }
+                    "#,
+                    expected: Ok(OkxMarkPriceCandles {
+                        subscription_id: SubscriptionId::from("mark-price-candle1D|BTC-USD-190628"),
+                        interval: Interval::D1,
+                        data: vec![OkxMarkPriceCandle {
+                            start_time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                                1597026383085,
+                            )),
+                            open: 3.721,
+                            high: 3.743,
+                            low: 3.677,
+                            close: 3.708,
+                            confirm: false,
+                        }],
+                    }),
+                },
+                TestCase {
+                    // TC1: invalid mark price candle w/ unsupported interval
+                    input: r#"
+                    {
+                        "arg": {
+                            "channel": "mark-price-candle1M",
+                            "instId": "BTC-USD-190628"
+                        },
+                        "data": [
+                            ["1597026383085", "3.721", "3.743", "3.677", "3.708", "1"]
+                        ]
+                    }
+                    "#,
+                    expected: Err(()),
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                let actual = serde_json::from_str::<OkxMarkPriceCandles>(test.input);
+                match (actual, test.expected) {
+                    (Ok(actual), Ok(expected)) => {
+                        assert_eq!(actual, expected, "TC{} failed", index)
+                    }
+                    (Err(_), Err(_)) => {
+                        // Test passed
+                    }
+                    (actual, expected) => {
+                        // Test failed
+                        panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                    }
+                }
+            }
+        }
+    }
+}
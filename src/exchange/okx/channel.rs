@@ -1,6 +1,12 @@
 use super::Okx;
 use crate::{
-    subscription::{trade::PublicTrades, Subscription},
+    subscription::{
+        book::{OrderBooksL1, OrderBooksL2},
+        candle::{Interval, MarkPriceCandles},
+        status::ExchangeStatus,
+        trade::{BlockTrades, PublicTrades},
+        Subscription,
+    },
     Identifier,
 };
 use serde::Serialize;
@@ -17,6 +23,41 @@ impl OkxChannel {
     ///
     /// See docs: <https://www.okx.com/docs-v5/en/#websocket-api-public-channel-trades-channel>
     pub const TRADES: Self = Self("trades");
+
+    /// [`Okx`] real-time public block trades channel.
+    ///
+    /// See docs: <https://www.okx.com/docs-v5/en/#block-trading-websocket-public-channel-public-block-trades-channel>
+    pub const BLOCK_TRADES: Self = Self("public-block-trades");
+
+    /// [`Okx`] real-time OrderBook Level2 channel (400 depth levels).
+    ///
+    /// See docs: <https://www.okx.com/docs-v5/en/#order-book-trading-market-data-ws-order-book-channel>
+    pub const ORDER_BOOK_L2: Self = Self("books");
+
+    /// [`Okx`] venue-wide system maintenance status channel.
+    ///
+    /// See docs: <https://www.okx.com/docs-v5/en/#status-websocket-status-channel>
+    pub const STATUS: Self = Self("status");
+
+    /// [`Okx`] mark price candlesticks channel for the provided [`Interval`].
+    ///
+    /// See docs: <https://www.okx.com/docs-v5/en/#public-data-websocket-mark-price-candlesticks-channel>
+    pub fn mark_price_candles(interval: Interval) -> Self {
+        Self(match interval {
+            Interval::M1 => "mark-price-candle1m",
+            Interval::M3 => "mark-price-candle3m",
+            Interval::M5 => "mark-price-candle5m",
+            Interval::M15 => "mark-price-candle15m",
+            Interval::M30 => "mark-price-candle30m",
+            Interval::H1 => "mark-price-candle1H",
+            Interval::H2 => "mark-price-candle2H",
+            Interval::H4 => "mark-price-candle4H",
+            Interval::H6 => "mark-price-candle6H",
+            Interval::H12 => "mark-price-candle12H",
+            Interval::D1 => "mark-price-candle1D",
+            Interval::W1 => "mark-price-candle1W",
+        })
+    }
 }
 
 impl<Instrument> Identifier<OkxChannel> for Subscription<Okx, Instrument, PublicTrades> {
@@ -25,6 +66,38 @@ impl<Instrument> Identifier<OkxChannel> for Subscription<Okx, Instrument, Public
     }
 }
 
+impl<Instrument> Identifier<OkxChannel> for Subscription<Okx, Instrument, BlockTrades> {
+    fn id(&self) -> OkxChannel {
+        OkxChannel::BLOCK_TRADES
+    }
+}
+
+impl<Instrument> Identifier<OkxChannel> for Subscription<Okx, Instrument, MarkPriceCandles> {
+    fn id(&self) -> OkxChannel {
+        OkxChannel::mark_price_candles(self.kind.0)
+    }
+}
+
+/// [`OrderBooksL1`] are derived from the OrderBook Level2 channel, since [`Okx`] has no
+/// supported bookTicker-style channel.
+impl<Instrument> Identifier<OkxChannel> for Subscription<Okx, Instrument, OrderBooksL1> {
+    fn id(&self) -> OkxChannel {
+        OkxChannel::ORDER_BOOK_L2
+    }
+}
+
+impl<Instrument> Identifier<OkxChannel> for Subscription<Okx, Instrument, OrderBooksL2> {
+    fn id(&self) -> OkxChannel {
+        OkxChannel::ORDER_BOOK_L2
+    }
+}
+
+impl<Instrument> Identifier<OkxChannel> for Subscription<Okx, Instrument, ExchangeStatus> {
+    fn id(&self) -> OkxChannel {
+        OkxChannel::STATUS
+    }
+}
+
 impl AsRef<str> for OkxChannel {
     fn as_ref(&self) -> &str {
         self.0
@@ -42,7 +42,7 @@ impl AsRef<str> for OkxMarket {
     }
 }
 
-fn okx_market(instrument: &Instrument) -> OkxMarket {
+pub(super) fn okx_market(instrument: &Instrument) -> OkxMarket {
     use InstrumentKind::*;
     let Instrument { base, quote, kind } = instrument;
 
@@ -1,20 +1,43 @@
 use self::{
-    channel::OkxChannel, market::OkxMarket, subscription::OkxSubResponse, trade::OkxTrades,
+    book::l2::OkxBookUpdater,
+    candle::OkxMarkPriceCandles,
+    channel::OkxChannel,
+    market::OkxMarket,
+    status::OkxStatusMessage,
+    subscription::OkxSubResponse,
+    trade::{OkxBlockTrades, OkxTrades},
 };
 use crate::instrument::InstrumentData;
 use crate::{
-    exchange::{Connector, ExchangeId, ExchangeSub, PingInterval, StreamSelector},
+    exchange::{Connector, ExchangeId, ExchangeSub, Keepalive, PongTimeout, StreamSelector},
     subscriber::{validator::WebSocketSubValidator, WebSocketSubscriber},
-    subscription::trade::PublicTrades,
-    transformer::stateless::StatelessTransformer,
+    subscription::{
+        book::{OrderBooksL1, OrderBooksL2},
+        candle::MarkPriceCandles,
+        status::ExchangeStatus,
+        trade::{BlockTrades, PublicTrades},
+    },
+    transformer::{
+        book::{l1::OrderBookL1Transformer, MultiBookTransformer},
+        stateless::StatelessTransformer,
+        status::StatusTransformer,
+    },
     ExchangeWsStream,
 };
-use barter_integration::{error::SocketError, protocol::websocket::WsMessage};
+use barter_integration::{
+    error::SocketError, model::instrument::Instrument, protocol::websocket::WsMessage,
+};
 use barter_macro::{DeExchange, SerExchange};
 use serde_json::json;
 use std::time::Duration;
 use url::Url;
 
+/// OrderBook types for [`Okx`].
+pub mod book;
+
+/// Mark price candlestick types for [`Okx`].
+pub mod candle;
+
 /// Defines the type that translates a Barter [`Subscription`](crate::subscription::Subscription)
 /// into an exchange [`Connector`] specific channel used for generating [`Connector::requests`].
 pub mod channel;
@@ -23,6 +46,9 @@ pub mod channel;
 /// into an exchange [`Connector`] specific market used for generating [`Connector::requests`].
 pub mod market;
 
+/// System maintenance status types for [`Okx`].
+pub mod status;
+
 /// [`Subscription`](crate::subscription::Subscription) response type and response
 /// [`Validator`](barter_integration::Validator) for [`Okx`].
 pub mod subscription;
@@ -35,11 +61,21 @@ pub mod trade;
 /// See docs: <https://www.okx.com/docs-v5/en/#overview-api-resources-and-support>
 pub const BASE_URL_OKX: &str = "wss://wsaws.okx.com:8443/ws/v5/public";
 
-/// [`Okx`] server [`PingInterval`] duration.
+/// [`Okx`] backup server base url, used if the [`BASE_URL_OKX`] endpoint is unavailable.
+///
+/// See docs: <https://www.okx.com/docs-v5/en/#overview-api-resources-and-support>
+pub const BACKUP_URL_OKX: &str = "wss://ws.okx.com:8443/ws/v5/public";
+
+/// [`Okx`] server [`Keepalive`] ping interval.
 ///
 /// See docs: <https://www.okx.com/docs-v5/en/#websocket-api-connect>
 pub const PING_INTERVAL_OKX: Duration = Duration::from_secs(29);
 
+/// [`Okx`] server [`PongTimeout`] duration, allowing for one missed pong response.
+///
+/// See docs: <https://www.okx.com/docs-v5/en/#websocket-api-connect>
+pub const PONG_TIMEOUT_OKX: Duration = Duration::from_secs(60);
+
 /// [`Okx`] exchange.
 ///
 /// See docs: <https://www.okx.com/docs-v5/en/#websocket-api>
@@ -60,10 +96,24 @@ impl Connector for Okx {
         Url::parse(BASE_URL_OKX).map_err(SocketError::UrlParse)
     }
 
-    fn ping_interval() -> Option<PingInterval> {
-        Some(PingInterval {
-            interval: tokio::time::interval(PING_INTERVAL_OKX),
+    fn urls() -> Result<Vec<Url>, SocketError> {
+        [BASE_URL_OKX, BACKUP_URL_OKX]
+            .into_iter()
+            .map(|url| Url::parse(url).map_err(SocketError::UrlParse))
+            .collect()
+    }
+
+    fn keepalive() -> Keepalive {
+        Keepalive::ApplicationJson {
+            interval: PING_INTERVAL_OKX,
             ping: || WsMessage::text("ping"),
+        }
+    }
+
+    fn pong_timeout() -> Option<PongTimeout> {
+        Some(PongTimeout {
+            timeout: PONG_TIMEOUT_OKX,
+            is_pong: |message| matches!(message, WsMessage::Text(text) if text == "pong"),
         })
     }
 
@@ -85,3 +135,42 @@ where
     type Stream =
         ExchangeWsStream<StatelessTransformer<Self, Instrument::Id, PublicTrades, OkxTrades>>;
 }
+
+impl<Instrument> StreamSelector<Instrument, BlockTrades> for Okx
+where
+    Instrument: InstrumentData,
+{
+    type Stream =
+        ExchangeWsStream<StatelessTransformer<Self, Instrument::Id, BlockTrades, OkxBlockTrades>>;
+}
+
+impl<Instrument> StreamSelector<Instrument, MarkPriceCandles> for Okx
+where
+    Instrument: InstrumentData,
+{
+    type Stream = ExchangeWsStream<
+        StatelessTransformer<Self, Instrument::Id, MarkPriceCandles, OkxMarkPriceCandles>,
+    >;
+}
+
+impl StreamSelector<Instrument, OrderBooksL2> for Okx {
+    type Stream =
+        ExchangeWsStream<MultiBookTransformer<Self, Instrument, OrderBooksL2, OkxBookUpdater>>;
+}
+
+impl StreamSelector<Instrument, OrderBooksL1> for Okx {
+    type Stream = ExchangeWsStream<
+        OrderBookL1Transformer<
+            Self,
+            Instrument,
+            MultiBookTransformer<Self, Instrument, OrderBooksL2, OkxBookUpdater>,
+        >,
+    >;
+}
+
+impl<Instrument> StreamSelector<Instrument, ExchangeStatus> for Okx
+where
+    Instrument: InstrumentData,
+{
+    type Stream = ExchangeWsStream<StatusTransformer<Self, Instrument::Id, OkxStatusMessage>>;
+}
@@ -0,0 +1,190 @@
+use crate::{
+    event::{MarketEvent, MarketIter},
+    exchange::ExchangeId,
+    subscription::status::{SystemState, SystemStatus},
+};
+use barter_integration::model::Exchange;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// [`Okx`](super::Okx) message received over
+/// [`WebSocket`](barter_integration::protocol::websocket::WebSocket) relating to an active
+/// [`ExchangeStatus`](crate::subscription::status::ExchangeStatus) subscription.
+///
+/// ### Notes
+/// The "status" channel is venue-wide, so the subscription is actioned without an "instId", and
+/// any other message (eg/ subscription responses) is ignored.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://www.okx.com/docs-v5/en/#status-websocket-status-channel>
+/// ```json
+/// {
+///   "arg": {"channel": "status"},
+///   "data": [
+///     {
+///       "title": "Spot System Upgrade",
+///       "state": "scheduled",
+///       "begin": "1610019546000",
+///       "href": "",
+///       "end": "1610019546000",
+///       "serviceType": "1",
+///       "system": "classic",
+///       "scheDesc": "",
+///       "ts": "1597026383085"
+///     }
+///   ]
+/// }
+/// ```
+#[derive(Clone, PartialEq, Debug, Deserialize)]
+#[serde(untagged)]
+pub enum OkxStatusMessage {
+    Status { data: Vec<OkxStatus> },
+    Other(serde::de::IgnoredAny),
+}
+
+/// [`Okx`](super::Okx) system maintenance status.
+///
+/// See [`OkxStatusMessage`] for full raw payload examples.
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct OkxStatus {
+    pub title: String,
+    pub state: OkxStatusState,
+    #[serde(deserialize_with = "crate::util::time::de_option_str_epoch_ms_as_datetime_utc")]
+    pub begin: Option<DateTime<Utc>>,
+    #[serde(deserialize_with = "crate::util::time::de_option_str_epoch_ms_as_datetime_utc")]
+    pub end: Option<DateTime<Utc>>,
+    #[serde(deserialize_with = "crate::util::time::de_str_epoch_ms_as_datetime_utc")]
+    pub ts: DateTime<Utc>,
+}
+
+/// [`Okx`](super::Okx) system maintenance state.
+///
+/// See docs: <https://www.okx.com/docs-v5/en/#status-websocket-status-channel>
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OkxStatusState {
+    Scheduled,
+    Ongoing,
+    PreOpen,
+    Completed,
+    Canceled,
+}
+
+impl From<OkxStatusState> for SystemState {
+    fn from(state: OkxStatusState) -> Self {
+        match state {
+            OkxStatusState::Scheduled => SystemState::Scheduled,
+            OkxStatusState::Ongoing => SystemState::Maintenance,
+            OkxStatusState::PreOpen => SystemState::CancelOnly,
+            OkxStatusState::Completed | OkxStatusState::Canceled => SystemState::Online,
+        }
+    }
+}
+
+impl<InstrumentId> From<(ExchangeId, InstrumentId, OkxStatusMessage)>
+    for MarketIter<InstrumentId, SystemStatus>
+where
+    InstrumentId: Clone,
+{
+    fn from(
+        (exchange_id, instrument, message): (ExchangeId, InstrumentId, OkxStatusMessage),
+    ) -> Self {
+        let OkxStatusMessage::Status { data } = message else {
+            return Self(vec![]);
+        };
+
+        data.into_iter()
+            .map(|status| {
+                Ok(MarketEvent {
+                    exchange_time: status.ts,
+                    received_time: Utc::now(),
+                    exchange: Exchange::from(exchange_id),
+                    instrument: instrument.clone(),
+                    kind: SystemStatus {
+                        state: SystemState::from(status.state),
+                        message: Some(status.title),
+                        start_time: status.begin,
+                        end_time: status.end,
+                    },
+                })
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod de {
+        use super::*;
+        use barter_integration::{de::datetime_utc_from_epoch_duration, error::SocketError};
+        use std::time::Duration;
+
+        #[test]
+        fn test_okx_status_message() {
+            struct TestCase {
+                input: &'static str,
+                expected: Result<OkxStatusMessage, SocketError>,
+            }
+
+            let tests = vec![
+                TestCase {
+                    // TC0: valid scheduled maintenance
+                    input: r#"
+                    {
+                        "arg": {"channel": "status"},
+                        "data": [
+                            {
+                                "title": "Spot System Upgrade",
+                                "state": "scheduled",
+                                "begin": "1610019546000",
+                                "href": "",
+                                "end": "",
+                                "serviceType": "1",
+                                "system": "classic",
+                                "scheDesc": "",
+                                "ts": "1597026383085"
+                            }
+                        ]
+                    }
+                    "#,
+                    expected: Ok(OkxStatusMessage::Status {
+                        data: vec![OkxStatus {
+                            title: "Spot System Upgrade".to_string(),
+                            state: OkxStatusState::Scheduled,
+                            begin: Some(datetime_utc_from_epoch_duration(Duration::from_millis(
+                                1610019546000,
+                            ))),
+                            end: None,
+                            ts: datetime_utc_from_epoch_duration(Duration::from_millis(
+                                1597026383085,
+                            )),
+                        }],
+                    }),
+                },
+                TestCase {
+                    // TC1: subscription response is ignored
+                    input: r#"{"event": "subscribe", "arg": {"channel": "status"}}"#,
+                    expected: Ok(OkxStatusMessage::Other(serde::de::IgnoredAny)),
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                let actual = serde_json::from_str::<OkxStatusMessage>(test.input);
+                match (actual, test.expected) {
+                    (Ok(actual), Ok(expected)) => {
+                        assert_eq!(actual, expected, "TC{} failed", index)
+                    }
+                    (Err(_), Err(_)) => {
+                        // Test passed
+                    }
+                    (actual, expected) => {
+                        // Test failed
+                        panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                    }
+                }
+            }
+        }
+    }
+}
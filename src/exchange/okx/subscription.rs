@@ -11,7 +11,10 @@ impl Serialize for ExchangeSub<OkxChannel, OkxMarket> {
     {
         let mut state = serializer.serialize_struct("OkxSubArg", 2)?;
         state.serialize_field("channel", self.channel.as_ref())?;
-        state.serialize_field("instId", self.market.as_ref())?;
+        // Venue-wide status channel does not accept an instId
+        if self.channel != OkxChannel::STATUS {
+            state.serialize_field("instId", self.market.as_ref())?;
+        }
         state.end()
     }
 }
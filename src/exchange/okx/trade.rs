@@ -1,7 +1,7 @@
 use crate::{
     event::{MarketEvent, MarketIter},
     exchange::{ExchangeId, ExchangeSub},
-    subscription::trade::PublicTrade,
+    subscription::trade::{BlockTrade, PublicTrade},
     Identifier,
 };
 use barter_integration::model::{Exchange, Side, SubscriptionId};
@@ -11,6 +11,34 @@ use serde::{Deserialize, Serialize};
 /// Terse type alias for an [`Okx`](super::Okx) real-time trades WebSocket message.
 pub type OkxTrades = OkxMessage<OkxTrade>;
 
+/// Terse type alias for an [`Okx`](super::Okx) real-time public block trades WebSocket message.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://www.okx.com/docs-v5/en/#block-trading-websocket-public-channel-public-block-trades-channel>
+/// ```json
+/// {
+///   "arg": {
+///     "channel": "public-block-trades",
+///     "instId": "BTC-USD-231020-5000-P"
+///   },
+///   "data": [
+///     {
+///       "fillVol": "5",
+///       "fwdPx": "26857.86591585",
+///       "idxPx": "26889.7",
+///       "instId": "BTC-USD-231020-5000-P",
+///       "markPx": "0.0000000000000001",
+///       "px": "0.0026",
+///       "side": "buy",
+///       "sz": "1",
+///       "tradeId": "632960608383700997",
+///       "ts": "1697422495542"
+///     }
+///   ]
+/// }
+/// ```
+pub type OkxBlockTrades = OkxMessage<OkxTrade>;
+
 /// [`Okx`](super::Okx) market data WebSocket message.
 ///
 /// ### Raw Payload Examples
@@ -86,7 +114,7 @@ pub struct OkxTrade {
     pub side: Side,
     #[serde(
         rename = "ts",
-        deserialize_with = "barter_integration::de::de_str_u64_epoch_ms_as_datetime_utc"
+        deserialize_with = "crate::util::time::de_str_epoch_ms_as_datetime_utc"
     )]
     pub time: DateTime<Utc>,
 }
@@ -116,8 +144,33 @@ impl<InstrumentId: Clone> From<(ExchangeId, InstrumentId, OkxTrades)>
     }
 }
 
+impl<InstrumentId: Clone> From<(ExchangeId, InstrumentId, OkxBlockTrades)>
+    for MarketIter<InstrumentId, BlockTrade>
+{
+    fn from((exchange_id, instrument, trades): (ExchangeId, InstrumentId, OkxBlockTrades)) -> Self {
+        trades
+            .data
+            .into_iter()
+            .map(|trade| {
+                Ok(MarketEvent {
+                    exchange_time: trade.time,
+                    received_time: Utc::now(),
+                    exchange: Exchange::from(exchange_id),
+                    instrument: instrument.clone(),
+                    kind: BlockTrade {
+                        id: trade.id,
+                        price: trade.price,
+                        amount: trade.amount,
+                        side: trade.side,
+                    },
+                })
+            })
+            .collect()
+    }
+}
+
 /// Deserialize an [`OkxMessage`] "arg" field as a Barter [`SubscriptionId`].
-fn de_okx_message_arg_as_subscription_id<'de, D>(
+pub(super) fn de_okx_message_arg_as_subscription_id<'de, D>(
     deserializer: D,
 ) -> Result<SubscriptionId, D::Error>
 where
@@ -137,6 +190,7 @@ where
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::subscription::trade::tests::assert_duplicate_frame_dedup_keys;
 
     mod de {
         use super::*;
@@ -189,5 +243,83 @@ mod tests {
                 }
             }
         }
+
+        #[test]
+        fn test_okx_message_block_trades() {
+            let input = r#"
+            {
+                "arg": {
+                    "channel": "public-block-trades",
+                    "instId": "BTC-USD-231020-5000-P"
+                },
+                "data": [
+                    {
+                        "fillVol": "5",
+                        "fwdPx": "26857.86591585",
+                        "idxPx": "26889.7",
+                        "instId": "BTC-USD-231020-5000-P",
+                        "markPx": "0.0000000000000001",
+                        "px": "0.0026",
+                        "side": "buy",
+                        "sz": "1",
+                        "tradeId": "632960608383700997",
+                        "ts": "1697422495542"
+                    }
+                ]
+            }
+            "#;
+
+            let actual = serde_json::from_str::<OkxBlockTrades>(input).unwrap();
+            assert_eq!(
+                actual,
+                OkxBlockTrades {
+                    subscription_id: SubscriptionId::from(
+                        "public-block-trades|BTC-USD-231020-5000-P"
+                    ),
+                    data: vec![OkxTrade {
+                        id: "632960608383700997".to_string(),
+                        price: 0.0026,
+                        amount: 1.0,
+                        side: Side::Buy,
+                        time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                            1697422495542
+                        )),
+                    }],
+                }
+            );
+        }
+    }
+
+    #[test]
+    fn test_okx_trades_duplicate_frame_dedup_keys() {
+        assert_duplicate_frame_dedup_keys::<OkxTrades>(
+            ExchangeId::Okx,
+            r#"
+            {
+                "arg": {
+                    "channel": "trades",
+                    "instId": "BTC-USDT"
+                },
+                "data": [
+                    {
+                        "instId": "BTC-USDT",
+                        "tradeId": "130639474",
+                        "px": "42219.9",
+                        "sz": "0.12060306",
+                        "side": "buy",
+                        "ts": "1630048897897"
+                    },
+                    {
+                        "instId": "BTC-USDT",
+                        "tradeId": "130639475",
+                        "px": "42219.9",
+                        "sz": "0.12060306",
+                        "side": "buy",
+                        "ts": "1630048897897"
+                    }
+                ]
+            }
+            "#,
+        );
     }
 }
@@ -0,0 +1,454 @@
+#![allow(unsafe_code)]
+
+use crate::{
+    event::{DataKind, MarketEvent},
+    exchange::ExchangeId,
+    streams::{builder::dynamic::DynamicStreams, options::StreamOptions},
+    subscription::{SubKind, Subscription},
+};
+use barter_integration::{error::SocketError, model::instrument::Instrument};
+use futures::{Stream, StreamExt};
+use serde::Serialize;
+use std::{
+    cell::RefCell,
+    ffi::{c_char, c_void, CStr, CString},
+    panic::{catch_unwind, AssertUnwindSafe},
+    ptr,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+};
+use tracing::{debug, warn};
+
+/// Callback invoked with each normalised [`MarketEvent`] of a [`BarterStream`], serialised as a
+/// NUL terminated JSON string of `len` bytes (excluding the NUL terminator).
+///
+/// The `event` pointer is only valid for the duration of the callback, so the JSON must be copied
+/// if it is required afterwards. The callback is invoked sequentially from a background thread.
+///
+/// A null callback is rejected by [`barter_stream_new`].
+pub type BarterEventCallback =
+    Option<extern "C" fn(user_data: *mut c_void, event: *const c_char, len: usize)>;
+
+/// Non-null [`BarterEventCallback`].
+type EventCallback = extern "C" fn(user_data: *mut c_void, event: *const c_char, len: usize);
+
+/// Opaque handle to a running stream of normalised [`MarketEvent`]s, delivered to a
+/// [`BarterEventCallback`].
+///
+/// Constructed via [`barter_stream_new`], and must be released via [`barter_stream_free`].
+#[derive(Debug)]
+pub struct BarterStream {
+    /// Runtime driving the WebSocket connections & event forwarding.
+    runtime: tokio::runtime::Runtime,
+    /// Set once the [`BarterStream`] is freed, after which the callback is never invoked again.
+    freed: Arc<AtomicBool>,
+}
+
+/// Caller provided `user_data` pointer passed back to the [`BarterEventCallback`].
+#[derive(Debug)]
+struct UserData(*mut c_void);
+
+// SAFETY: the user_data pointer is never dereferenced by Rust, and barter_stream_new documents
+// that the caller must ensure it can be used from the background callback thread.
+unsafe impl Send for UserData {}
+
+thread_local! {
+    /// Message of the last error that occurred on this thread, see [`barter_last_error`].
+    static LAST_ERROR: RefCell<Option<CString>> = const { RefCell::new(None) };
+}
+
+/// Initialise a [`BarterStream`] for the provided JSON array of [`Subscription`] batches, invoking
+/// the `callback` with each normalised [`MarketEvent`] until the stream is freed.
+///
+/// Each batch opens at-least-one WebSocket connection (see [`DynamicStreams::init`]). For example:
+/// ```json
+/// [
+///   [
+///     {
+///       "exchange": "binance_spot",
+///       "base": "btc",
+///       "quote": "usdt",
+///       "instrument_kind": "spot",
+///       "kind": "PublicTrades"
+///     },
+///     {
+///       "exchange": "binance_spot",
+///       "base": "eth",
+///       "quote": "usdt",
+///       "instrument_kind": "spot",
+///       "kind": "OrderBooksL1"
+///     }
+///   ],
+///   [
+///     {
+///       "exchange": "okx",
+///       "base": "btc",
+///       "quote": "usdt",
+///       "instrument_kind": "perpetual",
+///       "kind": "PublicTrades"
+///     }
+///   ]
+/// ]
+/// ```
+///
+/// Blocks until every WebSocket connection has been initialised. Returns null if the
+/// subscriptions are invalid, the `callback` is null, the streams cannot be initialised, or this
+/// function is called from within a Tokio runtime (eg/ from a [`BarterEventCallback`]), in which
+/// case the error is described by [`barter_last_error`].
+///
+/// # Safety
+/// - `subscriptions` must be a valid pointer to a NUL terminated UTF-8 string.
+/// - `user_data` must be safe to pass to the `callback` from another thread, and remain valid
+///   until [`barter_stream_free`] returns.
+#[no_mangle]
+pub unsafe extern "C" fn barter_stream_new(
+    subscriptions: *const c_char,
+    callback: BarterEventCallback,
+    user_data: *mut c_void,
+) -> *mut BarterStream {
+    if subscriptions.is_null() {
+        set_last_error("subscriptions pointer is null");
+        return ptr::null_mut();
+    }
+
+    let Some(callback) = callback else {
+        set_last_error("callback is null");
+        return ptr::null_mut();
+    };
+
+    // SAFETY: pointer is non-null, and the caller guarantees it is a NUL terminated string
+    let subscriptions = unsafe { CStr::from_ptr(subscriptions) };
+
+    // Panics must not unwind across the FFI boundary
+    match catch_unwind(AssertUnwindSafe(|| {
+        init(subscriptions, callback, UserData(user_data))
+    })) {
+        Ok(Ok(stream)) => Box::into_raw(Box::new(stream)),
+        Ok(Err(error)) => {
+            set_last_error(error);
+            ptr::null_mut()
+        }
+        Err(panic) => {
+            set_last_error(format!("panicked: {}", panic_message(&panic)));
+            ptr::null_mut()
+        }
+    }
+}
+
+/// Stop the [`BarterStream`], closing every WebSocket connection and releasing the handle.
+///
+/// Blocks until any in-flight [`BarterEventCallback`] has returned, after which the callback is
+/// never invoked again. Freeing a null handle is a no-op.
+///
+/// The handle may also be freed from within its own [`BarterEventCallback`], in which case the
+/// background threads are shut down without blocking once the callback returns.
+///
+/// # Safety
+/// `stream` must be null, or a handle returned by [`barter_stream_new`] that has not already been
+/// freed.
+#[no_mangle]
+pub unsafe extern "C" fn barter_stream_free(stream: *mut BarterStream) {
+    if stream.is_null() {
+        return;
+    }
+
+    // SAFETY: pointer is non-null, and the caller guarantees it originates from Box::into_raw
+    // in barter_stream_new & has not been freed
+    let stream = unsafe { Box::from_raw(stream) };
+
+    // Panics must not unwind across the FFI boundary
+    if let Err(panic) = catch_unwind(AssertUnwindSafe(|| {
+        stream.freed.store(true, Ordering::Release);
+
+        // Dropping a Runtime blocks, which panics if called from within a Tokio runtime (eg/ the
+        // BarterEventCallback running on a worker thread of this Runtime)
+        if tokio::runtime::Handle::try_current().is_ok() {
+            stream.runtime.shutdown_background();
+        } else {
+            drop(stream);
+        }
+    })) {
+        set_last_error(format!("panicked: {}", panic_message(&panic)));
+    }
+}
+
+/// Message describing the last error that occurred on the calling thread, or null if no error
+/// has occurred.
+///
+/// The returned NUL terminated string is owned by barter-data, and is only valid until the next
+/// barter-data call on the same thread.
+#[no_mangle]
+pub extern "C" fn barter_last_error() -> *const c_char {
+    LAST_ERROR.with(|last| {
+        last.borrow()
+            .as_ref()
+            .map_or(ptr::null(), |error| error.as_ptr())
+    })
+}
+
+/// Record the provided error message as the [`barter_last_error`] of the calling thread.
+fn set_last_error<E>(error: E)
+where
+    E: ToString,
+{
+    // Interior NUL bytes would truncate the message, so replace them
+    let message = CString::new(error.to_string().replace('\0', " "))
+        .expect("interior NUL bytes have been replaced");
+
+    LAST_ERROR.with(|last| *last.borrow_mut() = Some(message));
+}
+
+/// Message of a caught panic payload.
+fn panic_message(panic: &(dyn std::any::Any + Send)) -> &str {
+    panic
+        .downcast_ref::<&str>()
+        .copied()
+        .or_else(|| panic.downcast_ref::<String>().map(String::as_str))
+        .unwrap_or("unknown panic")
+}
+
+/// Parse the JSON [`Subscription`] batches, and initialise a [`BarterStream`] that forwards every
+/// [`MarketEvent`] to the `callback`.
+fn init(
+    subscriptions: &CStr,
+    callback: EventCallback,
+    user_data: UserData,
+) -> Result<BarterStream, Box<dyn std::error::Error>> {
+    // Runtime::block_on panics if called from within a Tokio runtime
+    if tokio::runtime::Handle::try_current().is_ok() {
+        return Err("barter_stream_new cannot be called from within a Tokio runtime".into());
+    }
+
+    let batches =
+        serde_json::from_slice::<Vec<Vec<Subscription<ExchangeId, Instrument, SubKind>>>>(
+            subscriptions.to_bytes(),
+        )
+        .map_err(|error| SocketError::Deserialise {
+            error,
+            payload: subscriptions.to_string_lossy().into_owned(),
+        })?;
+
+    let runtime = tokio::runtime::Builder::new_multi_thread()
+        .enable_all()
+        .build()?;
+
+    let streams = runtime.block_on(DynamicStreams::init(batches, StreamOptions::default()))?;
+
+    let freed = Arc::new(AtomicBool::new(false));
+    runtime.spawn(forward(
+        streams.select_all::<MarketEvent<Instrument, DataKind>>(),
+        callback,
+        user_data,
+        Arc::clone(&freed),
+    ));
+
+    Ok(BarterStream { runtime, freed })
+}
+
+/// Forward every event of the provided [`Stream`] to the `callback` as a NUL terminated JSON
+/// string, until the [`Stream`] ends or the [`BarterStream`] is `freed`.
+///
+/// Forwarding stops if delivering an event panics, rather than unwinding into the `callback`.
+async fn forward<St, Event>(
+    stream: St,
+    callback: EventCallback,
+    user_data: UserData,
+    freed: Arc<AtomicBool>,
+) where
+    St: Stream<Item = Event>,
+    Event: Serialize,
+{
+    let mut stream = std::pin::pin!(stream);
+    while let Some(event) = stream.next().await {
+        // The BarterStream may have been freed by a previous callback without yielding
+        if freed.load(Ordering::Acquire) {
+            break;
+        }
+
+        if let Err(panic) = catch_unwind(AssertUnwindSafe(|| deliver(&event, callback, &user_data)))
+        {
+            warn!(
+                panic = panic_message(&panic),
+                "BarterStream event delivery panicked, ending forwarding"
+            );
+            break;
+        }
+    }
+
+    debug!("BarterStream event forwarding ended");
+}
+
+/// Serialise the event as a NUL terminated JSON string, and deliver it to the `callback`.
+fn deliver<Event>(event: &Event, callback: EventCallback, user_data: &UserData)
+where
+    Event: Serialize,
+{
+    let json = match serde_json::to_string(event).map(CString::new) {
+        Ok(Ok(json)) => json,
+        Ok(Err(error)) => {
+            warn!(%error, "failed to convert MarketEvent JSON to CString");
+            return;
+        }
+        Err(error) => {
+            warn!(%error, "failed to serialise MarketEvent as JSON");
+            return;
+        }
+    };
+
+    callback(user_data.0, json.as_ptr(), json.as_bytes().len());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    extern "C" fn collect(user_data: *mut c_void, event: *const c_char, len: usize) {
+        // SAFETY: tests pass a valid &mut Vec<String> as user_data, and event is a valid CStr
+        let (events, event) =
+            unsafe { (&mut *user_data.cast::<Vec<String>>(), CStr::from_ptr(event)) };
+        assert_eq!(event.to_bytes().len(), len);
+        events.push(event.to_str().unwrap().to_owned());
+    }
+
+    fn last_error() -> Option<String> {
+        let error = barter_last_error();
+        // SAFETY: barter_last_error returns null or a valid NUL terminated string
+        (!error.is_null()).then(|| {
+            unsafe { CStr::from_ptr(error) }
+                .to_str()
+                .unwrap()
+                .to_owned()
+        })
+    }
+
+    #[tokio::test]
+    async fn test_forward() {
+        let mut events = Vec::<String>::new();
+        let user_data = UserData((&mut events as *mut Vec<String>).cast());
+
+        forward(
+            futures::stream::iter(vec![
+                serde_json::json!({ "price": 1.0 }),
+                serde_json::json!({ "name": "nul\0byte" }),
+            ]),
+            collect,
+            user_data,
+            Arc::new(AtomicBool::new(false)),
+        )
+        .await;
+
+        assert_eq!(
+            events,
+            vec![
+                r#"{"price":1.0}"#.to_owned(),
+                r#"{"name":"nul\u0000byte"}"#.to_owned()
+            ]
+        );
+
+        // Events are not forwarded once the BarterStream is freed
+        let mut events = Vec::<String>::new();
+        let user_data = UserData((&mut events as *mut Vec<String>).cast());
+        forward(
+            futures::stream::iter(vec![serde_json::json!({ "price": 1.0 })]),
+            collect,
+            user_data,
+            Arc::new(AtomicBool::new(true)),
+        )
+        .await;
+        assert!(events.is_empty());
+    }
+
+    #[test]
+    fn test_barter_stream_new_invalid() {
+        struct TestCase {
+            subscriptions: Option<&'static str>,
+            callback: BarterEventCallback,
+            expected_error: &'static str,
+        }
+
+        let cases = vec![
+            TestCase {
+                // TC0: null subscriptions pointer
+                subscriptions: None,
+                callback: Some(collect),
+                expected_error: "subscriptions pointer is null",
+            },
+            TestCase {
+                // TC1: malformed subscriptions JSON
+                subscriptions: Some("[[{"),
+                callback: Some(collect),
+                expected_error: "EOF while parsing",
+            },
+            TestCase {
+                // TC2: unsupported Subscription
+                subscriptions: Some(
+                    r#"[[{
+                        "exchange": "coinbase",
+                        "base": "btc",
+                        "quote": "usd",
+                        "instrument_kind": "spot",
+                        "kind": "Liquidations"
+                    }]]"#,
+                ),
+                callback: Some(collect),
+                expected_error: "coinbase does not support: Liquidations",
+            },
+            TestCase {
+                // TC3: null callback
+                subscriptions: Some("[]"),
+                callback: None,
+                expected_error: "callback is null",
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let subscriptions = test.subscriptions.map(|json| CString::new(json).unwrap());
+            let subscriptions = subscriptions
+                .as_ref()
+                .map_or(ptr::null(), |json| json.as_ptr());
+
+            // SAFETY: subscriptions is null or a valid CString, and user_data is never used
+            let stream =
+                unsafe { barter_stream_new(subscriptions, test.callback, ptr::null_mut()) };
+
+            assert!(stream.is_null(), "TC{index} failed");
+            let error = last_error().unwrap();
+            assert!(
+                error.contains(test.expected_error),
+                "TC{index} failed with error: {error}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_barter_stream_within_tokio_runtime() {
+        let outer = tokio::runtime::Builder::new_current_thread()
+            .build()
+            .unwrap();
+
+        // barter_stream_new cannot block on a nested runtime
+        let subscriptions = CString::new("[]").unwrap();
+        // SAFETY: subscriptions is a valid CString, and user_data is never used
+        let stream = outer.block_on(async {
+            unsafe { barter_stream_new(subscriptions.as_ptr(), Some(collect), ptr::null_mut()) }
+        });
+        assert!(stream.is_null());
+        assert!(last_error().unwrap().contains("within a Tokio runtime"));
+
+        // barter_stream_free does not block when called from within a runtime (eg/ a callback)
+        let stream = Box::into_raw(Box::new(BarterStream {
+            runtime: tokio::runtime::Builder::new_multi_thread().build().unwrap(),
+            freed: Arc::new(AtomicBool::new(false)),
+        }));
+        // SAFETY: stream was returned by Box::into_raw & has not been freed
+        outer.block_on(async { unsafe { barter_stream_free(stream) } });
+    }
+
+    #[test]
+    fn test_barter_stream_free_null() {
+        // SAFETY: freeing a null handle is a no-op
+        unsafe { barter_stream_free(ptr::null_mut()) };
+    }
+}
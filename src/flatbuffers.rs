@@ -0,0 +1,457 @@
+use crate::{
+    event::{DataKind, MarketEvent},
+    subscription::{
+        book::{Level, OrderBook, OrderBookL1},
+        candle::Candle,
+        liquidation::Liquidation,
+        trade::PublicTrade,
+    },
+};
+use barter_integration::model::Side;
+use chrono::{DateTime, Utc};
+use flatbuffers::{FlatBufferBuilder, UnionWIPOffset, VOffsetT, Vector, WIPOffset};
+use std::fmt::{Debug, Display};
+
+/// Encoder of normalised [`MarketEvent`]s into the FlatBuffers `MarketEvent` table, reusing the
+/// same underlying buffer for every encoded event to avoid allocating on the hot path.
+///
+/// Only [`MarketEvent`] kinds with a stable FlatBuffers schema (see [`FlatBufferKind`]) are
+/// encoded with a `kind`, others are encoded with [`Kind::None`].
+///
+/// Consumers read the encoded bytes in place using code generated by `flatc` from
+/// `fbs/barter/data/v1/market_event.fbs`.
+#[derive(Default)]
+pub struct MarketEventEncoder {
+    builder: FlatBufferBuilder<'static>,
+}
+
+impl MarketEventEncoder {
+    /// Construct a new [`MarketEventEncoder`] with an initial buffer capacity in bytes.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            builder: FlatBufferBuilder::with_capacity(capacity),
+        }
+    }
+
+    /// Encode the [`MarketEvent`] as a FlatBuffers `MarketEvent` table, returning the finished
+    /// bytes. The bytes are only valid until the next call to `encode`.
+    pub fn encode<InstrumentId, T>(&mut self, event: &MarketEvent<InstrumentId, T>) -> &[u8]
+    where
+        InstrumentId: Display,
+        T: FlatBufferKind,
+    {
+        let builder = &mut self.builder;
+        builder.reset();
+
+        let exchange = builder.create_string(&event.exchange.to_string());
+        let instrument = builder.create_string(&event.instrument.to_string());
+        let kind = event.kind.create(builder);
+
+        let table = builder.start_table();
+        builder.push_slot::<i64>(vt::EXCHANGE_TIME, nanos(event.exchange_time), 0);
+        builder.push_slot::<i64>(vt::RECEIVED_TIME, nanos(event.received_time), 0);
+        builder.push_slot_always(vt::EXCHANGE, exchange);
+        builder.push_slot_always(vt::INSTRUMENT, instrument);
+        if let Some((kind_type, kind)) = kind {
+            builder.push_slot_always(vt::KIND, kind);
+            builder.push_slot::<u8>(vt::KIND_TYPE, kind_type as u8, Kind::None as u8);
+        }
+        let root = builder.end_table(table);
+
+        builder.finish(root, None);
+        builder.finished_data()
+    }
+}
+
+impl Debug for MarketEventEncoder {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MarketEventEncoder").finish_non_exhaustive()
+    }
+}
+
+/// Discriminant of the FlatBuffers `Kind` union.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[repr(u8)]
+pub enum Kind {
+    None = 0,
+    PublicTrade = 1,
+    OrderBookL1 = 2,
+    OrderBook = 3,
+    Candle = 4,
+    Liquidation = 5,
+}
+
+/// Normalised [`MarketEvent`] kinds with a stable FlatBuffers schema.
+pub trait FlatBufferKind {
+    /// Create the FlatBuffers table of this kind, returning the [`Kind`] discriminant & union
+    /// value, or `None` if this kind has no stable schema.
+    fn create(
+        &self,
+        builder: &mut FlatBufferBuilder<'_>,
+    ) -> Option<(Kind, WIPOffset<UnionWIPOffset>)>;
+}
+
+impl FlatBufferKind for DataKind {
+    fn create(
+        &self,
+        builder: &mut FlatBufferBuilder<'_>,
+    ) -> Option<(Kind, WIPOffset<UnionWIPOffset>)> {
+        match self {
+            DataKind::Trade(trade) => trade.create(builder),
+            DataKind::OrderBookL1(book) => book.create(builder),
+            DataKind::OrderBook(book) => book.create(builder),
+            DataKind::Candle(candle) => candle.create(builder),
+            DataKind::Liquidation(liquidation) => liquidation.create(builder),
+            _ => None,
+        }
+    }
+}
+
+impl FlatBufferKind for PublicTrade {
+    fn create(
+        &self,
+        builder: &mut FlatBufferBuilder<'_>,
+    ) -> Option<(Kind, WIPOffset<UnionWIPOffset>)> {
+        let id = builder.create_string(&self.id);
+
+        let table = builder.start_table();
+        builder.push_slot::<f64>(vt::trade::PRICE, self.price, 0.0);
+        builder.push_slot::<f64>(vt::trade::AMOUNT, self.amount, 0.0);
+        builder.push_slot_always(vt::trade::ID, id);
+        builder.push_slot::<u8>(vt::trade::SIDE, side(self.side), 0);
+        let table = builder.end_table(table);
+
+        Some((Kind::PublicTrade, table.as_union_value()))
+    }
+}
+
+impl FlatBufferKind for OrderBookL1 {
+    fn create(
+        &self,
+        builder: &mut FlatBufferBuilder<'_>,
+    ) -> Option<(Kind, WIPOffset<UnionWIPOffset>)> {
+        let table = builder.start_table();
+        push_level_slot(builder, vt::book_l1::BEST_BID, &self.best_bid);
+        push_level_slot(builder, vt::book_l1::BEST_ASK, &self.best_ask);
+        builder.push_slot::<i64>(
+            vt::book_l1::LAST_UPDATE_TIME,
+            nanos(self.last_update_time),
+            0,
+        );
+        let table = builder.end_table(table);
+
+        Some((Kind::OrderBookL1, table.as_union_value()))
+    }
+}
+
+impl FlatBufferKind for OrderBook {
+    fn create(
+        &self,
+        builder: &mut FlatBufferBuilder<'_>,
+    ) -> Option<(Kind, WIPOffset<UnionWIPOffset>)> {
+        let bids = create_levels(builder, self.bids.levels());
+        let asks = create_levels(builder, self.asks.levels());
+
+        let table = builder.start_table();
+        builder.push_slot::<i64>(vt::book::LAST_UPDATE_TIME, nanos(self.last_update_time), 0);
+        builder.push_slot_always(vt::book::BIDS, bids);
+        builder.push_slot_always(vt::book::ASKS, asks);
+        let table = builder.end_table(table);
+
+        Some((Kind::OrderBook, table.as_union_value()))
+    }
+}
+
+impl FlatBufferKind for Candle {
+    fn create(
+        &self,
+        builder: &mut FlatBufferBuilder<'_>,
+    ) -> Option<(Kind, WIPOffset<UnionWIPOffset>)> {
+        let table = builder.start_table();
+        builder.push_slot::<i64>(vt::candle::CLOSE_TIME, nanos(self.close_time), 0);
+        builder.push_slot::<f64>(vt::candle::OPEN, self.open, 0.0);
+        builder.push_slot::<f64>(vt::candle::HIGH, self.high, 0.0);
+        builder.push_slot::<f64>(vt::candle::LOW, self.low, 0.0);
+        builder.push_slot::<f64>(vt::candle::CLOSE, self.close, 0.0);
+        builder.push_slot::<f64>(vt::candle::VOLUME, self.volume, 0.0);
+        builder.push_slot::<u64>(vt::candle::TRADE_COUNT, self.trade_count, 0);
+        let table = builder.end_table(table);
+
+        Some((Kind::Candle, table.as_union_value()))
+    }
+}
+
+impl FlatBufferKind for Liquidation {
+    fn create(
+        &self,
+        builder: &mut FlatBufferBuilder<'_>,
+    ) -> Option<(Kind, WIPOffset<UnionWIPOffset>)> {
+        let table = builder.start_table();
+        builder.push_slot::<f64>(vt::liquidation::PRICE, self.price, 0.0);
+        builder.push_slot::<f64>(vt::liquidation::QUANTITY, self.quantity, 0.0);
+        builder.push_slot::<i64>(vt::liquidation::TIME, nanos(self.time), 0);
+        builder.push_slot::<u8>(vt::liquidation::SIDE, side(self.side), 0);
+        let table = builder.end_table(table);
+
+        Some((Kind::Liquidation, table.as_union_value()))
+    }
+}
+
+/// Push the [`Level`] as an inline FlatBuffers `Level` struct field of the table under
+/// construction.
+///
+/// The builder writes back to front, so pushing the `amount` followed by the tracked `price`
+/// produces the contiguous `{ price, amount }` struct layout without any `unsafe` struct `Push`.
+fn push_level_slot(builder: &mut FlatBufferBuilder<'_>, slot: VOffsetT, level: &Level) {
+    builder.push(level.amount);
+    builder.push_slot_always(slot, level.price);
+}
+
+/// Create a FlatBuffers `[Level]` vector of the provided [`Level`]s.
+///
+/// See [`push_level_slot`] for how each `Level` struct is laid out.
+fn create_levels<'fbb>(
+    builder: &mut FlatBufferBuilder<'fbb>,
+    levels: &[Level],
+) -> WIPOffset<Vector<'fbb, f64>> {
+    builder.start_vector::<f64>(levels.len() * 2);
+    for level in levels.iter().rev() {
+        builder.push(level.amount);
+        builder.push(level.price);
+    }
+    builder.end_vector::<f64>(levels.len())
+}
+
+/// FlatBuffers `Side` enum value of the provided [`Side`].
+fn side(side: Side) -> u8 {
+    match side {
+        Side::Buy => 0,
+        Side::Sell => 1,
+    }
+}
+
+/// Nanoseconds since the Unix epoch, saturating to zero if out of range.
+fn nanos(time: DateTime<Utc>) -> i64 {
+    time.timestamp_nanos_opt().unwrap_or_default()
+}
+
+/// VTable offsets of each FlatBuffers table field, in schema declaration order.
+mod vt {
+    use flatbuffers::VOffsetT;
+
+    pub const EXCHANGE_TIME: VOffsetT = 4;
+    pub const RECEIVED_TIME: VOffsetT = 6;
+    pub const EXCHANGE: VOffsetT = 8;
+    pub const INSTRUMENT: VOffsetT = 10;
+    pub const KIND_TYPE: VOffsetT = 12;
+    pub const KIND: VOffsetT = 14;
+
+    pub mod trade {
+        use flatbuffers::VOffsetT;
+
+        pub const ID: VOffsetT = 4;
+        pub const PRICE: VOffsetT = 6;
+        pub const AMOUNT: VOffsetT = 8;
+        pub const SIDE: VOffsetT = 10;
+    }
+
+    pub mod book_l1 {
+        use flatbuffers::VOffsetT;
+
+        pub const LAST_UPDATE_TIME: VOffsetT = 4;
+        pub const BEST_BID: VOffsetT = 6;
+        pub const BEST_ASK: VOffsetT = 8;
+    }
+
+    pub mod book {
+        use flatbuffers::VOffsetT;
+
+        pub const LAST_UPDATE_TIME: VOffsetT = 4;
+        pub const BIDS: VOffsetT = 6;
+        pub const ASKS: VOffsetT = 8;
+    }
+
+    pub mod candle {
+        use flatbuffers::VOffsetT;
+
+        pub const CLOSE_TIME: VOffsetT = 4;
+        pub const OPEN: VOffsetT = 6;
+        pub const HIGH: VOffsetT = 8;
+        pub const LOW: VOffsetT = 10;
+        pub const CLOSE: VOffsetT = 12;
+        pub const VOLUME: VOffsetT = 14;
+        pub const TRADE_COUNT: VOffsetT = 16;
+    }
+
+    pub mod liquidation {
+        use flatbuffers::VOffsetT;
+
+        pub const SIDE: VOffsetT = 4;
+        pub const PRICE: VOffsetT = 6;
+        pub const QUANTITY: VOffsetT = 8;
+        pub const TIME: VOffsetT = 10;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::subscription::{
+        book::OrderBookSide,
+        status::{SystemState, SystemStatus},
+    };
+    use barter_integration::model::Exchange;
+
+    /// Minimal reader of encoded FlatBuffers tables, used to assert on the encoded fields.
+    #[derive(Copy, Clone)]
+    struct TableReader<'a> {
+        buffer: &'a [u8],
+        position: usize,
+    }
+
+    impl<'a> TableReader<'a> {
+        fn root(buffer: &'a [u8]) -> Self {
+            Self {
+                buffer,
+                position: read::<4>(buffer, 0).map(u32::from_le_bytes).unwrap() as usize,
+            }
+        }
+
+        fn field(&self, slot: VOffsetT) -> Option<usize> {
+            let soffset = i32::from_le_bytes(read(self.buffer, self.position)?);
+            let vtable = (self.position as i64 - soffset as i64) as usize;
+            let vtable_len = u16::from_le_bytes(read(self.buffer, vtable)?);
+            if slot >= vtable_len {
+                return None;
+            }
+            let offset = u16::from_le_bytes(read(self.buffer, vtable + slot as usize)?);
+            (offset != 0).then_some(self.position + offset as usize)
+        }
+
+        fn indirect(&self, slot: VOffsetT) -> Option<usize> {
+            let position = self.field(slot)?;
+            Some(position + u32::from_le_bytes(read(self.buffer, position)?) as usize)
+        }
+
+        fn u8(&self, slot: VOffsetT) -> u8 {
+            self.field(slot).map_or(0, |position| self.buffer[position])
+        }
+
+        fn i64(&self, slot: VOffsetT) -> i64 {
+            self.field(slot)
+                .and_then(|position| read(self.buffer, position))
+                .map_or(0, i64::from_le_bytes)
+        }
+
+        fn f64(&self, slot: VOffsetT) -> f64 {
+            self.field(slot)
+                .and_then(|position| read(self.buffer, position))
+                .map_or(0.0, f64::from_le_bytes)
+        }
+
+        fn level(&self, slot: VOffsetT) -> Option<(f64, f64)> {
+            self.field(slot)
+                .and_then(|position| level(self.buffer, position))
+        }
+
+        fn str(&self, slot: VOffsetT) -> Option<&'a str> {
+            let position = self.indirect(slot)?;
+            let len = u32::from_le_bytes(read(self.buffer, position)?) as usize;
+            std::str::from_utf8(self.buffer.get(position + 4..position + 4 + len)?).ok()
+        }
+
+        fn table(&self, slot: VOffsetT) -> Option<Self> {
+            Some(Self {
+                buffer: self.buffer,
+                position: self.indirect(slot)?,
+            })
+        }
+
+        fn levels(&self, slot: VOffsetT) -> Vec<(f64, f64)> {
+            let Some(position) = self.indirect(slot) else {
+                return vec![];
+            };
+            let len = read(self.buffer, position).map_or(0, u32::from_le_bytes) as usize;
+            (0..len)
+                .filter_map(|index| level(self.buffer, position + 4 + index * 16))
+                .collect()
+        }
+    }
+
+    fn read<const N: usize>(buffer: &[u8], position: usize) -> Option<[u8; N]> {
+        buffer.get(position..position + N)?.try_into().ok()
+    }
+
+    fn level(buffer: &[u8], position: usize) -> Option<(f64, f64)> {
+        Some((
+            f64::from_le_bytes(read(buffer, position)?),
+            f64::from_le_bytes(read(buffer, position + 8)?),
+        ))
+    }
+
+    #[test]
+    fn test_market_event_encoder() {
+        let time = DateTime::from_timestamp(1, 500).unwrap();
+        let event = |kind| MarketEvent {
+            exchange_time: time,
+            received_time: time,
+            exchange: Exchange::from("binance_spot"),
+            instrument: "btc_usdt",
+            kind,
+        };
+
+        let mut encoder = MarketEventEncoder::default();
+
+        // TC0: PublicTrade
+        let buffer = encoder.encode(&event(DataKind::Trade(PublicTrade {
+            id: "1".to_owned(),
+            price: 100.0,
+            amount: 2.0,
+            side: Side::Sell,
+        })));
+        let actual = TableReader::root(buffer);
+        assert_eq!(actual.i64(vt::EXCHANGE_TIME), 1_000_000_500);
+        assert_eq!(actual.i64(vt::RECEIVED_TIME), 1_000_000_500);
+        assert_eq!(actual.str(vt::EXCHANGE), Some("binance_spot"));
+        assert_eq!(actual.str(vt::INSTRUMENT), Some("btc_usdt"));
+        assert_eq!(actual.u8(vt::KIND_TYPE), Kind::PublicTrade as u8);
+        let trade = actual.table(vt::KIND).unwrap();
+        assert_eq!(trade.str(vt::trade::ID), Some("1"));
+        assert_eq!(trade.f64(vt::trade::PRICE), 100.0);
+        assert_eq!(trade.f64(vt::trade::AMOUNT), 2.0);
+        assert_eq!(trade.u8(vt::trade::SIDE), 1);
+
+        // TC1: OrderBook, re-using the encoder buffer
+        let buffer = encoder.encode(&event(DataKind::OrderBook(OrderBook {
+            last_update_time: time,
+            bids: OrderBookSide::new(Side::Buy, vec![(100.0, 1.0), (99.0, 2.0)]),
+            asks: OrderBookSide::new(Side::Sell, vec![(101.0, 3.0)]),
+        })));
+        let actual = TableReader::root(buffer);
+        assert_eq!(actual.u8(vt::KIND_TYPE), Kind::OrderBook as u8);
+        let book = actual.table(vt::KIND).unwrap();
+        assert_eq!(book.i64(vt::book::LAST_UPDATE_TIME), 1_000_000_500);
+        assert_eq!(book.levels(vt::book::BIDS), vec![(100.0, 1.0), (99.0, 2.0)]);
+        assert_eq!(book.levels(vt::book::ASKS), vec![(101.0, 3.0)]);
+
+        // TC2: OrderBookL1 with inline Level structs
+        let buffer = encoder.encode(&event(DataKind::OrderBookL1(OrderBookL1 {
+            last_update_time: time,
+            best_bid: Level::from((100.0, 1.0)),
+            best_ask: Level::from((101.0, 2.0)),
+        })));
+        let book = TableReader::root(buffer).table(vt::KIND).unwrap();
+        assert_eq!(book.level(vt::book_l1::BEST_BID), Some((100.0, 1.0)));
+        assert_eq!(book.level(vt::book_l1::BEST_ASK), Some((101.0, 2.0)));
+        assert_eq!(book.field(vt::book_l1::BEST_BID).unwrap() % 8, 0);
+
+        // TC3: kind without a stable schema is encoded as Kind::None
+        let buffer = encoder.encode(&event(DataKind::SystemStatus(SystemStatus::new(
+            SystemState::Online,
+        ))));
+        let actual = TableReader::root(buffer);
+        assert_eq!(actual.u8(vt::KIND_TYPE), Kind::None as u8);
+        assert!(actual.table(vt::KIND).is_none());
+        assert_eq!(actual.str(vt::EXCHANGE), Some("binance_spot"));
+    }
+}
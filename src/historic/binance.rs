@@ -0,0 +1,344 @@
+use super::{epoch_ms, get, HistoricFetcher, HistoricPage};
+use crate::{
+    error::DataError,
+    exchange::{binance::trade::de_side_from_buyer_is_maker, ExchangeId},
+    subscription::{
+        candle::{Candle, Interval},
+        trade::PublicTrade,
+    },
+    util::time::de_u64_epoch_ms_as_datetime_utc,
+};
+use async_trait::async_trait;
+use barter_integration::model::Side;
+use chrono::{DateTime, TimeDelta, Utc};
+use serde::{de::IgnoredAny, Deserialize, Serialize};
+
+/// [`BinanceSpot`](crate::exchange::binance::spot::BinanceSpot) HTTP base url.
+pub const HTTP_BASE_URL_BINANCE_SPOT: &str = "https://api.binance.com/api/v3";
+
+/// [`BinanceFuturesUsd`](crate::exchange::binance::futures::BinanceFuturesUsd) HTTP base url.
+pub const HTTP_BASE_URL_BINANCE_FUTURES_USD: &str = "https://fapi.binance.com/fapi/v1";
+
+/// Maximum number of klines returned per request.
+const KLINES_LIMIT: usize = 1000;
+
+/// Maximum number of aggregated trades returned per request.
+const AGG_TRADES_LIMIT: usize = 1000;
+
+/// Maximum time window of an aggregated trades request with both a start & end time.
+const AGG_TRADES_WINDOW: TimeDelta = TimeDelta::hours(1);
+
+/// [`HistoricFetcher`] of Binance [`Candle`]s via the klines REST endpoint.
+///
+/// Each [`Candle`] `exchange_time` is its `close_time`.
+///
+/// See docs: <https://binance-docs.github.io/apidocs/spot/en/#kline-candlestick-data>
+#[derive(Clone, Debug)]
+pub struct BinanceCandles {
+    client: reqwest::Client,
+    exchange: ExchangeId,
+    interval: Interval,
+}
+
+impl BinanceCandles {
+    /// Construct a [`BinanceCandles`] fetcher for `BinanceSpot` markets.
+    pub fn spot(interval: Interval) -> Self {
+        Self {
+            client: reqwest::Client::new(),
+            exchange: ExchangeId::BinanceSpot,
+            interval,
+        }
+    }
+
+    /// Construct a [`BinanceCandles`] fetcher for `BinanceFuturesUsd` markets.
+    pub fn futures_usd(interval: Interval) -> Self {
+        Self {
+            client: reqwest::Client::new(),
+            exchange: ExchangeId::BinanceFuturesUsd,
+            interval,
+        }
+    }
+}
+
+#[async_trait]
+impl HistoricFetcher for BinanceCandles {
+    type Event = Candle;
+
+    fn exchange(&self) -> ExchangeId {
+        self.exchange
+    }
+
+    async fn fetch_page(
+        &self,
+        market: &str,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> Result<HistoricPage<Candle>, DataError> {
+        // Klines are requested by open time, so offset the close time range by one interval
+        let interval = TimeDelta::from_std(self.interval.duration()).unwrap_or_default();
+        let query = [
+            ("symbol", market.to_owned()),
+            ("interval", binance_interval(self.interval).to_owned()),
+            ("startTime", epoch_ms(start - interval).to_string()),
+            ("endTime", (epoch_ms(end - interval) - 1).to_string()),
+            ("limit", KLINES_LIMIT.to_string()),
+        ];
+
+        let klines =
+            get::<Vec<BinanceKline>, _>(&self.client, &url(self.exchange, "klines"), &query)
+                .await?;
+
+        let candles = klines
+            .into_iter()
+            .map(|kline| {
+                let close_time = kline.0 + interval;
+                (
+                    close_time,
+                    Candle {
+                        close_time,
+                        open: kline.1,
+                        high: kline.2,
+                        low: kline.3,
+                        close: kline.4,
+                        volume: kline.5,
+                        trade_count: kline.8,
+                    },
+                )
+            })
+            .collect();
+
+        Ok(HistoricPage::from_limit(candles, KLINES_LIMIT))
+    }
+}
+
+/// [`HistoricFetcher`] of Binance [`PublicTrade`]s via the aggregated trades REST endpoint.
+///
+/// See docs: <https://binance-docs.github.io/apidocs/spot/en/#compressed-aggregate-trades-list>
+#[derive(Clone, Debug)]
+pub struct BinanceTrades {
+    client: reqwest::Client,
+    exchange: ExchangeId,
+}
+
+impl BinanceTrades {
+    /// Construct a [`BinanceTrades`] fetcher for `BinanceSpot` markets.
+    pub fn spot() -> Self {
+        Self {
+            client: reqwest::Client::new(),
+            exchange: ExchangeId::BinanceSpot,
+        }
+    }
+
+    /// Construct a [`BinanceTrades`] fetcher for `BinanceFuturesUsd` markets.
+    pub fn futures_usd() -> Self {
+        Self {
+            client: reqwest::Client::new(),
+            exchange: ExchangeId::BinanceFuturesUsd,
+        }
+    }
+}
+
+#[async_trait]
+impl HistoricFetcher for BinanceTrades {
+    type Event = PublicTrade;
+
+    fn exchange(&self) -> ExchangeId {
+        self.exchange
+    }
+
+    async fn fetch_page(
+        &self,
+        market: &str,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> Result<HistoricPage<PublicTrade>, DataError> {
+        let window_end = end.min(start + AGG_TRADES_WINDOW);
+        let query = [
+            ("symbol", market.to_owned()),
+            ("startTime", epoch_ms(start).to_string()),
+            ("endTime", (epoch_ms(window_end) - 1).to_string()),
+            ("limit", AGG_TRADES_LIMIT.to_string()),
+        ];
+
+        let trades =
+            get::<Vec<BinanceAggTrade>, _>(&self.client, &url(self.exchange, "aggTrades"), &query)
+                .await?;
+
+        let trades = trades
+            .into_iter()
+            .map(|trade| {
+                (
+                    trade.time,
+                    PublicTrade {
+                        id: trade.id.to_string(),
+                        price: trade.price,
+                        amount: trade.amount,
+                        side: trade.side,
+                    },
+                )
+            })
+            .collect::<Vec<_>>();
+
+        // Continue within this window if it was truncated by the limit
+        Ok(match trades.len() >= AGG_TRADES_LIMIT {
+            true => HistoricPage::from_limit(trades, AGG_TRADES_LIMIT),
+            false => HistoricPage::from_window(trades, window_end, end),
+        })
+    }
+}
+
+/// Binance kline REST response row.
+///
+/// Format: \[OPEN_TIME, OPEN, HIGH, LOW, CLOSE, VOLUME, CLOSE_TIME, QUOTE_VOLUME, TRADE_COUNT,
+/// TAKER_BUY_BASE_VOLUME, TAKER_BUY_QUOTE_VOLUME, IGNORE\]
+///
+/// ### Raw Payload Examples
+/// See docs: <https://binance-docs.github.io/apidocs/spot/en/#kline-candlestick-data>
+/// ```json
+/// [
+///   1499040000000, "0.01634790", "0.80000000", "0.01575800", "0.01577100", "148976.11427815",
+///   1499644799999, "2434.19055334", 308, "1756.87402397", "28.46694368", "0"
+/// ]
+/// ```
+#[derive(Copy, Clone, PartialEq, Debug, Deserialize)]
+pub struct BinanceKline(
+    #[serde(deserialize_with = "de_u64_epoch_ms_as_datetime_utc")] pub DateTime<Utc>,
+    #[serde(deserialize_with = "barter_integration::de::de_str")] pub f64,
+    #[serde(deserialize_with = "barter_integration::de::de_str")] pub f64,
+    #[serde(deserialize_with = "barter_integration::de::de_str")] pub f64,
+    #[serde(deserialize_with = "barter_integration::de::de_str")] pub f64,
+    #[serde(deserialize_with = "barter_integration::de::de_str")] pub f64,
+    IgnoredAny,
+    IgnoredAny,
+    pub u64,
+    IgnoredAny,
+    IgnoredAny,
+    IgnoredAny,
+);
+
+/// Binance aggregated trade REST response.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://binance-docs.github.io/apidocs/spot/en/#compressed-aggregate-trades-list>
+/// ```json
+/// {
+///   "a": 26129,
+///   "p": "0.01633102",
+///   "q": "4.70443515",
+///   "f": 27781,
+///   "l": 27781,
+///   "T": 1498793709153,
+///   "m": true,
+///   "M": true
+/// }
+/// ```
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct BinanceAggTrade {
+    #[serde(alias = "a")]
+    pub id: u64,
+    #[serde(alias = "p", deserialize_with = "barter_integration::de::de_str")]
+    pub price: f64,
+    #[serde(alias = "q", deserialize_with = "barter_integration::de::de_str")]
+    pub amount: f64,
+    #[serde(alias = "T", deserialize_with = "de_u64_epoch_ms_as_datetime_utc")]
+    pub time: DateTime<Utc>,
+    #[serde(alias = "m", deserialize_with = "de_side_from_buyer_is_maker")]
+    pub side: Side,
+}
+
+/// Binance REST url of the provided endpoint (eg/ "klines").
+fn url(exchange: ExchangeId, endpoint: &str) -> String {
+    match exchange {
+        ExchangeId::BinanceFuturesUsd => format!("{HTTP_BASE_URL_BINANCE_FUTURES_USD}/{endpoint}"),
+        _ => format!("{HTTP_BASE_URL_BINANCE_SPOT}/{endpoint}"),
+    }
+}
+
+/// Binance kline interval of the provided [`Interval`].
+fn binance_interval(interval: Interval) -> &'static str {
+    match interval {
+        Interval::M1 => "1m",
+        Interval::M3 => "3m",
+        Interval::M5 => "5m",
+        Interval::M15 => "15m",
+        Interval::M30 => "30m",
+        Interval::H1 => "1h",
+        Interval::H2 => "2h",
+        Interval::H4 => "4h",
+        Interval::H6 => "6h",
+        Interval::H12 => "12h",
+        Interval::D1 => "1d",
+        Interval::W1 => "1w",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod de {
+        use super::*;
+        use barter_integration::de::datetime_utc_from_epoch_duration;
+        use std::time::Duration;
+
+        #[test]
+        fn test_binance_kline() {
+            let input = r#"
+            [
+                1499040000000, "0.01634790", "0.80000000", "0.01575800", "0.01577100",
+                "148976.11427815", 1499644799999, "2434.19055334", 308, "1756.87402397",
+                "28.46694368", "0"
+            ]
+            "#;
+
+            let actual = serde_json::from_str::<BinanceKline>(input).unwrap();
+            assert_eq!(
+                actual.0,
+                datetime_utc_from_epoch_duration(Duration::from_millis(1499040000000))
+            );
+            assert_eq!(
+                (actual.1, actual.2, actual.3, actual.4, actual.5, actual.8),
+                (0.0163479, 0.8, 0.015758, 0.015771, 148976.11427815, 308)
+            );
+        }
+
+        #[test]
+        fn test_binance_agg_trade() {
+            let input = r#"
+            {
+                "a": 26129,
+                "p": "0.01633102",
+                "q": "4.70443515",
+                "f": 27781,
+                "l": 27781,
+                "T": 1498793709153,
+                "m": true,
+                "M": true
+            }
+            "#;
+
+            assert_eq!(
+                serde_json::from_str::<BinanceAggTrade>(input).unwrap(),
+                BinanceAggTrade {
+                    id: 26129,
+                    price: 0.01633102,
+                    amount: 4.70443515,
+                    time: datetime_utc_from_epoch_duration(Duration::from_millis(1498793709153)),
+                    side: Side::Sell,
+                }
+            );
+        }
+    }
+
+    #[test]
+    fn test_url() {
+        assert_eq!(
+            url(ExchangeId::BinanceSpot, "klines"),
+            "https://api.binance.com/api/v3/klines"
+        );
+        assert_eq!(
+            url(ExchangeId::BinanceFuturesUsd, "aggTrades"),
+            "https://fapi.binance.com/fapi/v1/aggTrades"
+        );
+    }
+}
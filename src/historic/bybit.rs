@@ -0,0 +1,237 @@
+use super::{epoch_ms, get, HistoricFetcher, HistoricPage};
+use crate::{
+    error::DataError,
+    exchange::ExchangeId,
+    subscription::candle::{Candle, Interval},
+    util::time::de_str_epoch_ms_as_datetime_utc,
+};
+use async_trait::async_trait;
+use barter_integration::error::SocketError;
+use chrono::{DateTime, TimeDelta, Utc};
+use serde::{de::IgnoredAny, Deserialize};
+
+/// [`Bybit`](crate::exchange::bybit::Bybit) HTTP klines url.
+///
+/// See docs: <https://bybit-exchange.github.io/docs/v5/market/kline>
+pub const HTTP_KLINE_URL_BYBIT: &str = "https://api.bybit.com/v5/market/kline";
+
+/// Maximum number of klines returned per request.
+const KLINES_LIMIT: i32 = 1000;
+
+/// [`HistoricFetcher`] of [`Bybit`](crate::exchange::bybit::Bybit) [`Candle`]s via the klines
+/// REST endpoint.
+///
+/// Klines are returned newest first, so each page requests a fixed window of `1000` intervals.
+/// Each [`Candle`] `exchange_time` is its `close_time`, and the `trade_count` is always zero since
+/// it is not provided.
+#[derive(Clone, Debug)]
+pub struct BybitCandles {
+    client: reqwest::Client,
+    exchange: ExchangeId,
+    interval: Interval,
+}
+
+impl BybitCandles {
+    /// Construct a [`BybitCandles`] fetcher for `BybitSpot` markets.
+    pub fn spot(interval: Interval) -> Self {
+        Self {
+            client: reqwest::Client::new(),
+            exchange: ExchangeId::BybitSpot,
+            interval,
+        }
+    }
+
+    /// Construct a [`BybitCandles`] fetcher for `BybitPerpetualsUsd` markets.
+    pub fn perpetuals_usd(interval: Interval) -> Self {
+        Self {
+            client: reqwest::Client::new(),
+            exchange: ExchangeId::BybitPerpetualsUsd,
+            interval,
+        }
+    }
+
+    /// Bybit product category of the configured [`ExchangeId`].
+    fn category(&self) -> &'static str {
+        match self.exchange {
+            ExchangeId::BybitPerpetualsUsd => "linear",
+            _ => "spot",
+        }
+    }
+}
+
+#[async_trait]
+impl HistoricFetcher for BybitCandles {
+    type Event = Candle;
+
+    fn exchange(&self) -> ExchangeId {
+        self.exchange
+    }
+
+    async fn fetch_page(
+        &self,
+        market: &str,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> Result<HistoricPage<Candle>, DataError> {
+        // Klines are requested by open time, so offset the close time range by one interval
+        let interval = TimeDelta::from_std(self.interval.duration()).unwrap_or_default();
+        let window_end = end.min(start + interval * KLINES_LIMIT);
+
+        // "start" & "end" are inclusive bounds of the kline open time
+        let query = [
+            ("category", self.category().to_owned()),
+            ("symbol", market.to_owned()),
+            ("interval", bybit_interval(self.interval).to_owned()),
+            ("start", epoch_ms(start - interval).to_string()),
+            ("end", (epoch_ms(window_end - interval) - 1).to_string()),
+            ("limit", KLINES_LIMIT.to_string()),
+        ];
+
+        let response = get::<BybitResponse, _>(&self.client, HTTP_KLINE_URL_BYBIT, &query).await?;
+
+        if response.ret_code != 0 {
+            return Err(DataError::from(SocketError::Exchange(response.ret_msg)));
+        }
+
+        let candles = response
+            .result
+            .list
+            .into_iter()
+            .rev()
+            .map(|kline| {
+                let close_time = kline.0 + interval;
+                (
+                    close_time,
+                    Candle {
+                        close_time,
+                        open: kline.1,
+                        high: kline.2,
+                        low: kline.3,
+                        close: kline.4,
+                        volume: kline.5,
+                        trade_count: 0,
+                    },
+                )
+            })
+            .collect();
+
+        Ok(HistoricPage::from_window(candles, window_end, end))
+    }
+}
+
+/// [`Bybit`](crate::exchange::bybit::Bybit) klines REST response.
+///
+/// A `ret_code` other than `0` indicates an error described by the `ret_msg`.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://bybit-exchange.github.io/docs/v5/market/kline>
+/// ```json
+/// {
+///   "retCode": 0,
+///   "retMsg": "OK",
+///   "result": {
+///     "symbol": "BTCUSDT",
+///     "category": "spot",
+///     "list": [
+///       ["1670608800000", "17071", "17073", "17027", "17055.5", "268611", "15.74462667"]
+///     ]
+///   },
+///   "time": 1672025956592
+/// }
+/// ```
+#[derive(Clone, PartialEq, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BybitResponse {
+    pub ret_code: i64,
+    pub ret_msg: String,
+    pub result: BybitKlines,
+}
+
+/// [`Bybit`](crate::exchange::bybit::Bybit) klines, sorted newest first.
+#[derive(Clone, PartialEq, Debug, Default, Deserialize)]
+pub struct BybitKlines {
+    #[serde(default)]
+    pub list: Vec<BybitKline>,
+}
+
+/// [`Bybit`](crate::exchange::bybit::Bybit) kline.
+///
+/// Format: \[START_TIME, OPEN, HIGH, LOW, CLOSE, VOLUME, TURNOVER\]
+#[derive(Copy, Clone, PartialEq, Debug, Deserialize)]
+pub struct BybitKline(
+    #[serde(deserialize_with = "de_str_epoch_ms_as_datetime_utc")] pub DateTime<Utc>,
+    #[serde(deserialize_with = "barter_integration::de::de_str")] pub f64,
+    #[serde(deserialize_with = "barter_integration::de::de_str")] pub f64,
+    #[serde(deserialize_with = "barter_integration::de::de_str")] pub f64,
+    #[serde(deserialize_with = "barter_integration::de::de_str")] pub f64,
+    #[serde(deserialize_with = "barter_integration::de::de_str")] pub f64,
+    IgnoredAny,
+);
+
+/// [`Bybit`](crate::exchange::bybit::Bybit) kline interval of the provided [`Interval`].
+fn bybit_interval(interval: Interval) -> &'static str {
+    match interval {
+        Interval::M1 => "1",
+        Interval::M3 => "3",
+        Interval::M5 => "5",
+        Interval::M15 => "15",
+        Interval::M30 => "30",
+        Interval::H1 => "60",
+        Interval::H2 => "120",
+        Interval::H4 => "240",
+        Interval::H6 => "360",
+        Interval::H12 => "720",
+        Interval::D1 => "D",
+        Interval::W1 => "W",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod de {
+        use super::*;
+        use barter_integration::de::datetime_utc_from_epoch_duration;
+        use std::time::Duration;
+
+        #[test]
+        fn test_bybit_kline_response() {
+            let input = r#"
+            {
+                "retCode": 0,
+                "retMsg": "OK",
+                "result": {
+                    "symbol": "BTCUSDT",
+                    "category": "spot",
+                    "list": [
+                        ["1670608800000", "17071", "17073", "17027", "17055.5", "268611", "15.74462667"]
+                    ]
+                },
+                "time": 1672025956592
+            }
+            "#;
+
+            let actual = serde_json::from_str::<BybitResponse>(input).unwrap();
+            assert_eq!(actual.ret_code, 0);
+            let kline = &actual.result.list[0];
+            assert_eq!(
+                kline.0,
+                datetime_utc_from_epoch_duration(Duration::from_millis(1670608800000))
+            );
+            assert_eq!(
+                (kline.1, kline.2, kline.3, kline.4, kline.5),
+                (17071.0, 17073.0, 17027.0, 17055.5, 268611.0)
+            );
+        }
+
+        #[test]
+        fn test_bybit_error_response() {
+            let input = r#"{"retCode": 10001, "retMsg": "Invalid symbol", "result": {}}"#;
+
+            let actual = serde_json::from_str::<BybitResponse>(input).unwrap();
+            assert_eq!(actual.ret_code, 10001);
+            assert!(actual.result.list.is_empty());
+        }
+    }
+}
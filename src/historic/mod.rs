@@ -0,0 +1,523 @@
+use crate::{error::DataError, event::MarketEvent, exchange::ExchangeId, runtime::Spawner};
+use async_trait::async_trait;
+use barter_integration::{error::SocketError, model::Exchange};
+use chrono::{DateTime, TimeDelta, Utc};
+use futures::{Stream, StreamExt};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use tokio::sync::mpsc;
+use tokio_stream::wrappers::UnboundedReceiverStream;
+use tracing::debug;
+
+/// [`Binance`](crate::exchange::binance) historical klines & aggregated trades fetchers.
+pub mod binance;
+
+/// [`Bybit`](crate::exchange::bybit) historical klines fetcher.
+pub mod bybit;
+
+/// [`Okx`](crate::exchange::okx) historical candlesticks fetcher.
+pub mod okx;
+
+/// Request for the historical [`MarketEvent`]s of a market, with an `exchange_time` in the range
+/// `[start, end)`.
+#[derive(Clone, Eq, PartialEq, Hash, Debug, Deserialize, Serialize)]
+pub struct HistoricRequest<InstrumentId> {
+    /// Exchange specific market symbol (eg/ "BTCUSDT", "BTC-USDT").
+    pub market: String,
+    /// Instrument identifier of every yielded [`MarketEvent`].
+    pub instrument: InstrumentId,
+    pub start: DateTime<Utc>,
+    pub end: DateTime<Utc>,
+}
+
+impl<InstrumentId> HistoricRequest<InstrumentId> {
+    /// Construct a new [`HistoricRequest`] for the `[start, end)` range of the provided market.
+    pub fn new<S>(
+        market: S,
+        instrument: InstrumentId,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> Self
+    where
+        S: Into<String>,
+    {
+        Self {
+            market: market.into(),
+            instrument,
+            start,
+            end,
+        }
+    }
+}
+
+/// Page of historical events fetched by a [`HistoricFetcher`].
+#[derive(Clone, PartialEq, Debug)]
+pub struct HistoricPage<Event> {
+    /// `(exchange_time, event)` pairs, sorted by ascending `exchange_time`.
+    pub events: Vec<(DateTime<Utc>, Event)>,
+    /// Start of the next page, or `None` if the requested range has been exhausted.
+    ///
+    /// The next page may start at the `exchange_time` of the last event in this page, since any
+    /// events that are fetched twice are de-duplicated.
+    pub next: Option<DateTime<Utc>>,
+}
+
+impl<Event> HistoricPage<Event> {
+    /// Construct a [`HistoricPage`] of `limit` sized pages, where a full page continues from the
+    /// `exchange_time` of its last event, and a partial page ends the requested range.
+    pub fn from_limit(events: Vec<(DateTime<Utc>, Event)>, limit: usize) -> Self {
+        let next = (events.len() >= limit)
+            .then(|| events.last().map(|(time, _)| *time))
+            .flatten();
+
+        Self { events, next }
+    }
+
+    /// Construct a [`HistoricPage`] of fixed time windows, where the next page starts at the end
+    /// of this window, unless it reaches the end of the requested range.
+    pub fn from_window(
+        events: Vec<(DateTime<Utc>, Event)>,
+        window_end: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            events,
+            next: (window_end < end).then_some(window_end),
+        }
+    }
+}
+
+/// Exchange REST API that historical market data can be fetched from, one page at a time.
+#[async_trait]
+pub trait HistoricFetcher: Send + Sync {
+    type Event: Send;
+
+    /// [`ExchangeId`] of the exchange this fetcher requests data from.
+    fn exchange(&self) -> ExchangeId;
+
+    /// Fetch the page of events for the provided market, with an `exchange_time` starting at
+    /// `start`, and strictly before `end`.
+    async fn fetch_page(
+        &self,
+        market: &str,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> Result<HistoricPage<Self::Event>, DataError>;
+}
+
+/// Fetch every historical [`MarketEvent`] of the [`HistoricRequest`] in ascending
+/// `exchange_time` order, paginating through the exchange REST API until the range is exhausted.
+///
+/// The [`Stream`] ends after yielding the first [`DataError`] encountered.
+pub fn fetch_historic<Fetcher, InstrumentId>(
+    fetcher: Fetcher,
+    request: HistoricRequest<InstrumentId>,
+) -> impl Stream<Item = Result<MarketEvent<InstrumentId, Fetcher::Event>, DataError>>
+where
+    Fetcher: HistoricFetcher,
+    Fetcher::Event: Clone + PartialEq,
+    InstrumentId: Clone,
+{
+    futures::stream::unfold(
+        Paginator::new(fetcher, request),
+        |mut paginator| async move {
+            let events = match paginator.next_page().await? {
+                Ok(events) => events.into_iter().map(Ok).collect(),
+                Err(error) => vec![Err(error)],
+            };
+            Some((futures::stream::iter(events), paginator))
+        },
+    )
+    .flatten()
+}
+
+/// Backfill the historical [`MarketEvent`]s of the [`HistoricRequest`], then continue with the
+/// provided live [`Stream`] (eg/ a [`MarketStream`](crate::MarketStream) for the same market).
+///
+/// The transition is stitched so that no events are missed or duplicated:
+/// - Once the `[start, end)` backfill is complete, the gap between the last backfilled event and
+///   the first live event is filled by fetching that range too.
+/// - Live events already yielded by the backfill (eg/ buffered whilst backfilling) are skipped.
+///
+/// The live [`Stream`] should therefore be initialised before calling this function, and `end`
+/// will typically be [`Utc::now`]. The transition is driven by a task spawned using the provided
+/// [`Spawner`].
+pub fn backfill_then_stream<Fetcher, InstrumentId, Live>(
+    fetcher: Fetcher,
+    request: HistoricRequest<InstrumentId>,
+    live: Live,
+    spawner: &Spawner,
+) -> UnboundedReceiverStream<Result<MarketEvent<InstrumentId, Fetcher::Event>, DataError>>
+where
+    Fetcher: HistoricFetcher + 'static,
+    Fetcher::Event: Clone + PartialEq + 'static,
+    InstrumentId: Clone + Send + 'static,
+    Live: Stream<Item = Result<MarketEvent<InstrumentId, Fetcher::Event>, DataError>>
+        + Send
+        + Unpin
+        + 'static,
+{
+    let (tx, rx) = mpsc::unbounded_channel();
+    spawner.spawn(Box::pin(backfill(
+        Paginator::new(fetcher, request),
+        live,
+        tx,
+    )));
+    UnboundedReceiverStream::new(rx)
+}
+
+/// Drive the backfill & live stream transition of [`backfill_then_stream`], sending the output
+/// until it is dropped.
+async fn backfill<Fetcher, InstrumentId, Live>(
+    mut paginator: Paginator<Fetcher, InstrumentId>,
+    mut live: Live,
+    tx: mpsc::UnboundedSender<Result<MarketEvent<InstrumentId, Fetcher::Event>, DataError>>,
+) where
+    Fetcher: HistoricFetcher,
+    Fetcher::Event: Clone + PartialEq,
+    InstrumentId: Clone,
+    Live: Stream<Item = Result<MarketEvent<InstrumentId, Fetcher::Event>, DataError>> + Unpin,
+{
+    // Backfill the requested range
+    if drain(&mut paginator, &tx).await.is_err() {
+        debug!(why = "output dropped", "ending historic backfill");
+        return;
+    }
+
+    let mut gap_filled = false;
+    while let Some(event) = live.next().await {
+        // Backfill the gap between the last backfilled event and the first live event
+        if let (false, Ok(first)) = (gap_filled, &event) {
+            gap_filled = true;
+            paginator.extend(first.exchange_time);
+            if drain(&mut paginator, &tx).await.is_err() {
+                debug!(why = "output dropped", "ending historic backfill");
+                return;
+            }
+        }
+
+        let event = match event {
+            Ok(event) if !paginator.stitch.admit(event.exchange_time, &event.kind) => continue,
+            event => event,
+        };
+
+        if tx.send(event).is_err() {
+            debug!(
+                why = "output dropped",
+                "ending live stream after historic backfill"
+            );
+            return;
+        }
+    }
+}
+
+/// Send every remaining page of the [`Paginator`], returning an error if the output is dropped.
+async fn drain<Fetcher, InstrumentId>(
+    paginator: &mut Paginator<Fetcher, InstrumentId>,
+    tx: &mpsc::UnboundedSender<Result<MarketEvent<InstrumentId, Fetcher::Event>, DataError>>,
+) -> Result<(), ()>
+where
+    Fetcher: HistoricFetcher,
+    Fetcher::Event: Clone + PartialEq,
+    InstrumentId: Clone,
+{
+    while let Some(events) = paginator.next_page().await {
+        match events {
+            Ok(events) => events
+                .into_iter()
+                .try_for_each(|event| tx.send(Ok(event)))
+                .map_err(|_| ())?,
+            Err(error) => tx.send(Err(error)).map_err(|_| ())?,
+        }
+    }
+    Ok(())
+}
+
+/// Paginates through a [`HistoricFetcher`], converting each page into de-duplicated
+/// [`MarketEvent`]s.
+#[derive(Debug)]
+struct Paginator<Fetcher, InstrumentId>
+where
+    Fetcher: HistoricFetcher,
+{
+    fetcher: Fetcher,
+    exchange: Exchange,
+    market: String,
+    instrument: InstrumentId,
+    cursor: Option<DateTime<Utc>>,
+    end: DateTime<Utc>,
+    stitch: Stitch<Fetcher::Event>,
+}
+
+impl<Fetcher, InstrumentId> Paginator<Fetcher, InstrumentId>
+where
+    Fetcher: HistoricFetcher,
+    Fetcher::Event: Clone + PartialEq,
+    InstrumentId: Clone,
+{
+    fn new(fetcher: Fetcher, request: HistoricRequest<InstrumentId>) -> Self {
+        Self {
+            exchange: Exchange::from(fetcher.exchange()),
+            fetcher,
+            market: request.market,
+            instrument: request.instrument,
+            cursor: (request.start < request.end).then_some(request.start),
+            end: request.end,
+            stitch: Stitch::default(),
+        }
+    }
+
+    /// Extend the paginated range up until the provided `end`, continuing from the last yielded
+    /// event.
+    fn extend(&mut self, end: DateTime<Utc>) {
+        let start = self.stitch.last.unwrap_or(self.end).min(self.end);
+        self.cursor = (start < end).then_some(start);
+        self.end = end;
+    }
+
+    /// Fetch the next page of [`MarketEvent`]s, or `None` if the range has been exhausted.
+    ///
+    /// Pagination ends after a failed fetch.
+    async fn next_page(
+        &mut self,
+    ) -> Option<Result<Vec<MarketEvent<InstrumentId, Fetcher::Event>>, DataError>> {
+        let start = self.cursor?;
+
+        let page = match self.fetcher.fetch_page(&self.market, start, self.end).await {
+            Ok(page) => page,
+            Err(error) => {
+                self.cursor = None;
+                return Some(Err(error));
+            }
+        };
+
+        let received_time = Utc::now();
+        let events = page
+            .events
+            .into_iter()
+            .filter(|(time, event)| {
+                *time >= start && *time < self.end && self.stitch.admit(*time, event)
+            })
+            .map(|(exchange_time, kind)| MarketEvent {
+                exchange_time,
+                received_time,
+                exchange: self.exchange.clone(),
+                instrument: self.instrument.clone(),
+                kind,
+            })
+            .collect::<Vec<_>>();
+
+        // A full page that only contains events already yielded at its start cannot make
+        // progress, so continue from the next millisecond (the finest exchange time resolution)
+        self.cursor = page.next.map(|next| next.max(start)).map(|next| {
+            match next == start && events.is_empty() {
+                true => {
+                    debug!(%start, "historic page made no progress, skipping to next millisecond");
+                    start + TimeDelta::milliseconds(1)
+                }
+                false => next,
+            }
+        });
+
+        Some(Ok(events))
+    }
+}
+
+/// De-duplicates events that are yielded more than once at page (or backfill & live stream)
+/// boundaries.
+#[derive(Debug)]
+struct Stitch<Event> {
+    /// Latest `exchange_time` yielded.
+    last: Option<DateTime<Utc>>,
+    /// Events yielded with the latest `exchange_time`.
+    boundary: Vec<Event>,
+}
+
+impl<Event> Default for Stitch<Event> {
+    fn default() -> Self {
+        Self {
+            last: None,
+            boundary: Vec::new(),
+        }
+    }
+}
+
+impl<Event> Stitch<Event>
+where
+    Event: Clone + PartialEq,
+{
+    /// Determine if the event should be yielded, recording it if so.
+    ///
+    /// Events before the latest yielded `exchange_time`, or equal to an event already yielded at
+    /// that time, are rejected.
+    fn admit(&mut self, time: DateTime<Utc>, event: &Event) -> bool {
+        match self.last {
+            Some(last) if time < last => return false,
+            Some(last) if time == last => {
+                if self.boundary.contains(event) {
+                    return false;
+                }
+            }
+            _ => {
+                self.last = Some(time);
+                self.boundary.clear();
+            }
+        }
+
+        self.boundary.push(event.clone());
+        true
+    }
+}
+
+/// Send the HTTP GET request with the provided query parameters, deserialising the response body
+/// as `T`.
+async fn get<T, Query>(client: &reqwest::Client, url: &str, query: &Query) -> Result<T, DataError>
+where
+    T: DeserializeOwned,
+    Query: Serialize + ?Sized,
+{
+    client
+        .get(url)
+        .query(query)
+        .send()
+        .await
+        .and_then(reqwest::Response::error_for_status)
+        .map_err(SocketError::from)?
+        .json::<T>()
+        .await
+        .map_err(|error| DataError::from(SocketError::from(error)))
+}
+
+/// Milliseconds since the Unix epoch of the provided [`DateTime<Utc>`].
+fn epoch_ms(time: DateTime<Utc>) -> i64 {
+    time.timestamp_millis()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::{Arc, Mutex};
+
+    /// [`HistoricFetcher`] of `(epoch_seconds, id)` events, returning pages of at most `limit`.
+    #[derive(Clone)]
+    struct MockFetcher {
+        events: Vec<(i64, u64)>,
+        limit: usize,
+        requests: Arc<Mutex<Vec<(i64, i64)>>>,
+    }
+
+    #[async_trait]
+    impl HistoricFetcher for MockFetcher {
+        type Event = u64;
+
+        fn exchange(&self) -> ExchangeId {
+            ExchangeId::BinanceSpot
+        }
+
+        async fn fetch_page(
+            &self,
+            _: &str,
+            start: DateTime<Utc>,
+            end: DateTime<Utc>,
+        ) -> Result<HistoricPage<u64>, DataError> {
+            self.requests
+                .lock()
+                .unwrap()
+                .push((start.timestamp_millis(), end.timestamp_millis()));
+
+            let events = self
+                .events
+                .iter()
+                .filter(|(time, _)| {
+                    let time = time * 1000;
+                    time >= start.timestamp_millis() && time < end.timestamp_millis()
+                })
+                .take(self.limit)
+                .map(|(time, id)| (time_s(*time), *id))
+                .collect();
+
+            Ok(HistoricPage::from_limit(events, self.limit))
+        }
+    }
+
+    fn time_s(seconds: i64) -> DateTime<Utc> {
+        DateTime::from_timestamp(seconds, 0).unwrap()
+    }
+
+    fn mock(events: Vec<(i64, u64)>, limit: usize) -> MockFetcher {
+        MockFetcher {
+            events,
+            limit,
+            requests: Arc::default(),
+        }
+    }
+
+    fn live_event(seconds: i64, id: u64) -> Result<MarketEvent<&'static str, u64>, DataError> {
+        Ok(MarketEvent {
+            exchange_time: time_s(seconds),
+            received_time: Utc::now(),
+            exchange: Exchange::from(ExchangeId::BinanceSpot),
+            instrument: "btc_usdt",
+            kind: id,
+        })
+    }
+
+    #[test]
+    fn test_stitch_admit() {
+        let mut stitch = Stitch::default();
+
+        // TC0: first event is admitted
+        assert!(stitch.admit(time_s(1), &1));
+        // TC1: duplicate event at the boundary is rejected
+        assert!(!stitch.admit(time_s(1), &1));
+        // TC2: distinct event at the boundary is admitted
+        assert!(stitch.admit(time_s(1), &2));
+        // TC3: later event is admitted
+        assert!(stitch.admit(time_s(2), &3));
+        // TC4: earlier event is rejected
+        assert!(!stitch.admit(time_s(1), &4));
+    }
+
+    #[tokio::test]
+    async fn test_fetch_historic() {
+        // Three events share t=2, spanning a page boundary
+        let fetcher = mock(vec![(1, 1), (2, 2), (2, 3), (2, 4), (3, 5), (9, 6)], 3);
+        let requests = fetcher.requests.clone();
+
+        let actual = fetch_historic(
+            fetcher,
+            HistoricRequest::new("BTCUSDT", "btc_usdt", time_s(0), time_s(9)),
+        )
+        .map(|event| event.unwrap().kind)
+        .collect::<Vec<_>>()
+        .await;
+
+        assert_eq!(actual, vec![1, 2, 3, 4, 5]);
+        assert_eq!(
+            *requests.lock().unwrap(),
+            vec![(0, 9000), (2000, 9000), (2000, 9000), (2001, 9000)]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_backfill_then_stream() {
+        let fetcher = mock(vec![(1, 1), (2, 2), (3, 3), (4, 4), (5, 5)], 10);
+        let requests = fetcher.requests.clone();
+
+        // Live stream started at t=3, so overlaps the backfill end, and leaves a gap until t=5
+        let live =
+            futures::stream::iter(vec![live_event(5, 5), live_event(2, 2), live_event(6, 6)]);
+
+        let actual = backfill_then_stream(
+            fetcher,
+            HistoricRequest::new("BTCUSDT", "btc_usdt", time_s(0), time_s(3)),
+            live,
+            &Spawner::default(),
+        )
+        .map(|event| event.unwrap().kind)
+        .collect::<Vec<_>>()
+        .await;
+
+        assert_eq!(actual, vec![1, 2, 3, 4, 5, 6]);
+        assert_eq!(*requests.lock().unwrap(), vec![(0, 3000), (2000, 5000)]);
+    }
+}
@@ -0,0 +1,193 @@
+use super::{epoch_ms, get, HistoricFetcher, HistoricPage};
+use crate::{
+    error::DataError,
+    exchange::ExchangeId,
+    subscription::candle::{Candle, Interval},
+    util::time::de_str_epoch_ms_as_datetime_utc,
+};
+use async_trait::async_trait;
+use barter_integration::error::SocketError;
+use chrono::{DateTime, TimeDelta, Utc};
+use serde::{de::IgnoredAny, Deserialize};
+
+/// [`Okx`](crate::exchange::okx::Okx) HTTP historical candlesticks url.
+///
+/// See docs: <https://www.okx.com/docs-v5/en/#order-book-trading-market-data-get-candlesticks-history>
+pub const HTTP_HISTORY_CANDLES_URL_OKX: &str = "https://www.okx.com/api/v5/market/history-candles";
+
+/// Maximum number of candlesticks returned per request.
+const CANDLES_LIMIT: i32 = 100;
+
+/// [`HistoricFetcher`] of [`Okx`](crate::exchange::okx::Okx) [`Candle`]s via the historical
+/// candlesticks REST endpoint.
+///
+/// Candlesticks are returned newest first and paginated backwards in time, so each page requests
+/// a fixed window of `100` intervals. Each [`Candle`] `exchange_time` is its `close_time`, and the
+/// `trade_count` is always zero since it is not provided.
+#[derive(Clone, Debug)]
+pub struct OkxCandles {
+    client: reqwest::Client,
+    interval: Interval,
+}
+
+impl OkxCandles {
+    /// Construct a new [`OkxCandles`] fetcher for the provided [`Interval`].
+    pub fn new(interval: Interval) -> Self {
+        Self {
+            client: reqwest::Client::new(),
+            interval,
+        }
+    }
+}
+
+#[async_trait]
+impl HistoricFetcher for OkxCandles {
+    type Event = Candle;
+
+    fn exchange(&self) -> ExchangeId {
+        ExchangeId::Okx
+    }
+
+    async fn fetch_page(
+        &self,
+        market: &str,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> Result<HistoricPage<Candle>, DataError> {
+        // Candlesticks are requested by open time, so offset the close time range by one interval
+        let interval = TimeDelta::from_std(self.interval.duration()).unwrap_or_default();
+        let window_end = end.min(start + interval * CANDLES_LIMIT);
+
+        // "before" & "after" are exclusive bounds of the candlestick open time
+        let query = [
+            ("instId", market.to_owned()),
+            ("bar", okx_bar(self.interval).to_owned()),
+            ("before", (epoch_ms(start - interval) - 1).to_string()),
+            ("after", epoch_ms(window_end - interval).to_string()),
+            ("limit", CANDLES_LIMIT.to_string()),
+        ];
+
+        let response =
+            get::<OkxResponse<OkxCandle>, _>(&self.client, HTTP_HISTORY_CANDLES_URL_OKX, &query)
+                .await?;
+
+        if response.code != "0" {
+            return Err(DataError::from(SocketError::Exchange(response.msg)));
+        }
+
+        let candles = response
+            .data
+            .into_iter()
+            .rev()
+            .map(|candle| {
+                let close_time = candle.0 + interval;
+                (
+                    close_time,
+                    Candle {
+                        close_time,
+                        open: candle.1,
+                        high: candle.2,
+                        low: candle.3,
+                        close: candle.4,
+                        volume: candle.5,
+                        trade_count: 0,
+                    },
+                )
+            })
+            .collect();
+
+        Ok(HistoricPage::from_window(candles, window_end, end))
+    }
+}
+
+/// [`Okx`](crate::exchange::okx::Okx) REST response.
+///
+/// A `code` other than "0" indicates an error described by the `msg`.
+#[derive(Clone, PartialEq, Debug, Deserialize)]
+pub struct OkxResponse<T> {
+    pub code: String,
+    pub msg: String,
+    pub data: Vec<T>,
+}
+
+/// [`Okx`](crate::exchange::okx::Okx) historical candlestick.
+///
+/// Format: \[TS, OPEN, HIGH, LOW, CLOSE, VOL, VOL_CCY, VOL_CCY_QUOTE, CONFIRM\], <br> where TS is
+/// the interval start time.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://www.okx.com/docs-v5/en/#order-book-trading-market-data-get-candlesticks-history>
+/// ```json
+/// ["1597026383085", "3.721", "3.743", "3.677", "3.708", "8422410", "22698348.04828491",
+///  "12698348.04828491", "1"]
+/// ```
+#[derive(Copy, Clone, PartialEq, Debug, Deserialize)]
+pub struct OkxCandle(
+    #[serde(deserialize_with = "de_str_epoch_ms_as_datetime_utc")] pub DateTime<Utc>,
+    #[serde(deserialize_with = "barter_integration::de::de_str")] pub f64,
+    #[serde(deserialize_with = "barter_integration::de::de_str")] pub f64,
+    #[serde(deserialize_with = "barter_integration::de::de_str")] pub f64,
+    #[serde(deserialize_with = "barter_integration::de::de_str")] pub f64,
+    #[serde(deserialize_with = "barter_integration::de::de_str")] pub f64,
+    IgnoredAny,
+    IgnoredAny,
+    IgnoredAny,
+);
+
+/// [`Okx`](crate::exchange::okx::Okx) candlestick bar of the provided [`Interval`], using the
+/// UTC aligned bars for intervals of six hours or more.
+fn okx_bar(interval: Interval) -> &'static str {
+    match interval {
+        Interval::M1 => "1m",
+        Interval::M3 => "3m",
+        Interval::M5 => "5m",
+        Interval::M15 => "15m",
+        Interval::M30 => "30m",
+        Interval::H1 => "1H",
+        Interval::H2 => "2H",
+        Interval::H4 => "4H",
+        Interval::H6 => "6Hutc",
+        Interval::H12 => "12Hutc",
+        Interval::D1 => "1Dutc",
+        Interval::W1 => "1Wutc",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod de {
+        use super::*;
+        use barter_integration::de::datetime_utc_from_epoch_duration;
+        use std::time::Duration;
+
+        #[test]
+        fn test_okx_history_candles() {
+            let input = r#"
+            {
+                "code": "0",
+                "msg": "",
+                "data": [
+                    [
+                        "1597026383085", "3.721", "3.743", "3.677", "3.708", "8422410",
+                        "22698348.04828491", "12698348.04828491", "1"
+                    ]
+                ]
+            }
+            "#;
+
+            let actual = serde_json::from_str::<OkxResponse<OkxCandle>>(input).unwrap();
+            assert_eq!(actual.code, "0");
+            let candle = &actual.data[0];
+            assert_eq!(
+                candle.0,
+                datetime_utc_from_epoch_duration(Duration::from_millis(1597026383085))
+            );
+            assert_eq!(
+                (candle.1, candle.2, candle.3, candle.4, candle.5),
+                (3.721, 3.743, 3.677, 3.708, 8422410.0)
+            );
+        }
+    }
+}
@@ -1,6 +1,7 @@
-#![forbid(unsafe_code)]
+#![cfg_attr(not(feature = "ffi"), forbid(unsafe_code))]
+#![cfg_attr(feature = "ffi", deny(unsafe_code))]
 #![warn(clippy::all)]
-#![allow(clippy::pedantic, clippy::type_complexity)]
+#![allow(clippy::pedantic, clippy::type_complexity, clippy::result_large_err)]
 #![warn(
     missing_debug_implementations,
     missing_copy_implementations,
@@ -75,7 +76,7 @@
 //!     // Join all exchange PublicTrades streams into a single tokio_stream::StreamMap
 //!     // Notes:
 //!     //  - Use `streams.select(ExchangeId)` to interact with the individual exchange streams!
-//!     //  - Use `streams.join()` to join all exchange streams into a single mpsc::UnboundedReceiver!
+//!     //  - Use `streams.join()` to join all exchange streams into a single Stream!
 //!     let mut joined_stream = streams.join_map().await;
 //!
 //!     while let Some((exchange, trade)) = joined_stream.next().await {
@@ -84,23 +85,51 @@
 //! }
 //! ```
 
+// The WebSocket transport, channels & timers are provided by Tokio, so only task spawning can be
+// customised (see runtime::Spawn). Fail at compile time rather than panic upon spawning.
+#[cfg(not(feature = "tokio"))]
+compile_error!(
+    "barter-data requires the \"tokio\" feature: the WebSocket transport, channels & timers are \
+     provided by Tokio. Use runtime::Spawner to customise how background tasks are spawned."
+);
+
 use crate::instrument::InstrumentData;
 use crate::{
     error::DataError,
     event::MarketEvent,
-    exchange::{Connector, ExchangeId, PingInterval},
-    subscriber::Subscriber,
-    subscription::{Subscription, SubscriptionKind},
+    exchange::{Connector, ExchangeId, Keepalive, PongTimeout},
+    streams::options::StreamOptions,
+    subscriber::{validator::SubscriptionRejection, Subscriber},
+    subscription::{Map, Subscription, SubscriptionKind},
     transformer::ExchangeTransformer,
 };
 use async_trait::async_trait;
 use barter_integration::{
-    protocol::websocket::{WebSocketParser, WsMessage, WsSink, WsStream},
+    protocol::{
+        websocket::{WebSocket, WebSocketParser, WsError, WsMessage, WsSink, WsStream},
+        StreamParser,
+    },
     ExchangeStream,
 };
 use futures::{SinkExt, Stream, StreamExt};
-use tokio::sync::mpsc;
-use tracing::{debug, error};
+use std::{
+    collections::VecDeque,
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+    time::Duration,
+};
+use tokio::{sync::mpsc, time::Sleep};
+use tracing::{debug, error, warn};
+
+/// Columnar [`ColumnBatch`](columnar::ColumnBatch)es of normalised [`MarketEvent`]s, laid out
+/// like Apache Arrow `RecordBatch`es for in-memory analytics pipelines (& convertible into them
+/// via the `arrow` feature).
+pub mod columnar;
+
+/// [`InstrumentDiscovery`](discovery::InstrumentDiscovery) cache of the markets listed by each
+/// exchange, used to validate [`Subscription`]s with nearest-match suggestions.
+pub mod discovery;
 
 /// All [`Error`](std::error::Error)s generated in Barter-Data.
 pub mod error;
@@ -122,6 +151,50 @@ pub mod streams;
 /// Standard implementations for subscribing to WebSocket [`MarketStream`]s are included.
 pub mod subscriber;
 
+/// [`PollStream`](poll::PollStream) [`MarketStream`] used for market data that is only available
+/// via periodically polling exchange REST APIs.
+pub mod poll;
+
+/// Historical [`MarketEvent`]s fetched from exchange REST APIs (eg/ klines & trades), that can be
+/// backfilled ahead of a live [`MarketStream`] (see [`historic::backfill_then_stream`]).
+pub mod historic;
+
+/// C ABI for embedding Barter-Data in other languages (eg/ C, C++, C#), delivering normalised
+/// [`MarketEvent`]s as JSON to a callback via opaque [`BarterStream`](ffi::BarterStream) handles.
+///
+/// The C header is generated by `cbindgen` into `include/barter_data.h` (see `cbindgen.toml`),
+/// and a C compatible library can be built via
+/// `cargo rustc --release --features ffi --crate-type cdylib` (or `staticlib`).
+#[cfg(feature = "ffi")]
+pub mod ffi;
+
+/// FlatBuffers encoding of the normalised models (eg/ [`MarketEvent`]), providing a zero-copy
+/// wire format for ultra-low-latency IPC consumers.
+///
+/// Tables are encoded by hand to mirror `fbs/barter/data/v1/market_event.fbs`, so building
+/// Barter-Data does not require `flatc`. See `benches/encoding.rs` for a comparison against
+/// `serde_json`.
+#[cfg(feature = "flatbuffers")]
+pub mod flatbuffers;
+
+/// Stable protobuf messages mirroring the normalised models (eg/ [`MarketEvent`]), with `prost`
+/// conversions, providing a cross-language wire format for sinks & servers.
+///
+/// Messages are maintained by hand to mirror `proto/barter/data/v1/market_data.proto`, so
+/// building Barter-Data does not require `protoc`.
+#[cfg(feature = "proto")]
+pub mod proto;
+
+/// Recorders that capture normalised [`MarketEvent`]s to storage (eg/ partitioned Parquet files).
+pub mod recorder;
+
+/// Servers that expose normalised [`MarketEvent`]s to other services (eg/ via gRPC).
+pub mod server;
+
+/// Sinks that publish normalised [`MarketEvent`]s to external systems (eg/ Apache Kafka), plus
+/// the shared batching utilities they use.
+pub mod sink;
+
 /// Types that communicate the type of each [`MarketStream`] to initialise, and what normalised
 /// Barter output type the exchange will be transformed into.
 pub mod subscription;
@@ -129,21 +202,33 @@ pub mod subscription;
 /// [`InstrumentData`] trait for instrument describing data.
 pub mod instrument;
 
+/// Market data quality monitoring that generates per-instrument
+/// [`QualityReport`](quality::QualityReport)s over a run or a recorded file.
+pub mod quality;
+
+/// [`Spawn`](runtime::Spawn) executor abstraction used to spawn every Barter-Data background
+/// task, defaulting to [`tokio::spawn`].
+pub mod runtime;
+
 /// Generic [`ExchangeTransformer`] implementations used by [`MarketStream`]s to translate exchange
 /// specific types to normalised Barter types.
 ///
 /// Standard implementations that work for most exchanges are included such as: <br>
 /// - [`StatelessTransformer`](transformer::stateless::StatelessTransformer) for
-///   [`PublicTrades`](subscription::trade::PublicTrades)
-///   and [`OrderBooksL1`](subscription::book::OrderBooksL1) streams. <br>
+///   [`PublicTrades`](subscription::trade::PublicTrades),
+///   [`OrderBooksL1`](subscription::book::OrderBooksL1) and
+///   [`OrderBooksL3`](subscription::book::OrderBooksL3) streams. <br>
 /// - [`MultiBookTransformer`](transformer::book::MultiBookTransformer) for
-///   [`OrderBooksL2`](subscription::book::OrderBooksL2) and
-///   [`OrderBooksL3`](subscription::book::OrderBooksL3) streams.
+///   [`OrderBooksL2`](subscription::book::OrderBooksL2) streams.
 pub mod transformer;
 
+/// Shared utilities, such as exchange epoch timestamp normalisation.
+pub mod util;
+
 /// Convenient type alias for an [`ExchangeStream`] utilising a tungstenite
 /// [`WebSocket`](barter_integration::protocol::websocket::WebSocket).
-pub type ExchangeWsStream<Transformer> = ExchangeStream<WebSocketParser, WsStream, Transformer>;
+pub type ExchangeWsStream<Transformer> =
+    ExchangeStream<WebSocketParser, PongTimeoutStream<WsStream>, Transformer>;
 
 /// Defines a generic identification type for the implementor.
 pub trait Identifier<T> {
@@ -152,6 +237,9 @@ pub trait Identifier<T> {
 
 /// [`Stream`] that yields [`Market<Kind>`](MarketEvent) events. The type of [`Market<Kind>`](MarketEvent)
 /// depends on the provided [`SubscriptionKind`] of the passed [`Subscription`]s.
+///
+/// Each connection is initialised with the [`StreamOptions`] configured via the
+/// [`StreamBuilder`](streams::builder::StreamBuilder).
 #[async_trait]
 pub trait MarketStream<Exchange, Instrument, Kind>
 where
@@ -163,8 +251,16 @@ where
     Instrument: InstrumentData,
     Kind: SubscriptionKind,
 {
+    /// Determines if the [`MarketStream`] is finite (eg/ a replay of recorded events).
+    ///
+    /// Once a finite [`MarketStream`] ends, it is not re-initialised by the
+    /// [`consumer`](streams::consumer) loops, which instead end with a
+    /// [`StreamEndReason::Finished`](event::StreamEndReason::Finished) terminal event.
+    const FINITE: bool = false;
+
     async fn init(
         subscriptions: &[Subscription<Exchange, Instrument, Kind>],
+        options: &StreamOptions,
     ) -> Result<Self, DataError>
     where
         Subscription<Exchange, Instrument, Kind>:
@@ -183,39 +279,98 @@ where
 {
     async fn init(
         subscriptions: &[Subscription<Exchange, Instrument, Kind>],
+        options: &StreamOptions,
     ) -> Result<Self, DataError>
     where
         Subscription<Exchange, Instrument, Kind>:
             Identifier<Exchange::Channel> + Identifier<Exchange::Market>,
     {
         // Connect & subscribe
-        let (websocket, map) = Exchange::Subscriber::subscribe(subscriptions).await?;
+        let (websocket, map, buffered, rejections) =
+            Exchange::Subscriber::subscribe(subscriptions, options).await?;
+
+        init_subscribed::<Exchange, Instrument, Kind, Transformer>(
+            websocket, map, buffered, rejections, options,
+        )
+        .await
+    }
+}
 
-        // Split WebSocket into WsStream & WsSink components
-        let (ws_sink, ws_stream) = websocket.split();
+/// Initialise an [`ExchangeWsStream`] from an already subscribed [`WebSocket`], the validated
+/// instrument [`Map`], any market data [`WsMessage`]s buffered during validation, and any
+/// [`SubscriptionRejection`]s, which are yielded first as non-terminal
+/// [`DataError::SubscriptionRejected`]s. The [`Transformer`](ExchangeTransformer) is constructed
+/// using the provided [`StreamOptions`].
+///
+/// Used by [`MarketStream::init`] once the [`Connector::Subscriber`] has subscribed, and useful
+/// for driving the full pipeline against a non-default (eg/ mock) exchange server.
+pub async fn init_subscribed<Exchange, Instrument, Kind, Transformer>(
+    websocket: WebSocket,
+    map: Map<Instrument::Id>,
+    buffered: Vec<WsMessage>,
+    rejections: Vec<SubscriptionRejection>,
+    options: &StreamOptions,
+) -> Result<ExchangeWsStream<Transformer>, DataError>
+where
+    Exchange: Connector + Send + Sync,
+    Instrument: InstrumentData,
+    Kind: SubscriptionKind + Send + Sync,
+    Transformer: ExchangeTransformer<Exchange, Instrument::Id, Kind> + Send,
+    Kind::Event: Send,
+{
+    // Split WebSocket into WsStream & WsSink components
+    let (ws_sink, ws_stream) = websocket.split();
 
-        // Spawn task to distribute Transformer messages (eg/ custom pongs) to the exchange
-        let (ws_sink_tx, ws_sink_rx) = mpsc::unbounded_channel();
-        tokio::spawn(distribute_messages_to_exchange(
+    // Spawn task to distribute Transformer messages (eg/ custom pongs) to the exchange
+    let (ws_sink_tx, ws_sink_rx) = mpsc::unbounded_channel();
+    options.spawner.spawn(distribute_messages_to_exchange(
+        Exchange::ID,
+        ws_sink,
+        ws_sink_rx,
+    ));
+
+    // Spawn optional task to distribute keepalive pings to the exchange
+    let keepalive = Exchange::keepalive();
+    if keepalive.ping().is_some() {
+        options.spawner.spawn(schedule_pings_to_exchange(
             Exchange::ID,
-            ws_sink,
-            ws_sink_rx,
+            ws_sink_tx.clone(),
+            keepalive,
         ));
+    }
 
-        // Spawn optional task to distribute custom application-level pings to the exchange
-        if let Some(ping_interval) = Exchange::ping_interval() {
-            tokio::spawn(schedule_pings_to_exchange(
-                Exchange::ID,
-                ws_sink_tx.clone(),
-                ping_interval,
-            ));
-        }
+    // Construct Transformer associated with this Exchange and SubscriptionKind
+    let mut transformer = Transformer::new(ws_sink_tx, map, options).await?;
 
-        // Construct Transformer associated with this Exchange and SubscriptionKind
-        let transformer = Transformer::new(ws_sink_tx, map).await?;
+    // Yield any SubscriptionRejections & initial Transformer events (eg/ OrderBook snapshots),
+    // followed by market data messages buffered during Subscription validation
+    let buffer = rejections
+        .into_iter()
+        .map(|rejection| {
+            Err(DataError::SubscriptionRejected {
+                subscription_id: rejection.subscription_id,
+                reason: rejection.reason,
+            })
+        })
+        .chain(transformer.initial_events())
+        .chain(
+            buffered
+                .into_iter()
+                .filter_map(|message| WebSocketParser::parse::<Transformer::Input>(Ok(message)))
+                .flat_map(|input| match input {
+                    Ok(input) => transformer.transform(input).into_iter().collect(),
+                    Err(error) => vec![Err(DataError::from(error))],
+                })
+                .collect::<Vec<_>>(),
+        )
+        .collect::<VecDeque<_>>();
 
-        Ok(ExchangeWsStream::new(ws_stream, transformer))
-    }
+    // Wrap WsStream so it ends if the exchange misses the optional pong or idle deadlines
+    let ws_stream =
+        PongTimeoutStream::new(Exchange::ID, ws_stream, options.pong_timeout::<Exchange>())
+            .with_idle_timeout(options.idle_timeout.or_else(Exchange::idle_timeout));
+
+    Ok(ExchangeWsStream::new(ws_stream, transformer, buffer))
 }
 
 /// Transmit [`WsMessage`]s sent from the [`ExchangeTransformer`] to the exchange via
@@ -245,27 +400,222 @@ pub async fn distribute_messages_to_exchange(
     }
 }
 
-/// Schedule the sending of custom application-level ping [`WsMessage`]s to the exchange using
-/// the provided [`PingInterval`].
+/// Schedule the sending of ping [`WsMessage`]s to the exchange using the provided [`Keepalive`]
+/// scheme.
 ///
 /// **Notes:**
-///  - This is only used for those exchanges that require custom application-level pings.
-///  - This is additional to the protocol-level pings already handled by `tokio_tungstenite`.
+///  - Returns immediately for [`Keepalive::None`].
+///  - This is additional to responding to exchange server protocol-level pings, which is already
+///    handled by `tokio_tungstenite`.
 pub async fn schedule_pings_to_exchange(
     exchange: ExchangeId,
     ws_sink_tx: mpsc::UnboundedSender<WsMessage>,
-    PingInterval { mut interval, ping }: PingInterval,
+    keepalive: Keepalive,
 ) {
+    let Some((period, _)) = keepalive.ping() else {
+        return;
+    };
+    let mut interval = tokio::time::interval(period);
+
     loop {
         // Wait for next scheduled ping
         interval.tick().await;
 
-        // Construct exchange custom application-level ping payload
-        let payload = ping();
-        debug!(%exchange, %payload, "sending custom application-level ping to exchange");
+        // Construct exchange keepalive ping payload
+        let Some((_, payload)) = keepalive.ping() else {
+            break;
+        };
+        debug!(%exchange, %payload, ?keepalive, "sending keepalive ping to exchange");
 
         if ws_sink_tx.send(payload).is_err() {
             break;
         }
     }
 }
+
+/// [`Stream`] wrapper that ends the inner [`WsStream`] if the exchange misses the
+/// [`PongTimeout`] deadline, or sends no message at all within the optional idle timeout (see
+/// [`StreamOptions::idle_timeout`]), enabling the [`MarketStream`] to be re-initialised.
+///
+/// If neither deadline is provided, messages are passed through untouched.
+#[derive(Debug)]
+pub struct PongTimeoutStream<InnerStream> {
+    exchange: ExchangeId,
+    stream: InnerStream,
+    pong_deadline: Option<(PongTimeout, Pin<Box<Sleep>>)>,
+    idle_deadline: Option<(Duration, Pin<Box<Sleep>>)>,
+}
+
+impl<InnerStream> PongTimeoutStream<InnerStream> {
+    /// Construct a new [`PongTimeoutStream`], starting the first pong deadline immediately.
+    pub fn new(exchange: ExchangeId, stream: InnerStream, timeout: Option<PongTimeout>) -> Self {
+        Self {
+            exchange,
+            stream,
+            pong_deadline: timeout
+                .map(|timeout| (timeout, Box::pin(tokio::time::sleep(timeout.timeout)))),
+            idle_deadline: None,
+        }
+    }
+
+    /// End the [`Stream`] if no message (including pings) is received within the provided idle
+    /// timeout, starting the first idle deadline immediately.
+    pub fn with_idle_timeout(self, timeout: Option<Duration>) -> Self {
+        Self {
+            idle_deadline: timeout.map(|timeout| (timeout, Box::pin(tokio::time::sleep(timeout)))),
+            ..self
+        }
+    }
+}
+
+impl<InnerStream> Stream for PongTimeoutStream<InnerStream>
+where
+    InnerStream: Stream<Item = Result<WsMessage, WsError>> + Unpin,
+{
+    type Item = Result<WsMessage, WsError>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+
+        // End the Stream if the exchange missed the pong deadline
+        if let Some((timeout, deadline)) = &mut this.pong_deadline {
+            if deadline.as_mut().poll(cx).is_ready() {
+                warn!(
+                    exchange = %this.exchange,
+                    timeout = ?timeout.timeout,
+                    action = "ending stream so it can be re-initialised",
+                    "exchange missed pong deadline"
+                );
+                return Poll::Ready(None);
+            }
+        }
+
+        // End the Stream if no message was received within the idle timeout
+        if let Some((timeout, deadline)) = &mut this.idle_deadline {
+            if deadline.as_mut().poll(cx).is_ready() {
+                warn!(
+                    exchange = %this.exchange,
+                    ?timeout,
+                    action = "ending stream so it can be re-initialised",
+                    "no message received from exchange within idle timeout"
+                );
+                return Poll::Ready(None);
+            }
+        }
+
+        let next = Pin::new(&mut this.stream).poll_next(cx);
+
+        // Reset the idle deadline if any message was received
+        if let (Poll::Ready(Some(_)), Some((timeout, deadline))) = (&next, &mut this.idle_deadline)
+        {
+            deadline
+                .as_mut()
+                .reset(tokio::time::Instant::now() + *timeout);
+        }
+
+        // Reset the pong deadline if a pong response was received
+        if let (Poll::Ready(Some(Ok(message))), Some((timeout, deadline))) =
+            (&next, &mut this.pong_deadline)
+        {
+            if (timeout.is_pong)(message) {
+                deadline
+                    .as_mut()
+                    .reset(tokio::time::Instant::now() + timeout.timeout);
+            }
+        }
+
+        next
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn is_pong(message: &WsMessage) -> bool {
+        matches!(message, WsMessage::Text(text) if text == "pong")
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_pong_timeout_stream() {
+        let (tx, rx) = mpsc::unbounded_channel::<Result<WsMessage, WsError>>();
+        let mut stream = PongTimeoutStream::new(
+            ExchangeId::Okx,
+            tokio_stream::wrappers::UnboundedReceiverStream::new(rx),
+            Some(PongTimeout {
+                timeout: Duration::from_secs(10),
+                is_pong,
+            }),
+        );
+
+        // Pong received within the deadline resets the deadline
+        tokio::time::sleep(Duration::from_secs(8)).await;
+        tx.send(Ok(WsMessage::text("pong"))).unwrap();
+        assert!(matches!(stream.next().await, Some(Ok(_))));
+
+        // Non-pong message does not reset the deadline
+        tokio::time::sleep(Duration::from_secs(8)).await;
+        tx.send(Ok(WsMessage::text("trade"))).unwrap();
+        assert!(matches!(stream.next().await, Some(Ok(_))));
+
+        // Deadline missed, so Stream ends
+        tokio::time::sleep(Duration::from_secs(3)).await;
+        assert!(stream.next().await.is_none());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_idle_timeout_stream() {
+        let (tx, rx) = mpsc::unbounded_channel::<Result<WsMessage, WsError>>();
+        let mut stream = PongTimeoutStream::new(
+            ExchangeId::Okx,
+            tokio_stream::wrappers::UnboundedReceiverStream::new(rx),
+            None,
+        )
+        .with_idle_timeout(Some(Duration::from_secs(10)));
+
+        // Any message, including pings, received within the deadline resets the deadline
+        tokio::time::sleep(Duration::from_secs(8)).await;
+        tx.send(Ok(WsMessage::Ping(Vec::new()))).unwrap();
+        assert!(matches!(stream.next().await, Some(Ok(_))));
+
+        tokio::time::sleep(Duration::from_secs(8)).await;
+        tx.send(Ok(WsMessage::text("trade"))).unwrap();
+        assert!(matches!(stream.next().await, Some(Ok(_))));
+
+        // No message received within the deadline, so Stream ends
+        tokio::time::sleep(Duration::from_secs(11)).await;
+        assert!(stream.next().await.is_none());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_schedule_pings_to_exchange() {
+        // Keepalive::Protocol sends protocol-level ping frames
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        tokio::spawn(schedule_pings_to_exchange(
+            ExchangeId::GateioSpot,
+            tx,
+            Keepalive::Protocol {
+                interval: Duration::from_secs(10),
+            },
+        ));
+        assert_eq!(rx.recv().await, Some(WsMessage::Ping(Vec::new())));
+        assert_eq!(rx.recv().await, Some(WsMessage::Ping(Vec::new())));
+
+        // Keepalive::ApplicationJson sends custom application-level pings
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        tokio::spawn(schedule_pings_to_exchange(
+            ExchangeId::Okx,
+            tx,
+            Keepalive::ApplicationJson {
+                interval: Duration::from_secs(10),
+                ping: || WsMessage::text("ping"),
+            },
+        ));
+        assert_eq!(rx.recv().await, Some(WsMessage::text("ping")));
+
+        // Keepalive::None sends nothing, dropping the sender immediately
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        schedule_pings_to_exchange(ExchangeId::Kraken, tx, Keepalive::None).await;
+        assert_eq!(rx.recv().await, None);
+    }
+}
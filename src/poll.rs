@@ -0,0 +1,168 @@
+use crate::{
+    error::DataError,
+    event::{MarketEvent, MarketIter},
+    runtime::Spawner,
+};
+use futures::Stream;
+use std::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+    time::Duration,
+};
+use tokio::sync::mpsc;
+use tracing::debug;
+
+/// Default [`Duration`] between each REST request made by a [`PollStream`] poller.
+pub const DEFAULT_POLL_INTERVAL: Duration = Duration::from_secs(10);
+
+/// [`MarketStream`](crate::MarketStream) that periodically polls exchange REST endpoints, used
+/// for market data that is not available via the exchange WebSocket API.
+///
+/// Each poller is driven by its own spawned task, and every yielded [`MarketEvent`] is sent
+/// through the same output channel as the WebSocket [`MarketStream`](crate::MarketStream)s. The
+/// spawned tasks end once the [`PollStream`] is dropped.
+#[derive(Debug)]
+pub struct PollStream<InstrumentId, Event> {
+    rx: mpsc::UnboundedReceiver<Result<MarketEvent<InstrumentId, Event>, DataError>>,
+}
+
+impl<InstrumentId, Event> PollStream<InstrumentId, Event>
+where
+    InstrumentId: Send + 'static,
+    Event: Send + 'static,
+{
+    /// Initialise a [`PollStream`] from the provided `(interval, poller)` pairs, spawning each
+    /// poller task using the provided [`Spawner`].
+    ///
+    /// Every poller is invoked once during initialisation so that invalid requests (eg/ unknown
+    /// markets) fail fast, after which each is invoked once every `interval`.
+    pub async fn init<Pollers, Poller, Fut>(
+        pollers: Pollers,
+        spawner: &Spawner,
+    ) -> Result<Self, DataError>
+    where
+        Pollers: IntoIterator<Item = (Duration, Poller)>,
+        Poller: FnMut() -> Fut + Send + 'static,
+        Fut: Future<Output = Result<MarketIter<InstrumentId, Event>, DataError>> + Send + 'static,
+    {
+        let (tx, rx) = mpsc::unbounded_channel();
+
+        // Invoke each poller before any task is spawned, so none are left running on failure
+        let mut initialised = Vec::new();
+        for (interval, mut poller) in pollers {
+            let events = poller().await?;
+            initialised.push((interval, poller, events));
+        }
+
+        for (interval, poller, events) in initialised {
+            events.0.into_iter().for_each(|event| {
+                let _ = tx.send(event);
+            });
+            spawner.spawn(schedule_polls(interval, poller, tx.clone()));
+        }
+
+        Ok(Self { rx })
+    }
+}
+
+/// Invoke the provided poller once every `interval`, sending the output to the [`PollStream`]
+/// until it is dropped.
+async fn schedule_polls<InstrumentId, Event, Poller, Fut>(
+    interval: Duration,
+    mut poller: Poller,
+    tx: mpsc::UnboundedSender<Result<MarketEvent<InstrumentId, Event>, DataError>>,
+) where
+    Poller: FnMut() -> Fut,
+    Fut: Future<Output = Result<MarketIter<InstrumentId, Event>, DataError>>,
+{
+    // First poll was made during PollStream::init, so first tick is after one interval
+    let mut interval = tokio::time::interval_at(tokio::time::Instant::now() + interval, interval);
+
+    loop {
+        interval.tick().await;
+
+        let events = match poller().await {
+            Ok(events) => events.0,
+            Err(error) => vec![Err(error)],
+        };
+
+        for event in events {
+            if tx.send(event).is_err() {
+                debug!(why = "PollStream dropped", "ending REST poller");
+                return;
+            }
+        }
+    }
+}
+
+impl<InstrumentId, Event> Stream for PollStream<InstrumentId, Event> {
+    type Item = Result<MarketEvent<InstrumentId, Event>, DataError>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.rx.poll_recv(cx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use barter_integration::{error::SocketError, model::Exchange};
+    use chrono::Utc;
+    use futures::StreamExt;
+    use std::sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc,
+    };
+
+    fn poller(
+        counter: Arc<AtomicU64>,
+    ) -> impl FnMut() -> futures::future::Ready<Result<MarketIter<u64, u64>, DataError>> {
+        move || {
+            let count = counter.fetch_add(1, Ordering::SeqCst);
+            futures::future::ready(match count {
+                // Second poll fails with a non-terminal error
+                1 => Err(DataError::Socket(SocketError::Sink)),
+                count => Ok(MarketIter(vec![Ok(MarketEvent {
+                    exchange_time: Utc::now(),
+                    received_time: Utc::now(),
+                    exchange: Exchange::from("test"),
+                    instrument: 1,
+                    kind: count,
+                })])),
+            })
+        }
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_poll_stream() {
+        let counter = Arc::new(AtomicU64::new(0));
+        let mut stream = PollStream::init(
+            [(Duration::from_secs(10), poller(counter.clone()))],
+            &Spawner::default(),
+        )
+        .await
+        .unwrap();
+
+        // Initial poll is made during init
+        assert_eq!(counter.load(Ordering::SeqCst), 1);
+        assert_eq!(stream.next().await.unwrap().unwrap().kind, 0);
+
+        // Failed polls are yielded as errors, and polling continues
+        assert!(stream.next().await.unwrap().is_err());
+        assert_eq!(stream.next().await.unwrap().unwrap().kind, 2);
+        assert_eq!(counter.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn test_poll_stream_init_fails_fast() {
+        let result = PollStream::<u64, u64>::init(
+            [(Duration::from_secs(10), || {
+                futures::future::ready(Err(DataError::Socket(SocketError::Sink)))
+            })],
+            &Spawner::default(),
+        )
+        .await;
+        assert!(result.is_err());
+    }
+}
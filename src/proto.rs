@@ -0,0 +1,474 @@
+use crate::{
+    event::{self, DataKind},
+    subscription::{
+        book::{self, Level as BookLevel, OrderBookSide},
+        candle, liquidation, trade,
+    },
+};
+use barter_integration::model;
+use chrono::{DateTime, Utc};
+use std::fmt::Display;
+use thiserror::Error;
+
+/// All errors generated when converting protobuf messages into the normalised models.
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Error)]
+pub enum ProtoError {
+    #[error("missing required field: {0}")]
+    MissingField(&'static str),
+
+    #[error("invalid Side: {0}")]
+    InvalidSide(i32),
+}
+
+/// Request of the `SubscribeMarketData` RPC. Empty filters match every [`MarketEvent`].
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct SubscribeMarketDataRequest {
+    #[prost(string, repeated, tag = "1")]
+    pub exchanges: Vec<String>,
+    #[prost(string, repeated, tag = "2")]
+    pub instruments: Vec<String>,
+}
+
+/// Normalised [`MarketEvent`](event::MarketEvent), with times in nanoseconds since the Unix epoch.
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct MarketEvent {
+    #[prost(int64, tag = "1")]
+    pub exchange_time: i64,
+    #[prost(int64, tag = "2")]
+    pub received_time: i64,
+    #[prost(string, tag = "3")]
+    pub exchange: String,
+    #[prost(string, tag = "4")]
+    pub instrument: String,
+    #[prost(oneof = "market_event::Kind", tags = "5, 6, 7, 8, 9")]
+    pub kind: Option<market_event::Kind>,
+}
+
+/// Nested types of the [`MarketEvent`] message.
+pub mod market_event {
+    /// Protobuf `oneof` of every [`MarketEvent`](super::MarketEvent) kind with a stable schema.
+    #[derive(Clone, PartialEq, prost::Oneof)]
+    pub enum Kind {
+        #[prost(message, tag = "5")]
+        Trade(super::PublicTrade),
+        #[prost(message, tag = "6")]
+        OrderBookL1(super::OrderBookL1),
+        #[prost(message, tag = "7")]
+        OrderBook(super::OrderBook),
+        #[prost(message, tag = "8")]
+        Candle(super::Candle),
+        #[prost(message, tag = "9")]
+        Liquidation(super::Liquidation),
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, prost::Enumeration)]
+#[repr(i32)]
+pub enum Side {
+    Unspecified = 0,
+    Buy = 1,
+    Sell = 2,
+}
+
+#[derive(Clone, Copy, PartialEq, prost::Message)]
+pub struct Level {
+    #[prost(double, tag = "1")]
+    pub price: f64,
+    #[prost(double, tag = "2")]
+    pub amount: f64,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct PublicTrade {
+    #[prost(string, tag = "1")]
+    pub id: String,
+    #[prost(double, tag = "2")]
+    pub price: f64,
+    #[prost(double, tag = "3")]
+    pub amount: f64,
+    #[prost(enumeration = "Side", tag = "4")]
+    pub side: i32,
+}
+
+#[derive(Clone, Copy, PartialEq, prost::Message)]
+pub struct OrderBookL1 {
+    #[prost(int64, tag = "1")]
+    pub last_update_time: i64,
+    #[prost(message, optional, tag = "2")]
+    pub best_bid: Option<Level>,
+    #[prost(message, optional, tag = "3")]
+    pub best_ask: Option<Level>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct OrderBook {
+    #[prost(int64, tag = "1")]
+    pub last_update_time: i64,
+    #[prost(message, repeated, tag = "2")]
+    pub bids: Vec<Level>,
+    #[prost(message, repeated, tag = "3")]
+    pub asks: Vec<Level>,
+}
+
+#[derive(Clone, Copy, PartialEq, prost::Message)]
+pub struct Candle {
+    #[prost(int64, tag = "1")]
+    pub close_time: i64,
+    #[prost(double, tag = "2")]
+    pub open: f64,
+    #[prost(double, tag = "3")]
+    pub high: f64,
+    #[prost(double, tag = "4")]
+    pub low: f64,
+    #[prost(double, tag = "5")]
+    pub close: f64,
+    #[prost(double, tag = "6")]
+    pub volume: f64,
+    #[prost(uint64, tag = "7")]
+    pub trade_count: u64,
+}
+
+#[derive(Clone, Copy, PartialEq, prost::Message)]
+pub struct Liquidation {
+    #[prost(enumeration = "Side", tag = "1")]
+    pub side: i32,
+    #[prost(double, tag = "2")]
+    pub price: f64,
+    #[prost(double, tag = "3")]
+    pub quantity: f64,
+    #[prost(int64, tag = "4")]
+    pub time: i64,
+}
+
+impl<InstrumentId, T> From<&event::MarketEvent<InstrumentId, T>> for MarketEvent
+where
+    InstrumentId: Display,
+    for<'a> Option<market_event::Kind>: From<&'a T>,
+{
+    fn from(event: &event::MarketEvent<InstrumentId, T>) -> Self {
+        Self {
+            exchange_time: nanos(event.exchange_time),
+            received_time: nanos(event.received_time),
+            exchange: event.exchange.to_string(),
+            instrument: event.instrument.to_string(),
+            kind: Option::from(&event.kind),
+        }
+    }
+}
+
+impl From<&DataKind> for Option<market_event::Kind> {
+    fn from(kind: &DataKind) -> Self {
+        match kind {
+            DataKind::Trade(trade) => Self::from(trade),
+            DataKind::OrderBookL1(book) => Self::from(book),
+            DataKind::OrderBook(book) => Self::from(book),
+            DataKind::Candle(candle) => Self::from(candle),
+            DataKind::Liquidation(liquidation) => Self::from(liquidation),
+            _ => None,
+        }
+    }
+}
+
+impl From<&trade::PublicTrade> for Option<market_event::Kind> {
+    fn from(trade: &trade::PublicTrade) -> Self {
+        Some(market_event::Kind::Trade(PublicTrade {
+            id: trade.id.clone(),
+            price: trade.price,
+            amount: trade.amount,
+            side: Side::from(trade.side) as i32,
+        }))
+    }
+}
+
+impl From<&book::OrderBookL1> for Option<market_event::Kind> {
+    fn from(book: &book::OrderBookL1) -> Self {
+        Some(market_event::Kind::OrderBookL1(OrderBookL1 {
+            last_update_time: nanos(book.last_update_time),
+            best_bid: Some(Level::from(&book.best_bid)),
+            best_ask: Some(Level::from(&book.best_ask)),
+        }))
+    }
+}
+
+impl From<&book::OrderBook> for Option<market_event::Kind> {
+    fn from(book: &book::OrderBook) -> Self {
+        Some(market_event::Kind::OrderBook(OrderBook {
+            last_update_time: nanos(book.last_update_time),
+            bids: book.bids.levels().iter().map(Level::from).collect(),
+            asks: book.asks.levels().iter().map(Level::from).collect(),
+        }))
+    }
+}
+
+impl From<&candle::Candle> for Option<market_event::Kind> {
+    fn from(candle: &candle::Candle) -> Self {
+        Some(market_event::Kind::Candle(Candle {
+            close_time: nanos(candle.close_time),
+            open: candle.open,
+            high: candle.high,
+            low: candle.low,
+            close: candle.close,
+            volume: candle.volume,
+            trade_count: candle.trade_count,
+        }))
+    }
+}
+
+impl From<&liquidation::Liquidation> for Option<market_event::Kind> {
+    fn from(liquidation: &liquidation::Liquidation) -> Self {
+        Some(market_event::Kind::Liquidation(Liquidation {
+            side: Side::from(liquidation.side) as i32,
+            price: liquidation.price,
+            quantity: liquidation.quantity,
+            time: nanos(liquidation.time),
+        }))
+    }
+}
+
+impl From<&BookLevel> for Level {
+    fn from(level: &BookLevel) -> Self {
+        Self {
+            price: level.price,
+            amount: level.amount,
+        }
+    }
+}
+
+impl TryFrom<MarketEvent> for event::MarketEvent<String, DataKind> {
+    type Error = ProtoError;
+
+    fn try_from(event: MarketEvent) -> Result<Self, Self::Error> {
+        let kind = match event.kind.ok_or(ProtoError::MissingField("kind"))? {
+            market_event::Kind::Trade(trade) => {
+                DataKind::Trade(trade::PublicTrade::try_from(trade)?)
+            }
+            market_event::Kind::OrderBookL1(book) => {
+                DataKind::OrderBookL1(book::OrderBookL1::try_from(book)?)
+            }
+            market_event::Kind::OrderBook(book) => DataKind::OrderBook(book::OrderBook::from(book)),
+            market_event::Kind::Candle(candle) => DataKind::Candle(candle::Candle::from(candle)),
+            market_event::Kind::Liquidation(liquidation) => {
+                DataKind::Liquidation(liquidation::Liquidation::try_from(liquidation)?)
+            }
+        };
+
+        Ok(Self {
+            exchange_time: time(event.exchange_time),
+            received_time: time(event.received_time),
+            exchange: model::Exchange::from(event.exchange),
+            instrument: event.instrument,
+            kind,
+        })
+    }
+}
+
+impl TryFrom<PublicTrade> for trade::PublicTrade {
+    type Error = ProtoError;
+
+    fn try_from(trade: PublicTrade) -> Result<Self, Self::Error> {
+        Ok(Self {
+            side: side(trade.side)?,
+            id: trade.id,
+            price: trade.price,
+            amount: trade.amount,
+        })
+    }
+}
+
+impl TryFrom<OrderBookL1> for book::OrderBookL1 {
+    type Error = ProtoError;
+
+    fn try_from(book: OrderBookL1) -> Result<Self, Self::Error> {
+        Ok(Self {
+            last_update_time: time(book.last_update_time),
+            best_bid: book
+                .best_bid
+                .map(BookLevel::from)
+                .ok_or(ProtoError::MissingField("best_bid"))?,
+            best_ask: book
+                .best_ask
+                .map(BookLevel::from)
+                .ok_or(ProtoError::MissingField("best_ask"))?,
+        })
+    }
+}
+
+impl From<OrderBook> for book::OrderBook {
+    fn from(book: OrderBook) -> Self {
+        Self {
+            last_update_time: time(book.last_update_time),
+            bids: OrderBookSide::new(model::Side::Buy, book.bids),
+            asks: OrderBookSide::new(model::Side::Sell, book.asks),
+        }
+    }
+}
+
+impl From<Candle> for candle::Candle {
+    fn from(candle: Candle) -> Self {
+        Self {
+            close_time: time(candle.close_time),
+            open: candle.open,
+            high: candle.high,
+            low: candle.low,
+            close: candle.close,
+            volume: candle.volume,
+            trade_count: candle.trade_count,
+        }
+    }
+}
+
+impl TryFrom<Liquidation> for liquidation::Liquidation {
+    type Error = ProtoError;
+
+    fn try_from(liquidation: Liquidation) -> Result<Self, Self::Error> {
+        Ok(Self {
+            side: side(liquidation.side)?,
+            price: liquidation.price,
+            quantity: liquidation.quantity,
+            time: time(liquidation.time),
+        })
+    }
+}
+
+impl From<Level> for BookLevel {
+    fn from(level: Level) -> Self {
+        Self {
+            price: level.price,
+            amount: level.amount,
+        }
+    }
+}
+
+impl From<model::Side> for Side {
+    fn from(side: model::Side) -> Self {
+        match side {
+            model::Side::Buy => Self::Buy,
+            model::Side::Sell => Self::Sell,
+        }
+    }
+}
+
+/// Nanoseconds since the Unix epoch, saturating to zero if out of range.
+fn nanos(time: DateTime<Utc>) -> i64 {
+    time.timestamp_nanos_opt().unwrap_or_default()
+}
+
+/// `DateTime<Utc>` of the provided nanoseconds since the Unix epoch.
+fn time(nanos: i64) -> DateTime<Utc> {
+    DateTime::from_timestamp_nanos(nanos)
+}
+
+/// Normalised [`model::Side`] of the provided protobuf [`Side`] value.
+fn side(side: i32) -> Result<model::Side, ProtoError> {
+    match Side::try_from(side) {
+        Ok(Side::Buy) => Ok(model::Side::Buy),
+        Ok(Side::Sell) => Ok(model::Side::Sell),
+        _ => Err(ProtoError::InvalidSide(side)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use prost::Message;
+
+    #[test]
+    fn test_market_event_from() {
+        let time = DateTime::from_timestamp(1, 500).unwrap();
+        let event = event::MarketEvent {
+            exchange_time: time,
+            received_time: time,
+            exchange: model::Exchange::from("binance_spot"),
+            instrument: "btc_usdt",
+            kind: DataKind::Trade(trade::PublicTrade {
+                id: "1".to_owned(),
+                price: 100.0,
+                amount: 2.0,
+                side: model::Side::Sell,
+            }),
+        };
+
+        let actual = MarketEvent::from(&event);
+        assert_eq!(
+            actual,
+            MarketEvent {
+                exchange_time: 1_000_000_500,
+                received_time: 1_000_000_500,
+                exchange: "binance_spot".to_owned(),
+                instrument: "btc_usdt".to_owned(),
+                kind: Some(market_event::Kind::Trade(PublicTrade {
+                    id: "1".to_owned(),
+                    price: 100.0,
+                    amount: 2.0,
+                    side: Side::Sell as i32,
+                })),
+            }
+        );
+
+        // Encoded MarketEvent round trips
+        let decoded = MarketEvent::decode(actual.encode_to_vec().as_slice()).unwrap();
+        assert_eq!(decoded, actual);
+    }
+
+    #[test]
+    fn test_market_event_try_from() {
+        let time = DateTime::from_timestamp(1, 500).unwrap();
+        let event = |kind| event::MarketEvent {
+            exchange_time: time,
+            received_time: time,
+            exchange: model::Exchange::from("binance_spot"),
+            instrument: "btc_usdt".to_owned(),
+            kind,
+        };
+
+        // TC0: PublicTrade round trips
+        let trade = event(DataKind::Trade(trade::PublicTrade {
+            id: "1".to_owned(),
+            price: 100.0,
+            amount: 2.0,
+            side: model::Side::Buy,
+        }));
+        let actual = event::MarketEvent::try_from(MarketEvent::from(&trade)).unwrap();
+        assert_eq!(actual, trade);
+
+        // TC1: OrderBook round trips
+        let book = event(DataKind::OrderBook(book::OrderBook {
+            last_update_time: time,
+            bids: OrderBookSide::new(model::Side::Buy, vec![(100.0, 1.0), (99.0, 2.0)]),
+            asks: OrderBookSide::new(model::Side::Sell, vec![(101.0, 1.0)]),
+        }));
+        let actual = event::MarketEvent::try_from(MarketEvent::from(&book)).unwrap();
+        assert_eq!(actual, book);
+
+        // TC2: Candle round trips
+        let candle = event(DataKind::Candle(candle::Candle {
+            close_time: time,
+            open: 1.0,
+            high: 2.0,
+            low: 0.5,
+            close: 1.5,
+            volume: 10.0,
+            trade_count: 3,
+        }));
+        let actual = event::MarketEvent::try_from(MarketEvent::from(&candle)).unwrap();
+        assert_eq!(actual, candle);
+
+        // TC3: missing kind is an error
+        let mut missing = MarketEvent::from(&trade);
+        missing.kind = None;
+        assert!(matches!(
+            event::MarketEvent::try_from(missing),
+            Err(ProtoError::MissingField("kind"))
+        ));
+
+        // TC4: unspecified Side is an error
+        let mut invalid = MarketEvent::from(&trade);
+        if let Some(market_event::Kind::Trade(trade)) = invalid.kind.as_mut() {
+            trade.side = 0;
+        }
+        assert!(matches!(
+            event::MarketEvent::try_from(invalid),
+            Err(ProtoError::InvalidSide(0))
+        ));
+    }
+}
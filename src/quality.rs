@@ -0,0 +1,323 @@
+use crate::event::{DataKind, MarketEvent};
+use barter_integration::model::Exchange;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::{HashMap, HashSet, VecDeque},
+    hash::Hash,
+    time::Duration,
+};
+
+/// Default inter-arrival [`Duration`] above which a [`QualityMonitor`] considers the period
+/// between two consecutive events for an instrument to be a gap.
+pub const DEFAULT_GAP_THRESHOLD: Duration = Duration::from_secs(5);
+
+/// Default number of recent trade identifiers remembered per instrument by a [`QualityMonitor`]
+/// in order to detect duplicate trades.
+pub const DEFAULT_DUPLICATE_WINDOW: usize = 10_000;
+
+/// Configuration for a [`QualityMonitor`].
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Deserialize, Serialize)]
+pub struct QualityConfig {
+    /// Inter-arrival [`Duration`] above which the period between two consecutive events for an
+    /// instrument is counted as a gap.
+    pub gap_threshold: Duration,
+
+    /// Number of recent trade identifiers remembered per instrument in order to detect
+    /// duplicate trades.
+    pub duplicate_window: usize,
+}
+
+impl Default for QualityConfig {
+    fn default() -> Self {
+        Self {
+            gap_threshold: DEFAULT_GAP_THRESHOLD,
+            duplicate_window: DEFAULT_DUPLICATE_WINDOW,
+        }
+    }
+}
+
+/// Observes [`MarketEvent<InstrumentId, DataKind>`](MarketEvent)s, either live over a run or
+/// replayed from a recorded file, and generates a per-instrument [`QualityReport`] that can be
+/// used to certify a collector before relying on its output.
+#[derive(Debug)]
+pub struct QualityMonitor<InstrumentId> {
+    config: QualityConfig,
+    start: Option<DateTime<Utc>>,
+    end: Option<DateTime<Utc>>,
+    instruments: HashMap<(Exchange, InstrumentId), InstrumentMonitor>,
+}
+
+/// Internal per-instrument state of a [`QualityMonitor`].
+#[derive(Debug, Default)]
+struct InstrumentMonitor {
+    quality: InstrumentQuality,
+    last_received_time: Option<DateTime<Utc>>,
+    trade_ids: HashSet<String>,
+    trade_ids_window: VecDeque<String>,
+}
+
+impl<InstrumentId> Default for QualityMonitor<InstrumentId> {
+    fn default() -> Self {
+        Self::new(QualityConfig::default())
+    }
+}
+
+impl<InstrumentId> QualityMonitor<InstrumentId> {
+    /// Construct a new [`QualityMonitor`] using the provided [`QualityConfig`].
+    pub fn new(config: QualityConfig) -> Self {
+        Self {
+            config,
+            start: None,
+            end: None,
+            instruments: HashMap::new(),
+        }
+    }
+}
+
+impl<InstrumentId> QualityMonitor<InstrumentId>
+where
+    InstrumentId: Clone + Eq + Hash,
+{
+    /// Construct a [`QualityMonitor`] with the default [`QualityConfig`] that has observed every
+    /// [`MarketEvent`] in the provided iterator (eg/ events replayed from a recorded file).
+    pub fn from_events<'a, Iter>(events: Iter) -> Self
+    where
+        Iter: IntoIterator<Item = &'a MarketEvent<InstrumentId, DataKind>>,
+        InstrumentId: 'a,
+    {
+        let mut monitor = Self::default();
+        events.into_iter().for_each(|event| monitor.observe(event));
+        monitor
+    }
+
+    /// Observe a [`MarketEvent`], updating the associated instrument [`InstrumentQuality`].
+    pub fn observe(&mut self, event: &MarketEvent<InstrumentId, DataKind>) {
+        // Track the observation period of the run
+        self.start = Some(
+            self.start
+                .map_or(event.received_time, |start| start.min(event.received_time)),
+        );
+        self.end = Some(
+            self.end
+                .map_or(event.received_time, |end| end.max(event.received_time)),
+        );
+
+        let monitor = self
+            .instruments
+            .entry((event.exchange.clone(), event.instrument.clone()))
+            .or_default();
+
+        monitor.quality.messages += 1;
+
+        // Events without a venue timestamp have an exchange_time taken from the local clock
+        if event.exchange_time != event.received_time {
+            monitor.quality.events_with_exchange_time += 1;
+        }
+
+        // Determine inter-arrival time since the previous event for this instrument
+        if let Some(inter_arrival) = monitor
+            .last_received_time
+            .and_then(|last| (event.received_time - last).to_std().ok())
+        {
+            monitor.quality.max_inter_arrival =
+                monitor.quality.max_inter_arrival.max(inter_arrival);
+            if inter_arrival > self.config.gap_threshold {
+                monitor.quality.gaps += 1;
+            }
+        }
+        monitor.last_received_time = Some(event.received_time);
+
+        // Detect duplicate trades using a bounded window of recent trade identifiers
+        if let DataKind::Trade(trade) = &event.kind {
+            let key = trade.dedup_key();
+            if monitor.trade_ids.contains(key) {
+                monitor.quality.duplicate_trades += 1;
+            } else if self.config.duplicate_window > 0 {
+                if monitor.trade_ids_window.len() >= self.config.duplicate_window {
+                    if let Some(oldest) = monitor.trade_ids_window.pop_front() {
+                        monitor.trade_ids.remove(&oldest);
+                    }
+                }
+                monitor.trade_ids.insert(key.to_owned());
+                monitor.trade_ids_window.push_back(key.to_owned());
+            }
+        }
+    }
+
+    /// Record that the local order book for the provided instrument was re-synchronised (eg/ after
+    /// an [`InvalidSequence`](crate::error::DataError::InvalidSequence) error).
+    pub fn record_book_resync(&mut self, exchange: Exchange, instrument: InstrumentId) {
+        self.instruments
+            .entry((exchange, instrument))
+            .or_default()
+            .quality
+            .book_resyncs += 1;
+    }
+
+    /// Generate a [`QualityReport`] from everything observed so far.
+    pub fn report(&self) -> QualityReport<InstrumentId> {
+        QualityReport {
+            start: self.start,
+            end: self.end,
+            instruments: self
+                .instruments
+                .iter()
+                .map(|((exchange, instrument), monitor)| InstrumentReport {
+                    exchange: exchange.clone(),
+                    instrument: instrument.clone(),
+                    quality: monitor.quality,
+                    exchange_time_pct: monitor.quality.exchange_time_pct(),
+                })
+                .collect(),
+        }
+    }
+}
+
+/// Data quality statistics for a single instrument observed by a [`QualityMonitor`].
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Default, Deserialize, Serialize)]
+pub struct InstrumentQuality {
+    /// Number of events received.
+    pub messages: u64,
+
+    /// Number of inter-arrival periods that exceeded the configured gap threshold.
+    pub gaps: u64,
+
+    /// Largest period between two consecutive events.
+    pub max_inter_arrival: Duration,
+
+    /// Number of events that contained a venue timestamp.
+    pub events_with_exchange_time: u64,
+
+    /// Number of trades received more than once.
+    pub duplicate_trades: u64,
+
+    /// Number of times the local order book was re-synchronised.
+    pub book_resyncs: u64,
+}
+
+impl InstrumentQuality {
+    /// Percentage of events that contained a venue timestamp.
+    pub fn exchange_time_pct(&self) -> f64 {
+        match self.messages {
+            0 => 0.0,
+            messages => self.events_with_exchange_time as f64 / messages as f64 * 100.0,
+        }
+    }
+}
+
+/// [`InstrumentQuality`] statistics for an instrument, as found in a [`QualityReport`].
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct InstrumentReport<InstrumentId> {
+    pub exchange: Exchange,
+    pub instrument: InstrumentId,
+    pub quality: InstrumentQuality,
+    pub exchange_time_pct: f64,
+}
+
+/// Per-instrument data quality report generated by a [`QualityMonitor`].
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct QualityReport<InstrumentId> {
+    pub start: Option<DateTime<Utc>>,
+    pub end: Option<DateTime<Utc>>,
+    pub instruments: Vec<InstrumentReport<InstrumentId>>,
+}
+
+impl<InstrumentId> QualityReport<InstrumentId>
+where
+    InstrumentId: Serialize,
+{
+    /// Serialise this [`QualityReport`] as pretty printed JSON.
+    pub fn to_json(&self) -> Result<String, serde_json::Error> {
+        serde_json::to_string_pretty(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::subscription::{
+        book::{Level, OrderBookL1},
+        trade::PublicTrade,
+    };
+    use barter_integration::model::Side;
+
+    fn time(secs: i64) -> DateTime<Utc> {
+        DateTime::from_timestamp(secs, 0).unwrap()
+    }
+
+    fn trade(id: &str, exchange_time: i64, received_time: i64) -> MarketEvent<u64, DataKind> {
+        MarketEvent {
+            exchange_time: time(exchange_time),
+            received_time: time(received_time),
+            exchange: Exchange::from("exchange"),
+            instrument: 1,
+            kind: DataKind::Trade(PublicTrade {
+                id: id.to_string(),
+                price: 100.0,
+                amount: 1.0,
+                side: Side::Buy,
+            }),
+        }
+    }
+
+    #[test]
+    fn test_quality_monitor_report() {
+        let events = vec![
+            trade("1", 0, 1),
+            trade("2", 1, 2),
+            trade("2", 2, 3),
+            // Gap of 10s
+            trade("3", 12, 13),
+            MarketEvent {
+                exchange_time: time(14),
+                received_time: time(14),
+                exchange: Exchange::from("exchange"),
+                instrument: 1,
+                kind: DataKind::OrderBookL1(OrderBookL1 {
+                    last_update_time: time(14),
+                    best_bid: Level::new(99.0, 1.0),
+                    best_ask: Level::new(101.0, 1.0),
+                }),
+            },
+        ];
+
+        let mut monitor = QualityMonitor::from_events(&events);
+        monitor.record_book_resync(Exchange::from("exchange"), 1);
+
+        let report = monitor.report();
+        assert_eq!(report.start, Some(time(1)));
+        assert_eq!(report.end, Some(time(14)));
+        assert_eq!(report.instruments.len(), 1);
+
+        let instrument = &report.instruments[0];
+        assert_eq!(
+            instrument.quality,
+            InstrumentQuality {
+                messages: 5,
+                gaps: 1,
+                max_inter_arrival: Duration::from_secs(10),
+                events_with_exchange_time: 4,
+                duplicate_trades: 1,
+                book_resyncs: 1,
+            }
+        );
+        assert_eq!(instrument.exchange_time_pct, 80.0);
+        assert!(report.to_json().is_ok());
+    }
+
+    #[test]
+    fn test_quality_monitor_duplicate_window() {
+        let mut monitor = QualityMonitor::new(QualityConfig {
+            gap_threshold: DEFAULT_GAP_THRESHOLD,
+            duplicate_window: 1,
+        });
+
+        // Trade "1" is evicted from the window by trade "2", so is not detected as a duplicate
+        for event in [trade("1", 0, 1), trade("2", 1, 2), trade("1", 2, 3)] {
+            monitor.observe(&event);
+        }
+
+        assert_eq!(monitor.report().instruments[0].quality.duplicate_trades, 0);
+    }
+}
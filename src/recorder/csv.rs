@@ -0,0 +1,281 @@
+use super::{path_segment, RecorderError, RecorderSummary};
+use crate::{
+    columnar::{Column, ColumnBatch, ColumnBatchBuilder, Columnar},
+    event::MarketEvent,
+};
+use chrono::{DateTime, SecondsFormat, Utc};
+use futures::{Stream, StreamExt};
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::HashMap,
+    fmt::Display,
+    fs::File,
+    path::PathBuf,
+    time::{Duration, Instant},
+};
+use tracing::info;
+
+/// Configuration for a [`CsvRecorder`].
+#[derive(Clone, Eq, PartialEq, Hash, Debug, Deserialize, Serialize)]
+pub struct CsvConfig {
+    /// Directory that CSV files are written to.
+    pub root: PathBuf,
+    /// File name component identifying the recorded event kind (eg/ "public_trades").
+    pub kind: String,
+    /// Approximate size in bytes at which a file is rotated.
+    pub max_bytes: u64,
+    /// Age at which a file is rotated.
+    pub max_age: Duration,
+}
+
+impl CsvConfig {
+    /// Construct a new [`CsvConfig`] writing to the provided root directory, using the default
+    /// `max_bytes` (100MB) & `max_age` (1 hour) rotation thresholds.
+    pub fn new<Root, Kind>(root: Root, kind: Kind) -> Self
+    where
+        Root: Into<PathBuf>,
+        Kind: Into<String>,
+    {
+        Self {
+            root: root.into(),
+            kind: kind.into(),
+            max_bytes: 100 * 1024 * 1024,
+            max_age: Duration::from_secs(60 * 60),
+        }
+    }
+}
+
+/// Open CSV file of a [`CsvRecorder`].
+#[derive(Debug)]
+struct CsvFile {
+    writer: csv::Writer<File>,
+    opened: Instant,
+    bytes: u64,
+}
+
+/// Recorder that writes normalised [`MarketEvent`]s to one CSV file per (exchange, instrument,
+/// kind), rotating each file once it exceeds the configured size or age.
+///
+/// Files are named `{root}/{exchange}_{instrument}_{kind}.{opened}.csv`, where `opened` is the
+/// Unix timestamp in nanoseconds of when the file was opened. Every file starts with a header
+/// row of the [`ColumnBatch`] field names, and timestamps are written in RFC 3339 format.
+///
+/// Useful for quick capture without an Arrow dependency (see
+/// [`ParquetRecorder`](super::parquet::ParquetRecorder) for research-grade capture).
+#[derive(Debug)]
+pub struct CsvRecorder<T> {
+    config: CsvConfig,
+    files: HashMap<(String, String), CsvFile>,
+    builder: ColumnBatchBuilder<T>,
+    summary: RecorderSummary,
+}
+
+impl<T> CsvRecorder<T>
+where
+    T: Columnar,
+{
+    /// Construct a new [`CsvRecorder`] using the provided [`CsvConfig`].
+    pub fn new(config: CsvConfig) -> Self {
+        Self {
+            config,
+            files: HashMap::new(),
+            builder: ColumnBatchBuilder::with_capacity(1),
+            summary: RecorderSummary::default(),
+        }
+    }
+
+    /// Write the [`MarketEvent`] to the CSV file of its exchange & instrument, rotating the file
+    /// first if it has exceeded the configured `max_bytes` or `max_age`.
+    pub fn record<InstrumentId>(
+        &mut self,
+        event: &MarketEvent<InstrumentId, T>,
+    ) -> Result<(), RecorderError>
+    where
+        InstrumentId: Display,
+    {
+        self.builder.push(event);
+        let batch = self.builder.finish();
+
+        let key = (event.exchange.to_string(), event.instrument.to_string());
+        let rotate = self.files.get(&key).is_none_or(|file| {
+            file.bytes >= self.config.max_bytes || file.opened.elapsed() >= self.config.max_age
+        });
+
+        if rotate {
+            if let Some(mut file) = self.files.remove(&key) {
+                file.writer.flush()?;
+            }
+            let file = self.open(&key, &batch)?;
+            self.files.insert(key.clone(), file);
+        }
+
+        let file = self
+            .files
+            .get_mut(&key)
+            .expect("CsvFile should be present after rotation");
+
+        for row in rows(&batch) {
+            file.bytes += row.iter().map(|value| value.len() as u64 + 1).sum::<u64>();
+            file.writer.write_record(&row).map_err(encode)?;
+            self.summary.rows += 1;
+        }
+
+        Ok(())
+    }
+
+    /// Flush every open CSV file.
+    pub fn flush(&mut self) -> Result<(), RecorderError> {
+        self.files
+            .values_mut()
+            .try_for_each(|file| file.writer.flush())
+            .map_err(RecorderError::from)
+    }
+
+    /// Record every [`MarketEvent`] of the provided [`Stream`] (eg/
+    /// [`Streams`](crate::streams::Streams)) until it ends, returning a [`RecorderSummary`] once
+    /// every open CSV file has been flushed.
+    pub async fn run<St, InstrumentId>(
+        mut self,
+        mut stream: St,
+    ) -> Result<RecorderSummary, RecorderError>
+    where
+        St: Stream<Item = MarketEvent<InstrumentId, T>> + Unpin,
+        InstrumentId: Display,
+    {
+        while let Some(event) = stream.next().await {
+            self.record(&event)?;
+        }
+
+        self.flush()?;
+        info!(summary = ?self.summary, "CsvRecorder input stream ended");
+        Ok(self.summary)
+    }
+
+    /// Open a new CSV file for the provided (exchange, instrument), writing the header row of the
+    /// [`ColumnBatch`] schema.
+    fn open(
+        &mut self,
+        (exchange, instrument): &(String, String),
+        batch: &ColumnBatch,
+    ) -> Result<CsvFile, RecorderError> {
+        std::fs::create_dir_all(&self.config.root)?;
+
+        let path = self.config.root.join(format!(
+            "{}_{}_{}.{}.csv",
+            path_segment(exchange),
+            path_segment(instrument),
+            path_segment(&self.config.kind),
+            Utc::now().timestamp_nanos_opt().unwrap_or_default(),
+        ));
+
+        let mut writer = csv::Writer::from_writer(File::create(path)?);
+        let header = batch.schema.iter().map(|field| field.name);
+        writer.write_record(header).map_err(encode)?;
+
+        self.summary.files += 1;
+        Ok(CsvFile {
+            writer,
+            opened: Instant::now(),
+            bytes: 0,
+        })
+    }
+}
+
+/// Format each row of the [`ColumnBatch`] as CSV values.
+fn rows(batch: &ColumnBatch) -> impl Iterator<Item = Vec<String>> + '_ {
+    (0..batch.num_rows()).map(|row| {
+        batch
+            .columns
+            .iter()
+            .map(|column| match column {
+                Column::TimestampNanosecondUtc(values) => {
+                    DateTime::from_timestamp_nanos(values[row])
+                        .to_rfc3339_opts(SecondsFormat::Nanos, true)
+                }
+                Column::Utf8(values) => values[row].clone(),
+                Column::Float64(values) => values[row].to_string(),
+                Column::UInt64(values) => values[row].to_string(),
+            })
+            .collect()
+    })
+}
+
+fn encode(error: csv::Error) -> RecorderError {
+    RecorderError::Encode(error.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::subscription::trade::PublicTrade;
+    use barter_integration::model::{Exchange, Side};
+
+    #[tokio::test]
+    async fn test_csv_recorder() {
+        let root = std::env::temp_dir().join(format!(
+            "barter-data-csv-{}",
+            Utc::now().timestamp_nanos_opt().unwrap()
+        ));
+        let time = DateTime::from_timestamp(1_700_000_000, 0).unwrap();
+        let event = |instrument: &'static str, id: &str| MarketEvent {
+            exchange_time: time,
+            received_time: time,
+            exchange: Exchange::from("binance_spot"),
+            instrument,
+            kind: PublicTrade {
+                id: id.to_owned(),
+                price: 100.5,
+                amount: 1.0,
+                side: Side::Buy,
+            },
+        };
+
+        // Every btc_usdt row exceeds max_bytes, so each one is written to a rotated file
+        let recorder = CsvRecorder::new(CsvConfig {
+            max_bytes: 1,
+            ..CsvConfig::new(&root, "public_trades")
+        });
+
+        let summary = recorder
+            .run(futures::stream::iter([
+                event("btc_usdt", "1"),
+                event("btc_usdt", "2"),
+                event("eth/usdt", "3"),
+            ]))
+            .await
+            .unwrap();
+
+        assert_eq!(summary, RecorderSummary { rows: 3, files: 3 });
+
+        let mut contents = std::fs::read_dir(&root)
+            .unwrap()
+            .map(|entry| {
+                let path = entry.unwrap().path();
+                let name = path.file_name().unwrap().to_string_lossy().into_owned();
+                (
+                    name.split('.').next().unwrap().to_owned(),
+                    std::fs::read_to_string(path).unwrap(),
+                )
+            })
+            .collect::<Vec<_>>();
+        contents.sort();
+
+        let header = "exchange_time,received_time,exchange,instrument,id,price,amount,side";
+        let row = |instrument: &str, id: &str| {
+            format!(
+                "2023-11-14T22:13:20.000000000Z,2023-11-14T22:13:20.000000000Z,binance_spot,\
+                {instrument},{id},100.5,1,buy"
+            )
+        };
+
+        assert_eq!(contents.len(), 3);
+        assert_eq!(contents[0].0, "binance_spot_btc_usdt_public_trades");
+        assert_eq!(contents[2].0, "binance_spot_eth_usdt_public_trades");
+        assert_eq!(
+            contents[2].1,
+            format!("{header}\n{}\n", row("eth/usdt", "3"))
+        );
+
+        std::fs::remove_dir_all(root).unwrap();
+    }
+}
@@ -0,0 +1,70 @@
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+/// [`ParquetRecorder`](parquet::ParquetRecorder) that batches normalised
+/// [`MarketEvent`](crate::event::MarketEvent)s into Arrow record batches & writes them to
+/// partitioned Apache Parquet files.
+#[cfg(feature = "parquet")]
+pub mod parquet;
+
+/// [`CsvRecorder`](csv::CsvRecorder) that writes normalised
+/// [`MarketEvent`](crate::event::MarketEvent)s to rotated CSV files, without an Arrow dependency.
+#[cfg(feature = "csv")]
+pub mod csv;
+
+/// [`SqliteJournal`](sqlite::SqliteJournal) that durably captures normalised
+/// [`MarketEvent`](crate::event::MarketEvent)s in an embedded SQLite database.
+#[cfg(feature = "sqlite")]
+pub mod sqlite;
+
+/// [`ObjectStoreUploader`](upload::ObjectStoreUploader) that archives rolled capture files to
+/// S3, GCS or Azure object storage with configurable partitioning & retention.
+#[cfg(feature = "object-store")]
+pub mod upload;
+
+/// All errors generated by market data recorders.
+#[derive(Debug, Error)]
+pub enum RecorderError {
+    #[error("IO error: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("failed to encode MarketEvents: {0}")]
+    Encode(String),
+
+    #[error("database error: {0}")]
+    Database(String),
+
+    #[error("object store error: {0}")]
+    ObjectStore(String),
+}
+
+/// Summary of the [`MarketEvent`](crate::event::MarketEvent)s written by a recorder once its input
+/// stream has ended.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Default, Deserialize, Serialize)]
+pub struct RecorderSummary {
+    pub rows: u64,
+    pub files: u64,
+}
+
+/// Sanitise the provided value for use as a file path segment, replacing path separators with
+/// '_'.
+pub fn path_segment(value: &str) -> String {
+    value
+        .chars()
+        .map(|char| match char {
+            '/' | '\\' | ':' => '_',
+            char => char,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_path_segment() {
+        assert_eq!(path_segment("btc_usdt"), "btc_usdt");
+        assert_eq!(path_segment("BTC/USD:PERP\\x"), "BTC_USD_PERP_x");
+    }
+}
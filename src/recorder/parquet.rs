@@ -0,0 +1,281 @@
+use super::{path_segment, RecorderError, RecorderSummary};
+use crate::{
+    columnar::{ColumnBatch, ColumnBatchBuilder, Columnar},
+    event::MarketEvent,
+};
+use chrono::{NaiveDate, Utc};
+use futures::{Stream, StreamExt};
+use parquet::{arrow::ArrowWriter, basic::Compression, file::properties::WriterProperties};
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::HashMap,
+    fmt::Display,
+    fs::File,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+use tracing::info;
+
+/// Configuration for a [`ParquetRecorder`].
+#[derive(Clone, Eq, PartialEq, Hash, Debug, Deserialize, Serialize)]
+pub struct ParquetConfig {
+    /// Root directory that partitioned Parquet files are written beneath.
+    pub root: PathBuf,
+    /// Partition value identifying the recorded event kind (eg/ "public_trades").
+    pub kind: String,
+    /// Interval at which every buffered partition is flushed to a new Parquet file.
+    pub flush_interval: Duration,
+    /// Number of buffered rows at which a partition is flushed, regardless of the
+    /// `flush_interval`.
+    pub max_rows: usize,
+}
+
+impl ParquetConfig {
+    /// Construct a new [`ParquetConfig`] writing beneath the provided root directory, using the
+    /// default `flush_interval` (60s) & `max_rows` (100,000).
+    pub fn new<Root, Kind>(root: Root, kind: Kind) -> Self
+    where
+        Root: Into<PathBuf>,
+        Kind: Into<String>,
+    {
+        Self {
+            root: root.into(),
+            kind: kind.into(),
+            flush_interval: Duration::from_secs(60),
+            max_rows: 100_000,
+        }
+    }
+}
+
+/// Key of a [`ParquetRecorder`] partition.
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+struct Partition {
+    date: NaiveDate,
+    exchange: String,
+    instrument: String,
+}
+
+/// Recorder that batches normalised [`MarketEvent`]s into Arrow record batches, and writes them to
+/// Parquet files partitioned by date (of the `exchange_time`), exchange, instrument & kind.
+///
+/// Files are written using the Hive partitioning layout, so they can be queried directly by most
+/// research tooling (eg/ DuckDB, Polars, Spark):
+/// `{root}/kind={kind}/date={date}/exchange={exchange}/instrument={instrument}/part-{n}.parquet`
+#[derive(Debug)]
+pub struct ParquetRecorder<T> {
+    config: ParquetConfig,
+    partitions: HashMap<Partition, ColumnBatchBuilder<T>>,
+    summary: RecorderSummary,
+}
+
+impl<T> ParquetRecorder<T>
+where
+    T: Columnar,
+{
+    /// Construct a new [`ParquetRecorder`] using the provided [`ParquetConfig`].
+    pub fn new(config: ParquetConfig) -> Self {
+        Self {
+            config,
+            partitions: HashMap::new(),
+            summary: RecorderSummary::default(),
+        }
+    }
+
+    /// Buffer the [`MarketEvent`] in its partition, flushing the partition to a new Parquet file
+    /// if it has reached the configured `max_rows`.
+    pub fn record<InstrumentId>(
+        &mut self,
+        event: &MarketEvent<InstrumentId, T>,
+    ) -> Result<(), RecorderError>
+    where
+        InstrumentId: Display,
+    {
+        let partition = Partition {
+            date: event.exchange_time.date_naive(),
+            exchange: event.exchange.to_string(),
+            instrument: event.instrument.to_string(),
+        };
+
+        let builder = self.partitions.entry(partition.clone()).or_default();
+        builder.push(event);
+
+        if builder.len() >= self.config.max_rows {
+            let batch = builder.finish();
+            self.write(&partition, &batch)?;
+        }
+
+        Ok(())
+    }
+
+    /// Flush every buffered partition to a new Parquet file, returning the written paths.
+    pub fn flush(&mut self) -> Result<Vec<PathBuf>, RecorderError> {
+        let batches = self
+            .partitions
+            .iter_mut()
+            .filter(|(_, builder)| !builder.is_empty())
+            .map(|(partition, builder)| (partition.clone(), builder.finish()))
+            .collect::<Vec<_>>();
+
+        batches
+            .iter()
+            .map(|(partition, batch)| self.write(partition, batch))
+            .collect()
+    }
+
+    /// Record every [`MarketEvent`] of the provided [`Stream`] (eg/
+    /// [`Streams`](crate::streams::Streams)) until it ends, flushing every `flush_interval`,
+    /// and returning a [`RecorderSummary`] once every buffered partition has been flushed.
+    pub async fn run<St, InstrumentId>(
+        mut self,
+        mut stream: St,
+    ) -> Result<RecorderSummary, RecorderError>
+    where
+        St: Stream<Item = MarketEvent<InstrumentId, T>> + Unpin,
+        InstrumentId: Display,
+    {
+        let mut flush = tokio::time::interval(self.config.flush_interval);
+        flush.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+        flush.tick().await;
+
+        loop {
+            tokio::select! {
+                event = stream.next() => match event {
+                    Some(event) => self.record(&event)?,
+                    None => break,
+                },
+                _ = flush.tick() => {
+                    self.flush()?;
+                }
+            }
+        }
+
+        self.flush()?;
+        info!(summary = ?self.summary, "ParquetRecorder input stream ended");
+        Ok(self.summary)
+    }
+
+    /// Write the [`ColumnBatch`] to a new Parquet file in the directory of the [`Partition`].
+    fn write(
+        &mut self,
+        partition: &Partition,
+        batch: &ColumnBatch,
+    ) -> Result<PathBuf, RecorderError> {
+        let directory = self.directory(partition);
+        std::fs::create_dir_all(&directory)?;
+
+        let path = directory.join(format!(
+            "part-{}-{}.parquet",
+            Utc::now().timestamp_nanos_opt().unwrap_or_default(),
+            self.summary.files
+        ));
+
+        write_parquet(&path, batch)?;
+
+        self.summary.rows += batch.num_rows() as u64;
+        self.summary.files += 1;
+        Ok(path)
+    }
+
+    /// Hive partitioned directory of the provided [`Partition`].
+    fn directory(&self, partition: &Partition) -> PathBuf {
+        self.config
+            .root
+            .join(format!("kind={}", path_segment(&self.config.kind)))
+            .join(format!("date={}", partition.date))
+            .join(format!("exchange={}", path_segment(&partition.exchange)))
+            .join(format!(
+                "instrument={}",
+                path_segment(&partition.instrument)
+            ))
+    }
+}
+
+/// Write the [`ColumnBatch`] to a Snappy compressed Parquet file at the provided path.
+pub fn write_parquet(path: &Path, batch: &ColumnBatch) -> Result<(), RecorderError> {
+    let encode = |error: &dyn Display| RecorderError::Encode(error.to_string());
+
+    let batch = batch.to_record_batch().map_err(|error| encode(&error))?;
+    let properties = WriterProperties::builder()
+        .set_compression(Compression::SNAPPY)
+        .build();
+
+    let mut writer = ArrowWriter::try_new(File::create(path)?, batch.schema(), Some(properties))
+        .map_err(|error| encode(&error))?;
+    writer.write(&batch).map_err(|error| encode(&error))?;
+    writer.close().map_err(|error| encode(&error))?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::subscription::trade::PublicTrade;
+    use barter_integration::model::{Exchange, Side};
+    use chrono::DateTime;
+    use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+
+    #[tokio::test]
+    async fn test_parquet_recorder() {
+        let root = std::env::temp_dir().join(format!(
+            "barter-data-parquet-{}",
+            Utc::now().timestamp_nanos_opt().unwrap()
+        ));
+        let time = DateTime::from_timestamp(1_700_000_000, 0).unwrap();
+        let event = |instrument: &'static str, id: &str| MarketEvent {
+            exchange_time: time,
+            received_time: time,
+            exchange: Exchange::from("binance_spot"),
+            instrument,
+            kind: PublicTrade {
+                id: id.to_owned(),
+                price: 100.0,
+                amount: 1.0,
+                side: Side::Buy,
+            },
+        };
+
+        let recorder = ParquetRecorder::new(ParquetConfig {
+            max_rows: 2,
+            ..ParquetConfig::new(&root, "public_trades")
+        });
+
+        let summary = recorder
+            .run(futures::stream::iter([
+                event("btc_usdt", "1"),
+                event("btc_usdt", "2"),
+                event("btc_usdt", "3"),
+                event("eth/usdt", "4"),
+            ]))
+            .await
+            .unwrap();
+
+        // btc_usdt flushed once at max_rows, then every partition flushed once the stream ended
+        assert_eq!(summary, RecorderSummary { rows: 4, files: 3 });
+
+        let directory = |instrument: &str| {
+            root.join("kind=public_trades")
+                .join("date=2023-11-14")
+                .join("exchange=binance_spot")
+                .join(format!("instrument={instrument}"))
+        };
+        let rows = |instrument: &str| {
+            std::fs::read_dir(directory(instrument))
+                .unwrap()
+                .map(|entry| {
+                    let file = File::open(entry.unwrap().path()).unwrap();
+                    ParquetRecordBatchReaderBuilder::try_new(file)
+                        .unwrap()
+                        .build()
+                        .unwrap()
+                        .map(|batch| batch.unwrap().num_rows())
+                        .sum::<usize>()
+                })
+                .sum::<usize>()
+        };
+
+        assert_eq!(rows("btc_usdt"), 3);
+        assert_eq!(rows("eth_usdt"), 1);
+
+        std::fs::remove_dir_all(root).unwrap();
+    }
+}
@@ -0,0 +1,230 @@
+use super::{RecorderError, RecorderSummary};
+use crate::{
+    event::MarketEvent,
+    sink::{next_batch, BatchConfig},
+};
+use futures::Stream;
+use rusqlite::{params, Connection};
+use serde::Serialize;
+use std::{fmt::Display, path::Path};
+use tracing::info;
+
+/// Schema of the `market_events` journal table.
+///
+/// Times are nanoseconds since the Unix epoch, and `payload` is the JSON serialised event kind.
+const SCHEMA: &str = "
+    CREATE TABLE IF NOT EXISTS market_events (
+        id INTEGER PRIMARY KEY AUTOINCREMENT,
+        exchange_time INTEGER NOT NULL,
+        received_time INTEGER NOT NULL,
+        exchange TEXT NOT NULL,
+        instrument TEXT NOT NULL,
+        kind TEXT NOT NULL,
+        payload TEXT NOT NULL
+    );
+    CREATE INDEX IF NOT EXISTS market_events_instrument_time
+        ON market_events (exchange, instrument, exchange_time);
+";
+
+const INSERT: &str = "
+    INSERT INTO market_events (exchange_time, received_time, exchange, instrument, kind, payload)
+    VALUES (?1, ?2, ?3, ?4, ?5, ?6)
+";
+
+/// Lightweight journal that durably captures normalised [`MarketEvent`]s in an embedded SQLite
+/// database, for small deployments & edge machines where a full database is overkill.
+///
+/// The database is opened in WAL mode, and [`MarketEvent`]s are written in batched transactions
+/// (see [`BatchConfig`]) to the `market_events` table.
+///
+/// Note that SQLite writes are blocking, and are performed on the task driving
+/// [`SqliteJournal::run`].
+#[derive(Debug)]
+pub struct SqliteJournal {
+    connection: Connection,
+    kind: String,
+    batch: BatchConfig,
+    summary: RecorderSummary,
+}
+
+impl SqliteJournal {
+    /// Open (or create) the SQLite journal at the provided path in WAL mode, recording every
+    /// [`MarketEvent`] with the provided `kind` (eg/ "public_trades").
+    pub fn open<P, Kind>(path: P, kind: Kind) -> Result<Self, RecorderError>
+    where
+        P: AsRef<Path>,
+        Kind: Into<String>,
+    {
+        let connection = Connection::open(path).map_err(database)?;
+        connection
+            .pragma_update(None, "journal_mode", "WAL")
+            .map_err(database)?;
+        connection
+            .pragma_update(None, "synchronous", "NORMAL")
+            .map_err(database)?;
+        connection.execute_batch(SCHEMA).map_err(database)?;
+
+        Ok(Self {
+            connection,
+            kind: kind.into(),
+            batch: BatchConfig::default(),
+            summary: RecorderSummary { rows: 0, files: 1 },
+        })
+    }
+
+    /// Configure how [`MarketEvent`]s are batched into transactions.
+    pub fn with_batch(self, batch: BatchConfig) -> Self {
+        Self { batch, ..self }
+    }
+
+    /// Write the [`MarketEvent`]s to the journal in a single transaction.
+    pub fn write<InstrumentId, T>(
+        &mut self,
+        events: &[MarketEvent<InstrumentId, T>],
+    ) -> Result<(), RecorderError>
+    where
+        InstrumentId: Display,
+        T: Serialize,
+    {
+        let transaction = self.connection.transaction().map_err(database)?;
+        {
+            let mut insert = transaction.prepare_cached(INSERT).map_err(database)?;
+            for event in events {
+                let payload = serde_json::to_string(&event.kind)
+                    .map_err(|error| RecorderError::Encode(error.to_string()))?;
+
+                insert
+                    .execute(params![
+                        event
+                            .exchange_time
+                            .timestamp_nanos_opt()
+                            .unwrap_or_default(),
+                        event
+                            .received_time
+                            .timestamp_nanos_opt()
+                            .unwrap_or_default(),
+                        event.exchange.to_string(),
+                        event.instrument.to_string(),
+                        self.kind,
+                        payload,
+                    ])
+                    .map_err(database)?;
+            }
+        }
+        transaction.commit().map_err(database)?;
+
+        self.summary.rows += events.len() as u64;
+        Ok(())
+    }
+
+    /// Journal every [`MarketEvent`] of the provided [`Stream`] (eg/
+    /// [`Streams`](crate::streams::Streams)) until it ends, returning a [`RecorderSummary`].
+    pub async fn run<St, InstrumentId, T>(
+        mut self,
+        mut stream: St,
+    ) -> Result<RecorderSummary, RecorderError>
+    where
+        St: Stream<Item = MarketEvent<InstrumentId, T>> + Unpin,
+        InstrumentId: Display,
+        T: Serialize,
+    {
+        while let Some(batch) = next_batch(&mut stream, &self.batch).await {
+            self.write(&batch)?;
+        }
+
+        info!(summary = ?self.summary, "SqliteJournal input stream ended");
+        Ok(self.summary)
+    }
+}
+
+fn database(error: rusqlite::Error) -> RecorderError {
+    RecorderError::Database(error.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::subscription::trade::PublicTrade;
+    use barter_integration::model::{Exchange, Side};
+    use chrono::{DateTime, Utc};
+
+    #[tokio::test]
+    async fn test_sqlite_journal() {
+        let path = std::env::temp_dir().join(format!(
+            "barter-data-journal-{}.sqlite",
+            Utc::now().timestamp_nanos_opt().unwrap()
+        ));
+        let time = DateTime::from_timestamp(1_700_000_000, 0).unwrap();
+        let event = |instrument: &'static str, id: &str| MarketEvent {
+            exchange_time: time,
+            received_time: time,
+            exchange: Exchange::from("binance_spot"),
+            instrument,
+            kind: PublicTrade {
+                id: id.to_owned(),
+                price: 100.0,
+                amount: 1.0,
+                side: Side::Buy,
+            },
+        };
+
+        let journal = SqliteJournal::open(&path, "public_trades")
+            .unwrap()
+            .with_batch(BatchConfig {
+                max_events: 2,
+                ..BatchConfig::default()
+            });
+
+        let summary = journal
+            .run(futures::stream::iter([
+                event("btc_usdt", "1"),
+                event("btc_usdt", "2"),
+                event("eth_usdt", "3"),
+            ]))
+            .await
+            .unwrap();
+        assert_eq!(summary, RecorderSummary { rows: 3, files: 1 });
+
+        // Re-opening the journal retains the previously written MarketEvents
+        let journal = SqliteJournal::open(&path, "public_trades").unwrap();
+        let journal_mode = journal
+            .connection
+            .query_row("PRAGMA journal_mode", [], |row| row.get::<_, String>(0))
+            .unwrap();
+        assert_eq!(journal_mode, "wal");
+
+        let (instrument, exchange_time, payload) = journal
+            .connection
+            .query_row(
+                "SELECT instrument, exchange_time, payload FROM market_events ORDER BY id DESC",
+                [],
+                |row| {
+                    Ok((
+                        row.get::<_, String>(0)?,
+                        row.get::<_, i64>(1)?,
+                        row.get::<_, String>(2)?,
+                    ))
+                },
+            )
+            .unwrap();
+        assert_eq!(instrument, "eth_usdt");
+        assert_eq!(exchange_time, 1_700_000_000_000_000_000);
+        assert_eq!(
+            serde_json::from_str::<PublicTrade>(&payload).unwrap().id,
+            "3"
+        );
+
+        let count = journal
+            .connection
+            .query_row("SELECT COUNT(*) FROM market_events", [], |row| {
+                row.get::<_, u64>(0)
+            })
+            .unwrap();
+        assert_eq!(count, 3);
+
+        drop(journal);
+        ["", "-wal", "-shm"].into_iter().for_each(|suffix| {
+            let _ = std::fs::remove_file(format!("{}{suffix}", path.display()));
+        });
+    }
+}
@@ -0,0 +1,409 @@
+use super::RecorderError;
+use chrono::{DateTime, Utc};
+use futures::{Future, TryStreamExt};
+use object_store::{path::Path as ObjectPath, ObjectStore, PutPayload};
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::HashMap,
+    fmt::Debug,
+    path::{Path, PathBuf},
+    sync::Arc,
+    time::{Duration, SystemTime},
+};
+use tracing::{info, warn};
+
+/// Object key partitioning applied to uploaded capture files, based on the time each file was
+/// last modified (ie/ rolled).
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Default, Deserialize, Serialize)]
+pub enum Partitioning {
+    /// `{prefix}/{file}`, eg/ for recorders that already partition their files (see
+    /// [`ParquetRecorder`](super::parquet::ParquetRecorder)).
+    None,
+    /// `{prefix}/date={date}/{file}`
+    #[default]
+    Daily,
+    /// `{prefix}/date={date}/hour={hour}/{file}`
+    Hourly,
+}
+
+/// Configuration for an [`ObjectStoreUploader`].
+#[derive(Clone, Eq, PartialEq, Hash, Debug, Deserialize, Serialize)]
+pub struct UploadConfig {
+    /// Local capture directory that is recursively scanned for rolled files (eg/ the
+    /// [`CsvConfig`](super::csv::CsvConfig) `root`).
+    pub root: PathBuf,
+    /// Object key prefix that files are uploaded beneath (eg/ "market-data/trades").
+    pub prefix: String,
+    /// Object key partitioning applied to uploaded files.
+    pub partitioning: Partitioning,
+    /// Interval at which the capture directory is scanned for rolled files.
+    pub scan_interval: Duration,
+    /// Duration a file must remain unmodified before it is considered rolled by its recorder, and
+    /// therefore ready for upload.
+    pub settle: Duration,
+    /// Age at which uploaded local files are deleted, or `None` to keep them indefinitely.
+    pub local_retention: Option<Duration>,
+    /// Age at which uploaded objects beneath the `prefix` are deleted, or `None` to keep them
+    /// indefinitely.
+    pub remote_retention: Option<Duration>,
+}
+
+impl UploadConfig {
+    /// Construct a new [`UploadConfig`] uploading the files of the provided root directory beneath
+    /// the object key `prefix`, using [`Partitioning::Daily`], a 60s `scan_interval` & `settle`,
+    /// deleting local files once uploaded, and retaining objects indefinitely.
+    pub fn new<Root, Prefix>(root: Root, prefix: Prefix) -> Self
+    where
+        Root: Into<PathBuf>,
+        Prefix: Into<String>,
+    {
+        Self {
+            root: root.into(),
+            prefix: prefix.into(),
+            partitioning: Partitioning::default(),
+            scan_interval: Duration::from_secs(60),
+            settle: Duration::from_secs(60),
+            local_retention: Some(Duration::ZERO),
+            remote_retention: None,
+        }
+    }
+}
+
+/// Summary of the capture files handled by an [`ObjectStoreUploader`].
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Default, Deserialize, Serialize)]
+pub struct UploadSummary {
+    pub uploaded: u64,
+    pub bytes: u64,
+    pub deleted_local: u64,
+    pub deleted_remote: u64,
+}
+
+/// Uploader that archives the rolled capture files of a recorder (eg/
+/// [`CsvRecorder`](super::csv::CsvRecorder), [`ParquetRecorder`](super::parquet::ParquetRecorder))
+/// to any [`ObjectStore`] (eg/ S3, GCS, Azure Blob Storage), applying the configured partitioning
+/// & retention.
+///
+/// Files are uploaded to `{prefix}/{partition}/{path}`, where `path` is the file path relative to
+/// the capture `root`. Files are considered rolled once they have been unmodified for the
+/// configured `settle` duration, so it should exceed the interval between writes to an open file.
+#[derive(Debug)]
+pub struct ObjectStoreUploader {
+    store: Arc<dyn ObjectStore>,
+    config: UploadConfig,
+    uploaded: HashMap<PathBuf, SystemTime>,
+    summary: UploadSummary,
+}
+
+impl ObjectStoreUploader {
+    /// Construct a new [`ObjectStoreUploader`] uploading to the provided [`ObjectStore`] (eg/
+    /// `object_store::aws::AmazonS3Builder::from_env().with_bucket_name(..).build()?`).
+    pub fn new(store: Arc<dyn ObjectStore>, config: UploadConfig) -> Self {
+        Self {
+            store,
+            config,
+            uploaded: HashMap::new(),
+            summary: UploadSummary::default(),
+        }
+    }
+
+    /// Scan the capture directory every `scan_interval`, uploading rolled files & applying the
+    /// retention policy, until the provided `shutdown` future resolves.
+    ///
+    /// Once shutdown, every remaining file is uploaded regardless of the `settle` duration, so
+    /// the recorders writing to the capture directory should be stopped first.
+    pub async fn run<Shutdown>(mut self, shutdown: Shutdown) -> Result<UploadSummary, RecorderError>
+    where
+        Shutdown: Future<Output = ()>,
+    {
+        let mut scan = tokio::time::interval(self.config.scan_interval);
+        scan.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+        tokio::pin!(shutdown);
+
+        loop {
+            tokio::select! {
+                _ = &mut shutdown => break,
+                _ = scan.tick() => {
+                    self.upload(self.config.settle).await?;
+                }
+            }
+        }
+
+        self.upload(Duration::ZERO).await?;
+        info!(summary = ?self.summary, "ObjectStoreUploader shutdown");
+        Ok(self.summary)
+    }
+
+    /// Upload every file of the capture directory that has been unmodified for at least `settle`,
+    /// then apply the retention policy, returning the cumulative [`UploadSummary`].
+    ///
+    /// Files that fail to upload are retried on the next scan.
+    pub async fn upload(&mut self, settle: Duration) -> Result<UploadSummary, RecorderError> {
+        let now = SystemTime::now();
+
+        for (path, modified) in files(&self.config.root)? {
+            let age = now.duration_since(modified).unwrap_or_default();
+            if age < settle {
+                continue;
+            }
+
+            if self.uploaded.get(&path) != Some(&modified) {
+                let key = self.key(&path, modified);
+                if let Err(error) = self.put(&path, &key).await {
+                    warn!(%error, ?path, %key, "ObjectStoreUploader failed to upload file");
+                    continue;
+                }
+                self.uploaded.insert(path.clone(), modified);
+            }
+
+            if self
+                .config
+                .local_retention
+                .is_some_and(|retention| age >= retention)
+            {
+                std::fs::remove_file(&path)?;
+                self.uploaded.remove(&path);
+                self.summary.deleted_local += 1;
+            }
+        }
+
+        if let Some(retention) = self.config.remote_retention {
+            self.expire(retention).await?;
+        }
+
+        Ok(self.summary)
+    }
+
+    /// Object key of the provided capture file, last modified at the provided time.
+    pub fn key(&self, path: &Path, modified: SystemTime) -> ObjectPath {
+        let modified = DateTime::<Utc>::from(modified);
+        let partition = match self.config.partitioning {
+            Partitioning::None => vec![],
+            Partitioning::Daily => vec![format!("date={}", modified.format("%Y-%m-%d"))],
+            Partitioning::Hourly => vec![
+                format!("date={}", modified.format("%Y-%m-%d")),
+                format!("hour={}", modified.format("%H")),
+            ],
+        };
+
+        let relative = path
+            .strip_prefix(&self.config.root)
+            .unwrap_or(path)
+            .components()
+            .map(|component| component.as_os_str().to_string_lossy().into_owned());
+
+        self.config
+            .prefix
+            .split('/')
+            .map(str::to_owned)
+            .chain(partition)
+            .chain(relative)
+            .collect()
+    }
+
+    /// Upload the local file at the provided path to the object key.
+    async fn put(&mut self, path: &Path, key: &ObjectPath) -> Result<(), RecorderError> {
+        let path = path.to_owned();
+        let bytes = tokio::task::spawn_blocking(move || std::fs::read(path))
+            .await
+            .map_err(|error| RecorderError::Io(std::io::Error::other(error)))??;
+
+        let size = bytes.len() as u64;
+        self.store
+            .put(key, PutPayload::from(bytes))
+            .await
+            .map_err(object_store)?;
+
+        self.summary.uploaded += 1;
+        self.summary.bytes += size;
+        Ok(())
+    }
+
+    /// Delete every object beneath the prefix that was last modified longer ago than the provided
+    /// retention.
+    async fn expire(&mut self, retention: Duration) -> Result<(), RecorderError> {
+        let prefix = ObjectPath::from_iter(self.config.prefix.split('/'));
+        let cutoff = Utc::now() - retention;
+
+        let expired = self
+            .store
+            .list(Some(&prefix))
+            .try_filter(|meta| futures::future::ready(meta.last_modified < cutoff))
+            .map_ok(|meta| meta.location)
+            .try_collect::<Vec<_>>()
+            .await
+            .map_err(object_store)?;
+
+        for location in expired {
+            self.store.delete(&location).await.map_err(object_store)?;
+            self.summary.deleted_remote += 1;
+        }
+
+        Ok(())
+    }
+}
+
+/// Recursively list every file beneath the provided directory alongside its last modified time,
+/// ignoring hidden files (eg/ in-progress temporary files).
+fn files(directory: &Path) -> Result<Vec<(PathBuf, SystemTime)>, RecorderError> {
+    if !directory.exists() {
+        return Ok(vec![]);
+    }
+
+    let mut files = Vec::new();
+    for entry in std::fs::read_dir(directory)? {
+        let entry = entry?;
+        let path = entry.path();
+        if entry.file_name().to_string_lossy().starts_with('.') {
+            continue;
+        }
+
+        let metadata = entry.metadata()?;
+        if metadata.is_dir() {
+            files.extend(self::files(&path)?);
+        } else if metadata.is_file() {
+            files.push((path, metadata.modified()?));
+        }
+    }
+
+    Ok(files)
+}
+
+fn object_store(error: object_store::Error) -> RecorderError {
+    RecorderError::ObjectStore(error.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use object_store::memory::InMemory;
+
+    fn root() -> PathBuf {
+        std::env::temp_dir().join(format!(
+            "barter-data-upload-{}",
+            Utc::now().timestamp_nanos_opt().unwrap()
+        ))
+    }
+
+    async fn keys(store: &InMemory) -> Vec<String> {
+        let mut keys = store
+            .list(None)
+            .map_ok(|meta| meta.location.to_string())
+            .try_collect::<Vec<_>>()
+            .await
+            .unwrap();
+        keys.sort();
+        keys
+    }
+
+    #[test]
+    fn test_key() {
+        let root = PathBuf::from("/capture");
+        let modified = SystemTime::from(
+            DateTime::parse_from_rfc3339("2024-01-02T03:04:05Z")
+                .unwrap()
+                .with_timezone(&Utc),
+        );
+        let key = |partitioning| {
+            let config = UploadConfig {
+                partitioning,
+                ..UploadConfig::new(&root, "archive/trades/")
+            };
+            ObjectStoreUploader::new(Arc::new(InMemory::new()), config)
+                .key(&root.join("kind=trades").join("part-0.parquet"), modified)
+                .to_string()
+        };
+
+        // TC0: Partitioning::None
+        assert_eq!(
+            key(Partitioning::None),
+            "archive/trades/kind=trades/part-0.parquet"
+        );
+
+        // TC1: Partitioning::Daily
+        assert_eq!(
+            key(Partitioning::Daily),
+            "archive/trades/date=2024-01-02/kind=trades/part-0.parquet"
+        );
+
+        // TC2: Partitioning::Hourly
+        assert_eq!(
+            key(Partitioning::Hourly),
+            "archive/trades/date=2024-01-02/hour=03/kind=trades/part-0.parquet"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_upload() {
+        let root = root();
+        std::fs::create_dir_all(root.join("nested")).unwrap();
+        std::fs::write(root.join("a.csv"), "a").unwrap();
+        std::fs::write(root.join("nested").join("b.csv"), "bb").unwrap();
+        std::fs::write(root.join(".tmp"), "ignored").unwrap();
+
+        let store = Arc::new(InMemory::new());
+        let config = UploadConfig {
+            partitioning: Partitioning::None,
+            local_retention: None,
+            ..UploadConfig::new(&root, "capture")
+        };
+        let mut uploader = ObjectStoreUploader::new(store.clone(), config);
+
+        // TC0: files that have not settled are not uploaded
+        let summary = uploader.upload(Duration::from_secs(3600)).await.unwrap();
+        assert_eq!(summary, UploadSummary::default());
+
+        // TC1: settled files are uploaded, and retained locally
+        let summary = uploader.upload(Duration::ZERO).await.unwrap();
+        assert_eq!(summary.uploaded, 2);
+        assert_eq!(summary.bytes, 3);
+        assert_eq!(
+            keys(&store).await,
+            vec!["capture/a.csv", "capture/nested/b.csv"]
+        );
+        assert!(root.join("a.csv").exists());
+
+        // TC2: unmodified files are not uploaded again
+        let summary = uploader.upload(Duration::ZERO).await.unwrap();
+        assert_eq!(summary.uploaded, 2);
+
+        // TC3: uploaded files are deleted locally once the local retention has elapsed
+        uploader.config.local_retention = Some(Duration::ZERO);
+        let summary = uploader.upload(Duration::ZERO).await.unwrap();
+        assert_eq!(summary.uploaded, 2);
+        assert_eq!(summary.deleted_local, 2);
+        assert!(!root.join("a.csv").exists());
+
+        // TC4: uploaded objects are deleted once the remote retention has elapsed
+        uploader.config.remote_retention = Some(Duration::ZERO);
+        let summary = uploader.upload(Duration::ZERO).await.unwrap();
+        assert_eq!(summary.deleted_remote, 2);
+        assert!(keys(&store).await.is_empty());
+
+        std::fs::remove_dir_all(root).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_run_uploads_remaining_files_on_shutdown() {
+        let root = root();
+        std::fs::create_dir_all(&root).unwrap();
+        std::fs::write(root.join("a.csv"), "a").unwrap();
+
+        let store = Arc::new(InMemory::new());
+        let config = UploadConfig {
+            settle: Duration::from_secs(3600),
+            ..UploadConfig::new(&root, "capture")
+        };
+
+        let summary = ObjectStoreUploader::new(store.clone(), config)
+            .run(futures::future::ready(()))
+            .await
+            .unwrap();
+
+        assert_eq!(summary.uploaded, 1);
+        assert_eq!(summary.deleted_local, 1);
+        assert_eq!(keys(&store).await.len(), 1);
+        assert!(keys(&store).await[0].starts_with("capture/date="));
+
+        std::fs::remove_dir_all(root).unwrap();
+    }
+}
@@ -0,0 +1,97 @@
+use futures::{future::BoxFuture, FutureExt};
+use std::{fmt::Debug, future::Future, sync::Arc};
+
+/// Executor used to spawn the background tasks of Barter-Data (eg/ consumer loops, WebSocket
+/// distribution & ping tasks, [`Streams`](crate::streams::Streams) combinators).
+///
+/// Provided via a [`Spawner`] handle (eg/ using
+/// [`StreamBuilder::with_spawner`](crate::streams::builder::StreamBuilder::with_spawner)),
+/// allowing the crate to be embedded in custom reactors.
+///
+/// ### Notes
+/// Only task spawning is pluggable. The WebSocket transport, channels & timers are provided by
+/// Tokio (via `barter-integration`), so the spawned tasks must still be driven within a Tokio
+/// runtime context (eg/ a [`Spawn`] that delegates to a dedicated runtime
+/// [`Handle`](tokio::runtime::Handle)).
+pub trait Spawn: Send + Sync {
+    /// Spawn the provided task, driving it to completion in the background.
+    fn spawn(&self, task: BoxFuture<'static, ()>);
+}
+
+/// Default [`Spawn`] implementation utilising [`tokio::spawn`].
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Default)]
+pub struct TokioSpawn;
+
+impl Spawn for TokioSpawn {
+    fn spawn(&self, task: BoxFuture<'static, ()>) {
+        drop(tokio::spawn(task));
+    }
+}
+
+/// Cheaply cloneable handle to the [`Spawn`] executor used to spawn background tasks.
+///
+/// Defaults to [`TokioSpawn`].
+#[derive(Clone)]
+pub struct Spawner(Arc<dyn Spawn>);
+
+impl Spawner {
+    /// Construct a new [`Self`] using the provided [`Spawn`] executor.
+    pub fn new<S>(spawner: S) -> Self
+    where
+        S: Spawn + 'static,
+    {
+        Self(Arc::new(spawner))
+    }
+
+    /// Spawn the provided task using the [`Spawn`] executor, discarding its output.
+    pub fn spawn<F>(&self, task: F)
+    where
+        F: Future + Send + 'static,
+    {
+        self.0.spawn(Box::pin(task.map(drop)))
+    }
+}
+
+impl Default for Spawner {
+    fn default() -> Self {
+        Self::new(TokioSpawn)
+    }
+}
+
+impl Debug for Spawner {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Spawner").finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use tokio::sync::oneshot;
+
+    #[derive(Debug, Default)]
+    struct CountingSpawn(Arc<AtomicUsize>);
+
+    impl Spawn for CountingSpawn {
+        fn spawn(&self, task: BoxFuture<'static, ()>) {
+            self.0.fetch_add(1, Ordering::SeqCst);
+            drop(tokio::spawn(task));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_spawner() {
+        // Provided executor is used to spawn (and delegates to Tokio to drive the task)
+        let spawned = Arc::new(AtomicUsize::new(0));
+        let spawner = Spawner::new(CountingSpawn(spawned.clone()));
+
+        let (tx, rx) = oneshot::channel();
+        spawner.spawn(async move {
+            let _ = tx.send(1);
+        });
+
+        assert_eq!(rx.await, Ok(1));
+        assert_eq!(spawned.load(Ordering::SeqCst), 1);
+    }
+}
@@ -0,0 +1,202 @@
+use crate::{
+    event::DataKind,
+    subscription::{
+        book::{Level, OrderBook, OrderBookL1},
+        candle::Candle,
+        trade::PublicTrade,
+    },
+};
+
+/// FIX `MDEntryType` (269) of a [`MdEntry`].
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum MdEntryType {
+    Bid,
+    Offer,
+    Trade,
+    OpeningPrice,
+    ClosingPrice,
+    TradingSessionHighPrice,
+    TradingSessionLowPrice,
+    TradeVolume,
+}
+
+impl MdEntryType {
+    /// FIX `MDEntryType` (269) value of the [`MdEntryType`].
+    pub fn as_char(&self) -> char {
+        match self {
+            Self::Bid => '0',
+            Self::Offer => '1',
+            Self::Trade => '2',
+            Self::OpeningPrice => '4',
+            Self::ClosingPrice => '5',
+            Self::TradingSessionHighPrice => '7',
+            Self::TradingSessionLowPrice => '8',
+            Self::TradeVolume => 'B',
+        }
+    }
+
+    /// Parse the [`MdEntryType`] from the provided FIX `MDEntryType` (269) value, returning `None`
+    /// if it is not supported.
+    pub fn from_fix(value: &str) -> Option<Self> {
+        match value {
+            "0" => Some(Self::Bid),
+            "1" => Some(Self::Offer),
+            "2" => Some(Self::Trade),
+            "4" => Some(Self::OpeningPrice),
+            "5" => Some(Self::ClosingPrice),
+            "7" => Some(Self::TradingSessionHighPrice),
+            "8" => Some(Self::TradingSessionLowPrice),
+            "B" => Some(Self::TradeVolume),
+            _ => None,
+        }
+    }
+}
+
+/// Market data entry of a FIX `MarketDataSnapshotFullRefresh` (W) or
+/// `MarketDataIncrementalRefresh` (X) message.
+#[derive(Clone, PartialEq, Debug)]
+pub struct MdEntry {
+    pub kind: MdEntryType,
+    /// `MDEntryPx` (270).
+    pub price: Option<f64>,
+    /// `MDEntrySize` (271).
+    pub size: Option<f64>,
+    /// `MDEntryID` (278), eg/ the trade id.
+    pub id: Option<String>,
+    /// `MDEntryPositionNo` (290), ie/ the one-based depth of an order book level.
+    pub position: Option<usize>,
+}
+
+impl MdEntry {
+    fn new(kind: MdEntryType, price: f64, size: Option<f64>) -> Self {
+        Self {
+            kind,
+            price: Some(price),
+            size,
+            id: None,
+            position: None,
+        }
+    }
+
+    fn levels(kind: MdEntryType, levels: &[Level]) -> impl Iterator<Item = Self> + '_ {
+        levels.iter().enumerate().map(move |(index, level)| Self {
+            position: Some(index + 1),
+            ..Self::new(kind, level.price, Some(level.amount))
+        })
+    }
+}
+
+/// Normalised market data converted into FIX [`MdEntry`]s, published as either a full refresh
+/// of the instrument state, or an incremental update.
+#[derive(Clone, PartialEq, Debug)]
+pub enum FixUpdate {
+    /// Full instrument state (eg/ an [`OrderBook`] snapshot), published as a
+    /// `MarketDataSnapshotFullRefresh` (W).
+    Snapshot(Vec<MdEntry>),
+    /// Incremental update (eg/ a [`PublicTrade`]), published as a `MarketDataIncrementalRefresh`
+    /// (X).
+    Incremental(Vec<MdEntry>),
+}
+
+impl FixUpdate {
+    /// [`MdEntry`]s of the [`FixUpdate`].
+    pub fn entries(&self) -> &[MdEntry] {
+        match self {
+            Self::Snapshot(entries) | Self::Incremental(entries) => entries,
+        }
+    }
+}
+
+impl From<&DataKind> for Option<FixUpdate> {
+    fn from(kind: &DataKind) -> Self {
+        match kind {
+            DataKind::Trade(trade) => trade.into(),
+            DataKind::OrderBookL1(book) => book.into(),
+            DataKind::OrderBook(book) => book.into(),
+            DataKind::Candle(candle) => candle.into(),
+            _ => None,
+        }
+    }
+}
+
+impl From<&PublicTrade> for Option<FixUpdate> {
+    fn from(trade: &PublicTrade) -> Self {
+        Some(FixUpdate::Incremental(vec![MdEntry {
+            id: Some(trade.id.clone()),
+            ..MdEntry::new(MdEntryType::Trade, trade.price, Some(trade.amount))
+        }]))
+    }
+}
+
+impl From<&OrderBookL1> for Option<FixUpdate> {
+    fn from(book: &OrderBookL1) -> Self {
+        Some(FixUpdate::Snapshot(
+            MdEntry::levels(MdEntryType::Bid, &[book.best_bid])
+                .chain(MdEntry::levels(MdEntryType::Offer, &[book.best_ask]))
+                .collect(),
+        ))
+    }
+}
+
+impl From<&OrderBook> for Option<FixUpdate> {
+    fn from(book: &OrderBook) -> Self {
+        Some(FixUpdate::Snapshot(
+            MdEntry::levels(MdEntryType::Bid, book.bids.levels())
+                .chain(MdEntry::levels(MdEntryType::Offer, book.asks.levels()))
+                .collect(),
+        ))
+    }
+}
+
+impl From<&Candle> for Option<FixUpdate> {
+    fn from(candle: &Candle) -> Self {
+        Some(FixUpdate::Incremental(vec![
+            MdEntry::new(MdEntryType::OpeningPrice, candle.open, None),
+            MdEntry::new(MdEntryType::TradingSessionHighPrice, candle.high, None),
+            MdEntry::new(MdEntryType::TradingSessionLowPrice, candle.low, None),
+            MdEntry::new(MdEntryType::ClosingPrice, candle.close, None),
+            MdEntry {
+                kind: MdEntryType::TradeVolume,
+                price: None,
+                size: Some(candle.volume),
+                id: None,
+                position: None,
+            },
+        ]))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use barter_integration::model::Side;
+    use chrono::Utc;
+
+    #[test]
+    fn test_order_book_fix_update() {
+        let book = OrderBook {
+            last_update_time: Utc::now(),
+            bids: crate::subscription::book::OrderBookSide::new(
+                Side::Buy,
+                vec![(100.0, 1.0), (99.0, 2.0)],
+            ),
+            asks: crate::subscription::book::OrderBookSide::new(Side::Sell, vec![(101.0, 3.0)]),
+        };
+
+        let Some(FixUpdate::Snapshot(entries)) = Option::<FixUpdate>::from(&book) else {
+            panic!("OrderBook should convert into a FixUpdate::Snapshot");
+        };
+
+        assert_eq!(
+            entries
+                .iter()
+                .map(|entry| (entry.kind, entry.price, entry.size, entry.position))
+                .collect::<Vec<_>>(),
+            vec![
+                (MdEntryType::Bid, Some(100.0), Some(1.0), Some(1)),
+                (MdEntryType::Bid, Some(99.0), Some(2.0), Some(2)),
+                (MdEntryType::Offer, Some(101.0), Some(3.0), Some(1)),
+            ]
+        );
+    }
+}
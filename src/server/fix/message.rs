@@ -0,0 +1,308 @@
+use chrono::{DateTime, Utc};
+use std::fmt::Write;
+use thiserror::Error;
+
+/// FIX 4.4 `BeginString` (8) of every message.
+pub const BEGIN_STRING: &str = "FIX.4.4";
+
+/// FIX field delimiter (ASCII SOH).
+pub const SOH: u8 = 0x01;
+
+/// Maximum length in bytes of an encoded [`FixMessage`] accepted by [`FixMessage::decode`].
+pub const MAX_MESSAGE_LEN: usize = 64 * 1024;
+
+/// FIX 4.4 field tags used by the [`FixGateway`](super::FixGateway).
+pub mod tag {
+    pub const BEGIN_STRING: u32 = 8;
+    pub const BODY_LENGTH: u32 = 9;
+    pub const CHECK_SUM: u32 = 10;
+    pub const MSG_SEQ_NUM: u32 = 34;
+    pub const MSG_TYPE: u32 = 35;
+    pub const SENDER_COMP_ID: u32 = 49;
+    pub const SENDING_TIME: u32 = 52;
+    pub const SYMBOL: u32 = 55;
+    pub const TARGET_COMP_ID: u32 = 56;
+    pub const TEXT: u32 = 58;
+    pub const ENCRYPT_METHOD: u32 = 98;
+    pub const HEART_BT_INT: u32 = 108;
+    pub const TEST_REQ_ID: u32 = 112;
+    pub const NO_RELATED_SYM: u32 = 146;
+    pub const SECURITY_EXCHANGE: u32 = 207;
+    pub const MD_REQ_ID: u32 = 262;
+    pub const SUBSCRIPTION_REQUEST_TYPE: u32 = 263;
+    pub const MARKET_DEPTH: u32 = 264;
+    pub const NO_MD_ENTRY_TYPES: u32 = 267;
+    pub const NO_MD_ENTRIES: u32 = 268;
+    pub const MD_ENTRY_TYPE: u32 = 269;
+    pub const MD_ENTRY_PX: u32 = 270;
+    pub const MD_ENTRY_SIZE: u32 = 271;
+    pub const MD_ENTRY_DATE: u32 = 272;
+    pub const MD_ENTRY_TIME: u32 = 273;
+    pub const MD_ENTRY_ID: u32 = 278;
+    pub const MD_UPDATE_ACTION: u32 = 279;
+    pub const MD_REQ_REJ_REASON: u32 = 281;
+    pub const MD_ENTRY_POSITION_NO: u32 = 290;
+    pub const REF_MSG_TYPE: u32 = 372;
+    pub const BUSINESS_REJECT_REASON: u32 = 380;
+}
+
+/// FIX 4.4 `MsgType` (35) values used by the [`FixGateway`](super::FixGateway).
+pub mod msg_type {
+    pub const HEARTBEAT: &str = "0";
+    pub const TEST_REQUEST: &str = "1";
+    pub const LOGOUT: &str = "5";
+    pub const LOGON: &str = "A";
+    pub const MARKET_DATA_REQUEST: &str = "V";
+    pub const MARKET_DATA_SNAPSHOT_FULL_REFRESH: &str = "W";
+    pub const MARKET_DATA_INCREMENTAL_REFRESH: &str = "X";
+    pub const MARKET_DATA_REQUEST_REJECT: &str = "Y";
+    pub const BUSINESS_MESSAGE_REJECT: &str = "j";
+}
+
+/// All errors generated when decoding FIX messages.
+#[derive(Debug, Error, Clone, Eq, PartialEq)]
+pub enum FixError {
+    #[error("invalid FIX message framing: {0}")]
+    Framing(String),
+
+    #[error("invalid FIX CheckSum: expected {expected:03}, received {received:03}")]
+    CheckSum { expected: u8, received: u8 },
+
+    #[error("FIX message is missing required tag {0}")]
+    MissingTag(u32),
+
+    #[error("FIX message exceeds the maximum length of {MAX_MESSAGE_LEN} bytes")]
+    TooLarge,
+}
+
+/// FIX 4.4 message body, consisting of the `MsgType` (35) and the ordered application & session
+/// fields following the standard header.
+///
+/// The standard header (`BeginString`, `BodyLength`, `MsgSeqNum`, `SenderCompID`,
+/// `TargetCompID`, `SendingTime`) and trailer (`CheckSum`) are added by [`FixMessage::encode`].
+/// Repeating groups are represented by their ordered fields.
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct FixMessage {
+    pub msg_type: String,
+    pub fields: Vec<(u32, String)>,
+}
+
+impl FixMessage {
+    /// Construct a new [`FixMessage`] of the provided `MsgType` (35), without any fields.
+    pub fn new<S>(msg_type: S) -> Self
+    where
+        S: Into<String>,
+    {
+        Self {
+            msg_type: msg_type.into(),
+            fields: Vec::new(),
+        }
+    }
+
+    /// Append the provided field.
+    pub fn with<V>(mut self, tag: u32, value: V) -> Self
+    where
+        V: ToString,
+    {
+        self.push(tag, value);
+        self
+    }
+
+    /// Append the provided field in place.
+    pub fn push<V>(&mut self, tag: u32, value: V)
+    where
+        V: ToString,
+    {
+        self.fields.push((tag, value.to_string()));
+    }
+
+    /// Value of the first field with the provided tag.
+    pub fn get(&self, tag: u32) -> Option<&str> {
+        self.fields
+            .iter()
+            .find_map(|(field, value)| (*field == tag).then_some(value.as_str()))
+    }
+
+    /// Value of the first field with the provided tag, or a [`FixError::MissingTag`].
+    pub fn require(&self, tag: u32) -> Result<&str, FixError> {
+        self.get(tag).ok_or(FixError::MissingTag(tag))
+    }
+
+    /// Encode the [`FixMessage`] with the standard header & trailer, ready to be sent.
+    pub fn encode(
+        &self,
+        sender_comp_id: &str,
+        target_comp_id: &str,
+        seq_num: u64,
+        sending_time: DateTime<Utc>,
+    ) -> Vec<u8> {
+        let mut body = String::new();
+        let mut field = |tag: u32, value: &dyn std::fmt::Display| {
+            let _ = write!(body, "{tag}={value}\x01");
+        };
+
+        field(tag::MSG_TYPE, &self.msg_type);
+        field(tag::SENDER_COMP_ID, &sender_comp_id);
+        field(tag::TARGET_COMP_ID, &target_comp_id);
+        field(tag::MSG_SEQ_NUM, &seq_num);
+        field(tag::SENDING_TIME, &utc_timestamp(sending_time));
+        for (tag, value) in &self.fields {
+            field(*tag, value);
+        }
+
+        let mut message = format!("8={BEGIN_STRING}\x019={}\x01{body}", body.len()).into_bytes();
+        let check_sum = check_sum(&message);
+        message.extend_from_slice(format!("10={check_sum:03}\x01").as_bytes());
+        message
+    }
+
+    /// Decode the next complete [`FixMessage`] from the start of the provided buffer, returning
+    /// it alongside the number of bytes consumed, or `None` if more bytes are required.
+    ///
+    /// The standard header fields are included in the decoded `fields`. Messages longer than
+    /// [`MAX_MESSAGE_LEN`] are rejected with a [`FixError::TooLarge`].
+    pub fn decode(buffer: &[u8]) -> Result<Option<(Self, usize)>, FixError> {
+        // Header: "8=FIX.4.4<SOH>9=<BodyLength><SOH>"
+        let Some(begin_end) = buffer.iter().position(|byte| *byte == SOH) else {
+            return Ok(None);
+        };
+        if &buffer[..begin_end] != format!("8={BEGIN_STRING}").as_bytes() {
+            return Err(FixError::Framing("expected BeginString FIX.4.4".to_owned()));
+        }
+
+        let length_start = begin_end + 1;
+        let Some(length_end) = buffer[length_start..]
+            .iter()
+            .position(|byte| *byte == SOH)
+            .map(|position| length_start + position)
+        else {
+            return Ok(None);
+        };
+        let body_length = std::str::from_utf8(&buffer[length_start..length_end])
+            .ok()
+            .and_then(|field| field.strip_prefix("9="))
+            .and_then(|length| length.parse::<usize>().ok())
+            .ok_or_else(|| FixError::Framing("expected BodyLength".to_owned()))?;
+
+        // Trailer: "10=<CheckSum><SOH>"
+        let (body_end, message_end) = (length_end + 1)
+            .checked_add(body_length)
+            .and_then(|body_end| Some((body_end, body_end.checked_add(7)?)))
+            .filter(|(_, message_end)| *message_end <= MAX_MESSAGE_LEN)
+            .ok_or(FixError::TooLarge)?;
+        if buffer.len() < message_end {
+            return Ok(None);
+        }
+        let received = std::str::from_utf8(&buffer[body_end..message_end])
+            .ok()
+            .and_then(|field| field.strip_prefix("10="))
+            .and_then(|field| field.strip_suffix('\x01'))
+            .and_then(|check_sum| check_sum.parse::<u8>().ok())
+            .ok_or_else(|| FixError::Framing("expected CheckSum".to_owned()))?;
+        let expected = check_sum(&buffer[..body_end]);
+        if received != expected {
+            return Err(FixError::CheckSum { expected, received });
+        }
+
+        let fields = buffer[length_end + 1..body_end]
+            .split(|byte| *byte == SOH)
+            .filter(|field| !field.is_empty())
+            .map(|field| {
+                let field = String::from_utf8_lossy(field);
+                field
+                    .split_once('=')
+                    .and_then(|(tag, value)| Some((tag.parse::<u32>().ok()?, value.to_owned())))
+                    .ok_or_else(|| FixError::Framing(format!("invalid field: {field}")))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let msg_type = fields
+            .iter()
+            .find_map(|(tag, value)| (*tag == tag::MSG_TYPE).then(|| value.clone()))
+            .ok_or(FixError::MissingTag(tag::MSG_TYPE))?;
+
+        Ok(Some((Self { msg_type, fields }, message_end)))
+    }
+}
+
+/// FIX `CheckSum` (10) of the provided bytes, ie/ the sum of every byte modulo 256.
+pub fn check_sum(bytes: &[u8]) -> u8 {
+    bytes.iter().fold(0u8, |sum, byte| sum.wrapping_add(*byte))
+}
+
+/// FIX `UTCTimestamp` with millisecond precision (eg/ "20240102-03:04:05.678").
+pub fn utc_timestamp(time: DateTime<Utc>) -> String {
+    time.format("%Y%m%d-%H:%M:%S%.3f").to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn time() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2024, 1, 2, 3, 4, 5).unwrap() + chrono::TimeDelta::milliseconds(678)
+    }
+
+    #[test]
+    fn test_encode() {
+        let message = FixMessage::new(msg_type::HEARTBEAT).with(tag::TEST_REQ_ID, "ping");
+
+        let actual = message.encode("BARTER", "CLIENT", 2, time());
+        let body =
+            "35=0\x0149=BARTER\x0156=CLIENT\x0134=2\x0152=20240102-03:04:05.678\x01112=ping\x01";
+        let header = format!("8=FIX.4.4\x019={}\x01{body}", body.len());
+        let expected = format!("{header}10={:03}\x01", check_sum(header.as_bytes()));
+
+        assert_eq!(String::from_utf8(actual).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_decode() {
+        let encoded = FixMessage::new(msg_type::LOGON)
+            .with(tag::ENCRYPT_METHOD, 0)
+            .with(tag::HEART_BT_INT, 30)
+            .encode("CLIENT", "BARTER", 1, time());
+
+        // TC0: partial message requires more bytes
+        assert_eq!(FixMessage::decode(&encoded[..encoded.len() - 1]), Ok(None));
+
+        // TC1: complete message followed by the start of another
+        let mut buffer = encoded.clone();
+        buffer.extend_from_slice(b"8=FIX");
+        let (message, consumed) = FixMessage::decode(&buffer).unwrap().unwrap();
+        assert_eq!(consumed, encoded.len());
+        assert_eq!(message.msg_type, msg_type::LOGON);
+        assert_eq!(message.get(tag::SENDER_COMP_ID), Some("CLIENT"));
+        assert_eq!(message.get(tag::HEART_BT_INT), Some("30"));
+        assert_eq!(
+            message.require(tag::TEXT),
+            Err(FixError::MissingTag(tag::TEXT))
+        );
+
+        // TC2: corrupted message fails the CheckSum
+        let mut corrupted = encoded.clone();
+        let position = corrupted.len() - 10;
+        corrupted[position] += 1;
+        assert!(matches!(
+            FixMessage::decode(&corrupted),
+            Err(FixError::CheckSum { .. })
+        ));
+
+        // TC3: unsupported BeginString
+        assert!(matches!(
+            FixMessage::decode(b"8=FIX.4.2\x019=5\x01"),
+            Err(FixError::Framing(_))
+        ));
+
+        // TC4: BodyLength exceeding the maximum message length
+        assert_eq!(
+            FixMessage::decode(format!("8=FIX.4.4\x019={}\x01", usize::MAX).as_bytes()),
+            Err(FixError::TooLarge)
+        );
+        assert_eq!(
+            FixMessage::decode(format!("8=FIX.4.4\x019={MAX_MESSAGE_LEN}\x01").as_bytes()),
+            Err(FixError::TooLarge)
+        );
+    }
+}
@@ -0,0 +1,837 @@
+use self::{
+    market_data::{FixUpdate, MdEntryType},
+    message::{msg_type, tag, FixError, FixMessage, MAX_MESSAGE_LEN},
+};
+use crate::{event::MarketEvent, runtime::Spawner};
+use chrono::{DateTime, Utc};
+use futures::{Stream, StreamExt};
+use std::{
+    collections::HashMap,
+    fmt::Display,
+    io,
+    sync::{Arc, PoisonError, RwLock},
+    time::Duration,
+};
+use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    net::{
+        tcp::{OwnedReadHalf, OwnedWriteHalf},
+        TcpListener, TcpStream,
+    },
+    sync::broadcast::{self, error::RecvError},
+    time::Instant,
+};
+use tracing::{debug, info, warn};
+
+/// Conversions of normalised market data into FIX market data entries.
+pub mod market_data;
+
+/// FIX 4.4 tag=value message encoding & decoding.
+pub mod message;
+
+/// Maximum duration a client has to send its `Logon` (A) after connecting.
+const LOGON_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Minimum heartbeat interval accepted from a client `Logon` (A).
+const MIN_HEARTBEAT: Duration = Duration::from_secs(1);
+
+/// Maximum heartbeat interval accepted from a client `Logon` (A).
+const MAX_HEARTBEAT: Duration = Duration::from_secs(300);
+
+/// FIX `MDReqRejReason` (281) for an unknown symbol.
+const REJECT_UNKNOWN_SYMBOL: char = '0';
+
+/// FIX `MDReqRejReason` (281) for an unsupported `SubscriptionRequestType` (263).
+const REJECT_UNSUPPORTED_SUBSCRIPTION_REQUEST_TYPE: char = '4';
+
+/// FIX `BusinessRejectReason` (380) for an unsupported `MsgType` (35).
+const REJECT_UNSUPPORTED_MSG_TYPE: char = '3';
+
+/// Normalised [`MarketEvent`] converted into a [`FixUpdate`] for an exchange instrument.
+#[derive(Clone, PartialEq, Debug)]
+pub struct FixMarketEvent {
+    /// `SecurityExchange` (207), eg/ "binance_spot".
+    pub exchange: String,
+    /// `Symbol` (55), eg/ "btc_usdt".
+    pub symbol: String,
+    pub time: DateTime<Utc>,
+    pub update: FixUpdate,
+}
+
+/// FIX 4.4 market data gateway that republishes the normalised [`MarketEvent`]s it is fed (see
+/// [`FixGateway::feed`]) to every connected FIX client, for interop with legacy OMS & analytics
+/// systems.
+///
+/// Clients connect via TCP, `Logon` (A), and send `MarketDataRequest`s (V) for instruments
+/// identified by `Symbol` (55) (eg/ "btc_usdt") & optionally `SecurityExchange` (207)
+/// (eg/ "binance_spot"):
+/// - Order book [`MarketEvent`]s are published as `MarketDataSnapshotFullRefresh` (W) messages.
+/// - Trade & candle [`MarketEvent`]s are published as `MarketDataIncrementalRefresh` (X) messages.
+/// - The latest snapshot of each instrument is sent upon subscribing, and snapshot only requests
+///   (`SubscriptionRequestType` 0) are rejected if no snapshot is available.
+///
+/// Sessions are not persisted, so every connection starts at `MsgSeqNum` 1 and resend requests
+/// are not supported. Clients that lag more than the broadcast `capacity` behind skip the missed
+/// [`MarketEvent`]s.
+///
+/// eg/ `gateway.serve(TcpListener::bind(addr).await?)`, whilst concurrently awaiting
+/// `gateway.feed(streams)`.
+#[derive(Clone, Debug)]
+pub struct FixGateway {
+    sender_comp_id: Arc<str>,
+    events: broadcast::Sender<Arc<FixMarketEvent>>,
+    snapshots: Arc<RwLock<HashMap<(String, String), Arc<FixMarketEvent>>>>,
+    spawner: Spawner,
+}
+
+impl FixGateway {
+    /// Construct a new [`FixGateway`] identified by the provided `SenderCompID` (49), that buffers
+    /// up to `capacity` [`MarketEvent`]s for each client.
+    pub fn new<S>(sender_comp_id: S, capacity: usize) -> Self
+    where
+        S: Into<String>,
+    {
+        Self {
+            sender_comp_id: Arc::from(sender_comp_id.into()),
+            events: broadcast::channel(capacity).0,
+            snapshots: Arc::default(),
+            spawner: Spawner::default(),
+        }
+    }
+
+    /// Spawn each FIX client session using the provided [`Spawner`], rather than the default.
+    pub fn with_spawner(self, spawner: Spawner) -> Self {
+        Self { spawner, ..self }
+    }
+
+    /// Publish every [`MarketEvent`] of the provided [`Stream`] (eg/
+    /// [`Streams`](crate::streams::Streams)) to the subscribed FIX clients, until it ends.
+    pub async fn feed<St, InstrumentId, T>(&self, mut stream: St)
+    where
+        St: Stream<Item = MarketEvent<InstrumentId, T>> + Unpin,
+        InstrumentId: Display,
+        for<'a> Option<FixUpdate>: From<&'a T>,
+    {
+        while let Some(event) = stream.next().await {
+            let Some(update) = Option::<FixUpdate>::from(&event.kind) else {
+                continue;
+            };
+
+            let event = Arc::new(FixMarketEvent {
+                exchange: event.exchange.to_string(),
+                symbol: event.instrument.to_string(),
+                time: event.exchange_time,
+                update,
+            });
+
+            if let FixUpdate::Snapshot(_) = event.update {
+                self.snapshots
+                    .write()
+                    .unwrap_or_else(PoisonError::into_inner)
+                    .insert(
+                        (event.exchange.clone(), event.symbol.clone()),
+                        Arc::clone(&event),
+                    );
+            }
+
+            // Events are discarded if there are currently no connected clients
+            let _ = self.events.send(event);
+        }
+    }
+
+    /// Accept FIX client connections from the provided [`TcpListener`], serving each session in
+    /// the background until it disconnects.
+    ///
+    /// Returns an error if accepting a connection fails.
+    pub async fn serve(&self, listener: TcpListener) -> io::Result<()> {
+        loop {
+            let (stream, address) = listener.accept().await?;
+            debug!(%address, "FixGateway accepted connection");
+
+            let session = Session::new(self.clone(), stream);
+            self.spawner.spawn(async move {
+                match session.run().await {
+                    Ok(()) => info!(%address, "FixGateway session ended"),
+                    Err(error) => warn!(%address, %error, "FixGateway session failed"),
+                }
+            });
+        }
+    }
+
+    /// Latest snapshots of the instruments matching the provided [`MdInstrument`].
+    fn snapshots(&self, instrument: &MdInstrument) -> Vec<Arc<FixMarketEvent>> {
+        self.snapshots
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .values()
+            .filter(|event| instrument.matches(event))
+            .cloned()
+            .collect()
+    }
+}
+
+/// Instrument requested by a `MarketDataRequest` (V).
+#[derive(Clone, Eq, PartialEq, Debug)]
+struct MdInstrument {
+    exchange: Option<String>,
+    symbol: String,
+}
+
+impl MdInstrument {
+    fn matches(&self, event: &FixMarketEvent) -> bool {
+        self.symbol == event.symbol
+            && self
+                .exchange
+                .as_ref()
+                .is_none_or(|exchange| *exchange == event.exchange)
+    }
+}
+
+/// Active `MarketDataRequest` (V) subscription of a [`Session`].
+#[derive(Clone, Eq, PartialEq, Debug)]
+struct MdSubscription {
+    md_req_id: String,
+    instruments: Vec<MdInstrument>,
+    /// Requested [`MdEntryType`]s, where empty requests every [`MdEntryType`].
+    entry_types: Vec<MdEntryType>,
+}
+
+impl MdSubscription {
+    /// Parse the [`MdSubscription`] & `SubscriptionRequestType` (263) of a `MarketDataRequest`.
+    fn parse(message: &FixMessage) -> Result<(Self, String), FixError> {
+        let md_req_id = message.require(tag::MD_REQ_ID)?.to_owned();
+        let request_type = message.require(tag::SUBSCRIPTION_REQUEST_TYPE)?.to_owned();
+
+        // Repeating groups: each Symbol (55) starts a new instrument, optionally followed by its
+        // SecurityExchange (207)
+        let mut instruments = Vec::<MdInstrument>::new();
+        let mut entry_types = Vec::new();
+        for (field, value) in &message.fields {
+            match *field {
+                tag::SYMBOL => instruments.push(MdInstrument {
+                    exchange: None,
+                    symbol: value.clone(),
+                }),
+                tag::SECURITY_EXCHANGE => {
+                    if let Some(instrument) = instruments.last_mut() {
+                        instrument.exchange = Some(value.clone());
+                    }
+                }
+                tag::MD_ENTRY_TYPE => entry_types.extend(MdEntryType::from_fix(value)),
+                _ => {}
+            }
+        }
+
+        Ok((
+            Self {
+                md_req_id,
+                instruments,
+                entry_types,
+            },
+            request_type,
+        ))
+    }
+
+    fn matches(&self, event: &FixMarketEvent) -> bool {
+        self.instruments
+            .iter()
+            .any(|instrument| instrument.matches(event))
+    }
+
+    /// Construct the `MarketDataSnapshotFullRefresh` (W) or `MarketDataIncrementalRefresh` (X)
+    /// of the [`FixMarketEvent`], or `None` if it contains no requested [`MdEntryType`]s.
+    fn message(&self, event: &FixMarketEvent) -> Option<FixMessage> {
+        let entries = event
+            .update
+            .entries()
+            .iter()
+            .filter(|entry| self.entry_types.is_empty() || self.entry_types.contains(&entry.kind))
+            .collect::<Vec<_>>();
+
+        if entries.is_empty() {
+            return None;
+        }
+
+        let date = event.time.format("%Y%m%d").to_string();
+        let time = event.time.format("%H:%M:%S%.3f").to_string();
+
+        let mut message = match event.update {
+            FixUpdate::Snapshot(_) => FixMessage::new(msg_type::MARKET_DATA_SNAPSHOT_FULL_REFRESH)
+                .with(tag::MD_REQ_ID, &self.md_req_id)
+                .with(tag::SYMBOL, &event.symbol)
+                .with(tag::SECURITY_EXCHANGE, &event.exchange)
+                .with(tag::NO_MD_ENTRIES, entries.len()),
+            FixUpdate::Incremental(_) => FixMessage::new(msg_type::MARKET_DATA_INCREMENTAL_REFRESH)
+                .with(tag::MD_REQ_ID, &self.md_req_id)
+                .with(tag::NO_MD_ENTRIES, entries.len()),
+        };
+
+        for entry in entries {
+            if let FixUpdate::Incremental(_) = event.update {
+                // MDUpdateAction (279) New
+                message.push(tag::MD_UPDATE_ACTION, '0');
+            }
+            message.push(tag::MD_ENTRY_TYPE, entry.kind.as_char());
+            if let FixUpdate::Incremental(_) = event.update {
+                message.push(tag::SYMBOL, &event.symbol);
+                message.push(tag::SECURITY_EXCHANGE, &event.exchange);
+            }
+            if let Some(id) = &entry.id {
+                message.push(tag::MD_ENTRY_ID, id);
+            }
+            if let Some(price) = entry.price {
+                message.push(tag::MD_ENTRY_PX, price);
+            }
+            if let Some(size) = entry.size {
+                message.push(tag::MD_ENTRY_SIZE, size);
+            }
+            message.push(tag::MD_ENTRY_DATE, &date);
+            message.push(tag::MD_ENTRY_TIME, &time);
+            if let Some(position) = entry.position {
+                message.push(tag::MD_ENTRY_POSITION_NO, position);
+            }
+        }
+
+        Some(message)
+    }
+}
+
+/// FIX session of a single client connection.
+#[derive(Debug)]
+struct Session {
+    gateway: FixGateway,
+    reader: OwnedReadHalf,
+    writer: OwnedWriteHalf,
+    buffer: Vec<u8>,
+    target_comp_id: String,
+    seq_num: u64,
+    heartbeat: Duration,
+    last_sent: Instant,
+    last_received: Instant,
+    subscriptions: Vec<MdSubscription>,
+}
+
+impl Session {
+    fn new(gateway: FixGateway, stream: TcpStream) -> Self {
+        let (reader, writer) = stream.into_split();
+        Self {
+            gateway,
+            reader,
+            writer,
+            buffer: Vec::with_capacity(4096),
+            target_comp_id: String::new(),
+            seq_num: 0,
+            heartbeat: Duration::from_secs(30),
+            last_sent: Instant::now(),
+            last_received: Instant::now(),
+            subscriptions: Vec::new(),
+        }
+    }
+
+    /// Run the [`Session`] until the client logs out or disconnects.
+    async fn run(mut self) -> io::Result<()> {
+        let Ok(logon) = tokio::time::timeout(LOGON_TIMEOUT, self.next_message()).await else {
+            return Err(io::Error::new(io::ErrorKind::TimedOut, "Logon timed out"));
+        };
+        self.logon(logon?).await?;
+
+        let mut events = self.gateway.events.subscribe();
+        let mut tick = tokio::time::interval(MIN_HEARTBEAT);
+
+        loop {
+            tokio::select! {
+                message = self.next_message() => match message {
+                    Ok(Some(message)) => {
+                        if !self.handle(message).await? {
+                            return Ok(());
+                        }
+                    }
+                    Ok(None) => return Ok(()),
+                    Err(error) => return Err(error),
+                },
+                event = events.recv() => match event {
+                    Ok(event) => self.publish(&event).await?,
+                    Err(RecvError::Lagged(skipped)) => {
+                        warn!(skipped, "FixGateway session lagged, skipping MarketEvents");
+                    }
+                    Err(RecvError::Closed) => {
+                        return self.logout("FixGateway shutting down").await;
+                    }
+                },
+                _ = tick.tick() => {
+                    if self.last_received.elapsed() > self.heartbeat.saturating_mul(2) {
+                        return self.logout("heartbeat timeout").await;
+                    }
+                    if self.last_sent.elapsed() >= self.heartbeat {
+                        self.send(FixMessage::new(msg_type::HEARTBEAT)).await?;
+                    }
+                }
+            }
+        }
+    }
+
+    /// Await the next complete [`FixMessage`] from the client, or `None` if it disconnected.
+    async fn next_message(&mut self) -> io::Result<Option<FixMessage>> {
+        loop {
+            match FixMessage::decode(&self.buffer) {
+                Ok(Some((message, consumed))) => {
+                    self.buffer.drain(..consumed);
+                    self.last_received = Instant::now();
+                    return Ok(Some(message));
+                }
+                Ok(None) => {}
+                Err(error) => return Err(io::Error::new(io::ErrorKind::InvalidData, error)),
+            }
+
+            // Incomplete messages can never exceed the maximum length, so bound the buffer
+            if self.buffer.len() >= MAX_MESSAGE_LEN {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    FixError::TooLarge,
+                ));
+            }
+
+            if self.reader.read_buf(&mut self.buffer).await? == 0 {
+                return Ok(None);
+            }
+        }
+    }
+
+    /// Validate the client `Logon` (A), and respond with the gateway `Logon`.
+    async fn logon(&mut self, message: Option<FixMessage>) -> io::Result<()> {
+        let Some(message) = message else {
+            return Err(io::Error::new(
+                io::ErrorKind::UnexpectedEof,
+                "disconnected before Logon",
+            ));
+        };
+
+        self.target_comp_id = message
+            .get(tag::SENDER_COMP_ID)
+            .unwrap_or_default()
+            .to_owned();
+
+        if message.msg_type != msg_type::LOGON {
+            self.logout("first message must be Logon").await?;
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "first message was not Logon",
+            ));
+        }
+
+        self.heartbeat = message
+            .get(tag::HEART_BT_INT)
+            .and_then(|seconds| seconds.parse::<u64>().ok())
+            .map_or(self.heartbeat, Duration::from_secs)
+            .clamp(MIN_HEARTBEAT, MAX_HEARTBEAT);
+
+        info!(
+            target_comp_id = %self.target_comp_id,
+            heartbeat = ?self.heartbeat,
+            "FixGateway session logged on"
+        );
+
+        self.send(
+            FixMessage::new(msg_type::LOGON)
+                .with(tag::ENCRYPT_METHOD, 0)
+                .with(tag::HEART_BT_INT, self.heartbeat.as_secs()),
+        )
+        .await
+    }
+
+    /// Handle a client [`FixMessage`], returning `false` if the session should end.
+    async fn handle(&mut self, message: FixMessage) -> io::Result<bool> {
+        match message.msg_type.as_str() {
+            msg_type::HEARTBEAT | msg_type::LOGON => {}
+            msg_type::TEST_REQUEST => {
+                let mut heartbeat = FixMessage::new(msg_type::HEARTBEAT);
+                if let Some(id) = message.get(tag::TEST_REQ_ID) {
+                    heartbeat.push(tag::TEST_REQ_ID, id);
+                }
+                self.send(heartbeat).await?;
+            }
+            msg_type::LOGOUT => {
+                self.send(FixMessage::new(msg_type::LOGOUT)).await?;
+                return Ok(false);
+            }
+            msg_type::MARKET_DATA_REQUEST => self.market_data_request(&message).await?,
+            other => {
+                self.send(
+                    FixMessage::new(msg_type::BUSINESS_MESSAGE_REJECT)
+                        .with(tag::REF_MSG_TYPE, other)
+                        .with(tag::BUSINESS_REJECT_REASON, REJECT_UNSUPPORTED_MSG_TYPE)
+                        .with(tag::TEXT, "unsupported MsgType"),
+                )
+                .await?;
+            }
+        }
+
+        Ok(true)
+    }
+
+    /// Handle a `MarketDataRequest` (V), subscribing, unsubscribing, or sending the latest
+    /// snapshots.
+    async fn market_data_request(&mut self, message: &FixMessage) -> io::Result<()> {
+        let (subscription, request_type) = match MdSubscription::parse(message) {
+            Ok(request) => request,
+            Err(error) => {
+                let md_req_id = message.get(tag::MD_REQ_ID).unwrap_or_default().to_owned();
+                return self
+                    .reject(&md_req_id, REJECT_UNKNOWN_SYMBOL, &error.to_string())
+                    .await;
+            }
+        };
+
+        match request_type.as_str() {
+            // Snapshot, or Snapshot + Updates
+            "0" | "1" => {
+                let snapshots = subscription
+                    .instruments
+                    .iter()
+                    .flat_map(|instrument| self.gateway.snapshots(instrument))
+                    .collect::<Vec<_>>();
+
+                if request_type == "1" {
+                    if subscription.instruments.is_empty() {
+                        return self
+                            .reject(&subscription.md_req_id, REJECT_UNKNOWN_SYMBOL, "no Symbol")
+                            .await;
+                    }
+                    self.subscriptions
+                        .retain(|existing| existing.md_req_id != subscription.md_req_id);
+                    self.subscriptions.push(subscription.clone());
+                } else if snapshots.is_empty() {
+                    return self
+                        .reject(
+                            &subscription.md_req_id,
+                            REJECT_UNKNOWN_SYMBOL,
+                            "no snapshot available",
+                        )
+                        .await;
+                }
+
+                for snapshot in snapshots {
+                    if let Some(message) = subscription.message(&snapshot) {
+                        self.send(message).await?;
+                    }
+                }
+                Ok(())
+            }
+            // Disable previous Snapshot + Updates
+            "2" => {
+                self.subscriptions
+                    .retain(|existing| existing.md_req_id != subscription.md_req_id);
+                Ok(())
+            }
+            _ => {
+                self.reject(
+                    &subscription.md_req_id,
+                    REJECT_UNSUPPORTED_SUBSCRIPTION_REQUEST_TYPE,
+                    "unsupported SubscriptionRequestType",
+                )
+                .await
+            }
+        }
+    }
+
+    /// Publish the [`FixMarketEvent`] to every matching [`MdSubscription`].
+    async fn publish(&mut self, event: &FixMarketEvent) -> io::Result<()> {
+        let messages = self
+            .subscriptions
+            .iter()
+            .filter(|subscription| subscription.matches(event))
+            .filter_map(|subscription| subscription.message(event))
+            .collect::<Vec<_>>();
+
+        for message in messages {
+            self.send(message).await?;
+        }
+        Ok(())
+    }
+
+    /// Send a `MarketDataRequestReject` (Y).
+    async fn reject(&mut self, md_req_id: &str, reason: char, text: &str) -> io::Result<()> {
+        self.send(
+            FixMessage::new(msg_type::MARKET_DATA_REQUEST_REJECT)
+                .with(tag::MD_REQ_ID, md_req_id)
+                .with(tag::MD_REQ_REJ_REASON, reason)
+                .with(tag::TEXT, text),
+        )
+        .await
+    }
+
+    /// Send a `Logout` (5) with the provided reason.
+    async fn logout(&mut self, text: &str) -> io::Result<()> {
+        debug!(target_comp_id = %self.target_comp_id, text, "FixGateway session logging out");
+        self.send(FixMessage::new(msg_type::LOGOUT).with(tag::TEXT, text))
+            .await
+    }
+
+    /// Encode & send the [`FixMessage`] with the next `MsgSeqNum` (34).
+    async fn send(&mut self, message: FixMessage) -> io::Result<()> {
+        self.seq_num += 1;
+        let bytes = message.encode(
+            &self.gateway.sender_comp_id,
+            &self.target_comp_id,
+            self.seq_num,
+            Utc::now(),
+        );
+        self.writer.write_all(&bytes).await?;
+        self.last_sent = Instant::now();
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::subscription::{
+        book::{Level, OrderBookL1},
+        trade::PublicTrade,
+    };
+    use barter_integration::model::{Exchange, Side};
+
+    struct Client {
+        stream: TcpStream,
+        buffer: Vec<u8>,
+        seq_num: u64,
+    }
+
+    impl Client {
+        async fn send(&mut self, message: FixMessage) {
+            self.seq_num += 1;
+            let bytes = message.encode("CLIENT", "BARTER", self.seq_num, Utc::now());
+            self.stream.write_all(&bytes).await.unwrap();
+        }
+
+        async fn recv(&mut self) -> FixMessage {
+            loop {
+                if let Some((message, consumed)) = FixMessage::decode(&self.buffer).unwrap() {
+                    self.buffer.drain(..consumed);
+                    return message;
+                }
+                assert!(self.stream.read_buf(&mut self.buffer).await.unwrap() > 0);
+            }
+        }
+    }
+
+    fn event<T>(instrument: &'static str, kind: T) -> MarketEvent<&'static str, T> {
+        MarketEvent {
+            exchange_time: Utc::now(),
+            received_time: Utc::now(),
+            exchange: Exchange::from("binance_spot"),
+            instrument,
+            kind,
+        }
+    }
+
+    fn fields(message: &FixMessage, tags: &[u32]) -> Vec<(u32, String)> {
+        message
+            .fields
+            .iter()
+            .filter(|(tag, _)| tags.contains(tag))
+            .cloned()
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_fix_gateway() {
+        let gateway = FixGateway::new("BARTER", 16);
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        tokio::spawn({
+            let gateway = gateway.clone();
+            async move { gateway.serve(listener).await }
+        });
+
+        // Cache an OrderBookL1 snapshot before any client subscribes
+        gateway
+            .feed(futures::stream::iter([event(
+                "btc_usdt",
+                OrderBookL1 {
+                    last_update_time: Utc::now(),
+                    best_bid: Level::new(100.0, 1.0),
+                    best_ask: Level::new(101.0, 2.0),
+                },
+            )]))
+            .await;
+
+        let mut client = Client {
+            stream: TcpStream::connect(address).await.unwrap(),
+            buffer: Vec::new(),
+            seq_num: 0,
+        };
+
+        // Logon
+        client
+            .send(
+                FixMessage::new(msg_type::LOGON)
+                    .with(tag::ENCRYPT_METHOD, 0)
+                    .with(tag::HEART_BT_INT, 30),
+            )
+            .await;
+        let logon = client.recv().await;
+        assert_eq!(logon.msg_type, msg_type::LOGON);
+        assert_eq!(logon.get(tag::TARGET_COMP_ID), Some("CLIENT"));
+        assert_eq!(logon.get(tag::MSG_SEQ_NUM), Some("1"));
+
+        // Snapshot request for an unknown Symbol is rejected
+        client
+            .send(
+                FixMessage::new(msg_type::MARKET_DATA_REQUEST)
+                    .with(tag::MD_REQ_ID, "unknown")
+                    .with(tag::SUBSCRIPTION_REQUEST_TYPE, 0)
+                    .with(tag::NO_RELATED_SYM, 1)
+                    .with(tag::SYMBOL, "eth_usdt"),
+            )
+            .await;
+        let reject = client.recv().await;
+        assert_eq!(reject.msg_type, msg_type::MARKET_DATA_REQUEST_REJECT);
+        assert_eq!(reject.get(tag::MD_REQ_ID), Some("unknown"));
+
+        // Snapshot + Updates request receives the cached snapshot
+        client
+            .send(
+                FixMessage::new(msg_type::MARKET_DATA_REQUEST)
+                    .with(tag::MD_REQ_ID, "btc")
+                    .with(tag::SUBSCRIPTION_REQUEST_TYPE, 1)
+                    .with(tag::MARKET_DEPTH, 1)
+                    .with(tag::NO_MD_ENTRY_TYPES, 3)
+                    .with(tag::MD_ENTRY_TYPE, 0)
+                    .with(tag::MD_ENTRY_TYPE, 1)
+                    .with(tag::MD_ENTRY_TYPE, 2)
+                    .with(tag::NO_RELATED_SYM, 1)
+                    .with(tag::SYMBOL, "btc_usdt")
+                    .with(tag::SECURITY_EXCHANGE, "binance_spot"),
+            )
+            .await;
+        let snapshot = client.recv().await;
+        assert_eq!(
+            snapshot.msg_type,
+            msg_type::MARKET_DATA_SNAPSHOT_FULL_REFRESH
+        );
+        assert_eq!(
+            fields(
+                &snapshot,
+                &[
+                    tag::MD_REQ_ID,
+                    tag::SYMBOL,
+                    tag::NO_MD_ENTRIES,
+                    tag::MD_ENTRY_TYPE,
+                    tag::MD_ENTRY_PX,
+                    tag::MD_ENTRY_SIZE
+                ]
+            ),
+            vec![
+                (tag::MD_REQ_ID, "btc".to_owned()),
+                (tag::SYMBOL, "btc_usdt".to_owned()),
+                (tag::NO_MD_ENTRIES, "2".to_owned()),
+                (tag::MD_ENTRY_TYPE, "0".to_owned()),
+                (tag::MD_ENTRY_PX, "100".to_owned()),
+                (tag::MD_ENTRY_SIZE, "1".to_owned()),
+                (tag::MD_ENTRY_TYPE, "1".to_owned()),
+                (tag::MD_ENTRY_PX, "101".to_owned()),
+                (tag::MD_ENTRY_SIZE, "2".to_owned()),
+            ]
+        );
+
+        // Subsequent trades of the subscribed instrument are published incrementally
+        gateway
+            .feed(futures::stream::iter([
+                event(
+                    "eth_usdt",
+                    PublicTrade {
+                        id: "1".to_owned(),
+                        price: 10.0,
+                        amount: 1.0,
+                        side: Side::Buy,
+                    },
+                ),
+                event(
+                    "btc_usdt",
+                    PublicTrade {
+                        id: "2".to_owned(),
+                        price: 100.5,
+                        amount: 0.25,
+                        side: Side::Sell,
+                    },
+                ),
+            ]))
+            .await;
+        let incremental = client.recv().await;
+        assert_eq!(
+            incremental.msg_type,
+            msg_type::MARKET_DATA_INCREMENTAL_REFRESH
+        );
+        assert_eq!(
+            fields(
+                &incremental,
+                &[
+                    tag::MD_UPDATE_ACTION,
+                    tag::MD_ENTRY_TYPE,
+                    tag::SYMBOL,
+                    tag::MD_ENTRY_ID,
+                    tag::MD_ENTRY_PX,
+                    tag::MD_ENTRY_SIZE
+                ]
+            ),
+            vec![
+                (tag::MD_UPDATE_ACTION, "0".to_owned()),
+                (tag::MD_ENTRY_TYPE, "2".to_owned()),
+                (tag::SYMBOL, "btc_usdt".to_owned()),
+                (tag::MD_ENTRY_ID, "2".to_owned()),
+                (tag::MD_ENTRY_PX, "100.5".to_owned()),
+                (tag::MD_ENTRY_SIZE, "0.25".to_owned()),
+            ]
+        );
+
+        // TestRequest is answered with a Heartbeat
+        client
+            .send(FixMessage::new(msg_type::TEST_REQUEST).with(tag::TEST_REQ_ID, "ping"))
+            .await;
+        let heartbeat = client.recv().await;
+        assert_eq!(heartbeat.msg_type, msg_type::HEARTBEAT);
+        assert_eq!(heartbeat.get(tag::TEST_REQ_ID), Some("ping"));
+
+        // Logout is acknowledged
+        client.send(FixMessage::new(msg_type::LOGOUT)).await;
+        assert_eq!(client.recv().await.msg_type, msg_type::LOGOUT);
+    }
+
+    #[tokio::test]
+    async fn test_fix_gateway_rejects_oversized_input() {
+        let gateway = FixGateway::new("BARTER", 16);
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        tokio::spawn(async move { gateway.serve(listener).await });
+
+        let mut client = Client {
+            stream: TcpStream::connect(address).await.unwrap(),
+            buffer: Vec::new(),
+            seq_num: 0,
+        };
+
+        // HeartBtInt is clamped to the maximum heartbeat interval
+        client
+            .send(
+                FixMessage::new(msg_type::LOGON)
+                    .with(tag::ENCRYPT_METHOD, 0)
+                    .with(tag::HEART_BT_INT, u64::MAX),
+            )
+            .await;
+        let logon = client.recv().await;
+        assert_eq!(
+            logon.get(tag::HEART_BT_INT),
+            Some(MAX_HEARTBEAT.as_secs().to_string().as_str())
+        );
+
+        // BodyLength exceeding the maximum message length terminates the session
+        client
+            .stream
+            .write_all(format!("8=FIX.4.4\x019={}\x01", usize::MAX).as_bytes())
+            .await
+            .unwrap();
+        let mut remaining = Vec::new();
+        client.stream.read_to_end(&mut remaining).await.unwrap();
+    }
+}
@@ -0,0 +1,213 @@
+use crate::{
+    event::MarketEvent,
+    proto::{self, market_event, SubscribeMarketDataRequest},
+};
+use futures::{Stream, StreamExt};
+use std::{fmt::Display, pin::Pin};
+use tokio::sync::broadcast;
+use tokio_stream::wrappers::{errors::BroadcastStreamRecvError, BroadcastStream};
+use tonic::{
+    codec::ProstCodec,
+    codegen::{empty_body, http, Body, BoxFuture, Context, Poll, Service, StdError},
+    server::{Grpc, NamedService, ServerStreamingService},
+    Status,
+};
+use tracing::warn;
+
+/// Fully qualified name of the `MarketData` gRPC service.
+pub const SERVICE_NAME: &str = "barter.data.v1.MarketData";
+
+/// Path of the `SubscribeMarketData` server-streaming RPC.
+const SUBSCRIBE_MARKET_DATA: &str = "/barter.data.v1.MarketData/SubscribeMarketData";
+
+/// Communicative type alias representing the response [`Stream`] of a `SubscribeMarketData` RPC.
+pub type MarketDataStream =
+    Pin<Box<dyn Stream<Item = Result<proto::MarketEvent, Status>> + Send + 'static>>;
+
+/// `MarketData` gRPC service that broadcasts the normalised [`MarketEvent`]s it is fed (see
+/// [`MarketDataService::feed`]) to every `SubscribeMarketData` RPC, allowing services in other
+/// languages to consume Barter-Data feeds.
+///
+/// Only [`MarketEvent`] kinds with a stable protobuf schema (see [`market_event::Kind`]) are
+/// served. Subscribers that lag more than the broadcast `capacity` behind skip the missed
+/// [`MarketEvent`]s.
+///
+/// eg/ `Server::builder().add_service(service.clone().into_server()).serve(addr)`, whilst
+/// concurrently awaiting `service.feed(streams)`.
+#[derive(Clone, Debug)]
+pub struct MarketDataService {
+    events: broadcast::Sender<proto::MarketEvent>,
+}
+
+impl MarketDataService {
+    /// Construct a new [`MarketDataService`] that buffers up to `capacity` [`MarketEvent`]s for
+    /// each subscriber.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            events: broadcast::channel(capacity).0,
+        }
+    }
+
+    /// Broadcast every [`MarketEvent`] of the provided [`Stream`] (eg/
+    /// [`Streams`](crate::streams::Streams)) to the current subscribers, until it ends.
+    pub async fn feed<St, InstrumentId, T>(&self, mut stream: St)
+    where
+        St: Stream<Item = MarketEvent<InstrumentId, T>> + Unpin,
+        InstrumentId: Display,
+        for<'a> Option<market_event::Kind>: From<&'a T>,
+    {
+        while let Some(event) = stream.next().await {
+            let event = proto::MarketEvent::from(&event);
+            if event.kind.is_some() {
+                // Events are discarded if there are currently no subscribers
+                let _ = self.events.send(event);
+            }
+        }
+    }
+
+    /// Subscribe to every subsequent [`MarketEvent`] matching the request filters.
+    pub fn subscribe_market_data(&self, request: SubscribeMarketDataRequest) -> MarketDataStream {
+        let stream = BroadcastStream::new(self.events.subscribe()).filter_map(move |event| {
+            let event = match event {
+                Ok(event) if matches(&request, &event) => Some(Ok(event)),
+                Ok(_) => None,
+                Err(BroadcastStreamRecvError::Lagged(skipped)) => {
+                    warn!(
+                        skipped,
+                        "SubscribeMarketData subscriber lagged, skipping MarketEvents"
+                    );
+                    None
+                }
+            };
+            futures::future::ready(event)
+        });
+
+        Box::pin(stream)
+    }
+
+    /// Construct a [`MarketDataServer`] that serves this [`MarketDataService`], suitable for
+    /// adding to a `tonic::transport::Server`.
+    pub fn into_server(self) -> MarketDataServer {
+        MarketDataServer { service: self }
+    }
+}
+
+/// Determine if the [`proto::MarketEvent`] matches the [`SubscribeMarketDataRequest`] filters.
+fn matches(request: &SubscribeMarketDataRequest, event: &proto::MarketEvent) -> bool {
+    let matches =
+        |filters: &[String], value: &String| filters.is_empty() || filters.contains(value);
+    matches(&request.exchanges, &event.exchange) && matches(&request.instruments, &event.instrument)
+}
+
+/// gRPC server of the [`MarketDataService`], routing `SubscribeMarketData` requests.
+///
+/// Equivalent to the server `tonic-build` would generate from
+/// `proto/barter/data/v1/market_data.proto`.
+#[derive(Clone, Debug)]
+pub struct MarketDataServer {
+    service: MarketDataService,
+}
+
+impl NamedService for MarketDataServer {
+    const NAME: &'static str = SERVICE_NAME;
+}
+
+impl<B> Service<http::Request<B>> for MarketDataServer
+where
+    B: Body + Send + 'static,
+    B::Error: Into<StdError> + Send + 'static,
+{
+    type Response = http::Response<tonic::body::BoxBody>;
+    type Error = std::convert::Infallible;
+    type Future = BoxFuture<Self::Response, Self::Error>;
+
+    fn poll_ready(&mut self, _: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, request: http::Request<B>) -> Self::Future {
+        match request.uri().path() {
+            SUBSCRIBE_MARKET_DATA => {
+                let method = SubscribeMarketData(self.service.clone());
+                Box::pin(async move {
+                    let mut grpc = Grpc::new(ProstCodec::default());
+                    Ok(grpc.server_streaming(method, request).await)
+                })
+            }
+            _ => Box::pin(async move {
+                let mut response = http::Response::new(empty_body());
+                let headers = response.headers_mut();
+                headers.insert(
+                    Status::GRPC_STATUS,
+                    (tonic::Code::Unimplemented as i32).into(),
+                );
+                headers.insert(
+                    http::header::CONTENT_TYPE,
+                    tonic::metadata::GRPC_CONTENT_TYPE,
+                );
+                Ok(response)
+            }),
+        }
+    }
+}
+
+/// [`ServerStreamingService`] handling the `SubscribeMarketData` RPC.
+struct SubscribeMarketData(MarketDataService);
+
+impl ServerStreamingService<SubscribeMarketDataRequest> for SubscribeMarketData {
+    type Response = proto::MarketEvent;
+    type ResponseStream = MarketDataStream;
+    type Future = BoxFuture<tonic::Response<Self::ResponseStream>, Status>;
+
+    fn call(&mut self, request: tonic::Request<SubscribeMarketDataRequest>) -> Self::Future {
+        let stream = self.0.subscribe_market_data(request.into_inner());
+        Box::pin(async move { Ok(tonic::Response::new(stream)) })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::subscription::trade::PublicTrade;
+    use barter_integration::model::{Exchange, Side};
+    use chrono::Utc;
+
+    #[tokio::test]
+    async fn test_subscribe_market_data() {
+        let event = |exchange: &'static str, instrument: &'static str| MarketEvent {
+            exchange_time: Utc::now(),
+            received_time: Utc::now(),
+            exchange: Exchange::from(exchange),
+            instrument,
+            kind: PublicTrade {
+                id: "1".to_owned(),
+                price: 100.0,
+                amount: 1.0,
+                side: Side::Buy,
+            },
+        };
+
+        let service = MarketDataService::new(16);
+        let mut all = service.subscribe_market_data(SubscribeMarketDataRequest::default());
+        let mut filtered = service.subscribe_market_data(SubscribeMarketDataRequest {
+            exchanges: vec!["okx".to_owned()],
+            instruments: vec![],
+        });
+
+        service
+            .feed(futures::stream::iter([
+                event("binance_spot", "btc_usdt"),
+                event("okx", "eth_usdt"),
+            ]))
+            .await;
+
+        // Empty filters match every MarketEvent
+        assert_eq!(all.next().await.unwrap().unwrap().exchange, "binance_spot");
+        assert_eq!(all.next().await.unwrap().unwrap().exchange, "okx");
+
+        // Filters match only the requested MarketEvents
+        let actual = filtered.next().await.unwrap().unwrap();
+        assert_eq!(actual.exchange, "okx");
+        assert_eq!(actual.instrument, "eth_usdt");
+    }
+}
@@ -0,0 +1,11 @@
+/// [`MarketDataService`](grpc::MarketDataService) gRPC server exposing the `SubscribeMarketData`
+/// server-streaming RPC, with a stable protobuf schema for normalised
+/// [`MarketEvent`](crate::event::MarketEvent)s.
+#[cfg(feature = "grpc")]
+pub mod grpc;
+
+/// [`FixGateway`](fix::FixGateway) FIX 4.4 server that republishes normalised
+/// [`MarketEvent`](crate::event::MarketEvent)s as `MarketDataSnapshotFullRefresh` &
+/// `MarketDataIncrementalRefresh` messages to connected FIX clients.
+#[cfg(feature = "fix")]
+pub mod fix;
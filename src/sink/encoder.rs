@@ -0,0 +1,128 @@
+use super::SinkError;
+use serde::Serialize;
+
+/// Encodes the normalised [`MarketEvent`](crate::event::MarketEvent)s published by a sink into
+/// payload bytes (eg/ JSON, MessagePack).
+///
+/// Every sink defaults to the [`JsonEncoder`], and can be configured with an alternative via its
+/// `with_encoder` method.
+pub trait EventEncoder: Send + Sync {
+    /// MIME type of the encoded payloads (eg/ "application/json").
+    fn content_type(&self) -> &'static str;
+
+    /// Encode the provided event into payload bytes.
+    fn encode<Event>(&self, event: &Event) -> Result<Vec<u8>, SinkError>
+    where
+        Event: Serialize;
+}
+
+/// [`EventEncoder`] that serialises events as JSON.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Default)]
+pub struct JsonEncoder;
+
+impl EventEncoder for JsonEncoder {
+    fn content_type(&self) -> &'static str {
+        "application/json"
+    }
+
+    fn encode<Event>(&self, event: &Event) -> Result<Vec<u8>, SinkError>
+    where
+        Event: Serialize,
+    {
+        serde_json::to_vec(event).map_err(SinkError::from)
+    }
+}
+
+/// [`EventEncoder`] that serialises events as MessagePack (see [`to_msgpack`]).
+#[cfg(feature = "msgpack")]
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Default)]
+pub struct MessagePackEncoder;
+
+#[cfg(feature = "msgpack")]
+impl EventEncoder for MessagePackEncoder {
+    fn content_type(&self) -> &'static str {
+        "application/msgpack"
+    }
+
+    fn encode<Event>(&self, event: &Event) -> Result<Vec<u8>, SinkError>
+    where
+        Event: Serialize,
+    {
+        to_msgpack(event).map_err(SinkError::from)
+    }
+}
+
+/// Serialise the provided value as MessagePack.
+///
+/// Structs are encoded as maps keyed by field name (rather than positional arrays), so the
+/// payloads are self-describing for non-Rust consumers, mirroring the JSON representation.
+#[cfg(feature = "msgpack")]
+pub fn to_msgpack<T>(value: &T) -> Result<Vec<u8>, rmp_serde::encode::Error>
+where
+    T: Serialize + ?Sized,
+{
+    rmp_serde::to_vec_named(value)
+}
+
+/// Deserialise a value from the provided MessagePack bytes (eg/ encoded via [`to_msgpack`]).
+#[cfg(feature = "msgpack")]
+pub fn from_msgpack<T>(bytes: &[u8]) -> Result<T, rmp_serde::decode::Error>
+where
+    T: serde::de::DeserializeOwned,
+{
+    rmp_serde::from_slice(bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        event::{DataKind, MarketEvent},
+        subscription::trade::PublicTrade,
+    };
+    use barter_integration::model::{Exchange, Side};
+    use chrono::Utc;
+
+    fn event() -> MarketEvent<String, DataKind> {
+        MarketEvent {
+            exchange_time: Utc::now(),
+            received_time: Utc::now(),
+            exchange: Exchange::from("binance_spot"),
+            instrument: "btc_usdt".to_owned(),
+            kind: DataKind::Trade(PublicTrade {
+                id: "1".to_owned(),
+                price: 100.0,
+                amount: 1.5,
+                side: Side::Buy,
+            }),
+        }
+    }
+
+    #[test]
+    fn test_json_encoder() {
+        let event = event();
+        let payload = JsonEncoder.encode(&event).unwrap();
+
+        assert_eq!(JsonEncoder.content_type(), "application/json");
+        assert_eq!(
+            serde_json::from_slice::<MarketEvent<String, DataKind>>(&payload).unwrap(),
+            event
+        );
+    }
+
+    #[cfg(feature = "msgpack")]
+    #[test]
+    fn test_msgpack_encoder() {
+        let event = event();
+        let payload = MessagePackEncoder.encode(&event).unwrap();
+
+        assert_eq!(MessagePackEncoder.content_type(), "application/msgpack");
+        assert_eq!(
+            from_msgpack::<MarketEvent<String, DataKind>>(&payload).unwrap(),
+            event
+        );
+
+        // MessagePack payloads are more compact than the equivalent JSON
+        assert!(payload.len() < JsonEncoder.encode(&event).unwrap().len());
+    }
+}
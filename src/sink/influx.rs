@@ -0,0 +1,501 @@
+use super::{next_batch, BatchConfig, DeliveryFailurePolicy, SinkError, SinkSummary};
+use crate::{
+    event::{DataKind, MarketEvent},
+    subscription::{book::OrderBookL1, candle::Candle, trade::PublicTrade},
+};
+use futures::Stream;
+use serde::{Deserialize, Serialize};
+use std::{
+    fmt::{Debug, Display, Write},
+    marker::PhantomData,
+    time::Duration,
+};
+use tracing::{debug, info, warn};
+
+/// Configures how a failed InfluxDB write request is retried.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Deserialize, Serialize)]
+pub struct RetryConfig {
+    /// Maximum number of times a failed write request is retried.
+    pub max_retries: u32,
+    /// Backoff before the first retry, doubled for every subsequent retry.
+    pub backoff: Duration,
+    /// Timeout applied to each write request.
+    pub timeout: Duration,
+}
+
+impl Default for RetryConfig {
+    fn default() -> Self {
+        Self {
+            max_retries: 3,
+            backoff: Duration::from_millis(250),
+            timeout: Duration::from_secs(10),
+        }
+    }
+}
+
+/// Value of a line protocol point field.
+#[derive(Clone, PartialEq, PartialOrd, Debug)]
+pub enum FieldValue {
+    Float(f64),
+    UInteger(u64),
+    String(String),
+}
+
+impl Display for FieldValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Float(value) => write!(f, "{value}"),
+            Self::UInteger(value) => write!(f, "{value}u"),
+            Self::String(value) => {
+                write!(
+                    f,
+                    "\"{}\"",
+                    value.replace('\\', "\\\\").replace('"', "\\\"")
+                )
+            }
+        }
+    }
+}
+
+/// Normalised market data that can be written to InfluxDB as a line protocol point.
+pub trait InfluxPoint {
+    /// Measurement & fields of the point, or `None` if this data cannot be written (eg/ an
+    /// unsupported [`DataKind`] variant).
+    fn point(&self) -> Option<(&'static str, Vec<(&'static str, FieldValue)>)>;
+}
+
+impl InfluxPoint for PublicTrade {
+    fn point(&self) -> Option<(&'static str, Vec<(&'static str, FieldValue)>)> {
+        Some((
+            "trades",
+            vec![
+                ("id", FieldValue::String(self.id.clone())),
+                ("price", FieldValue::Float(self.price)),
+                ("amount", FieldValue::Float(self.amount)),
+                ("side", FieldValue::String(self.side.to_string())),
+            ],
+        ))
+    }
+}
+
+impl InfluxPoint for OrderBookL1 {
+    fn point(&self) -> Option<(&'static str, Vec<(&'static str, FieldValue)>)> {
+        Some((
+            "quotes",
+            vec![
+                ("bid_price", FieldValue::Float(self.best_bid.price)),
+                ("bid_amount", FieldValue::Float(self.best_bid.amount)),
+                ("ask_price", FieldValue::Float(self.best_ask.price)),
+                ("ask_amount", FieldValue::Float(self.best_ask.amount)),
+            ],
+        ))
+    }
+}
+
+impl InfluxPoint for Candle {
+    fn point(&self) -> Option<(&'static str, Vec<(&'static str, FieldValue)>)> {
+        Some((
+            "candles",
+            vec![
+                ("open", FieldValue::Float(self.open)),
+                ("high", FieldValue::Float(self.high)),
+                ("low", FieldValue::Float(self.low)),
+                ("close", FieldValue::Float(self.close)),
+                ("volume", FieldValue::Float(self.volume)),
+                ("trade_count", FieldValue::UInteger(self.trade_count)),
+            ],
+        ))
+    }
+}
+
+impl InfluxPoint for DataKind {
+    fn point(&self) -> Option<(&'static str, Vec<(&'static str, FieldValue)>)> {
+        match self {
+            Self::Trade(trade) => trade.point(),
+            Self::OrderBookL1(book) => book.point(),
+            Self::Candle(candle) => candle.point(),
+            _ => None,
+        }
+    }
+}
+
+/// Sink that consumes any [`Stream`] of normalised [`MarketEvent`]s (eg/
+/// [`Streams`](crate::streams::Streams)) and writes them to an InfluxDB v2 bucket as line
+/// protocol points (see [`line`]).
+///
+/// [`MarketEvent`]s are written in batches via the `/api/v2/write` HTTP API. Failed write
+/// requests are retried according to the [`RetryConfig`], after which every [`MarketEvent`] of
+/// the batch is handled according to the configured [`DeliveryFailurePolicy`].
+pub struct InfluxSink<InstrumentId, T> {
+    client: reqwest::Client,
+    write_url: reqwest::Url,
+    token: Option<String>,
+    batch: BatchConfig,
+    retry: RetryConfig,
+    failure_policy: DeliveryFailurePolicy,
+    phantom: PhantomData<fn(MarketEvent<InstrumentId, T>)>,
+}
+
+impl<InstrumentId, T> InfluxSink<InstrumentId, T> {
+    /// Construct a new [`InfluxSink`] writing to the provided `bucket` of the `org`, hosted by the
+    /// InfluxDB server at the base `url` (eg/ "http://localhost:8086").
+    pub fn new(url: &str, org: &str, bucket: &str) -> Result<Self, SinkError> {
+        let mut write_url = reqwest::Url::parse(url)
+            .and_then(|url| url.join("api/v2/write"))
+            .map_err(|error| SinkError::Init(format!("invalid InfluxDB url {url}: {error}")))?;
+
+        write_url
+            .query_pairs_mut()
+            .append_pair("org", org)
+            .append_pair("bucket", bucket)
+            .append_pair("precision", "ns");
+
+        Ok(Self {
+            client: reqwest::Client::new(),
+            write_url,
+            token: None,
+            batch: BatchConfig::default(),
+            retry: RetryConfig::default(),
+            failure_policy: DeliveryFailurePolicy::default(),
+            phantom: PhantomData,
+        })
+    }
+
+    /// Authenticate every write request using the provided InfluxDB API token.
+    pub fn with_token<S>(self, token: S) -> Self
+    where
+        S: Into<String>,
+    {
+        Self {
+            token: Some(token.into()),
+            ..self
+        }
+    }
+
+    /// Configure how [`MarketEvent`]s are batched into a single write request.
+    pub fn with_batch(self, batch: BatchConfig) -> Self {
+        Self { batch, ..self }
+    }
+
+    /// Configure how failed write requests are retried.
+    pub fn with_retry(self, retry: RetryConfig) -> Self {
+        Self { retry, ..self }
+    }
+
+    /// Configure how [`MarketEvent`]s that could not be delivered are handled.
+    pub fn with_failure_policy(self, failure_policy: DeliveryFailurePolicy) -> Self {
+        Self {
+            failure_policy,
+            ..self
+        }
+    }
+
+    /// Write every [`MarketEvent`] of the provided [`Stream`] until it ends, returning a
+    /// [`SinkSummary`] of the delivered & failed events.
+    ///
+    /// [`MarketEvent`]s that cannot be represented as a point (see [`InfluxPoint`]) are skipped,
+    /// and are not counted in the [`SinkSummary`].
+    ///
+    /// Returns a [`SinkError::Delivery`] if a batch could not be delivered and the
+    /// [`DeliveryFailurePolicy::Stop`] policy is configured.
+    pub async fn run<St>(self, mut stream: St) -> Result<SinkSummary, SinkError>
+    where
+        St: Stream<Item = MarketEvent<InstrumentId, T>> + Unpin,
+        InstrumentId: Display,
+        T: InfluxPoint,
+    {
+        let mut summary = SinkSummary::default();
+
+        while let Some(batch) = next_batch(&mut stream, &self.batch).await {
+            let lines = batch.iter().filter_map(line).collect::<Vec<_>>();
+            if lines.is_empty() {
+                continue;
+            }
+
+            let Err(reason) = self.write(lines.join("\n")).await else {
+                summary.delivered += lines.len() as u64;
+                continue;
+            };
+
+            summary.failed += lines.len() as u64;
+            let error = SinkError::Delivery {
+                destination: self.write_url.to_string(),
+                reason,
+            };
+
+            match self.failure_policy {
+                DeliveryFailurePolicy::Skip => {
+                    warn!(%error, events = lines.len(), "InfluxSink failed to deliver batch")
+                }
+                DeliveryFailurePolicy::Stop => return Err(error),
+            }
+        }
+
+        info!(?summary, "InfluxSink input stream ended");
+        Ok(summary)
+    }
+
+    /// Send a write request with the provided line protocol body, retrying server errors &
+    /// transport failures according to the [`RetryConfig`].
+    async fn write(&self, body: String) -> Result<(), String> {
+        let mut backoff = self.retry.backoff;
+        let mut attempt = 0;
+
+        loop {
+            let mut request = self
+                .client
+                .post(self.write_url.clone())
+                .timeout(self.retry.timeout)
+                .header(reqwest::header::CONTENT_TYPE, "text/plain; charset=utf-8")
+                .body(body.clone());
+            if let Some(token) = &self.token {
+                request = request.header(reqwest::header::AUTHORIZATION, format!("Token {token}"));
+            }
+
+            let (reason, retryable) = match request.send().await {
+                Ok(response) if response.status().is_success() => break Ok(()),
+                Ok(response) => {
+                    let status = response.status();
+                    let retryable = status.is_server_error()
+                        || status == reqwest::StatusCode::TOO_MANY_REQUESTS;
+                    let body = response.text().await.unwrap_or_default();
+                    (format!("{status}: {body}"), retryable)
+                }
+                Err(error) => (error.to_string(), true),
+            };
+
+            if !retryable || attempt >= self.retry.max_retries {
+                break Err(reason);
+            }
+
+            debug!(
+                attempt,
+                %reason,
+                ?backoff,
+                "InfluxSink write request failed, retrying after backoff"
+            );
+            tokio::time::sleep(backoff).await;
+            backoff *= 2;
+            attempt += 1;
+        }
+    }
+}
+
+impl<InstrumentId, T> Debug for InfluxSink<InstrumentId, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("InfluxSink")
+            .field("write_url", &self.write_url.as_str())
+            .field("token", &self.token.as_ref().map(|_| "***"))
+            .field("batch", &self.batch)
+            .field("retry", &self.retry)
+            .field("failure_policy", &self.failure_policy)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Construct the line protocol point of the provided [`MarketEvent`], tagged with its exchange
+/// & instrument, and timestamped with its exchange time in nanoseconds.
+///
+/// Returns `None` if the [`MarketEvent`] cannot be represented as a point.
+///
+/// eg/ `trades,exchange=binance_spot,instrument=btc_usdt price=100,amount=1.5 1704164645000000000`
+pub fn line<InstrumentId, T>(event: &MarketEvent<InstrumentId, T>) -> Option<String>
+where
+    InstrumentId: Display,
+    T: InfluxPoint,
+{
+    let (measurement, fields) = event.kind.point()?;
+    let timestamp = event.exchange_time.timestamp_nanos_opt()?;
+
+    let mut line = format!(
+        "{},exchange={},instrument={} ",
+        escape(measurement, &[',', ' ']),
+        escape(&event.exchange.to_string(), &[',', '=', ' ']),
+        escape(&event.instrument.to_string(), &[',', '=', ' ']),
+    );
+    for (index, (key, value)) in fields.iter().enumerate() {
+        let delimiter = if index == 0 { "" } else { "," };
+        let _ = write!(line, "{delimiter}{}={value}", escape(key, &[',', '=', ' ']));
+    }
+    let _ = write!(line, " {timestamp}");
+
+    Some(line)
+}
+
+/// Escape the provided special characters of a line protocol measurement, tag or field key.
+fn escape(value: &str, special: &[char]) -> String {
+    value
+        .chars()
+        .fold(String::with_capacity(value.len()), |mut escaped, char| {
+            if special.contains(&char) {
+                escaped.push('\\');
+            }
+            escaped.push(char);
+            escaped
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::subscription::{book::Level, open_interest::OpenInterest};
+    use barter_integration::model::{Exchange, Side};
+    use chrono::{DateTime, TimeZone, Utc};
+    use tokio::{
+        io::{AsyncReadExt, AsyncWriteExt},
+        net::TcpListener,
+    };
+
+    fn time() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2024, 1, 2, 3, 4, 5).unwrap()
+    }
+
+    fn event<T>(instrument: &'static str, kind: T) -> MarketEvent<&'static str, T> {
+        MarketEvent {
+            exchange_time: time(),
+            received_time: time(),
+            exchange: Exchange::from("binance_spot"),
+            instrument,
+            kind,
+        }
+    }
+
+    fn trade() -> PublicTrade {
+        PublicTrade {
+            id: "1".to_owned(),
+            price: 100.0,
+            amount: 1.5,
+            side: Side::Buy,
+        }
+    }
+
+    #[test]
+    fn test_line() {
+        // TC0: PublicTrade
+        assert_eq!(
+            line(&event("btc_usdt", trade())).unwrap(),
+            "trades,exchange=binance_spot,instrument=btc_usdt \
+             id=\"1\",price=100,amount=1.5,side=\"buy\" 1704164645000000000"
+        );
+
+        // TC1: OrderBookL1
+        let book = OrderBookL1 {
+            last_update_time: time(),
+            best_bid: Level::new(99.5, 2.0),
+            best_ask: Level::new(100.5, 3.0),
+        };
+        assert_eq!(
+            line(&event("btc_usdt", book)).unwrap(),
+            "quotes,exchange=binance_spot,instrument=btc_usdt \
+             bid_price=99.5,bid_amount=2,ask_price=100.5,ask_amount=3 1704164645000000000"
+        );
+
+        // TC2: Candle
+        let candle = Candle {
+            close_time: time(),
+            open: 1.0,
+            high: 2.0,
+            low: 0.5,
+            close: 1.5,
+            volume: 10.0,
+            trade_count: 7,
+        };
+        assert_eq!(
+            line(&event("btc_usdt", candle)).unwrap(),
+            "candles,exchange=binance_spot,instrument=btc_usdt \
+             open=1,high=2,low=0.5,close=1.5,volume=10,trade_count=7u 1704164645000000000"
+        );
+
+        // TC3: tag values & string fields are escaped
+        let trade = PublicTrade {
+            id: "a\"b\\c".to_owned(),
+            ..trade()
+        };
+        assert_eq!(
+            line(&event("btc usdt,x=y", trade)).unwrap(),
+            "trades,exchange=binance_spot,instrument=btc\\ usdt\\,x\\=y \
+             id=\"a\\\"b\\\\c\",price=100,amount=1.5,side=\"buy\" 1704164645000000000"
+        );
+
+        // TC4: unsupported DataKind variants are skipped
+        let open_interest = DataKind::OpenInterest(OpenInterest { contracts: 1.0 });
+        assert!(line(&event("btc_usdt", open_interest)).is_none());
+    }
+
+    #[tokio::test]
+    async fn test_run_retries_server_errors() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+
+        // Mock InfluxDB server that fails the first write request, then accepts the retry
+        let server = tokio::spawn(async move {
+            let mut requests = Vec::new();
+            for status in ["503 Service Unavailable", "204 No Content"] {
+                let (mut socket, _) = listener.accept().await.unwrap();
+                let mut request = Vec::new();
+                let mut buffer = [0u8; 4096];
+                loop {
+                    let read = socket.read(&mut buffer).await.unwrap();
+                    request.extend_from_slice(&buffer[..read]);
+                    let text = String::from_utf8_lossy(&request);
+                    if let Some((head, body)) = text.split_once("\r\n\r\n") {
+                        let length = head
+                            .lines()
+                            .find_map(|line| line.strip_prefix("content-length: "))
+                            .map_or(0, |length| length.parse::<usize>().unwrap());
+                        if body.len() >= length {
+                            break;
+                        }
+                    }
+                }
+                socket
+                    .write_all(
+                        format!(
+                            "HTTP/1.1 {status}\r\ncontent-length: 0\r\nconnection: close\r\n\r\n"
+                        )
+                        .as_bytes(),
+                    )
+                    .await
+                    .unwrap();
+                requests.push(String::from_utf8(request).unwrap());
+            }
+            requests
+        });
+
+        let sink = InfluxSink::new(&url, "barter", "market")
+            .unwrap()
+            .with_token("secret")
+            .with_retry(RetryConfig {
+                backoff: Duration::from_millis(1),
+                ..RetryConfig::default()
+            });
+
+        let events = vec![
+            event("btc_usdt", DataKind::Trade(trade())),
+            event(
+                "btc_usdt",
+                DataKind::OpenInterest(OpenInterest { contracts: 1.0 }),
+            ),
+            event("eth_usdt", DataKind::Trade(trade())),
+        ];
+        let summary = sink.run(futures::stream::iter(events)).await.unwrap();
+        assert_eq!(
+            summary,
+            SinkSummary {
+                delivered: 2,
+                failed: 0
+            }
+        );
+
+        let requests = server.await.unwrap();
+        assert_eq!(requests[0], requests[1]);
+        assert!(requests[1]
+            .starts_with("POST /api/v2/write?org=barter&bucket=market&precision=ns HTTP/1.1"));
+        assert!(requests[1].contains("authorization: Token secret"));
+        assert!(requests[1].ends_with(
+            "instrument=btc_usdt id=\"1\",price=100,amount=1.5,side=\"buy\" 1704164645000000000\n\
+             trades,exchange=binance_spot,instrument=eth_usdt \
+             id=\"1\",price=100,amount=1.5,side=\"buy\" 1704164645000000000"
+        ));
+    }
+}
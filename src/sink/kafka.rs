@@ -0,0 +1,194 @@
+use super::{
+    encoder::{EventEncoder, JsonEncoder},
+    next_batch, partition_key, BatchConfig, DeliveryFailurePolicy, SinkError, SinkSummary,
+};
+use crate::event::MarketEvent;
+use futures::{future::join_all, Stream};
+use rdkafka::{
+    config::ClientConfig,
+    producer::{FutureProducer, FutureRecord, Producer},
+};
+use serde::Serialize;
+use std::{
+    fmt::{Debug, Display},
+    sync::Arc,
+    time::Duration,
+};
+use tracing::{info, warn};
+
+/// Communicative type alias representing a [`KafkaSink::with_topic`] function, used to determine
+/// the topic each [`MarketEvent`] is published to.
+pub type KafkaTopic<Event> = Arc<dyn Fn(&Event) -> String + Send + Sync>;
+
+/// Sink that consumes any [`Stream`] of normalised [`MarketEvent`]s (eg/
+/// [`Streams`](crate::streams::Streams)) and publishes them to Apache Kafka topics, encoded as JSON
+/// by default (see [`KafkaSink::with_encoder`]).
+///
+/// Each [`MarketEvent`] is keyed by its [`partition_key`], such that every event of an exchange
+/// instrument is routed to the same partition, and published in batches (see [`BatchConfig`]).
+///
+/// eg/ `KafkaSink::new(&config, "market_events")?.with_topic(|event| format!("trades.{}", event.exchange)).run(streams).await`
+pub struct KafkaSink<InstrumentId, T, Encoder = JsonEncoder> {
+    producer: FutureProducer,
+    topic: KafkaTopic<MarketEvent<InstrumentId, T>>,
+    encoder: Encoder,
+    batch: BatchConfig,
+    queue_timeout: Duration,
+    failure_policy: DeliveryFailurePolicy,
+}
+
+impl<InstrumentId, T> KafkaSink<InstrumentId, T> {
+    /// Construct a new [`KafkaSink`] using the provided librdkafka [`ClientConfig`] (eg/
+    /// `bootstrap.servers`), publishing every [`MarketEvent`] to the provided topic.
+    pub fn new<Topic>(config: &ClientConfig, topic: Topic) -> Result<Self, SinkError>
+    where
+        Topic: Into<String>,
+    {
+        let producer = config
+            .create::<FutureProducer>()
+            .map_err(|error| SinkError::Init(error.to_string()))?;
+
+        let topic = topic.into();
+
+        Ok(Self {
+            producer,
+            topic: Arc::new(move |_| topic.clone()),
+            encoder: JsonEncoder,
+            batch: BatchConfig::default(),
+            queue_timeout: Duration::from_secs(5),
+            failure_policy: DeliveryFailurePolicy::default(),
+        })
+    }
+}
+
+impl<InstrumentId, T, Encoder> KafkaSink<InstrumentId, T, Encoder> {
+    /// Encode each published [`MarketEvent`] using the provided [`EventEncoder`] (eg/
+    /// MessagePack), rather than the default [`JsonEncoder`].
+    pub fn with_encoder<NewEncoder>(
+        self,
+        encoder: NewEncoder,
+    ) -> KafkaSink<InstrumentId, T, NewEncoder>
+    where
+        NewEncoder: EventEncoder,
+    {
+        KafkaSink {
+            producer: self.producer,
+            topic: self.topic,
+            encoder,
+            batch: self.batch,
+            queue_timeout: self.queue_timeout,
+            failure_policy: self.failure_policy,
+        }
+    }
+
+    /// Determine the topic each [`MarketEvent`] is published to using the provided function
+    /// (eg/ a topic per exchange or per [`SubKind`](crate::subscription::SubKind)).
+    pub fn with_topic<F>(self, topic: F) -> Self
+    where
+        F: Fn(&MarketEvent<InstrumentId, T>) -> String + Send + Sync + 'static,
+    {
+        Self {
+            topic: Arc::new(topic),
+            ..self
+        }
+    }
+
+    /// Configure how [`MarketEvent`]s are batched before being published.
+    pub fn with_batch(self, batch: BatchConfig) -> Self {
+        Self { batch, ..self }
+    }
+
+    /// Maximum duration to wait for space in the producer queue before a [`MarketEvent`] is
+    /// considered undelivered.
+    pub fn with_queue_timeout(self, queue_timeout: Duration) -> Self {
+        Self {
+            queue_timeout,
+            ..self
+        }
+    }
+
+    /// Configure how [`MarketEvent`]s that could not be delivered (after any retries configured
+    /// via the librdkafka `message.send.max.retries` property) are handled.
+    pub fn with_failure_policy(self, failure_policy: DeliveryFailurePolicy) -> Self {
+        Self {
+            failure_policy,
+            ..self
+        }
+    }
+
+    /// Publish every [`MarketEvent`] of the provided [`Stream`] until it ends, returning a
+    /// [`SinkSummary`] of the delivered & failed events.
+    ///
+    /// Returns a [`SinkError::Delivery`] if a [`MarketEvent`] could not be delivered and the
+    /// [`DeliveryFailurePolicy::Stop`] policy is configured.
+    pub async fn run<St>(self, mut stream: St) -> Result<SinkSummary, SinkError>
+    where
+        St: Stream<Item = MarketEvent<InstrumentId, T>> + Unpin,
+        InstrumentId: Display + Serialize,
+        T: Serialize,
+        Encoder: EventEncoder,
+    {
+        let mut summary = SinkSummary::default();
+
+        while let Some(batch) = next_batch(&mut stream, &self.batch).await {
+            let records = batch
+                .iter()
+                .map(|event| {
+                    self.encoder
+                        .encode(event)
+                        .map(|payload| ((self.topic)(event), partition_key(event), payload))
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+
+            let deliveries = join_all(records.iter().map(|(topic, key, payload)| {
+                let record = FutureRecord::to(topic).key(key).payload(payload);
+                self.producer.send(record, self.queue_timeout)
+            }))
+            .await;
+
+            for (delivery, (topic, key, _)) in deliveries.into_iter().zip(&records) {
+                let Err((error, _)) = delivery else {
+                    summary.delivered += 1;
+                    continue;
+                };
+
+                summary.failed += 1;
+                let error = SinkError::Delivery {
+                    destination: format!("{topic}/{key}"),
+                    reason: error.to_string(),
+                };
+
+                match self.failure_policy {
+                    DeliveryFailurePolicy::Skip => {
+                        warn!(%error, "KafkaSink failed to deliver MarketEvent")
+                    }
+                    DeliveryFailurePolicy::Stop => return Err(error),
+                }
+            }
+        }
+
+        self.producer
+            .flush(self.queue_timeout)
+            .map_err(|error| SinkError::Delivery {
+                destination: "kafka".to_owned(),
+                reason: error.to_string(),
+            })?;
+
+        info!(?summary, "KafkaSink input stream ended");
+        Ok(summary)
+    }
+}
+
+impl<InstrumentId, T, Encoder> Debug for KafkaSink<InstrumentId, T, Encoder>
+where
+    Encoder: Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("KafkaSink")
+            .field("encoder", &self.encoder)
+            .field("batch", &self.batch)
+            .field("queue_timeout", &self.queue_timeout)
+            .field("failure_policy", &self.failure_policy)
+            .finish_non_exhaustive()
+    }
+}
@@ -0,0 +1,167 @@
+use crate::event::MarketEvent;
+use futures::{Stream, StreamExt};
+use serde::{Deserialize, Serialize};
+use std::{fmt::Display, time::Duration};
+use thiserror::Error;
+
+/// Pluggable [`EventEncoder`](encoder::EventEncoder)s that determine the payload format of
+/// published [`MarketEvent`]s (eg/ JSON, MessagePack).
+pub mod encoder;
+
+/// [`InfluxSink`](influx::InfluxSink) that writes normalised trades, L1 quotes & candles to
+/// InfluxDB as line protocol points.
+#[cfg(feature = "influx")]
+pub mod influx;
+
+/// [`KafkaSink`](kafka::KafkaSink) that publishes normalised [`MarketEvent`]s to Apache Kafka
+/// topics.
+#[cfg(feature = "kafka")]
+pub mod kafka;
+
+/// [`NatsSink`](nats::NatsSink) that publishes normalised [`MarketEvent`]s to NATS subject
+/// hierarchies, optionally via JetStream.
+#[cfg(feature = "nats")]
+pub mod nats;
+
+/// [`ZmqSink`](zeromq::ZmqSink) that broadcasts normalised [`MarketEvent`]s via a ZeroMQ PUB
+/// socket.
+#[cfg(feature = "zeromq")]
+pub mod zeromq;
+
+/// All errors generated by market data sinks.
+#[derive(Debug, Error)]
+pub enum SinkError {
+    #[error("failed to serialise MarketEvent: {0}")]
+    Serialise(#[from] serde_json::Error),
+
+    #[cfg(feature = "msgpack")]
+    #[error("failed to encode MarketEvent as MessagePack: {0}")]
+    MessagePack(#[from] rmp_serde::encode::Error),
+
+    #[error("failed to initialise sink: {0}")]
+    Init(String),
+
+    #[error("failed to deliver MarketEvent to {destination}: {reason}")]
+    Delivery { destination: String, reason: String },
+}
+
+/// Configures how a sink batches [`MarketEvent`]s before publishing them.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Deserialize, Serialize)]
+pub struct BatchConfig {
+    /// Maximum number of [`MarketEvent`]s published in a single batch.
+    pub max_events: usize,
+    /// Maximum duration to wait for a batch to fill after its first [`MarketEvent`] is received.
+    pub linger: Duration,
+}
+
+impl Default for BatchConfig {
+    fn default() -> Self {
+        Self {
+            max_events: 500,
+            linger: Duration::from_millis(100),
+        }
+    }
+}
+
+/// Determines how a sink handles a [`MarketEvent`] that could not be delivered.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Default, Deserialize, Serialize)]
+pub enum DeliveryFailurePolicy {
+    /// Log & count the undelivered [`MarketEvent`], then continue publishing.
+    #[default]
+    Skip,
+    /// Stop publishing, returning the [`SinkError::Delivery`].
+    Stop,
+}
+
+/// Summary of the [`MarketEvent`]s published by a sink once its input stream has ended.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Default, Deserialize, Serialize)]
+pub struct SinkSummary {
+    pub delivered: u64,
+    pub failed: u64,
+}
+
+/// Await the next batch of items from the provided [`Stream`], returning `None` once it has ended.
+///
+/// A batch is returned once it contains [`BatchConfig::max_events`] items, once
+/// [`BatchConfig::linger`] has elapsed since its first item was received, or once the [`Stream`]
+/// ends.
+pub async fn next_batch<St>(stream: &mut St, config: &BatchConfig) -> Option<Vec<St::Item>>
+where
+    St: Stream + Unpin,
+{
+    let first = stream.next().await?;
+
+    let mut batch = Vec::with_capacity(config.max_events.max(1));
+    batch.push(first);
+
+    let deadline = tokio::time::sleep(config.linger);
+    tokio::pin!(deadline);
+
+    while batch.len() < config.max_events {
+        tokio::select! {
+            next = stream.next() => match next {
+                Some(item) => batch.push(item),
+                None => break,
+            },
+            _ = &mut deadline => break,
+        }
+    }
+
+    Some(batch)
+}
+
+/// Key used to partition published [`MarketEvent`]s, such that every event of an exchange
+/// instrument is routed to the same partition (preserving its order).
+///
+/// eg/ "binance_spot|btc_usdt"
+pub fn partition_key<InstrumentId, T>(event: &MarketEvent<InstrumentId, T>) -> String
+where
+    InstrumentId: Display,
+{
+    format!("{}|{}", event.exchange, event.instrument)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use barter_integration::model::Exchange;
+    use chrono::Utc;
+    use tokio::sync::mpsc;
+    use tokio_stream::wrappers::UnboundedReceiverStream;
+
+    #[tokio::test(start_paused = true)]
+    async fn test_next_batch() {
+        let config = BatchConfig {
+            max_events: 2,
+            linger: Duration::from_millis(100),
+        };
+        let (tx, rx) = mpsc::unbounded_channel();
+        let mut stream = UnboundedReceiverStream::new(rx);
+
+        // Full batches are returned immediately
+        (1..=3).for_each(|item| tx.send(item).unwrap());
+        assert_eq!(next_batch(&mut stream, &config).await, Some(vec![1, 2]));
+
+        // Partial batches are returned once the linger has elapsed
+        assert_eq!(next_batch(&mut stream, &config).await, Some(vec![3]));
+
+        // Partial batches are returned once the Stream ends
+        tx.send(4).unwrap();
+        drop(tx);
+        assert_eq!(next_batch(&mut stream, &config).await, Some(vec![4]));
+        assert_eq!(next_batch(&mut stream, &config).await, None);
+    }
+
+    #[test]
+    fn test_partition_key() {
+        let event = MarketEvent {
+            exchange_time: Utc::now(),
+            received_time: Utc::now(),
+            exchange: Exchange::from("binance_spot"),
+            instrument: "btc_usdt",
+            kind: (),
+        };
+
+        assert_eq!(partition_key(&event), "binance_spot|btc_usdt");
+    }
+}
@@ -0,0 +1,273 @@
+use super::{
+    encoder::{EventEncoder, JsonEncoder},
+    next_batch, BatchConfig, DeliveryFailurePolicy, SinkError, SinkSummary,
+};
+use crate::event::MarketEvent;
+use async_nats::{jetstream, Client};
+use futures::{future::join_all, Stream};
+use serde::Serialize;
+use std::{
+    fmt::{Debug, Display},
+    sync::Arc,
+};
+use tracing::{info, warn};
+
+/// Communicative type alias representing a [`NatsSink::with_subject`] function, used to determine
+/// the subject each [`MarketEvent`] is published to.
+pub type NatsSubject<Event> = Arc<dyn Fn(&Event) -> String + Send + Sync>;
+
+/// Sink that consumes any [`Stream`] of normalised [`MarketEvent`]s (eg/
+/// [`Streams`](crate::streams::Streams)) and publishes them to NATS subjects, encoded as JSON by
+/// default (see [`NatsSink::with_encoder`]).
+///
+/// By default, each [`MarketEvent`] is published to the subject hierarchy
+/// `market.{exchange}.{instrument}.{kind}` (see [`subject`]), allowing consumers to subscribe
+/// using wildcards (eg/ `market.binance_spot.*.trades`).
+///
+/// Optionally, [`MarketEvent`]s can be published via JetStream (see [`NatsSink::with_jetstream`])
+/// for persistence, in which case each publish is acknowledged by the server.
+pub struct NatsSink<InstrumentId, T, Encoder = JsonEncoder> {
+    client: Client,
+    jetstream: Option<jetstream::Context>,
+    subject: NatsSubject<MarketEvent<InstrumentId, T>>,
+    encoder: Encoder,
+    batch: BatchConfig,
+    failure_policy: DeliveryFailurePolicy,
+}
+
+impl<InstrumentId, T> NatsSink<InstrumentId, T>
+where
+    InstrumentId: Display,
+{
+    /// Construct a new [`NatsSink`] using the provided connected NATS [`Client`], publishing every
+    /// [`MarketEvent`] to the `market.{exchange}.{instrument}.{kind}` subject with the provided
+    /// `kind` token (eg/ "trades").
+    pub fn new<Kind>(client: Client, kind: Kind) -> Self
+    where
+        Kind: Into<String>,
+    {
+        let kind = kind.into();
+
+        Self {
+            client,
+            jetstream: None,
+            subject: Arc::new(move |event| subject(event, &kind)),
+            encoder: JsonEncoder,
+            batch: BatchConfig::default(),
+            failure_policy: DeliveryFailurePolicy::default(),
+        }
+    }
+}
+
+impl<InstrumentId, T, Encoder> NatsSink<InstrumentId, T, Encoder> {
+    /// Encode each published [`MarketEvent`] using the provided [`EventEncoder`] (eg/
+    /// MessagePack), rather than the default [`JsonEncoder`].
+    pub fn with_encoder<NewEncoder>(
+        self,
+        encoder: NewEncoder,
+    ) -> NatsSink<InstrumentId, T, NewEncoder>
+    where
+        NewEncoder: EventEncoder,
+    {
+        NatsSink {
+            client: self.client,
+            jetstream: self.jetstream,
+            subject: self.subject,
+            encoder,
+            batch: self.batch,
+            failure_policy: self.failure_policy,
+        }
+    }
+
+    /// Determine the subject each [`MarketEvent`] is published to using the provided function.
+    pub fn with_subject<F>(self, subject: F) -> Self
+    where
+        F: Fn(&MarketEvent<InstrumentId, T>) -> String + Send + Sync + 'static,
+    {
+        Self {
+            subject: Arc::new(subject),
+            ..self
+        }
+    }
+
+    /// Publish via JetStream, persisting [`MarketEvent`]s in any stream bound to their subjects.
+    ///
+    /// Each publish is acknowledged by the server, and un-acknowledged [`MarketEvent`]s are
+    /// handled according to the configured [`DeliveryFailurePolicy`].
+    pub fn with_jetstream(self) -> Self {
+        Self {
+            jetstream: Some(jetstream::new(self.client.clone())),
+            ..self
+        }
+    }
+
+    /// Configure how [`MarketEvent`]s are batched before being published.
+    pub fn with_batch(self, batch: BatchConfig) -> Self {
+        Self { batch, ..self }
+    }
+
+    /// Configure how [`MarketEvent`]s that could not be delivered are handled.
+    pub fn with_failure_policy(self, failure_policy: DeliveryFailurePolicy) -> Self {
+        Self {
+            failure_policy,
+            ..self
+        }
+    }
+
+    /// Publish every [`MarketEvent`] of the provided [`Stream`] until it ends, returning a
+    /// [`SinkSummary`] of the delivered & failed events.
+    ///
+    /// Returns a [`SinkError::Delivery`] if a [`MarketEvent`] could not be delivered and the
+    /// [`DeliveryFailurePolicy::Stop`] policy is configured.
+    pub async fn run<St>(self, mut stream: St) -> Result<SinkSummary, SinkError>
+    where
+        St: Stream<Item = MarketEvent<InstrumentId, T>> + Unpin,
+        InstrumentId: Serialize,
+        T: Serialize,
+        Encoder: EventEncoder,
+    {
+        let mut summary = SinkSummary::default();
+
+        while let Some(batch) = next_batch(&mut stream, &self.batch).await {
+            let records = batch
+                .iter()
+                .map(|event| {
+                    self.encoder
+                        .encode(event)
+                        .map(|payload| ((self.subject)(event), payload))
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+
+            let deliveries = match &self.jetstream {
+                // JetStream publishes are awaited concurrently until acknowledged
+                Some(jetstream) => {
+                    join_all(records.into_iter().map(|(subject, payload)| async move {
+                        let ack = match jetstream.publish(subject.clone(), payload.into()).await {
+                            Ok(ack) => ack.await.map(|_| ()).map_err(|error| error.to_string()),
+                            Err(error) => Err(error.to_string()),
+                        };
+                        (subject, ack)
+                    }))
+                    .await
+                }
+                // Core NATS publishes are fire & forget once buffered by the Client
+                None => {
+                    let mut deliveries = Vec::with_capacity(records.len());
+                    for (subject, payload) in records {
+                        let delivery = self
+                            .client
+                            .publish(subject.clone(), payload.into())
+                            .await
+                            .map_err(|error| error.to_string());
+                        deliveries.push((subject, delivery));
+                    }
+                    deliveries
+                }
+            };
+
+            for (subject, delivery) in deliveries {
+                let Err(reason) = delivery else {
+                    summary.delivered += 1;
+                    continue;
+                };
+
+                summary.failed += 1;
+                let error = SinkError::Delivery {
+                    destination: subject,
+                    reason,
+                };
+
+                match self.failure_policy {
+                    DeliveryFailurePolicy::Skip => {
+                        warn!(%error, "NatsSink failed to deliver MarketEvent")
+                    }
+                    DeliveryFailurePolicy::Stop => return Err(error),
+                }
+            }
+        }
+
+        self.client
+            .flush()
+            .await
+            .map_err(|error| SinkError::Delivery {
+                destination: "nats".to_owned(),
+                reason: error.to_string(),
+            })?;
+
+        info!(?summary, "NatsSink input stream ended");
+        Ok(summary)
+    }
+}
+
+impl<InstrumentId, T, Encoder> Debug for NatsSink<InstrumentId, T, Encoder>
+where
+    Encoder: Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("NatsSink")
+            .field("encoder", &self.encoder)
+            .field("jetstream", &self.jetstream.is_some())
+            .field("batch", &self.batch)
+            .field("failure_policy", &self.failure_policy)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Construct the `market.{exchange}.{instrument}.{kind}` subject of the provided [`MarketEvent`].
+///
+/// Characters that are not valid within a NATS subject token (ie/ '.', '*', '>' & whitespace)
+/// are replaced with '_'.
+///
+/// eg/ "market.binance_spot.btc_usdt.trades"
+pub fn subject<InstrumentId, T>(event: &MarketEvent<InstrumentId, T>, kind: &str) -> String
+where
+    InstrumentId: Display,
+{
+    format!(
+        "market.{}.{}.{}",
+        token(&event.exchange.to_string()),
+        token(&event.instrument.to_string()),
+        token(kind)
+    )
+}
+
+fn token(value: &str) -> String {
+    value
+        .chars()
+        .map(|char| match char {
+            '.' | '*' | '>' => '_',
+            char if char.is_whitespace() => '_',
+            char => char,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use barter_integration::model::Exchange;
+    use chrono::Utc;
+
+    #[test]
+    fn test_subject() {
+        let event = |instrument| MarketEvent {
+            exchange_time: Utc::now(),
+            received_time: Utc::now(),
+            exchange: Exchange::from("binance_spot"),
+            instrument,
+            kind: (),
+        };
+
+        // TC0: valid tokens are unchanged
+        assert_eq!(
+            subject(&event("btc_usdt"), "trades"),
+            "market.binance_spot.btc_usdt.trades"
+        );
+
+        // TC1: invalid token characters are replaced
+        assert_eq!(
+            subject(&event("btc.usdt *>"), "order books"),
+            "market.binance_spot.btc_usdt___.order_books"
+        );
+    }
+}
@@ -0,0 +1,208 @@
+use super::{
+    encoder::{EventEncoder, JsonEncoder},
+    partition_key, DeliveryFailurePolicy, SinkError, SinkSummary,
+};
+use crate::event::MarketEvent;
+use futures::{Stream, StreamExt};
+use serde::Serialize;
+use std::{
+    fmt::{Debug, Display},
+    sync::Arc,
+};
+use tracing::{info, warn};
+use zeromq::{Endpoint, PubSocket, Socket, SocketSend, ZmqMessage};
+
+/// Communicative type alias representing a [`ZmqSink::with_topic`] function, used to determine
+/// the topic prefix each [`MarketEvent`] is published with.
+pub type ZmqTopic<Event> = Arc<dyn Fn(&Event) -> String + Send + Sync>;
+
+/// Sink that binds a ZeroMQ PUB socket and broadcasts every [`MarketEvent`] of any [`Stream`] (eg/
+/// [`Streams`](crate::streams::Streams)) to its connected SUB sockets, providing low-latency
+/// IPC/TCP fan-out to non-Rust consumers.
+///
+/// Each [`MarketEvent`] is published as a two frame message: the topic (by default the
+/// [`partition_key`], eg/ "binance_spot|btc_usdt"), followed by the encoded event (JSON by
+/// default, see [`ZmqSink::with_encoder`]). SUB sockets can therefore filter by topic prefix
+/// (eg/ subscribe to "binance_spot|").
+///
+/// Note that PUB sockets drop messages for SUB sockets that are not connected, or are too slow.
+pub struct ZmqSink<InstrumentId, T, Encoder = JsonEncoder> {
+    socket: PubSocket,
+    endpoint: Endpoint,
+    topic: ZmqTopic<MarketEvent<InstrumentId, T>>,
+    encoder: Encoder,
+    failure_policy: DeliveryFailurePolicy,
+}
+
+impl<InstrumentId, T> ZmqSink<InstrumentId, T>
+where
+    InstrumentId: Display,
+{
+    /// Construct a new [`ZmqSink`] with a PUB socket bound to the provided endpoint
+    /// (eg/ "tcp://127.0.0.1:5556", "ipc:///tmp/market_events").
+    pub async fn bind(endpoint: &str) -> Result<Self, SinkError> {
+        let mut socket = PubSocket::new();
+        let endpoint = socket
+            .bind(endpoint)
+            .await
+            .map_err(|error| SinkError::Init(error.to_string()))?;
+
+        Ok(Self {
+            socket,
+            endpoint,
+            topic: Arc::new(|event| partition_key(event)),
+            encoder: JsonEncoder,
+            failure_policy: DeliveryFailurePolicy::default(),
+        })
+    }
+}
+
+impl<InstrumentId, T, Encoder> ZmqSink<InstrumentId, T, Encoder> {
+    /// [`Endpoint`] the PUB socket is bound to, including any port assigned by the OS.
+    pub fn endpoint(&self) -> &Endpoint {
+        &self.endpoint
+    }
+
+    /// Encode each broadcast [`MarketEvent`] using the provided [`EventEncoder`] (eg/
+    /// MessagePack), rather than the default [`JsonEncoder`].
+    pub fn with_encoder<NewEncoder>(
+        self,
+        encoder: NewEncoder,
+    ) -> ZmqSink<InstrumentId, T, NewEncoder>
+    where
+        NewEncoder: EventEncoder,
+    {
+        ZmqSink {
+            socket: self.socket,
+            endpoint: self.endpoint,
+            topic: self.topic,
+            encoder,
+            failure_policy: self.failure_policy,
+        }
+    }
+
+    /// Determine the topic prefix each [`MarketEvent`] is published with using the provided
+    /// function.
+    pub fn with_topic<F>(self, topic: F) -> Self
+    where
+        F: Fn(&MarketEvent<InstrumentId, T>) -> String + Send + Sync + 'static,
+    {
+        Self {
+            topic: Arc::new(topic),
+            ..self
+        }
+    }
+
+    /// Configure how [`MarketEvent`]s that could not be sent are handled.
+    pub fn with_failure_policy(self, failure_policy: DeliveryFailurePolicy) -> Self {
+        Self {
+            failure_policy,
+            ..self
+        }
+    }
+
+    /// Broadcast every [`MarketEvent`] of the provided [`Stream`] until it ends, returning a
+    /// [`SinkSummary`] of the sent & failed events.
+    ///
+    /// Returns a [`SinkError::Delivery`] if a [`MarketEvent`] could not be sent and the
+    /// [`DeliveryFailurePolicy::Stop`] policy is configured.
+    pub async fn run<St>(mut self, mut stream: St) -> Result<SinkSummary, SinkError>
+    where
+        St: Stream<Item = MarketEvent<InstrumentId, T>> + Unpin,
+        InstrumentId: Serialize,
+        T: Serialize,
+        Encoder: EventEncoder,
+    {
+        let mut summary = SinkSummary::default();
+
+        while let Some(event) = stream.next().await {
+            let topic = (self.topic)(&event);
+            let mut message = ZmqMessage::from(topic.clone());
+            message.push_back(self.encoder.encode(&event)?.into());
+
+            let Err(error) = self.socket.send(message).await else {
+                summary.delivered += 1;
+                continue;
+            };
+
+            summary.failed += 1;
+            let error = SinkError::Delivery {
+                destination: format!("{}/{topic}", self.endpoint),
+                reason: error.to_string(),
+            };
+
+            match self.failure_policy {
+                DeliveryFailurePolicy::Skip => {
+                    warn!(%error, "ZmqSink failed to send MarketEvent")
+                }
+                DeliveryFailurePolicy::Stop => return Err(error),
+            }
+        }
+
+        info!(?summary, "ZmqSink input stream ended");
+        Ok(summary)
+    }
+}
+
+impl<InstrumentId, T, Encoder> Debug for ZmqSink<InstrumentId, T, Encoder>
+where
+    Encoder: Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ZmqSink")
+            .field("endpoint", &self.endpoint)
+            .field("encoder", &self.encoder)
+            .field("failure_policy", &self.failure_policy)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use barter_integration::model::Exchange;
+    use chrono::Utc;
+    use std::time::Duration;
+    use tokio::sync::mpsc;
+    use tokio_stream::wrappers::UnboundedReceiverStream;
+    use zeromq::{SocketRecv, SubSocket};
+
+    #[tokio::test]
+    async fn test_zmq_sink() {
+        let sink = ZmqSink::<&'static str, u64>::bind("tcp://127.0.0.1:0")
+            .await
+            .unwrap();
+
+        let mut sub = SubSocket::new();
+        sub.connect(&sink.endpoint().to_string()).await.unwrap();
+        sub.subscribe("binance_spot|").await.unwrap();
+
+        let (tx, rx) = mpsc::unbounded_channel();
+        let sink = tokio::spawn(sink.run(UnboundedReceiverStream::new(rx)));
+
+        // PUB sockets drop messages until the subscription has propagated, so publish until received
+        let message = loop {
+            tx.send(MarketEvent {
+                exchange_time: Utc::now(),
+                received_time: Utc::now(),
+                exchange: Exchange::from("binance_spot"),
+                instrument: "btc_usdt",
+                kind: 1,
+            })
+            .unwrap();
+
+            if let Ok(message) = tokio::time::timeout(Duration::from_millis(50), sub.recv()).await {
+                break message.unwrap();
+            }
+        };
+
+        let frames = message.into_vec();
+        assert_eq!(frames[0].as_ref(), b"binance_spot|btc_usdt");
+        let event = serde_json::from_slice::<MarketEvent<String, u64>>(&frames[1]).unwrap();
+        assert_eq!(event.instrument, "btc_usdt");
+        assert_eq!(event.kind, 1);
+
+        drop(tx);
+        assert!(sink.await.unwrap().unwrap().delivered >= 1);
+    }
+}
@@ -0,0 +1,309 @@
+use super::{multi::MultiStreamBuilder, StreamBuilder};
+use crate::{
+    error::DataError,
+    event::MarketEvent,
+    exchange::{
+        binance::{futures::BinanceFuturesUsd, spot::BinanceSpot},
+        bitfinex::Bitfinex,
+        bitmex::Bitmex,
+        bybit::{futures::BybitPerpetualsUsd, spot::BybitSpot},
+        coinbase::Coinbase,
+        deribit::Deribit,
+        gateio::{
+            future::{GateioFuturesBtc, GateioFuturesUsd},
+            option::GateioOptions,
+            perpetual::{GateioPerpetualsBtc, GateioPerpetualsUsd},
+            spot::GateioSpot,
+        },
+        kraken::Kraken,
+        kucoin::Kucoin,
+        okx::Okx,
+        ExchangeId,
+    },
+    subscription::{
+        book::{OrderBook, OrderBookL1, OrderBooksL1, OrderBooksL2},
+        liquidation::{Liquidation, Liquidations},
+        trade::{PublicTrade, PublicTrades},
+        SubKind, SubKindId, Subscription, SubscriptionKind,
+    },
+};
+use barter_integration::model::instrument::{kind::InstrumentKind, symbol::Symbol, Instrument};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// Deserialisable configuration of heterogeneous [`Subscription`]s, enabling fully
+/// config-driven deployments.
+///
+/// See [`StreamBuilder::subscribe_from_config`] & [`MultiStreamBuilder::from_config`].
+///
+/// eg/ `{"subscriptions": [{"exchange": "okx", "base": "btc", "quote": "usdt",
+/// "instrument_kind": "spot", "kind": "PublicTrades"}]}`
+#[derive(Clone, Eq, PartialEq, Debug, Default, Deserialize, Serialize)]
+pub struct StreamConfig {
+    pub subscriptions: Vec<SubscriptionConfig>,
+}
+
+/// Single `(exchange, instrument, kind)` entry of a [`StreamConfig`].
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct SubscriptionConfig {
+    pub exchange: ExchangeId,
+    pub base: Symbol,
+    pub quote: Symbol,
+    pub instrument_kind: InstrumentKind,
+    pub kind: SubKind,
+}
+
+impl SubscriptionConfig {
+    /// Construct the [`Instrument`] associated with this entry.
+    pub fn instrument(&self) -> Instrument {
+        Instrument::from((self.base.clone(), self.quote.clone(), self.instrument_kind))
+    }
+}
+
+/// Registry of the statically typed exchanges that support a [`SubscriptionKind`], used to
+/// dispatch runtime [`StreamConfig`] entries to the appropriate [`StreamBuilder::subscribe`] call.
+pub trait ConfigRegistry: SubscriptionKind + Sized {
+    /// Add the provided exchange [`Instrument`]s to the [`StreamBuilder`] via a statically typed
+    /// [`StreamBuilder::subscribe`] call, returning [`DataError::Unsupported`] if the exchange is
+    /// not registered for this [`SubscriptionKind`].
+    fn subscribe(
+        builder: StreamBuilder<Self>,
+        exchange: ExchangeId,
+        instruments: Vec<Instrument>,
+    ) -> Result<StreamBuilder<Self>, DataError>;
+}
+
+/// Implement [`ConfigRegistry`] for a [`SubscriptionKind`] (constructed via the provided value),
+/// registering each provided `ExchangeId => Exchange` pair.
+macro_rules! impl_config_registry {
+    ($kind:ident = $value:expr, [$($exchange_id:ident => $exchange:ty),* $(,)?]) => {
+        impl ConfigRegistry for $kind {
+            fn subscribe(
+                builder: StreamBuilder<Self>,
+                exchange: ExchangeId,
+                instruments: Vec<Instrument>,
+            ) -> Result<StreamBuilder<Self>, DataError> {
+                match exchange {
+                    $(
+                        ExchangeId::$exchange_id => Ok(builder.subscribe(
+                            instruments.into_iter().map(|instrument| {
+                                Subscription::<$exchange, Instrument, $kind>::new(
+                                    <$exchange>::default(),
+                                    instrument,
+                                    $value,
+                                )
+                            }),
+                        )),
+                    )*
+                    _ => Err(DataError::Unsupported {
+                        exchange,
+                        sub_kind: SubKind::$kind,
+                    }),
+                }
+            }
+        }
+    };
+}
+
+impl_config_registry!(PublicTrades = PublicTrades, [
+    BinanceSpot => BinanceSpot,
+    BinanceFuturesUsd => BinanceFuturesUsd,
+    Bitfinex => Bitfinex,
+    Bitmex => Bitmex,
+    BybitSpot => BybitSpot,
+    BybitPerpetualsUsd => BybitPerpetualsUsd,
+    Coinbase => Coinbase,
+    Deribit => Deribit,
+    GateioSpot => GateioSpot,
+    GateioFuturesUsd => GateioFuturesUsd,
+    GateioFuturesBtc => GateioFuturesBtc,
+    GateioPerpetualsUsd => GateioPerpetualsUsd,
+    GateioPerpetualsBtc => GateioPerpetualsBtc,
+    GateioOptions => GateioOptions,
+    Kraken => Kraken,
+    Okx => Okx,
+]);
+
+impl_config_registry!(OrderBooksL1 = OrderBooksL1, [
+    BinanceSpot => BinanceSpot,
+    BinanceFuturesUsd => BinanceFuturesUsd,
+    Bitfinex => Bitfinex,
+    Bitmex => Bitmex,
+    BybitSpot => BybitSpot,
+    BybitPerpetualsUsd => BybitPerpetualsUsd,
+    Coinbase => Coinbase,
+    Deribit => Deribit,
+    GateioSpot => GateioSpot,
+    GateioFuturesUsd => GateioFuturesUsd,
+    Kraken => Kraken,
+    Okx => Okx,
+]);
+
+impl_config_registry!(OrderBooksL2 = OrderBooksL2::default(), [
+    BinanceSpot => BinanceSpot,
+    BinanceFuturesUsd => BinanceFuturesUsd,
+    Bitfinex => Bitfinex,
+    Bitmex => Bitmex,
+    BybitSpot => BybitSpot,
+    BybitPerpetualsUsd => BybitPerpetualsUsd,
+    Coinbase => Coinbase,
+    Deribit => Deribit,
+    GateioSpot => GateioSpot,
+    GateioPerpetualsUsd => GateioPerpetualsUsd,
+    GateioPerpetualsBtc => GateioPerpetualsBtc,
+    Kraken => Kraken,
+    Kucoin => Kucoin,
+    Okx => Okx,
+]);
+
+impl_config_registry!(Liquidations = Liquidations, [
+    BinanceFuturesUsd => BinanceFuturesUsd,
+    BybitPerpetualsUsd => BybitPerpetualsUsd,
+]);
+
+impl<Kind> StreamBuilder<Kind>
+where
+    Kind: ConfigRegistry,
+{
+    /// Add every [`StreamConfig`] entry of this [`SubscriptionKind`] to the [`StreamBuilder`],
+    /// dispatching each exchange's entries to a statically typed
+    /// [`subscribe()`](StreamBuilder::subscribe()) call (ie/ a distinct connection per exchange).
+    ///
+    /// Entries of any other [`SubKind`] are ignored, see [`MultiStreamBuilder::from_config`] to
+    /// initialise every entry.
+    pub fn subscribe_from_config(self, config: &StreamConfig) -> Result<Self, DataError> {
+        group_by_exchange(config, Kind::ID)
+            .into_iter()
+            .try_fold(self, |builder, (exchange, instruments)| {
+                Kind::subscribe(builder, exchange, instruments)
+            })
+    }
+}
+
+impl<Output> MultiStreamBuilder<Output> {
+    /// Construct a [`MultiStreamBuilder`] from every [`StreamConfig`] entry, adding a
+    /// [`StreamBuilder`] for each configured [`SubKind`] via
+    /// [`StreamBuilder::subscribe_from_config`].
+    ///
+    /// Returns [`DataError::Unsupported`] if an entry's [`SubKind`] or exchange is not registered
+    /// in the [`ConfigRegistry`].
+    pub fn from_config(config: &StreamConfig) -> Result<Self, DataError>
+    where
+        Output: From<MarketEvent<Instrument, PublicTrade>>
+            + From<MarketEvent<Instrument, OrderBookL1>>
+            + From<MarketEvent<Instrument, OrderBook>>
+            + From<MarketEvent<Instrument, Liquidation>>
+            + Send
+            + 'static,
+    {
+        // Determine each configured SubKind, alongside an associated exchange for error reporting
+        let kinds = config
+            .subscriptions
+            .iter()
+            .map(|entry| (entry.kind, entry.exchange))
+            .collect::<BTreeMap<_, _>>();
+
+        let mut builder = Self::new();
+        for (kind, exchange) in kinds {
+            builder =
+                match kind {
+                    SubKind::PublicTrades => builder
+                        .add(StreamBuilder::<PublicTrades>::new().subscribe_from_config(config)?),
+                    SubKind::OrderBooksL1 => builder
+                        .add(StreamBuilder::<OrderBooksL1>::new().subscribe_from_config(config)?),
+                    SubKind::OrderBooksL2 => builder
+                        .add(StreamBuilder::<OrderBooksL2>::new().subscribe_from_config(config)?),
+                    SubKind::Liquidations => builder
+                        .add(StreamBuilder::<Liquidations>::new().subscribe_from_config(config)?),
+                    sub_kind => return Err(DataError::Unsupported { exchange, sub_kind }),
+                };
+        }
+
+        Ok(builder)
+    }
+}
+
+/// Group the [`Instrument`]s of every [`StreamConfig`] entry with the provided [`SubKindId`] by
+/// exchange.
+fn group_by_exchange(
+    config: &StreamConfig,
+    kind: SubKindId,
+) -> BTreeMap<ExchangeId, Vec<Instrument>> {
+    config
+        .subscriptions
+        .iter()
+        .filter(|entry| SubKindId::Kind(entry.kind) == kind)
+        .fold(BTreeMap::new(), |mut grouped, entry| {
+            grouped
+                .entry(entry.exchange)
+                .or_insert_with(Vec::new)
+                .push(entry.instrument());
+            grouped
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::event::DataKind;
+
+    fn config() -> StreamConfig {
+        serde_json::from_str(
+            r#"
+            {
+                "subscriptions": [
+                    {"exchange": "okx", "base": "btc", "quote": "usdt", "instrument_kind": "spot", "kind": "PublicTrades"},
+                    {"exchange": "okx", "base": "eth", "quote": "usdt", "instrument_kind": "spot", "kind": "PublicTrades"},
+                    {"exchange": "binance_spot", "base": "btc", "quote": "usdt", "instrument_kind": "spot", "kind": "OrderBooksL1"},
+                    {"exchange": "binance_futures_usd", "base": "btc", "quote": "usdt", "instrument_kind": "perpetual", "kind": "Liquidations"}
+                ]
+            }
+            "#,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_subscribe_from_config() {
+        let builder = StreamBuilder::<PublicTrades>::new()
+            .subscribe_from_config(&config())
+            .unwrap();
+        assert_eq!(builder.futures.len(), 1);
+        assert!(builder.channels.contains_key(&ExchangeId::Okx));
+
+        // Exchange not registered for the SubscriptionKind
+        let actual = StreamBuilder::<Liquidations>::new().subscribe_from_config(&StreamConfig {
+            subscriptions: vec![SubscriptionConfig {
+                exchange: ExchangeId::Okx,
+                base: Symbol::from("btc"),
+                quote: Symbol::from("usdt"),
+                instrument_kind: InstrumentKind::Perpetual,
+                kind: SubKind::Liquidations,
+            }],
+        });
+        assert!(matches!(
+            actual,
+            Err(DataError::Unsupported {
+                exchange: ExchangeId::Okx,
+                sub_kind: SubKind::Liquidations
+            })
+        ));
+    }
+
+    #[test]
+    fn test_multi_from_config() {
+        let builder =
+            MultiStreamBuilder::<MarketEvent<Instrument, DataKind>>::from_config(&config())
+                .unwrap();
+
+        let mut actual = builder.channels.keys().copied().collect::<Vec<_>>();
+        actual.sort();
+        let mut expected = vec![
+            (ExchangeId::Okx, PublicTrades::ID),
+            (ExchangeId::BinanceSpot, OrderBooksL1::ID),
+            (ExchangeId::BinanceFuturesUsd, Liquidations::ID),
+        ];
+        expected.sort();
+        assert_eq!(actual, expected);
+    }
+}
@@ -25,7 +25,12 @@ use crate::exchange::ExchangeId;
 use crate::instrument::InstrumentData;
 use crate::streams::builder::ExchangeChannel;
 use crate::streams::consumer::consume;
+use crate::streams::health::SubscriptionHealth;
+use crate::streams::hooks::ConsumerHooks;
+use crate::streams::options::StreamOptions;
+use crate::streams::reconnect::ReconnectPolicy;
 use crate::subscription::book::{OrderBook, OrderBookL1, OrderBooksL1};
+use crate::subscription::candle::{Candle, Candles, Interval};
 use crate::subscription::liquidation::{Liquidation, Liquidations};
 use crate::subscription::trade::{PublicTrade, PublicTrades};
 use crate::subscription::{SubKind, Subscription};
@@ -47,6 +52,8 @@ pub struct DynamicStreams<InstrumentId> {
     pub l2s: VecMap<ExchangeId, UnboundedReceiverStream<MarketEvent<InstrumentId, OrderBook>>>,
     pub liquidations:
         VecMap<ExchangeId, UnboundedReceiverStream<MarketEvent<InstrumentId, Liquidation>>>,
+    pub candles: VecMap<ExchangeId, UnboundedReceiverStream<MarketEvent<InstrumentId, Candle>>>,
+    pub health: SubscriptionHealth,
 }
 
 impl<InstrumentId> DynamicStreams<InstrumentId> {
@@ -56,18 +63,25 @@ impl<InstrumentId> DynamicStreams<InstrumentId> {
     /// WebSocket `Stream` under the hood. If the batch contains more-than-one [`ExchangeId`] and/or
     /// [`SubKind`], it will be further split under the hood for compile-time reasons.
     ///
+    /// Every WebSocket `Stream` is initialised using the provided [`StreamOptions`] (eg/ the
+    /// [`Spawner`](crate::runtime::Spawner) used to spawn each consumer loop).
+    ///
+    /// Note that [`SubKind::Candles`] does not carry an [`Interval`], so [`Candles`] are always
+    /// subscribed to with [`Interval::M1`].
+    ///
     /// ## Examples
     /// Please see barter-data-rs/examples/dynamic_multi_stream_multi_exchange.rs for a
     /// comprehensive example of how to use this market data stream initialiser.
     pub async fn init<SubBatchIter, SubIter, Sub, Instrument>(
         subscription_batches: SubBatchIter,
+        options: StreamOptions,
     ) -> Result<Self, DataError>
     where
         SubBatchIter: IntoIterator<Item = SubIter>,
         SubIter: IntoIterator<Item = Sub>,
         Sub: Into<Subscription<ExchangeId, Instrument, SubKind>>,
         Instrument: InstrumentData<Id = InstrumentId> + Ord + 'static,
-        InstrumentId: Clone + Send,
+        InstrumentId: Clone + Eq + std::hash::Hash + Send + Sync + 'static,
         Subscription<BinanceSpot, Instrument, PublicTrades>: Identifier<BinanceMarket>,
         Subscription<BinanceSpot, Instrument, PublicTrades>: Identifier<BinanceMarket>,
         Subscription<BinanceSpot, Instrument, OrderBooksL1>: Identifier<BinanceMarket>,
@@ -78,9 +92,12 @@ impl<InstrumentId> DynamicStreams<InstrumentId> {
         Subscription<Bitmex, Instrument, PublicTrades>: Identifier<BitmexMarket>,
         Subscription<BybitSpot, Instrument, PublicTrades>: Identifier<BybitMarket>,
         Subscription<BybitPerpetualsUsd, Instrument, PublicTrades>: Identifier<BybitMarket>,
+        Subscription<BybitPerpetualsUsd, Instrument, Liquidations>: Identifier<BybitMarket>,
         Subscription<Coinbase, Instrument, PublicTrades>: Identifier<CoinbaseMarket>,
         Subscription<GateioSpot, Instrument, PublicTrades>: Identifier<GateioMarket>,
+        Subscription<GateioSpot, Instrument, Candles>: Identifier<GateioMarket>,
         Subscription<GateioFuturesUsd, Instrument, PublicTrades>: Identifier<GateioMarket>,
+        Subscription<GateioFuturesUsd, Instrument, Candles>: Identifier<GateioMarket>,
         Subscription<GateioFuturesBtc, Instrument, PublicTrades>: Identifier<GateioMarket>,
         Subscription<GateioPerpetualsUsd, Instrument, PublicTrades>: Identifier<GateioMarket>,
         Subscription<GateioPerpetualsBtc, Instrument, PublicTrades>: Identifier<GateioMarket>,
@@ -93,6 +110,8 @@ impl<InstrumentId> DynamicStreams<InstrumentId> {
         let batches = validate_batches(subscription_batches)?;
 
         let mut channels = Channels::<Instrument::Id>::default();
+        let health = SubscriptionHealth::default();
+        let reconnect_policy = ReconnectPolicy::default();
 
         for mut batch in batches {
             batch.sort_unstable_by_key(|sub| (sub.exchange, sub.kind));
@@ -102,180 +121,333 @@ impl<InstrumentId> DynamicStreams<InstrumentId> {
             for ((exchange, sub_kind), subs) in by_exchange_by_sub_kind.into_iter() {
                 match (exchange, sub_kind) {
                     (ExchangeId::BinanceSpot, SubKind::PublicTrades) => {
-                        tokio::spawn(consume::<BinanceSpot, Instrument, PublicTrades>(
-                            subs.into_iter()
-                                .map(|sub| {
-                                    Subscription::new(
-                                        BinanceSpot::default(),
-                                        sub.instrument,
-                                        PublicTrades,
-                                    )
-                                })
-                                .collect(),
-                            channels.trades.entry(exchange).or_default().tx.clone(),
-                        ));
+                        options
+                            .spawner
+                            .spawn(consume::<BinanceSpot, Instrument, PublicTrades>(
+                                subs.into_iter()
+                                    .map(|sub| {
+                                        Subscription::new(
+                                            BinanceSpot::default(),
+                                            sub.instrument,
+                                            PublicTrades,
+                                        )
+                                    })
+                                    .collect(),
+                                channels.trades.entry(exchange).or_default().tx.clone(),
+                                health.clone(),
+                                reconnect_policy,
+                                options.clone(),
+                                ConsumerHooks::default(),
+                            ));
                     }
                     (ExchangeId::BinanceSpot, SubKind::OrderBooksL1) => {
-                        tokio::spawn(consume::<BinanceSpot, Instrument, OrderBooksL1>(
-                            subs.into_iter()
-                                .map(|sub| {
-                                    Subscription::new(
-                                        BinanceSpot::default(),
-                                        sub.instrument,
-                                        OrderBooksL1,
-                                    )
-                                })
-                                .collect(),
-                            channels.l1s.entry(exchange).or_default().tx.clone(),
-                        ));
+                        options
+                            .spawner
+                            .spawn(consume::<BinanceSpot, Instrument, OrderBooksL1>(
+                                subs.into_iter()
+                                    .map(|sub| {
+                                        Subscription::new(
+                                            BinanceSpot::default(),
+                                            sub.instrument,
+                                            OrderBooksL1,
+                                        )
+                                    })
+                                    .collect(),
+                                channels.l1s.entry(exchange).or_default().tx.clone(),
+                                health.clone(),
+                                reconnect_policy,
+                                options.clone(),
+                                ConsumerHooks::default(),
+                            ));
                     }
                     (ExchangeId::BinanceFuturesUsd, SubKind::PublicTrades) => {
-                        tokio::spawn(consume::<BinanceFuturesUsd, Instrument, PublicTrades>(
-                            subs.into_iter()
-                                .map(|sub| {
-                                    Subscription::new(
-                                        BinanceFuturesUsd::default(),
-                                        sub.instrument,
-                                        PublicTrades,
-                                    )
-                                })
-                                .collect(),
-                            channels.trades.entry(exchange).or_default().tx.clone(),
-                        ));
+                        options.spawner.spawn(
+                            consume::<BinanceFuturesUsd, Instrument, PublicTrades>(
+                                subs.into_iter()
+                                    .map(|sub| {
+                                        Subscription::new(
+                                            BinanceFuturesUsd::default(),
+                                            sub.instrument,
+                                            PublicTrades,
+                                        )
+                                    })
+                                    .collect(),
+                                channels.trades.entry(exchange).or_default().tx.clone(),
+                                health.clone(),
+                                reconnect_policy,
+                                options.clone(),
+                                ConsumerHooks::default(),
+                            ),
+                        );
                     }
                     (ExchangeId::BinanceFuturesUsd, SubKind::OrderBooksL1) => {
-                        tokio::spawn(consume::<BinanceFuturesUsd, Instrument, OrderBooksL1>(
-                            subs.into_iter()
-                                .map(|sub| {
-                                    Subscription::<_, Instrument, _>::new(
-                                        BinanceFuturesUsd::default(),
-                                        sub.instrument,
-                                        OrderBooksL1,
-                                    )
-                                })
-                                .collect(),
-                            channels.l1s.entry(exchange).or_default().tx.clone(),
-                        ));
+                        options.spawner.spawn(
+                            consume::<BinanceFuturesUsd, Instrument, OrderBooksL1>(
+                                subs.into_iter()
+                                    .map(|sub| {
+                                        Subscription::<_, Instrument, _>::new(
+                                            BinanceFuturesUsd::default(),
+                                            sub.instrument,
+                                            OrderBooksL1,
+                                        )
+                                    })
+                                    .collect(),
+                                channels.l1s.entry(exchange).or_default().tx.clone(),
+                                health.clone(),
+                                reconnect_policy,
+                                options.clone(),
+                                ConsumerHooks::default(),
+                            ),
+                        );
                     }
                     (ExchangeId::BinanceFuturesUsd, SubKind::Liquidations) => {
-                        tokio::spawn(consume::<BinanceFuturesUsd, Instrument, Liquidations>(
-                            subs.into_iter()
-                                .map(|sub| {
-                                    Subscription::<_, Instrument, _>::new(
-                                        BinanceFuturesUsd::default(),
-                                        sub.instrument,
-                                        Liquidations,
-                                    )
-                                })
-                                .collect(),
-                            channels
-                                .liquidations
-                                .entry(exchange)
-                                .or_default()
-                                .tx
-                                .clone(),
-                        ));
+                        options.spawner.spawn(
+                            consume::<BinanceFuturesUsd, Instrument, Liquidations>(
+                                subs.into_iter()
+                                    .map(|sub| {
+                                        Subscription::<_, Instrument, _>::new(
+                                            BinanceFuturesUsd::default(),
+                                            sub.instrument,
+                                            Liquidations,
+                                        )
+                                    })
+                                    .collect(),
+                                channels
+                                    .liquidations
+                                    .entry(exchange)
+                                    .or_default()
+                                    .tx
+                                    .clone(),
+                                health.clone(),
+                                reconnect_policy,
+                                options.clone(),
+                                ConsumerHooks::default(),
+                            ),
+                        );
                     }
                     (ExchangeId::Bitfinex, SubKind::PublicTrades) => {
-                        tokio::spawn(consume::<Bitfinex, Instrument, PublicTrades>(
-                            subs.into_iter()
-                                .map(|sub| {
-                                    Subscription::new(Bitfinex, sub.instrument, PublicTrades)
-                                })
-                                .collect(),
-                            channels.trades.entry(exchange).or_default().tx.clone(),
-                        ));
+                        options
+                            .spawner
+                            .spawn(consume::<Bitfinex, Instrument, PublicTrades>(
+                                subs.into_iter()
+                                    .map(|sub| {
+                                        Subscription::new(Bitfinex, sub.instrument, PublicTrades)
+                                    })
+                                    .collect(),
+                                channels.trades.entry(exchange).or_default().tx.clone(),
+                                health.clone(),
+                                reconnect_policy,
+                                options.clone(),
+                                ConsumerHooks::default(),
+                            ));
                     }
                     (ExchangeId::Bitmex, SubKind::PublicTrades) => {
-                        tokio::spawn(consume::<Bitmex, Instrument, PublicTrades>(
-                            subs.into_iter()
-                                .map(|sub| Subscription::new(Bitmex, sub.instrument, PublicTrades))
-                                .collect(),
-                            channels.trades.entry(exchange).or_default().tx.clone(),
-                        ));
+                        options
+                            .spawner
+                            .spawn(consume::<Bitmex, Instrument, PublicTrades>(
+                                subs.into_iter()
+                                    .map(|sub| {
+                                        Subscription::new(Bitmex, sub.instrument, PublicTrades)
+                                    })
+                                    .collect(),
+                                channels.trades.entry(exchange).or_default().tx.clone(),
+                                health.clone(),
+                                reconnect_policy,
+                                options.clone(),
+                                ConsumerHooks::default(),
+                            ));
                     }
                     (ExchangeId::BybitSpot, SubKind::PublicTrades) => {
-                        tokio::spawn(consume::<BybitSpot, Instrument, PublicTrades>(
+                        options
+                            .spawner
+                            .spawn(consume::<BybitSpot, Instrument, PublicTrades>(
+                                subs.into_iter()
+                                    .map(|sub| {
+                                        Subscription::new(
+                                            BybitSpot::default(),
+                                            sub.instrument,
+                                            PublicTrades,
+                                        )
+                                    })
+                                    .collect(),
+                                channels.trades.entry(exchange).or_default().tx.clone(),
+                                health.clone(),
+                                reconnect_policy,
+                                options.clone(),
+                                ConsumerHooks::default(),
+                            ));
+                    }
+                    (ExchangeId::BybitPerpetualsUsd, SubKind::PublicTrades) => {
+                        options.spawner.spawn(consume::<
+                            BybitPerpetualsUsd,
+                            Instrument,
+                            PublicTrades,
+                        >(
                             subs.into_iter()
                                 .map(|sub| {
                                     Subscription::new(
-                                        BybitSpot::default(),
+                                        BybitPerpetualsUsd::default(),
                                         sub.instrument,
                                         PublicTrades,
                                     )
                                 })
                                 .collect(),
                             channels.trades.entry(exchange).or_default().tx.clone(),
+                            health.clone(),
+                            reconnect_policy,
+                            options.clone(),
+                            ConsumerHooks::default(),
                         ));
                     }
-                    (ExchangeId::BybitPerpetualsUsd, SubKind::PublicTrades) => {
-                        tokio::spawn(consume::<BybitPerpetualsUsd, Instrument, PublicTrades>(
+                    (ExchangeId::BybitPerpetualsUsd, SubKind::Liquidations) => {
+                        options.spawner.spawn(consume::<
+                            BybitPerpetualsUsd,
+                            Instrument,
+                            Liquidations,
+                        >(
                             subs.into_iter()
                                 .map(|sub| {
                                     Subscription::new(
                                         BybitPerpetualsUsd::default(),
                                         sub.instrument,
-                                        PublicTrades,
+                                        Liquidations,
                                     )
                                 })
                                 .collect(),
-                            channels.trades.entry(exchange).or_default().tx.clone(),
+                            channels
+                                .liquidations
+                                .entry(exchange)
+                                .or_default()
+                                .tx
+                                .clone(),
+                            health.clone(),
+                            reconnect_policy,
+                            options.clone(),
+                            ConsumerHooks::default(),
                         ));
                     }
                     (ExchangeId::Coinbase, SubKind::PublicTrades) => {
-                        tokio::spawn(consume::<Coinbase, Instrument, PublicTrades>(
-                            subs.into_iter()
-                                .map(|sub| {
-                                    Subscription::new(Coinbase, sub.instrument, PublicTrades)
-                                })
-                                .collect(),
-                            channels.trades.entry(exchange).or_default().tx.clone(),
-                        ));
+                        options
+                            .spawner
+                            .spawn(consume::<Coinbase, Instrument, PublicTrades>(
+                                subs.into_iter()
+                                    .map(|sub| {
+                                        Subscription::new(Coinbase, sub.instrument, PublicTrades)
+                                    })
+                                    .collect(),
+                                channels.trades.entry(exchange).or_default().tx.clone(),
+                                health.clone(),
+                                reconnect_policy,
+                                options.clone(),
+                                ConsumerHooks::default(),
+                            ));
                     }
                     (ExchangeId::GateioSpot, SubKind::PublicTrades) => {
-                        tokio::spawn(consume::<GateioSpot, Instrument, PublicTrades>(
-                            subs.into_iter()
-                                .map(|sub| {
-                                    Subscription::new(
-                                        GateioSpot::default(),
-                                        sub.instrument,
-                                        PublicTrades,
-                                    )
-                                })
-                                .collect(),
-                            channels.trades.entry(exchange).or_default().tx.clone(),
-                        ));
+                        options
+                            .spawner
+                            .spawn(consume::<GateioSpot, Instrument, PublicTrades>(
+                                subs.into_iter()
+                                    .map(|sub| {
+                                        Subscription::new(
+                                            GateioSpot::default(),
+                                            sub.instrument,
+                                            PublicTrades,
+                                        )
+                                    })
+                                    .collect(),
+                                channels.trades.entry(exchange).or_default().tx.clone(),
+                                health.clone(),
+                                reconnect_policy,
+                                options.clone(),
+                                ConsumerHooks::default(),
+                            ));
+                    }
+                    (ExchangeId::GateioSpot, SubKind::Candles) => {
+                        options
+                            .spawner
+                            .spawn(consume::<GateioSpot, Instrument, Candles>(
+                                subs.into_iter()
+                                    .map(|sub| {
+                                        Subscription::new(
+                                            GateioSpot::default(),
+                                            sub.instrument,
+                                            Candles(Interval::M1),
+                                        )
+                                    })
+                                    .collect(),
+                                channels.candles.entry(exchange).or_default().tx.clone(),
+                                health.clone(),
+                                reconnect_policy,
+                                options.clone(),
+                                ConsumerHooks::default(),
+                            ));
                     }
                     (ExchangeId::GateioFuturesUsd, SubKind::PublicTrades) => {
-                        tokio::spawn(consume::<GateioFuturesUsd, Instrument, PublicTrades>(
-                            subs.into_iter()
-                                .map(|sub| {
-                                    Subscription::new(
-                                        GateioFuturesUsd::default(),
-                                        sub.instrument,
-                                        PublicTrades,
-                                    )
-                                })
-                                .collect(),
-                            channels.trades.entry(exchange).or_default().tx.clone(),
-                        ));
+                        options.spawner.spawn(
+                            consume::<GateioFuturesUsd, Instrument, PublicTrades>(
+                                subs.into_iter()
+                                    .map(|sub| {
+                                        Subscription::new(
+                                            GateioFuturesUsd::default(),
+                                            sub.instrument,
+                                            PublicTrades,
+                                        )
+                                    })
+                                    .collect(),
+                                channels.trades.entry(exchange).or_default().tx.clone(),
+                                health.clone(),
+                                reconnect_policy,
+                                options.clone(),
+                                ConsumerHooks::default(),
+                            ),
+                        );
+                    }
+                    (ExchangeId::GateioFuturesUsd, SubKind::Candles) => {
+                        options
+                            .spawner
+                            .spawn(consume::<GateioFuturesUsd, Instrument, Candles>(
+                                subs.into_iter()
+                                    .map(|sub| {
+                                        Subscription::new(
+                                            GateioFuturesUsd::default(),
+                                            sub.instrument,
+                                            Candles(Interval::M1),
+                                        )
+                                    })
+                                    .collect(),
+                                channels.candles.entry(exchange).or_default().tx.clone(),
+                                health.clone(),
+                                reconnect_policy,
+                                options.clone(),
+                                ConsumerHooks::default(),
+                            ));
                     }
                     (ExchangeId::GateioFuturesBtc, SubKind::PublicTrades) => {
-                        tokio::spawn(consume::<GateioFuturesBtc, Instrument, PublicTrades>(
-                            subs.into_iter()
-                                .map(|sub| {
-                                    Subscription::new(
-                                        GateioFuturesBtc::default(),
-                                        sub.instrument,
-                                        PublicTrades,
-                                    )
-                                })
-                                .collect(),
-                            channels.trades.entry(exchange).or_default().tx.clone(),
-                        ));
+                        options.spawner.spawn(
+                            consume::<GateioFuturesBtc, Instrument, PublicTrades>(
+                                subs.into_iter()
+                                    .map(|sub| {
+                                        Subscription::new(
+                                            GateioFuturesBtc::default(),
+                                            sub.instrument,
+                                            PublicTrades,
+                                        )
+                                    })
+                                    .collect(),
+                                channels.trades.entry(exchange).or_default().tx.clone(),
+                                health.clone(),
+                                reconnect_policy,
+                                options.clone(),
+                                ConsumerHooks::default(),
+                            ),
+                        );
                     }
                     (ExchangeId::GateioPerpetualsUsd, SubKind::PublicTrades) => {
-                        tokio::spawn(consume::<GateioPerpetualsUsd, Instrument, PublicTrades>(
+                        options.spawner.spawn(consume::<
+                            GateioPerpetualsUsd,
+                            Instrument,
+                            PublicTrades,
+                        >(
                             subs.into_iter()
                                 .map(|sub| {
                                     Subscription::new(
@@ -286,10 +458,18 @@ impl<InstrumentId> DynamicStreams<InstrumentId> {
                                 })
                                 .collect(),
                             channels.trades.entry(exchange).or_default().tx.clone(),
+                            health.clone(),
+                            reconnect_policy,
+                            options.clone(),
+                            ConsumerHooks::default(),
                         ));
                     }
                     (ExchangeId::GateioPerpetualsBtc, SubKind::PublicTrades) => {
-                        tokio::spawn(consume::<GateioPerpetualsBtc, Instrument, PublicTrades>(
+                        options.spawner.spawn(consume::<
+                            GateioPerpetualsBtc,
+                            Instrument,
+                            PublicTrades,
+                        >(
                             subs.into_iter()
                                 .map(|sub| {
                                     Subscription::new(
@@ -300,45 +480,77 @@ impl<InstrumentId> DynamicStreams<InstrumentId> {
                                 })
                                 .collect(),
                             channels.trades.entry(exchange).or_default().tx.clone(),
+                            health.clone(),
+                            reconnect_policy,
+                            options.clone(),
+                            ConsumerHooks::default(),
                         ));
                     }
                     (ExchangeId::GateioOptions, SubKind::PublicTrades) => {
-                        tokio::spawn(consume::<GateioOptions, Instrument, PublicTrades>(
-                            subs.into_iter()
-                                .map(|sub| {
-                                    Subscription::new(
-                                        GateioOptions::default(),
-                                        sub.instrument,
-                                        PublicTrades,
-                                    )
-                                })
-                                .collect(),
-                            channels.trades.entry(exchange).or_default().tx.clone(),
-                        ));
+                        options
+                            .spawner
+                            .spawn(consume::<GateioOptions, Instrument, PublicTrades>(
+                                subs.into_iter()
+                                    .map(|sub| {
+                                        Subscription::new(
+                                            GateioOptions::default(),
+                                            sub.instrument,
+                                            PublicTrades,
+                                        )
+                                    })
+                                    .collect(),
+                                channels.trades.entry(exchange).or_default().tx.clone(),
+                                health.clone(),
+                                reconnect_policy,
+                                options.clone(),
+                                ConsumerHooks::default(),
+                            ));
                     }
                     (ExchangeId::Kraken, SubKind::PublicTrades) => {
-                        tokio::spawn(consume::<Kraken, Instrument, PublicTrades>(
-                            subs.into_iter()
-                                .map(|sub| Subscription::new(Kraken, sub.instrument, PublicTrades))
-                                .collect(),
-                            channels.trades.entry(exchange).or_default().tx.clone(),
-                        ));
+                        options
+                            .spawner
+                            .spawn(consume::<Kraken, Instrument, PublicTrades>(
+                                subs.into_iter()
+                                    .map(|sub| {
+                                        Subscription::new(Kraken, sub.instrument, PublicTrades)
+                                    })
+                                    .collect(),
+                                channels.trades.entry(exchange).or_default().tx.clone(),
+                                health.clone(),
+                                reconnect_policy,
+                                options.clone(),
+                                ConsumerHooks::default(),
+                            ));
                     }
                     (ExchangeId::Kraken, SubKind::OrderBooksL1) => {
-                        tokio::spawn(consume::<Kraken, Instrument, OrderBooksL1>(
-                            subs.into_iter()
-                                .map(|sub| Subscription::new(Kraken, sub.instrument, OrderBooksL1))
-                                .collect(),
-                            channels.l1s.entry(exchange).or_default().tx.clone(),
-                        ));
+                        options
+                            .spawner
+                            .spawn(consume::<Kraken, Instrument, OrderBooksL1>(
+                                subs.into_iter()
+                                    .map(|sub| {
+                                        Subscription::new(Kraken, sub.instrument, OrderBooksL1)
+                                    })
+                                    .collect(),
+                                channels.l1s.entry(exchange).or_default().tx.clone(),
+                                health.clone(),
+                                reconnect_policy,
+                                options.clone(),
+                                ConsumerHooks::default(),
+                            ));
                     }
                     (ExchangeId::Okx, SubKind::PublicTrades) => {
-                        tokio::spawn(consume::<Okx, Instrument, PublicTrades>(
-                            subs.into_iter()
-                                .map(|sub| Subscription::new(Okx, sub.instrument, PublicTrades))
-                                .collect(),
-                            channels.trades.entry(exchange).or_default().tx.clone(),
-                        ));
+                        options
+                            .spawner
+                            .spawn(consume::<Okx, Instrument, PublicTrades>(
+                                subs.into_iter()
+                                    .map(|sub| Subscription::new(Okx, sub.instrument, PublicTrades))
+                                    .collect(),
+                                channels.trades.entry(exchange).or_default().tx.clone(),
+                                health.clone(),
+                                reconnect_policy,
+                                options.clone(),
+                                ConsumerHooks::default(),
+                            ));
                     }
                     (exchange, sub_kind) => {
                         return Err(DataError::Unsupported { exchange, sub_kind })
@@ -368,6 +580,12 @@ impl<InstrumentId> DynamicStreams<InstrumentId> {
                 .into_iter()
                 .map(|(exchange, channel)| (exchange, UnboundedReceiverStream::new(channel.rx)))
                 .collect(),
+            candles: channels
+                .candles
+                .into_iter()
+                .map(|(exchange, channel)| (exchange, UnboundedReceiverStream::new(channel.rx)))
+                .collect(),
+            health,
         })
     }
 
@@ -443,6 +661,24 @@ impl<InstrumentId> DynamicStreams<InstrumentId> {
         select_all(std::mem::take(&mut self.liquidations).into_values())
     }
 
+    /// Remove an exchange [`Candle`] `Stream` from the [`DynamicStreams`] collection.
+    ///
+    /// Note that calling this method will permanently remove this `Stream` from [`Self`].
+    pub fn select_candles(
+        &mut self,
+        exchange: ExchangeId,
+    ) -> Option<UnboundedReceiverStream<MarketEvent<InstrumentId, Candle>>> {
+        self.candles.remove(&exchange)
+    }
+
+    /// Select and merge every exchange [`Candle`] `Stream` using
+    /// [`SelectAll`](futures_util::stream::select_all).
+    pub fn select_all_candles(
+        &mut self,
+    ) -> SelectAll<UnboundedReceiverStream<MarketEvent<InstrumentId, Candle>>> {
+        select_all(std::mem::take(&mut self.candles).into_values())
+    }
+
     /// Select and merge every exchange `Stream` for every data type using
     /// [`SelectAll`](futures_util::stream::select_all).
     ///
@@ -456,12 +692,15 @@ impl<InstrumentId> DynamicStreams<InstrumentId> {
         MarketEvent<InstrumentId, OrderBookL1>: Into<Output>,
         MarketEvent<InstrumentId, OrderBook>: Into<Output>,
         MarketEvent<InstrumentId, Liquidation>: Into<Output>,
+        MarketEvent<InstrumentId, Candle>: Into<Output>,
     {
         let Self {
             trades,
             l1s,
             l2s,
             liquidations,
+            candles,
+            ..
         } = self;
 
         let trades = trades
@@ -480,7 +719,15 @@ impl<InstrumentId> DynamicStreams<InstrumentId> {
             .into_values()
             .map(|stream| stream.map(MarketEvent::into).boxed());
 
-        let all = trades.chain(l1s).chain(l2s).chain(liquidations);
+        let candles = candles
+            .into_values()
+            .map(|stream| stream.map(MarketEvent::into).boxed());
+
+        let all = trades
+            .chain(l1s)
+            .chain(l2s)
+            .chain(liquidations)
+            .chain(candles);
 
         select_all(all)
     }
@@ -519,6 +766,7 @@ struct Channels<InstrumentId> {
     l1s: HashMap<ExchangeId, ExchangeChannel<MarketEvent<InstrumentId, OrderBookL1>>>,
     l2s: HashMap<ExchangeId, ExchangeChannel<MarketEvent<InstrumentId, OrderBook>>>,
     liquidations: HashMap<ExchangeId, ExchangeChannel<MarketEvent<InstrumentId, Liquidation>>>,
+    candles: HashMap<ExchangeId, ExchangeChannel<MarketEvent<InstrumentId, Candle>>>,
 }
 
 impl<InstrumentId> Default for Channels<InstrumentId> {
@@ -528,6 +776,7 @@ impl<InstrumentId> Default for Channels<InstrumentId> {
             l1s: Default::default(),
             l2s: Default::default(),
             liquidations: Default::default(),
+            candles: Default::default(),
         }
     }
 }
@@ -1,27 +1,74 @@
-use super::{consumer::consume, Streams};
-use crate::exchange::Connector;
+use self::priority::{forward_prioritised, Lane};
+use super::{
+    consumer::{consume, consume_with_standby},
+    health::{key, SubscriptionHealth, SubscriptionKey},
+    hooks::ConsumerHooks,
+    options::StreamOptions,
+    reconnect::ReconnectPolicy,
+    Streams,
+};
+use crate::{discovery::InstrumentDiscovery, exchange::Connector};
 use crate::{
     error::DataError,
     event::MarketEvent,
     exchange::{ExchangeId, StreamSelector},
-    subscription::{Subscription, SubscriptionKind},
+    runtime::Spawner,
+    subscriber::{pacing::RequestRate, validator::SubscriptionFailurePolicy},
+    subscription::{book::OrderBooksL2, DataKinds, SubKindId, Subscription, SubscriptionKind},
+    transformer::book::audit::AuditConfig,
     Identifier,
 };
 use barter_integration::model::instrument::Instrument;
 use barter_integration::{error::SocketError, Validator};
-use std::{collections::HashMap, fmt::Debug, future::Future, pin::Pin};
-use tokio::sync::mpsc;
+use futures::{future::BoxFuture, FutureExt};
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::Debug,
+    future::Future,
+    pin::Pin,
+    sync::Arc,
+    time::Duration,
+};
+use tokio::{sync::mpsc, time::Instant};
+use tracing::warn;
 
 /// Defines the [`MultiStreamBuilder`](multi::MultiStreamBuilder) API for ergonomically
 /// initialising a common [`Streams<Output>`](Streams) from multiple
 /// [`StreamBuilder<SubscriptionKind>`](StreamBuilder)s.
 pub mod multi;
 
+/// Deserialisable [`StreamConfig`](config::StreamConfig) & the
+/// [`ConfigRegistry`](config::ConfigRegistry) used to dispatch its entries to statically typed
+/// [`StreamBuilder::subscribe`] calls.
+pub mod config;
+
 pub mod dynamic;
 
+/// [`Lane`](priority::Lane)s used to prioritise the delivery of trades or OrderBook events
+/// consumed from a shared [`DataKinds`] connection.
+pub mod priority;
+
 /// Communicative type alias representing the [`Future`] result of a [`Subscription`] [`validate`]
-/// call generated whilst executing [`StreamBuilder::subscribe`].
-pub type SubscribeFuture = Pin<Box<dyn Future<Output = Result<(), DataError>>>>;
+/// call generated whilst executing [`StreamBuilder::subscribe`], yielding the un-spawned
+/// [`StreamTask`]s that drive the validated [`Subscription`]s.
+pub type SubscribeFuture = Pin<Box<dyn Future<Output = Result<Vec<StreamTask>, DataError>>>>;
+
+/// Communicative type alias representing a long running task (eg/ a [`consume`] loop) that must
+/// be driven for a [`Streams`] to yield events.
+///
+/// See [`StreamBuilder::build`].
+pub type StreamTask = BoxFuture<'static, ()>;
+
+/// Communicative type alias representing a function applied to every
+/// [`SubscriptionKind::Event`] before it is distributed to the [`Streams`].
+pub type EventMap<Event> = Arc<dyn Fn(Event) -> Event + Send + Sync>;
+
+/// Communicative type alias representing a function that determines if a
+/// [`SubscriptionKind::Event`] should be distributed via the priority lane.
+///
+/// See [`forward_prioritised`].
+pub type EventPriority<Event> = Arc<dyn Fn(&Event) -> bool + Send + Sync>;
 
 /// Builder to configure and initialise a [`Streams<MarketEvent<SubscriptionKind::Event>`](Streams) instance
 /// for a specific [`SubscriptionKind`].
@@ -31,7 +78,17 @@ where
     Kind: SubscriptionKind,
 {
     pub channels: HashMap<ExchangeId, ExchangeChannel<MarketEvent<Instrument, Kind::Event>>>,
-    pub futures: Vec<SubscribeFuture>,
+    pub futures: Vec<(ExchangeId, SubscribeFuture)>,
+    pub health: SubscriptionHealth,
+    pub discovery: InstrumentDiscovery,
+    event_map: Option<EventMap<Kind::Event>>,
+    conflation: Option<Duration>,
+    priority: Option<EventPriority<Kind::Event>>,
+    reconnect_policy: ReconnectPolicy,
+    options: StreamOptions,
+    hooks: ConsumerHooks<MarketEvent<Instrument, Kind::Event>>,
+    subscribed: HashSet<SubscriptionKey>,
+    duplicate_policy: DuplicatePolicy,
 }
 
 impl<Kind> Debug for StreamBuilder<Kind>
@@ -42,6 +99,14 @@ where
         f.debug_struct("StreamBuilder<SubscriptionKind>")
             .field("channels", &self.channels)
             .field("num_futures", &self.futures.len())
+            .field("event_map", &self.event_map.is_some())
+            .field("conflation", &self.conflation)
+            .field("priority", &self.priority.is_some())
+            .field("reconnect_policy", &self.reconnect_policy)
+            .field("options", &self.options)
+            .field("hooks", &self.hooks)
+            .field("subscribed", &self.subscribed)
+            .field("duplicate_policy", &self.duplicate_policy)
             .finish()
     }
 }
@@ -55,15 +120,174 @@ where
         Self {
             channels: HashMap::new(),
             futures: Vec::new(),
+            health: SubscriptionHealth::default(),
+            discovery: InstrumentDiscovery::default(),
+            event_map: None,
+            conflation: None,
+            priority: None,
+            reconnect_policy: ReconnectPolicy::default(),
+            options: StreamOptions::default(),
+            hooks: ConsumerHooks::default(),
+            subscribed: HashSet::new(),
+            duplicate_policy: DuplicatePolicy::default(),
+        }
+    }
+
+    /// Re-initialise disconnected [`MarketStream`](crate::MarketStream)s using the provided
+    /// [`ReconnectPolicy`], rather than the default policy.
+    ///
+    /// Applies to [`Subscription`]s added after this method is invoked.
+    pub fn with_reconnect_policy(self, reconnect_policy: ReconnectPolicy) -> Self {
+        Self {
+            reconnect_policy,
+            ..self
+        }
+    }
+
+    /// Handle exchange rejections of some [`Subscription`]s using the provided
+    /// [`SubscriptionFailurePolicy`], rather than the default
+    /// [`SubscriptionFailurePolicy::FailFast`].
+    ///
+    /// Applies to [`Subscription`]s added after this method is invoked.
+    pub fn with_failure_policy(mut self, failure_policy: SubscriptionFailurePolicy) -> Self {
+        self.options.failure_policy = failure_policy;
+        self
+    }
+
+    /// Periodically audit every maintained OrderBook against a fresh exchange snapshot using the
+    /// provided [`AuditConfig`], rather than leaving the integrity audit disabled.
+    ///
+    /// Applies to [`Subscription`]s added after this method is invoked.
+    pub fn with_audit_config(mut self, config: AuditConfig) -> Self {
+        self.options.audit = Some(config);
+        self
+    }
+
+    /// Pace subscription requests to the provided exchange using the provided [`RequestRate`]
+    /// (or disable pacing with `None`), rather than the default [`Connector::request_rate`].
+    ///
+    /// Applies to [`Subscription`]s added after this method is invoked.
+    pub fn with_request_rate(mut self, exchange: ExchangeId, rate: Option<RequestRate>) -> Self {
+        self.options.request_rates.insert(exchange, rate);
+        self
+    }
+
+    /// End & re-connect any [`MarketStream`](crate::MarketStream) that receives no message from
+    /// the exchange server within the provided idle timeout, catching half-open connections that
+    /// would otherwise hang forever.
+    ///
+    /// Applies to [`Subscription`]s added after this method is invoked.
+    pub fn with_idle_timeout(mut self, timeout: Duration) -> Self {
+        self.options.idle_timeout = Some(timeout);
+        self
+    }
+
+    /// Require the provided exchange to respond to keepalive pings within the provided pong
+    /// timeout (or disable pong tracking with `None`), rather than the default
+    /// [`Connector::pong_timeout`] deadline.
+    ///
+    /// Applies to [`Subscription`]s added after this method is invoked.
+    pub fn with_pong_timeout(mut self, exchange: ExchangeId, timeout: Option<Duration>) -> Self {
+        self.options.pong_timeouts.insert(exchange, timeout);
+        self
+    }
+
+    /// Recycle up to `capacity` OrderBook `Vec<Level>` buffers per connection, avoiding a pair
+    /// of allocations per update when maintaining many instruments' OrderBooks. Zero (the
+    /// default) disables pooling.
+    ///
+    /// Applies to [`Subscription`]s added after this method is invoked.
+    pub fn with_level_pool_capacity(mut self, capacity: usize) -> Self {
+        self.options.level_pool_capacity = capacity;
+        self
+    }
+
+    /// Spawn every background task (eg/ consumer loops, WebSocket distribution & ping tasks,
+    /// [`Streams`] combinators) using the provided [`Spawner`], rather than the default Tokio
+    /// executor.
+    ///
+    /// Applies to [`Subscription`]s added after this method is invoked, and to the [`Streams`]
+    /// initialised by this [`StreamBuilder`].
+    pub fn with_spawner(mut self, spawner: Spawner) -> Self {
+        self.options.spawner = spawner;
+        self
+    }
+
+    /// Handle [`Subscription`]s that duplicate those added via a previous
+    /// [`subscribe()`](StreamBuilder::subscribe()) call using the provided [`DuplicatePolicy`],
+    /// rather than the default [`DuplicatePolicy::Merge`].
+    ///
+    /// Applies to [`Subscription`]s added after this method is invoked.
+    pub fn with_duplicate_policy(self, duplicate_policy: DuplicatePolicy) -> Self {
+        Self {
+            duplicate_policy,
+            ..self
         }
     }
 
+    /// Invoke the provided [`ConsumerHooks`] from every consumer loop, allowing custom metrics &
+    /// alerting to be attached.
+    ///
+    /// Applies to [`Subscription`]s added after this method is invoked.
+    pub fn with_hooks(self, hooks: ConsumerHooks<MarketEvent<Instrument, Kind::Event>>) -> Self {
+        Self { hooks, ..self }
+    }
+
+    /// Validate every subsequently added [`Subscription`] against the markets cached in the
+    /// provided [`InstrumentDiscovery`], failing [`init()`](StreamBuilder::init()) with
+    /// nearest-match suggestions if an exchange market is unknown.
+    pub fn discovery(self, discovery: InstrumentDiscovery) -> Self {
+        Self { discovery, ..self }
+    }
+
     /// Add a collection of [`Subscription`]s to the [`StreamBuilder`] that will be actioned on
     /// a distinct [`WebSocket`](barter_integration::protocol::websocket::WebSocket) connection.
     ///
+    /// If the collection exceeds the exchange [`Connector::subscription_limit`], it is sharded
+    /// across multiple connections whose outputs are merged transparently.
+    ///
     /// Note that [`Subscription`]s are not actioned until the
     /// [`init()`](StreamBuilder::init()) method is invoked.
-    pub fn subscribe<SubIter, Sub, Exchange>(mut self, subscriptions: SubIter) -> Self
+    pub fn subscribe<SubIter, Sub, Exchange>(self, subscriptions: SubIter) -> Self
+    where
+        SubIter: IntoIterator<Item = Sub>,
+        Sub: Into<Subscription<Exchange, Instrument, Kind>>,
+        Exchange: StreamSelector<Instrument, Kind> + Ord + Send + Sync + 'static,
+        Kind: Ord + Send + Sync + 'static,
+        Kind::Event: Send,
+        Subscription<Exchange, Instrument, Kind>:
+            Identifier<Exchange::Channel> + Identifier<Exchange::Market>,
+    {
+        self.subscribe_with(subscriptions, false)
+    }
+
+    /// Add a collection of latency-critical [`Subscription`]s to the [`StreamBuilder`] that will
+    /// be actioned on a distinct [`WebSocket`](barter_integration::protocol::websocket::WebSocket)
+    /// connection, alongside a muted warm-standby connection that is promoted instantly if the
+    /// primary drops.
+    ///
+    /// See [`consume_with_standby`] for more information.
+    ///
+    /// Note that [`Subscription`]s are not actioned until the
+    /// [`init()`](StreamBuilder::init()) method is invoked.
+    pub fn subscribe_with_standby<SubIter, Sub, Exchange>(self, subscriptions: SubIter) -> Self
+    where
+        SubIter: IntoIterator<Item = Sub>,
+        Sub: Into<Subscription<Exchange, Instrument, Kind>>,
+        Exchange: StreamSelector<Instrument, Kind> + Ord + Send + Sync + 'static,
+        Kind: Ord + Send + Sync + 'static,
+        Kind::Event: Send,
+        Subscription<Exchange, Instrument, Kind>:
+            Identifier<Exchange::Channel> + Identifier<Exchange::Market>,
+    {
+        self.subscribe_with(subscriptions, true)
+    }
+
+    fn subscribe_with<SubIter, Sub, Exchange>(
+        mut self,
+        subscriptions: SubIter,
+        standby: bool,
+    ) -> Self
     where
         SubIter: IntoIterator<Item = Sub>,
         Sub: Into<Subscription<Exchange, Instrument, Kind>>,
@@ -76,24 +300,129 @@ where
         // Construct Vec<Subscriptions> from input SubIter
         let mut subscriptions = subscriptions.into_iter().map(Sub::into).collect::<Vec<_>>();
 
+        // Validate Subscriptions before translating them into exchange specific identifiers
+        if let Err(error) =
+            validate(&subscriptions).and_then(|()| self.discovery.validate(&subscriptions))
+        {
+            self.futures
+                .push((Exchange::ID, Box::pin(async move { Err(error) })));
+            return self;
+        }
+
+        // Detect Subscriptions that were already added via a previous subscribe call
+        let keys = subscriptions.iter().map(key).collect::<Vec<_>>();
+        if let Some((exchange, subscription_id)) =
+            keys.iter().find(|key| self.subscribed.contains(key))
+        {
+            match self.duplicate_policy {
+                DuplicatePolicy::Reject => {
+                    let error = DataError::DuplicateSubscription {
+                        exchange: *exchange,
+                        subscription_id: subscription_id.clone(),
+                    };
+                    self.futures
+                        .push((Exchange::ID, Box::pin(async move { Err(error) })));
+                    return self;
+                }
+                DuplicatePolicy::Merge => {
+                    warn!(
+                        %exchange,
+                        %subscription_id,
+                        action = "merging with existing Subscription",
+                        "duplicate Subscription added to StreamBuilder"
+                    );
+                    subscriptions
+                        .retain(|subscription| !self.subscribed.contains(&key(subscription)));
+                    if subscriptions.is_empty() {
+                        return self;
+                    }
+                }
+            }
+        }
+        self.subscribed.extend(keys);
+
         // Acquire channel Sender to send Market<Kind::Event> from consumer loop to user
         // '--> Add ExchangeChannel Entry if this Exchange <--> SubscriptionKind combination is new
         let exchange_tx = self.channels.entry(Exchange::ID).or_default().tx.clone();
+        let event_map = self.event_map.clone();
+        let conflation = self.conflation;
+        let priority = self.priority.clone();
+        let reconnect_policy = self.reconnect_policy;
+        let options = self.options.clone();
+        let hooks = self.hooks.clone();
+        let health = self.health.clone();
 
         // Add Future that once awaited will yield the Result<(), SocketError> of subscribing
-        self.futures.push(Box::pin(async move {
-            // Validate Subscriptions
-            validate(&subscriptions)?;
+        self.futures.push((
+            Exchange::ID,
+            Box::pin(async move {
+                // Remove duplicate Subscriptions
+                subscriptions.sort();
+                subscriptions.dedup();
 
-            // Remove duplicate Subscriptions
-            subscriptions.sort();
-            subscriptions.dedup();
+                // Route events through priority & standard lanes before distributing them downstream
+                let mut tasks = Vec::new();
+                let exchange_tx = match priority {
+                    Some(priority) => {
+                        let (exchange_tx, task) = forward_prioritised(exchange_tx, priority);
+                        tasks.push(task);
+                        exchange_tx
+                    }
+                    None => exchange_tx,
+                };
 
-            // Spawn a MarketStream consumer loop with these Subscriptions<Exchange, Kind>
-            tokio::spawn(consume(subscriptions, exchange_tx));
+                // Conflate events per instrument before distributing them downstream
+                let exchange_tx = match conflation {
+                    Some(interval) => {
+                        let (exchange_tx, task) = forward_conflated(exchange_tx, interval);
+                        tasks.push(task);
+                        exchange_tx
+                    }
+                    None => exchange_tx,
+                };
 
-            Ok(())
-        }));
+                // Apply any EventMap before distributing events downstream
+                let exchange_tx = match event_map {
+                    Some(event_map) => {
+                        let (exchange_tx, task) = forward_mapped(exchange_tx, event_map);
+                        tasks.push(task);
+                        exchange_tx
+                    }
+                    None => exchange_tx,
+                };
+
+                // Construct a MarketStream consumer loop for each shard of Subscriptions<Exchange, Kind>,
+                // each of which distributes events to the same exchange_tx
+                for subscriptions in shard(subscriptions, Exchange::subscription_limit()) {
+                    let consumer = if standby {
+                        consume_with_standby(
+                            subscriptions,
+                            exchange_tx.clone(),
+                            health.clone(),
+                            reconnect_policy,
+                            options.clone(),
+                            hooks.clone(),
+                        )
+                        .map(drop)
+                        .boxed()
+                    } else {
+                        consume(
+                            subscriptions,
+                            exchange_tx.clone(),
+                            health.clone(),
+                            reconnect_policy,
+                            options.clone(),
+                            hooks.clone(),
+                        )
+                        .map(drop)
+                        .boxed()
+                    };
+                    tasks.push(consumer);
+                }
+
+                Ok(tasks)
+            }),
+        ));
 
         self
     }
@@ -105,17 +434,360 @@ where
     /// Each consumer loop distributes consumed [`MarketEvent<SubscriptionKind::Event>s`](MarketEvent) to
     /// the [`Streams`] `HashMap` returned by this method.
     pub async fn init(self) -> Result<Streams<MarketEvent<Instrument, Kind::Event>>, DataError> {
-        // Await Stream initialisation perpetual and ensure success
-        futures::future::try_join_all(self.futures).await?;
+        let spawner = self.options.spawner.clone();
+        let (streams, tasks) = self.build().await?;
+        tasks.into_iter().for_each(|task| spawner.spawn(task));
+        Ok(streams)
+    }
+
+    /// Validate every collection of [`Subscription`]s added to the [`StreamBuilder`], returning
+    /// the [`Streams`] alongside the un-spawned [`StreamTask`]s (eg/ consumer loops) that must be
+    /// driven for the [`Streams`] to yield events.
+    ///
+    /// Unlike [`init()`](StreamBuilder::init()), nothing is spawned, allowing the tasks to be
+    /// driven by a custom executor or structured concurrency primitive (eg/ a
+    /// [`JoinSet`](tokio::task::JoinSet)).
+    ///
+    /// Note that each [`MarketStream`](crate::MarketStream) connection still spawns its WebSocket
+    /// distribution & ping tasks internally, using the configured [`Spawner`].
+    pub async fn build(
+        self,
+    ) -> Result<
+        (
+            Streams<MarketEvent<Instrument, Kind::Event>>,
+            Vec<StreamTask>,
+        ),
+        DataError,
+    > {
+        // Await Subscription validation and ensure success
+        let tasks =
+            futures::future::try_join_all(self.futures.into_iter().map(|(_, future)| future))
+                .await?;
 
         // Construct Streams using each ExchangeChannel receiver
-        Ok(Streams {
-            streams: self
-                .channels
+        let streams = Streams::new(
+            self.channels
                 .into_iter()
                 .map(|(exchange, channel)| (exchange, channel.rx))
                 .collect(),
-        })
+            self.health,
+        )
+        .with_spawner(self.options.spawner);
+
+        Ok((streams, tasks.into_iter().flatten().collect()))
+    }
+
+    /// Spawn a [`MarketEvent<SubscriptionKind::Event>`](MarketEvent) consumer loop for each
+    /// collection of [`Subscription`]s added to [`StreamBuilder`], without the first failure
+    /// aborting the initialisation of every other exchange.
+    ///
+    /// The returned [`Streams`] only contain the exchanges with at least one successfully
+    /// initialised collection of [`Subscription`]s, allowing multi-venue deployments to run
+    /// degraded. The [`InitReport`] details which exchanges initialised and which failed.
+    pub async fn init_with_report(
+        self,
+    ) -> (Streams<MarketEvent<Instrument, Kind::Event>>, InitReport) {
+        // Await every Stream initialisation, retaining the outcome of each
+        let (exchanges, futures): (Vec<_>, Vec<_>) = self.futures.into_iter().unzip();
+        let results = futures::future::join_all(futures).await;
+
+        let mut report = InitReport::default();
+        for (exchange, result) in exchanges.into_iter().zip(results) {
+            match result {
+                Ok(tasks) => {
+                    tasks
+                        .into_iter()
+                        .for_each(|task| self.options.spawner.spawn(task));
+                    if !report.initialised.contains(&(exchange, Kind::ID)) {
+                        report.initialised.push((exchange, Kind::ID));
+                    }
+                }
+                Err(error) => report.failed.push(InitFailure {
+                    exchange,
+                    sub_kind: Kind::ID,
+                    error,
+                }),
+            }
+        }
+
+        // Construct Streams using each successfully initialised ExchangeChannel receiver
+        let streams = Streams::new(
+            self.channels
+                .into_iter()
+                .filter(|(exchange, _)| report.initialised.contains(&(*exchange, Kind::ID)))
+                .map(|(exchange, channel)| (exchange, channel.rx))
+                .collect(),
+            self.health,
+        )
+        .with_spawner(self.options.spawner);
+
+        (streams, report)
+    }
+}
+
+/// Policy used by a [`StreamBuilder`] to handle [`Subscription`]s that duplicate the
+/// `(exchange, instrument, kind)` of those added via a previous
+/// [`subscribe()`](StreamBuilder::subscribe()) call.
+///
+/// Duplicates within a single [`subscribe()`](StreamBuilder::subscribe()) call are always
+/// removed.
+#[derive(
+    Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default, Deserialize, Serialize,
+)]
+pub enum DuplicatePolicy {
+    /// Remove the duplicate [`Subscription`]s, since their events are already distributed via
+    /// the existing [`Subscription`].
+    #[default]
+    Merge,
+
+    /// Fail [`init()`](StreamBuilder::init()) with a [`DataError::DuplicateSubscription`].
+    Reject,
+}
+
+/// Outcome of initialising each `(ExchangeId, SubKindId)` channel of a [`StreamBuilder`], returned
+/// by [`StreamBuilder::init_with_report`].
+///
+/// Note that an exchange may appear in both `initialised` & `failed` if it was configured via
+/// multiple [`StreamBuilder::subscribe`] calls, some of which failed.
+#[derive(Debug, Default)]
+pub struct InitReport {
+    pub initialised: Vec<(ExchangeId, SubKindId)>,
+    pub failed: Vec<InitFailure>,
+}
+
+impl InitReport {
+    /// Determine if every `(ExchangeId, SubKindId)` channel initialised successfully.
+    pub fn is_complete(&self) -> bool {
+        self.failed.is_empty()
+    }
+}
+
+/// `(ExchangeId, SubKindId)` channel that failed to initialise, alongside the cause.
+#[derive(Debug)]
+pub struct InitFailure {
+    pub exchange: ExchangeId,
+    pub sub_kind: SubKindId,
+    pub error: DataError,
+}
+
+impl StreamBuilder<DataKinds> {
+    /// Route trades & OrderBook events consumed from each [`DataKinds`] connection through
+    /// separate lanes, distributing every queued event of the provided [`Lane`] first.
+    ///
+    /// This ensures a burst of OrderBook deltas cannot delay trade delivery (or vice versa).
+    /// Events that are neither trades nor OrderBook events share the non-prioritised lane.
+    ///
+    /// Applies to [`Subscription`]s added after this method is invoked.
+    pub fn prioritise(self, lane: Lane) -> Self {
+        Self {
+            priority: Some(Arc::new(move |kind| Lane::of(kind) == Some(lane))),
+            ..self
+        }
+    }
+}
+
+impl StreamBuilder<OrderBooksL2> {
+    /// Only distribute the best `depth` [`Level`](crate::subscription::book::Level)s of each
+    /// [`OrderBook`](crate::subscription::book::OrderBook) side, for consumers that do not
+    /// require the full book on every emission.
+    ///
+    /// Applies to [`Subscription`]s added after this method is invoked.
+    pub fn depth(self, depth: usize) -> Self {
+        Self {
+            event_map: Some(Arc::new(move |mut book| book.truncated(depth))),
+            ..self
+        }
+    }
+
+    /// Distribute at most one [`OrderBook`](crate::subscription::book::OrderBook) per instrument
+    /// every `interval`, protecting slow consumers from high frequency update streams (eg/
+    /// Binance futures 100ms depth updates across many symbols).
+    ///
+    /// Since each [`OrderBook`](crate::subscription::book::OrderBook) event contains the full
+    /// book state, interim updates are merged by retaining only the latest. The first update
+    /// after a quiet period is distributed immediately.
+    ///
+    /// Applies to [`Subscription`]s added after this method is invoked.
+    pub fn conflate(self, interval: Duration) -> Self {
+        Self {
+            conflation: Some(interval),
+            ..self
+        }
+    }
+}
+
+/// Construct a [`StreamTask`] that forwards every [`MarketEvent`] sent via the returned
+/// [`mpsc::UnboundedSender`] to the provided `exchange_tx`, distributing at most one event per
+/// instrument every `interval`. Interim events are conflated, such that only the latest is
+/// distributed once the instrument's `interval` has elapsed.
+fn forward_conflated<Event>(
+    exchange_tx: mpsc::UnboundedSender<MarketEvent<Instrument, Event>>,
+    interval: Duration,
+) -> (
+    mpsc::UnboundedSender<MarketEvent<Instrument, Event>>,
+    StreamTask,
+)
+where
+    Event: Send + 'static,
+{
+    let (tx, mut rx) = mpsc::unbounded_channel::<MarketEvent<Instrument, Event>>();
+
+    let task = Box::pin(async move {
+        let mut conflator = Conflator::new(interval);
+
+        loop {
+            let event = match conflator.next_flush() {
+                Some(deadline) => tokio::select! {
+                    event = rx.recv() => event,
+                    _ = tokio::time::sleep_until(deadline) => {
+                        let flushed = conflator.flush(Instant::now());
+                        if flushed.into_iter().any(|event| exchange_tx.send(event).is_err()) {
+                            break;
+                        }
+                        continue;
+                    }
+                },
+                None => rx.recv().await,
+            };
+
+            let Some(event) = event else {
+                // Distribute any pending events before ending
+                conflator
+                    .drain()
+                    .for_each(|event| drop(exchange_tx.send(event)));
+                break;
+            };
+
+            if let Some(event) = conflator.push(event, Instant::now()) {
+                if exchange_tx.send(event).is_err() {
+                    break;
+                }
+            }
+        }
+    });
+
+    (tx, task)
+}
+
+/// Per instrument [`MarketEvent`] conflation state used by [`forward_conflated`].
+struct Conflator<Event> {
+    interval: Duration,
+    next_emit: HashMap<Instrument, Instant>,
+    pending: HashMap<Instrument, MarketEvent<Instrument, Event>>,
+}
+
+impl<Event> Conflator<Event> {
+    fn new(interval: Duration) -> Self {
+        Self {
+            interval,
+            next_emit: HashMap::new(),
+            pending: HashMap::new(),
+        }
+    }
+
+    /// Returns the [`MarketEvent`] if it may be distributed immediately, otherwise it replaces any
+    /// pending [`MarketEvent`] for the same instrument.
+    fn push(
+        &mut self,
+        event: MarketEvent<Instrument, Event>,
+        now: Instant,
+    ) -> Option<MarketEvent<Instrument, Event>> {
+        match self.next_emit.get(&event.instrument) {
+            Some(next_emit) if *next_emit > now => {
+                self.pending.insert(event.instrument.clone(), event);
+                None
+            }
+            _ => {
+                self.next_emit
+                    .insert(event.instrument.clone(), now + self.interval);
+                Some(event)
+            }
+        }
+    }
+
+    /// Earliest [`Instant`] a pending [`MarketEvent`] may be distributed, if any are pending.
+    fn next_flush(&self) -> Option<Instant> {
+        self.pending
+            .keys()
+            .filter_map(|instrument| self.next_emit.get(instrument))
+            .min()
+            .copied()
+    }
+
+    /// Remove every pending [`MarketEvent`] that may be distributed at the provided [`Instant`].
+    fn flush(&mut self, now: Instant) -> Vec<MarketEvent<Instrument, Event>> {
+        let due = self
+            .pending
+            .keys()
+            .filter(|instrument| {
+                self.next_emit
+                    .get(*instrument)
+                    .is_none_or(|next_emit| *next_emit <= now)
+            })
+            .cloned()
+            .collect::<Vec<_>>();
+
+        due.into_iter()
+            .filter_map(|instrument| {
+                self.next_emit
+                    .insert(instrument.clone(), now + self.interval);
+                self.pending.remove(&instrument)
+            })
+            .collect()
+    }
+
+    /// Remove every pending [`MarketEvent`], regardless of the conflation `interval`.
+    fn drain(&mut self) -> impl Iterator<Item = MarketEvent<Instrument, Event>> + '_ {
+        self.pending.drain().map(|(_, event)| event)
+    }
+}
+
+/// Construct a [`StreamTask`] that applies the [`EventMap`] to every [`MarketEvent`] sent via the returned
+/// [`mpsc::UnboundedSender`], before forwarding it to the provided `exchange_tx`.
+fn forward_mapped<Event>(
+    exchange_tx: mpsc::UnboundedSender<MarketEvent<Instrument, Event>>,
+    event_map: EventMap<Event>,
+) -> (
+    mpsc::UnboundedSender<MarketEvent<Instrument, Event>>,
+    StreamTask,
+)
+where
+    Event: Send + 'static,
+{
+    let (tx, mut rx) = mpsc::unbounded_channel::<MarketEvent<Instrument, Event>>();
+
+    let task = Box::pin(async move {
+        while let Some(event) = rx.recv().await {
+            let event = MarketEvent {
+                exchange_time: event.exchange_time,
+                received_time: event.received_time,
+                exchange: event.exchange,
+                instrument: event.instrument,
+                kind: event_map(event.kind),
+            };
+
+            if exchange_tx.send(event).is_err() {
+                break;
+            }
+        }
+    });
+
+    (tx, task)
+}
+
+/// Split the provided [`Subscription`]s into shards of at most `limit` [`Subscription`]s, each of
+/// which is actioned on a distinct connection.
+fn shard<Sub>(subscriptions: Vec<Sub>, limit: Option<usize>) -> Vec<Vec<Sub>> {
+    match limit {
+        Some(limit) if subscriptions.len() > limit => {
+            let mut subscriptions = subscriptions.into_iter().peekable();
+            let mut shards = Vec::new();
+            while subscriptions.peek().is_some() {
+                shards.push(subscriptions.by_ref().take(limit.max(1)).collect());
+            }
+            shards
+        }
+        _ => vec![subscriptions],
     }
 }
 
@@ -142,12 +814,14 @@ impl<T> Default for ExchangeChannel<T> {
 }
 
 /// Validate the provided collection of [`Subscription`]s, ensuring that the associated exchange
-/// supports every [`Subscription`] [`InstrumentKind`](barter_integration::model::InstrumentKind).
+/// supports every [`Subscription`] [`InstrumentKind`](barter_integration::model::InstrumentKind)
+/// and [`SubscriptionKind`].
 pub fn validate<Exchange, Kind>(
     subscriptions: &[Subscription<Exchange, Instrument, Kind>],
 ) -> Result<(), DataError>
 where
     Exchange: Connector,
+    Kind: SubscriptionKind,
 {
     // Ensure at least one Subscription has been provided
     if subscriptions.is_empty() {
@@ -168,9 +842,54 @@ where
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{exchange::coinbase::Coinbase, subscription::trade::PublicTrades};
+    use crate::{
+        exchange::{binance::spot::BinanceSpot, coinbase::Coinbase, okx::Okx},
+        subscription::{trade::PublicTrades, DataKinds, SubKind},
+    };
     use barter_integration::model::instrument::kind::InstrumentKind;
-    use barter_integration::model::instrument::Instrument;
+    use barter_integration::model::{instrument::Instrument, Exchange};
+    use chrono::Utc;
+
+    #[test]
+    fn test_conflator() {
+        fn event(instrument: &Instrument, kind: u64) -> MarketEvent<Instrument, u64> {
+            MarketEvent {
+                exchange_time: Utc::now(),
+                received_time: Utc::now(),
+                exchange: Exchange::from("exchange"),
+                instrument: instrument.clone(),
+                kind,
+            }
+        }
+
+        let interval = Duration::from_millis(100);
+        let now = Instant::now();
+        let btc = Instrument::from(("btc", "usdt", InstrumentKind::Perpetual));
+        let eth = Instrument::from(("eth", "usdt", InstrumentKind::Perpetual));
+        let mut conflator = Conflator::new(interval);
+
+        // First event for each instrument is distributed immediately
+        assert_eq!(conflator.push(event(&btc, 1), now).map(|e| e.kind), Some(1));
+        assert_eq!(conflator.push(event(&eth, 1), now).map(|e| e.kind), Some(1));
+        assert_eq!(conflator.next_flush(), None);
+
+        // Interim events are conflated, retaining only the latest
+        assert!(conflator.push(event(&btc, 2), now).is_none());
+        assert!(conflator.push(event(&btc, 3), now).is_none());
+        assert_eq!(conflator.next_flush(), Some(now + interval));
+
+        // Nothing is flushed before the interval has elapsed
+        assert!(conflator.flush(now + interval / 2).is_empty());
+
+        // Latest pending event is flushed once the interval has elapsed
+        let flushed = conflator.flush(now + interval);
+        assert_eq!(flushed.iter().map(|e| e.kind).collect::<Vec<_>>(), vec![3]);
+        assert_eq!(conflator.next_flush(), None);
+
+        // Flushing restarts the instrument's interval
+        assert!(conflator.push(event(&btc, 4), now + interval).is_none());
+        assert_eq!(conflator.next_flush(), Some(now + interval * 2));
+    }
 
     #[test]
     fn test_validate() {
@@ -232,4 +951,125 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn test_shard() {
+        struct TestCase {
+            subscriptions: Vec<u32>,
+            limit: Option<usize>,
+            expected: Vec<Vec<u32>>,
+        }
+
+        let cases = vec![
+            TestCase {
+                // TC0: no limit yields a single shard
+                subscriptions: vec![1, 2, 3],
+                limit: None,
+                expected: vec![vec![1, 2, 3]],
+            },
+            TestCase {
+                // TC1: subscriptions within the limit yield a single shard
+                subscriptions: vec![1, 2, 3],
+                limit: Some(3),
+                expected: vec![vec![1, 2, 3]],
+            },
+            TestCase {
+                // TC2: subscriptions exceeding the limit are sharded
+                subscriptions: vec![1, 2, 3, 4, 5],
+                limit: Some(2),
+                expected: vec![vec![1, 2], vec![3, 4], vec![5]],
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = shard(test.subscriptions, test.limit);
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_init_with_report() {
+        let (streams, report) = StreamBuilder::<PublicTrades>::new()
+            .subscribe([(Okx, "btc", "usdt", InstrumentKind::Spot, PublicTrades)])
+            .subscribe(Vec::<Subscription<Coinbase, Instrument, PublicTrades>>::new())
+            .init_with_report()
+            .await;
+
+        assert!(!report.is_complete());
+        assert_eq!(
+            report.initialised,
+            vec![(ExchangeId::Okx, PublicTrades::ID)]
+        );
+        assert_eq!(report.failed.len(), 1);
+        assert_eq!(report.failed[0].exchange, ExchangeId::Coinbase);
+        assert!(matches!(report.failed[0].error, DataError::Socket(_)));
+
+        // Only the successfully initialised exchanges are present in the Streams
+        assert!(streams.streams.contains_key(&ExchangeId::Okx));
+        assert!(!streams.streams.contains_key(&ExchangeId::Coinbase));
+    }
+
+    #[tokio::test]
+    async fn test_duplicate_policy() {
+        let btc = (Okx, "btc", "usdt", InstrumentKind::Spot, PublicTrades);
+        let eth = (Okx, "eth", "usdt", InstrumentKind::Spot, PublicTrades);
+
+        // Duplicates across subscribe calls are merged by default
+        let builder = StreamBuilder::<PublicTrades>::new()
+            .subscribe([btc])
+            .subscribe([btc, eth])
+            .subscribe([eth]);
+        assert_eq!(builder.subscribed.len(), 2);
+        assert_eq!(builder.futures.len(), 2);
+
+        // Duplicates across subscribe calls are rejected with DuplicatePolicy::Reject
+        let result = StreamBuilder::<PublicTrades>::new()
+            .with_duplicate_policy(DuplicatePolicy::Reject)
+            .subscribe([btc])
+            .subscribe([eth, btc])
+            .init()
+            .await;
+        assert!(matches!(
+            result,
+            Err(DataError::DuplicateSubscription {
+                exchange: ExchangeId::Okx,
+                ..
+            })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_subscribe_unsupported_returns_error() {
+        // Unsupported Subscriptions are validated before any exchange specific identifier is
+        // determined, so init() returns an error rather than panicking
+        let result = StreamBuilder::<DataKinds>::new()
+            .subscribe([(
+                BinanceSpot::default(),
+                "btc",
+                "usdt",
+                InstrumentKind::Spot,
+                DataKinds(SubKind::Candles),
+            )])
+            .init()
+            .await;
+        assert!(matches!(
+            result,
+            Err(DataError::Socket(SocketError::Unsupported { .. }))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_build() {
+        let (streams, tasks) = StreamBuilder::<PublicTrades>::new()
+            .subscribe([(Okx, "btc", "usdt", InstrumentKind::Spot, PublicTrades)])
+            .subscribe([(Okx, "eth", "usdt", InstrumentKind::Spot, PublicTrades)])
+            .build()
+            .await
+            .unwrap();
+
+        // One un-spawned consumer loop per subscribe call, nothing is driven until spawned
+        assert_eq!(tasks.len(), 2);
+        assert!(streams.streams.contains_key(&ExchangeId::Okx));
+        assert!(streams.health.statuses().is_empty());
+    }
 }
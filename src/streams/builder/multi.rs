@@ -1,9 +1,21 @@
 use super::{ExchangeChannel, StreamBuilder, Streams};
+use crate::streams::health::{SubscriptionHealth, SubscriptionKey};
 use crate::{
-    error::DataError, event::MarketEvent, exchange::ExchangeId, subscription::SubscriptionKind,
+    error::DataError,
+    event::MarketEvent,
+    exchange::ExchangeId,
+    runtime::Spawner,
+    subscription::{SubKindId, SubscriptionKind},
 };
 use barter_integration::model::instrument::Instrument;
-use std::{collections::HashMap, fmt::Debug, future::Future, pin::Pin};
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::Debug,
+    future::Future,
+    pin::Pin,
+};
+use tokio::sync::mpsc;
+use tokio_stream::{wrappers::UnboundedReceiverStream, StreamMap};
 
 /// Communicative type alias representing the [`Future`] result of a [`StreamBuilder::init`] call
 /// generated whilst executing [`MultiStreamBuilder::add`].
@@ -11,10 +23,18 @@ pub type BuilderInitFuture = Pin<Box<dyn Future<Output = Result<(), DataError>>>
 
 /// Builder to configure and initialise a common [`Streams<Output>`](Streams) instance from
 /// multiple [`StreamBuilder<SubscriptionKind>`](StreamBuilder)s.
+///
+/// Each `Output` channel is keyed by the [`ExchangeId`] & [`SubKindId`] it originates from,
+/// allowing the output to be initialised either as [`Streams<Output>`](Streams) keyed by
+/// [`ExchangeId`] (see [`Self::init`]), or as a [`StreamMap`] that preserves the full provenance
+/// (see [`Self::init_map`]).
 #[derive(Default)]
 pub struct MultiStreamBuilder<Output> {
-    pub channels: HashMap<ExchangeId, ExchangeChannel<Output>>,
+    pub channels: HashMap<(ExchangeId, SubKindId), ExchangeChannel<Output>>,
     pub futures: Vec<BuilderInitFuture>,
+    pub health: SubscriptionHealth,
+    subscribed: HashSet<SubscriptionKey>,
+    spawner: Spawner,
 }
 
 impl<Output> Debug for MultiStreamBuilder<Output>
@@ -35,13 +55,30 @@ impl<Output> MultiStreamBuilder<Output> {
         Self {
             channels: HashMap::new(),
             futures: Vec::new(),
+            health: SubscriptionHealth::default(),
+            subscribed: HashSet::new(),
+            spawner: Spawner::default(),
         }
     }
 
+    /// Spawn the tasks joining the `Output`s of each added [`StreamBuilder`] using the provided
+    /// [`Spawner`], rather than the default.
+    ///
+    /// Each added [`StreamBuilder`] spawns its own tasks (eg/ consumer loops) using the
+    /// [`Spawner`] it was configured with (see [`StreamBuilder::with_spawner`]).
+    pub fn with_spawner(self, spawner: Spawner) -> Self {
+        Self { spawner, ..self }
+    }
+
     /// Add a [`StreamBuilder<SubscriptionKind>`](StreamBuilder) to the [`MultiStreamBuilder`]. Creates a
     /// [`Future`] that calls [`StreamBuilder::init`] and maps the [`SubscriptionKind::Event`](SubscriptionKind)
     /// into a common `Output`.
     ///
+    /// If the [`StreamBuilder`] contains a [`Subscription`](crate::subscription::Subscription)
+    /// that duplicates one of a previously added [`StreamBuilder`], initialisation fails with a
+    /// [`DataError::DuplicateSubscription`], since events cannot be merged across the
+    /// distinct `Output` channels of each [`SubKindId`].
+    ///
     /// Note that the created [`Future`] is not awaited until the [`MultiStreamBuilder::init`]
     /// method is invoked.
     #[allow(clippy::should_implement_trait)]
@@ -51,19 +88,43 @@ impl<Output> MultiStreamBuilder<Output> {
         Kind: SubscriptionKind + 'static,
         Kind::Event: Send,
     {
+        // Reject Subscriptions that duplicate those of a previously added StreamBuilder
+        if let Some((exchange, subscription_id)) = builder
+            .subscribed
+            .iter()
+            .find(|key| self.subscribed.contains(key))
+        {
+            let error = DataError::DuplicateSubscription {
+                exchange: *exchange,
+                subscription_id: subscription_id.clone(),
+            };
+            self.futures.push(Box::pin(async move { Err(error) }));
+            return self;
+        }
+        self.subscribed.extend(builder.subscribed.iter().cloned());
+
         // Allocate HashMap to hold the exchange_tx<Output> for each StreamBuilder exchange present
         let mut exchange_txs = HashMap::with_capacity(builder.channels.len());
 
         // Iterate over each StreamBuilder exchange present
         for exchange in builder.channels.keys().copied() {
-            // Insert ExchangeChannel<Output> Entry to Self for each exchange
-            let exchange_tx = self.channels.entry(exchange).or_default().tx.clone();
+            // Insert ExchangeChannel<Output> Entry to Self for each (exchange, SubKindId)
+            let exchange_tx = self
+                .channels
+                .entry((exchange, Kind::ID))
+                .or_default()
+                .tx
+                .clone();
 
             // Insert new exchange_tx<Output> into HashMap for each exchange
             exchange_txs.insert(exchange, exchange_tx);
         }
 
+        // Track the Subscription health of this StreamBuilder alongside the others
+        self.health.merge(builder.health.clone());
+
         // Init Streams<Kind::Event> & send mapped Outputs to the associated exchange_tx
+        let spawner = self.spawner.clone();
         self.futures.push(Box::pin(async move {
             builder
                 .init()
@@ -78,7 +139,7 @@ impl<Output> MultiStreamBuilder<Output> {
                         .expect("all exchange_txs should be present here");
 
                     // Task to receive MarketEvent<SubscriptionKind::Event> and send Outputs via exchange_tx
-                    tokio::spawn(async move {
+                    spawner.spawn(async move {
                         while let Some(event) = exchange_rx.recv().await {
                             let _ = exchange_tx.send(Output::from(event));
                         }
@@ -94,17 +155,154 @@ impl<Output> MultiStreamBuilder<Output> {
     /// Initialise each [`StreamBuilder<SubscriptionKind>`](StreamBuilder) that was added to the
     /// [`MultiStreamBuilder`] and map all [`Streams<SubscriptionKind::Event>`](Streams) into a common
     /// [`Streams<Output>`](Streams).
-    pub async fn init(self) -> Result<Streams<Output>, DataError> {
+    ///
+    /// The `Output`s of every [`SubKindId`] associated with an exchange are joined into that
+    /// exchange's [`mpsc::UnboundedReceiver`].
+    pub async fn init(self) -> Result<Streams<Output>, DataError>
+    where
+        Output: Send + 'static,
+    {
         // Await Stream initialisation perpetual and ensure success
         futures::future::try_join_all(self.futures).await?;
 
-        // Construct Streams<Output> using each ExchangeChannel receiver
-        Ok(Streams {
-            streams: self
-                .channels
+        // Group each ExchangeChannel receiver by ExchangeId
+        let mut grouped = HashMap::<ExchangeId, Vec<mpsc::UnboundedReceiver<Output>>>::new();
+        for ((exchange, _), channel) in self.channels {
+            grouped.entry(exchange).or_default().push(channel.rx);
+        }
+
+        // Construct Streams<Output>, joining the receivers of any exchange with many SubKindIds
+        Ok(Streams::new(
+            grouped
                 .into_iter()
-                .map(|(exchange, channel)| (exchange, channel.rx))
+                .map(|(exchange, mut receivers)| match receivers.len() {
+                    1 => (exchange, receivers.remove(0)),
+                    _ => (exchange, join(&self.spawner, receivers)),
+                })
                 .collect(),
-        })
+            self.health,
+        )
+        .with_spawner(self.spawner))
+    }
+
+    /// Initialise each [`StreamBuilder<SubscriptionKind>`](StreamBuilder) that was added to the
+    /// [`MultiStreamBuilder`] and map all [`Streams<SubscriptionKind::Event>`](Streams) into a
+    /// [`StreamMap`] keyed by the `(ExchangeId, SubKindId)` each `Output` originates from.
+    ///
+    /// This allows consumers to demultiplex the merged `Output` without inspecting every event.
+    pub async fn init_map(
+        self,
+    ) -> Result<StreamMap<(ExchangeId, SubKindId), UnboundedReceiverStream<Output>>, DataError>
+    {
+        // Await Stream initialisation perpetual and ensure success
+        futures::future::try_join_all(self.futures).await?;
+
+        Ok(self
+            .channels
+            .into_iter()
+            .fold(StreamMap::new(), |mut map, (key, channel)| {
+                map.insert(key, UnboundedReceiverStream::new(channel.rx));
+                map
+            }))
+    }
+}
+
+/// Join the provided [`mpsc::UnboundedReceiver`]s into a single [`mpsc::UnboundedReceiver`].
+fn join<Output>(
+    spawner: &Spawner,
+    receivers: Vec<mpsc::UnboundedReceiver<Output>>,
+) -> mpsc::UnboundedReceiver<Output>
+where
+    Output: Send + 'static,
+{
+    let (joined_tx, joined_rx) = mpsc::unbounded_channel();
+
+    for mut rx in receivers {
+        let joined_tx = joined_tx.clone();
+        spawner.spawn(async move {
+            while let Some(event) = rx.recv().await {
+                let _ = joined_tx.send(event);
+            }
+        });
+    }
+
+    joined_rx
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        exchange::okx::Okx,
+        subscription::{
+            trade::{PublicTrade, PublicTrades},
+            SubKind,
+        },
+    };
+    use barter_integration::model::instrument::kind::InstrumentKind;
+    use tokio_stream::StreamExt;
+
+    fn builder(keys: &[(ExchangeId, SubKindId)]) -> MultiStreamBuilder<(ExchangeId, SubKindId)> {
+        let mut builder = MultiStreamBuilder::new();
+        for key in keys {
+            let channel = builder.channels.entry(*key).or_default();
+            channel.tx.send(*key).unwrap();
+        }
+        builder
+    }
+
+    #[tokio::test]
+    async fn test_init_preserves_provenance() {
+        let trades = (
+            ExchangeId::BinanceSpot,
+            SubKindId::Kind(SubKind::PublicTrades),
+        );
+        let books = (
+            ExchangeId::BinanceSpot,
+            SubKindId::Kind(SubKind::OrderBooksL1),
+        );
+        let okx = (ExchangeId::Okx, SubKindId::DataKinds);
+
+        // Streams<Output> joins every SubKindId of an exchange
+        let mut streams = builder(&[trades, books, okx]).init().await.unwrap();
+        let mut binance = streams.select(ExchangeId::BinanceSpot).unwrap();
+        let mut actual = vec![binance.recv().await.unwrap(), binance.recv().await.unwrap()];
+        actual.sort();
+        assert_eq!(actual, vec![trades, books]);
+        assert_eq!(
+            streams.select(ExchangeId::Okx).unwrap().recv().await,
+            Some(okx)
+        );
+
+        // StreamMap keys each Output by its (ExchangeId, SubKindId)
+        let mut map = builder(&[trades, books, okx]).init_map().await.unwrap();
+        assert_eq!(map.len(), 3);
+        for _ in 0..3 {
+            let (key, event) = map.next().await.unwrap();
+            assert_eq!(key, event);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_add_rejects_duplicates() {
+        let builder = || {
+            StreamBuilder::<PublicTrades>::new().subscribe([(
+                Okx,
+                "btc",
+                "usdt",
+                InstrumentKind::Spot,
+                PublicTrades,
+            )])
+        };
+
+        let result = MultiStreamBuilder::<MarketEvent<Instrument, PublicTrade>>::new()
+            .add(builder())
+            .add(builder())
+            .init()
+            .await;
+        assert!(matches!(
+            result,
+            Err(DataError::DuplicateSubscription { .. })
+        ));
     }
 }
@@ -0,0 +1,165 @@
+use super::{EventPriority, StreamTask};
+use crate::event::{DataKind, MarketEvent};
+use barter_integration::model::instrument::Instrument;
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+use tokio::sync::mpsc;
+
+/// Class of [`DataKind`] event distributed via a dedicated lane of a
+/// [`DataKinds`](crate::subscription::DataKinds) connection.
+///
+/// See [`StreamBuilder::prioritise`](super::StreamBuilder::prioritise).
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub enum Lane {
+    Trades,
+    OrderBooks,
+}
+
+impl Lane {
+    /// Determine the [`Lane`] of the provided [`DataKind`], if it is a trade or OrderBook event.
+    pub fn of(kind: &DataKind) -> Option<Self> {
+        match kind {
+            DataKind::Trade(_) | DataKind::AggTrade(_) | DataKind::BlockTrade(_) => {
+                Some(Self::Trades)
+            }
+            DataKind::OrderBookL1(_)
+            | DataKind::OrderBook(_)
+            | DataKind::OrderBookEvent(_)
+            | DataKind::OrderBookL3(_) => Some(Self::OrderBooks),
+            _ => None,
+        }
+    }
+}
+
+/// Construct a [`StreamTask`] that forwards every [`MarketEvent`] sent via the returned
+/// [`mpsc::UnboundedSender`] to the provided `exchange_tx`, routing events through a priority
+/// lane & a standard lane.
+///
+/// Every queued priority event is distributed before the next standard event, so a burst of
+/// standard events (eg/ OrderBook deltas) cannot delay the delivery of priority events (eg/
+/// trades). Events within a lane retain their original order.
+pub(super) fn forward_prioritised<Event>(
+    exchange_tx: mpsc::UnboundedSender<MarketEvent<Instrument, Event>>,
+    priority: EventPriority<Event>,
+) -> (
+    mpsc::UnboundedSender<MarketEvent<Instrument, Event>>,
+    StreamTask,
+)
+where
+    Event: Send + 'static,
+{
+    let (tx, mut rx) = mpsc::unbounded_channel::<MarketEvent<Instrument, Event>>();
+
+    let task = Box::pin(async move {
+        let mut lanes = Lanes::default();
+
+        loop {
+            // Wait for the next event if both lanes are empty
+            if lanes.is_empty() {
+                let Some(event) = rx.recv().await else {
+                    break;
+                };
+                let prioritised = priority(&event.kind);
+                lanes.push(event, prioritised);
+            }
+
+            // Route every event already received into its lane
+            while let Ok(event) = rx.try_recv() {
+                let prioritised = priority(&event.kind);
+                lanes.push(event, prioritised);
+            }
+
+            if let Some(event) = lanes.pop() {
+                if exchange_tx.send(event).is_err() {
+                    break;
+                }
+            }
+        }
+    });
+
+    (tx, task)
+}
+
+/// Priority & standard lane queues used by [`forward_prioritised`].
+#[derive(Debug)]
+struct Lanes<T> {
+    priority: VecDeque<T>,
+    standard: VecDeque<T>,
+}
+
+impl<T> Default for Lanes<T> {
+    fn default() -> Self {
+        Self {
+            priority: VecDeque::new(),
+            standard: VecDeque::new(),
+        }
+    }
+}
+
+impl<T> Lanes<T> {
+    fn push(&mut self, item: T, prioritised: bool) {
+        match prioritised {
+            true => self.priority.push_back(item),
+            false => self.standard.push_back(item),
+        }
+    }
+
+    /// Remove the next item, taking from the priority lane first.
+    fn pop(&mut self) -> Option<T> {
+        self.priority
+            .pop_front()
+            .or_else(|| self.standard.pop_front())
+    }
+
+    fn is_empty(&self) -> bool {
+        self.priority.is_empty() && self.standard.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::subscription::{
+        book::{Level, OrderBookL1},
+        trade::PublicTrade,
+    };
+    use barter_integration::model::Side;
+    use chrono::Utc;
+
+    #[test]
+    fn test_lane_of() {
+        let trade = DataKind::Trade(PublicTrade {
+            id: "1".to_owned(),
+            price: 1.0,
+            amount: 1.0,
+            side: Side::Buy,
+        });
+        assert_eq!(Lane::of(&trade), Some(Lane::Trades));
+        let book = DataKind::OrderBookL1(OrderBookL1 {
+            last_update_time: Utc::now(),
+            best_bid: Level::new(1.0, 1.0),
+            best_ask: Level::new(2.0, 1.0),
+        });
+        assert_eq!(Lane::of(&book), Some(Lane::OrderBooks));
+    }
+
+    #[test]
+    fn test_lanes() {
+        let mut lanes = Lanes::default();
+        lanes.push("book_1", false);
+        lanes.push("book_2", false);
+        lanes.push("trade_1", true);
+        lanes.push("book_3", false);
+        lanes.push("trade_2", true);
+
+        let mut actual = Vec::new();
+        while let Some(item) = lanes.pop() {
+            actual.push(item);
+        }
+        assert_eq!(
+            actual,
+            vec!["trade_1", "trade_2", "book_1", "book_2", "book_3"]
+        );
+        assert!(lanes.is_empty());
+    }
+}
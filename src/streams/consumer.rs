@@ -1,123 +1,941 @@
+use super::{
+    errors::StreamError,
+    health::{ConsumerTrackers, SubscriptionHealth, SubscriptionStatus},
+    hooks::ConsumerHooks,
+    lifecycle::MarketStreamEvent,
+    options::StreamOptions,
+    reconnect::ReconnectPolicy,
+};
+use crate::error::DataError;
 use crate::instrument::InstrumentData;
 use crate::{
-    error::DataError,
-    event::MarketEvent,
-    exchange::StreamSelector,
-    subscription::{Subscription, SubscriptionKind},
+    event::{MarketEvent, StreamEndReason, StreamEnded},
+    exchange::{
+        failover::{endpoints, rotate, url},
+        subscription::ExchangeSub,
+        Connector, ExchangeId, StreamSelector,
+    },
+    subscription::{SubKindId, Subscription, SubscriptionKind},
     Identifier, MarketStream,
 };
-use futures::StreamExt;
-use std::time::Duration;
+use barter_integration::{error::SocketError, model::SubscriptionId};
+use chrono::Utc;
+use futures::{future::BoxFuture, StreamExt};
+use std::{
+    collections::HashMap,
+    hash::Hash,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
 use tokio::sync::mpsc;
 use tracing::{debug, error, info, warn};
 
-/// Initial duration that the [`consume`] function should wait after disconnecting before attempting
-/// to re-initialise a [`MarketStream`]. This duration will increase exponentially as a result
-/// of repeated disconnections with re-initialisation failures.
+/// Default initial duration that the [`consume`] function should wait after disconnecting before
+/// attempting to re-initialise a [`MarketStream`]. This duration will increase exponentially as a
+/// result of repeated disconnections with re-initialisation failures.
+///
+/// See [`ReconnectPolicy`] to configure the backoff.
 pub const STARTING_RECONNECT_BACKOFF_MS: u64 = 125;
 
 /// Central [`MarketEvent<T>`](MarketEvent) consumer loop.
 ///
-/// Initialises an exchange [`MarketStream`] using a collection of [`Subscription`]s. Consumed
-/// events are distributed downstream via the `exchange_tx mpsc::UnboundedSender`. A re-connection
-/// mechanism with an exponential backoff policy is utilised to ensure maximum up-time.
+/// Initialises an exchange [`MarketStream`] using a collection of [`Subscription`]s and the
+/// provided per connection [`StreamOptions`]. Consumed events are distributed downstream via the
+/// `exchange_tx mpsc::UnboundedSender`. A re-connection mechanism with the provided exponential
+/// backoff [`ReconnectPolicy`] is utilised to ensure maximum up-time. If the initial connection
+/// fails on every [`Connector::urls`] endpoint, or the [`ReconnectPolicy`] is exhausted, the
+/// consumer loop ends.
+///
+/// Each failed connection attempt rotates to the next [`Connector::urls`] endpoint (see
+/// [`failover`](crate::exchange::failover)), so backup exchange servers are used if the primary
+/// is unavailable.
+///
+/// The [`SubscriptionStatus`] of each [`Subscription`] is tracked in the provided
+/// [`SubscriptionHealth`]. Once the consumer loop permanently ends (eg/ the downstream receiver
+/// was dropped), each [`Subscription`] is marked as [`SubscriptionStatus::Ended`] before the
+/// `exchange_tx` is dropped, and the associated [`StreamEnded`] terminal event is returned.
+///
+/// Connection lifecycle [`MarketStreamEvent`]s are notified to the
+/// [`LifecycleListeners`](super::lifecycle::LifecycleListeners) of the [`SubscriptionHealth`],
+/// and the provided [`ConsumerHooks`] are invoked for every consumed event, [`DataError`] and
+/// re-connection attempt.
+///
+/// Non-terminal [`DataError`]s are notified to the
+/// [`ErrorListeners`](super::errors::ErrorListeners) of the [`SubscriptionHealth`] as
+/// [`StreamError`]s, with the context of the originating [`Subscription`].
+///
+/// Events are buffered whilst the exchange is paused via the
+/// [`StreamsHandle`](super::pause::StreamsHandle) of the [`SubscriptionHealth`].
 pub async fn consume<Exchange, Instrument, Kind>(
     subscriptions: Vec<Subscription<Exchange, Instrument, Kind>>,
     exchange_tx: mpsc::UnboundedSender<MarketEvent<Instrument::Id, Kind::Event>>,
-) -> Result<(), DataError>
+    health: SubscriptionHealth,
+    policy: ReconnectPolicy,
+    options: StreamOptions,
+    hooks: ConsumerHooks<MarketEvent<Instrument::Id, Kind::Event>>,
+) -> StreamEnded
 where
     Exchange: StreamSelector<Instrument, Kind>,
     Kind: SubscriptionKind,
+    Kind::Event: Send + 'static,
     Instrument: InstrumentData,
+    Instrument::Id: Eq + Hash + 'static,
     Subscription<Exchange, Instrument, Kind>:
         Identifier<Exchange::Channel> + Identifier<Exchange::Market>,
 {
     // Determine ExchangeId associated with these Subscriptions
     let exchange = Exchange::ID;
 
+    // Buffer events whilst the exchange is paused via the StreamsHandle
+    let mut gate = health.handle().gate(exchange);
+
     info!(
         %exchange,
         ?subscriptions,
-        policy = "retry connection with exponential backoff",
+        ?policy,
         "MarketStream consumer loop running",
     );
 
-    // Consumer loop retry parameters
-    let mut attempt: u32 = 0;
-    let mut backoff_ms: u64 = STARTING_RECONNECT_BACKOFF_MS;
+    // Register each Subscription with the SubscriptionHealth
+    let trackers = ConsumerTrackers::register(&health, &subscriptions);
+    let set_status = |status: SubscriptionStatus| trackers.set(status);
+
+    // Instrument of each SubscriptionId, used to add context to reported StreamErrors
+    let instruments = subscriptions
+        .iter()
+        .map(|sub| {
+            (
+                ExchangeSub::<Exchange::Channel, Exchange::Market>::new(sub).id(),
+                format!("{:?}", sub.instrument.id()),
+            )
+        })
+        .collect::<HashMap<_, _>>();
+
+    // Consumer loop retry parameters, failing over between exchange endpoints independently of
+    // other consumer loops
+    let mut options = options;
+    let lifecycle = health.lifecycle();
+    let mut initialised = false;
+    let mut failed_attempts: u32 = 0;
 
-    'retry: loop {
-        // Increment retry parameters at start of every iteration
-        attempt += 1;
-        backoff_ms *= 2;
-        info!(%exchange, attempt, "attempting to initialise MarketStream");
+    let ended = 'retry: loop {
+        info!(%exchange, failed_attempts, "attempting to initialise MarketStream");
+        if initialised {
+            hooks.reconnect(exchange, failed_attempts + 1);
+            lifecycle.notify(
+                exchange,
+                MarketStreamEvent::Reconnecting {
+                    attempt: failed_attempts + 1,
+                },
+            );
+        }
 
-        // Attempt to initialise MarketStream: if it fails on first attempt return DataError
-        let mut stream = match Exchange::Stream::init(&subscriptions).await {
+        // Attempt to initialise MarketStream: if the initial connection fails end the consumer loop
+        let mut stream = match Exchange::Stream::init(&subscriptions, &options).await {
             Ok(stream) => {
-                info!(%exchange, attempt, "successfully initialised MarketStream");
-                attempt = 0;
-                backoff_ms = STARTING_RECONNECT_BACKOFF_MS;
+                info!(%exchange, failed_attempts, "successfully initialised MarketStream");
+                set_status(SubscriptionStatus::Validated);
+                lifecycle.notify(
+                    exchange,
+                    match initialised {
+                        true => MarketStreamEvent::Resubscribed,
+                        false => MarketStreamEvent::Connected,
+                    },
+                );
+                initialised = true;
+                failed_attempts = 0;
                 stream
             }
             Err(error) => {
-                error!(%exchange, attempt, ?error, "failed to initialise MarketStream");
+                error!(%exchange, failed_attempts, ?error, "failed to initialise MarketStream");
+                hooks.error(exchange, &error);
+                set_status(SubscriptionStatus::Errored {
+                    reason: error.to_string(),
+                });
+                failed_attempts += 1;
+                options.endpoint = failover::<Exchange>(options.endpoint, &error);
 
-                // Exit function if Stream::init failed the first attempt, else retry
-                if attempt == 1 {
-                    return Err(error);
+                // Exit function if the initial connection failed on every endpoint or the
+                // ReconnectPolicy is exhausted, else retry after backoff
+                let endpoints_tried = failed_attempts as usize >= endpoints::<Exchange>();
+                if (!initialised && endpoints_tried) || policy.exhausted(failed_attempts) {
+                    break 'retry StreamEnded::new(StreamEndReason::Error(error.to_string()));
                 } else {
+                    tokio::time::sleep(policy.backoff(failed_attempts)).await;
                     continue;
                 }
             }
         };
 
+        // SubscriptionStatus to report once the MarketStream ends
+        let mut end_status = SubscriptionStatus::Resubscribing;
+
         // Consume Result<MarketEvent<T>, DataError> from MarketStream
-        while let Some(event_result) = stream.next().await {
+        loop {
+            let event_result = tokio::select! {
+                event_result = stream.next() => match event_result {
+                    Some(event_result) => event_result,
+                    None => break,
+                },
+                // Distribute events buffered whilst paused once resumed
+                () = gate.resumed() => match gate.flush(&exchange_tx) {
+                    Ok(()) => continue,
+                    Err(_) => break 'retry StreamEnded::new(StreamEndReason::Shutdown),
+                },
+            };
+
             match event_result {
                 // If Ok: send MarketEvent<T> to exchange receiver
                 Ok(market_event) => {
-                    if let Err(error) = exchange_tx.send(market_event) {
+                    trackers.streamed(&market_event.instrument, market_event.received_time);
+                    hooks.message(exchange, &market_event);
+
+                    if let Err(error) = gate.send(market_event, &exchange_tx) {
                         debug!(
                             payload = ?error.0,
                             why = "receiver dropped",
                             action = "shutting down Stream",
                             "failed to send Event<MarketData> to Exchange receiver"
                         );
-                        break 'retry Ok(());
+                        break 'retry StreamEnded::new(StreamEndReason::Shutdown);
                     }
                 }
                 // If terminal DataError: break
                 Err(error) if error.is_terminal() => {
+                    hooks.error(exchange, &error);
                     error!(
                         %exchange,
                         %error,
                         action = "re-initialising Stream",
                         "consumed DataError from MarketStream",
                     );
+                    end_status = SubscriptionStatus::Errored {
+                        reason: error.to_string(),
+                    };
                     break;
                 }
 
                 // If non-terminal DataError: log & continue
                 Err(error) => {
+                    hooks.error(exchange, &error);
+                    report_rejection(&health, exchange, &error);
                     warn!(
                         %exchange,
                         %error,
                         action = "skipping message",
                         "consumed DataError from MarketStream",
                     );
+                    report_error(&health, exchange, Kind::ID, &instruments, error);
                     continue;
                 }
             }
         }
 
-        // If MarketStream ends unexpectedly, attempt re-connection after backoff_ms
+        lifecycle.notify(exchange, disconnected(&end_status));
+
+        // If a finite MarketStream (eg/ a replay) ends, it has yielded every available event
+        if <Exchange::Stream as MarketStream<Exchange, Instrument, Kind>>::FINITE {
+            break 'retry StreamEnded::new(match end_status {
+                SubscriptionStatus::Errored { reason } => StreamEndReason::Error(reason),
+                _ => StreamEndReason::Finished,
+            });
+        }
+
+        // If MarketStream ends unexpectedly, attempt re-connection after backoff
+        let backoff = policy.backoff(0);
         warn!(
             %exchange,
-            backoff_ms,
+            ?backoff,
             action = "attempt re-connection after backoff",
             "exchange MarketStream unexpectedly ended"
         );
-        tokio::time::sleep(Duration::from_millis(backoff_ms)).await;
+        set_status(end_status);
+        tokio::time::sleep(backoff).await;
+    };
+
+    info!(%exchange, ?ended, "MarketStream consumer loop ended");
+    let _ = gate.flush(&exchange_tx);
+    set_status(SubscriptionStatus::Ended {
+        reason: ended.reason.clone(),
+    });
+    ended
+}
+
+/// Central [`MarketEvent<T>`](MarketEvent) consumer loop for latency-critical [`Subscription`]s
+/// that maintains a warm-standby [`MarketStream`].
+///
+/// Behaves like [`consume`], except that a second [`MarketStream`] is initialised and validated
+/// with the same [`Subscription`]s, but its events are muted (consumed & discarded to keep the
+/// connection alive). When the primary [`MarketStream`] drops, the standby is promoted instantly,
+/// and a new standby is initialised in the background. This cuts the downtime associated with
+/// re-connecting & re-subscribing from seconds to the time taken to swap streams.
+///
+/// If no validated standby is available when the primary drops, the consumer loop waits for the
+/// in-flight standby initialisation, retrying with the provided [`ReconnectPolicy`] until it is
+/// exhausted.
+pub async fn consume_with_standby<Exchange, Instrument, Kind>(
+    subscriptions: Vec<Subscription<Exchange, Instrument, Kind>>,
+    exchange_tx: mpsc::UnboundedSender<MarketEvent<Instrument::Id, Kind::Event>>,
+    health: SubscriptionHealth,
+    policy: ReconnectPolicy,
+    options: StreamOptions,
+    hooks: ConsumerHooks<MarketEvent<Instrument::Id, Kind::Event>>,
+) -> StreamEnded
+where
+    Exchange: StreamSelector<Instrument, Kind>,
+    Kind: SubscriptionKind,
+    Kind::Event: Send + 'static,
+    Instrument: InstrumentData,
+    Instrument::Id: Eq + Hash + 'static,
+    Subscription<Exchange, Instrument, Kind>:
+        Identifier<Exchange::Channel> + Identifier<Exchange::Market> + Sync,
+{
+    // Determine ExchangeId associated with these Subscriptions
+    let exchange = Exchange::ID;
+
+    // Buffer events whilst the exchange is paused via the StreamsHandle
+    let mut gate = health.handle().gate(exchange);
+
+    info!(
+        %exchange,
+        ?subscriptions,
+        ?policy,
+        action = "promote warm-standby connection, else retry with backoff",
+        "MarketStream consumer loop running",
+    );
+
+    // Register each Subscription with the SubscriptionHealth
+    let trackers = ConsumerTrackers::register(&health, &subscriptions);
+    let set_status = |status: SubscriptionStatus| trackers.set(status);
+
+    // Instrument of each SubscriptionId, used to add context to reported StreamErrors
+    let instruments = subscriptions
+        .iter()
+        .map(|sub| {
+            (
+                ExchangeSub::<Exchange::Channel, Exchange::Market>::new(sub).id(),
+                format!("{:?}", sub.instrument.id()),
+            )
+        })
+        .collect::<HashMap<_, _>>();
+
+    // Active exchange endpoint, failed over independently of other consumer loops
+    let endpoint = AtomicUsize::new(options.endpoint);
+    let rotate_endpoint = |error: &DataError| {
+        let next = failover::<Exchange>(endpoint.load(Ordering::Relaxed), error);
+        endpoint.store(next, Ordering::Relaxed);
+    };
+
+    // Initialise a MarketStream via the active endpoint after waiting for the provided backoff
+    let connect = |backoff: Duration| -> BoxFuture<'_, Result<Exchange::Stream, DataError>> {
+        let subscriptions = &subscriptions;
+        let options = StreamOptions {
+            endpoint: endpoint.load(Ordering::Relaxed),
+            ..options.clone()
+        };
+        Box::pin(async move {
+            tokio::time::sleep(backoff).await;
+            Exchange::Stream::init(subscriptions, &options).await
+        })
+    };
+
+    // Attempt to initialise primary MarketStream: if it fails on every endpoint end the consumer loop
+    let mut initial = connect(Duration::ZERO).await;
+    for _ in 1..endpoints::<Exchange>() {
+        let Err(error) = &initial else {
+            break;
+        };
+        rotate_endpoint(error);
+        initial = connect(Duration::ZERO).await;
+    }
+
+    let mut primary = match initial {
+        Ok(stream) => {
+            info!(%exchange, "successfully initialised primary MarketStream");
+            set_status(SubscriptionStatus::Validated);
+            health
+                .lifecycle()
+                .notify(exchange, MarketStreamEvent::Connected);
+            stream
+        }
+        Err(error) => {
+            error!(%exchange, ?error, "failed to initialise primary MarketStream");
+            hooks.error(exchange, &error);
+            let ended = StreamEnded::new(StreamEndReason::Error(error.to_string()));
+            set_status(SubscriptionStatus::Ended {
+                reason: ended.reason.clone(),
+            });
+            return ended;
+        }
+    };
+
+    // Warm-standby MarketStream retry parameters
+    let mut standby = Standby::Connecting(connect(Duration::ZERO));
+    let mut standby_failed_attempts: u32 = 0;
+
+    let ended = 'consume: loop {
+        tokio::select! {
+            event_result = primary.next() => {
+                let end_status = match event_result {
+                    // If Ok: send MarketEvent<T> to exchange receiver
+                    Some(Ok(market_event)) => {
+                        trackers.streamed(&market_event.instrument, market_event.received_time);
+                        hooks.message(exchange, &market_event);
+
+                        if let Err(error) = gate.send(market_event, &exchange_tx) {
+                            debug!(
+                                payload = ?error.0,
+                                why = "receiver dropped",
+                                action = "shutting down Stream",
+                                "failed to send Event<MarketData> to Exchange receiver"
+                            );
+                            break 'consume StreamEnded::new(StreamEndReason::Shutdown);
+                        }
+                        continue;
+                    }
+
+                    // If non-terminal DataError: log & continue
+                    Some(Err(error)) if !error.is_terminal() => {
+                        hooks.error(exchange, &error);
+                        report_rejection(&health, exchange, &error);
+                        warn!(
+                            %exchange,
+                            %error,
+                            action = "skipping message",
+                            "consumed DataError from primary MarketStream",
+                        );
+                        report_error(&health, exchange, Kind::ID, &instruments, error);
+                        continue;
+                    }
+
+                    // If terminal DataError: promote standby
+                    Some(Err(error)) => {
+                        hooks.error(exchange, &error);
+                        error!(
+                            %exchange,
+                            %error,
+                            action = "promoting warm-standby MarketStream",
+                            "consumed DataError from primary MarketStream",
+                        );
+                        SubscriptionStatus::Errored {
+                            reason: error.to_string(),
+                        }
+                    }
+
+                    // If a finite primary (eg/ a replay) ends, it has yielded every available event
+                    None if <Exchange::Stream as MarketStream<Exchange, Instrument, Kind>>::FINITE => {
+                        break 'consume StreamEnded::new(StreamEndReason::Finished);
+                    }
+
+                    // If primary ends unexpectedly: promote standby
+                    None => {
+                        warn!(
+                            %exchange,
+                            action = "promoting warm-standby MarketStream",
+                            "primary MarketStream unexpectedly ended"
+                        );
+                        SubscriptionStatus::Resubscribing
+                    }
+                };
+
+                // Promote validated standby, else await the in-flight standby initialisation
+                health.lifecycle().notify(exchange, disconnected(&end_status));
+                primary = loop {
+                    match std::mem::replace(&mut standby, Standby::Connecting(connect(Duration::ZERO))) {
+                        Standby::Ready(stream) => {
+                            hooks.reconnect(exchange, standby_failed_attempts + 1);
+                            info!(%exchange, "promoted warm-standby MarketStream to primary");
+                            break stream;
+                        }
+                        Standby::Connecting(init) => {
+                            set_status(end_status.clone());
+                            hooks.reconnect(exchange, standby_failed_attempts + 1);
+                            health.lifecycle().notify(
+                                exchange,
+                                MarketStreamEvent::Reconnecting {
+                                    attempt: standby_failed_attempts + 1,
+                                },
+                            );
+                            match init.await {
+                                Ok(stream) => {
+                                    info!(%exchange, "initialised MarketStream to replace primary");
+                                    standby_failed_attempts = 0;
+                                    break stream;
+                                }
+                                Err(error) => {
+                                    error!(%exchange, ?error, "failed to initialise MarketStream");
+                                    hooks.error(exchange, &error);
+                                    rotate_endpoint(&error);
+                                    standby_failed_attempts += 1;
+                                    if policy.exhausted(standby_failed_attempts) {
+                                        break 'consume StreamEnded::new(StreamEndReason::Error(
+                                            error.to_string(),
+                                        ));
+                                    }
+                                    standby = Standby::Connecting(connect(
+                                        policy.backoff(standby_failed_attempts),
+                                    ));
+                                }
+                            }
+                        }
+                    }
+                };
+                set_status(SubscriptionStatus::Validated);
+                health
+                    .lifecycle()
+                    .notify(exchange, MarketStreamEvent::Resubscribed);
+            }
+
+            // Distribute events buffered whilst paused once resumed
+            () = gate.resumed() => {
+                if gate.flush(&exchange_tx).is_err() {
+                    break 'consume StreamEnded::new(StreamEndReason::Shutdown);
+                }
+            }
+
+            standby_update = standby.next() => match standby_update {
+                Ok(()) => standby_failed_attempts = 0,
+                Err(error) => {
+                    hooks.error(exchange, &error);
+                    rotate_endpoint(&error);
+                    let backoff = policy.backoff(standby_failed_attempts);
+                    warn!(
+                        %exchange,
+                        ?error,
+                        ?backoff,
+                        action = "re-initialise warm-standby after backoff",
+                        "warm-standby MarketStream failed"
+                    );
+                    standby = Standby::Connecting(connect(backoff));
+                    standby_failed_attempts += 1;
+                }
+            }
+        }
+    };
+
+    info!(%exchange, ?ended, "MarketStream consumer loop ended");
+    let _ = gate.flush(&exchange_tx);
+    set_status(SubscriptionStatus::Ended {
+        reason: ended.reason.clone(),
+    });
+    ended
+}
+
+/// Notify the [`ErrorListeners`](super::errors::ErrorListeners) of the [`SubscriptionHealth`] of
+/// a non-terminal [`DataError`], alongside the context of the originating [`Subscription`].
+fn report_error(
+    health: &SubscriptionHealth,
+    exchange: ExchangeId,
+    sub_kind: SubKindId,
+    instruments: &HashMap<SubscriptionId, String>,
+    error: DataError,
+) {
+    let subscription_id = error.subscription_id().cloned();
+    health.errors().notify(StreamError {
+        exchange,
+        sub_kind,
+        time: Utc::now(),
+        instrument: subscription_id
+            .as_ref()
+            .and_then(|subscription_id| instruments.get(subscription_id).cloned()),
+        subscription_id,
+        error: Arc::new(error),
+    });
+}
+
+/// Rotate from the provided endpoint index to the next [`Connector::urls`] endpoint after a
+/// failed connection attempt, if the exchange advertises any backup endpoints. Returns the new
+/// endpoint index.
+fn failover<Exchange>(endpoint: usize, error: &DataError) -> usize
+where
+    Exchange: Connector,
+{
+    if endpoints::<Exchange>() <= 1 {
+        return endpoint;
+    }
+
+    let endpoint = rotate::<Exchange>(endpoint);
+    match url::<Exchange>(endpoint) {
+        Ok(url) => warn!(
+            exchange = %Exchange::ID,
+            %error,
+            %url,
+            "rotated to next exchange endpoint after connection failure"
+        ),
+        Err(error) => error!(
+            exchange = %Exchange::ID,
+            %error,
+            "failed to rotate exchange endpoint"
+        ),
+    }
+    endpoint
+}
+
+/// Construct the [`MarketStreamEvent::Disconnected`] associated with the [`SubscriptionStatus`]
+/// reported once a [`MarketStream`] ends.
+fn disconnected(end_status: &SubscriptionStatus) -> MarketStreamEvent {
+    MarketStreamEvent::Disconnected {
+        reason: match end_status {
+            SubscriptionStatus::Errored { reason } => reason.clone(),
+            _ => "MarketStream ended".to_owned(),
+        },
+    }
+}
+
+/// Report the [`Subscription`] of a [`DataError::SubscriptionRejected`] as
+/// [`SubscriptionStatus::Rejected`] in the [`SubscriptionHealth`].
+fn report_rejection(health: &SubscriptionHealth, exchange: ExchangeId, error: &DataError) {
+    if let DataError::SubscriptionRejected {
+        subscription_id,
+        reason,
+    } = error
+    {
+        if let Some(tracker) = health.tracker(&(exchange, subscription_id.clone())) {
+            tracker.set(SubscriptionStatus::Rejected {
+                reason: reason.clone(),
+            });
+        }
+    }
+}
+
+/// Muted warm-standby [`MarketStream`] maintained by [`consume_with_standby`].
+enum Standby<'a, Stream> {
+    Connecting(BoxFuture<'a, Result<Stream, DataError>>),
+    Ready(Stream),
+}
+
+impl<Stream, Event> Standby<'_, Stream>
+where
+    Stream: futures::Stream<Item = Result<Event, DataError>> + Unpin,
+{
+    /// Drive the standby [`MarketStream`], discarding any consumed events.
+    ///
+    /// Returns `Ok(())` once the standby makes progress, or `Err` if it failed and must be
+    /// re-initialised. Cancel safe, since the in-flight initialisation is held in `self`.
+    async fn next(&mut self) -> Result<(), DataError> {
+        match self {
+            Standby::Connecting(init) => {
+                *self = Standby::Ready(init.await?);
+                Ok(())
+            }
+            Standby::Ready(stream) => match stream.next().await {
+                Some(Ok(_)) => Ok(()),
+                Some(Err(error)) if !error.is_terminal() => Ok(()),
+                Some(Err(error)) => Err(error),
+                None => Err(DataError::Socket(SocketError::Terminated(
+                    "warm-standby MarketStream ended".to_owned(),
+                ))),
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        event::FeedEvent,
+        streams::{lifecycle::MarketStreamEvent, Streams},
+        subscriber::{validator::WebSocketSubValidator, WebSocketSubscriber},
+        subscription::trade::{PublicTrade, PublicTrades},
+    };
+    use async_trait::async_trait;
+    use barter_integration::{
+        model::{
+            instrument::{kind::InstrumentKind, Instrument},
+            Exchange, Side,
+        },
+        protocol::websocket::WsMessage,
+    };
+    use serde::{Deserialize, Serialize};
+    use std::{cell::RefCell, collections::VecDeque, future::Future};
+    use tokio_stream::wrappers::UnboundedReceiverStream;
+    use url::Url;
+
+    type MockEvent = Result<MarketEvent<Instrument, PublicTrade>, DataError>;
+
+    thread_local! {
+        /// Scripted outcome of each [`MockStream::init`], failing once exhausted.
+        static CONNECTIONS: RefCell<VecDeque<mpsc::UnboundedReceiver<MockEvent>>> =
+            RefCell::default();
+    }
+
+    /// Script the next [`MockStream::init`] to succeed, returning the sender of its events.
+    fn connection() -> mpsc::UnboundedSender<MockEvent> {
+        let (tx, rx) = mpsc::unbounded_channel();
+        CONNECTIONS.with(|connections| connections.borrow_mut().push_back(rx));
+        tx
+    }
+
+    #[derive(Copy, Clone, Debug, Default, Deserialize, Serialize)]
+    struct MockExchange<const FINITE: bool>;
+
+    impl<const FINITE: bool> Connector for MockExchange<FINITE> {
+        const ID: ExchangeId = ExchangeId::Coinbase;
+        type Channel = String;
+        type Market = String;
+        type Subscriber = WebSocketSubscriber;
+        type SubValidator = WebSocketSubValidator;
+        type SubResponse = crate::exchange::coinbase::subscription::CoinbaseSubResponse;
+
+        fn url() -> Result<Url, SocketError> {
+            Url::parse("ws://mock").map_err(SocketError::UrlParse)
+        }
+
+        fn requests(_: Vec<ExchangeSub<Self::Channel, Self::Market>>) -> Vec<WsMessage> {
+            vec![]
+        }
+    }
+
+    impl<const FINITE: bool> StreamSelector<Instrument, PublicTrades> for MockExchange<FINITE> {
+        type Stream = MockStream<FINITE>;
+    }
+
+    impl<const FINITE: bool> Identifier<String>
+        for Subscription<MockExchange<FINITE>, Instrument, PublicTrades>
+    {
+        fn id(&self) -> String {
+            self.instrument.to_string()
+        }
+    }
+
+    struct MockStream<const FINITE: bool>(UnboundedReceiverStream<MockEvent>);
+
+    impl<const FINITE: bool> futures::Stream for MockStream<FINITE> {
+        type Item = MockEvent;
+
+        fn poll_next(
+            mut self: std::pin::Pin<&mut Self>,
+            cx: &mut std::task::Context<'_>,
+        ) -> std::task::Poll<Option<Self::Item>> {
+            std::pin::Pin::new(&mut self.0).poll_next(cx)
+        }
+    }
+
+    #[async_trait]
+    impl<const FINITE: bool> MarketStream<MockExchange<FINITE>, Instrument, PublicTrades>
+        for MockStream<FINITE>
+    {
+        const FINITE: bool = FINITE;
+
+        async fn init(
+            _: &[Subscription<MockExchange<FINITE>, Instrument, PublicTrades>],
+            _: &StreamOptions,
+        ) -> Result<Self, DataError> {
+            CONNECTIONS
+                .with(|connections| connections.borrow_mut().pop_front())
+                .map(|rx| Self(UnboundedReceiverStream::new(rx)))
+                .ok_or(DataError::Socket(SocketError::Sink))
+        }
+    }
+
+    fn instrument() -> Instrument {
+        Instrument::from(("btc", "usd", InstrumentKind::Spot))
+    }
+
+    fn trade(id: &str) -> MockEvent {
+        Ok(MarketEvent {
+            exchange_time: Utc::now(),
+            received_time: Utc::now(),
+            exchange: Exchange::from(ExchangeId::Coinbase),
+            instrument: instrument(),
+            kind: PublicTrade {
+                id: id.to_owned(),
+                price: 1.0,
+                amount: 1.0,
+                side: Side::Buy,
+            },
+        })
+    }
+
+    /// Run the provided consumer loop (eg/ [`consume`]) for [`MockExchange`] alongside the
+    /// provided script, returning the [`FeedEvent`] trade ids terminated by the [`StreamEnded`]
+    /// event, the [`StreamEnded`] returned, and every [`MarketStreamEvent`] notified.
+    async fn run<const FINITE: bool, Consumer, ConsumerFut>(
+        consumer: Consumer,
+        policy: ReconnectPolicy,
+        script: impl Future<Output = ()>,
+    ) -> (Vec<FeedEvent<String>>, StreamEnded, Vec<MarketStreamEvent>)
+    where
+        Consumer: FnOnce(
+            Vec<Subscription<MockExchange<FINITE>, Instrument, PublicTrades>>,
+            mpsc::UnboundedSender<MarketEvent<Instrument, PublicTrade>>,
+            SubscriptionHealth,
+            ReconnectPolicy,
+            StreamOptions,
+            ConsumerHooks<MarketEvent<Instrument, PublicTrade>>,
+        ) -> ConsumerFut,
+        ConsumerFut: Future<Output = StreamEnded>,
+    {
+        let (exchange_tx, exchange_rx) = mpsc::unbounded_channel();
+        let health = SubscriptionHealth::default();
+        let mut lifecycle_rx = health.lifecycle().subscribe();
+        let consumer = consumer(
+            vec![Subscription::new(
+                MockExchange::<FINITE>,
+                instrument(),
+                PublicTrades,
+            )],
+            exchange_tx,
+            health.clone(),
+            policy,
+            StreamOptions::default(),
+            ConsumerHooks::default(),
+        );
+
+        let streams = Streams::new(HashMap::from([(ExchangeId::Coinbase, exchange_rx)]), health)
+            .with_end()
+            .map(|event| match event {
+                FeedEvent::Item(event) => FeedEvent::Item(event.kind.id),
+                FeedEvent::Ended(ended) => FeedEvent::Ended(ended),
+            });
+
+        let (ended, events, ()) = futures::join!(consumer, streams.collect::<Vec<_>>(), script);
+
+        let mut lifecycle = Vec::new();
+        while let Ok(event) = lifecycle_rx.try_recv() {
+            lifecycle.push(event.event);
+        }
+
+        (events, ended, lifecycle)
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_consume_finite_stream_finished() {
+        let tx = connection();
+        tx.send(trade("1")).unwrap();
+        tx.send(trade("2")).unwrap();
+        drop(tx);
+
+        let ended = StreamEnded::new(StreamEndReason::Finished);
+        let (events, actual, _) =
+            run::<true, _, _>(consume, ReconnectPolicy::default(), async {}).await;
+        assert_eq!(actual, ended);
+        assert_eq!(
+            events,
+            vec![
+                FeedEvent::Item("1".to_owned()),
+                FeedEvent::Item("2".to_owned()),
+                FeedEvent::Ended(ended),
+            ]
+        );
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_consume_exhausted_ended_in_stream() {
+        // Connection ends, and every re-initialisation fails until the ReconnectPolicy is exhausted
+        let tx = connection();
+        tx.send(trade("1")).unwrap();
+        drop(tx);
+
+        let policy = ReconnectPolicy {
+            max_retries: Some(2),
+            ..Default::default()
+        };
+        let (events, actual, _) = run::<false, _, _>(consume, policy, async {}).await;
+        assert!(matches!(actual.reason, StreamEndReason::Error(_)));
+        assert_eq!(
+            events,
+            vec![FeedEvent::Item("1".to_owned()), FeedEvent::Ended(actual)]
+        );
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_consume_with_standby_promoted_on_primary_failure() {
+        let primary = connection();
+        let standby = connection();
+
+        let script = async move {
+            primary.send(trade("1")).unwrap();
+            tokio::time::sleep(Duration::from_secs(1)).await;
+
+            // Warm-standby connected up front, before the primary failed
+            CONNECTIONS.with(|connections| assert!(connections.borrow().is_empty()));
+
+            // Terminal error fails the primary, promoting the warm-standby without reconnecting
+            primary
+                .send(Err(DataError::BookDesynchronised("gap".to_owned())))
+                .unwrap();
+            tokio::time::sleep(Duration::from_secs(1)).await;
+
+            standby.send(trade("2")).unwrap();
+            drop(standby);
+        };
+
+        let ended = StreamEnded::new(StreamEndReason::Finished);
+        let (events, actual, lifecycle) =
+            run::<true, _, _>(consume_with_standby, ReconnectPolicy::default(), script).await;
+        assert_eq!(actual, ended);
+        assert_eq!(
+            events,
+            vec![
+                FeedEvent::Item("1".to_owned()),
+                FeedEvent::Item("2".to_owned()),
+                FeedEvent::Ended(ended),
+            ]
+        );
+        assert_eq!(
+            lifecycle,
+            vec![
+                MarketStreamEvent::Connected,
+                MarketStreamEvent::Disconnected {
+                    reason: DataError::BookDesynchronised("gap".to_owned()).to_string(),
+                },
+                MarketStreamEvent::Resubscribed,
+            ]
+        );
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_consume_with_standby_primary_recovers_without_ready_standby() {
+        // Only the primary connects, so the warm-standby is backing off when the primary fails
+        let primary = connection();
+
+        let script = async move {
+            primary.send(trade("1")).unwrap();
+            tokio::time::sleep(Duration::from_secs(1)).await;
+
+            // Primary fails, so the consumer awaits the in-flight standby initialisation
+            let replacement = connection();
+            primary
+                .send(Err(DataError::BookDesynchronised("gap".to_owned())))
+                .unwrap();
+            tokio::time::sleep(Duration::from_secs(30)).await;
+
+            replacement.send(trade("2")).unwrap();
+            drop(replacement);
+        };
+
+        let policy = ReconnectPolicy {
+            initial_backoff: Duration::from_secs(10),
+            ..Default::default()
+        };
+        let ended = StreamEnded::new(StreamEndReason::Finished);
+        let (events, actual, lifecycle) =
+            run::<true, _, _>(consume_with_standby, policy, script).await;
+        assert_eq!(actual, ended);
+        assert_eq!(
+            events,
+            vec![
+                FeedEvent::Item("1".to_owned()),
+                FeedEvent::Item("2".to_owned()),
+                FeedEvent::Ended(ended),
+            ]
+        );
+        assert!(
+            matches!(
+                lifecycle.as_slice(),
+                [
+                    MarketStreamEvent::Connected,
+                    MarketStreamEvent::Disconnected { .. },
+                    MarketStreamEvent::Reconnecting { .. },
+                    MarketStreamEvent::Resubscribed,
+                ]
+            ),
+            "unexpected lifecycle: {lifecycle:?}"
+        );
     }
 }
@@ -0,0 +1,111 @@
+use crate::{error::DataError, exchange::ExchangeId, subscription::SubKindId};
+use barter_integration::model::SubscriptionId;
+use chrono::{DateTime, Utc};
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+use tokio::sync::mpsc;
+
+/// Non-terminal [`DataError`] consumed from a [`MarketStream`](crate::MarketStream), alongside
+/// the context of the [`Subscription`](crate::subscription::Subscription) it originated from.
+///
+/// Non-terminal errors (eg/ rejected subscriptions, OrderBook discrepancies, undeserialisable
+/// messages) do not end the connection, so this allows consumers to react programmatically to
+/// bad data.
+#[derive(Clone, Debug)]
+pub struct StreamError {
+    pub exchange: ExchangeId,
+    pub sub_kind: SubKindId,
+    pub time: DateTime<Utc>,
+    /// [`SubscriptionId`] of the originating [`Subscription`](crate::subscription::Subscription),
+    /// if it is known.
+    pub subscription_id: Option<SubscriptionId>,
+    /// `Debug` representation of the originating instrument, if it is known.
+    pub instrument: Option<String>,
+    pub error: Arc<DataError>,
+}
+
+/// Shared, cloneable registry of [`StreamError`] listeners, notified by the
+/// [`consumer`](super::consumer) loops.
+///
+/// Errors are only delivered to listeners subscribed at the time they occur.
+#[derive(Clone, Debug)]
+pub struct ErrorListeners {
+    registries: Vec<Registry>,
+}
+
+type Registry = Arc<Mutex<Vec<mpsc::UnboundedSender<StreamError>>>>;
+
+impl Default for ErrorListeners {
+    fn default() -> Self {
+        Self {
+            registries: vec![Registry::default()],
+        }
+    }
+}
+
+impl ErrorListeners {
+    /// Merge the listeners of another [`ErrorListeners`] into this one, such that subsequent
+    /// subscribers also receive the errors it is notified of.
+    pub fn merge(&mut self, other: ErrorListeners) {
+        self.registries.extend(other.registries);
+    }
+
+    /// Subscribe to every subsequent [`StreamError`].
+    pub fn subscribe(&self) -> mpsc::UnboundedReceiver<StreamError> {
+        let (tx, rx) = mpsc::unbounded_channel();
+        self.registries
+            .iter()
+            .for_each(|registry| lock(registry).push(tx.clone()));
+        rx
+    }
+
+    /// Notify every subscribed listener of the [`StreamError`], removing any that have been
+    /// dropped.
+    pub fn notify(&self, error: StreamError) {
+        self.registries.iter().for_each(|registry| {
+            lock(registry).retain(|listener| listener.send(error.clone()).is_ok())
+        });
+    }
+}
+
+fn lock(registry: &Registry) -> MutexGuard<'_, Vec<mpsc::UnboundedSender<StreamError>>> {
+    registry.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::subscription::SubKind;
+
+    #[test]
+    fn test_error_listeners() {
+        let error = |reason: &str| StreamError {
+            exchange: ExchangeId::Kraken,
+            sub_kind: SubKindId::Kind(SubKind::PublicTrades),
+            time: Utc::now(),
+            subscription_id: Some(SubscriptionId::from("trade|XBT/USD")),
+            instrument: None,
+            error: Arc::new(DataError::SubscriptionRejected {
+                subscription_id: SubscriptionId::from("trade|XBT/USD"),
+                reason: reason.to_owned(),
+            }),
+        };
+
+        let listeners = ErrorListeners::default();
+        let mut merged = ErrorListeners::default();
+        merged.merge(listeners.clone());
+
+        // Errors that occur before subscribing are not delivered
+        listeners.notify(error("first"));
+        let mut rx = merged.subscribe();
+        assert!(rx.try_recv().is_err());
+
+        // Errors notified to a merged registry are delivered
+        listeners.notify(error("second"));
+        let actual = rx.try_recv().unwrap();
+        assert_eq!(actual.exchange, ExchangeId::Kraken);
+        assert_eq!(
+            actual.error.to_string(),
+            "Subscription trade|XBT/USD rejected by exchange: second"
+        );
+    }
+}
@@ -0,0 +1,515 @@
+use super::{errors::ErrorListeners, lifecycle::LifecycleListeners, pause::StreamsHandle};
+use crate::{
+    event::{StreamEndReason, StreamEnded},
+    exchange::{subscription::ExchangeSub, Connector, ExchangeId},
+    instrument::InstrumentData,
+    subscription::Subscription,
+    Identifier,
+};
+use barter_integration::model::SubscriptionId;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::HashMap,
+    hash::Hash,
+    sync::{
+        atomic::{AtomicBool, AtomicI64, Ordering},
+        Arc, Mutex, RwLock,
+    },
+    time::Duration,
+};
+
+/// Default [`Duration`] without any data after which a [`SubscriptionStatus::Validated`] or
+/// [`SubscriptionStatus::Streaming`] [`Subscription`] is reported as [`SubscriptionStatus::Stale`].
+pub const DEFAULT_STALE_THRESHOLD: Duration = Duration::from_secs(30);
+
+/// Status of an individual [`Subscription`], as reported by [`SubscriptionHealth`].
+#[derive(Clone, Eq, PartialEq, Hash, Debug, Deserialize, Serialize)]
+pub enum SubscriptionStatus {
+    /// [`Subscription`] was successfully validated by the exchange, but no data has been
+    /// received yet.
+    Validated,
+
+    /// [`Subscription`] is receiving data.
+    Streaming,
+
+    /// [`Subscription`] has not received any data since the provided time.
+    Stale { since: DateTime<Utc> },
+
+    /// [`Subscription`] connection failed, and is awaiting re-initialisation.
+    Errored { reason: String },
+
+    /// [`Subscription`] connection ended, and is being re-initialised.
+    Resubscribing,
+
+    /// [`Subscription`] feed has permanently ended, and will not be re-initialised.
+    Ended { reason: StreamEndReason },
+
+    /// [`Subscription`] was rejected by the exchange, whilst the other [`Subscription`]s on the
+    /// same connection continued with the
+    /// [`SubscriptionFailurePolicy::ContinueWithAccepted`](crate::subscriber::validator::SubscriptionFailurePolicy)
+    /// policy.
+    Rejected { reason: String },
+}
+
+/// Unique key of a [`Subscription`] tracked by [`SubscriptionHealth`].
+///
+/// eg/ (ExchangeId::BinanceSpot, "@trade|BTCUSDT")
+pub type SubscriptionKey = (ExchangeId, SubscriptionId);
+
+/// Shared, cloneable registry of per [`Subscription`] [`SubscriptionStatus`]es.
+///
+/// Populated by the [`consume`](super::consumer::consume) loops driving each
+/// [`MarketStream`](crate::MarketStream), allowing orchestration layers to restart or re-route
+/// individual instruments rather than whole exchange connections.
+#[derive(Clone, Debug)]
+pub struct SubscriptionHealth {
+    stale_threshold: Duration,
+    registries: Vec<Registry>,
+    lifecycle: LifecycleListeners,
+    errors: ErrorListeners,
+    handle: StreamsHandle,
+}
+
+type Registry = Arc<RwLock<HashMap<SubscriptionKey, SubscriptionTracker>>>;
+
+impl Default for SubscriptionHealth {
+    fn default() -> Self {
+        Self {
+            stale_threshold: DEFAULT_STALE_THRESHOLD,
+            registries: vec![Registry::default()],
+            lifecycle: LifecycleListeners::default(),
+            errors: ErrorListeners::default(),
+            handle: StreamsHandle::default(),
+        }
+    }
+}
+
+impl SubscriptionHealth {
+    /// Construct a [`SubscriptionHealth`] handle that reports [`SubscriptionStatus::Stale`] once
+    /// a [`Subscription`] has not received any data for the provided threshold.
+    pub fn with_stale_threshold(self, stale_threshold: Duration) -> Self {
+        Self {
+            stale_threshold,
+            ..self
+        }
+    }
+
+    /// Merge the [`Subscription`]s tracked by another [`SubscriptionHealth`] into this one.
+    pub fn merge(&mut self, other: SubscriptionHealth) {
+        self.registries.extend(other.registries);
+        self.lifecycle.merge(other.lifecycle);
+        self.errors.merge(other.errors);
+        self.handle.merge(other.handle);
+    }
+
+    /// [`LifecycleListeners`] notified of the connection lifecycle events of every tracked
+    /// [`Subscription`]'s consumer loop.
+    pub fn lifecycle(&self) -> &LifecycleListeners {
+        &self.lifecycle
+    }
+
+    /// [`ErrorListeners`] notified of the non-terminal errors consumed by every tracked
+    /// [`Subscription`]'s consumer loop.
+    pub fn errors(&self) -> &ErrorListeners {
+        &self.errors
+    }
+
+    /// [`StreamsHandle`] used to pause & resume the delivery of events from the connections of
+    /// every tracked [`Subscription`].
+    pub fn handle(&self) -> &StreamsHandle {
+        &self.handle
+    }
+
+    /// Register a [`Subscription`], returning the [`SubscriptionTracker`] used to update its
+    /// [`SubscriptionStatus`].
+    ///
+    /// If the [`Subscription`] is already tracked by any merged [`SubscriptionHealth`], its
+    /// existing [`SubscriptionTracker`] is returned. Otherwise, it is tracked by this
+    /// [`SubscriptionHealth`].
+    pub fn register<Exchange, Instrument, Kind>(
+        &self,
+        subscription: &Subscription<Exchange, Instrument, Kind>,
+    ) -> SubscriptionTracker
+    where
+        Exchange: Connector,
+        Subscription<Exchange, Instrument, Kind>:
+            Identifier<Exchange::Channel> + Identifier<Exchange::Market>,
+    {
+        let key = key(subscription);
+        if let Some(tracker) = self.tracker(&key) {
+            return tracker;
+        }
+
+        // Registries of merged SubscriptionHealths follow this SubscriptionHealth's own registry
+        self.registries[0]
+            .write()
+            .expect("SubscriptionHealth lock poisoned")
+            .entry(key)
+            .or_default()
+            .clone()
+    }
+
+    /// Determine the [`SubscriptionStatus`] of the provided [`Subscription`].
+    ///
+    /// Returns `None` if the [`Subscription`] is not (yet) being tracked.
+    pub fn status_of<Exchange, Instrument, Kind>(
+        &self,
+        subscription: &Subscription<Exchange, Instrument, Kind>,
+    ) -> Option<SubscriptionStatus>
+    where
+        Exchange: Connector,
+        Subscription<Exchange, Instrument, Kind>:
+            Identifier<Exchange::Channel> + Identifier<Exchange::Market>,
+    {
+        self.status(&key(subscription))
+    }
+
+    /// [`SubscriptionTracker`] associated with the provided [`SubscriptionKey`], if it is being
+    /// tracked.
+    pub fn tracker(&self, key: &SubscriptionKey) -> Option<SubscriptionTracker> {
+        self.registries.iter().find_map(|registry| {
+            registry
+                .read()
+                .expect("SubscriptionHealth lock poisoned")
+                .get(key)
+                .cloned()
+        })
+    }
+
+    /// Determine the [`SubscriptionStatus`] associated with the provided [`SubscriptionKey`].
+    ///
+    /// Returns `None` if the [`SubscriptionKey`] is not (yet) being tracked.
+    pub fn status(&self, key: &SubscriptionKey) -> Option<SubscriptionStatus> {
+        self.registries.iter().find_map(|registry| {
+            registry
+                .read()
+                .expect("SubscriptionHealth lock poisoned")
+                .get(key)
+                .map(|tracker| tracker.status(self.stale_threshold))
+        })
+    }
+
+    /// Determine if every [`Subscription`] tracked for the provided [`ExchangeId`] has permanently
+    /// ended, returning the [`StreamEnded`] terminal event if so.
+    ///
+    /// Returns `None` if no [`Subscription`]s are tracked for the [`ExchangeId`], or at least one
+    /// is still live.
+    pub fn ended(&self, exchange: ExchangeId) -> Option<StreamEnded> {
+        let mut ended = None;
+        for (_, status) in self
+            .statuses()
+            .into_iter()
+            .filter(|((sub_exchange, _), _)| *sub_exchange == exchange)
+        {
+            match status {
+                SubscriptionStatus::Ended { reason } => {
+                    ended.get_or_insert(StreamEnded::new(reason));
+                }
+                _ => return None,
+            }
+        }
+        ended
+    }
+
+    /// Snapshot the [`SubscriptionStatus`] of every tracked [`Subscription`].
+    pub fn statuses(&self) -> HashMap<SubscriptionKey, SubscriptionStatus> {
+        self.registries
+            .iter()
+            .flat_map(|registry| {
+                registry
+                    .read()
+                    .expect("SubscriptionHealth lock poisoned")
+                    .iter()
+                    .map(|(key, tracker)| (key.clone(), tracker.status(self.stale_threshold)))
+                    .collect::<Vec<_>>()
+            })
+            .collect()
+    }
+}
+
+/// Determine the [`SubscriptionKey`] of the provided [`Subscription`].
+pub(crate) fn key<Exchange, Instrument, Kind>(
+    subscription: &Subscription<Exchange, Instrument, Kind>,
+) -> SubscriptionKey
+where
+    Exchange: Connector,
+    Subscription<Exchange, Instrument, Kind>:
+        Identifier<Exchange::Channel> + Identifier<Exchange::Market>,
+{
+    (
+        Exchange::ID,
+        ExchangeSub::<Exchange::Channel, Exchange::Market>::new(subscription).id(),
+    )
+}
+
+/// Handle used to update the [`SubscriptionStatus`] of a single tracked [`Subscription`].
+#[derive(Clone, Debug, Default)]
+pub struct SubscriptionTracker {
+    state: Arc<TrackerState>,
+}
+
+/// [`SubscriptionTracker`] state, where the time of the latest data is updated atomically so
+/// that [`SubscriptionTracker::streamed`] never locks whilst already streaming.
+#[derive(Debug)]
+struct TrackerState {
+    status: Mutex<SubscriptionStatus>,
+    streaming: AtomicBool,
+    updated_micros: AtomicI64,
+}
+
+impl Default for TrackerState {
+    fn default() -> Self {
+        Self {
+            status: Mutex::new(SubscriptionStatus::Resubscribing),
+            streaming: AtomicBool::new(false),
+            updated_micros: AtomicI64::new(Utc::now().timestamp_micros()),
+        }
+    }
+}
+
+impl SubscriptionTracker {
+    /// Set the current [`SubscriptionStatus`].
+    pub fn set(&self, status: SubscriptionStatus) {
+        let mut current = self
+            .state
+            .status
+            .lock()
+            .expect("SubscriptionTracker lock poisoned");
+        self.state.streaming.store(
+            matches!(status, SubscriptionStatus::Streaming),
+            Ordering::Release,
+        );
+        self.state
+            .updated_micros
+            .store(Utc::now().timestamp_micros(), Ordering::Release);
+        *current = status;
+    }
+
+    /// Record that data was received at the provided time, setting the [`SubscriptionStatus`] to
+    /// [`SubscriptionStatus::Streaming`].
+    ///
+    /// Only the first call after a status change locks, so this is cheap to invoke for every
+    /// event.
+    pub fn streamed(&self, time: DateTime<Utc>) {
+        if self.state.streaming.load(Ordering::Acquire) {
+            self.state
+                .updated_micros
+                .fetch_max(time.timestamp_micros(), Ordering::AcqRel);
+        } else {
+            self.set(SubscriptionStatus::Streaming);
+        }
+    }
+
+    /// Determine the current [`SubscriptionStatus`], reporting [`SubscriptionStatus::Stale`] if
+    /// no data has been received within the provided threshold.
+    pub fn status(&self, stale_threshold: Duration) -> SubscriptionStatus {
+        let status = self
+            .state
+            .status
+            .lock()
+            .expect("SubscriptionTracker lock poisoned");
+        let updated =
+            DateTime::from_timestamp_micros(self.state.updated_micros.load(Ordering::Acquire))
+                .unwrap_or_default();
+
+        match &*status {
+            SubscriptionStatus::Validated | SubscriptionStatus::Streaming
+                if (Utc::now() - updated)
+                    .to_std()
+                    .is_ok_and(|elapsed| elapsed > stale_threshold) =>
+            {
+                SubscriptionStatus::Stale { since: updated }
+            }
+            status => status.clone(),
+        }
+    }
+}
+
+/// [`SubscriptionTracker`]s of the [`Subscription`]s driven by a single consumer loop, keyed by
+/// [`SubscriptionKey`], alongside the trackers associated with each instrument.
+///
+/// Subscriptions to the same instrument (eg/ via different channels) are tracked independently.
+#[derive(Debug)]
+pub(crate) struct ConsumerTrackers<InstrumentId> {
+    trackers: HashMap<SubscriptionKey, SubscriptionTracker>,
+    instruments: HashMap<InstrumentId, Vec<SubscriptionTracker>>,
+}
+
+impl<InstrumentId> ConsumerTrackers<InstrumentId>
+where
+    InstrumentId: Clone + Eq + Hash,
+{
+    /// Register each [`Subscription`] with the provided [`SubscriptionHealth`].
+    pub(crate) fn register<Exchange, Instrument, Kind>(
+        health: &SubscriptionHealth,
+        subscriptions: &[Subscription<Exchange, Instrument, Kind>],
+    ) -> Self
+    where
+        Exchange: Connector,
+        Instrument: InstrumentData<Id = InstrumentId>,
+        Subscription<Exchange, Instrument, Kind>:
+            Identifier<Exchange::Channel> + Identifier<Exchange::Market>,
+    {
+        let mut trackers = HashMap::with_capacity(subscriptions.len());
+        let mut instruments = HashMap::<_, Vec<_>>::with_capacity(subscriptions.len());
+
+        for subscription in subscriptions {
+            let key = key(subscription);
+            if trackers.contains_key(&key) {
+                continue;
+            }
+
+            let tracker = health.register(subscription);
+            instruments
+                .entry(subscription.instrument.id().clone())
+                .or_default()
+                .push(tracker.clone());
+            trackers.insert(key, tracker);
+        }
+
+        Self {
+            trackers,
+            instruments,
+        }
+    }
+
+    /// Set the [`SubscriptionStatus`] of every tracked [`Subscription`].
+    pub(crate) fn set(&self, status: SubscriptionStatus) {
+        self.trackers
+            .values()
+            .for_each(|tracker| tracker.set(status.clone()))
+    }
+
+    /// Record that data was received at the provided time for the [`Subscription`]s of the
+    /// provided instrument.
+    pub(crate) fn streamed(&self, instrument: &InstrumentId, time: DateTime<Utc>) {
+        if let Some(trackers) = self.instruments.get(instrument) {
+            trackers.iter().for_each(|tracker| tracker.streamed(time))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{exchange::coinbase::Coinbase, subscription::trade::PublicTrades};
+    use barter_integration::model::instrument::{kind::InstrumentKind, Instrument};
+
+    fn subscription(base: &str) -> Subscription<Coinbase, Instrument, PublicTrades> {
+        Subscription::from((Coinbase, base, "usd", InstrumentKind::Spot, PublicTrades))
+    }
+
+    #[test]
+    fn test_subscription_health() {
+        let health = SubscriptionHealth::default();
+        let mut other = SubscriptionHealth::default();
+
+        let btc = health.register(&subscription("btc"));
+        assert_eq!(
+            health.status_of(&subscription("btc")),
+            Some(SubscriptionStatus::Resubscribing)
+        );
+        assert_eq!(health.status_of(&subscription("eth")), None);
+
+        btc.set(SubscriptionStatus::Streaming);
+        assert_eq!(
+            health.status_of(&subscription("btc")),
+            Some(SubscriptionStatus::Streaming)
+        );
+
+        // Zero stale threshold means any Streaming Subscription is reported as Stale
+        let stale = health.clone().with_stale_threshold(Duration::ZERO);
+        std::thread::sleep(Duration::from_millis(1));
+        assert!(matches!(
+            stale.status_of(&subscription("btc")),
+            Some(SubscriptionStatus::Stale { .. })
+        ));
+
+        // Errored Subscriptions are never reported as Stale
+        btc.set(SubscriptionStatus::Errored {
+            reason: "disconnected".to_string(),
+        });
+        assert_eq!(
+            stale.status_of(&subscription("btc")),
+            Some(SubscriptionStatus::Errored {
+                reason: "disconnected".to_string()
+            })
+        );
+
+        // Merged SubscriptionHealth reports Subscriptions tracked by both registries
+        other.register(&subscription("eth"));
+        assert_eq!(other.ended(ExchangeId::Coinbase), None);
+        other
+            .register(&subscription("eth"))
+            .set(SubscriptionStatus::Ended {
+                reason: StreamEndReason::Shutdown,
+            });
+        assert_eq!(
+            other.ended(ExchangeId::Coinbase),
+            Some(StreamEnded::new(StreamEndReason::Shutdown))
+        );
+        other.merge(health.clone());
+        assert_eq!(other.statuses().len(), 2);
+        assert_eq!(
+            other.status(&(
+                ExchangeId::Coinbase,
+                SubscriptionId::from("matches|BTC-USD")
+            )),
+            Some(SubscriptionStatus::Errored {
+                reason: "disconnected".to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn test_register_merged() {
+        let health = SubscriptionHealth::default();
+        let mut merged = SubscriptionHealth::default();
+        merged.merge(health.clone());
+
+        // Subscriptions already tracked by a merged SubscriptionHealth share the same tracker
+        let tracked = health.register(&subscription("btc"));
+        merged
+            .register(&subscription("btc"))
+            .set(SubscriptionStatus::Streaming);
+        assert_eq!(
+            tracked.status(DEFAULT_STALE_THRESHOLD),
+            SubscriptionStatus::Streaming
+        );
+        assert_eq!(merged.statuses().len(), 1);
+    }
+
+    #[test]
+    fn test_consumer_trackers() {
+        use crate::{exchange::bybit::spot::BybitSpot, subscription::book::OrderBooksL2};
+
+        let health = SubscriptionHealth::default();
+        let instrument = Instrument::from(("btc", "usdt", InstrumentKind::Spot));
+        let subscriptions: [Subscription<_, Instrument, _>; 2] = [
+            Subscription::new(
+                BybitSpot::default(),
+                instrument.clone(),
+                OrderBooksL2::default(),
+            ),
+            Subscription::new(
+                BybitSpot::default(),
+                instrument.clone(),
+                OrderBooksL2::with_depth(200),
+            ),
+        ];
+
+        // Subscriptions to the same instrument via different channels are tracked independently
+        let trackers = ConsumerTrackers::register(&health, &subscriptions);
+        trackers.set(SubscriptionStatus::Validated);
+        assert_eq!(health.statuses().len(), 2);
+
+        // Data received for the instrument streams every associated Subscription
+        trackers.streamed(&instrument, Utc::now());
+        trackers.streamed(&instrument, Utc::now());
+        assert!(health
+            .statuses()
+            .values()
+            .all(|status| *status == SubscriptionStatus::Streaming));
+    }
+}
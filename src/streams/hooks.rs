@@ -0,0 +1,150 @@
+use crate::{error::DataError, exchange::ExchangeId};
+use std::{fmt::Debug, sync::Arc};
+
+/// Communicative type alias representing a [`ConsumerHooks::on_message`] callback.
+pub type OnMessage<Event> = Arc<dyn Fn(ExchangeId, &Event) + Send + Sync>;
+
+/// Communicative type alias representing a [`ConsumerHooks::on_error`] callback.
+pub type OnError = Arc<dyn Fn(ExchangeId, &DataError) + Send + Sync>;
+
+/// Communicative type alias representing a [`ConsumerHooks::on_reconnect`] callback.
+pub type OnReconnect = Arc<dyn Fn(ExchangeId, u32) + Send + Sync>;
+
+/// User callbacks invoked by the [`consumer`](super::consumer) loops, allowing custom metrics &
+/// alerting to be attached without forking the consumer loop.
+///
+/// Callbacks are invoked synchronously on the consumer task, so should return quickly.
+///
+/// eg/ `ConsumerHooks::default().on_error(|exchange, error| alert(exchange, error))`
+pub struct ConsumerHooks<Event> {
+    on_message: Option<OnMessage<Event>>,
+    on_error: Option<OnError>,
+    on_reconnect: Option<OnReconnect>,
+}
+
+impl<Event> ConsumerHooks<Event> {
+    /// Invoke the provided callback with every event consumed from a
+    /// [`MarketStream`](crate::MarketStream), before it is distributed downstream.
+    pub fn on_message<F>(self, on_message: F) -> Self
+    where
+        F: Fn(ExchangeId, &Event) + Send + Sync + 'static,
+    {
+        Self {
+            on_message: Some(Arc::new(on_message)),
+            ..self
+        }
+    }
+
+    /// Invoke the provided callback with every [`DataError`] consumed from a
+    /// [`MarketStream`](crate::MarketStream), or generated whilst initialising one.
+    pub fn on_error<F>(self, on_error: F) -> Self
+    where
+        F: Fn(ExchangeId, &DataError) + Send + Sync + 'static,
+    {
+        Self {
+            on_error: Some(Arc::new(on_error)),
+            ..self
+        }
+    }
+
+    /// Invoke the provided callback with the attempt number each time a disconnected
+    /// [`MarketStream`](crate::MarketStream) is re-initialised.
+    pub fn on_reconnect<F>(self, on_reconnect: F) -> Self
+    where
+        F: Fn(ExchangeId, u32) + Send + Sync + 'static,
+    {
+        Self {
+            on_reconnect: Some(Arc::new(on_reconnect)),
+            ..self
+        }
+    }
+
+    pub(crate) fn message(&self, exchange: ExchangeId, event: &Event) {
+        if let Some(on_message) = &self.on_message {
+            on_message(exchange, event)
+        }
+    }
+
+    pub(crate) fn error(&self, exchange: ExchangeId, error: &DataError) {
+        if let Some(on_error) = &self.on_error {
+            on_error(exchange, error)
+        }
+    }
+
+    pub(crate) fn reconnect(&self, exchange: ExchangeId, attempt: u32) {
+        if let Some(on_reconnect) = &self.on_reconnect {
+            on_reconnect(exchange, attempt)
+        }
+    }
+}
+
+impl<Event> Default for ConsumerHooks<Event> {
+    fn default() -> Self {
+        Self {
+            on_message: None,
+            on_error: None,
+            on_reconnect: None,
+        }
+    }
+}
+
+impl<Event> Clone for ConsumerHooks<Event> {
+    fn clone(&self) -> Self {
+        Self {
+            on_message: self.on_message.clone(),
+            on_error: self.on_error.clone(),
+            on_reconnect: self.on_reconnect.clone(),
+        }
+    }
+}
+
+impl<Event> Debug for ConsumerHooks<Event> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ConsumerHooks")
+            .field("on_message", &self.on_message.is_some())
+            .field("on_error", &self.on_error.is_some())
+            .field("on_reconnect", &self.on_reconnect.is_some())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use barter_integration::error::SocketError;
+    use std::sync::Mutex;
+
+    #[test]
+    fn test_consumer_hooks() {
+        let calls = Arc::new(Mutex::new(Vec::new()));
+
+        let (messages, errors, reconnects) = (calls.clone(), calls.clone(), calls.clone());
+        let hooks = ConsumerHooks::<u64>::default()
+            .on_message(move |exchange, event| {
+                messages
+                    .lock()
+                    .unwrap()
+                    .push(format!("{exchange}:message:{event}"))
+            })
+            .on_error(move |exchange, _| errors.lock().unwrap().push(format!("{exchange}:error")))
+            .on_reconnect(move |exchange, attempt| {
+                reconnects
+                    .lock()
+                    .unwrap()
+                    .push(format!("{exchange}:reconnect:{attempt}"))
+            });
+
+        let error = DataError::Socket(SocketError::Subscribe("test".to_owned()));
+        hooks.message(ExchangeId::Okx, &1);
+        hooks.error(ExchangeId::Okx, &error);
+        hooks.reconnect(ExchangeId::Okx, 2);
+
+        // Unset hooks are no-ops
+        ConsumerHooks::<u64>::default().message(ExchangeId::Okx, &1);
+
+        assert_eq!(
+            *calls.lock().unwrap(),
+            vec!["okx:message:1", "okx:error", "okx:reconnect:2"]
+        );
+    }
+}
@@ -0,0 +1,119 @@
+use crate::exchange::ExchangeId;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+use tokio::sync::mpsc;
+
+/// Connection lifecycle event of a [`MarketStream`](crate::MarketStream) consumer loop, allowing
+/// trading systems to mark data as stale during re-connections rather than silently missing
+/// events.
+#[derive(Clone, Eq, PartialEq, Hash, Debug, Deserialize, Serialize)]
+pub enum MarketStreamEvent {
+    /// Initial connection was established & every [`Subscription`](crate::subscription::Subscription)
+    /// validated.
+    Connected,
+
+    /// Connection was lost for the provided reason, so subsequent data will be missing until
+    /// [`MarketStreamEvent::Resubscribed`].
+    Disconnected { reason: String },
+
+    /// Re-connection attempt is starting, beginning at attempt 1 after each disconnection.
+    Reconnecting { attempt: u32 },
+
+    /// Connection was re-established & every [`Subscription`](crate::subscription::Subscription)
+    /// re-validated.
+    Resubscribed,
+}
+
+/// [`MarketStreamEvent`] associated with the [`ExchangeId`] connection it occurred on.
+#[derive(Clone, Eq, PartialEq, Hash, Debug, Deserialize, Serialize)]
+pub struct LifecycleEvent {
+    pub exchange: ExchangeId,
+    pub time: DateTime<Utc>,
+    pub event: MarketStreamEvent,
+}
+
+/// Shared, cloneable registry of [`LifecycleEvent`] listeners, notified by the
+/// [`consumer`](super::consumer) loops.
+///
+/// Events are only delivered to listeners subscribed at the time they occur.
+#[derive(Clone, Debug)]
+pub struct LifecycleListeners {
+    registries: Vec<Registry>,
+}
+
+type Registry = Arc<Mutex<Vec<mpsc::UnboundedSender<LifecycleEvent>>>>;
+
+impl Default for LifecycleListeners {
+    fn default() -> Self {
+        Self {
+            registries: vec![Registry::default()],
+        }
+    }
+}
+
+impl LifecycleListeners {
+    /// Merge the listeners of another [`LifecycleListeners`] into this one, such that subsequent
+    /// subscribers also receive the events it is notified of.
+    pub fn merge(&mut self, other: LifecycleListeners) {
+        self.registries.extend(other.registries);
+    }
+
+    /// Subscribe to every subsequent [`LifecycleEvent`].
+    pub fn subscribe(&self) -> mpsc::UnboundedReceiver<LifecycleEvent> {
+        let (tx, rx) = mpsc::unbounded_channel();
+        self.registries
+            .iter()
+            .for_each(|registry| lock(registry).push(tx.clone()));
+        rx
+    }
+
+    /// Notify every subscribed listener of the [`MarketStreamEvent`] that occurred on the
+    /// provided [`ExchangeId`] connection, removing any that have been dropped.
+    pub fn notify(&self, exchange: ExchangeId, event: MarketStreamEvent) {
+        let event = LifecycleEvent {
+            exchange,
+            time: Utc::now(),
+            event,
+        };
+
+        self.registries.iter().for_each(|registry| {
+            lock(registry).retain(|listener| listener.send(event.clone()).is_ok())
+        });
+    }
+}
+
+fn lock(registry: &Registry) -> MutexGuard<'_, Vec<mpsc::UnboundedSender<LifecycleEvent>>> {
+    registry.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lifecycle_listeners() {
+        let listeners = LifecycleListeners::default();
+        let mut merged = LifecycleListeners::default();
+        merged.merge(listeners.clone());
+
+        // Events that occur before subscribing are not delivered
+        listeners.notify(ExchangeId::Coinbase, MarketStreamEvent::Connected);
+        let mut rx = merged.subscribe();
+        assert!(rx.try_recv().is_err());
+
+        // Events notified via a merged registry are delivered
+        listeners.notify(
+            ExchangeId::Coinbase,
+            MarketStreamEvent::Reconnecting { attempt: 1 },
+        );
+        let actual = rx.try_recv().unwrap();
+        assert_eq!(actual.exchange, ExchangeId::Coinbase);
+        assert_eq!(actual.event, MarketStreamEvent::Reconnecting { attempt: 1 });
+
+        // Dropped listeners are removed
+        drop(rx);
+        listeners.notify(ExchangeId::Coinbase, MarketStreamEvent::Resubscribed);
+        assert!(lock(&listeners.registries[0]).is_empty());
+    }
+}
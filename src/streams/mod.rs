@@ -1,7 +1,23 @@
-use self::builder::{multi::MultiStreamBuilder, StreamBuilder};
-use crate::{exchange::ExchangeId, subscription::SubscriptionKind};
-use std::collections::HashMap;
-use tokio::sync::mpsc;
+use self::{
+    builder::{multi::MultiStreamBuilder, StreamBuilder},
+    health::SubscriptionHealth,
+};
+use crate::{
+    event::{DynMarketEvent, EventPhase, FeedEvent, MarketEvent, StreamEndReason, StreamEnded},
+    exchange::ExchangeId,
+    runtime::Spawner,
+    subscription::SubscriptionKind,
+};
+use futures::Stream;
+use std::{
+    collections::{HashMap, HashSet},
+    hash::Hash,
+    pin::Pin,
+    sync::Arc,
+    task::{Context, Poll},
+    time::Duration,
+};
+use tokio::{sync::mpsc, time::Instant};
 use tokio_stream::{wrappers::UnboundedReceiverStream, StreamMap};
 
 /// Defines the [`StreamBuilder`](builder::StreamBuilder) and
@@ -13,13 +29,75 @@ pub mod builder;
 /// to drive a re-connecting [`MarketStream`](super::MarketStream).
 pub mod consumer;
 
+/// [`StreamError`](errors::StreamError) non-terminal errors, notified by the [`consumer`] loops
+/// to subscribed listeners alongside the context of the originating subscription.
+pub mod errors;
+
+/// Per [`Subscription`](crate::subscription::Subscription) status tracking, allowing
+/// orchestration layers to query the health of individual instruments.
+pub mod health;
+
+/// [`ConsumerHooks`](hooks::ConsumerHooks) user callbacks invoked by the [`consumer`] loops,
+/// used to attach custom metrics & alerting.
+pub mod hooks;
+
+/// [`MarketStreamEvent`](lifecycle::MarketStreamEvent) connection lifecycle events, notified by
+/// the [`consumer`] loops to subscribed listeners.
+pub mod lifecycle;
+
+/// [`StreamOptions`](options::StreamOptions) per connection configuration, passed by the
+/// [`consumer`] loops into every [`MarketStream`](super::MarketStream) initialisation.
+pub mod options;
+
+/// [`StreamsHandle`](pause::StreamsHandle) used to pause & resume the delivery of events from
+/// individual exchange connections.
+pub mod pause;
+
+/// [`ReconnectPolicy`](reconnect::ReconnectPolicy) exponential backoff configuration used by the
+/// [`consumer`] loops to re-initialise a disconnected [`MarketStream`](super::MarketStream).
+pub mod reconnect;
+
+/// [`OrderingBuffer`](ordering::OrderingBuffer) bounded reordering window used to merge
+/// exchange [`Streams`] sorted by event time.
+pub mod ordering;
+
 /// Ergonomic collection of exchange [`MarketEvent<T>`](crate::event::MarketEvent) receivers.
+///
+/// Implements [`Stream`], yielding the events of every exchange receiver (polled fairly), so
+/// [`StreamExt`](futures::StreamExt) combinators can be used directly.
 #[derive(Debug)]
 pub struct Streams<T> {
+    /// Exchange receivers that are not yet being polled via the [`Stream`] implementation.
+    ///
+    /// Note that polling [`Self`] as a [`Stream`] moves these receivers into an internal
+    /// [`StreamMap`], draining this `HashMap`. Receivers inserted afterwards are picked up on the
+    /// next poll. Use [`Self::select`] (or any combinator) to access every receiver regardless.
     pub streams: HashMap<ExchangeId, mpsc::UnboundedReceiver<T>>,
+    pub health: SubscriptionHealth,
+    spawner: Spawner,
+    polled: StreamMap<ExchangeId, UnboundedReceiverStream<T>>,
 }
 
 impl<T> Streams<T> {
+    /// Construct a new [`Self`] from the provided exchange receivers & [`SubscriptionHealth`].
+    pub fn new(
+        streams: HashMap<ExchangeId, mpsc::UnboundedReceiver<T>>,
+        health: SubscriptionHealth,
+    ) -> Self {
+        Self {
+            streams,
+            health,
+            spawner: Spawner::default(),
+            polled: StreamMap::new(),
+        }
+    }
+
+    /// Spawn the tasks of every combinator (eg/ [`Self::merge`], [`Self::fan_out`]) using the
+    /// provided [`Spawner`], rather than the default.
+    pub fn with_spawner(self, spawner: Spawner) -> Self {
+        Self { spawner, ..self }
+    }
+
     /// Construct a [`StreamBuilder`] for configuring new
     /// [`MarketEvent<SubscriptionKind::Event>`](crate::event::MarketEvent) [`Streams`].
     pub fn builder<Kind>() -> StreamBuilder<Kind>
@@ -37,31 +115,102 @@ impl<T> Streams<T> {
 
     /// Remove an exchange [`mpsc::UnboundedReceiver`] from the [`Streams`] `HashMap`.
     pub fn select(&mut self, exchange: ExchangeId) -> Option<mpsc::UnboundedReceiver<T>> {
-        self.streams.remove(&exchange)
+        self.streams.remove(&exchange).or_else(|| {
+            self.polled
+                .remove(&exchange)
+                .map(UnboundedReceiverStream::into_inner)
+        })
+    }
+
+    /// Remove the exchange [`mpsc::UnboundedReceiver`]s of the provided [`ExchangeId`]s from the
+    /// [`Streams`] `HashMap`, merging them into a single [`StreamMap`] keyed by [`ExchangeId`].
+    ///
+    /// The receivers of every other exchange are left untouched. Any [`ExchangeId`] without a
+    /// receiver (eg/ not subscribed, or already selected) is ignored.
+    ///
+    /// eg/ `streams.select_many([ExchangeId::BinanceSpot, ExchangeId::Okx])`
+    pub fn select_many<Iter>(
+        &mut self,
+        exchanges: Iter,
+    ) -> StreamMap<ExchangeId, UnboundedReceiverStream<T>>
+    where
+        Iter: IntoIterator<Item = ExchangeId>,
+    {
+        exchanges
+            .into_iter()
+            .fold(StreamMap::new(), |mut map, exchange| {
+                if let Some(rx) = self.select(exchange) {
+                    map.insert(exchange, UnboundedReceiverStream::new(rx));
+                }
+                map
+            })
+    }
+
+    /// Shared [`SubscriptionHealth`] handle used to query the
+    /// [`SubscriptionStatus`](health::SubscriptionStatus) of each
+    /// [`Subscription`](crate::subscription::Subscription) driving these [`Streams`].
+    pub fn health(&self) -> SubscriptionHealth {
+        self.health.clone()
+    }
+
+    /// Shared [`StreamsHandle`](pause::StreamsHandle) used to pause & resume the delivery of
+    /// events from individual exchanges, without dropping their
+    /// [`Subscription`](crate::subscription::Subscription)s.
+    pub fn handle(&self) -> pause::StreamsHandle {
+        self.health.handle().clone()
+    }
+
+    /// Subscribe to the [`LifecycleEvent`](lifecycle::LifecycleEvent)s (eg/ disconnections &
+    /// re-connections) of every connection driving these [`Streams`], allowing consumers to mark
+    /// data as stale whilst re-connecting.
+    pub fn lifecycle(&self) -> mpsc::UnboundedReceiver<lifecycle::LifecycleEvent> {
+        self.health.lifecycle().subscribe()
+    }
+
+    /// Subscribe to the non-terminal [`StreamError`](errors::StreamError)s (eg/ rejected
+    /// subscriptions, undeserialisable messages) of every connection driving these [`Streams`],
+    /// allowing consumers to react programmatically to bad data.
+    pub fn errors(&self) -> mpsc::UnboundedReceiver<errors::StreamError> {
+        self.health.errors().subscribe()
+    }
+
+    /// Determine if the feed for the provided [`ExchangeId`] has permanently ended, returning
+    /// the [`StreamEnded`](crate::event::StreamEnded) terminal event if so.
+    ///
+    /// Every consumer loop records its [`StreamEnded`](crate::event::StreamEnded) before its
+    /// exchange [`mpsc::UnboundedSender`] is dropped. Therefore, once an exchange receiver yields
+    /// `None`, a `None` from this method indicates the channel closed unexpectedly.
+    pub fn ended(&self, exchange: ExchangeId) -> Option<crate::event::StreamEnded> {
+        self.health.ended(exchange)
     }
 
     /// Join all exchange [`mpsc::UnboundedReceiver`] streams into a unified
-    /// [`mpsc::UnboundedReceiver`].
-    pub async fn join(self) -> mpsc::UnboundedReceiver<T>
+    /// [`UnboundedReceiverStream`].
+    ///
+    /// Unlike polling the [`Streams`] directly, each exchange receiver is drained by a dedicated
+    /// task.
+    pub async fn join(mut self) -> UnboundedReceiverStream<T>
     where
         T: Send + 'static,
     {
+        self.reclaim();
         let (joined_tx, joined_rx) = mpsc::unbounded_channel();
 
         for mut exchange_rx in self.streams.into_values() {
             let joined_tx = joined_tx.clone();
-            tokio::spawn(async move {
+            self.spawner.spawn(async move {
                 while let Some(event) = exchange_rx.recv().await {
                     let _ = joined_tx.send(event);
                 }
             });
         }
 
-        joined_rx
+        UnboundedReceiverStream::new(joined_rx)
     }
 
     /// Join all exchange [`mpsc::UnboundedReceiver`] streams into a unified [`StreamMap`].
-    pub async fn join_map(self) -> StreamMap<ExchangeId, UnboundedReceiverStream<T>> {
+    pub async fn join_map(mut self) -> StreamMap<ExchangeId, UnboundedReceiverStream<T>> {
+        self.reclaim();
         self.streams
             .into_iter()
             .fold(StreamMap::new(), |mut map, (exchange, rx)| {
@@ -69,4 +218,767 @@ impl<T> Streams<T> {
                 map
             })
     }
+
+    /// Map every event of these [`Streams`] using the provided function, returning new
+    /// [`Streams`] of the mapped events.
+    ///
+    /// eg/ `streams.map(|event| event.kind.price)`
+    pub fn map<F, Output>(self, f: F) -> Streams<Output>
+    where
+        T: Send + 'static,
+        Output: Send + 'static,
+        F: Fn(T) -> Output + Send + Sync + 'static,
+    {
+        self.forward(move |event| Some(f(event)))
+    }
+
+    /// Filter the events of these [`Streams`] using the provided predicate, returning new
+    /// [`Streams`] that only yield the events the predicate returns `true` for.
+    pub fn filter<F>(self, predicate: F) -> Streams<T>
+    where
+        T: Send + 'static,
+        F: Fn(&T) -> bool + Send + Sync + 'static,
+    {
+        self.forward(move |event| predicate(&event).then_some(event))
+    }
+
+    /// Call the provided function with a reference to every event of these [`Streams`] (eg/ for
+    /// logging or metrics), returning new [`Streams`] that yield the unmodified events.
+    pub fn inspect<F>(self, f: F) -> Streams<T>
+    where
+        T: Send + 'static,
+        F: Fn(&T) + Send + Sync + 'static,
+    {
+        self.forward(move |event| {
+            f(&event);
+            Some(event)
+        })
+    }
+
+    /// Merge another [`Streams`] of the same event type into these [`Streams`], returning
+    /// [`Streams`] that yield the events of both (eg/ after [`Streams::erase`]).
+    ///
+    /// Exchange receivers present in both are combined into one exchange receiver by a dedicated
+    /// task, which ends once both exchange streams end, or the combined receiver is dropped.
+    pub fn merge(mut self, mut other: Streams<T>) -> Streams<T>
+    where
+        T: Send + 'static,
+    {
+        self.reclaim();
+        other.reclaim();
+        for (exchange, mut other_rx) in other.streams {
+            let Some(mut exchange_rx) = self.streams.remove(&exchange) else {
+                self.streams.insert(exchange, other_rx);
+                continue;
+            };
+
+            let (tx, rx) = mpsc::unbounded_channel();
+            self.spawner.spawn(async move {
+                let (mut exchange_open, mut other_open) = (true, true);
+                while exchange_open || other_open {
+                    let event = tokio::select! {
+                        event = exchange_rx.recv(), if exchange_open => event.or_else(|| {
+                            exchange_open = false;
+                            None
+                        }),
+                        event = other_rx.recv(), if other_open => event.or_else(|| {
+                            other_open = false;
+                            None
+                        }),
+                    };
+
+                    if let Some(event) = event {
+                        if tx.send(event).is_err() {
+                            break;
+                        }
+                    }
+                }
+            });
+
+            self.streams.insert(exchange, rx);
+        }
+
+        self.health.merge(other.health);
+        self
+    }
+
+    /// Fan out these [`Streams`] into `consumers` independent [`Streams`], each of which receives
+    /// every event (eg/ for a strategy, a recorder & a monitor).
+    ///
+    /// Every event is cloned into each consumer's unbounded exchange [`mpsc::UnboundedReceiver`],
+    /// so a slow consumer never causes events to be dropped for the others. Each fan out task
+    /// ends once its exchange stream ends, or every consumer has dropped its receiver.
+    pub fn fan_out(mut self, consumers: usize) -> Vec<Streams<T>>
+    where
+        T: Clone + Send + 'static,
+    {
+        self.reclaim();
+        let mut fanned_out = (0..consumers)
+            .map(|_| {
+                Streams::new(
+                    HashMap::with_capacity(self.streams.len()),
+                    self.health.clone(),
+                )
+                .with_spawner(self.spawner.clone())
+            })
+            .collect::<Vec<_>>();
+
+        for (exchange, mut exchange_rx) in self.streams {
+            let mut consumer_txs = fanned_out
+                .iter_mut()
+                .map(|streams| {
+                    let (tx, rx) = mpsc::unbounded_channel();
+                    streams.streams.insert(exchange, rx);
+                    tx
+                })
+                .collect::<Vec<_>>();
+
+            self.spawner.spawn(async move {
+                while let Some(event) = exchange_rx.recv().await {
+                    // Stop distributing events to a consumer once its receiver is dropped
+                    consumer_txs.retain(|tx| tx.send(event.clone()).is_ok());
+                    if consumer_txs.is_empty() {
+                        break;
+                    }
+                }
+            });
+        }
+
+        fanned_out
+    }
+
+    /// Terminate each exchange stream of these [`Streams`] in-stream with its [`StreamEnded`]
+    /// event, allowing consumers to distinguish a feed that has finished from a channel that
+    /// closed unexpectedly.
+    ///
+    /// Once an exchange stream ends, the [`StreamEnded`] recorded by its consumer loops is
+    /// yielded as a [`FeedEvent::Ended`]. If no consumer loop recorded one, untracked feeds
+    /// (eg/ replays) end with [`StreamEndReason::Finished`], and tracked feeds end with a
+    /// [`StreamEndReason::Error`].
+    pub fn with_end(mut self) -> Streams<FeedEvent<T>>
+    where
+        T: Send + 'static,
+    {
+        self.reclaim();
+        let streams = self
+            .streams
+            .into_iter()
+            .map(|(exchange, mut exchange_rx)| {
+                let (tx, rx) = mpsc::unbounded_channel();
+                let health = self.health.clone();
+
+                self.spawner.spawn(async move {
+                    while let Some(event) = exchange_rx.recv().await {
+                        if tx.send(FeedEvent::Item(event)).is_err() {
+                            return;
+                        }
+                    }
+                    let _ = tx.send(FeedEvent::Ended(ended(&health, exchange)));
+                });
+
+                (exchange, rx)
+            })
+            .collect();
+
+        Streams::new(streams, self.health).with_spawner(self.spawner)
+    }
+
+    /// Move the exchange receivers already being polled via the [`Stream`] implementation back
+    /// into the [`Streams`] `HashMap`.
+    fn reclaim(&mut self) {
+        let polled = self.polled.keys().copied().collect::<Vec<_>>();
+        for exchange in polled {
+            if let Some(rx) = self.polled.remove(&exchange) {
+                self.streams.insert(exchange, rx.into_inner());
+            }
+        }
+    }
+
+    /// Forward every event of each exchange [`mpsc::UnboundedReceiver`] through the provided
+    /// function, into a new exchange [`mpsc::UnboundedReceiver`] of the returned [`Streams`].
+    ///
+    /// Events the function maps to `None` are discarded. Each forwarding task ends once its
+    /// exchange stream ends, or the new exchange [`mpsc::UnboundedReceiver`] is dropped.
+    fn forward<F, Output>(mut self, f: F) -> Streams<Output>
+    where
+        T: Send + 'static,
+        Output: Send + 'static,
+        F: Fn(T) -> Option<Output> + Send + Sync + 'static,
+    {
+        self.reclaim();
+        let f = Arc::new(f);
+
+        let streams = self
+            .streams
+            .into_iter()
+            .map(|(exchange, mut exchange_rx)| {
+                let (tx, rx) = mpsc::unbounded_channel();
+                let f = Arc::clone(&f);
+
+                self.spawner.spawn(async move {
+                    while let Some(event) = exchange_rx.recv().await {
+                        let Some(output) = f(event) else {
+                            continue;
+                        };
+
+                        if tx.send(output).is_err() {
+                            break;
+                        }
+                    }
+                });
+
+                (exchange, rx)
+            })
+            .collect();
+
+        Streams::new(streams, self.health).with_spawner(self.spawner)
+    }
+}
+
+/// Terminal [`StreamEnded`] event of the ended exchange stream, using the [`SubscriptionHealth`]
+/// of its consumer loops (if any).
+fn ended(health: &SubscriptionHealth, exchange: ExchangeId) -> StreamEnded {
+    health.ended(exchange).unwrap_or_else(|| {
+        let tracked = health
+            .statuses()
+            .keys()
+            .any(|(sub_exchange, _)| *sub_exchange == exchange);
+
+        StreamEnded::new(match tracked {
+            true => StreamEndReason::Error(format!("{exchange} stream closed unexpectedly")),
+            false => StreamEndReason::Finished,
+        })
+    })
+}
+
+impl<T> Stream for Streams<T> {
+    type Item = T;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+
+        // Move any newly added exchange receivers into the StreamMap, which polls them fairly
+        for (exchange, rx) in this.streams.drain() {
+            this.polled
+                .insert(exchange, UnboundedReceiverStream::new(rx));
+        }
+
+        Pin::new(&mut this.polled)
+            .poll_next(cx)
+            .map(|event| event.map(|(_, event)| event))
+    }
+}
+
+impl<InstrumentId, T> Streams<MarketEvent<InstrumentId, T>>
+where
+    InstrumentId: Clone + Eq + Hash + Send + 'static,
+    T: Send + 'static,
+{
+    /// Erase the event type of these [`Streams`], converting every event into a
+    /// [`DynMarketEvent`].
+    ///
+    /// Erased [`Streams`] of different [`SubscriptionKind`]s can be combined into one
+    /// heterogeneous [`Streams`] via [`Streams::merge`].
+    ///
+    /// eg/ `trades.erase().merge(order_books_l1.erase())`
+    pub fn erase(self) -> Streams<DynMarketEvent<InstrumentId>>
+    where
+        MarketEvent<InstrumentId, T>: Into<DynMarketEvent<InstrumentId>>,
+    {
+        self.map(MarketEvent::into)
+    }
+
+    /// Remove an exchange [`mpsc::UnboundedReceiver`] from the [`Streams`] `HashMap`, returning an
+    /// [`mpsc::UnboundedReceiver`] filtered to the provided instrument.
+    ///
+    /// Events of any other instrument are discarded, so use [`Self::select_instruments`] to
+    /// select many instruments of the same exchange.
+    pub fn select_instrument(
+        &mut self,
+        exchange: ExchangeId,
+        instrument: InstrumentId,
+    ) -> Option<mpsc::UnboundedReceiver<MarketEvent<InstrumentId, T>>> {
+        self.select_instruments(exchange, [instrument.clone()])
+            .and_then(|mut receivers| receivers.remove(&instrument))
+    }
+
+    /// Remove an exchange [`mpsc::UnboundedReceiver`] from the [`Streams`] `HashMap`, and
+    /// demultiplex it into an [`mpsc::UnboundedReceiver`] per provided instrument.
+    ///
+    /// Events of any other instrument are discarded. The demultiplexing task ends once the
+    /// exchange stream ends, or every returned [`mpsc::UnboundedReceiver`] is dropped.
+    pub fn select_instruments<Iter>(
+        &mut self,
+        exchange: ExchangeId,
+        instruments: Iter,
+    ) -> Option<HashMap<InstrumentId, mpsc::UnboundedReceiver<MarketEvent<InstrumentId, T>>>>
+    where
+        Iter: IntoIterator<Item = InstrumentId>,
+    {
+        let mut exchange_rx = self.select(exchange)?;
+
+        let (mut instrument_txs, instrument_rxs): (HashMap<_, _>, HashMap<_, _>) = instruments
+            .into_iter()
+            .map(|instrument| {
+                let (tx, rx) = mpsc::unbounded_channel();
+                ((instrument.clone(), tx), (instrument, rx))
+            })
+            .unzip();
+
+        self.spawner.spawn(async move {
+            while let Some(event) = exchange_rx.recv().await {
+                let Some(instrument_tx) = instrument_txs.get(&event.instrument) else {
+                    continue;
+                };
+
+                // Stop routing events to an instrument once its receiver is dropped
+                if instrument_tx.send(event).is_err() {
+                    instrument_txs.retain(|_, tx| !tx.is_closed());
+                    if instrument_txs.is_empty() {
+                        break;
+                    }
+                }
+            }
+        });
+
+        Some(instrument_rxs)
+    }
+
+    /// Mark every event of these stateful [`Streams`] (eg/ OrderBooks, candles) with its
+    /// [`EventPhase`], allowing consumers to distinguish warm-up state from live updates.
+    ///
+    /// The first event of each instrument after its exchange connection is (re)subscribed is
+    /// marked as an [`EventPhase::Snapshot`], and every subsequent event as an
+    /// [`EventPhase::Live`] update.
+    ///
+    /// Re-subscriptions are detected via the [`lifecycle`](Self::lifecycle) of the
+    /// [`Streams`], which is notified before the re-subscribed connection yields any events.
+    pub fn with_snapshots(mut self) -> Streams<(EventPhase, MarketEvent<InstrumentId, T>)> {
+        self.reclaim();
+        let lifecycle = self.health.lifecycle();
+
+        let streams = self
+            .streams
+            .into_iter()
+            .map(|(exchange, mut exchange_rx)| {
+                let (tx, rx) = mpsc::unbounded_channel();
+                let mut lifecycle_rx = lifecycle.subscribe();
+
+                self.spawner.spawn(async move {
+                    let mut initialised = HashSet::new();
+
+                    while let Some(event) = exchange_rx.recv().await {
+                        // Instruments of a (re)subscribed connection yield a new initial state
+                        while let Ok(lifecycle_event) = lifecycle_rx.try_recv() {
+                            if lifecycle_event.exchange == exchange
+                                && matches!(
+                                    lifecycle_event.event,
+                                    lifecycle::MarketStreamEvent::Connected
+                                        | lifecycle::MarketStreamEvent::Resubscribed
+                                )
+                            {
+                                initialised.clear();
+                            }
+                        }
+
+                        let phase = match initialised.insert(event.instrument.clone()) {
+                            true => EventPhase::Snapshot,
+                            false => EventPhase::Live,
+                        };
+
+                        if tx.send((phase, event)).is_err() {
+                            break;
+                        }
+                    }
+                });
+
+                (exchange, rx)
+            })
+            .collect();
+
+        Streams::new(streams, self.health).with_spawner(self.spawner)
+    }
+
+    /// Merge every exchange receiver of these [`Streams`] into a single [`Stream`] sorted by
+    /// `exchange_time`, within a bounded reordering window of `max_lateness`.
+    ///
+    /// Each event is delayed by at most `max_lateness`, and events that arrive later than this
+    /// can no longer be ordered, so are yielded immediately. Useful for research pipelines that
+    /// require deterministic cross-exchange ordering.
+    ///
+    /// See [`OrderingBuffer`](ordering::OrderingBuffer).
+    pub fn ordered(
+        mut self,
+        max_lateness: Duration,
+    ) -> UnboundedReceiverStream<MarketEvent<InstrumentId, T>> {
+        let (tx, rx) = mpsc::unbounded_channel();
+
+        let spawner = self.spawner.clone();
+        spawner.spawn(async move {
+            let mut buffer = ordering::OrderingBuffer::new(max_lateness);
+
+            loop {
+                let event = match buffer.next_release() {
+                    Some(deadline) => tokio::select! {
+                        event = futures::StreamExt::next(&mut self) => event,
+                        _ = tokio::time::sleep_until(deadline) => {
+                            let released = buffer.release(Instant::now());
+                            if released.into_iter().any(|event| tx.send(event).is_err()) {
+                                break;
+                            }
+                            continue;
+                        }
+                    },
+                    None => futures::StreamExt::next(&mut self).await,
+                };
+
+                let Some(event) = event else {
+                    // Distribute any buffered events before ending
+                    buffer
+                        .drain()
+                        .into_iter()
+                        .for_each(|event| drop(tx.send(event)));
+                    break;
+                };
+
+                if let Some(event) = buffer.push(event, Instant::now()) {
+                    if tx.send(event).is_err() {
+                        break;
+                    }
+                }
+            }
+        });
+
+        UnboundedReceiverStream::new(rx)
+    }
+
+    /// Remove an exchange [`mpsc::UnboundedReceiver`] from the [`Streams`] `HashMap`, and
+    /// demultiplex it into a [`StreamMap`] keyed by each provided instrument.
+    ///
+    /// See [`Self::select_instruments`].
+    pub fn select_instruments_map<Iter>(
+        &mut self,
+        exchange: ExchangeId,
+        instruments: Iter,
+    ) -> Option<StreamMap<InstrumentId, UnboundedReceiverStream<MarketEvent<InstrumentId, T>>>>
+    where
+        Iter: IntoIterator<Item = InstrumentId>,
+    {
+        self.select_instruments(exchange, instruments)
+            .map(|receivers| {
+                receivers
+                    .into_iter()
+                    .fold(StreamMap::new(), |mut map, (instrument, rx)| {
+                        map.insert(instrument, UnboundedReceiverStream::new(rx));
+                        map
+                    })
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use barter_integration::model::Exchange;
+    use chrono::Utc;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use tokio_stream::StreamExt;
+
+    fn streams(instruments: &[&'static str]) -> Streams<MarketEvent<&'static str, ()>> {
+        let (tx, rx) = mpsc::unbounded_channel();
+        for instrument in instruments {
+            tx.send(MarketEvent {
+                exchange_time: Utc::now(),
+                received_time: Utc::now(),
+                exchange: Exchange::from(ExchangeId::BinanceSpot),
+                instrument: *instrument,
+                kind: (),
+            })
+            .unwrap();
+        }
+
+        Streams::new(
+            HashMap::from([(ExchangeId::BinanceSpot, rx)]),
+            SubscriptionHealth::default(),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_with_end() {
+        // Untracked finite feed (eg/ a replay) is terminated with StreamEndReason::Finished
+        let actual = streams(&["btc_usdt", "eth_usdt"])
+            .with_end()
+            .map(|event| match event {
+                FeedEvent::Item(event) => FeedEvent::Item(event.instrument),
+                FeedEvent::Ended(ended) => FeedEvent::Ended(ended),
+            })
+            .collect::<Vec<_>>()
+            .await;
+
+        assert_eq!(
+            actual,
+            vec![
+                FeedEvent::Item("btc_usdt"),
+                FeedEvent::Item("eth_usdt"),
+                FeedEvent::Ended(StreamEnded::new(StreamEndReason::Finished)),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_select_instrument() {
+        let mut streams = streams(&["btc_usdt", "eth_usdt", "btc_usdt"]);
+        assert!(streams
+            .select_instrument(ExchangeId::Okx, "btc_usdt")
+            .is_none());
+
+        let mut btc_rx = streams
+            .select_instrument(ExchangeId::BinanceSpot, "btc_usdt")
+            .unwrap();
+        assert_eq!(btc_rx.recv().await.unwrap().instrument, "btc_usdt");
+        assert_eq!(btc_rx.recv().await.unwrap().instrument, "btc_usdt");
+        assert!(btc_rx.recv().await.is_none());
+
+        // Exchange receiver has already been selected
+        assert!(streams
+            .select_instrument(ExchangeId::BinanceSpot, "eth_usdt")
+            .is_none());
+    }
+
+    #[tokio::test]
+    async fn test_select_many() {
+        let mut streams = streams(&["btc_usdt"]);
+        let (tx, rx) = mpsc::unbounded_channel::<MarketEvent<&'static str, ()>>();
+        streams.streams.insert(ExchangeId::Okx, rx);
+        drop(tx);
+
+        // Unknown exchanges are ignored, and unselected exchanges are left untouched
+        let mut map = streams.select_many([ExchangeId::BinanceSpot, ExchangeId::Kraken]);
+        assert_eq!(map.len(), 1);
+        assert!(streams.streams.contains_key(&ExchangeId::Okx));
+
+        let (exchange, event) = map.next().await.unwrap();
+        assert_eq!(exchange, ExchangeId::BinanceSpot);
+        assert_eq!(event.instrument, "btc_usdt");
+
+        // Already selected exchanges are ignored
+        assert!(streams.select_many([ExchangeId::BinanceSpot]).is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_select_instruments_map() {
+        let mut map = streams(&["btc_usdt", "eth_usdt", "sol_usdt"])
+            .select_instruments_map(ExchangeId::BinanceSpot, ["btc_usdt", "eth_usdt"])
+            .unwrap();
+
+        let mut actual = Vec::new();
+        while let Some((instrument, event)) = map.next().await {
+            assert_eq!(instrument, event.instrument);
+            actual.push(instrument);
+        }
+        actual.sort();
+        assert_eq!(actual, vec!["btc_usdt", "eth_usdt"]);
+    }
+
+    #[tokio::test]
+    async fn test_streams_stream() {
+        let mut streams = streams(&["btc_usdt", "eth_usdt"]);
+        let (tx, rx) = mpsc::unbounded_channel();
+        streams.streams.insert(ExchangeId::Okx, rx);
+        tx.send(MarketEvent {
+            exchange_time: Utc::now(),
+            received_time: Utc::now(),
+            exchange: Exchange::from(ExchangeId::Okx),
+            instrument: "sol_usdt",
+            kind: (),
+        })
+        .unwrap();
+        drop(tx);
+
+        let mut actual = Vec::new();
+        while let Some(event) = StreamExt::next(&mut streams).await {
+            actual.push(event.instrument);
+        }
+        actual.sort();
+        assert_eq!(actual, vec!["btc_usdt", "eth_usdt", "sol_usdt"]);
+
+        let joined = self::streams(&["btc_usdt"]).join().await;
+        assert_eq!(joined.collect::<Vec<_>>().await.len(), 1);
+
+        // Exchange receivers already being polled can still be selected
+        let mut streams = self::streams(&["btc_usdt", "eth_usdt"]);
+        let first = StreamExt::next(&mut streams).await.unwrap();
+        assert_eq!(first.instrument, "btc_usdt");
+        let mut rx = streams.select(ExchangeId::BinanceSpot).unwrap();
+        assert_eq!(rx.recv().await.unwrap().instrument, "eth_usdt");
+    }
+
+    #[tokio::test]
+    async fn test_combinators() {
+        let inspected = Arc::new(AtomicUsize::new(0));
+        let counter = Arc::clone(&inspected);
+
+        let mut streams = streams(&["btc_usdt", "eth_usdt", "btc_usdt"])
+            .inspect(move |_| {
+                counter.fetch_add(1, Ordering::Relaxed);
+            })
+            .filter(|event| event.instrument == "btc_usdt")
+            .map(|event| event.instrument.len());
+
+        let mut rx = streams.select(ExchangeId::BinanceSpot).unwrap();
+        assert_eq!(rx.recv().await, Some(8));
+        assert_eq!(rx.recv().await, Some(8));
+        assert_eq!(rx.recv().await, None);
+        assert_eq!(inspected.load(Ordering::Relaxed), 3);
+    }
+
+    #[tokio::test]
+    async fn test_fan_out() {
+        let mut consumers = streams(&["btc_usdt", "eth_usdt"]).fan_out(3);
+        assert_eq!(consumers.len(), 3);
+
+        // Dropped consumers do not prevent the others receiving every event
+        consumers.pop();
+
+        for mut consumer in consumers {
+            let mut rx = consumer.select(ExchangeId::BinanceSpot).unwrap();
+            assert_eq!(rx.recv().await.unwrap().instrument, "btc_usdt");
+            assert_eq!(rx.recv().await.unwrap().instrument, "eth_usdt");
+            assert!(rx.recv().await.is_none());
+        }
+    }
+
+    #[tokio::test]
+    async fn test_erase_merge() {
+        use crate::{
+            event::DataKind,
+            subscription::{
+                book::{Level, OrderBookL1},
+                trade::PublicTrade,
+            },
+        };
+
+        fn streams<T>(
+            exchange: ExchangeId,
+            kinds: Vec<T>,
+        ) -> Streams<MarketEvent<&'static str, T>> {
+            let (tx, rx) = mpsc::unbounded_channel();
+            for kind in kinds {
+                tx.send(MarketEvent {
+                    exchange_time: Utc::now(),
+                    received_time: Utc::now(),
+                    exchange: Exchange::from(exchange),
+                    instrument: "btc_usdt",
+                    kind,
+                })
+                .unwrap();
+            }
+            Streams::new(
+                HashMap::from([(exchange, rx)]),
+                SubscriptionHealth::default(),
+            )
+        }
+
+        let trade = PublicTrade {
+            id: "1".to_owned(),
+            price: 100.0,
+            amount: 1.0,
+            side: barter_integration::model::Side::Buy,
+        };
+
+        let trades = streams(ExchangeId::BinanceSpot, vec![trade.clone(), trade]);
+        let books = streams(
+            ExchangeId::BinanceSpot,
+            vec![OrderBookL1 {
+                last_update_time: Utc::now(),
+                best_bid: Level::new(99.0, 1.0),
+                best_ask: Level::new(101.0, 1.0),
+            }],
+        );
+        let okx_trades = streams(ExchangeId::Okx, Vec::<PublicTrade>::new());
+
+        let mut erased = trades
+            .erase()
+            .merge(books.erase())
+            .merge(okx_trades.erase());
+        assert_eq!(erased.streams.len(), 2);
+
+        // TC0: shared exchange receivers are combined into one heterogeneous receiver
+        let mut rx = erased.select(ExchangeId::BinanceSpot).unwrap();
+        let mut kinds = Vec::new();
+        while let Some(event) = rx.recv().await {
+            kinds.push(match event.kind {
+                DataKind::Trade(_) => "trade",
+                DataKind::OrderBookL1(_) => "l1",
+                _ => "other",
+            });
+        }
+        kinds.sort();
+        assert_eq!(kinds, vec!["l1", "trade", "trade"]);
+
+        // TC1: exchange receivers unique to one Streams are left untouched
+        let mut rx = erased.select(ExchangeId::Okx).unwrap();
+        assert!(rx.recv().await.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_with_snapshots() {
+        let event = |instrument| MarketEvent {
+            exchange_time: Utc::now(),
+            received_time: Utc::now(),
+            exchange: Exchange::from(ExchangeId::BinanceSpot),
+            instrument,
+            kind: (),
+        };
+
+        let (tx, rx) = mpsc::unbounded_channel();
+        let health = SubscriptionHealth::default();
+        let mut streams = Streams::new(
+            HashMap::from([(ExchangeId::BinanceSpot, rx)]),
+            health.clone(),
+        )
+        .with_snapshots();
+        let mut rx = streams.select(ExchangeId::BinanceSpot).unwrap();
+
+        // First event of each instrument is the initial state
+        tx.send(event("btc_usdt")).unwrap();
+        tx.send(event("btc_usdt")).unwrap();
+        tx.send(event("eth_usdt")).unwrap();
+        assert_eq!(rx.recv().await.unwrap().0, EventPhase::Snapshot);
+        assert_eq!(rx.recv().await.unwrap().0, EventPhase::Live);
+        assert_eq!(rx.recv().await.unwrap().0, EventPhase::Snapshot);
+
+        // Re-subscribed connection yields a new initial state
+        health.lifecycle().notify(
+            ExchangeId::BinanceSpot,
+            lifecycle::MarketStreamEvent::Resubscribed,
+        );
+        tx.send(event("btc_usdt")).unwrap();
+        tx.send(event("btc_usdt")).unwrap();
+        assert_eq!(rx.recv().await.unwrap().0, EventPhase::Snapshot);
+        assert_eq!(rx.recv().await.unwrap().0, EventPhase::Live);
+    }
+
+    #[tokio::test]
+    async fn test_with_snapshots_after_poll() {
+        let mut streams = streams(&["btc_usdt", "eth_usdt"]);
+
+        // Polling moves the exchange receiver out of the Streams HashMap
+        assert_eq!(
+            StreamExt::next(&mut streams).await.unwrap().instrument,
+            "btc_usdt"
+        );
+        assert!(streams.streams.is_empty());
+
+        // Already polled exchange receivers are not lost
+        let mut rx = streams
+            .with_snapshots()
+            .select(ExchangeId::BinanceSpot)
+            .unwrap();
+        let (phase, event) = rx.recv().await.unwrap();
+        assert_eq!(
+            (phase, event.instrument),
+            (EventPhase::Snapshot, "eth_usdt")
+        );
+    }
 }
@@ -0,0 +1,159 @@
+use crate::{
+    exchange::{Connector, ExchangeId, PongTimeout},
+    runtime::Spawner,
+    subscriber::{pacing::RequestRate, validator::SubscriptionFailurePolicy},
+    transformer::book::audit::AuditConfig,
+};
+use serde::{Deserialize, Serialize};
+use std::{collections::HashMap, time::Duration};
+
+/// Per connection options configured via the [`StreamBuilder`](super::builder::StreamBuilder),
+/// passed by the [`consumer`](super::consumer) loops into every
+/// [`MarketStream::init`](crate::MarketStream::init).
+///
+/// eg/ `StreamOptions { failure_policy: SubscriptionFailurePolicy::ContinueWithAccepted, ..Default::default() }`
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct StreamOptions {
+    /// Defines how the [`SubscriptionValidator`](crate::subscriber::validator::SubscriptionValidator)
+    /// handles the exchange rejecting some of the actioned
+    /// [`Subscription`](crate::subscription::Subscription)s.
+    pub failure_policy: SubscriptionFailurePolicy,
+
+    /// Opt-in OrderBook integrity audit performed by every
+    /// [`MultiBookTransformer`](crate::transformer::book::MultiBookTransformer), or `None` to
+    /// disable.
+    pub audit: Option<AuditConfig>,
+
+    /// [`RequestRate`] overrides (or `None` to disable pacing) of each exchange, taking
+    /// precedence over the [`Connector::request_rate`].
+    pub request_rates: HashMap<ExchangeId, Option<RequestRate>>,
+
+    /// Maximum [`Duration`] allowed without receiving any message from the exchange server before
+    /// the [`MarketStream`](crate::MarketStream) is ended & re-connected, taking precedence over
+    /// the [`Connector::idle_timeout`].
+    pub idle_timeout: Option<Duration>,
+
+    /// [`PongTimeout`] deadline overrides (or `None` to disable pong tracking) of each exchange,
+    /// taking precedence over the [`Connector::pong_timeout`] deadline.
+    pub pong_timeouts: HashMap<ExchangeId, Option<Duration>>,
+
+    /// Index of the [`Connector::urls`] endpoint to connect to, where `0` is the primary. Rotated
+    /// by the [`consumer`](super::consumer) loops on connection failures (see
+    /// [`failover`](crate::exchange::failover)).
+    pub endpoint: usize,
+
+    /// Maximum number of recycled `Vec<Level>` buffers retained by the
+    /// [`LevelPool`](crate::subscription::book::pool::LevelPool) of each
+    /// [`MultiBookTransformer`](crate::transformer::book::MultiBookTransformer). Zero disables
+    /// pooling.
+    pub level_pool_capacity: usize,
+
+    /// [`Spawner`] used to spawn the background tasks of each connection (eg/ WebSocket
+    /// distribution & ping tasks).
+    #[serde(skip)]
+    pub spawner: Spawner,
+}
+
+impl StreamOptions {
+    /// [`RequestRate`] used to pace subscription requests to the provided exchange
+    /// [`Connector`], if any.
+    ///
+    /// Any override in [`Self::request_rates`] takes precedence over the
+    /// [`Connector::request_rate`].
+    pub fn request_rate<Exchange>(&self) -> Option<RequestRate>
+    where
+        Exchange: Connector,
+    {
+        self.request_rates
+            .get(&Exchange::ID)
+            .copied()
+            .unwrap_or_else(Exchange::request_rate)
+    }
+
+    /// [`PongTimeout`] deadline within which the provided exchange [`Connector`] must respond to
+    /// keepalive pings, if any.
+    ///
+    /// Any override in [`Self::pong_timeouts`] takes precedence over the
+    /// [`Connector::pong_timeout`] deadline. Overrides have no effect if the [`Connector`] does
+    /// not track pong responses.
+    pub fn pong_timeout<Exchange>(&self) -> Option<PongTimeout>
+    where
+        Exchange: Connector,
+    {
+        let pong_timeout = Exchange::pong_timeout()?;
+        match self.pong_timeouts.get(&Exchange::ID) {
+            Some(Some(timeout)) => Some(PongTimeout {
+                timeout: *timeout,
+                ..pong_timeout
+            }),
+            Some(None) => None,
+            None => Some(pong_timeout),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::exchange::{
+        binance::spot::{BinanceSpot, REQUEST_RATE_BINANCE_SPOT},
+        bybit::{spot::BybitSpot, PONG_TIMEOUT_BYBIT},
+        coinbase::Coinbase,
+        okx::Okx,
+    };
+
+    #[test]
+    fn test_request_rate() {
+        // Defaults to the exchange Connector::request_rate
+        let options = StreamOptions::default();
+        assert_eq!(
+            options.request_rate::<BinanceSpot>(),
+            Some(REQUEST_RATE_BINANCE_SPOT)
+        );
+        assert_eq!(options.request_rate::<Coinbase>(), None);
+
+        // Overrides take precedence, including disabling pacing
+        let rate = RequestRate::new(1, Duration::from_secs(1));
+        let options = StreamOptions {
+            request_rates: HashMap::from([
+                (ExchangeId::BinanceSpot, None),
+                (ExchangeId::Coinbase, Some(rate)),
+            ]),
+            ..Default::default()
+        };
+        assert_eq!(options.request_rate::<BinanceSpot>(), None);
+        assert_eq!(options.request_rate::<Coinbase>(), Some(rate));
+    }
+
+    #[test]
+    fn test_pong_timeout() {
+        let timeout = |options: &StreamOptions| {
+            (
+                options
+                    .pong_timeout::<BybitSpot>()
+                    .map(|pong_timeout| pong_timeout.timeout),
+                options.pong_timeout::<Okx>().is_some(),
+                options.pong_timeout::<Coinbase>().is_some(),
+            )
+        };
+
+        // Defaults to the exchange Connector::pong_timeout
+        let options = StreamOptions::default();
+        assert_eq!(timeout(&options), (Some(PONG_TIMEOUT_BYBIT), true, false));
+
+        // Overrides take precedence, including disabling pong tracking, but are ignored for
+        // exchanges that do not track pong responses
+        let options = StreamOptions {
+            pong_timeouts: HashMap::from([
+                (ExchangeId::BybitSpot, Some(Duration::from_secs(5))),
+                (ExchangeId::Okx, None),
+                (ExchangeId::Coinbase, Some(Duration::from_secs(5))),
+            ]),
+            ..Default::default()
+        };
+        assert_eq!(
+            timeout(&options),
+            (Some(Duration::from_secs(5)), false, false)
+        );
+    }
+}
@@ -0,0 +1,163 @@
+use crate::event::MarketEvent;
+use chrono::{DateTime, Utc};
+use std::time::Duration;
+use tokio::time::Instant;
+
+/// Bounded reordering window that releases [`MarketEvent`]s sorted by `exchange_time`.
+///
+/// Each buffered [`MarketEvent`] is held for at most `max_lateness` after it is received. Once it
+/// is due, it is released alongside every buffered [`MarketEvent`] with an earlier (or equal)
+/// `exchange_time`, in `exchange_time` order. Events with equal `exchange_time`s are released in
+/// the order they were received.
+///
+/// Events that arrive after a later `exchange_time` has already been released can no longer be
+/// ordered, so are released immediately.
+///
+/// See [`Streams::ordered`](super::Streams::ordered).
+#[derive(Debug)]
+pub struct OrderingBuffer<InstrumentId, T> {
+    max_lateness: Duration,
+    sequence: u64,
+    released: Option<DateTime<Utc>>,
+    pending: Vec<Pending<InstrumentId, T>>,
+}
+
+#[derive(Debug)]
+struct Pending<InstrumentId, T> {
+    deadline: Instant,
+    sequence: u64,
+    event: MarketEvent<InstrumentId, T>,
+}
+
+impl<InstrumentId, T> OrderingBuffer<InstrumentId, T> {
+    /// Construct a new empty [`Self`] with the provided `max_lateness` reordering window.
+    pub fn new(max_lateness: Duration) -> Self {
+        Self {
+            max_lateness,
+            sequence: 0,
+            released: None,
+            pending: Vec::new(),
+        }
+    }
+
+    /// Buffer the [`MarketEvent`] received at the provided [`Instant`], returning it if it must
+    /// be released immediately since a later `exchange_time` has already been released.
+    pub fn push(
+        &mut self,
+        event: MarketEvent<InstrumentId, T>,
+        now: Instant,
+    ) -> Option<MarketEvent<InstrumentId, T>> {
+        if self
+            .released
+            .is_some_and(|released| event.exchange_time < released)
+        {
+            return Some(event);
+        }
+
+        self.sequence += 1;
+        self.pending.push(Pending {
+            deadline: now + self.max_lateness,
+            sequence: self.sequence,
+            event,
+        });
+        None
+    }
+
+    /// Earliest [`Instant`] a buffered [`MarketEvent`] is due for release, if any are buffered.
+    pub fn next_release(&self) -> Option<Instant> {
+        self.pending.iter().map(|pending| pending.deadline).min()
+    }
+
+    /// Remove every [`MarketEvent`] due for release at the provided [`Instant`], sorted by
+    /// `exchange_time`.
+    pub fn release(&mut self, now: Instant) -> Vec<MarketEvent<InstrumentId, T>> {
+        let cutoff = self
+            .pending
+            .iter()
+            .filter(|pending| pending.deadline <= now)
+            .map(|pending| pending.event.exchange_time)
+            .max();
+
+        match cutoff {
+            Some(cutoff) => self.take(|pending| pending.event.exchange_time <= cutoff),
+            None => Vec::new(),
+        }
+    }
+
+    /// Remove every buffered [`MarketEvent`], sorted by `exchange_time`.
+    pub fn drain(&mut self) -> Vec<MarketEvent<InstrumentId, T>> {
+        self.take(|_| true)
+    }
+
+    fn take<F>(&mut self, mut release: F) -> Vec<MarketEvent<InstrumentId, T>>
+    where
+        F: FnMut(&Pending<InstrumentId, T>) -> bool,
+    {
+        let (mut released, pending) = std::mem::take(&mut self.pending)
+            .into_iter()
+            .partition::<Vec<_>, _>(|pending| release(pending));
+        self.pending = pending;
+
+        released.sort_by_key(|pending| (pending.event.exchange_time, pending.sequence));
+        if let Some(last) = released.last() {
+            self.released = Some(last.event.exchange_time);
+        }
+
+        released.into_iter().map(|pending| pending.event).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use barter_integration::model::Exchange;
+
+    #[test]
+    fn test_ordering_buffer() {
+        let start = Utc::now();
+        let event = |millis: i64, kind: u64| MarketEvent {
+            exchange_time: start + chrono::Duration::milliseconds(millis),
+            received_time: Utc::now(),
+            exchange: Exchange::from("exchange"),
+            instrument: "btc_usdt",
+            kind,
+        };
+        let kinds = |events: Vec<MarketEvent<&str, u64>>| {
+            events
+                .into_iter()
+                .map(|event| event.kind)
+                .collect::<Vec<_>>()
+        };
+
+        let max_lateness = Duration::from_millis(100);
+        let now = Instant::now();
+        let mut buffer = OrderingBuffer::new(max_lateness);
+
+        // Events are buffered, rather than released immediately
+        assert!(buffer.push(event(20, 1), now).is_none());
+        assert!(buffer.push(event(10, 2), now).is_none());
+        assert!(buffer.push(event(40, 3), now + max_lateness / 2).is_none());
+        assert!(buffer.push(event(10, 4), now + max_lateness / 2).is_none());
+        assert_eq!(buffer.next_release(), Some(now + max_lateness));
+
+        // Nothing is released before the max_lateness has elapsed
+        assert!(buffer.release(now + max_lateness / 4).is_empty());
+
+        // Due events are released in exchange_time order, alongside any earlier events
+        assert_eq!(kinds(buffer.release(now + max_lateness)), vec![2, 4, 1]);
+        assert_eq!(buffer.next_release(), Some(now + max_lateness * 3 / 2));
+
+        // Events that can no longer be ordered are released immediately
+        assert_eq!(
+            buffer
+                .push(event(15, 5), now + max_lateness)
+                .map(|e| e.kind),
+            Some(5)
+        );
+
+        // Remaining events are drained in exchange_time order
+        assert!(buffer.push(event(30, 6), now + max_lateness).is_none());
+        assert_eq!(kinds(buffer.drain()), vec![6, 3]);
+        assert_eq!(buffer.next_release(), None);
+    }
+}
@@ -0,0 +1,229 @@
+use crate::exchange::ExchangeId;
+use std::{
+    collections::{HashMap, VecDeque},
+    sync::{Arc, Mutex, MutexGuard, PoisonError},
+};
+use tokio::sync::{mpsc, watch};
+use tracing::warn;
+
+/// Maximum number of events buffered by each consumer loop whilst its exchange is paused, after
+/// which the oldest buffered events are dropped.
+pub const PAUSE_BUFFER_CAPACITY: usize = 10_000;
+
+/// Shared, cloneable handle used to pause & resume the delivery of events from individual
+/// exchange connections, without dropping their [`Subscription`](crate::subscription::Subscription)s.
+///
+/// Whilst an exchange is paused, its connections continue to be consumed (keeping them alive), but
+/// events are buffered rather than distributed. Once resumed, the buffered events are distributed
+/// in their original order, followed by any new events. At most [`PAUSE_BUFFER_CAPACITY`] events
+/// are buffered by each consumer loop, so long pauses of high volume streams drop the oldest
+/// events.
+///
+/// Useful for maintenance windows, or throttling whilst a strategy restarts.
+#[derive(Clone, Debug)]
+pub struct StreamsHandle {
+    registries: Vec<Registry>,
+}
+
+type Registry = Arc<Mutex<HashMap<ExchangeId, watch::Sender<bool>>>>;
+
+impl Default for StreamsHandle {
+    fn default() -> Self {
+        Self {
+            registries: vec![Registry::default()],
+        }
+    }
+}
+
+impl StreamsHandle {
+    /// Merge the exchange connections controlled by another [`StreamsHandle`] into this one.
+    pub fn merge(&mut self, other: StreamsHandle) {
+        self.registries.extend(other.registries);
+    }
+
+    /// Pause the delivery of events from every connection of the provided [`ExchangeId`],
+    /// buffering them until [`Self::resume`] is invoked.
+    pub fn pause(&self, exchange: ExchangeId) {
+        self.set_paused(exchange, true)
+    }
+
+    /// Resume the delivery of events from every connection of the provided [`ExchangeId`],
+    /// distributing any events buffered whilst paused.
+    pub fn resume(&self, exchange: ExchangeId) {
+        self.set_paused(exchange, false)
+    }
+
+    /// Determine if the provided [`ExchangeId`] is paused.
+    pub fn is_paused(&self, exchange: ExchangeId) -> bool {
+        self.registries.iter().any(|registry| {
+            lock(registry)
+                .get(&exchange)
+                .is_some_and(|paused| *paused.borrow())
+        })
+    }
+
+    fn set_paused(&self, exchange: ExchangeId, paused: bool) {
+        self.registries.iter().for_each(|registry| {
+            lock(registry)
+                .entry(exchange)
+                .or_insert_with(|| watch::channel(false).0)
+                .send_replace(paused);
+        });
+    }
+
+    /// Construct the [`Gate`] used by a consumer loop to distribute events of the provided
+    /// [`ExchangeId`], observing its paused state in every merged registry.
+    pub(crate) fn gate<T>(&self, exchange: ExchangeId) -> Gate<T> {
+        Gate {
+            exchange,
+            paused: self
+                .registries
+                .iter()
+                .map(|registry| {
+                    lock(registry)
+                        .entry(exchange)
+                        .or_insert_with(|| watch::channel(false).0)
+                        .subscribe()
+                })
+                .collect(),
+            buffer: VecDeque::new(),
+        }
+    }
+}
+
+/// Pausable distribution of the events consumed by a consumer loop, buffering events whilst the
+/// exchange is paused via the [`StreamsHandle`].
+#[derive(Debug)]
+pub(crate) struct Gate<T> {
+    exchange: ExchangeId,
+    paused: Vec<watch::Receiver<bool>>,
+    buffer: VecDeque<T>,
+}
+
+impl<T> Gate<T> {
+    fn is_paused(&self) -> bool {
+        self.paused.iter().any(|paused| *paused.borrow())
+    }
+
+    /// Distribute the event via the `exchange_tx`, or buffer it whilst paused.
+    pub(crate) fn send(
+        &mut self,
+        event: T,
+        exchange_tx: &mpsc::UnboundedSender<T>,
+    ) -> Result<(), mpsc::error::SendError<T>> {
+        if !self.is_paused() {
+            self.flush(exchange_tx)?;
+            return exchange_tx.send(event);
+        }
+
+        if self.buffer.len() >= PAUSE_BUFFER_CAPACITY {
+            warn!(
+                exchange = %self.exchange,
+                capacity = PAUSE_BUFFER_CAPACITY,
+                action = "dropping oldest buffered event",
+                "paused event buffer is full"
+            );
+            self.buffer.pop_front();
+        }
+        self.buffer.push_back(event);
+        Ok(())
+    }
+
+    /// Distribute every buffered event via the `exchange_tx`.
+    pub(crate) fn flush(
+        &mut self,
+        exchange_tx: &mpsc::UnboundedSender<T>,
+    ) -> Result<(), mpsc::error::SendError<T>> {
+        self.buffer
+            .drain(..)
+            .try_for_each(|event| exchange_tx.send(event))
+    }
+
+    /// Wait until the exchange is resumed whilst events are buffered, after which they should be
+    /// distributed via [`Self::flush`]. Never completes if no events are buffered.
+    pub(crate) async fn resumed(&mut self) {
+        if self.buffer.is_empty() {
+            return std::future::pending().await;
+        }
+
+        loop {
+            // Mark every paused state as seen, so only subsequent changes are awaited
+            let mut paused = false;
+            for receiver in &mut self.paused {
+                paused |= *receiver.borrow_and_update();
+            }
+            if !paused {
+                return;
+            }
+
+            let changed = futures::future::select_all(
+                self.paused
+                    .iter_mut()
+                    .map(|paused| Box::pin(paused.changed())),
+            );
+            if changed.await.0.is_err() {
+                // StreamsHandle dropped, so the exchange can no longer be resumed
+                return std::future::pending().await;
+            }
+        }
+    }
+}
+
+fn lock(registry: &Registry) -> MutexGuard<'_, HashMap<ExchangeId, watch::Sender<bool>>> {
+    registry.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_streams_handle() {
+        let handle = StreamsHandle::default();
+        let mut merged = StreamsHandle::default();
+        merged.merge(handle.clone());
+
+        let (exchange_tx, mut exchange_rx) = mpsc::unbounded_channel();
+        let mut gate = merged.gate(ExchangeId::Okx);
+
+        // Events are distributed whilst not paused
+        gate.send(1, &exchange_tx).unwrap();
+        assert_eq!(exchange_rx.try_recv(), Ok(1));
+
+        // Events are buffered whilst paused via any merged StreamsHandle
+        handle.pause(ExchangeId::Okx);
+        assert!(merged.is_paused(ExchangeId::Okx));
+        assert!(!merged.is_paused(ExchangeId::Kraken));
+        gate.send(2, &exchange_tx).unwrap();
+        gate.send(3, &exchange_tx).unwrap();
+        assert!(exchange_rx.try_recv().is_err());
+
+        // Gate is resumed once every merged StreamsHandle is resumed
+        handle.resume(ExchangeId::Okx);
+        tokio::time::timeout(std::time::Duration::from_secs(1), gate.resumed())
+            .await
+            .unwrap();
+
+        // Buffered events are distributed in order, followed by new events
+        gate.send(4, &exchange_tx).unwrap();
+        assert_eq!(exchange_rx.try_recv(), Ok(2));
+        assert_eq!(exchange_rx.try_recv(), Ok(3));
+        assert_eq!(exchange_rx.try_recv(), Ok(4));
+    }
+
+    #[test]
+    fn test_gate_bounded_buffer() {
+        let handle = StreamsHandle::default();
+        let (exchange_tx, mut exchange_rx) = mpsc::unbounded_channel();
+        let mut gate = handle.gate(ExchangeId::Okx);
+
+        // Oldest buffered events are dropped once the buffer is full
+        handle.pause(ExchangeId::Okx);
+        (0..PAUSE_BUFFER_CAPACITY + 2).for_each(|event| gate.send(event, &exchange_tx).unwrap());
+
+        handle.resume(ExchangeId::Okx);
+        gate.flush(&exchange_tx).unwrap();
+        assert_eq!(exchange_rx.try_recv(), Ok(2));
+        assert_eq!(exchange_rx.len(), PAUSE_BUFFER_CAPACITY - 1);
+    }
+}
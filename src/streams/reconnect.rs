@@ -0,0 +1,134 @@
+use super::consumer::STARTING_RECONNECT_BACKOFF_MS;
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::hash_map::RandomState,
+    hash::{BuildHasher, Hasher},
+    time::Duration,
+};
+
+/// Exponential backoff policy used by the [`consumer`](super::consumer) loops to re-initialise a
+/// [`MarketStream`](crate::MarketStream) after it disconnects.
+///
+/// The backoff before re-connection attempt `n` is `initial_backoff * multiplier^n`, capped at
+/// `max_backoff`. Each backoff is reduced by a random fraction of up to `jitter` (0.0 to 1.0), so
+/// connections that dropped together do not re-connect in lockstep.
+///
+/// eg/ `ReconnectPolicy { max_retries: Some(10), jitter: 0.5, ..Default::default() }`
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct ReconnectPolicy {
+    pub initial_backoff: Duration,
+    pub max_backoff: Duration,
+    pub multiplier: f64,
+    /// Maximum consecutive failed re-connection attempts before the consumer loop ends, or `None`
+    /// to retry indefinitely.
+    pub max_retries: Option<u32>,
+    pub jitter: f64,
+}
+
+impl Default for ReconnectPolicy {
+    fn default() -> Self {
+        Self {
+            initial_backoff: Duration::from_millis(STARTING_RECONNECT_BACKOFF_MS),
+            max_backoff: Duration::from_secs(60),
+            multiplier: 2.0,
+            max_retries: None,
+            jitter: 0.0,
+        }
+    }
+}
+
+impl ReconnectPolicy {
+    /// Backoff to wait before the provided re-connection attempt, where `0` is the first attempt
+    /// after a disconnection.
+    pub fn backoff(&self, attempt: u32) -> Duration {
+        let exponent = i32::try_from(attempt).unwrap_or(i32::MAX);
+        let backoff = self.initial_backoff.as_secs_f64() * self.multiplier.powi(exponent);
+        let backoff = backoff.min(self.max_backoff.as_secs_f64());
+
+        let jitter = self.jitter.clamp(0.0, 1.0) * unit_random();
+        Duration::from_secs_f64(backoff * (1.0 - jitter))
+    }
+
+    /// Determines if the provided number of consecutive failed re-connection attempts exhausts
+    /// this policy.
+    pub fn exhausted(&self, failed_attempts: u32) -> bool {
+        self.max_retries
+            .is_some_and(|max_retries| failed_attempts >= max_retries)
+    }
+}
+
+/// Random `f64` in the range [0.0, 1.0), sourced from the randomly seeded std [`RandomState`].
+fn unit_random() -> f64 {
+    let random = RandomState::new().build_hasher().finish();
+    (random >> 11) as f64 / (1_u64 << 53) as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_backoff() {
+        struct TestCase {
+            attempt: u32,
+            expected: Duration,
+        }
+
+        let policy = ReconnectPolicy {
+            initial_backoff: Duration::from_millis(100),
+            max_backoff: Duration::from_secs(1),
+            multiplier: 3.0,
+            max_retries: Some(5),
+            jitter: 0.0,
+        };
+
+        let tests = vec![
+            TestCase {
+                // TC0: first attempt uses the initial backoff
+                attempt: 0,
+                expected: Duration::from_millis(100),
+            },
+            TestCase {
+                // TC1: backoff grows by the multiplier
+                attempt: 2,
+                expected: Duration::from_millis(900),
+            },
+            TestCase {
+                // TC2: backoff is capped at the max backoff
+                attempt: 3,
+                expected: Duration::from_secs(1),
+            },
+            TestCase {
+                // TC3: large attempts do not overflow
+                attempt: u32::MAX,
+                expected: Duration::from_secs(1),
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = policy.backoff(test.attempt);
+            assert_eq!(
+                actual.as_millis(),
+                test.expected.as_millis(),
+                "TC{} failed",
+                index
+            );
+        }
+
+        assert!(!policy.exhausted(4));
+        assert!(policy.exhausted(5));
+    }
+
+    #[test]
+    fn test_backoff_jitter() {
+        let policy = ReconnectPolicy {
+            jitter: 0.5,
+            ..Default::default()
+        };
+
+        for _ in 0..100 {
+            let actual = policy.backoff(0);
+            assert!(actual >= Duration::from_micros(62_500) && actual <= policy.initial_backoff);
+        }
+    }
+}
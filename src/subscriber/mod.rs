@@ -1,38 +1,60 @@
 use self::{
     mapper::{SubscriptionMapper, WebSocketSubMapper},
-    validator::SubscriptionValidator,
+    pacing::RequestPacer,
+    validator::{SubscriptionRejection, SubscriptionValidator},
 };
 use crate::instrument::InstrumentData;
 use crate::{
-    exchange::Connector,
+    exchange::{failover, Connector},
+    streams::options::StreamOptions,
     subscription::{Map, Subscription, SubscriptionKind, SubscriptionMeta},
     Identifier,
 };
 use async_trait::async_trait;
 use barter_integration::{
     error::SocketError,
-    protocol::websocket::{connect, WebSocket},
+    protocol::websocket::{connect, WebSocket, WsMessage},
 };
 use futures::SinkExt;
 use serde::{Deserialize, Serialize};
 use tracing::{debug, info};
+use url::Url;
 
 /// [`SubscriptionMapper`] implementations defining how to map a
 /// collection of Barter [`Subscription`]s into exchange specific [`SubscriptionMeta`].
 pub mod mapper;
 
+/// [`RequestRate`](pacing::RequestRate) pacing of outgoing subscription requests, preventing
+/// large collections of [`Subscription`]s exceeding exchange uplink rate limits.
+pub mod pacing;
+
 /// [`SubscriptionValidator`] implementations defining how to
 /// validate actioned [`Subscription`]s were successful.
 pub mod validator;
 
 /// Defines how to connect to a socket and subscribe to market data streams.
+///
+/// Returns the subscribed [`WebSocket`], the validated [`Map`], any market data
+/// [`WsMessage`]s buffered during [`SubscriptionValidator::validate`], and any
+/// [`SubscriptionRejection`]s tolerated by the
+/// [`SubscriptionFailurePolicy`](validator::SubscriptionFailurePolicy) of the provided
+/// [`StreamOptions`].
 #[async_trait]
 pub trait Subscriber {
     type SubMapper: SubscriptionMapper;
 
     async fn subscribe<Exchange, Instrument, Kind>(
         subscriptions: &[Subscription<Exchange, Instrument, Kind>],
-    ) -> Result<(WebSocket, Map<Instrument::Id>), SocketError>
+        options: &StreamOptions,
+    ) -> Result<
+        (
+            WebSocket,
+            Map<Instrument::Id>,
+            Vec<WsMessage>,
+            Vec<SubscriptionRejection>,
+        ),
+        SocketError,
+    >
     where
         Exchange: Connector + Send + Sync,
         Kind: SubscriptionKind + Send + Sync,
@@ -51,7 +73,50 @@ impl Subscriber for WebSocketSubscriber {
 
     async fn subscribe<Exchange, Instrument, Kind>(
         subscriptions: &[Subscription<Exchange, Instrument, Kind>],
-    ) -> Result<(WebSocket, Map<Instrument::Id>), SocketError>
+        options: &StreamOptions,
+    ) -> Result<
+        (
+            WebSocket,
+            Map<Instrument::Id>,
+            Vec<WsMessage>,
+            Vec<SubscriptionRejection>,
+        ),
+        SocketError,
+    >
+    where
+        Exchange: Connector + Send + Sync,
+        Kind: SubscriptionKind + Send + Sync,
+        Instrument: InstrumentData,
+        Subscription<Exchange, Instrument, Kind>:
+            Identifier<Exchange::Channel> + Identifier<Exchange::Market>,
+    {
+        Self::subscribe_to(
+            failover::url::<Exchange>(options.endpoint)?,
+            subscriptions,
+            options,
+        )
+        .await
+    }
+}
+
+impl WebSocketSubscriber {
+    /// Connect to the provided [`Url`] and subscribe to the provided [`Subscription`]s, rather
+    /// than the default [`Connector::url`].
+    ///
+    /// Useful for subscribing via alternative exchange servers (eg/ mock or recorded sessions).
+    pub async fn subscribe_to<Exchange, Instrument, Kind>(
+        url: Url,
+        subscriptions: &[Subscription<Exchange, Instrument, Kind>],
+        options: &StreamOptions,
+    ) -> Result<
+        (
+            WebSocket,
+            Map<Instrument::Id>,
+            Vec<WsMessage>,
+            Vec<SubscriptionRejection>,
+        ),
+        SocketError,
+    >
     where
         Exchange: Connector + Send + Sync,
         Kind: SubscriptionKind + Send + Sync,
@@ -61,9 +126,20 @@ impl Subscriber for WebSocketSubscriber {
     {
         // Define variables for logging ergonomics
         let exchange = Exchange::ID;
-        let url = Exchange::url()?;
         debug!(%exchange, %url, ?subscriptions, "subscribing to WebSocket");
 
+        // Reject SubscriptionKinds the exchange does not support (eg/ runtime DataKinds) before
+        // they are mapped to exchange specific subscriptions
+        if let Some(subscription) = subscriptions
+            .iter()
+            .find(|sub| !sub.kind.supported_by(exchange, sub.instrument.kind()))
+        {
+            return Err(SocketError::Unsupported {
+                entity: exchange.as_str(),
+                item: format!("{:?} {}", subscription.kind, subscription.instrument.kind()),
+            });
+        }
+
         // Connect to exchange
         let mut websocket = connect(url).await?;
         debug!(%exchange, ?subscriptions, "connected to WebSocket");
@@ -72,22 +148,28 @@ impl Subscriber for WebSocketSubscriber {
         let SubscriptionMeta {
             instrument_map,
             subscriptions,
-        } = Self::SubMapper::map::<Exchange, Instrument, Kind>(subscriptions);
+        } = WebSocketSubMapper::map::<Exchange, Instrument, Kind>(subscriptions);
 
-        // Send Subscriptions over WebSocket
+        // Send Subscriptions over WebSocket, pacing them to respect any exchange RequestRate
+        let mut pacer = options.request_rate::<Exchange>().map(RequestPacer::new);
         for subscription in subscriptions {
+            if let Some(pacer) = pacer.as_mut() {
+                pacer.wait().await;
+            }
             debug!(%exchange, payload = ?subscription, "sending exchange subscription");
             websocket.send(subscription).await?;
         }
 
         // Validate Subscription responses
-        let map = Exchange::SubValidator::validate::<Exchange, Instrument, Kind>(
-            instrument_map,
-            &mut websocket,
-        )
-        .await?;
+        let (map, buffered, rejections) =
+            Exchange::SubValidator::validate::<Exchange, Instrument, Kind>(
+                instrument_map,
+                &mut websocket,
+                options.failure_policy,
+            )
+            .await?;
 
         info!(%exchange, "subscribed to WebSocket");
-        Ok((websocket, map))
+        Ok((websocket, map, buffered, rejections))
     }
 }
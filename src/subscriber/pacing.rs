@@ -0,0 +1,85 @@
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+use tokio::time::Instant;
+
+/// Maximum number of subscription [`WsMessage`](barter_integration::protocol::websocket::WsMessage)s
+/// that may be sent to an exchange server every `interval`, before the connection is dropped.
+///
+/// eg/ KuCoin allows 100 uplink messages every 10 seconds.
+#[derive(
+    Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default, Deserialize, Serialize,
+)]
+pub struct RequestRate {
+    pub messages: usize,
+    pub interval: Duration,
+}
+
+impl RequestRate {
+    /// Construct a new [`Self`].
+    pub const fn new(messages: usize, interval: Duration) -> Self {
+        Self { messages, interval }
+    }
+}
+
+/// Paces outgoing subscription requests to respect a [`RequestRate`].
+///
+/// At most [`RequestRate::messages`] requests are permitted within each window of
+/// [`RequestRate::interval`], which starts when the first request of the window is sent.
+#[derive(Copy, Clone, Debug)]
+pub struct RequestPacer {
+    rate: RequestRate,
+    window_start: Option<Instant>,
+    sent: usize,
+}
+
+impl RequestPacer {
+    /// Construct a new [`Self`].
+    pub fn new(rate: RequestRate) -> Self {
+        Self {
+            rate,
+            window_start: None,
+            sent: 0,
+        }
+    }
+
+    /// Wait until another request may be sent without exceeding the [`RequestRate`].
+    pub async fn wait(&mut self) {
+        if let Some(window_start) = self.window_start {
+            if self.sent >= self.rate.messages.max(1) {
+                tokio::time::sleep_until(window_start + self.rate.interval).await;
+                self.window_start = None;
+            }
+        }
+
+        if self.window_start.is_none() {
+            self.window_start = Some(Instant::now());
+            self.sent = 0;
+        }
+
+        self.sent += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test(start_paused = true)]
+    async fn test_request_pacer() {
+        let start = Instant::now();
+        let mut pacer = RequestPacer::new(RequestRate::new(2, Duration::from_secs(10)));
+
+        // First window of requests is sent immediately
+        pacer.wait().await;
+        pacer.wait().await;
+        assert_eq!(start.elapsed(), Duration::ZERO);
+
+        // Next request waits for the next window
+        pacer.wait().await;
+        assert_eq!(start.elapsed(), Duration::from_secs(10));
+        pacer.wait().await;
+        assert_eq!(start.elapsed(), Duration::from_secs(10));
+        pacer.wait().await;
+        assert_eq!(start.elapsed(), Duration::from_secs(20));
+    }
+}
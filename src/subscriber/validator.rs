@@ -6,18 +6,51 @@ use crate::{
 use async_trait::async_trait;
 use barter_integration::{
     error::SocketError,
+    model::SubscriptionId,
     protocol::{
-        websocket::{WebSocket, WebSocketParser},
+        websocket::{WebSocket, WebSocketParser, WsMessage},
         StreamParser,
     },
     Validator,
 };
 use futures::StreamExt;
 use serde::{Deserialize, Serialize};
-use tracing::debug;
+use tracing::{debug, warn};
+
+/// Defines how a [`SubscriptionValidator`] handles the exchange rejecting some of the actioned
+/// [`Subscription`](crate::subscription::Subscription)s.
+#[derive(
+    Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default, Deserialize, Serialize,
+)]
+pub enum SubscriptionFailurePolicy {
+    /// Fail the whole connection if any [`Subscription`](crate::subscription::Subscription) is
+    /// rejected.
+    #[default]
+    FailFast,
+
+    /// Continue streaming the accepted [`Subscription`](crate::subscription::Subscription)s,
+    /// returning a [`SubscriptionRejection`] for each rejected one.
+    ///
+    /// Only applies to rejections the exchange [`Connector::rejected_subscription`] can
+    /// attribute to a [`SubscriptionId`], otherwise the connection still fails.
+    ContinueWithAccepted,
+}
+
+/// [`Subscription`](crate::subscription::Subscription) rejected by the exchange whilst validating
+/// with the [`SubscriptionFailurePolicy::ContinueWithAccepted`] policy.
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct SubscriptionRejection {
+    pub subscription_id: SubscriptionId,
+    pub reason: String,
+}
 
 /// Defines how to validate that actioned market data
 /// [`Subscription`](crate::subscription::Subscription)s were accepted by the exchange.
+///
+/// Alongside the validated [`Map`], any market data [`WsMessage`]s received during validation
+/// that must not be dropped (eg/ initial OrderBook snapshots) are returned so they can be
+/// transformed before the rest of the stream, as well as any [`SubscriptionRejection`]s tolerated
+/// by the provided [`SubscriptionFailurePolicy`].
 #[async_trait]
 pub trait SubscriptionValidator {
     type Parser: StreamParser;
@@ -25,7 +58,15 @@ pub trait SubscriptionValidator {
     async fn validate<Exchange, Instrument, Kind>(
         instrument_map: Map<Instrument::Id>,
         websocket: &mut WebSocket,
-    ) -> Result<Map<Instrument::Id>, SocketError>
+        policy: SubscriptionFailurePolicy,
+    ) -> Result<
+        (
+            Map<Instrument::Id>,
+            Vec<WsMessage>,
+            Vec<SubscriptionRejection>,
+        ),
+        SocketError,
+    >
     where
         Exchange: Connector + Send,
         Instrument: InstrumentData,
@@ -41,9 +82,17 @@ impl SubscriptionValidator for WebSocketSubValidator {
     type Parser = WebSocketParser;
 
     async fn validate<Exchange, Instrument, Kind>(
-        instrument_map: Map<Instrument::Id>,
+        mut instrument_map: Map<Instrument::Id>,
         websocket: &mut WebSocket,
-    ) -> Result<Map<Instrument::Id>, SocketError>
+        policy: SubscriptionFailurePolicy,
+    ) -> Result<
+        (
+            Map<Instrument::Id>,
+            Vec<WsMessage>,
+            Vec<SubscriptionRejection>,
+        ),
+        SocketError,
+    >
     where
         Exchange: Connector + Send,
         Instrument: InstrumentData,
@@ -53,14 +102,21 @@ impl SubscriptionValidator for WebSocketSubValidator {
         let timeout = Exchange::subscription_timeout();
         let expected_responses = Exchange::expected_responses(&instrument_map);
 
-        // Parameter to keep track of successful Subscription outcomes
+        // Parameters to keep track of successful & rejected Subscription outcomes
         let mut success_responses = 0usize;
+        let mut rejections = Vec::new();
 
         loop {
-            // Break if all Subscriptions were a success
-            if success_responses == expected_responses {
+            // Break if all Subscriptions were a success, or all rejections are tolerated
+            if success_responses + rejections.len() == expected_responses {
+                if success_responses == 0 && !rejections.is_empty() {
+                    break Err(SocketError::Subscribe(
+                        "all subscriptions were rejected".to_string(),
+                    ));
+                }
+
                 debug!(exchange = %Exchange::ID, "validated exchange WebSocket subscriptions");
-                break Ok(instrument_map);
+                break Ok((instrument_map, Vec::new(), rejections));
             }
 
             tokio::select! {
@@ -78,21 +134,48 @@ impl SubscriptionValidator for WebSocketSubValidator {
                     };
 
                     match Self::Parser::parse::<Exchange::SubResponse>(response) {
-                        Some(Ok(response)) => match response.validate() {
-                            // Subscription success
-                            Ok(response) => {
-                                success_responses += 1;
-                                debug!(
-                                    exchange = %Exchange::ID,
-                                    %success_responses,
-                                    %expected_responses,
-                                    payload = ?response,
-                                    "received valid Ok subscription response",
-                                );
-                            }
+                        Some(Ok(response)) => {
+                            let rejected = Exchange::rejected_subscription(&response);
+
+                            match response.validate() {
+                                // Subscription success
+                                Ok(response) => {
+                                    success_responses += 1;
+                                    debug!(
+                                        exchange = %Exchange::ID,
+                                        %success_responses,
+                                        %expected_responses,
+                                        payload = ?response,
+                                        "received valid Ok subscription response",
+                                    );
+                                }
 
-                            // Subscription failure
-                            Err(err) => break Err(err)
+                                // Subscription failure
+                                Err(err) => {
+                                    // Tolerate attributable rejections if the policy allows
+                                    let tolerated = match (policy, rejected) {
+                                        (SubscriptionFailurePolicy::ContinueWithAccepted, Some(id)) => {
+                                            instrument_map.0.remove(&id).map(|_| id)
+                                        }
+                                        _ => None,
+                                    };
+
+                                    let Some(subscription_id) = tolerated else {
+                                        break Err(err)
+                                    };
+
+                                    warn!(
+                                        exchange = %Exchange::ID,
+                                        %subscription_id,
+                                        error = %err,
+                                        "continuing with accepted subscriptions after rejection",
+                                    );
+                                    rejections.push(SubscriptionRejection {
+                                        subscription_id,
+                                        reason: err.to_string(),
+                                    });
+                                }
+                            }
                         }
                         Some(Err(SocketError::Deserialise { error, payload })) if success_responses >= 1 => {
                             // Already active subscription payloads, so skip to next SubResponse
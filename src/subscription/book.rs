@@ -1,4 +1,4 @@
-use super::SubscriptionKind;
+use super::{SubKind, SubKindId, SubscriptionKind};
 use crate::{
     event::{MarketEvent, MarketIter},
     exchange::ExchangeId,
@@ -7,9 +7,25 @@ use barter_integration::model::{Exchange, Side};
 use barter_macro::{DeSubKind, SerSubKind};
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
-use std::cmp::Ordering;
+use std::{cmp::Ordering, collections::BTreeMap};
 use tracing::debug;
 
+/// [`OrderBookAnalytics`](analytics::OrderBookAnalytics) extension providing common
+/// [`OrderBook`] & [`OrderBookL1`] metrics (eg/ imbalance, microprice, depth within bps).
+pub mod analytics;
+
+/// Feature gated [`Decimal`](rust_decimal::Decimal) [`Level`] & [`OrderBook`] representations,
+/// free from floating point equality issues.
+#[cfg(feature = "decimal")]
+pub mod decimal;
+
+/// Order-by-order [`OrderBookL3`](l3::OrderBookL3) built from [`OrderBookL3Event`]s.
+pub mod l3;
+
+/// Opt-in [`LevelPool`](pool::LevelPool) that recycles the `Vec<Level>` buffers of processed
+/// [`OrderBook`] snapshots.
+pub mod pool;
+
 /// Barter [`Subscription`](super::Subscription) [`SubscriptionKind`] that yields level 1 [`OrderBook`]
 /// [`MarketEvent<T>`](MarketEvent) events.
 ///
@@ -20,6 +36,7 @@ pub struct OrderBooksL1;
 
 impl SubscriptionKind for OrderBooksL1 {
     type Event = OrderBookL1;
+    const ID: SubKindId = SubKindId::Kind(SubKind::OrderBooksL1);
 }
 
 /// Normalised Barter [`OrderBookL1`] snapshot containing the latest best bid and ask.
@@ -33,17 +50,28 @@ pub struct OrderBookL1 {
 impl OrderBookL1 {
     /// Calculate the mid price by taking the average of the best bid and ask prices.
     ///
+    /// Returns `None` if either the best bid or ask [`Level`] has no amount.
+    ///
     /// See Docs: <https://www.quantstart.com/articles/high-frequency-trading-ii-limit-order-book>
-    pub fn mid_price(&self) -> f64 {
-        mid_price(self.best_bid.price, self.best_ask.price)
+    pub fn mid_price(&self) -> Option<f64> {
+        self.is_two_sided()
+            .then(|| mid_price(self.best_bid.price, self.best_ask.price))
     }
 
     /// Calculate the volume weighted mid price (micro-price), weighing the best bid and ask prices
     /// with their associated amount.
     ///
+    /// Returns `None` if either the best bid or ask [`Level`] has no amount.
+    ///
     /// See Docs: <https://www.quantstart.com/articles/high-frequency-trading-ii-limit-order-book>
-    pub fn volume_weighed_mid_price(&self) -> f64 {
-        volume_weighted_mid_price(self.best_bid, self.best_ask)
+    pub fn volume_weighed_mid_price(&self) -> Option<f64> {
+        self.is_two_sided()
+            .then(|| volume_weighted_mid_price(self.best_bid, self.best_ask))
+    }
+
+    /// Determines if both the best bid and ask [`Level`]s contain an amount.
+    fn is_two_sided(&self) -> bool {
+        self.best_bid.amount > 0.0 && self.best_ask.amount > 0.0
     }
 }
 
@@ -51,23 +79,243 @@ impl OrderBookL1 {
 /// [`MarketEvent<T>`](MarketEvent) events.
 ///
 /// Level 2 refers to the [`OrderBook`] aggregated by price.
-#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, DeSubKind, SerSubKind)]
-pub struct OrderBooksL2;
+///
+/// An optional `depth` (number of [`Level`]s per side) can be requested, which each exchange's
+/// `Identifier<Channel>` implementation maps to the closest channel variant it supports. Exchanges
+/// offering a single OrderBook Level2 channel ignore it.
+///
+/// Similarly, an optional `interval_ms` between updates can be requested for exchanges that offer
+/// channel variants with differing update speeds (eg/ Binance `@depth@100ms` vs `@depth`).
+///
+/// eg/ `OrderBooksL2::default()`, `OrderBooksL2::with_depth(200)` or
+/// `OrderBooksL2::with_depth(10).interval_ms(1000)`
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+pub struct OrderBooksL2 {
+    pub depth: Option<u16>,
+    pub interval_ms: Option<u32>,
+}
+
+impl OrderBooksL2 {
+    /// Construct an [`OrderBooksL2`] requesting the provided `depth`.
+    pub const fn with_depth(depth: u16) -> Self {
+        Self {
+            depth: Some(depth),
+            interval_ms: None,
+        }
+    }
+
+    /// Request the provided `interval_ms` between updates.
+    pub const fn interval_ms(self, interval_ms: u32) -> Self {
+        Self {
+            interval_ms: Some(interval_ms),
+            ..self
+        }
+    }
+}
 
 impl SubscriptionKind for OrderBooksL2 {
     type Event = OrderBook;
+    const ID: SubKindId = SubKindId::Kind(SubKind::OrderBooksL2);
 }
 
-/// Barter [`Subscription`](super::Subscription) [`SubscriptionKind`] that yields level 3 [`OrderBook`]
-/// [`MarketEvent<T>`](MarketEvent) events.
+/// [`OrderBooksL2`] configuration, (de)serialised as
+/// `{"order_books_l2": {"depth": 200, "interval_ms": 1000}}`.
+#[derive(Deserialize, Serialize)]
+struct OrderBooksL2Config {
+    order_books_l2: OrderBooksL2Params,
+}
+
+#[derive(Deserialize, Serialize)]
+struct OrderBooksL2Params {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    depth: Option<u16>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    interval_ms: Option<u32>,
+}
+
+impl<'de> Deserialize<'de> for OrderBooksL2 {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::de::Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Input {
+            Name(String),
+            Config(OrderBooksL2Config),
+        }
+
+        match Input::deserialize(deserializer)? {
+            Input::Name(name) if name == "order_books_l2" => Ok(Self::default()),
+            Input::Name(name) => Err(serde::de::Error::invalid_value(
+                serde::de::Unexpected::Str(&name),
+                &"order_books_l2",
+            )),
+            Input::Config(config) => Ok(Self {
+                depth: config.order_books_l2.depth,
+                interval_ms: config.order_books_l2.interval_ms,
+            }),
+        }
+    }
+}
+
+impl Serialize for OrderBooksL2 {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::ser::Serializer,
+    {
+        match (self.depth, self.interval_ms) {
+            (None, None) => serializer.serialize_str("order_books_l2"),
+            (depth, interval_ms) => OrderBooksL2Config {
+                order_books_l2: OrderBooksL2Params { depth, interval_ms },
+            }
+            .serialize(serializer),
+        }
+    }
+}
+
+/// Barter [`Subscription`](super::Subscription) [`SubscriptionKind`] that yields level 2
+/// [`OrderBookEvent`] [`MarketEvent<T>`](MarketEvent) events.
 ///
-/// Level 3 refers to the non-aggregated [`OrderBook`]. This is a direct replication of the exchange
-/// [`OrderBook`].
+/// Unlike [`OrderBooksL2`], the initial [`OrderBook`] snapshot (eg/ fetched via HTTP) is emitted
+/// into the stream as an explicit [`OrderBookEvent::Snapshot`], so persistence layers capture the
+/// full initial state and replays can reconstruct the [`OrderBook`] exactly.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, DeSubKind, SerSubKind)]
+pub struct OrderBooksL2Events;
+
+impl SubscriptionKind for OrderBooksL2Events {
+    type Event = OrderBookEvent;
+    const ID: SubKindId = SubKindId::Kind(SubKind::OrderBooksL2Events);
+}
+
+/// Barter [`Subscription`](super::Subscription) [`SubscriptionKind`] that yields raw level 2
+/// [`OrderBookDelta`] [`MarketEvent<T>`](MarketEvent) events.
+///
+/// Unlike [`OrderBooksL2`], no local [`OrderBook`] is maintained, minimising latency for users
+/// that maintain [`OrderBook`] state in their own engine. Consumers are responsible for fetching
+/// an initial snapshot and validating the [`OrderBookDelta`] sequence.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, DeSubKind, SerSubKind)]
+pub struct OrderBookDeltas;
+
+impl SubscriptionKind for OrderBookDeltas {
+    type Event = OrderBookDelta;
+    const ID: SubKindId = SubKindId::Kind(SubKind::OrderBookDeltas);
+}
+
+/// Normalised Barter level 2 [`OrderBookDelta`], containing the absolute amount of each changed
+/// price [`Level`]. A [`Level`] with zero amount should be removed from the [`OrderBook`].
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct OrderBookDelta {
+    /// First exchange update id contained in this [`OrderBookDelta`].
+    pub first_update_id: u64,
+    /// Last exchange update id contained in this [`OrderBookDelta`].
+    pub last_update_id: u64,
+    /// Last exchange update id of the previous [`OrderBookDelta`], if provided by the exchange.
+    pub prev_last_update_id: Option<u64>,
+    pub bids: Vec<Level>,
+    pub asks: Vec<Level>,
+}
+
+/// Normalised Barter level 2 [`OrderBook`] event.
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Debug, Deserialize, Serialize)]
+pub enum OrderBookEvent {
+    /// Initial [`OrderBook`] snapshot the subsequent [`OrderBookEvent::Update`]s were applied to.
+    Snapshot(OrderBook),
+
+    /// [`OrderBook`] state after applying an exchange update.
+    Update(OrderBook),
+}
+
+impl OrderBookEvent {
+    /// [`OrderBook`] state associated with this [`OrderBookEvent`].
+    pub fn book(&self) -> &OrderBook {
+        match self {
+            Self::Snapshot(book) | Self::Update(book) => book,
+        }
+    }
+}
+
+/// Barter [`Subscription`](super::Subscription) [`SubscriptionKind`] that yields level 3
+/// [`OrderBookL3Event`] [`MarketEvent<T>`](MarketEvent) events.
+///
+/// Level 3 refers to the non-aggregated [`OrderBook`]. Applying each order-by-order
+/// [`OrderBookL3Event`] is a direct replication of the exchange [`OrderBook`].
 #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, DeSubKind, SerSubKind)]
 pub struct OrderBooksL3;
 
 impl SubscriptionKind for OrderBooksL3 {
-    type Event = OrderBook;
+    type Event = OrderBookL3Event;
+    const ID: SubKindId = SubKindId::Kind(SubKind::OrderBooksL3);
+}
+
+/// Normalised Barter order-by-order [`OrderBookL3Event`].
+///
+/// Each event contains the exchange `sequence` number, allowing consumers to detect gaps.
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub enum OrderBookL3Event {
+    /// Order is now open on the [`OrderBook`], with `amount` remaining.
+    Open {
+        sequence: u64,
+        order_id: String,
+        side: Side,
+        price: f64,
+        amount: f64,
+    },
+
+    /// Open order has been modified, and now has the provided `price` and `amount`.
+    Change {
+        sequence: u64,
+        order_id: String,
+        side: Side,
+        price: f64,
+        amount: f64,
+    },
+
+    /// Order is no longer on the [`OrderBook`].
+    ///
+    /// `price` & `remaining` are `None` for market orders, which are never opened.
+    Done {
+        sequence: u64,
+        order_id: String,
+        side: Side,
+        price: Option<f64>,
+        remaining: Option<f64>,
+        reason: OrderBookL3DoneReason,
+    },
+
+    /// Trade occurred between a maker and taker order.
+    ///
+    /// `side` is the [`Side`] of the maker order.
+    Match {
+        sequence: u64,
+        trade_id: String,
+        maker_order_id: String,
+        taker_order_id: String,
+        side: Side,
+        price: f64,
+        amount: f64,
+    },
+}
+
+/// Reason an order is no longer on the [`OrderBook`], as found in [`OrderBookL3Event::Done`].
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub enum OrderBookL3DoneReason {
+    Filled,
+    Cancelled,
+    /// Exchange does not disclose why the order was removed from the [`OrderBook`].
+    Unknown,
+}
+
+impl OrderBookL3Event {
+    /// Exchange sequence number of this [`OrderBookL3Event`].
+    pub fn sequence(&self) -> u64 {
+        match self {
+            Self::Open { sequence, .. }
+            | Self::Change { sequence, .. }
+            | Self::Done { sequence, .. }
+            | Self::Match { sequence, .. } => *sequence,
+        }
+    }
 }
 
 /// Normalised Barter [`OrderBook`] snapshot.
@@ -87,30 +335,54 @@ impl OrderBook {
         self.clone()
     }
 
+    /// Generate an [`OrderBook`] snapshot like [`Self::snapshot`], allocating the cloned
+    /// [`Level`]s from the provided [`LevelPool`](pool::LevelPool).
+    pub fn snapshot_in(&mut self, pool: &mut pool::LevelPool) -> Self {
+        // Sort OrderBook & Clone into pooled buffers
+        self.bids.sort();
+        self.asks.sort();
+
+        Self {
+            last_update_time: self.last_update_time,
+            bids: pool.copy_side(&self.bids),
+            asks: pool.copy_side(&self.asks),
+        }
+    }
+
+    /// Generate an [`OrderBook`] snapshot containing only the best `depth` [`Level`]s of each
+    /// [`OrderBookSide`], avoiding a clone of the full [`OrderBook`].
+    pub fn truncated(&mut self, depth: usize) -> Self {
+        Self {
+            last_update_time: self.last_update_time,
+            bids: self.bids.truncated(depth),
+            asks: self.asks.truncated(depth),
+        }
+    }
+
     /// Calculate the mid price by taking the average of the best bid and ask prices.
     ///
+    /// Returns `None` if either side of the [`OrderBook`] is empty.
+    ///
     /// See Docs: <https://www.quantstart.com/articles/high-frequency-trading-ii-limit-order-book>
     pub fn mid_price(&self) -> Option<f64> {
         match (self.bids.levels.first(), self.asks.levels.first()) {
             (Some(best_bid), Some(best_ask)) => Some(mid_price(best_bid.price, best_ask.price)),
-            (Some(best_bid), None) => Some(best_bid.price),
-            (None, Some(best_ask)) => Some(best_ask.price),
-            (None, None) => None,
+            _ => None,
         }
     }
 
     /// Calculate the volume weighted mid price (micro-price), weighing the best bid and ask prices
     /// with their associated amount.
     ///
+    /// Returns `None` if either side of the [`OrderBook`] is empty.
+    ///
     /// See Docs: <https://www.quantstart.com/articles/high-frequency-trading-ii-limit-order-book>
     pub fn volume_weighed_mid_price(&self) -> Option<f64> {
         match (self.bids.levels.first(), self.asks.levels.first()) {
             (Some(best_bid), Some(best_ask)) => {
                 Some(volume_weighted_mid_price(*best_bid, *best_ask))
             }
-            (Some(best_bid), None) => Some(best_bid.price),
-            (None, Some(best_ask)) => Some(best_ask.price),
-            (None, None) => None,
+            _ => None,
         }
     }
 }
@@ -192,6 +464,27 @@ impl OrderBookSide {
         };
     }
 
+    /// Sorted [`Level`]s of this [`OrderBookSide`], if [`Self::sort`] has been called since the
+    /// last upsert.
+    pub fn levels(&self) -> &[Level] {
+        &self.levels
+    }
+
+    /// Sort this [`OrderBookSide`] and remove all [`Level`]s beyond the provided depth.
+    pub fn truncate(&mut self, depth: usize) {
+        self.sort();
+        self.levels.truncate(depth);
+    }
+
+    /// Sort this [`OrderBookSide`] and clone only the best `depth` [`Level`]s.
+    pub fn truncated(&mut self, depth: usize) -> Self {
+        self.sort();
+        Self {
+            side: self.side,
+            levels: self.levels.iter().take(depth).copied().collect(),
+        }
+    }
+
     /// Sort this [`OrderBookSide`] (bids are reversed).
     pub fn sort(&mut self) {
         // Sort Levels
@@ -204,6 +497,191 @@ impl OrderBookSide {
     }
 }
 
+/// Barter [`OrderBook`] alternative that stores each side in a price ordered [`BTreeMap`].
+///
+/// Upserting a [`Level`] is O(log n) rather than the O(n) scan of an [`OrderBookSide`], which
+/// is preferable when maintaining deep books that receive high-frequency level 2 updates. Use
+/// [`SortedOrderBook::snapshot`] to generate the normalised [`OrderBook`] representation.
+#[derive(Clone, PartialEq, Debug)]
+pub struct SortedOrderBook {
+    pub last_update_time: DateTime<Utc>,
+    pub bids: SortedOrderBookSide,
+    pub asks: SortedOrderBookSide,
+}
+
+impl SortedOrderBook {
+    /// Construct a new empty [`Self`].
+    pub fn new(last_update_time: DateTime<Utc>) -> Self {
+        Self {
+            last_update_time,
+            bids: SortedOrderBookSide::new(Side::Buy, Vec::<Level>::new()),
+            asks: SortedOrderBookSide::new(Side::Sell, Vec::<Level>::new()),
+        }
+    }
+
+    /// Generate a sorted [`OrderBook`] snapshot of [`Self`].
+    pub fn snapshot(&self) -> OrderBook {
+        OrderBook {
+            last_update_time: self.last_update_time,
+            bids: self.bids.snapshot(),
+            asks: self.asks.snapshot(),
+        }
+    }
+
+    /// Calculate the mid price by taking the average of the best bid and ask prices.
+    ///
+    /// Returns `None` if either side of the [`SortedOrderBook`] is empty.
+    ///
+    /// See Docs: <https://www.quantstart.com/articles/high-frequency-trading-ii-limit-order-book>
+    pub fn mid_price(&self) -> Option<f64> {
+        match (self.bids.best(), self.asks.best()) {
+            (Some(best_bid), Some(best_ask)) => Some(mid_price(best_bid.price, best_ask.price)),
+            _ => None,
+        }
+    }
+}
+
+impl From<OrderBook> for SortedOrderBook {
+    fn from(book: OrderBook) -> Self {
+        Self {
+            last_update_time: book.last_update_time,
+            bids: SortedOrderBookSide::from(book.bids),
+            asks: SortedOrderBookSide::from(book.asks),
+        }
+    }
+}
+
+impl From<&SortedOrderBook> for OrderBook {
+    fn from(book: &SortedOrderBook) -> Self {
+        book.snapshot()
+    }
+}
+
+/// Price ordered [`Level`]s for one [`Side`] of a [`SortedOrderBook`].
+///
+/// Prices are compared exactly using [`f64::total_cmp`], rather than within [`f64::EPSILON`]
+/// like [`Level::eq_price`].
+#[derive(Clone, PartialEq, Debug)]
+pub struct SortedOrderBookSide {
+    side: Side,
+    levels: BTreeMap<Price, f64>,
+}
+
+impl SortedOrderBookSide {
+    /// Construct a new [`Self`] with the [`Level`]s provided, ignoring those with a 0 amount.
+    pub fn new<Iter, L>(side: Side, levels: Iter) -> Self
+    where
+        Iter: IntoIterator<Item = L>,
+        L: Into<Level>,
+    {
+        let mut book_side = Self {
+            side,
+            levels: BTreeMap::new(),
+        };
+        book_side.upsert(levels);
+        book_side
+    }
+
+    /// Upsert a collection of [`Level`]s into this [`SortedOrderBookSide`].
+    pub fn upsert<Iter, L>(&mut self, levels: Iter)
+    where
+        Iter: IntoIterator<Item = L>,
+        L: Into<Level>,
+    {
+        levels
+            .into_iter()
+            .for_each(|level| self.upsert_single(level))
+    }
+
+    /// Upsert a single [`Level`] into this [`SortedOrderBookSide`].
+    ///
+    /// See [`OrderBookSide::upsert_single`] for the upsert scenarios.
+    pub fn upsert_single<L>(&mut self, new_level: L)
+    where
+        L: Into<Level>,
+    {
+        let new_level = new_level.into();
+
+        if new_level.amount == 0.0 {
+            if self.levels.remove(&Price(new_level.price)).is_none() {
+                debug!(
+                    ?new_level,
+                    side = %self.side,
+                    "Level to remove not found",
+                );
+            }
+        } else {
+            self.levels.insert(Price(new_level.price), new_level.amount);
+        }
+    }
+
+    /// Best [`Level`] of this [`SortedOrderBookSide`] (highest bid or lowest ask).
+    pub fn best(&self) -> Option<Level> {
+        self.levels().next()
+    }
+
+    /// Iterator over the [`Level`]s of this [`SortedOrderBookSide`], from best to worst.
+    pub fn levels(&self) -> impl Iterator<Item = Level> + '_ {
+        let levels = self
+            .levels
+            .iter()
+            .map(|(price, amount)| Level::new(price.0, *amount));
+
+        match self.side {
+            Side::Buy => itertools::Either::Left(levels.rev()),
+            Side::Sell => itertools::Either::Right(levels),
+        }
+    }
+
+    /// Number of [`Level`]s in this [`SortedOrderBookSide`].
+    pub fn len(&self) -> usize {
+        self.levels.len()
+    }
+
+    /// Returns true if this [`SortedOrderBookSide`] contains no [`Level`]s.
+    pub fn is_empty(&self) -> bool {
+        self.levels.is_empty()
+    }
+
+    /// Generate a sorted [`OrderBookSide`] snapshot of [`Self`].
+    pub fn snapshot(&self) -> OrderBookSide {
+        OrderBookSide {
+            side: self.side,
+            levels: self.levels().collect(),
+        }
+    }
+}
+
+impl From<OrderBookSide> for SortedOrderBookSide {
+    fn from(book_side: OrderBookSide) -> Self {
+        Self::new(book_side.side, book_side.levels)
+    }
+}
+
+/// [`SortedOrderBookSide`] key that totally orders [`Level`] prices.
+#[derive(Clone, Copy, Debug)]
+struct Price(f64);
+
+impl PartialEq for Price {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Price {}
+
+impl PartialOrd for Price {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Price {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.total_cmp(&other.0)
+    }
+}
+
 /// Normalised Barter OrderBook [`Level`].
 #[derive(Clone, Copy, PartialEq, Debug, Default, Deserialize, Serialize)]
 pub struct Level {
@@ -227,6 +705,7 @@ impl Ord for Level {
     }
 }
 
+#[allow(clippy::non_canonical_partial_ord_impl)]
 impl PartialOrd for Level {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
         match self.price.partial_cmp(&other.price)? {
@@ -298,7 +777,7 @@ mod tests {
         fn test_mid_price() {
             struct TestCase {
                 input: OrderBookL1,
-                expected: f64,
+                expected: Option<f64>,
             }
 
             let tests = vec![
@@ -309,7 +788,7 @@ mod tests {
                         best_bid: Level::new(100, 999999),
                         best_ask: Level::new(200, 1),
                     },
-                    expected: 150.0,
+                    expected: Some(150.0),
                 },
                 TestCase {
                     // TC1
@@ -318,7 +797,7 @@ mod tests {
                         best_bid: Level::new(50, 1),
                         best_ask: Level::new(250, 999999),
                     },
-                    expected: 150.0,
+                    expected: Some(150.0),
                 },
                 TestCase {
                     // TC2
@@ -327,7 +806,16 @@ mod tests {
                         best_bid: Level::new(10, 999999),
                         best_ask: Level::new(250, 999999),
                     },
-                    expected: 130.0,
+                    expected: Some(130.0),
+                },
+                TestCase {
+                    // TC3: no ask amount so no mid-price
+                    input: OrderBookL1 {
+                        last_update_time: Default::default(),
+                        best_bid: Level::new(10, 999999),
+                        best_ask: Level::default(),
+                    },
+                    expected: None,
                 },
             ];
 
@@ -340,7 +828,7 @@ mod tests {
         fn test_volume_weighted_mid_price() {
             struct TestCase {
                 input: OrderBookL1,
-                expected: f64,
+                expected: Option<f64>,
             }
 
             let tests = vec![
@@ -351,7 +839,7 @@ mod tests {
                         best_bid: Level::new(100, 100),
                         best_ask: Level::new(200, 100),
                     },
-                    expected: 150.0,
+                    expected: Some(150.0),
                 },
                 TestCase {
                     // TC1: volume affects mid-price
@@ -360,7 +848,7 @@ mod tests {
                         best_bid: Level::new(100, 600),
                         best_ask: Level::new(200, 1000),
                     },
-                    expected: 137.5,
+                    expected: Some(137.5),
                 },
                 TestCase {
                     // TC2: volume the same and price the same
@@ -369,7 +857,16 @@ mod tests {
                         best_bid: Level::new(1000, 999999),
                         best_ask: Level::new(1000, 999999),
                     },
-                    expected: 1000.0,
+                    expected: Some(1000.0),
+                },
+                TestCase {
+                    // TC3: no bid amount so no mid-price
+                    input: OrderBookL1 {
+                        last_update_time: Default::default(),
+                        best_bid: Level::default(),
+                        best_ask: Level::new(1000, 999999),
+                    },
+                    expected: None,
                 },
             ];
 
@@ -395,7 +892,7 @@ mod tests {
 
             let tests = vec![
                 TestCase {
-                    // TC0: no levels so 0.0 mid-price
+                    // TC0: no levels so no mid-price
                     input: OrderBook {
                         last_update_time: Default::default(),
                         bids: OrderBookSide {
@@ -410,7 +907,7 @@ mod tests {
                     expected: None,
                 },
                 TestCase {
-                    // TC1: no asks in the book so take best bid price
+                    // TC1: no asks in the book so no mid-price
                     input: OrderBook {
                         last_update_time: Default::default(),
                         bids: OrderBookSide {
@@ -422,10 +919,10 @@ mod tests {
                             levels: vec![],
                         },
                     },
-                    expected: Some(100.0),
+                    expected: None,
                 },
                 TestCase {
-                    // TC2: no bids in the book so take ask price
+                    // TC2: no bids in the book so no mid-price
                     input: OrderBook {
                         last_update_time: Default::default(),
                         bids: OrderBookSide {
@@ -437,7 +934,7 @@ mod tests {
                             levels: vec![Level::new(50.0, 100.0), Level::new(100.0, 100.0)],
                         },
                     },
-                    expected: Some(50.0),
+                    expected: None,
                 },
                 TestCase {
                     // TC3: best bid and ask amount is the same, so regular mid-price
@@ -470,7 +967,7 @@ mod tests {
 
             let tests = vec![
                 TestCase {
-                    // TC0: no levels so 0.0 mid-price
+                    // TC0: no levels so no mid-price
                     input: OrderBook {
                         last_update_time: Default::default(),
                         bids: OrderBookSide {
@@ -485,7 +982,7 @@ mod tests {
                     expected: None,
                 },
                 TestCase {
-                    // TC1: no asks in the book so take best bid price
+                    // TC1: no asks in the book so no mid-price
                     input: OrderBook {
                         last_update_time: Default::default(),
                         bids: OrderBookSide {
@@ -497,10 +994,10 @@ mod tests {
                             levels: vec![],
                         },
                     },
-                    expected: Some(100.0),
+                    expected: None,
                 },
                 TestCase {
-                    // TC2: no bids in the book so take ask price
+                    // TC2: no bids in the book so no mid-price
                     input: OrderBook {
                         last_update_time: Default::default(),
                         bids: OrderBookSide {
@@ -512,7 +1009,7 @@ mod tests {
                             levels: vec![Level::new(50.0, 100.0), Level::new(100.0, 100.0)],
                         },
                     },
-                    expected: Some(50.0),
+                    expected: None,
                 },
                 TestCase {
                     // TC3: best bid and ask amount is the same, so regular mid-price
@@ -559,6 +1056,50 @@ mod tests {
     mod order_book_side {
         use super::*;
 
+        #[test]
+        fn test_truncated() {
+            struct TestCase {
+                input: OrderBookSide,
+                depth: usize,
+                expected: OrderBookSide,
+            }
+
+            let tests = vec![
+                TestCase {
+                    // TC0: unsorted bids truncated to best levels
+                    input: OrderBookSide::new(
+                        Side::Buy,
+                        vec![Level::new(80, 1), Level::new(100, 1), Level::new(90, 1)],
+                    ),
+                    depth: 2,
+                    expected: OrderBookSide::new(
+                        Side::Buy,
+                        vec![Level::new(100, 1), Level::new(90, 1)],
+                    ),
+                },
+                TestCase {
+                    // TC1: unsorted asks truncated to best levels
+                    input: OrderBookSide::new(
+                        Side::Sell,
+                        vec![Level::new(80, 1), Level::new(100, 1), Level::new(90, 1)],
+                    ),
+                    depth: 1,
+                    expected: OrderBookSide::new(Side::Sell, vec![Level::new(80, 1)]),
+                },
+                TestCase {
+                    // TC2: depth greater than number of levels
+                    input: OrderBookSide::new(Side::Sell, vec![Level::new(80, 1)]),
+                    depth: 10,
+                    expected: OrderBookSide::new(Side::Sell, vec![Level::new(80, 1)]),
+                },
+            ];
+
+            for (index, mut test) in tests.into_iter().enumerate() {
+                let actual = test.input.truncated(test.depth);
+                assert_eq!(actual, test.expected, "TC{} failed", index);
+            }
+        }
+
         #[test]
         fn test_upsert_single() {
             struct TestCase {
@@ -800,6 +1341,88 @@ mod tests {
         }
     }
 
+    mod sorted_order_book_side {
+        use super::*;
+
+        #[test]
+        fn test_upsert_single() {
+            struct TestCase {
+                book_side: SortedOrderBookSide,
+                new_level: Level,
+                expected: Vec<Level>,
+            }
+
+            let tests = vec![
+                TestCase {
+                    // TC0: Level exists & new value is 0 => remove Level
+                    book_side: SortedOrderBookSide::new(
+                        Side::Buy,
+                        vec![Level::new(80, 1), Level::new(90, 1), Level::new(100, 1)],
+                    ),
+                    new_level: Level::new(100, 0),
+                    expected: vec![Level::new(90, 1), Level::new(80, 1)],
+                },
+                TestCase {
+                    // TC1: Level exists & new value is > 0 => replace Level
+                    book_side: SortedOrderBookSide::new(
+                        Side::Buy,
+                        vec![Level::new(80, 1), Level::new(90, 1), Level::new(100, 1)],
+                    ),
+                    new_level: Level::new(100, 10),
+                    expected: vec![Level::new(100, 10), Level::new(90, 1), Level::new(80, 1)],
+                },
+                TestCase {
+                    // TC2: Level does not exist & new value > 0 => insert new Level in order
+                    book_side: SortedOrderBookSide::new(
+                        Side::Sell,
+                        vec![Level::new(80, 1), Level::new(100, 1)],
+                    ),
+                    new_level: Level::new(90, 1),
+                    expected: vec![Level::new(80, 1), Level::new(90, 1), Level::new(100, 1)],
+                },
+                TestCase {
+                    // TC3: Level does not exist & new value is 0 => no change
+                    book_side: SortedOrderBookSide::new(
+                        Side::Sell,
+                        vec![Level::new(80, 1), Level::new(100, 1)],
+                    ),
+                    new_level: Level::new(110, 0),
+                    expected: vec![Level::new(80, 1), Level::new(100, 1)],
+                },
+            ];
+
+            for (index, mut test) in tests.into_iter().enumerate() {
+                test.book_side.upsert_single(test.new_level);
+                assert_eq!(
+                    test.book_side.levels().collect::<Vec<_>>(),
+                    test.expected,
+                    "TC{} failed",
+                    index
+                );
+            }
+        }
+
+        #[test]
+        fn test_snapshot_matches_order_book() {
+            let mut book = OrderBook {
+                last_update_time: Default::default(),
+                bids: OrderBookSide::new(
+                    Side::Buy,
+                    vec![Level::new(90, 1), Level::new(100, 2), Level::new(80, 3)],
+                ),
+                asks: OrderBookSide::new(
+                    Side::Sell,
+                    vec![Level::new(120, 1), Level::new(110, 2), Level::new(130, 3)],
+                ),
+            };
+
+            let sorted = SortedOrderBook::from(book.clone());
+
+            assert_eq!(sorted.snapshot(), book.snapshot());
+            assert_eq!(sorted.mid_price(), book.mid_price());
+        }
+    }
+
     mod level {
         use super::*;
 
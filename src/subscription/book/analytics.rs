@@ -0,0 +1,269 @@
+use super::{mid_price, volume_weighted_mid_price, Level, OrderBook, OrderBookL1};
+use barter_integration::model::Side;
+use std::cmp::Ordering;
+
+/// Number of basis points in a unit.
+const BPS: f64 = 10_000.0;
+
+/// Common analytics calculated from the best [`Level`]s of an [`OrderBook`] or [`OrderBookL1`].
+///
+/// [`Level`]s are expected to be sorted best first, as is the case for every [`OrderBook`]
+/// emitted by a [`MultiBookTransformer`](crate::transformer::book::MultiBookTransformer).
+///
+/// All calculations return `None` if either side of the book is empty.
+pub trait OrderBookAnalytics {
+    /// Bid [`Level`]s, sorted best (highest price) first.
+    fn bid_levels(&self) -> &[Level];
+
+    /// Ask [`Level`]s, sorted best (lowest price) first.
+    fn ask_levels(&self) -> &[Level];
+
+    /// Best bid & best ask [`Level`]s, if both sides of the book are populated.
+    fn best_levels(&self) -> Option<(Level, Level)> {
+        match (self.bid_levels().first(), self.ask_levels().first()) {
+            (Some(best_bid), Some(best_ask)) if best_bid.amount > 0.0 && best_ask.amount > 0.0 => {
+                Some((*best_bid, *best_ask))
+            }
+            _ => None,
+        }
+    }
+
+    /// Difference between the best ask and best bid prices.
+    fn spread(&self) -> Option<f64> {
+        self.best_levels()
+            .map(|(best_bid, best_ask)| best_ask.price - best_bid.price)
+    }
+
+    /// [`Self::spread`] in basis points of the mid price.
+    fn spread_bps(&self) -> Option<f64> {
+        self.best_levels().map(|(best_bid, best_ask)| {
+            (best_ask.price - best_bid.price) / mid_price(best_bid.price, best_ask.price) * BPS
+        })
+    }
+
+    /// Volume weighted mid price of the best bid and ask [`Level`]s.
+    ///
+    /// See Docs: <https://www.quantstart.com/articles/high-frequency-trading-ii-limit-order-book>
+    fn microprice(&self) -> Option<f64> {
+        self.best_levels()
+            .map(|(best_bid, best_ask)| volume_weighted_mid_price(best_bid, best_ask))
+    }
+
+    /// Imbalance of the best bid and ask amounts in the range [-1, 1], where a positive value
+    /// indicates more resting bid liquidity.
+    fn imbalance(&self) -> Option<f64> {
+        self.best_levels().map(|(best_bid, best_ask)| {
+            (best_bid.amount - best_ask.amount) / (best_bid.amount + best_ask.amount)
+        })
+    }
+
+    /// Cumulative amount resting on the provided [`Side`] within `bps` basis points of the mid
+    /// price.
+    fn depth_within_bps(&self, side: Side, bps: f64) -> Option<f64> {
+        let (best_bid, best_ask) = self.best_levels()?;
+        let mid = mid_price(best_bid.price, best_ask.price);
+        let distance = mid * bps / BPS;
+
+        let depth = match side {
+            Side::Buy => self
+                .bid_levels()
+                .iter()
+                .take_while(|level| level.price >= mid - distance)
+                .map(|level| level.amount)
+                .sum(),
+            Side::Sell => self
+                .ask_levels()
+                .iter()
+                .take_while(|level| level.price <= mid + distance)
+                .map(|level| level.amount)
+                .sum(),
+        };
+
+        Some(depth)
+    }
+
+    /// Imbalance of the cumulative bid and ask amounts within `bps` basis points of the mid price,
+    /// in the range [-1, 1].
+    fn depth_imbalance_within_bps(&self, bps: f64) -> Option<f64> {
+        let bids = self.depth_within_bps(Side::Buy, bps)?;
+        let asks = self.depth_within_bps(Side::Sell, bps)?;
+        Some((bids - asks) / (bids + asks))
+    }
+
+    /// Order flow imbalance between the `previous` and current best [`Level`]s, where a positive
+    /// value indicates net buying pressure.
+    ///
+    /// See Docs: <https://arxiv.org/abs/1011.6402>
+    fn order_flow_imbalance(&self, previous: &Self) -> Option<f64> {
+        let (bid, ask) = self.best_levels()?;
+        let (prev_bid, prev_ask) = previous.best_levels()?;
+
+        let bid_flow = match bid.price.partial_cmp(&prev_bid.price)? {
+            Ordering::Greater => bid.amount,
+            Ordering::Equal => bid.amount - prev_bid.amount,
+            Ordering::Less => -prev_bid.amount,
+        };
+
+        let ask_flow = match ask.price.partial_cmp(&prev_ask.price)? {
+            Ordering::Less => ask.amount,
+            Ordering::Equal => ask.amount - prev_ask.amount,
+            Ordering::Greater => -prev_ask.amount,
+        };
+
+        Some(bid_flow - ask_flow)
+    }
+}
+
+impl OrderBookAnalytics for OrderBook {
+    fn bid_levels(&self) -> &[Level] {
+        self.bids.levels()
+    }
+
+    fn ask_levels(&self) -> &[Level] {
+        self.asks.levels()
+    }
+}
+
+impl OrderBookAnalytics for OrderBookL1 {
+    fn bid_levels(&self) -> &[Level] {
+        std::slice::from_ref(&self.best_bid)
+    }
+
+    fn ask_levels(&self) -> &[Level] {
+        std::slice::from_ref(&self.best_ask)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::subscription::book::OrderBookSide;
+
+    fn book(bids: Vec<Level>, asks: Vec<Level>) -> OrderBook {
+        OrderBook {
+            last_update_time: Default::default(),
+            bids: OrderBookSide::new(Side::Buy, bids),
+            asks: OrderBookSide::new(Side::Sell, asks),
+        }
+    }
+
+    #[test]
+    fn test_spread_and_imbalance() {
+        let input = book(
+            vec![Level::new(99.0, 3.0), Level::new(98.0, 5.0)],
+            vec![Level::new(101.0, 1.0), Level::new(102.0, 5.0)],
+        );
+
+        assert_eq!(input.spread(), Some(2.0));
+        assert_eq!(input.spread_bps(), Some(200.0));
+        assert_eq!(input.imbalance(), Some(0.5));
+        assert_eq!(input.microprice(), Some(100.5));
+    }
+
+    #[test]
+    fn test_depth_within_bps() {
+        struct TestCase {
+            side: Side,
+            bps: f64,
+            expected: Option<f64>,
+        }
+
+        let input = book(
+            vec![
+                Level::new(99.0, 3.0),
+                Level::new(98.0, 5.0),
+                Level::new(90.0, 10.0),
+            ],
+            vec![Level::new(101.0, 1.0), Level::new(102.0, 5.0)],
+        );
+
+        let tests = vec![
+            TestCase {
+                // TC0: best bid only within 100bps of mid
+                side: Side::Buy,
+                bps: 100.0,
+                expected: Some(3.0),
+            },
+            TestCase {
+                // TC1: two bid levels within 200bps of mid
+                side: Side::Buy,
+                bps: 200.0,
+                expected: Some(8.0),
+            },
+            TestCase {
+                // TC2: all ask levels within 200bps of mid
+                side: Side::Sell,
+                bps: 200.0,
+                expected: Some(6.0),
+            },
+            TestCase {
+                // TC3: no levels within 0bps of mid
+                side: Side::Sell,
+                bps: 0.0,
+                expected: Some(0.0),
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = input.depth_within_bps(test.side, test.bps);
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
+
+    #[test]
+    fn test_one_sided_book() {
+        let input = book(vec![Level::new(99.0, 3.0)], vec![]);
+
+        assert_eq!(input.spread(), None);
+        assert_eq!(input.spread_bps(), None);
+        assert_eq!(input.imbalance(), None);
+        assert_eq!(input.depth_within_bps(Side::Buy, 100.0), None);
+    }
+
+    #[test]
+    fn test_order_flow_imbalance() {
+        struct TestCase {
+            previous: OrderBookL1,
+            current: OrderBookL1,
+            expected: Option<f64>,
+        }
+
+        let l1 = |best_bid: Level, best_ask: Level| OrderBookL1 {
+            last_update_time: Default::default(),
+            best_bid,
+            best_ask,
+        };
+
+        let tests = vec![
+            TestCase {
+                // TC0: unchanged prices, bid amount increased & ask amount decreased
+                previous: l1(Level::new(100.0, 5.0), Level::new(101.0, 5.0)),
+                current: l1(Level::new(100.0, 7.0), Level::new(101.0, 4.0)),
+                expected: Some(3.0),
+            },
+            TestCase {
+                // TC1: bid price increased & ask price increased
+                previous: l1(Level::new(100.0, 5.0), Level::new(101.0, 5.0)),
+                current: l1(Level::new(100.5, 2.0), Level::new(102.0, 4.0)),
+                expected: Some(7.0),
+            },
+            TestCase {
+                // TC2: bid price decreased & ask price decreased
+                previous: l1(Level::new(100.0, 5.0), Level::new(101.0, 5.0)),
+                current: l1(Level::new(99.5, 2.0), Level::new(100.5, 4.0)),
+                expected: Some(-9.0),
+            },
+            TestCase {
+                // TC3: empty previous ask
+                previous: l1(Level::new(100.0, 5.0), Level::default()),
+                current: l1(Level::new(100.0, 5.0), Level::new(101.0, 4.0)),
+                expected: None,
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = test.current.order_flow_imbalance(&test.previous);
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
+}
@@ -0,0 +1,186 @@
+use super::{Level, OrderBook, OrderBookSide};
+use barter_integration::model::Side;
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// [`Decimal`] representation of a [`Level`], free from floating point equality issues.
+#[derive(
+    Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default, Deserialize, Serialize,
+)]
+pub struct DecimalLevel {
+    pub price: Decimal,
+    pub amount: Decimal,
+}
+
+impl DecimalLevel {
+    /// Construct a new [`Self`].
+    pub fn new(price: Decimal, amount: Decimal) -> Self {
+        Self { price, amount }
+    }
+}
+
+impl TryFrom<Level> for DecimalLevel {
+    type Error = rust_decimal::Error;
+
+    /// Convert each `f64` into the [`Decimal`] with the shortest representation that round trips
+    /// to the same `f64` (eg/ 3366.1 rather than 3366.09999...), which matches the exchange
+    /// string for any value that was deserialised from one.
+    fn try_from(level: Level) -> Result<Self, Self::Error> {
+        Ok(Self {
+            price: Decimal::try_from(level.price)?,
+            amount: Decimal::try_from(level.amount)?,
+        })
+    }
+}
+
+impl TryFrom<DecimalLevel> for Level {
+    type Error = rust_decimal::Error;
+
+    fn try_from(level: DecimalLevel) -> Result<Self, Self::Error> {
+        Ok(Self {
+            price: f64::try_from(level.price)?,
+            amount: f64::try_from(level.amount)?,
+        })
+    }
+}
+
+/// [`OrderBook`] with [`Decimal`] price keys & amounts.
+///
+/// Levels are keyed by their exact [`Decimal`] price, so upserting a [`DecimalLevel`] with a zero
+/// amount reliably removes the level, without requiring float tolerance comparisons.
+#[derive(Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+pub struct DecimalOrderBook {
+    pub last_update_time: DateTime<Utc>,
+    bids: BTreeMap<Decimal, Decimal>,
+    asks: BTreeMap<Decimal, Decimal>,
+}
+
+impl DecimalOrderBook {
+    /// Construct a new empty [`Self`].
+    pub fn new(last_update_time: DateTime<Utc>) -> Self {
+        Self {
+            last_update_time,
+            bids: BTreeMap::new(),
+            asks: BTreeMap::new(),
+        }
+    }
+
+    /// Upsert a [`DecimalLevel`] into the provided [`Side`] of this [`DecimalOrderBook`],
+    /// removing the level if the amount is zero.
+    pub fn upsert(&mut self, side: Side, level: DecimalLevel) {
+        let levels = match side {
+            Side::Buy => &mut self.bids,
+            Side::Sell => &mut self.asks,
+        };
+
+        if level.amount.is_zero() {
+            levels.remove(&level.price);
+        } else {
+            levels.insert(level.price, level.amount);
+        }
+    }
+
+    /// Bid [`DecimalLevel`]s, sorted best (highest price) first.
+    pub fn bids(&self) -> impl Iterator<Item = DecimalLevel> + '_ {
+        self.bids
+            .iter()
+            .rev()
+            .map(|(price, amount)| DecimalLevel::new(*price, *amount))
+    }
+
+    /// Ask [`DecimalLevel`]s, sorted best (lowest price) first.
+    pub fn asks(&self) -> impl Iterator<Item = DecimalLevel> + '_ {
+        self.asks
+            .iter()
+            .map(|(price, amount)| DecimalLevel::new(*price, *amount))
+    }
+
+    /// Best bid [`DecimalLevel`], if any.
+    pub fn best_bid(&self) -> Option<DecimalLevel> {
+        self.bids().next()
+    }
+
+    /// Best ask [`DecimalLevel`], if any.
+    pub fn best_ask(&self) -> Option<DecimalLevel> {
+        self.asks().next()
+    }
+}
+
+impl TryFrom<&OrderBook> for DecimalOrderBook {
+    type Error = rust_decimal::Error;
+
+    fn try_from(book: &OrderBook) -> Result<Self, Self::Error> {
+        let mut decimal = Self::new(book.last_update_time);
+
+        for (side, levels) in [
+            (Side::Buy, book.bids.levels()),
+            (Side::Sell, book.asks.levels()),
+        ] {
+            for level in levels {
+                decimal.upsert(side, DecimalLevel::try_from(*level)?);
+            }
+        }
+
+        Ok(decimal)
+    }
+}
+
+impl TryFrom<&DecimalOrderBook> for OrderBook {
+    type Error = rust_decimal::Error;
+
+    fn try_from(book: &DecimalOrderBook) -> Result<Self, Self::Error> {
+        Ok(Self {
+            last_update_time: book.last_update_time,
+            bids: OrderBookSide::new(
+                Side::Buy,
+                book.bids()
+                    .map(Level::try_from)
+                    .collect::<Result<Vec<_>, _>>()?,
+            ),
+            asks: OrderBookSide::new(
+                Side::Sell,
+                book.asks()
+                    .map(Level::try_from)
+                    .collect::<Result<Vec<_>, _>>()?,
+            ),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn test_decimal_level_from_level() {
+        let actual = DecimalLevel::try_from(Level::new(3366.1, 0.000005)).unwrap();
+        assert_eq!(actual, DecimalLevel::new(dec!(3366.1), dec!(0.000005)));
+    }
+
+    #[test]
+    fn test_upsert() {
+        let mut book = DecimalOrderBook::new(Utc::now());
+
+        book.upsert(Side::Buy, DecimalLevel::new(dec!(0.1), dec!(1.0)));
+        book.upsert(Side::Buy, DecimalLevel::new(dec!(0.3), dec!(2.0)));
+        book.upsert(Side::Sell, DecimalLevel::new(dec!(0.4), dec!(1.5)));
+        assert_eq!(
+            book.best_bid(),
+            Some(DecimalLevel::new(dec!(0.3), dec!(2.0)))
+        );
+
+        // 0.1 + 0.2 != 0.3 as f64, but the Decimal key is exact so the level is removed
+        book.upsert(Side::Buy, DecimalLevel::new(dec!(0.1) + dec!(0.2), dec!(0)));
+        assert_eq!(
+            book.bids().collect::<Vec<_>>(),
+            vec![DecimalLevel::new(dec!(0.1), dec!(1.0))]
+        );
+
+        let actual = OrderBook::try_from(&book).unwrap();
+        assert_eq!(actual.bids.levels(), &[Level::new(0.1, 1.0)]);
+        assert_eq!(actual.asks.levels(), &[Level::new(0.4, 1.5)]);
+    }
+}
@@ -0,0 +1,444 @@
+use super::{Level, OrderBook, OrderBookL3Event, OrderBookSide, Price};
+use barter_integration::model::Side;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap, VecDeque};
+use tracing::debug;
+
+/// Order-by-order [`OrderBookL3`] maintained by applying [`OrderBookL3Event`]s (eg/ Coinbase full
+/// channel or Bitfinex raw books).
+///
+/// Each price level retains its orders in time priority, allowing the queue position of an order
+/// to be determined. Use [`OrderBookL3::l2`] to derive the aggregated level 2 [`OrderBook`].
+#[derive(Clone, PartialEq, Debug)]
+pub struct OrderBookL3 {
+    pub last_update_time: DateTime<Utc>,
+    pub sequence: u64,
+    orders: HashMap<String, OrderL3>,
+    bids: BTreeMap<Price, VecDeque<String>>,
+    asks: BTreeMap<Price, VecDeque<String>>,
+}
+
+/// Open order resting on an [`OrderBookL3`].
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct OrderL3 {
+    pub order_id: String,
+    pub side: Side,
+    pub price: f64,
+    pub amount: f64,
+}
+
+/// Position of an order in the time priority queue of its [`OrderBookL3`] price level.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct QueuePosition {
+    /// Number of orders ahead at the same price level.
+    pub orders_ahead: usize,
+    /// Total amount of the orders ahead at the same price level.
+    pub amount_ahead: f64,
+}
+
+impl Default for OrderBookL3 {
+    fn default() -> Self {
+        Self::new(Utc::now())
+    }
+}
+
+impl OrderBookL3 {
+    /// Construct a new empty [`Self`].
+    pub fn new(last_update_time: DateTime<Utc>) -> Self {
+        Self {
+            last_update_time,
+            sequence: 0,
+            orders: HashMap::new(),
+            bids: BTreeMap::new(),
+            asks: BTreeMap::new(),
+        }
+    }
+
+    /// Apply an [`OrderBookL3Event`] to this [`OrderBookL3`].
+    ///
+    /// Events with a sequence not greater than the last applied sequence are ignored. Gap
+    /// detection is left to the caller, since not every exchange sequences events contiguously.
+    pub fn apply(&mut self, event: OrderBookL3Event, time: DateTime<Utc>) {
+        let sequence = event.sequence();
+        if sequence <= self.sequence && self.sequence != 0 {
+            debug!(
+                sequence,
+                last_sequence = self.sequence,
+                "ignoring stale OrderBookL3Event"
+            );
+            return;
+        }
+
+        match event {
+            OrderBookL3Event::Open {
+                order_id,
+                side,
+                price,
+                amount,
+                ..
+            } => self.open(OrderL3 {
+                order_id,
+                side,
+                price,
+                amount,
+            }),
+            OrderBookL3Event::Change {
+                order_id,
+                side,
+                price,
+                amount,
+                ..
+            } => self.change(OrderL3 {
+                order_id,
+                side,
+                price,
+                amount,
+            }),
+            OrderBookL3Event::Done { order_id, .. } => {
+                self.remove(&order_id);
+            }
+            OrderBookL3Event::Match {
+                maker_order_id,
+                amount,
+                ..
+            } => self.fill(&maker_order_id, amount),
+        }
+
+        self.sequence = sequence;
+        self.last_update_time = time;
+    }
+
+    /// Open [`OrderL3`] associated with the provided `order_id`, if any.
+    pub fn order(&self, order_id: &str) -> Option<&OrderL3> {
+        self.orders.get(order_id)
+    }
+
+    /// Number of open orders on this [`OrderBookL3`].
+    pub fn len(&self) -> usize {
+        self.orders.len()
+    }
+
+    /// Returns true if this [`OrderBookL3`] contains no open orders.
+    pub fn is_empty(&self) -> bool {
+        self.orders.is_empty()
+    }
+
+    /// Determine the [`QueuePosition`] of the order associated with the provided `order_id`, if
+    /// it is open.
+    pub fn queue_position(&self, order_id: &str) -> Option<QueuePosition> {
+        let order = self.orders.get(order_id)?;
+        let queue = self.side(order.side).get(&Price(order.price))?;
+
+        let ahead = queue
+            .iter()
+            .take_while(|queued| queued.as_str() != order_id);
+        let (orders_ahead, amount_ahead) = ahead.fold((0, 0.0), |(orders, amount), queued| {
+            (orders + 1, amount + self.orders[queued].amount)
+        });
+
+        Some(QueuePosition {
+            orders_ahead,
+            amount_ahead,
+        })
+    }
+
+    /// Derive the aggregated level 2 [`OrderBook`] from the open orders, sorted best first.
+    pub fn l2(&self) -> OrderBook {
+        OrderBook {
+            last_update_time: self.last_update_time,
+            bids: OrderBookSide::new(Side::Buy, self.levels(&self.bids).rev()),
+            asks: OrderBookSide::new(Side::Sell, self.levels(&self.asks)),
+        }
+    }
+
+    /// Aggregated [`Level`]s of one side of this [`OrderBookL3`], in ascending price order.
+    fn levels<'a>(
+        &'a self,
+        side: &'a BTreeMap<Price, VecDeque<String>>,
+    ) -> impl DoubleEndedIterator<Item = Level> + 'a {
+        side.iter().map(|(price, queue)| {
+            let amount = queue
+                .iter()
+                .map(|order_id| self.orders[order_id].amount)
+                .sum::<f64>();
+            Level::new(price.0, amount)
+        })
+    }
+
+    fn side(&self, side: Side) -> &BTreeMap<Price, VecDeque<String>> {
+        match side {
+            Side::Buy => &self.bids,
+            Side::Sell => &self.asks,
+        }
+    }
+
+    fn side_mut(&mut self, side: Side) -> &mut BTreeMap<Price, VecDeque<String>> {
+        match side {
+            Side::Buy => &mut self.bids,
+            Side::Sell => &mut self.asks,
+        }
+    }
+
+    /// Add the [`OrderL3`] to the back of its price level queue, replacing any existing order
+    /// with the same `order_id`.
+    fn open(&mut self, order: OrderL3) {
+        self.remove(&order.order_id);
+
+        if order.amount <= 0.0 {
+            return;
+        }
+
+        self.side_mut(order.side)
+            .entry(Price(order.price))
+            .or_default()
+            .push_back(order.order_id.clone());
+        self.orders.insert(order.order_id.clone(), order);
+    }
+
+    /// Modify an open order. Time priority is retained if only the amount is reduced, otherwise
+    /// the order is moved to the back of its (new) price level queue.
+    fn change(&mut self, order: OrderL3) {
+        match self.orders.get_mut(&order.order_id) {
+            Some(existing)
+                if existing.price.total_cmp(&order.price).is_eq()
+                    && order.amount <= existing.amount
+                    && order.amount > 0.0 =>
+            {
+                existing.amount = order.amount;
+            }
+            _ => self.open(order),
+        }
+    }
+
+    /// Reduce the amount of an open order after a match, removing it if fully filled.
+    fn fill(&mut self, order_id: &str, amount: f64) {
+        let Some(order) = self.orders.get_mut(order_id) else {
+            debug!(order_id, "matched order not found in OrderBookL3");
+            return;
+        };
+
+        order.amount -= amount;
+        if order.amount <= 0.0 {
+            self.remove(order_id);
+        }
+    }
+
+    /// Remove an open order from this [`OrderBookL3`], returning it if it was present.
+    fn remove(&mut self, order_id: &str) -> Option<OrderL3> {
+        let order = self.orders.remove(order_id)?;
+        let side = self.side_mut(order.side);
+        let price = Price(order.price);
+
+        if let Some(queue) = side.get_mut(&price) {
+            queue.retain(|queued| queued != order_id);
+            if queue.is_empty() {
+                side.remove(&price);
+            }
+        }
+
+        Some(order)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::subscription::book::OrderBookL3DoneReason;
+
+    fn open(
+        sequence: u64,
+        order_id: &str,
+        side: Side,
+        price: f64,
+        amount: f64,
+    ) -> OrderBookL3Event {
+        OrderBookL3Event::Open {
+            sequence,
+            order_id: order_id.to_string(),
+            side,
+            price,
+            amount,
+        }
+    }
+
+    fn book(events: Vec<OrderBookL3Event>) -> OrderBookL3 {
+        let mut book = OrderBookL3::default();
+        events
+            .into_iter()
+            .for_each(|event| book.apply(event, Utc::now()));
+        book
+    }
+
+    #[test]
+    fn test_l2() {
+        let book = book(vec![
+            open(1, "b1", Side::Buy, 100.0, 1.0),
+            open(2, "b2", Side::Buy, 100.0, 2.0),
+            open(3, "b3", Side::Buy, 99.0, 1.0),
+            open(4, "a1", Side::Sell, 101.0, 1.5),
+            open(5, "a2", Side::Sell, 102.0, 1.0),
+        ]);
+
+        let l2 = book.l2();
+        assert_eq!(
+            l2.bids.levels(),
+            &[Level::new(100.0, 3.0), Level::new(99.0, 1.0)]
+        );
+        assert_eq!(
+            l2.asks.levels(),
+            &[Level::new(101.0, 1.5), Level::new(102.0, 1.0)]
+        );
+    }
+
+    #[test]
+    fn test_apply() {
+        struct TestCase {
+            input: Vec<OrderBookL3Event>,
+            expected_order: Option<OrderL3>,
+            expected_len: usize,
+        }
+
+        let order = |price, amount| {
+            Some(OrderL3 {
+                order_id: "b1".to_string(),
+                side: Side::Buy,
+                price,
+                amount,
+            })
+        };
+
+        let tests = vec![
+            TestCase {
+                // TC0: Open adds order
+                input: vec![open(1, "b1", Side::Buy, 100.0, 1.0)],
+                expected_order: order(100.0, 1.0),
+                expected_len: 1,
+            },
+            TestCase {
+                // TC1: Change modifies order
+                input: vec![
+                    open(1, "b1", Side::Buy, 100.0, 1.0),
+                    OrderBookL3Event::Change {
+                        sequence: 2,
+                        order_id: "b1".to_string(),
+                        side: Side::Buy,
+                        price: 100.0,
+                        amount: 0.5,
+                    },
+                ],
+                expected_order: order(100.0, 0.5),
+                expected_len: 1,
+            },
+            TestCase {
+                // TC2: Match partially fills maker order
+                input: vec![
+                    open(1, "b1", Side::Buy, 100.0, 1.0),
+                    OrderBookL3Event::Match {
+                        sequence: 2,
+                        trade_id: "t1".to_string(),
+                        maker_order_id: "b1".to_string(),
+                        taker_order_id: "s1".to_string(),
+                        side: Side::Buy,
+                        price: 100.0,
+                        amount: 0.25,
+                    },
+                ],
+                expected_order: order(100.0, 0.75),
+                expected_len: 1,
+            },
+            TestCase {
+                // TC3: Done removes order
+                input: vec![
+                    open(1, "b1", Side::Buy, 100.0, 1.0),
+                    OrderBookL3Event::Done {
+                        sequence: 2,
+                        order_id: "b1".to_string(),
+                        side: Side::Buy,
+                        price: Some(100.0),
+                        remaining: Some(1.0),
+                        reason: OrderBookL3DoneReason::Cancelled,
+                    },
+                ],
+                expected_order: None,
+                expected_len: 0,
+            },
+            TestCase {
+                // TC4: stale event is ignored
+                input: vec![
+                    open(2, "b1", Side::Buy, 100.0, 1.0),
+                    open(1, "b2", Side::Buy, 100.0, 1.0),
+                ],
+                expected_order: order(100.0, 1.0),
+                expected_len: 1,
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = book(test.input);
+            assert_eq!(
+                actual.order("b1").cloned(),
+                test.expected_order,
+                "TC{} failed",
+                index
+            );
+            assert_eq!(actual.len(), test.expected_len, "TC{} failed", index);
+        }
+    }
+
+    #[test]
+    fn test_queue_position() {
+        let mut book = book(vec![
+            open(1, "b1", Side::Buy, 100.0, 1.0),
+            open(2, "b2", Side::Buy, 100.0, 2.0),
+            open(3, "b3", Side::Buy, 100.0, 3.0),
+        ]);
+
+        assert_eq!(
+            book.queue_position("b3"),
+            Some(QueuePosition {
+                orders_ahead: 2,
+                amount_ahead: 3.0
+            })
+        );
+
+        // Reducing the amount retains time priority
+        book.apply(
+            OrderBookL3Event::Change {
+                sequence: 4,
+                order_id: "b1".to_string(),
+                side: Side::Buy,
+                price: 100.0,
+                amount: 0.5,
+            },
+            Utc::now(),
+        );
+        assert_eq!(
+            book.queue_position("b3"),
+            Some(QueuePosition {
+                orders_ahead: 2,
+                amount_ahead: 2.5
+            })
+        );
+
+        // Increasing the amount loses time priority
+        book.apply(
+            OrderBookL3Event::Change {
+                sequence: 5,
+                order_id: "b1".to_string(),
+                side: Side::Buy,
+                price: 100.0,
+                amount: 5.0,
+            },
+            Utc::now(),
+        );
+        assert_eq!(
+            book.queue_position("b1"),
+            Some(QueuePosition {
+                orders_ahead: 2,
+                amount_ahead: 5.0
+            })
+        );
+        assert_eq!(book.queue_position("unknown"), None);
+    }
+}
@@ -0,0 +1,138 @@
+use super::{Level, OrderBook, OrderBookSide};
+
+/// Pool of cleared `Vec<Level>` buffers, recycled across [`OrderBook`] snapshots to avoid a pair
+/// of allocations per update when maintaining many instruments' OrderBooks on one connection.
+///
+/// Each [`MultiBookTransformer`](crate::transformer::book::MultiBookTransformer) owns a pool, so
+/// connections never contend on a shared buffer store. Pooling is opt-in: the pool retains no
+/// buffers until a non-zero capacity is configured (eg/ via
+/// [`StreamBuilder::with_level_pool_capacity`](crate::streams::builder::StreamBuilder::with_level_pool_capacity)).
+/// Buffers are returned to the pool once a snapshot has been processed (eg/ via
+/// [`Recycle::recycle`]).
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct LevelPool {
+    capacity: usize,
+    buffers: Vec<Vec<Level>>,
+}
+
+impl LevelPool {
+    /// Construct a new [`Self`] retaining at most `capacity` buffers. Zero disables pooling.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            buffers: Vec::new(),
+        }
+    }
+
+    /// Set the maximum number of buffers retained by this pool, releasing any excess. Zero
+    /// disables pooling.
+    pub fn set_capacity(&mut self, capacity: usize) {
+        self.capacity = capacity;
+        self.buffers.truncate(capacity);
+        self.buffers.shrink_to(capacity);
+    }
+
+    /// Maximum number of buffers retained by this pool.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Number of buffers currently available for reuse.
+    pub fn len(&self) -> usize {
+        self.buffers.len()
+    }
+
+    /// Returns true if no buffers are currently available for reuse.
+    pub fn is_empty(&self) -> bool {
+        self.buffers.is_empty()
+    }
+
+    /// Copy the provided [`Level`]s into a pooled buffer, allocating a new one if none are
+    /// available.
+    pub fn copy(&mut self, levels: &[Level]) -> Vec<Level> {
+        match self.buffers.pop() {
+            Some(mut buffer) => {
+                buffer.extend_from_slice(levels);
+                buffer
+            }
+            None => levels.to_vec(),
+        }
+    }
+
+    /// Clear & return the provided buffer to this pool, dropping it if the pool is full.
+    pub fn put(&mut self, mut levels: Vec<Level>) {
+        if levels.capacity() == 0 || self.buffers.len() >= self.capacity {
+            return;
+        }
+
+        levels.clear();
+        self.buffers.push(levels);
+    }
+
+    /// Copy the provided [`OrderBookSide`] using a pooled buffer.
+    pub(super) fn copy_side(&mut self, side: &OrderBookSide) -> OrderBookSide {
+        OrderBookSide {
+            side: side.side,
+            levels: self.copy(&side.levels),
+        }
+    }
+}
+
+/// Owner of a [`LevelPool`] that processed [`OrderBook`]s can be returned to, so their buffers
+/// are reused by subsequent snapshots.
+pub trait Recycle {
+    /// Return both [`OrderBookSide`] buffers of a processed [`OrderBook`] to the [`LevelPool`].
+    fn recycle(&mut self, book: OrderBook);
+}
+
+impl Recycle for LevelPool {
+    fn recycle(&mut self, book: OrderBook) {
+        self.put(book.bids.levels);
+        self.put(book.asks.levels);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use barter_integration::model::Side;
+    use chrono::Utc;
+
+    fn book(levels: usize) -> OrderBook {
+        OrderBook {
+            last_update_time: Utc::now(),
+            bids: OrderBookSide::new(Side::Buy, vec![Level::new(100.0, 1.0); levels]),
+            asks: OrderBookSide::new(Side::Sell, vec![Level::new(101.0, 1.0); levels]),
+        }
+    }
+
+    #[test]
+    fn test_level_pool() {
+        let mut pool = LevelPool::default();
+
+        // Pooling is disabled by default
+        pool.recycle(book(10));
+        assert!(pool.is_empty());
+
+        // Recycled buffers are retained up to the capacity
+        pool.set_capacity(3);
+        pool.recycle(book(10));
+        pool.recycle(book(10));
+        assert_eq!(pool.len(), 3);
+
+        // Copies reuse a recycled buffer, retaining its allocation
+        let levels = [Level::new(99.0, 2.0)];
+        let actual = pool.copy(&levels);
+        assert_eq!(actual, levels);
+        assert!(actual.capacity() >= 10);
+        assert_eq!(pool.len(), 2);
+
+        // Reducing the capacity releases excess buffers
+        pool.set_capacity(1);
+        assert_eq!(pool.len(), 1);
+
+        // Disabling pooling releases every buffer
+        pool.set_capacity(0);
+        assert!(pool.is_empty());
+    }
+}
@@ -1,14 +1,98 @@
-use super::SubscriptionKind;
+use super::{SubKind, SubKindId, SubscriptionKind};
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use std::time::Duration;
 
 /// Barter [`Subscription`](super::Subscription) [`SubscriptionKind`] that yields [`Candle`]
-/// [`MarketEvent<T>`](crate::event::MarketEvent) events.
+/// [`MarketEvent<T>`](crate::event::MarketEvent) events for the provided [`Interval`].
 #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
-pub struct Candles;
+pub struct Candles(pub Interval);
 
 impl SubscriptionKind for Candles {
     type Event = Candle;
+    const ID: SubKindId = SubKindId::Kind(SubKind::Candles);
+}
+
+/// Barter [`Subscription`](super::Subscription) [`SubscriptionKind`] that yields mark price
+/// [`Candle`] [`MarketEvent<T>`](crate::event::MarketEvent) events for the provided [`Interval`].
+///
+/// Mark price candles are derived from the exchange mark price rather than trades, so the
+/// [`Candle`] `volume` and `trade_count` are always zero.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct MarkPriceCandles(pub Interval);
+
+impl SubscriptionKind for MarkPriceCandles {
+    type Event = Candle;
+    const ID: SubKindId = SubKindId::Kind(SubKind::MarkPriceCandles);
+}
+
+/// [`Candle`] interval.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub enum Interval {
+    #[serde(alias = "1m")]
+    M1,
+    #[serde(alias = "3m")]
+    M3,
+    #[serde(alias = "5m")]
+    M5,
+    #[serde(alias = "15m")]
+    M15,
+    #[serde(alias = "30m")]
+    M30,
+    #[serde(alias = "1h")]
+    H1,
+    #[serde(alias = "2h")]
+    H2,
+    #[serde(alias = "4h")]
+    H4,
+    #[serde(alias = "6h")]
+    H6,
+    #[serde(alias = "12h")]
+    H12,
+    #[serde(alias = "1d")]
+    D1,
+    #[serde(alias = "1w")]
+    W1,
+}
+
+impl Interval {
+    /// Every supported [`Interval`].
+    pub const ALL: [Self; 12] = [
+        Self::M1,
+        Self::M3,
+        Self::M5,
+        Self::M15,
+        Self::M30,
+        Self::H1,
+        Self::H2,
+        Self::H4,
+        Self::H6,
+        Self::H12,
+        Self::D1,
+        Self::W1,
+    ];
+
+    /// [`Duration`] of this [`Interval`].
+    pub fn duration(&self) -> Duration {
+        const MINUTE: u64 = 60;
+        const HOUR: u64 = 60 * MINUTE;
+        const DAY: u64 = 24 * HOUR;
+
+        Duration::from_secs(match self {
+            Self::M1 => MINUTE,
+            Self::M3 => 3 * MINUTE,
+            Self::M5 => 5 * MINUTE,
+            Self::M15 => 15 * MINUTE,
+            Self::M30 => 30 * MINUTE,
+            Self::H1 => HOUR,
+            Self::H2 => 2 * HOUR,
+            Self::H4 => 4 * HOUR,
+            Self::H6 => 6 * HOUR,
+            Self::H12 => 12 * HOUR,
+            Self::D1 => DAY,
+            Self::W1 => 7 * DAY,
+        })
+    }
 }
 
 /// Normalised Barter OHLCV [`Candle`] model.
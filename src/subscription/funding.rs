@@ -0,0 +1,22 @@
+use super::{SubKind, SubKindId, SubscriptionKind};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Barter [`Subscription`](super::Subscription) [`SubscriptionKind`] that yields [`FundingRate`]
+/// [`MarketEvent<T>`](crate::event::MarketEvent) events.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct FundingRates;
+
+impl SubscriptionKind for FundingRates {
+    type Event = FundingRate;
+    const ID: SubKindId = SubKindId::Kind(SubKind::FundingRates);
+}
+
+/// Normalised Barter perpetual [`FundingRate`] model.
+///
+/// The `next_funding_time` is `None` if it is not provided by the exchange.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct FundingRate {
+    pub rate: f64,
+    pub next_funding_time: Option<DateTime<Utc>>,
+}
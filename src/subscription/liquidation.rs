@@ -1,4 +1,4 @@
-use super::SubscriptionKind;
+use super::{SubKind, SubKindId, SubscriptionKind};
 use barter_integration::model::Side;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
@@ -10,6 +10,7 @@ pub struct Liquidations;
 
 impl SubscriptionKind for Liquidations {
     type Event = Liquidation;
+    const ID: SubKindId = SubKindId::Kind(SubKind::Liquidations);
 }
 
 /// Normalised Barter [`Liquidation`] model.
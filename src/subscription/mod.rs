@@ -1,3 +1,4 @@
+use crate::event::DataKind;
 use crate::exchange::{Connector, ExchangeId};
 use crate::instrument::{InstrumentData, KeyedInstrument};
 use barter_integration::{
@@ -24,9 +25,27 @@ pub mod book;
 /// Candle [`SubscriptionKind`] and the associated Barter output data model.
 pub mod candle;
 
+/// Funding rate [`SubscriptionKind`] and the associated Barter output data model.
+pub mod funding;
+
 /// Liquidation [`SubscriptionKind`] and the associated Barter output data model.
 pub mod liquidation;
 
+/// Open interest [`SubscriptionKind`] and the associated Barter output data model.
+pub mod open_interest;
+
+/// Option summary [`SubscriptionKind`] and the associated Barter output data model.
+pub mod option;
+
+/// Premium index [`SubscriptionKind`] and the associated Barter output data model.
+pub mod premium;
+
+/// Market statistics [`SubscriptionKind`] and the associated Barter output data model.
+pub mod stats;
+
+/// Exchange system status [`SubscriptionKind`] and the associated Barter output data model.
+pub mod status;
+
 /// Public trade [`SubscriptionKind`] and the associated Barter output data model.
 pub mod trade;
 
@@ -36,6 +55,61 @@ where
     Self: Debug + Clone,
 {
     type Event: Debug;
+
+    /// [`SubKindId`] identifying the provenance of the [`Self::Event`]s yielded by this
+    /// [`SubscriptionKind`] (eg/ to demultiplex streams merged by a
+    /// [`MultiStreamBuilder`](crate::streams::builder::multi::MultiStreamBuilder)).
+    const ID: SubKindId;
+
+    /// Determine if the exchange associated with the provided [`ExchangeId`] supports this
+    /// [`SubscriptionKind`] for the provided [`InstrumentKind`].
+    ///
+    /// Defaults to `true`, since a statically typed [`SubscriptionKind`] is only usable with an
+    /// exchange that implements the associated [`StreamSelector`](crate::exchange::StreamSelector).
+    fn supported_by(&self, _exchange: ExchangeId, _instrument_kind: InstrumentKind) -> bool {
+        true
+    }
+}
+
+/// Barter [`Subscription`] [`SubscriptionKind`] that yields [`DataKind`]
+/// [`MarketEvent<T>`](crate::event::MarketEvent) events, allowing [`Subscription`]s of mixed
+/// [`SubKind`]s to be actioned over the same connection and consumed as one stream.
+///
+/// ### Notes
+/// Only the [`SubKind`]s in [`Self::SUB_KINDS`] are supported. [`SubKind::OrderBooksL2`]
+/// [`Subscription`]s require a stateful transformer, so can only share a connection with other
+/// [`SubKind`]s when using the barter [`Instrument`] (see
+/// [`DataKindsTransformer`](crate::transformer::kinds::DataKindsTransformer)).
+///
+/// eg/ `Subscription::from((BinanceSpot::default(), "btc", "usdt", InstrumentKind::Spot, DataKinds(SubKind::OrderBooksL1)))`
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct DataKinds(pub SubKind);
+
+impl DataKinds {
+    /// [`SubKind`]s that can be combined into a [`DataKinds`] stream.
+    pub const SUB_KINDS: [SubKind; 6] = [
+        SubKind::PublicTrades,
+        SubKind::AggTrades,
+        SubKind::OrderBooksL1,
+        SubKind::OrderBooksL2,
+        SubKind::Candles,
+        SubKind::Liquidations,
+    ];
+}
+
+impl From<SubKind> for DataKinds {
+    fn from(sub_kind: SubKind) -> Self {
+        Self(sub_kind)
+    }
+}
+
+impl SubscriptionKind for DataKinds {
+    type Event = DataKind;
+    const ID: SubKindId = SubKindId::DataKinds;
+
+    fn supported_by(&self, exchange: ExchangeId, instrument_kind: InstrumentKind) -> bool {
+        Self::SUB_KINDS.contains(&self.0) && exchange.supports(instrument_kind, self.0)
+    }
 }
 
 /// Barter [`Subscription`] used to subscribe to a [`SubscriptionKind`] for a particular exchange
@@ -54,11 +128,34 @@ pub struct Subscription<Exchange = ExchangeId, Inst = Instrument, Kind = SubKind
 )]
 pub enum SubKind {
     PublicTrades,
+    AggTrades,
+    BlockTrades,
     OrderBooksL1,
     OrderBooksL2,
+    OrderBooksL2Events,
+    OrderBookDeltas,
     OrderBooksL3,
     Liquidations,
     Candles,
+    MarkPriceCandles,
+    OptionSummaries,
+    PremiumIndexes,
+    FundingRates,
+    OpenInterests,
+    MarketStats,
+    ExchangeStatus,
+}
+
+/// Identifies the [`SubscriptionKind`] a stream of events originates from.
+///
+/// Statically typed [`SubscriptionKind`]s yield events of a single [`SubKind`], whereas
+/// [`DataKinds`] streams yield [`DataKind`] events of mixed [`SubKind`]s.
+#[derive(
+    Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize, Display,
+)]
+pub enum SubKindId {
+    Kind(SubKind),
+    DataKinds,
 }
 
 impl<Exchange, Instrument, Kind> Display for Subscription<Exchange, Instrument, Kind>
@@ -132,6 +229,7 @@ impl<Instrument, Exchange, Kind> Subscription<Exchange, Instrument, Kind> {
 impl<Exchange, Kind> Validator for &Subscription<Exchange, Instrument, Kind>
 where
     Exchange: Connector,
+    Kind: SubscriptionKind,
 {
     fn validate(self) -> Result<Self, SocketError>
     where
@@ -141,14 +239,22 @@ where
         let exchange = Exchange::ID;
 
         // Validate the Exchange supports the Subscription InstrumentKind
-        if exchange.supports_instrument_kind(self.instrument.kind) {
-            Ok(self)
-        } else {
-            Err(SocketError::Unsupported {
+        if !exchange.supports_instrument_kind(self.instrument.kind) {
+            return Err(SocketError::Unsupported {
                 entity: exchange.as_str(),
-                item: self.instrument.kind.to_string(),
-            })
+                item: unsupported_instrument_kind(exchange, self.instrument.kind),
+            });
         }
+
+        // Validate the Exchange supports the SubscriptionKind (eg/ runtime DataKinds)
+        if !self.kind.supported_by(exchange, self.instrument.kind) {
+            return Err(SocketError::Unsupported {
+                entity: exchange.as_str(),
+                item: format!("{:?} {}", self.kind, self.instrument.kind),
+            });
+        }
+
+        Ok(self)
     }
 }
 
@@ -166,12 +272,27 @@ where
         } else {
             Err(SocketError::Unsupported {
                 entity: self.exchange.as_str(),
-                item: self.instrument.kind().to_string(),
+                item: format!(
+                    "{} {}",
+                    self.kind,
+                    unsupported_instrument_kind(self.exchange, self.instrument.kind())
+                ),
             })
         }
     }
 }
 
+/// Describe an unsupported [`InstrumentKind`], suggesting the [`InstrumentKind`]s the exchange
+/// does support.
+///
+/// eg/ "perpetual (supported InstrumentKinds: spot)"
+fn unsupported_instrument_kind(exchange: ExchangeId, kind: InstrumentKind) -> String {
+    format!(
+        "{kind} (supported InstrumentKinds: {})",
+        exchange.supported_instrument_kinds().join(", ")
+    )
+}
+
 /// Metadata generated from a collection of Barter [`Subscription`]s, including the exchange
 /// specific subscription payloads that are sent to the exchange.
 #[derive(Clone, Eq, PartialEq, Debug)]
@@ -240,6 +361,7 @@ mod tests {
             use crate::{
                 exchange::{
                     binance::{futures::BinanceFuturesUsd, spot::BinanceSpot},
+                    bybit::spot::BybitSpot,
                     gateio::perpetual::GateioPerpetualsUsd,
                     okx::Okx,
                 },
@@ -295,6 +417,58 @@ mod tests {
                 .unwrap();
             }
 
+            #[test]
+            fn test_subscription_bybit_spot_order_books_l2_with_depth() {
+                let input = r#"
+                {
+                    "exchange": "bybit_spot",
+                    "base": "btc",
+                    "quote": "usdt",
+                    "instrument_kind": "spot",
+                    "kind": { "order_books_l2": { "depth": 200 } }
+                }
+                "#;
+
+                let actual = serde_json::from_str::<
+                    Subscription<BybitSpot, Instrument, OrderBooksL2>,
+                >(input)
+                .unwrap();
+
+                assert_eq!(actual.kind, OrderBooksL2::with_depth(200));
+                assert_eq!(
+                    serde_json::to_value(actual.kind).unwrap(),
+                    serde_json::json!({ "order_books_l2": { "depth": 200 } })
+                );
+                assert_eq!(
+                    serde_json::to_value(OrderBooksL2::default()).unwrap(),
+                    serde_json::json!("order_books_l2")
+                );
+            }
+
+            #[test]
+            fn test_subscription_binance_futures_usd_order_books_l2_with_interval() {
+                let input = r#"
+                {
+                    "exchange": "binance_futures_usd",
+                    "base": "btc",
+                    "quote": "usdt",
+                    "instrument_kind": "perpetual",
+                    "kind": { "order_books_l2": { "depth": 10, "interval_ms": 1000 } }
+                }
+                "#;
+
+                let actual = serde_json::from_str::<
+                    Subscription<BinanceFuturesUsd, Instrument, OrderBooksL2>,
+                >(input)
+                .unwrap();
+
+                assert_eq!(actual.kind, OrderBooksL2::with_depth(10).interval_ms(1000));
+                assert_eq!(
+                    serde_json::to_value(OrderBooksL2::default().interval_ms(1000)).unwrap(),
+                    serde_json::json!({ "order_books_l2": { "interval_ms": 1000 } })
+                );
+            }
+
             #[test]
             fn subscription_gateio_futures_usd_public_trades() {
                 let input = r#"
@@ -430,6 +604,60 @@ mod tests {
                 }
             }
         }
+        #[test]
+        fn test_validate_binance_data_kinds() {
+            use crate::exchange::binance::{futures::BinanceFuturesUsd, spot::BinanceSpot};
+
+            // Valid BinanceSpot Spot DataKinds(OrderBooksL1) subscription
+            let subscription = Subscription::<_, Instrument, _>::from((
+                BinanceSpot::default(),
+                "btc",
+                "usdt",
+                InstrumentKind::Spot,
+                DataKinds(SubKind::OrderBooksL1),
+            ));
+            assert!((&subscription).validate().is_ok());
+
+            // Invalid BinanceSpot Spot DataKinds(Liquidations) subscription
+            let subscription = Subscription::<_, Instrument, _>::from((
+                BinanceSpot::default(),
+                "btc",
+                "usdt",
+                InstrumentKind::Spot,
+                DataKinds(SubKind::Liquidations),
+            ));
+            assert!((&subscription).validate().is_err());
+
+            // Valid BinanceFuturesUsd Perpetual DataKinds(Liquidations) subscription
+            let subscription = Subscription::<_, Instrument, _>::from((
+                BinanceFuturesUsd::default(),
+                "btc",
+                "usdt",
+                InstrumentKind::Perpetual,
+                DataKinds(SubKind::Liquidations),
+            ));
+            assert!((&subscription).validate().is_ok());
+
+            // Valid BinanceFuturesUsd Perpetual DataKinds(OrderBooksL2) subscription
+            let subscription = Subscription::<_, Instrument, _>::from((
+                BinanceFuturesUsd::default(),
+                "btc",
+                "usdt",
+                InstrumentKind::Perpetual,
+                DataKinds(SubKind::OrderBooksL2),
+            ));
+            assert!((&subscription).validate().is_ok());
+
+            // Invalid BinanceFuturesUsd Perpetual DataKinds(OrderBooksL3) subscription
+            let subscription = Subscription::<_, Instrument, _>::from((
+                BinanceFuturesUsd::default(),
+                "btc",
+                "usdt",
+                InstrumentKind::Perpetual,
+                DataKinds(SubKind::OrderBooksL3),
+            ));
+            assert!((&subscription).validate().is_err());
+        }
     }
 
     mod instrument_map {
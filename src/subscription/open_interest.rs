@@ -0,0 +1,33 @@
+use super::{SubKind, SubKindId, SubscriptionKind};
+use crate::poll::DEFAULT_POLL_INTERVAL;
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+/// Barter [`Subscription`](super::Subscription) [`SubscriptionKind`] that yields [`OpenInterest`]
+/// [`MarketEvent<T>`](crate::event::MarketEvent) events.
+///
+/// Open interest is polled via the exchange REST API once every `poll_interval`.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct OpenInterests {
+    pub poll_interval: Duration,
+}
+
+impl Default for OpenInterests {
+    fn default() -> Self {
+        Self {
+            poll_interval: DEFAULT_POLL_INTERVAL,
+        }
+    }
+}
+
+impl SubscriptionKind for OpenInterests {
+    type Event = OpenInterest;
+    const ID: SubKindId = SubKindId::Kind(SubKind::OpenInterests);
+}
+
+/// Normalised Barter [`OpenInterest`] model, measured as the total number of outstanding
+/// contracts.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct OpenInterest {
+    pub contracts: f64,
+}
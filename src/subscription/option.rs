@@ -0,0 +1,32 @@
+use super::{SubKind, SubKindId, SubscriptionKind};
+use barter_macro::{DeSubKind, SerSubKind};
+use serde::{Deserialize, Serialize};
+
+/// Barter [`Subscription`](super::Subscription) [`SubscriptionKind`] that yields [`OptionSummary`]
+/// [`MarketEvent<T>`](crate::event::MarketEvent) events.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, DeSubKind, SerSubKind)]
+pub struct OptionSummaries;
+
+impl SubscriptionKind for OptionSummaries {
+    type Event = OptionSummary;
+    const ID: SubKindId = SubKindId::Kind(SubKind::OptionSummaries);
+}
+
+/// Normalised Barter [`OptionSummary`] model, containing the exchange provided pricing & greeks of
+/// an option contract.
+///
+/// ### Notes
+/// - `iv` is the mark implied volatility as a fraction (eg/ 0.55 for 55%).
+/// - `mark` is denominated in the same currency as the exchange quotes the option premium
+///   (eg/ BTC for Deribit inverse options).
+/// - `open_interest` is denominated in contracts.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct OptionSummary {
+    pub iv: f64,
+    pub delta: f64,
+    pub gamma: f64,
+    pub vega: f64,
+    pub theta: f64,
+    pub mark: f64,
+    pub open_interest: f64,
+}
@@ -0,0 +1,53 @@
+use super::{SubKind, SubKindId, SubscriptionKind};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Barter [`Subscription`](super::Subscription) [`SubscriptionKind`] that yields [`PremiumIndex`]
+/// [`MarketEvent<T>`](crate::event::MarketEvent) events.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct PremiumIndexes;
+
+impl SubscriptionKind for PremiumIndexes {
+    type Event = PremiumIndex;
+    const ID: SubKindId = SubKindId::Kind(SubKind::PremiumIndexes);
+}
+
+/// Normalised Barter [`PremiumIndex`] model, describing the premium (basis) of a derivative mark
+/// price relative to the underlying index price.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct PremiumIndex {
+    pub mark_price: f64,
+    pub index_price: f64,
+    pub funding_rate: f64,
+    pub next_funding_time: DateTime<Utc>,
+}
+
+impl PremiumIndex {
+    /// Calculate the absolute premium of the mark price over the index price.
+    pub fn premium(&self) -> f64 {
+        self.mark_price - self.index_price
+    }
+
+    /// Calculate the basis rate, being the premium as a fraction of the index price.
+    pub fn basis_rate(&self) -> f64 {
+        self.premium() / self.index_price
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_premium_index() {
+        let index = PremiumIndex {
+            mark_price: 101.0,
+            index_price: 100.0,
+            funding_rate: 0.0001,
+            next_funding_time: DateTime::<Utc>::MIN_UTC,
+        };
+
+        assert_eq!(index.premium(), 1.0);
+        assert_eq!(index.basis_rate(), 0.01);
+    }
+}
@@ -0,0 +1,44 @@
+use super::{SubKind, SubKindId, SubscriptionKind};
+use crate::poll::DEFAULT_POLL_INTERVAL;
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+/// Barter [`Subscription`](super::Subscription) [`SubscriptionKind`] that yields
+/// [`MarketSentiment`] [`MarketEvent<T>`](crate::event::MarketEvent) events.
+///
+/// Market statistics are polled via the exchange REST API once every `poll_interval`.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct MarketStats {
+    pub poll_interval: Duration,
+}
+
+impl Default for MarketStats {
+    fn default() -> Self {
+        Self {
+            poll_interval: DEFAULT_POLL_INTERVAL,
+        }
+    }
+}
+
+impl SubscriptionKind for MarketStats {
+    type Event = MarketSentiment;
+    const ID: SubKindId = SubKindId::Kind(SubKind::MarketStats);
+}
+
+/// Normalised Barter [`MarketSentiment`] model.
+///
+/// Combines the long/short positioning of the exchange's top traders with the taker buy/sell
+/// volume over the same period.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct MarketSentiment {
+    /// Ratio of top trader long positions to short positions.
+    pub long_short_ratio: f64,
+    /// Share of top trader positions that are long (0.0 to 1.0).
+    pub long_share: f64,
+    /// Share of top trader positions that are short (0.0 to 1.0).
+    pub short_share: f64,
+    /// Ratio of taker buy volume to taker sell volume.
+    pub taker_buy_sell_ratio: f64,
+    pub taker_buy_volume: f64,
+    pub taker_sell_volume: f64,
+}
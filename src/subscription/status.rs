@@ -0,0 +1,66 @@
+use super::{SubKind, SubKindId, SubscriptionKind};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Barter [`Subscription`](super::Subscription) [`SubscriptionKind`] that yields venue-wide
+/// [`SystemStatus`] [`MarketEvent<T>`](crate::event::MarketEvent) events.
+///
+/// Exchange system messages are not instrument specific, so each [`SystemStatus`] is emitted
+/// once for every instrument with an active [`ExchangeStatus`] subscription.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct ExchangeStatus;
+
+impl SubscriptionKind for ExchangeStatus {
+    type Event = SystemStatus;
+    const ID: SubKindId = SubKindId::Kind(SubKind::ExchangeStatus);
+}
+
+/// Normalised Barter [`SystemStatus`] model describing exchange connectivity & maintenance.
+///
+/// The `start_time` and `end_time` are only provided by exchanges that announce maintenance
+/// windows in advance.
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct SystemStatus {
+    pub state: SystemState,
+    pub message: Option<String>,
+    pub start_time: Option<DateTime<Utc>>,
+    pub end_time: Option<DateTime<Utc>>,
+}
+
+impl SystemStatus {
+    /// Construct a [`SystemStatus`] without a message or maintenance window.
+    pub fn new(state: SystemState) -> Self {
+        Self {
+            state,
+            message: None,
+            start_time: None,
+            end_time: None,
+        }
+    }
+}
+
+/// Normalised exchange [`SystemState`].
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SystemState {
+    /// Exchange is fully operational.
+    Online,
+
+    /// Exchange is fully operational, but maintenance has been scheduled.
+    Scheduled,
+
+    /// Exchange is in maintenance, and trading is unavailable.
+    Maintenance,
+
+    /// Only order cancellations are accepted.
+    CancelOnly,
+
+    /// Only limit orders are accepted.
+    LimitOnly,
+
+    /// Only post-only limit orders are accepted.
+    PostOnly,
+
+    /// Exchange server is restarting, and connections must be re-established.
+    Restarting,
+}
@@ -1,5 +1,6 @@
-use super::SubscriptionKind;
-use barter_integration::model::Side;
+use super::{SubKind, SubKindId, SubscriptionKind};
+use crate::event::MarketEvent;
+use barter_integration::model::{Exchange, Side};
 use barter_macro::{DeSubKind, SerSubKind};
 use serde::{Deserialize, Serialize};
 
@@ -10,6 +11,7 @@ pub struct PublicTrades;
 
 impl SubscriptionKind for PublicTrades {
     type Event = PublicTrade;
+    const ID: SubKindId = SubKindId::Kind(SubKind::PublicTrades);
 }
 
 /// Normalised Barter [`PublicTrade`] model.
@@ -20,3 +22,155 @@ pub struct PublicTrade {
     pub amount: f64,
     pub side: Side,
 }
+
+impl PublicTrade {
+    /// Strongest venue-provided uniqueness key for this [`PublicTrade`], unique per exchange
+    /// instrument.
+    ///
+    /// Each connector populates the [`PublicTrade`] `id` with this key:
+    /// - Binance: trade id (`t`).
+    /// - Bitfinex: trade id.
+    /// - Bitmex: trade match id (`trdMatchID`).
+    /// - Bybit: trade id (`i`).
+    /// - Coinbase: trade id (`trade_id`).
+    /// - Deribit: trade id (`trade_id`).
+    /// - Gateio: trade id (`id`).
+    /// - Okx: trade id (`tradeId`).
+    /// - Kraken: no trade id or sequence is provided, so the key is derived from the trade time,
+    ///   side, price & amount, suffixed by the occurrence of identical fills within a message.
+    pub fn dedup_key(&self) -> &str {
+        &self.id
+    }
+}
+
+/// Normalised uniqueness key for a [`MarketEvent<PublicTrade>`](MarketEvent), allowing downstream
+/// exactly-once consumers (eg/ transactional producers, database upserts) to deduplicate trades
+/// without venue specific logic.
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct TradeDedupKey<InstrumentId> {
+    pub exchange: Exchange,
+    pub instrument: InstrumentId,
+    pub id: String,
+}
+
+impl<InstrumentId> MarketEvent<InstrumentId, PublicTrade>
+where
+    InstrumentId: Clone,
+{
+    /// Construct the [`TradeDedupKey`] for this [`MarketEvent<PublicTrade>`](MarketEvent).
+    pub fn dedup_key(&self) -> TradeDedupKey<InstrumentId> {
+        TradeDedupKey {
+            exchange: self.exchange.clone(),
+            instrument: self.instrument.clone(),
+            id: self.kind.dedup_key().to_owned(),
+        }
+    }
+}
+
+/// Barter [`Subscription`](super::Subscription) [`SubscriptionKind`] that yields [`AggTrade`]
+/// [`MarketEvent<T>`](crate::event::MarketEvent) events.
+///
+/// Useful for consumers that want a lower message rate than [`PublicTrades`].
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, DeSubKind, SerSubKind)]
+pub struct AggTrades;
+
+impl SubscriptionKind for AggTrades {
+    type Event = AggTrade;
+    const ID: SubKindId = SubKindId::Kind(SubKind::AggTrades);
+}
+
+/// Normalised Barter [`AggTrade`] model, representing several public trades that were filled at
+/// the same price, on the same side, by the same taker order.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct AggTrade {
+    pub first_id: u64,
+    pub last_id: u64,
+    pub price: f64,
+    pub amount: f64,
+    pub side: Side,
+}
+
+/// Barter [`Subscription`](super::Subscription) [`SubscriptionKind`] that yields [`BlockTrade`]
+/// [`MarketEvent<T>`](crate::event::MarketEvent) events.
+///
+/// Block trades are large trades negotiated off the public order book, and are therefore not
+/// included in the [`PublicTrades`] feed.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, DeSubKind, SerSubKind)]
+pub struct BlockTrades;
+
+impl SubscriptionKind for BlockTrades {
+    type Event = BlockTrade;
+    const ID: SubKindId = SubKindId::Kind(SubKind::BlockTrades);
+}
+
+/// Normalised Barter [`BlockTrade`] model.
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct BlockTrade {
+    pub id: String,
+    pub price: f64,
+    pub amount: f64,
+    pub side: Side,
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use crate::{event::MarketIter, exchange::ExchangeId};
+    use chrono::Utc;
+    use serde::de::DeserializeOwned;
+    use std::collections::HashSet;
+
+    /// Transform the same raw trades frame twice (ie/ a duplicate frame), asserting the duplicate
+    /// yields identical [`TradeDedupKey`]s, and that every key is unique within a single frame.
+    pub(crate) fn assert_duplicate_frame_dedup_keys<Message>(exchange: ExchangeId, frame: &str)
+    where
+        Message: DeserializeOwned,
+        MarketIter<u64, PublicTrade>: From<(ExchangeId, u64, Message)>,
+    {
+        let dedup_keys = || {
+            let message = serde_json::from_str::<Message>(frame).unwrap();
+            let MarketIter(events) = MarketIter::from((exchange, 1, message));
+            events
+                .into_iter()
+                .map(|event| event.unwrap().dedup_key())
+                .collect::<Vec<_>>()
+        };
+
+        let keys = dedup_keys();
+        assert!(!keys.is_empty(), "{exchange} frame yielded no trades");
+        assert_eq!(
+            keys,
+            dedup_keys(),
+            "{exchange} duplicate frame yielded different dedup keys"
+        );
+        assert_eq!(
+            keys.iter().collect::<HashSet<_>>().len(),
+            keys.len(),
+            "{exchange} frame yielded colliding dedup keys"
+        );
+    }
+
+    #[test]
+    fn test_market_event_trade_dedup_key() {
+        let time = Utc::now();
+        let trade = |exchange: &'static str, instrument: u64, id: &str| MarketEvent {
+            exchange_time: time,
+            received_time: time,
+            exchange: Exchange::from(exchange),
+            instrument,
+            kind: PublicTrade {
+                id: id.to_string(),
+                price: 100.0,
+                amount: 1.0,
+                side: Side::Buy,
+            },
+        };
+
+        let base = trade("binance_spot", 1, "1");
+        assert_eq!(base.kind.dedup_key(), "1");
+        assert_eq!(base.dedup_key(), trade("binance_spot", 1, "1").dedup_key());
+        assert_ne!(base.dedup_key(), trade("binance_spot", 1, "2").dedup_key());
+        assert_ne!(base.dedup_key(), trade("binance_spot", 2, "1").dedup_key());
+        assert_ne!(base.dedup_key(), trade("okx", 1, "1").dedup_key());
+    }
+}
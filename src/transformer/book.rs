@@ -1,20 +1,53 @@
+use self::{audit::AuditConfig, checksum::BookChecksum};
 use crate::{
     error::DataError,
-    event::{MarketEvent, MarketIter},
+    event::MarketEvent,
     exchange::Connector,
-    subscription::{book::OrderBook, Map, SubscriptionKind},
+    runtime::Spawner,
+    streams::options::StreamOptions,
+    subscription::{
+        book::{
+            pool::{LevelPool, Recycle},
+            OrderBook, OrderBookEvent,
+        },
+        Map, SubscriptionKind,
+    },
     transformer::ExchangeTransformer,
     Identifier,
 };
 use async_trait::async_trait;
+use barter_integration::model::Exchange as ExchangeName;
 use barter_integration::{
     model::{instrument::Instrument, SubscriptionId},
     protocol::websocket::WsMessage,
     Transformer,
 };
+use chrono::Utc;
 use serde::{Deserialize, Serialize};
-use std::marker::PhantomData;
-use tokio::sync::mpsc;
+use std::{collections::HashMap, fmt::Debug, marker::PhantomData, time::Instant};
+use tokio::sync::{mpsc, oneshot, oneshot::error::TryRecvError};
+use tracing::{debug, warn};
+
+/// Opt-in OrderBook integrity audit that periodically diffs the locally maintained [`OrderBook`]
+/// against a fresh exchange snapshot.
+pub mod audit;
+
+/// [`BookChecksum`] abstraction & exchange CRC32 implementations used to validate the local
+/// [`OrderBook`] after each update.
+pub mod checksum;
+
+/// [`OrderBookL1Transformer`](l1::OrderBookL1Transformer) adapter that derives
+/// [`OrderBookL1`](crate::subscription::book::OrderBookL1) events from an
+/// [`OrderBooksL2`](crate::subscription::book::OrderBooksL2) transformer.
+pub mod l1;
+
+/// [`BookReplay`](replay::BookReplay) used to reconstruct [`OrderBook`] state at any point in time
+/// from recorded updates, reusing the live [`OrderBookUpdater`] implementations.
+pub mod replay;
+
+/// [`SnapshotFetcher`](snapshot::SnapshotFetcher) abstraction used to fetch initial OrderBook
+/// snapshots via HTTP, with timeouts, retries & rate limiting.
+pub mod snapshot;
 
 /// Defines how to apply a [`Self::Update`] to an [`Self::OrderBook`].
 #[async_trait]
@@ -25,6 +58,22 @@ where
     type OrderBook;
     type Update;
 
+    /// Determines if a [`MultiBookTransformer`] should re-run [`Self::init`] for an
+    /// [`Instrument`] after [`Self::update`] reports a sequence gap (ie/
+    /// [`DataError::InvalidSequence`]), rather than propagating the terminal error.
+    ///
+    /// Only appropriate if [`Self::init`] fully re-initialises the [`OrderBook`] (eg/ fresh HTTP
+    /// snapshot), since the WebSocket subscription is left untouched.
+    const RESYNC_ON_GAP: bool = false;
+
+    /// Determines if a [`MultiBookTransformer`] may audit the [`OrderBook`] by periodically
+    /// re-running [`Self::init`] and diffing the result against the local [`OrderBook`], once
+    /// enabled via the [`StreamOptions`] audit [`AuditConfig`].
+    ///
+    /// Only appropriate if [`Self::init`] fetches a fresh snapshot without side effects (eg/ HTTP
+    /// snapshot), and the returned [`Self`] validates subsequent updates against it.
+    const AUDIT: bool = false;
+
     /// Initialises the [`InstrumentOrderBook`] for the provided [`Instrument`]. This often requires
     /// a HTTP call to receive a starting [`OrderBook`] snapshot.
     async fn init<Exchange, Kind>(
@@ -35,12 +84,80 @@ where
         Exchange: Send,
         Kind: Send;
 
+    /// Configure the initialised [`Self`] for the exchange channel variant it was subscribed with
+    /// (eg/ partial vs diff depth streams), returning the [`SubscriptionId`] its [`Self::Update`]s
+    /// are identified by.
+    ///
+    /// Called after every [`Self::init`], including re-initialisations. Defaults to the subscribed
+    /// [`SubscriptionId`], which suits exchanges offering a single OrderBook channel variant.
+    fn configure(&mut self, subscription_id: SubscriptionId) -> SubscriptionId {
+        subscription_id
+    }
+
     /// Apply the [`Self::Update`] to the provided mutable [`Self::OrderBook`].
     fn update(
         &mut self,
         book: &mut Self::OrderBook,
         update: Self::Update,
+        pool: &mut LevelPool,
     ) -> Result<Option<Self::OrderBook>, DataError>;
+
+    /// Exchange checksum that the [`Self::OrderBook`] must match once the provided
+    /// [`Self::Update`] has been applied, if any.
+    ///
+    /// After applying such an update, the [`MultiBookTransformer`] validates the
+    /// [`Self::OrderBook`] using [`Self::book_checksum`].
+    fn expected_checksum(&self, _update: &Self::Update) -> Option<u32> {
+        None
+    }
+
+    /// [`BookChecksum`] used to calculate the local [`Self::OrderBook`] checksum, if the exchange
+    /// provides checksums.
+    fn book_checksum(&self) -> Option<&dyn BookChecksum> {
+        None
+    }
+
+    /// Handle a local [`Self::OrderBook`] checksum mismatch, returning the [`DataError`] to
+    /// propagate (eg/ after re-subscribing to receive a fresh snapshot).
+    fn checksum_mismatch(&mut self, expected: u32, actual: u32) -> DataError {
+        DataError::InvalidChecksum { expected, actual }
+    }
+}
+
+/// Output event of a [`MultiBookTransformer`], constructed from the managed [`OrderBook`].
+pub trait OrderBookOutput {
+    /// Determines if the initial [`OrderBook`] snapshot is emitted as an event.
+    const EMIT_SNAPSHOT: bool;
+
+    /// Construct the event emitted for the initial [`OrderBook`] snapshot.
+    fn snapshot(book: OrderBook) -> Self;
+
+    /// Construct the event emitted after an update has been applied to the [`OrderBook`].
+    fn update(book: OrderBook) -> Self;
+}
+
+impl OrderBookOutput for OrderBook {
+    const EMIT_SNAPSHOT: bool = false;
+
+    fn snapshot(book: OrderBook) -> Self {
+        book
+    }
+
+    fn update(book: OrderBook) -> Self {
+        book
+    }
+}
+
+impl OrderBookOutput for OrderBookEvent {
+    const EMIT_SNAPSHOT: bool = true;
+
+    fn snapshot(book: OrderBook) -> Self {
+        Self::Snapshot(book)
+    }
+
+    fn update(book: OrderBook) -> Self {
+        Self::Update(book)
+    }
 }
 
 /// [`OrderBook`] for an [`Instrument`] with an exchange specific [`OrderBookUpdater`] to define
@@ -55,24 +172,108 @@ pub struct InstrumentOrderBook<InstrumentId, Updater> {
 /// Standard generic [`ExchangeTransformer`] to translate exchange specific OrderBook types into
 /// normalised Barter OrderBook types. Requires an exchange specific [`OrderBookUpdater`]
 /// implementation.
-#[derive(Clone, PartialEq, Eq, Debug, Deserialize, Serialize)]
-pub struct MultiBookTransformer<Exchange, InstrumentId, Kind, Updater> {
+///
+/// If the [`OrderBookUpdater`] opts in via [`OrderBookUpdater::RESYNC_ON_GAP`], a sequence gap
+/// re-initialises only the affected [`InstrumentOrderBook`] in the background. Updates received
+/// in the meantime are buffered and applied once it completes, and the fresh [`OrderBook`] is
+/// emitted as a recovery [`OrderBookOutput::snapshot`] event.
+///
+/// If the [`OrderBookUpdater`] provides an [`OrderBookUpdater::expected_checksum`], the
+/// [`OrderBook`] is validated using the [`OrderBookUpdater::book_checksum`] after each update.
+///
+/// If the [`OrderBookUpdater`] opts in via [`OrderBookUpdater::AUDIT`] and an [`AuditConfig`] is
+/// set, each [`OrderBook`] is periodically diffed against a fresh exchange snapshot. Any
+/// discrepancy is logged & emitted as a non-terminal [`DataError::OrderBookDiscrepancy`].
+pub struct MultiBookTransformer<Exchange, InstrumentId, Kind, Updater>
+where
+    Updater: OrderBookUpdater,
+{
     pub book_map: Map<InstrumentOrderBook<InstrumentId, Updater>>,
+    snapshots: Vec<(InstrumentId, OrderBook)>,
+    ws_sink_tx: mpsc::UnboundedSender<WsMessage>,
+    resyncs: HashMap<SubscriptionId, Resync<InstrumentId, Updater>>,
+    audit: Option<AuditConfig>,
+    audits: HashMap<SubscriptionId, Audit<InstrumentId, Updater>>,
+    subscribed: HashMap<SubscriptionId, SubscriptionId>,
+    pool: LevelPool,
+    spawner: Spawner,
     phantom: PhantomData<(Exchange, Kind)>,
 }
 
+/// In-flight re-initialisation of an [`InstrumentOrderBook`] after a sequence gap.
+struct Resync<InstrumentId, Updater>
+where
+    Updater: OrderBookUpdater,
+{
+    init_rx: oneshot::Receiver<Result<InstrumentOrderBook<InstrumentId, Updater>, DataError>>,
+    buffered: Vec<Updater::Update>,
+}
+
+/// OrderBook integrity audit state of an [`InstrumentOrderBook`].
+enum Audit<InstrumentId, Updater> {
+    /// Next audit is due at the [`Instant`].
+    Scheduled(Instant),
+    /// Fetching a fresh exchange snapshot via [`OrderBookUpdater::init`].
+    Fetching(oneshot::Receiver<Result<InstrumentOrderBook<InstrumentId, Updater>, DataError>>),
+    /// Applying updates to the exchange snapshot until it reaches the same update as the local
+    /// [`OrderBook`], at which point they are diffed.
+    Syncing(InstrumentOrderBook<InstrumentId, Updater>),
+}
+
+impl<Exchange, InstrumentId, Kind, Updater> Debug
+    for MultiBookTransformer<Exchange, InstrumentId, Kind, Updater>
+where
+    InstrumentId: Debug,
+    Updater: OrderBookUpdater + Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MultiBookTransformer")
+            .field("book_map", &self.book_map)
+            .field("snapshots", &self.snapshots)
+            .field("resyncs", &self.resyncs.keys().collect::<Vec<_>>())
+            .field("audit", &self.audit)
+            .finish()
+    }
+}
+
+impl<Exchange, InstrumentId, Kind, Updater>
+    MultiBookTransformer<Exchange, InstrumentId, Kind, Updater>
+where
+    Exchange: Connector,
+    Kind: SubscriptionKind,
+    Kind::Event: OrderBookOutput,
+    Updater: OrderBookUpdater,
+{
+    /// Construct a [`MarketEvent`] from the provided [`OrderBook`] state.
+    fn market_event(
+        instrument: InstrumentId,
+        book: OrderBook,
+        output: fn(OrderBook) -> Kind::Event,
+    ) -> MarketEvent<InstrumentId, Kind::Event> {
+        MarketEvent {
+            exchange_time: book.last_update_time,
+            received_time: Utc::now(),
+            exchange: ExchangeName::from(Exchange::ID),
+            instrument,
+            kind: output(book),
+        }
+    }
+}
+
 #[async_trait]
 impl<Exchange, Kind, Updater> ExchangeTransformer<Exchange, Instrument, Kind>
     for MultiBookTransformer<Exchange, Instrument, Kind, Updater>
 where
-    Exchange: Connector + Send,
-    Kind: SubscriptionKind<Event = OrderBook> + Send,
-    Updater: OrderBookUpdater<OrderBook = Kind::Event> + Send,
-    Updater::Update: Identifier<Option<SubscriptionId>> + for<'de> Deserialize<'de>,
+    Exchange: Connector + Send + 'static,
+    Kind: SubscriptionKind + Send + 'static,
+    Kind::Event: OrderBookOutput,
+    Updater: OrderBookUpdater<OrderBook = OrderBook> + Send + 'static,
+    Updater::Update: Identifier<Option<SubscriptionId>> + for<'de> Deserialize<'de> + Clone,
 {
     async fn new(
         ws_sink_tx: mpsc::UnboundedSender<WsMessage>,
         map: Map<Instrument>,
+        options: &StreamOptions,
     ) -> Result<Self, DataError> {
         // Initialise InstrumentOrderBooks for all Subscriptions
         let (sub_ids, init_book_requests): (Vec<_>, Vec<_>) = map
@@ -87,45 +288,80 @@ where
             .unzip();
 
         // Await all initial OrderBook snapshot requests
-        let init_order_books = futures::future::join_all(init_book_requests)
+        let mut init_order_books = futures::future::join_all(init_book_requests)
             .await
             .into_iter()
             .collect::<Result<Vec<InstrumentOrderBook<Instrument, Updater>>, DataError>>()?;
 
-        // Construct OrderBookMap if all requests successful
+        // Retain initial OrderBook snapshots if they are to be emitted as events
+        let mut pool = LevelPool::new(options.level_pool_capacity);
+        let snapshots = match Kind::Event::EMIT_SNAPSHOT {
+            true => init_order_books
+                .iter_mut()
+                .map(|book| (book.instrument.clone(), book.book.snapshot_in(&mut pool)))
+                .collect(),
+            false => Vec::new(),
+        };
+
+        // Construct OrderBookMap if all requests successful, keyed by the SubscriptionId each
+        // configured OrderBookUpdater expects its updates to be identified by
+        let mut subscribed = HashMap::with_capacity(sub_ids.len());
         let book_map = sub_ids
             .into_iter()
-            .zip(init_order_books.into_iter())
+            .zip(init_order_books)
+            .map(|(sub_id, mut book)| {
+                let update_id = book.updater.configure(sub_id.clone());
+                subscribed.insert(update_id.clone(), sub_id);
+                (update_id, book)
+            })
             .collect::<Map<InstrumentOrderBook<Instrument, Updater>>>();
 
-        Ok(Self {
+        let mut transformer = Self {
             book_map,
+            snapshots,
+            ws_sink_tx,
+            resyncs: HashMap::new(),
+            audit: None,
+            audits: HashMap::new(),
+            subscribed,
+            pool,
+            spawner: options.spawner.clone(),
             phantom: PhantomData,
-        })
+        };
+
+        // Schedule OrderBook integrity audits if enabled
+        if let Some(config) = options.audit.filter(|_| Updater::AUDIT) {
+            transformer.schedule_audits(config);
+        }
+
+        Ok(transformer)
+    }
+
+    fn initial_events(&mut self) -> Vec<Result<MarketEvent<Instrument, Kind::Event>, DataError>> {
+        self.snapshots
+            .drain(..)
+            .map(|(instrument, book)| {
+                Ok(Self::market_event(instrument, book, Kind::Event::snapshot))
+            })
+            .collect()
     }
 }
 
-impl<Exchange, InstrumentId, Kind, Updater> Transformer
-    for MultiBookTransformer<Exchange, InstrumentId, Kind, Updater>
+impl<Exchange, Kind, Updater> MultiBookTransformer<Exchange, Instrument, Kind, Updater>
 where
-    Exchange: Connector,
-    InstrumentId: Clone,
-    Kind: SubscriptionKind<Event = OrderBook>,
-    Updater: OrderBookUpdater<OrderBook = Kind::Event>,
-    Updater::Update: Identifier<Option<SubscriptionId>> + for<'de> Deserialize<'de>,
+    Exchange: Connector + Send + 'static,
+    Kind: SubscriptionKind + Send + 'static,
+    Kind::Event: OrderBookOutput,
+    Updater: OrderBookUpdater<OrderBook = OrderBook> + Send + 'static,
+    Updater::Update: Identifier<Option<SubscriptionId>> + for<'de> Deserialize<'de> + Clone,
 {
-    type Error = DataError;
-    type Input = Updater::Update;
-    type Output = MarketEvent<InstrumentId, Kind::Event>;
-    type OutputIter = Vec<Result<Self::Output, Self::Error>>;
-
-    fn transform(&mut self, update: Self::Input) -> Self::OutputIter {
-        // Determine if the update has an identifiable SubscriptionId
-        let subscription_id = match update.id() {
-            Some(subscription_id) => subscription_id,
-            None => return vec![],
-        };
-
+    /// Apply the update to the [`InstrumentOrderBook`] associated with the [`SubscriptionId`],
+    /// starting a [`Resync`] if the [`OrderBookUpdater`] reports a sequence gap.
+    fn apply(
+        &mut self,
+        subscription_id: SubscriptionId,
+        update: Updater::Update,
+    ) -> Vec<Result<MarketEvent<Instrument, Kind::Event>, DataError>> {
         // Retrieve the InstrumentOrderBook associated with this update (snapshot or delta)
         let book = match self.book_map.find_mut(&subscription_id) {
             Ok(book) => book,
@@ -139,18 +375,507 @@ where
             updater,
         } = book;
 
-        // Apply update (snapshot or delta) to OrderBook & generate Market<OrderBook> snapshot
-        match updater.update(book, update) {
+        // Retain a copy of the update for any in-progress audit
+        let audit_update = matches!(
+            self.audits.get(&subscription_id),
+            Some(Audit::Fetching(_) | Audit::Syncing(_))
+        )
+        .then(|| update.clone());
+
+        // Apply update (snapshot or delta) to OrderBook, validate any exchange checksum, &
+        // generate Market<OrderBook> snapshot
+        match checksum::update(updater, book, update, &mut self.pool) {
             Ok(Some(book)) => {
-                MarketIter::<InstrumentId, OrderBook>::from((
-                    Exchange::ID,
-                    instrument.clone(),
-                    book,
-                ))
-                .0
+                let event = Self::market_event(instrument.clone(), book, Kind::Event::update);
+
+                std::iter::once(Ok(event))
+                    .chain(self.audit(&subscription_id, audit_update).map(Err))
+                    .collect()
             }
             Ok(None) => vec![],
+            Err(error @ DataError::InvalidSequence { .. }) if Updater::RESYNC_ON_GAP => {
+                let instrument = instrument.clone();
+                self.resync(subscription_id, instrument, error)
+            }
             Err(error) => vec![Err(error)],
         }
     }
+
+    /// Schedule an OrderBook integrity audit of every [`InstrumentOrderBook`] using the provided
+    /// [`AuditConfig`].
+    fn schedule_audits(&mut self, config: AuditConfig) {
+        let next_audit = Instant::now() + config.interval;
+
+        self.audits = self
+            .book_map
+            .0
+            .keys()
+            .map(|subscription_id| (subscription_id.clone(), Audit::Scheduled(next_audit)))
+            .collect();
+        self.audit = Some(config);
+    }
+
+    /// Progress the OrderBook integrity audit of the [`InstrumentOrderBook`] associated with the
+    /// [`SubscriptionId`], after the provided update was applied to the local [`OrderBook`].
+    ///
+    /// Returns a [`DataError::OrderBookDiscrepancy`] if the audit completed & found the local
+    /// [`OrderBook`] diverged from the exchange snapshot.
+    fn audit(
+        &mut self,
+        subscription_id: &SubscriptionId,
+        update: Option<Updater::Update>,
+    ) -> Option<DataError> {
+        let config = self.audit?;
+        let audit = self.audits.get_mut(subscription_id)?;
+        let rescheduled = || Audit::Scheduled(Instant::now() + config.interval);
+
+        // Start syncing the exchange snapshot once it has been fetched
+        if let Audit::Fetching(init_rx) = audit {
+            match init_rx.try_recv() {
+                Ok(Ok(snapshot)) => *audit = Audit::Syncing(snapshot),
+                Err(TryRecvError::Empty) => return None,
+                Ok(Err(error)) => {
+                    warn!(%subscription_id, ?error, "failed to fetch OrderBook audit snapshot");
+                    *audit = rescheduled();
+                    return None;
+                }
+                Err(TryRecvError::Closed) => {
+                    *audit = rescheduled();
+                    return None;
+                }
+            }
+        }
+
+        match audit {
+            Audit::Scheduled(next_audit) if Instant::now() >= *next_audit => {
+                let instrument = self.book_map.find(subscription_id).ok()?.instrument.clone();
+                let init_rx = Self::reinit(
+                    &self.spawner,
+                    self.ws_sink_tx.clone(),
+                    &self.subscribed,
+                    subscription_id,
+                    instrument,
+                );
+
+                *audit = Audit::Fetching(init_rx);
+                None
+            }
+            Audit::Syncing(snapshot) => {
+                match snapshot
+                    .updater
+                    .update(&mut snapshot.book, update?, &mut self.pool)
+                {
+                    // Update precedes the exchange snapshot, so keep syncing
+                    Ok(None) => None,
+                    // Exchange snapshot & local OrderBook reflect the same update, so diff them
+                    Ok(Some(snapshot)) => {
+                        *audit = rescheduled();
+                        let local = &self.book_map.find(subscription_id).ok()?.book;
+                        let discrepancy = audit::diff(local, &snapshot, config.depth);
+                        self.pool.recycle(snapshot);
+                        let discrepancy = discrepancy?;
+
+                        warn!(%subscription_id, %discrepancy, "OrderBook diverged from exchange");
+                        Some(DataError::OrderBookDiscrepancy {
+                            subscription_id: subscription_id.clone(),
+                            discrepancy,
+                        })
+                    }
+                    // Exchange snapshot precedes the local OrderBook, so audit is inconclusive
+                    Err(error) => {
+                        debug!(%subscription_id, ?error, "inconclusive OrderBook audit");
+                        *audit = rescheduled();
+                        None
+                    }
+                }
+            }
+            Audit::Scheduled(_) | Audit::Fetching(_) => None,
+        }
+    }
+
+    /// Re-initialise the [`InstrumentOrderBook`] associated with the [`SubscriptionId`] in the
+    /// background, yielding a non-terminal [`DataError::OrderBookResync`] notification.
+    fn resync(
+        &mut self,
+        subscription_id: SubscriptionId,
+        instrument: Instrument,
+        reason: DataError,
+    ) -> Vec<Result<MarketEvent<Instrument, Kind::Event>, DataError>> {
+        let init_rx = Self::reinit(
+            &self.spawner,
+            self.ws_sink_tx.clone(),
+            &self.subscribed,
+            &subscription_id,
+            instrument,
+        );
+
+        self.resyncs.insert(
+            subscription_id.clone(),
+            Resync {
+                init_rx,
+                buffered: Vec::new(),
+            },
+        );
+
+        vec![Err(DataError::OrderBookResync {
+            subscription_id,
+            reason: reason.to_string(),
+        })]
+    }
+
+    /// Re-initialise & configure the [`InstrumentOrderBook`] associated with the [`SubscriptionId`]
+    /// in the background, returning a [`oneshot::Receiver`] for the outcome.
+    fn reinit(
+        spawner: &Spawner,
+        ws_sink_tx: mpsc::UnboundedSender<WsMessage>,
+        subscribed: &HashMap<SubscriptionId, SubscriptionId>,
+        subscription_id: &SubscriptionId,
+        instrument: Instrument,
+    ) -> oneshot::Receiver<Result<InstrumentOrderBook<Instrument, Updater>, DataError>> {
+        let (init_tx, init_rx) = oneshot::channel();
+        let sub_id = subscribed
+            .get(subscription_id)
+            .unwrap_or(subscription_id)
+            .clone();
+
+        spawner.spawn(async move {
+            let book = Updater::init::<Exchange, Kind>(ws_sink_tx, instrument)
+                .await
+                .map(|mut book| {
+                    book.updater.configure(sub_id);
+                    book
+                });
+            let _ = init_tx.send(book);
+        });
+
+        init_rx
+    }
+
+    /// Replace the [`InstrumentOrderBook`] associated with the [`SubscriptionId`] with the
+    /// re-initialised one, yielding the recovery snapshot followed by the buffered updates.
+    fn resynchronised(
+        &mut self,
+        subscription_id: SubscriptionId,
+        mut book: InstrumentOrderBook<Instrument, Updater>,
+        buffered: Vec<Updater::Update>,
+    ) -> Vec<Result<MarketEvent<Instrument, Kind::Event>, DataError>> {
+        let recovery = Self::market_event(
+            book.instrument.clone(),
+            book.book.snapshot_in(&mut self.pool),
+            Kind::Event::snapshot,
+        );
+
+        if let Some(replaced) = self.book_map.0.insert(subscription_id, book) {
+            self.pool.recycle(replaced.book);
+        }
+
+        std::iter::once(Ok(recovery))
+            .chain(
+                buffered
+                    .into_iter()
+                    .flat_map(|update| self.transform(update))
+                    .collect::<Vec<_>>(),
+            )
+            .collect()
+    }
+}
+
+impl<Exchange, Kind, Updater> Transformer
+    for MultiBookTransformer<Exchange, Instrument, Kind, Updater>
+where
+    Exchange: Connector + Send + 'static,
+    Kind: SubscriptionKind + Send + 'static,
+    Kind::Event: OrderBookOutput,
+    Updater: OrderBookUpdater<OrderBook = OrderBook> + Send + 'static,
+    Updater::Update: Identifier<Option<SubscriptionId>> + for<'de> Deserialize<'de> + Clone,
+{
+    type Error = DataError;
+    type Input = Updater::Update;
+    type Output = MarketEvent<Instrument, Kind::Event>;
+    type OutputIter = Vec<Result<Self::Output, Self::Error>>;
+
+    fn transform(&mut self, update: Self::Input) -> Self::OutputIter {
+        // Determine if the update has an identifiable SubscriptionId
+        let subscription_id = match update.id() {
+            Some(subscription_id) => subscription_id,
+            None => return vec![],
+        };
+
+        // Buffer updates whilst the associated InstrumentOrderBook is being re-initialised
+        let Some(resync) = self.resyncs.get_mut(&subscription_id) else {
+            return self.apply(subscription_id, update);
+        };
+
+        match resync.init_rx.try_recv() {
+            Err(TryRecvError::Empty) => {
+                resync.buffered.push(update);
+                vec![]
+            }
+            Ok(Ok(book)) => {
+                let mut buffered = self
+                    .resyncs
+                    .remove(&subscription_id)
+                    .map(|resync| resync.buffered)
+                    .unwrap_or_default();
+                buffered.push(update);
+                self.resynchronised(subscription_id, book, buffered)
+            }
+            Ok(Err(error)) => {
+                self.resyncs.remove(&subscription_id);
+                vec![Err(error)]
+            }
+            Err(TryRecvError::Closed) => {
+                self.resyncs.remove(&subscription_id);
+                vec![Err(DataError::BookDesynchronised(format!(
+                    "OrderBook re-initialisation for {subscription_id} ended unexpectedly"
+                )))]
+            }
+        }
+    }
+}
+
+impl<Exchange, InstrumentId, Kind, Updater> Recycle
+    for MultiBookTransformer<Exchange, InstrumentId, Kind, Updater>
+where
+    Updater: OrderBookUpdater,
+{
+    fn recycle(&mut self, book: OrderBook) {
+        self.pool.recycle(book);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        exchange::coinbase::Coinbase,
+        subscription::book::{Level, OrderBookSide, OrderBooksL2, OrderBooksL2Events},
+    };
+    use barter_integration::model::{instrument::kind::InstrumentKind, Side};
+
+    /// Test update containing a sequence number & the bid [`Level`] to upsert.
+    #[derive(Clone, Deserialize)]
+    struct TestUpdate {
+        sequence: u64,
+        bid: Level,
+    }
+
+    impl Identifier<Option<SubscriptionId>> for TestUpdate {
+        fn id(&self) -> Option<SubscriptionId> {
+            Some(SubscriptionId::from("test"))
+        }
+    }
+
+    /// Test updater that initialises from a snapshot at sequence 10.
+    #[derive(Debug)]
+    struct TestUpdater {
+        last_sequence: u64,
+    }
+
+    #[async_trait]
+    impl OrderBookUpdater for TestUpdater {
+        type OrderBook = OrderBook;
+        type Update = TestUpdate;
+        const RESYNC_ON_GAP: bool = true;
+        const AUDIT: bool = true;
+
+        async fn init<Exchange, Kind>(
+            _: mpsc::UnboundedSender<WsMessage>,
+            instrument: Instrument,
+        ) -> Result<InstrumentOrderBook<Instrument, Self>, DataError>
+        where
+            Exchange: Send,
+            Kind: Send,
+        {
+            Ok(InstrumentOrderBook {
+                instrument,
+                updater: Self { last_sequence: 10 },
+                book: OrderBook {
+                    last_update_time: Default::default(),
+                    bids: OrderBookSide::new(Side::Buy, vec![Level::new(100.0, 1.0)]),
+                    asks: OrderBookSide::new(Side::Sell, Vec::<Level>::new()),
+                },
+            })
+        }
+
+        fn update(
+            &mut self,
+            book: &mut Self::OrderBook,
+            update: Self::Update,
+            pool: &mut LevelPool,
+        ) -> Result<Option<Self::OrderBook>, DataError> {
+            if update.sequence <= self.last_sequence {
+                return Ok(None);
+            }
+            if update.sequence != self.last_sequence + 1 {
+                return Err(DataError::InvalidSequence {
+                    prev_last_update_id: self.last_sequence,
+                    first_update_id: update.sequence,
+                });
+            }
+            self.last_sequence = update.sequence;
+            book.bids.upsert_single(update.bid);
+            Ok(Some(book.snapshot_in(pool)))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_resync_on_gap() {
+        let (ws_sink_tx, _ws_sink_rx) = mpsc::unbounded_channel();
+        let instrument = Instrument::from(("btc", "usd", InstrumentKind::Spot));
+        let mut transformer =
+            MultiBookTransformer::<Coinbase, Instrument, OrderBooksL2, TestUpdater>::new(
+                ws_sink_tx,
+                Map::from_iter([(SubscriptionId::from("test"), instrument)]),
+                &StreamOptions::default(),
+            )
+            .await
+            .unwrap();
+
+        let update = |sequence, price| TestUpdate {
+            sequence,
+            bid: Level::new(price, 1.0),
+        };
+
+        // Contiguous update is applied
+        assert!(matches!(
+            transformer.transform(update(11, 99.0)).as_slice(),
+            [Ok(_)]
+        ));
+
+        // Gap triggers a non-terminal resync notification
+        let actual = transformer.transform(update(13, 98.0));
+        assert!(matches!(
+            actual.as_slice(),
+            [Err(error @ DataError::OrderBookResync { .. })] if !error.is_terminal()
+        ));
+
+        // Allow the background re-initialisation to complete on the current thread runtime
+        tokio::task::yield_now().await;
+
+        // Recovery snapshot is emitted, followed by the contiguous update
+        let actual = transformer.transform(update(11, 97.0));
+        match actual.as_slice() {
+            [Ok(recovery), Ok(event)] => {
+                assert_eq!(recovery.kind.bids.levels(), &[Level::new(100.0, 1.0)]);
+                assert_eq!(
+                    event.kind.bids.levels(),
+                    &[Level::new(100.0, 1.0), Level::new(97.0, 1.0)]
+                );
+            }
+            actual => panic!("unexpected output: {actual:?}"),
+        }
+        assert!(transformer.resyncs.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_order_books_l2_events() {
+        let (ws_sink_tx, _ws_sink_rx) = mpsc::unbounded_channel();
+        let instrument = Instrument::from(("btc", "usd", InstrumentKind::Spot));
+        let mut transformer =
+            MultiBookTransformer::<Coinbase, Instrument, OrderBooksL2Events, TestUpdater>::new(
+                ws_sink_tx,
+                Map::from_iter([(SubscriptionId::from("test"), instrument)]),
+                &StreamOptions::default(),
+            )
+            .await
+            .unwrap();
+
+        // Initial OrderBook snapshot is emitted before any update
+        match transformer.initial_events().as_slice() {
+            [Ok(event)] => assert!(matches!(
+                &event.kind,
+                OrderBookEvent::Snapshot(book) if book.bids.levels() == [Level::new(100.0, 1.0)]
+            )),
+            actual => panic!("unexpected output: {actual:?}"),
+        }
+        assert!(transformer.initial_events().is_empty());
+
+        // Subsequent updates are emitted as deltas applied to the snapshot
+        match transformer
+            .transform(TestUpdate {
+                sequence: 11,
+                bid: Level::new(99.0, 1.0),
+            })
+            .as_slice()
+        {
+            [Ok(event)] => assert!(matches!(
+                &event.kind,
+                OrderBookEvent::Update(book)
+                    if book.bids.levels() == [Level::new(100.0, 1.0), Level::new(99.0, 1.0)]
+            )),
+            actual => panic!("unexpected output: {actual:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_audit_discrepancy() {
+        let (ws_sink_tx, _ws_sink_rx) = mpsc::unbounded_channel();
+        let instrument = Instrument::from(("btc", "usd", InstrumentKind::Spot));
+        let subscription_id = SubscriptionId::from("test");
+        let mut transformer =
+            MultiBookTransformer::<Coinbase, Instrument, OrderBooksL2, TestUpdater>::new(
+                ws_sink_tx,
+                Map::from_iter([(subscription_id.clone(), instrument.clone())]),
+                &StreamOptions {
+                    audit: Some(AuditConfig::default()),
+                    ..Default::default()
+                },
+            )
+            .await
+            .unwrap();
+
+        let update = |sequence, price| TestUpdate {
+            sequence,
+            bid: Level::new(price, 1.0),
+        };
+
+        // Local OrderBook silently drifts from the exchange
+        transformer
+            .book_map
+            .find_mut(&subscription_id)
+            .unwrap()
+            .book
+            .bids
+            .upsert_single(Level::new(50.0, 1.0));
+
+        // Exchange snapshot at sequence 11 is fetched for the audit
+        let (init_tx, init_rx) = oneshot::channel();
+        init_tx
+            .send(Ok(InstrumentOrderBook {
+                instrument,
+                updater: TestUpdater { last_sequence: 11 },
+                book: OrderBook {
+                    last_update_time: Default::default(),
+                    bids: OrderBookSide::new(
+                        Side::Buy,
+                        vec![Level::new(100.0, 1.0), Level::new(99.0, 1.0)],
+                    ),
+                    asks: OrderBookSide::new(Side::Sell, Vec::<Level>::new()),
+                },
+            }))
+            .unwrap_or_else(|_| panic!("audit receiver dropped"));
+        transformer
+            .audits
+            .insert(subscription_id.clone(), Audit::Fetching(init_rx));
+
+        // Update preceding the exchange snapshot is applied locally, but does not complete audit
+        assert!(matches!(
+            transformer.transform(update(11, 99.0)).as_slice(),
+            [Ok(_)]
+        ));
+
+        // Update applied to both OrderBooks completes the audit & reports the drift
+        match transformer.transform(update(12, 98.0)).as_slice() {
+            [Ok(_), Err(error @ DataError::OrderBookDiscrepancy { discrepancy, .. })] => {
+                assert!(!error.is_terminal());
+                assert!(discrepancy.contains("price: 50.0"), "{discrepancy}");
+            }
+            actual => panic!("unexpected output: {actual:?}"),
+        }
+        assert!(matches!(
+            transformer.audits.get(&subscription_id),
+            Some(Audit::Scheduled(_))
+        ));
+    }
 }
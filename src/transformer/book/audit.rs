@@ -0,0 +1,140 @@
+use crate::subscription::book::{Level, OrderBook};
+use serde::{Deserialize, Serialize};
+use std::{fmt::Write, time::Duration};
+
+/// Configures the opt-in [`MultiBookTransformer`](super::MultiBookTransformer) integrity audit,
+/// which periodically fetches a fresh exchange OrderBook snapshot and diffs it against the
+/// locally maintained [`OrderBook`].
+///
+/// Enabled per [`StreamBuilder`](crate::streams::builder::StreamBuilder) via
+/// [`with_audit_config`](crate::streams::builder::StreamBuilder::with_audit_config), and only
+/// applies to [`OrderBookUpdater`](super::OrderBookUpdater)s that opt in via
+/// [`OrderBookUpdater::AUDIT`](super::OrderBookUpdater::AUDIT).
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct AuditConfig {
+    /// Interval between audits of each [`OrderBook`].
+    pub interval: Duration,
+    /// Number of [`Level`]s compared on each side of the [`OrderBook`].
+    pub depth: usize,
+}
+
+impl Default for AuditConfig {
+    fn default() -> Self {
+        Self {
+            interval: Duration::from_secs(60),
+            depth: 20,
+        }
+    }
+}
+
+/// Diff the best `depth` [`Level`]s of the local [`OrderBook`] against the exchange snapshot
+/// [`OrderBook`], returning a description of every discrepancy found (if any).
+pub fn diff(local: &OrderBook, snapshot: &OrderBook, depth: usize) -> Option<String> {
+    let mut discrepancies = String::new();
+    diff_side(
+        "bid",
+        local.bids.levels(),
+        snapshot.bids.levels(),
+        depth,
+        &mut discrepancies,
+    );
+    diff_side(
+        "ask",
+        local.asks.levels(),
+        snapshot.asks.levels(),
+        depth,
+        &mut discrepancies,
+    );
+
+    (!discrepancies.is_empty()).then_some(discrepancies)
+}
+
+/// Append a description of every differing [`Level`] within the best `depth` to the provided
+/// `discrepancies`.
+fn diff_side(
+    side: &str,
+    local: &[Level],
+    snapshot: &[Level],
+    depth: usize,
+    discrepancies: &mut String,
+) {
+    let len = local.len().max(snapshot.len()).min(depth);
+
+    for index in 0..len {
+        let (local, snapshot) = (local.get(index), snapshot.get(index));
+        if local != snapshot {
+            if !discrepancies.is_empty() {
+                discrepancies.push_str(", ");
+            }
+            let _ = write!(
+                discrepancies,
+                "{side} level {index}: local {local:?} != exchange {snapshot:?}"
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::subscription::book::OrderBookSide;
+    use barter_integration::model::Side;
+    use chrono::Utc;
+
+    fn book(bids: Vec<Level>, asks: Vec<Level>) -> OrderBook {
+        OrderBook {
+            last_update_time: Utc::now(),
+            bids: OrderBookSide::new(Side::Buy, bids),
+            asks: OrderBookSide::new(Side::Sell, asks),
+        }
+    }
+
+    #[test]
+    fn test_diff() {
+        struct TestCase {
+            local: OrderBook,
+            snapshot: OrderBook,
+            depth: usize,
+            expected: Option<&'static str>,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: identical books have no discrepancies
+                local: book(vec![Level::new(100.0, 1.0)], vec![Level::new(101.0, 1.0)]),
+                snapshot: book(vec![Level::new(100.0, 1.0)], vec![Level::new(101.0, 1.0)]),
+                depth: 20,
+                expected: None,
+            },
+            TestCase {
+                // TC1: differing amount & missing level are reported
+                local: book(vec![Level::new(100.0, 1.0)], vec![Level::new(101.0, 1.0)]),
+                snapshot: book(
+                    vec![Level::new(100.0, 2.0)],
+                    vec![Level::new(101.0, 1.0), Level::new(102.0, 1.0)],
+                ),
+                depth: 20,
+                expected: Some(
+                    "bid level 0: local Some(Level { price: 100.0, amount: 1.0 }) != exchange \
+                    Some(Level { price: 100.0, amount: 2.0 }), ask level 1: local None != \
+                    exchange Some(Level { price: 102.0, amount: 1.0 })",
+                ),
+            },
+            TestCase {
+                // TC2: discrepancies beyond the audited depth are ignored
+                local: book(
+                    vec![Level::new(100.0, 1.0), Level::new(99.0, 1.0)],
+                    vec![Level::new(101.0, 1.0)],
+                ),
+                snapshot: book(vec![Level::new(100.0, 1.0)], vec![Level::new(101.0, 1.0)]),
+                depth: 1,
+                expected: None,
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = diff(&test.local, &test.snapshot, test.depth);
+            assert_eq!(actual.as_deref(), test.expected, "TC{} failed", index);
+        }
+    }
+}
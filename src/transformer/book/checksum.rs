@@ -0,0 +1,342 @@
+use super::OrderBookUpdater;
+use crate::{
+    error::DataError,
+    exchange::{
+        bitfinex::book::l2::BOOK_L2_CHECKSUM_DEPTH_BITFINEX,
+        kraken::book::l2::BOOK_L2_CHECKSUM_DEPTH_KRAKEN, okx::book::l2::BOOK_L2_CHECKSUM_DEPTH_OKX,
+    },
+    subscription::book::{pool::LevelPool, Level, OrderBook},
+};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Number of [`Level`](crate::subscription::book::Level)s on each side of the Bitget OrderBook used to calculate the CRC32
+/// checksum.
+///
+/// See docs: <https://www.bitget.com/api-doc/spot/websocket/public/Depth-Channel>
+pub const BOOK_L2_CHECKSUM_DEPTH_BITGET: usize = 25;
+
+/// Calculates the checksum of a sorted [`OrderBook`], allowing the
+/// [`MultiBookTransformer`](super::MultiBookTransformer) to validate the local [`OrderBook`]
+/// against the checksum provided by the exchange.
+///
+/// See [`OrderBookUpdater::book_checksum`] for how an exchange opts in.
+pub trait BookChecksum {
+    /// Calculate the checksum of the provided sorted [`OrderBook`].
+    fn checksum(&self, book: &OrderBook) -> u32;
+}
+
+/// [`Okx`](crate::exchange::okx::Okx) CRC32 [`BookChecksum`].
+///
+/// The top 25 bids & asks are alternately arranged as "bid:amount:ask:amount" `String`s joined by
+/// ":". If one side has fewer levels, the remaining levels of the other side are appended.
+///
+/// The checksum input must use the exact price & amount `String`s sent by the exchange (eg/
+/// "3366.10"), which cannot be reconstructed from an `f64`. Therefore, the raw `String`s of each
+/// level are retained via [`Self::upsert`], falling back to formatting the `f64` if absent.
+///
+/// See docs: <https://www.okx.com/docs-v5/en/#order-book-trading-market-data-ws-order-book-channel>
+#[derive(Clone, Eq, PartialEq, Debug, Default, Deserialize, Serialize)]
+pub struct OkxChecksum {
+    /// Raw (price, amount) `String`s of each bid level, keyed by the price bits.
+    bids: HashMap<u64, (String, String)>,
+    /// Raw (price, amount) `String`s of each ask level, keyed by the price bits.
+    asks: HashMap<u64, (String, String)>,
+}
+
+impl OkxChecksum {
+    /// Remove the raw `String`s of every level (eg/ before applying a snapshot).
+    pub fn clear(&mut self) {
+        self.bids.clear();
+        self.asks.clear();
+    }
+
+    /// Upsert the raw price & amount `String`s of a bid (`is_bid`) or ask level, removing the
+    /// level if the `amount` is zero.
+    pub fn upsert(&mut self, is_bid: bool, price: f64, amount: f64, raw: (String, String)) {
+        let levels = if is_bid {
+            &mut self.bids
+        } else {
+            &mut self.asks
+        };
+
+        if amount == 0.0 {
+            levels.remove(&price.to_bits());
+        } else {
+            levels.insert(price.to_bits(), raw);
+        }
+    }
+}
+
+impl BookChecksum for OkxChecksum {
+    fn checksum(&self, book: &OrderBook) -> u32 {
+        let format = |raw: &HashMap<u64, (String, String)>, level: &Level| {
+            raw.get(&level.price.to_bits()).map_or_else(
+                || format!("{}:{}", level.price, level.amount),
+                |(price, amount)| format!("{price}:{amount}"),
+            )
+        };
+
+        let bids = book.bids.levels();
+        let asks = book.asks.levels();
+
+        let input = (0..BOOK_L2_CHECKSUM_DEPTH_OKX)
+            .flat_map(|index| {
+                [
+                    bids.get(index).map(|bid| format(&self.bids, bid)),
+                    asks.get(index).map(|ask| format(&self.asks, ask)),
+                ]
+            })
+            .flatten()
+            .collect::<Vec<_>>()
+            .join(":");
+
+        crc32fast::hash(input.as_bytes())
+    }
+}
+
+/// Bitget CRC32 [`BookChecksum`].
+///
+/// Identical to the [`OkxChecksum`]: the top 25 bids & asks are alternately arranged as
+/// "bid:amount:ask:amount" `String`s joined by ":".
+///
+/// See docs: <https://www.bitget.com/api-doc/spot/websocket/public/Depth-Channel>
+#[derive(
+    Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default, Deserialize, Serialize,
+)]
+pub struct BitgetChecksum;
+
+impl BookChecksum for BitgetChecksum {
+    fn checksum(&self, book: &OrderBook) -> u32 {
+        interleaved_crc32(book, BOOK_L2_CHECKSUM_DEPTH_BITGET, false)
+    }
+}
+
+/// [`Bitfinex`](crate::exchange::bitfinex::Bitfinex) CRC32 [`BookChecksum`].
+///
+/// The top 25 bids & asks are alternately arranged as "bid:amount:ask:-amount" `String`s joined
+/// by ":", where ask amounts are negative. If one side has fewer levels, the remaining levels of
+/// the other side are appended.
+///
+/// See docs: <https://docs.bitfinex.com/docs/ws-websocket-checksum>
+#[derive(
+    Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default, Deserialize, Serialize,
+)]
+pub struct BitfinexChecksum;
+
+impl BookChecksum for BitfinexChecksum {
+    fn checksum(&self, book: &OrderBook) -> u32 {
+        interleaved_crc32(book, BOOK_L2_CHECKSUM_DEPTH_BITFINEX, true)
+    }
+}
+
+/// [`Kraken`](crate::exchange::kraken::Kraken) CRC32 [`BookChecksum`].
+///
+/// For each of the top 10 asks (ascending) followed by the top 10 bids (descending), the price &
+/// amount `String`s are appended with the decimal point & leading zeros removed. The number of
+/// decimals is taken from the initial snapshot.
+///
+/// See docs: <https://docs.kraken.com/websockets/#book-checksum>
+#[derive(
+    Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default, Deserialize, Serialize,
+)]
+pub struct KrakenChecksum {
+    pub price_decimals: usize,
+    pub amount_decimals: usize,
+}
+
+impl BookChecksum for KrakenChecksum {
+    fn checksum(&self, book: &OrderBook) -> u32 {
+        let mut hasher = crc32fast::Hasher::new();
+
+        book.asks
+            .levels()
+            .iter()
+            .take(BOOK_L2_CHECKSUM_DEPTH_KRAKEN)
+            .chain(
+                book.bids
+                    .levels()
+                    .iter()
+                    .take(BOOK_L2_CHECKSUM_DEPTH_KRAKEN),
+            )
+            .for_each(|level| {
+                hasher.update(kraken_checksum_str(level.price, self.price_decimals).as_bytes());
+                hasher.update(kraken_checksum_str(level.amount, self.amount_decimals).as_bytes());
+            });
+
+        hasher.finalize()
+    }
+}
+
+/// Apply the [`OrderBookUpdater::Update`] to the provided [`OrderBook`], then validate it against
+/// the [`OrderBookUpdater::expected_checksum`] of the update, if any.
+///
+/// Any snapshot yielded by the [`OrderBookUpdater`] is allocated from the provided [`LevelPool`].
+///
+/// The [`OrderBook`] is sorted before its checksum is calculated via the
+/// [`OrderBookUpdater::book_checksum`]. On mismatch, the [`DataError`] returned by
+/// [`OrderBookUpdater::checksum_mismatch`] is propagated.
+pub fn update<Updater>(
+    updater: &mut Updater,
+    book: &mut OrderBook,
+    update: Updater::Update,
+    pool: &mut LevelPool,
+) -> Result<Option<OrderBook>, DataError>
+where
+    Updater: OrderBookUpdater<OrderBook = OrderBook>,
+{
+    let expected = updater.expected_checksum(&update);
+    let snapshot = updater.update(book, update, pool)?;
+
+    if let Some(expected) = expected {
+        validate(updater, book, expected)?;
+    }
+
+    Ok(snapshot)
+}
+
+/// Validate the provided [`OrderBook`] against the `expected` exchange checksum.
+fn validate<Updater>(
+    updater: &mut Updater,
+    book: &mut OrderBook,
+    expected: u32,
+) -> Result<(), DataError>
+where
+    Updater: OrderBookUpdater<OrderBook = OrderBook>,
+{
+    let Some(checksum) = updater.book_checksum() else {
+        return Ok(());
+    };
+
+    book.bids.sort();
+    book.asks.sort();
+
+    match checksum.checksum(book) {
+        actual if actual == expected => Ok(()),
+        actual => Err(updater.checksum_mismatch(expected, actual)),
+    }
+}
+
+/// Calculate the CRC32 checksum of the top `depth` bids & asks alternately arranged as
+/// "price:amount" `String`s joined by ":", optionally negating the ask amounts.
+fn interleaved_crc32(book: &OrderBook, depth: usize, negate_asks: bool) -> u32 {
+    let bids = book.bids.levels();
+    let asks = book.asks.levels();
+    let ask_sign = if negate_asks { -1.0 } else { 1.0 };
+
+    let input = (0..depth)
+        .flat_map(|index| {
+            [
+                bids.get(index)
+                    .map(|bid| format!("{}:{}", bid.price, bid.amount)),
+                asks.get(index)
+                    .map(|ask| format!("{}:{}", ask.price, ask_sign * ask.amount)),
+            ]
+        })
+        .flatten()
+        .collect::<Vec<_>>()
+        .join(":");
+
+    crc32fast::hash(input.as_bytes())
+}
+
+/// Format a price or amount as a [`Kraken`](crate::exchange::kraken::Kraken) checksum input
+/// `String`, with the decimal point and leading zeros removed (eg/ 0.0500 -> "500").
+fn kraken_checksum_str(value: f64, decimals: usize) -> String {
+    format!("{value:.decimals$}")
+        .replace('.', "")
+        .trim_start_matches('0')
+        .to_owned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::subscription::book::OrderBookSide;
+    use barter_integration::model::Side;
+    use chrono::Utc;
+
+    fn book(bids: Vec<(f64, f64)>, asks: Vec<(f64, f64)>) -> OrderBook {
+        OrderBook {
+            last_update_time: Utc::now(),
+            bids: OrderBookSide::new(Side::Buy, bids.into_iter().map(Level::from)),
+            asks: OrderBookSide::new(Side::Sell, asks.into_iter().map(Level::from)),
+        }
+    }
+
+    #[test]
+    fn test_kraken_checksum_str() {
+        assert_eq!(kraken_checksum_str(5541.3, 5), "554130000");
+        assert_eq!(kraken_checksum_str(0.05005, 5), "5005");
+        assert_eq!(kraken_checksum_str(2.507, 8), "250700000");
+        assert_eq!(kraken_checksum_str(0.0, 8), "");
+    }
+
+    #[test]
+    fn test_checksum() {
+        struct TestCase {
+            checksum: Box<dyn BookChecksum>,
+            book: OrderBook,
+            expected: u32,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: Okx interleaves bids & asks, appending the longer side
+                // crc32("3366.1:7:3366.8:9:3366:6:3368:8:3372:1")
+                checksum: Box::new(OkxChecksum::default()),
+                book: book(
+                    vec![(3366.1, 7.0), (3366.0, 6.0)],
+                    vec![(3366.8, 9.0), (3368.0, 8.0), (3372.0, 1.0)],
+                ),
+                expected: 686728965,
+            },
+            TestCase {
+                // TC1: Bitget uses the same algorithm as Okx
+                checksum: Box::new(BitgetChecksum),
+                book: book(
+                    vec![(3366.1, 7.0), (3366.0, 6.0)],
+                    vec![(3366.8, 9.0), (3368.0, 8.0), (3372.0, 1.0)],
+                ),
+                expected: 686728965,
+            },
+            TestCase {
+                // TC2: Bitfinex negates ask amounts
+                // crc32("7254.7:3.5:7254.8:-0.5") as i32
+                checksum: Box::new(BitfinexChecksum),
+                book: book(vec![(7254.7, 3.5)], vec![(7254.8, 0.5)]),
+                expected: 23593583,
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = test.checksum.checksum(&test.book);
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
+
+    #[test]
+    fn test_okx_checksum_raw_strings() {
+        let book = book(vec![(3366.1, 7.0)], vec![(3366.8, 9.0), (3368.0, 8.0)]);
+
+        let mut checksum = OkxChecksum::default();
+        checksum.upsert(true, 3366.1, 7.0, ("3366.10".to_owned(), "7.0".to_owned()));
+        checksum.upsert(false, 3366.8, 9.0, ("3366.8".to_owned(), "9e0".to_owned()));
+
+        // Raw Strings are used where retained, falling back to formatting the f64
+        assert_eq!(
+            checksum.checksum(&book),
+            crc32fast::hash(b"3366.10:7.0:3366.8:9e0:3368:8")
+        );
+
+        // Zero amount levels are removed
+        checksum.upsert(true, 3366.1, 0.0, ("3366.10".to_owned(), "0".to_owned()));
+        assert_eq!(
+            checksum.checksum(&book),
+            crc32fast::hash(b"3366.1:7:3366.8:9e0:3368:8")
+        );
+
+        checksum.clear();
+        assert_eq!(checksum, OkxChecksum::default());
+    }
+}
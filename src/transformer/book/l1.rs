@@ -0,0 +1,219 @@
+use crate::{
+    error::DataError,
+    event::MarketEvent,
+    streams::options::StreamOptions,
+    subscription::{
+        book::{pool::Recycle, Level, OrderBook, OrderBookL1, OrderBooksL1, OrderBooksL2},
+        Map,
+    },
+    transformer::ExchangeTransformer,
+};
+use async_trait::async_trait;
+use barter_integration::{protocol::websocket::WsMessage, Transformer};
+use std::{collections::HashMap, hash::Hash, marker::PhantomData};
+use tokio::sync::mpsc;
+
+/// Generic [`ExchangeTransformer`] adapter that derives [`OrderBookL1`] events from an inner
+/// [`OrderBooksL2`] [`ExchangeTransformer`] (eg/ [`MultiBookTransformer`](super::MultiBookTransformer)).
+///
+/// Used to provide [`OrderBooksL1`] support for exchanges that lack a native bookTicker-style
+/// channel. An [`OrderBookL1`] is only emitted once both sides of the [`OrderBook`] are known,
+/// and thereafter only when the best bid or ask [`Level`] changes.
+#[derive(Debug)]
+pub struct OrderBookL1Transformer<Exchange, InstrumentId, Inner> {
+    inner: Inner,
+    best: HashMap<InstrumentId, (Level, Level)>,
+    phantom: PhantomData<Exchange>,
+}
+
+#[async_trait]
+impl<Exchange, InstrumentId, Inner> ExchangeTransformer<Exchange, InstrumentId, OrderBooksL1>
+    for OrderBookL1Transformer<Exchange, InstrumentId, Inner>
+where
+    Exchange: Send,
+    InstrumentId: Clone + Eq + Hash + Send,
+    Inner: ExchangeTransformer<Exchange, InstrumentId, OrderBooksL2> + Recycle + Send,
+{
+    async fn new(
+        ws_sink_tx: mpsc::UnboundedSender<WsMessage>,
+        instrument_map: Map<InstrumentId>,
+        options: &StreamOptions,
+    ) -> Result<Self, DataError> {
+        Ok(Self {
+            inner: Inner::new(ws_sink_tx, instrument_map, options).await?,
+            best: HashMap::new(),
+            phantom: PhantomData,
+        })
+    }
+
+    fn initial_events(&mut self) -> Vec<Result<MarketEvent<InstrumentId, OrderBookL1>, DataError>> {
+        let events = self.inner.initial_events();
+        self.derive(events)
+    }
+}
+
+impl<Exchange, InstrumentId, Inner> Transformer
+    for OrderBookL1Transformer<Exchange, InstrumentId, Inner>
+where
+    InstrumentId: Clone + Eq + Hash,
+    Inner: Transformer<Output = MarketEvent<InstrumentId, OrderBook>, Error = DataError> + Recycle,
+{
+    type Error = DataError;
+    type Input = Inner::Input;
+    type Output = MarketEvent<InstrumentId, OrderBookL1>;
+    type OutputIter = Vec<Result<Self::Output, Self::Error>>;
+
+    fn transform(&mut self, input: Self::Input) -> Self::OutputIter {
+        let events = self.inner.transform(input);
+        self.derive(events)
+    }
+}
+
+impl<Exchange, InstrumentId, Inner> OrderBookL1Transformer<Exchange, InstrumentId, Inner>
+where
+    InstrumentId: Clone + Eq + Hash,
+    Inner: Recycle,
+{
+    /// Derive [`OrderBookL1`] events from the inner [`OrderBook`] events, dropping any that do
+    /// not change the best bid or ask [`Level`].
+    fn derive<Iter>(
+        &mut self,
+        events: Iter,
+    ) -> Vec<Result<MarketEvent<InstrumentId, OrderBookL1>, DataError>>
+    where
+        Iter: IntoIterator<Item = Result<MarketEvent<InstrumentId, OrderBook>, DataError>>,
+    {
+        events
+            .into_iter()
+            .filter_map(|event| match event {
+                Ok(event) => self.top_of_book(event).map(Ok),
+                Err(error) => Some(Err(error)),
+            })
+            .collect()
+    }
+
+    /// Construct an [`OrderBookL1`] event from the [`OrderBook`] event if both sides are known and
+    /// the best bid or ask [`Level`] changed since the last emission for the instrument.
+    fn top_of_book(
+        &mut self,
+        event: MarketEvent<InstrumentId, OrderBook>,
+    ) -> Option<MarketEvent<InstrumentId, OrderBookL1>> {
+        // Recycle the OrderBook Levels once the top of book has been read
+        let last_update_time = event.kind.last_update_time;
+        let top_of_book = (
+            event.kind.bids.levels().first().copied(),
+            event.kind.asks.levels().first().copied(),
+        );
+        self.inner.recycle(event.kind);
+        let (Some(best_bid), Some(best_ask)) = top_of_book else {
+            return None;
+        };
+
+        if self.best.get(&event.instrument) == Some(&(best_bid, best_ask)) {
+            return None;
+        }
+        self.best
+            .insert(event.instrument.clone(), (best_bid, best_ask));
+
+        Some(MarketEvent {
+            exchange_time: event.exchange_time,
+            received_time: event.received_time,
+            exchange: event.exchange,
+            instrument: event.instrument,
+            kind: OrderBookL1 {
+                last_update_time,
+                best_bid,
+                best_ask,
+            },
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::subscription::book::OrderBookSide;
+    use barter_integration::model::{Exchange, Side};
+    use chrono::Utc;
+
+    /// Inner [`Transformer`] that yields the input [`OrderBook`] for instrument "btc_usdt".
+    struct TestTransformer;
+
+    impl Transformer for TestTransformer {
+        type Error = DataError;
+        type Input = OrderBook;
+        type Output = MarketEvent<&'static str, OrderBook>;
+        type OutputIter = Vec<Result<Self::Output, Self::Error>>;
+
+        fn transform(&mut self, input: Self::Input) -> Self::OutputIter {
+            vec![Ok(MarketEvent {
+                exchange_time: input.last_update_time,
+                received_time: Utc::now(),
+                exchange: Exchange::from("test"),
+                instrument: "btc_usdt",
+                kind: input,
+            })]
+        }
+    }
+
+    impl Recycle for TestTransformer {
+        fn recycle(&mut self, _: OrderBook) {}
+    }
+
+    fn book(bids: Vec<(f64, f64)>, asks: Vec<(f64, f64)>) -> OrderBook {
+        OrderBook {
+            last_update_time: Utc::now(),
+            bids: OrderBookSide::new(Side::Buy, bids),
+            asks: OrderBookSide::new(Side::Sell, asks),
+        }
+    }
+
+    #[test]
+    fn test_transform() {
+        struct TestCase {
+            input: OrderBook,
+            expected: Option<(Level, Level)>,
+        }
+
+        let mut transformer = OrderBookL1Transformer::<(), _, _> {
+            inner: TestTransformer,
+            best: HashMap::new(),
+            phantom: PhantomData,
+        };
+
+        let tests = vec![
+            TestCase {
+                // TC0: one sided OrderBook is not emitted
+                input: book(vec![(100.0, 1.0)], vec![]),
+                expected: None,
+            },
+            TestCase {
+                // TC1: first two sided OrderBook is emitted
+                input: book(vec![(100.0, 1.0), (99.0, 1.0)], vec![(101.0, 1.0)]),
+                expected: Some((Level::new(100.0, 1.0), Level::new(101.0, 1.0))),
+            },
+            TestCase {
+                // TC2: change beyond the top of book is not emitted
+                input: book(vec![(100.0, 1.0), (99.0, 5.0)], vec![(101.0, 1.0)]),
+                expected: None,
+            },
+            TestCase {
+                // TC3: change in best ask amount is emitted
+                input: book(vec![(100.0, 1.0)], vec![(101.0, 2.0)]),
+                expected: Some((Level::new(100.0, 1.0), Level::new(101.0, 2.0))),
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = transformer
+                .transform(test.input)
+                .into_iter()
+                .map(|event| {
+                    let kind = event.unwrap().kind;
+                    (kind.best_bid, kind.best_ask)
+                })
+                .next();
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
+}
@@ -0,0 +1,229 @@
+use super::{checksum, InstrumentOrderBook, OrderBookUpdater};
+use crate::{
+    error::DataError,
+    subscription::book::{
+        pool::{LevelPool, Recycle},
+        OrderBook,
+    },
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Recorded exchange specific [`OrderBookUpdater::Update`] (snapshot or delta), alongside the
+/// time it was received.
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct RecordedUpdate<Update> {
+    pub time: DateTime<Utc>,
+    pub update: Update,
+}
+
+impl<Update> RecordedUpdate<Update> {
+    /// Construct a new [`Self`].
+    pub fn new(time: DateTime<Utc>, update: Update) -> Self {
+        Self { time, update }
+    }
+}
+
+/// Reconstructs [`OrderBook`] state at any point in time from a recorded sequence of
+/// [`RecordedUpdate`]s.
+///
+/// Updates are applied using the same [`OrderBookUpdater`] implementations as live mode,
+/// including exchange checksum validation, starting from the recorded initial
+/// [`InstrumentOrderBook`] (ie/ the output of [`OrderBookUpdater::init`]).
+///
+/// eg/ `BookReplay::new(initial, updates).at(time)?`
+#[derive(Clone, Debug)]
+pub struct BookReplay<InstrumentId, Updater>
+where
+    Updater: OrderBookUpdater,
+{
+    initial: InstrumentOrderBook<InstrumentId, Updater>,
+    updates: Vec<RecordedUpdate<Updater::Update>>,
+}
+
+impl<InstrumentId, Updater> BookReplay<InstrumentId, Updater>
+where
+    InstrumentId: Clone,
+    Updater: OrderBookUpdater<OrderBook = OrderBook> + Clone,
+    Updater::Update: Clone,
+{
+    /// Construct a new [`Self`] from the initial [`InstrumentOrderBook`] and the subsequently
+    /// recorded updates, which are (stably) sorted by time.
+    pub fn new<Iter>(initial: InstrumentOrderBook<InstrumentId, Updater>, updates: Iter) -> Self
+    where
+        Iter: IntoIterator<Item = RecordedUpdate<Updater::Update>>,
+    {
+        let mut updates = updates.into_iter().collect::<Vec<_>>();
+        updates.sort_by_key(|recorded| recorded.time);
+
+        Self { initial, updates }
+    }
+
+    /// Recorded updates, sorted by time.
+    pub fn updates(&self) -> &[RecordedUpdate<Updater::Update>] {
+        &self.updates
+    }
+
+    /// Reconstruct the [`OrderBook`] after every update recorded at or before the provided time.
+    pub fn at(&self, time: DateTime<Utc>) -> Result<OrderBook, DataError> {
+        let mut cursor = self.cursor();
+        cursor.advance_to(time)?;
+        Ok(cursor.book().clone())
+    }
+
+    /// Construct a [`ReplayCursor`] positioned at the initial [`InstrumentOrderBook`], used to
+    /// efficiently step forward through time.
+    pub fn cursor(&self) -> ReplayCursor<'_, InstrumentId, Updater> {
+        ReplayCursor {
+            state: self.initial.clone(),
+            updates: &self.updates,
+            next: 0,
+            // Retains both OrderBookSide buffers of each discarded snapshot for the next
+            pool: LevelPool::new(2),
+        }
+    }
+}
+
+/// Forward-only position within a [`BookReplay`], maintaining the reconstructed
+/// [`InstrumentOrderBook`].
+#[derive(Debug)]
+pub struct ReplayCursor<'a, InstrumentId, Updater>
+where
+    Updater: OrderBookUpdater,
+{
+    state: InstrumentOrderBook<InstrumentId, Updater>,
+    updates: &'a [RecordedUpdate<Updater::Update>],
+    next: usize,
+    pool: LevelPool,
+}
+
+impl<InstrumentId, Updater> ReplayCursor<'_, InstrumentId, Updater>
+where
+    Updater: OrderBookUpdater<OrderBook = OrderBook>,
+    Updater::Update: Clone,
+{
+    /// Apply every remaining update recorded at or before the provided time, returning the
+    /// reconstructed [`OrderBook`].
+    ///
+    /// If an update fails to apply (eg/ checksum mismatch), the [`DataError`] is returned and the
+    /// cursor is positioned after the failed update.
+    pub fn advance_to(&mut self, time: DateTime<Utc>) -> Result<&OrderBook, DataError> {
+        while let Some(recorded) = self.updates.get(self.next) {
+            if recorded.time > time {
+                break;
+            }
+
+            self.next += 1;
+            let InstrumentOrderBook { updater, book, .. } = &mut self.state;
+            if let Some(snapshot) =
+                checksum::update(updater, book, recorded.update.clone(), &mut self.pool)?
+            {
+                self.pool.recycle(snapshot);
+            }
+        }
+
+        Ok(&self.state.book)
+    }
+
+    /// Reconstructed [`OrderBook`] at the current position.
+    pub fn book(&self) -> &OrderBook {
+        &self.state.book
+    }
+
+    /// Time of the last applied update, if any have been applied.
+    pub fn time(&self) -> Option<DateTime<Utc>> {
+        self.next
+            .checked_sub(1)
+            .and_then(|last| self.updates.get(last))
+            .map(|recorded| recorded.time)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        exchange::bitfinex::book::l2::{
+            BitfinexBookUpdater, BitfinexLevel, BitfinexOrderBookL2, BitfinexOrderBookL2Payload,
+        },
+        subscription::book::{Level, OrderBookSide},
+    };
+    use barter_integration::model::Side;
+    use chrono::Duration;
+
+    fn recorded(
+        time: DateTime<Utc>,
+        payload: BitfinexOrderBookL2Payload,
+    ) -> RecordedUpdate<BitfinexOrderBookL2> {
+        RecordedUpdate::new(
+            time,
+            BitfinexOrderBookL2 {
+                channel_id: 17082,
+                payload,
+            },
+        )
+    }
+
+    fn level(price: f64, count: u64, amount: f64) -> BitfinexLevel {
+        BitfinexLevel {
+            price,
+            count,
+            amount,
+        }
+    }
+
+    #[test]
+    fn test_book_replay() {
+        let start = Utc::now();
+        let at = |seconds| start + Duration::seconds(seconds);
+
+        let initial = InstrumentOrderBook {
+            instrument: "btc_usd",
+            updater: BitfinexBookUpdater::default(),
+            book: OrderBook {
+                last_update_time: start,
+                bids: OrderBookSide::new(Side::Buy, Vec::<Level>::new()),
+                asks: OrderBookSide::new(Side::Sell, Vec::<Level>::new()),
+            },
+        };
+
+        // Recorded out of order, so must be sorted by time
+        let replay = BookReplay::new(
+            initial,
+            vec![
+                recorded(
+                    at(2),
+                    BitfinexOrderBookL2Payload::Update(level(7254.7, 4, 3.5)),
+                ),
+                recorded(
+                    at(1),
+                    BitfinexOrderBookL2Payload::Snapshot(vec![
+                        level(7254.7, 3, 3.3),
+                        level(7254.8, 1, -0.5),
+                    ]),
+                ),
+                // Valid checksum: crc32("7254.7:3.5:7254.8:-0.5") as i32
+                recorded(at(3), BitfinexOrderBookL2Payload::Checksum(23593583)),
+                recorded(at(4), BitfinexOrderBookL2Payload::Checksum(1)),
+            ],
+        );
+
+        // Before any updates, the initial OrderBook is returned
+        assert!(replay.at(at(0)).unwrap().bids.levels().is_empty());
+
+        // State is reconstructed at any point in time
+        let actual = replay.at(at(1)).unwrap();
+        assert_eq!(actual.bids.levels(), &[Level::new(7254.7, 3.3)]);
+        let actual = replay.at(at(2)).unwrap();
+        assert_eq!(actual.bids.levels(), &[Level::new(7254.7, 3.5)]);
+
+        // Cursor steps forward through time, validating checksums as in live mode
+        let mut cursor = replay.cursor();
+        assert!(cursor.advance_to(at(3)).is_ok());
+        assert_eq!(cursor.time(), Some(at(3)));
+        assert!(matches!(
+            cursor.advance_to(at(4)),
+            Err(DataError::BookDesynchronised(_))
+        ));
+    }
+}
@@ -0,0 +1,183 @@
+use crate::error::DataError;
+use async_trait::async_trait;
+use barter_integration::{error::SocketError, model::instrument::Instrument};
+use serde::de::DeserializeOwned;
+use std::{
+    collections::HashMap,
+    sync::{Mutex, OnceLock},
+    time::Duration,
+};
+use tokio::time::Instant;
+use tracing::warn;
+
+/// Defines how to fetch the initial OrderBook snapshot for an [`Instrument`] via HTTP, used by
+/// [`OrderBookUpdater::init`](super::OrderBookUpdater::init) implementations.
+///
+/// Timeouts, retries and rate limiting are handled by the default [`Self::fetch`]
+/// implementation, configured via [`Self::CONFIG`].
+#[async_trait]
+pub trait SnapshotFetcher {
+    /// Exchange specific HTTP OrderBook snapshot response.
+    type Snapshot: DeserializeOwned + Send;
+
+    /// [`SnapshotConfig`] used when fetching a [`Self::Snapshot`].
+    const CONFIG: SnapshotConfig = SnapshotConfig::DEFAULT;
+
+    /// HTTP GET url of the OrderBook snapshot for the provided [`Instrument`].
+    fn url(instrument: &Instrument) -> String;
+
+    /// Fetch the [`Self::Snapshot`] for the provided [`Instrument`].
+    async fn fetch(instrument: &Instrument) -> Result<Self::Snapshot, DataError> {
+        fetch_snapshot(Self::url(instrument), Self::CONFIG).await
+    }
+}
+
+/// Configures the timeout, retry & rate limiting behaviour of a [`SnapshotFetcher`].
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct SnapshotConfig {
+    /// Timeout applied to each HTTP request.
+    pub timeout: Duration,
+    /// Maximum number of times a failed HTTP request is retried.
+    pub max_retries: u32,
+    /// Backoff before the first retry, doubled for every subsequent retry.
+    pub retry_backoff: Duration,
+    /// Minimum interval between HTTP requests sent to the same host.
+    pub min_request_interval: Duration,
+}
+
+impl SnapshotConfig {
+    /// Default [`SnapshotConfig`].
+    pub const DEFAULT: Self = Self {
+        timeout: Duration::from_secs(10),
+        max_retries: 3,
+        retry_backoff: Duration::from_millis(250),
+        min_request_interval: Duration::ZERO,
+    };
+}
+
+impl Default for SnapshotConfig {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+/// Fetch & deserialise the OrderBook snapshot located at the provided `url`, respecting the
+/// provided [`SnapshotConfig`].
+pub async fn fetch_snapshot<Snapshot>(
+    url: String,
+    config: SnapshotConfig,
+) -> Result<Snapshot, DataError>
+where
+    Snapshot: DeserializeOwned,
+{
+    let host = reqwest::Url::parse(&url)
+        .map_err(SocketError::UrlParse)?
+        .host_str()
+        .unwrap_or_default()
+        .to_owned();
+
+    let mut backoff = config.retry_backoff;
+    let mut attempt = 0;
+
+    loop {
+        // Wait for this host's rate limit to allow another request
+        let scheduled = reserve(&host, config.min_request_interval, Instant::now());
+        tokio::time::sleep_until(scheduled).await;
+
+        match request(&url, config.timeout).await {
+            Ok(snapshot) => break Ok(snapshot),
+            Err(error) if attempt < config.max_retries => {
+                warn!(
+                    %url,
+                    attempt,
+                    ?error,
+                    ?backoff,
+                    "failed to fetch OrderBook snapshot, retrying after backoff"
+                );
+                tokio::time::sleep(backoff).await;
+                backoff *= 2;
+                attempt += 1;
+            }
+            Err(error) => break Err(DataError::from(SocketError::Http(error))),
+        }
+    }
+}
+
+/// Send a single HTTP GET request to the provided `url`, deserialising the response as a
+/// `Snapshot`.
+async fn request<Snapshot>(url: &str, timeout: Duration) -> Result<Snapshot, reqwest::Error>
+where
+    Snapshot: DeserializeOwned,
+{
+    static CLIENT: OnceLock<reqwest::Client> = OnceLock::new();
+
+    CLIENT
+        .get_or_init(reqwest::Client::new)
+        .get(url)
+        .timeout(timeout)
+        .send()
+        .await?
+        .error_for_status()?
+        .json::<Snapshot>()
+        .await
+}
+
+/// Reserve the next available request slot for the provided `host`, returning the [`Instant`]
+/// the request may be sent.
+fn reserve(host: &str, min_request_interval: Duration, now: Instant) -> Instant {
+    static NEXT_REQUEST: OnceLock<Mutex<HashMap<String, Instant>>> = OnceLock::new();
+
+    let mut next_request = NEXT_REQUEST
+        .get_or_init(Default::default)
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+
+    reserve_slot(&mut next_request, host, min_request_interval, now)
+}
+
+/// Determine the [`Instant`] a request to the `host` may be sent, and record when the following
+/// request may be sent.
+fn reserve_slot(
+    next_request: &mut HashMap<String, Instant>,
+    host: &str,
+    min_request_interval: Duration,
+    now: Instant,
+) -> Instant {
+    let scheduled = next_request.get(host).map_or(now, |next| (*next).max(now));
+
+    next_request.insert(host.to_owned(), scheduled + min_request_interval);
+
+    scheduled
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_reserve_slot() {
+        let interval = Duration::from_millis(100);
+        let now = Instant::now();
+        let mut next_request = HashMap::new();
+
+        // First request to a host is sent immediately
+        assert_eq!(reserve_slot(&mut next_request, "a", interval, now), now);
+
+        // Subsequent requests to the same host are spaced by the interval
+        assert_eq!(
+            reserve_slot(&mut next_request, "a", interval, now),
+            now + interval
+        );
+        assert_eq!(
+            reserve_slot(&mut next_request, "a", interval, now),
+            now + interval * 2
+        );
+
+        // Requests to a different host are not delayed
+        assert_eq!(reserve_slot(&mut next_request, "b", interval, now), now);
+
+        // Requests after the reserved slots have elapsed are sent immediately
+        let later = now + interval * 10;
+        assert_eq!(reserve_slot(&mut next_request, "a", interval, later), later);
+    }
+}
@@ -0,0 +1,215 @@
+use super::{
+    book::{MultiBookTransformer, OrderBookUpdater},
+    stateless::StatelessTransformer,
+    ExchangeTransformer,
+};
+use crate::{
+    error::DataError,
+    event::{DataKind, MarketEvent, MarketIter},
+    exchange::{subscription::ExchangeSub, Connector, ExchangeId},
+    streams::options::StreamOptions,
+    subscription::{book::OrderBook, book::OrderBooksL2, DataKinds, Map, SubKind, Subscription},
+    Identifier,
+};
+use async_trait::async_trait;
+use barter_integration::{
+    model::{instrument::Instrument, SubscriptionId},
+    protocol::websocket::WsMessage,
+    Transformer,
+};
+use serde::{Deserialize, Serialize};
+use std::fmt::Debug;
+use tokio::sync::mpsc;
+
+/// Exchange message received over a [`DataKinds`] connection, which is either an OrderBook
+/// [`OrderBookUpdater::Update`], or any other message handled by a [`StatelessTransformer`].
+///
+/// ### Notes
+/// The OrderBook variant is attempted first, since stateless messages (eg/ trades) lack the
+/// fields of an OrderBook update.
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+#[serde(untagged)]
+pub enum DataKindsMessage<Book, Other> {
+    OrderBook(Book),
+    Other(Other),
+}
+
+/// Generic [`DataKinds`] [`ExchangeTransformer`] that allows [`SubKind::OrderBooksL2`]
+/// [`Subscription`]s to share a connection with stateless [`SubKind`]s (eg/ trades & OrderBook
+/// Level 1).
+///
+/// OrderBook updates are routed to an inner [`MultiBookTransformer`], which maintains the
+/// [`OrderBook`] of each [`SubKind::OrderBooksL2`] [`Subscription`]. All other messages are
+/// routed to an inner [`StatelessTransformer`].
+pub struct DataKindsTransformer<Exchange, Input, Updater>
+where
+    Updater: OrderBookUpdater,
+{
+    stateless: StatelessTransformer<Exchange, Instrument, DataKinds, Input>,
+    books: MultiBookTransformer<Exchange, Instrument, OrderBooksL2, Updater>,
+}
+
+impl<Exchange, Input, Updater> Debug for DataKindsTransformer<Exchange, Input, Updater>
+where
+    Exchange: Debug,
+    Input: Debug,
+    Updater: OrderBookUpdater + Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DataKindsTransformer")
+            .field("stateless", &self.stateless)
+            .field("books", &self.books)
+            .finish()
+    }
+}
+
+#[async_trait]
+impl<Exchange, Input, Updater> ExchangeTransformer<Exchange, Instrument, DataKinds>
+    for DataKindsTransformer<Exchange, Input, Updater>
+where
+    Exchange: Connector + Send + 'static,
+    Subscription<Exchange, Instrument, DataKinds>:
+        Identifier<Exchange::Channel> + Identifier<Exchange::Market>,
+    Input: Identifier<Option<SubscriptionId>> + for<'de> Deserialize<'de> + Send,
+    MarketIter<Instrument, DataKind>: From<(ExchangeId, Instrument, Input)>,
+    Updater: OrderBookUpdater<OrderBook = OrderBook> + Send + 'static,
+    Updater::Update: Identifier<Option<SubscriptionId>> + for<'de> Deserialize<'de> + Clone,
+{
+    async fn new(
+        ws_sink_tx: mpsc::UnboundedSender<WsMessage>,
+        instrument_map: Map<Instrument>,
+        options: &StreamOptions,
+    ) -> Result<Self, DataError> {
+        // Partition the SubscriptionIds into OrderBooksL2 & stateless Subscriptions
+        let (books, stateless): (Vec<_>, Vec<_>) =
+            instrument_map
+                .0
+                .into_iter()
+                .partition(|(subscription_id, instrument)| {
+                    is_order_book_l2::<Exchange>(subscription_id, instrument)
+                });
+
+        Ok(Self {
+            stateless: StatelessTransformer::new(
+                ws_sink_tx.clone(),
+                Map::from_iter(stateless),
+                options,
+            )
+            .await?,
+            books: MultiBookTransformer::new(ws_sink_tx, Map::from_iter(books), options).await?,
+        })
+    }
+
+    fn initial_events(&mut self) -> Vec<Result<MarketEvent<Instrument, DataKind>, DataError>> {
+        into_data_kinds(self.books.initial_events())
+    }
+}
+
+impl<Exchange, Input, Updater> Transformer for DataKindsTransformer<Exchange, Input, Updater>
+where
+    Exchange: Connector + Send + 'static,
+    Input: Identifier<Option<SubscriptionId>> + for<'de> Deserialize<'de>,
+    MarketIter<Instrument, DataKind>: From<(ExchangeId, Instrument, Input)>,
+    Updater: OrderBookUpdater<OrderBook = OrderBook> + Send + 'static,
+    Updater::Update: Identifier<Option<SubscriptionId>> + for<'de> Deserialize<'de> + Clone,
+{
+    type Error = DataError;
+    type Input = DataKindsMessage<Updater::Update, Input>;
+    type Output = MarketEvent<Instrument, DataKind>;
+    type OutputIter = Vec<Result<Self::Output, Self::Error>>;
+
+    fn transform(&mut self, input: Self::Input) -> Self::OutputIter {
+        match input {
+            DataKindsMessage::OrderBook(update) => into_data_kinds(self.books.transform(update)),
+            DataKindsMessage::Other(message) => self.stateless.transform(message),
+        }
+    }
+}
+
+/// Determine if the provided [`SubscriptionId`] identifies the [`SubKind::OrderBooksL2`]
+/// [`DataKinds`] [`Subscription`] of the associated [`Instrument`].
+fn is_order_book_l2<Exchange>(subscription_id: &SubscriptionId, instrument: &Instrument) -> bool
+where
+    Exchange: Connector,
+    Subscription<Exchange, Instrument, DataKinds>:
+        Identifier<Exchange::Channel> + Identifier<Exchange::Market>,
+{
+    let subscription = Subscription::<_, Instrument, _>::new(
+        Exchange::default(),
+        instrument.clone(),
+        DataKinds(SubKind::OrderBooksL2),
+    );
+
+    ExchangeSub::<Exchange::Channel, Exchange::Market>::new(&subscription).id() == *subscription_id
+}
+
+/// Map the provided [`OrderBook`] events into [`DataKind`] events.
+fn into_data_kinds(
+    events: Vec<Result<MarketEvent<Instrument, OrderBook>, DataError>>,
+) -> Vec<Result<MarketEvent<Instrument, DataKind>, DataError>> {
+    events
+        .into_iter()
+        .map(|event| event.map(MarketEvent::from))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::exchange::binance::{
+        kinds::BinanceDataKind, spot::l2::BinanceSpotOrderBookL2Delta, spot::BinanceSpot,
+    };
+    use barter_integration::model::instrument::kind::InstrumentKind;
+
+    #[test]
+    fn test_is_order_book_l2() {
+        let instrument = Instrument::from(("btc", "usdt", InstrumentKind::Spot));
+
+        assert!(is_order_book_l2::<BinanceSpot>(
+            &SubscriptionId::from("@depth@100ms|BTCUSDT"),
+            &instrument
+        ));
+        assert!(!is_order_book_l2::<BinanceSpot>(
+            &SubscriptionId::from("@trade|BTCUSDT"),
+            &instrument
+        ));
+    }
+
+    #[test]
+    fn test_de_data_kinds_message() {
+        type Message = DataKindsMessage<BinanceSpotOrderBookL2Delta, BinanceDataKind>;
+
+        let input = r#"
+        {
+            "e":"depthUpdate","E":1671656397761,"s":"ETHUSDT","U":22611425143,"u":22611425151,
+            "b":[["1209.67000000","85.48210000"]],"a":[]
+        }
+        "#;
+        assert!(matches!(
+            serde_json::from_str::<Message>(input).unwrap(),
+            DataKindsMessage::OrderBook(_)
+        ));
+
+        let input = r#"
+        {
+            "e":"trade","E":1649324825173,"s":"ETHUSDT","t":1000000000,"p":"10000.19",
+            "q":"0.239000","b":10108767791,"a":10108764858,"T":1749354825200,"m":false,"M":true
+        }
+        "#;
+        assert!(matches!(
+            serde_json::from_str::<Message>(input).unwrap(),
+            DataKindsMessage::Other(BinanceDataKind::Trade(_))
+        ));
+
+        let input = r#"
+        {
+            "u":22606535573,"s":"ETHUSDT","b":"1215.27000000","B":"32.49110000",
+            "a":"1215.28000000","A":"13.93900000"
+        }
+        "#;
+        assert!(matches!(
+            serde_json::from_str::<Message>(input).unwrap(),
+            DataKindsMessage::Other(BinanceDataKind::OrderBookL1(_))
+        ));
+    }
+}
@@ -1,6 +1,7 @@
 use crate::{
     error::DataError,
     event::MarketEvent,
+    streams::options::StreamOptions,
     subscription::{Map, SubscriptionKind},
 };
 use async_trait::async_trait;
@@ -10,10 +11,18 @@ use tokio::sync::mpsc;
 /// Generic OrderBook [`ExchangeTransformer`]s.
 pub mod book;
 
+/// Generic [`DataKinds`](crate::subscription::DataKinds) [`ExchangeTransformer`] that allows
+/// OrderBook Level 2 subscriptions to share a connection with stateless subscriptions.
+pub mod kinds;
+
 /// Generic stateless [`ExchangeTransformer`] often used for transforming
 /// [`PublicTrades`](crate::subscription::trade::PublicTrades) streams.
 pub mod stateless;
 
+/// Generic [`ExchangeTransformer`] that fans out venue-wide exchange system messages as
+/// [`ExchangeStatus`](crate::subscription::status::ExchangeStatus) events.
+pub mod status;
+
 /// Defines how to construct a [`Transformer`] used by [`MarketStream`](super::MarketStream)s to
 /// translate exchange specific types to normalised Barter types.
 #[async_trait]
@@ -25,8 +34,20 @@ where
     /// Construct a new [`Self`].
     ///
     /// The [`mpsc::UnboundedSender`] can be used by [`Self`] to send messages back to the exchange.
+    /// The [`StreamOptions`] configure any opt-in behaviour (eg/ OrderBook integrity audits).
     async fn new(
         ws_sink_tx: mpsc::UnboundedSender<WsMessage>,
         instrument_map: Map<InstrumentId>,
+        options: &StreamOptions,
     ) -> Result<Self, DataError>;
+
+    /// Drain any events generated whilst constructing [`Self`] (eg/ initial [`OrderBook`]
+    /// snapshots), which are yielded before any events transformed from exchange messages.
+    ///
+    /// Defaults to no initial events.
+    ///
+    /// [`OrderBook`]: crate::subscription::book::OrderBook
+    fn initial_events(&mut self) -> Vec<Result<MarketEvent<InstrumentId, Kind::Event>, DataError>> {
+        Vec::new()
+    }
 }
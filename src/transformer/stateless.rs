@@ -3,6 +3,7 @@ use crate::{
     error::DataError,
     event::{MarketEvent, MarketIter},
     exchange::{Connector, ExchangeId},
+    streams::options::StreamOptions,
     subscription::{Map, SubscriptionKind},
     Identifier,
 };
@@ -35,6 +36,7 @@ where
     async fn new(
         _: mpsc::UnboundedSender<WsMessage>,
         instrument_map: Map<InstrumentId>,
+        _: &StreamOptions,
     ) -> Result<Self, DataError> {
         Ok(Self {
             instrument_map,
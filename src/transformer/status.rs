@@ -0,0 +1,100 @@
+use super::ExchangeTransformer;
+use crate::{
+    error::DataError,
+    event::{MarketEvent, MarketIter},
+    exchange::{Connector, ExchangeId},
+    streams::options::StreamOptions,
+    subscription::{
+        status::{ExchangeStatus, SystemState, SystemStatus},
+        Map,
+    },
+};
+use async_trait::async_trait;
+use barter_integration::{protocol::websocket::WsMessage, Transformer};
+use chrono::Utc;
+use serde::Deserialize;
+use std::marker::PhantomData;
+use tokio::sync::mpsc;
+
+/// Generic [`ExchangeTransformer`] that translates venue-wide exchange system messages into
+/// normalised [`SystemStatus`] events for every instrument with an active [`ExchangeStatus`]
+/// subscription.
+///
+/// ### Notes
+/// Since the exchange [`Subscription`](crate::Subscription)s were validated successfully, an
+/// initial [`SystemState::Online`] [`SystemStatus`] is emitted for every instrument upon
+/// connecting.
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct StatusTransformer<Exchange, InstrumentId, Input> {
+    instruments: Vec<InstrumentId>,
+    phantom: PhantomData<(Exchange, Input)>,
+}
+
+#[async_trait]
+impl<Exchange, InstrumentId, Input> ExchangeTransformer<Exchange, InstrumentId, ExchangeStatus>
+    for StatusTransformer<Exchange, InstrumentId, Input>
+where
+    Exchange: Connector + Send,
+    InstrumentId: Clone + Send,
+    Input: Clone + for<'de> Deserialize<'de> + Send,
+    MarketIter<InstrumentId, SystemStatus>: From<(ExchangeId, InstrumentId, Input)>,
+{
+    async fn new(
+        _: mpsc::UnboundedSender<WsMessage>,
+        instrument_map: Map<InstrumentId>,
+        _: &StreamOptions,
+    ) -> Result<Self, DataError> {
+        // Each instrument has exactly one ExchangeStatus SubscriptionId
+        let instruments = instrument_map.0.into_values().collect();
+
+        Ok(Self {
+            instruments,
+            phantom: PhantomData,
+        })
+    }
+
+    fn initial_events(
+        &mut self,
+    ) -> Vec<Result<MarketEvent<InstrumentId, SystemStatus>, DataError>> {
+        let time = Utc::now();
+        self.instruments
+            .iter()
+            .map(|instrument| {
+                Ok(MarketEvent {
+                    exchange_time: time,
+                    received_time: time,
+                    exchange: Exchange::ID.into(),
+                    instrument: instrument.clone(),
+                    kind: SystemStatus::new(SystemState::Online),
+                })
+            })
+            .collect()
+    }
+}
+
+impl<Exchange, InstrumentId, Input> Transformer for StatusTransformer<Exchange, InstrumentId, Input>
+where
+    Exchange: Connector,
+    InstrumentId: Clone,
+    Input: Clone + for<'de> Deserialize<'de>,
+    MarketIter<InstrumentId, SystemStatus>: From<(ExchangeId, InstrumentId, Input)>,
+{
+    type Error = DataError;
+    type Input = Input;
+    type Output = MarketEvent<InstrumentId, SystemStatus>;
+    type OutputIter = Vec<Result<Self::Output, Self::Error>>;
+
+    fn transform(&mut self, input: Self::Input) -> Self::OutputIter {
+        self.instruments
+            .iter()
+            .flat_map(|instrument| {
+                MarketIter::<InstrumentId, SystemStatus>::from((
+                    Exchange::ID,
+                    instrument.clone(),
+                    input.clone(),
+                ))
+                .0
+            })
+            .collect()
+    }
+}
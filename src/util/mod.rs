@@ -0,0 +1,3 @@
+/// Exchange epoch timestamp normalisation utilities (eg/ fractional seconds, float millis,
+/// nanoseconds), shared by every exchange [`Connector`](crate::exchange::Connector).
+pub mod time;
@@ -0,0 +1,271 @@
+use barter_integration::de::datetime_utc_from_epoch_duration;
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+use std::time::Duration;
+
+/// Unit of an exchange epoch timestamp.
+///
+/// Exchanges are inconsistent in the precision of the timestamps they provide, eg/
+/// - Kraken: seconds with a fractional part (eg/ "1534614057.321597").
+/// - Gate.io: float milliseconds as a string (eg/ "1606292218213.4578").
+/// - KuCoin: nanoseconds with trailing zeros (eg/ 1545896669145000000).
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub enum EpochUnit {
+    Seconds,
+    Millis,
+    Micros,
+    Nanos,
+}
+
+impl EpochUnit {
+    /// Number of decimal places required to express this [`EpochUnit`] in nanoseconds.
+    const fn nanos_exponent(&self) -> u32 {
+        match self {
+            Self::Seconds => 9,
+            Self::Millis => 6,
+            Self::Micros => 3,
+            Self::Nanos => 0,
+        }
+    }
+
+    /// Convert an integer epoch timestamp in this [`EpochUnit`] into a [`DateTime<Utc>`].
+    pub fn datetime(&self, epoch: u64) -> DateTime<Utc> {
+        datetime_utc_from_epoch_duration(match self {
+            Self::Seconds => Duration::from_secs(epoch),
+            Self::Millis => Duration::from_millis(epoch),
+            Self::Micros => Duration::from_micros(epoch),
+            Self::Nanos => Duration::from_nanos(epoch),
+        })
+    }
+
+    /// Parse a decimal epoch timestamp string in this [`EpochUnit`] (eg/ "1534614057.321597")
+    /// into a [`DateTime<Utc>`].
+    ///
+    /// The fractional part is parsed exactly rather than via an `f64`, so no precision is lost.
+    /// Any precision beyond nanoseconds is truncated.
+    pub fn parse(&self, epoch: &str) -> Result<DateTime<Utc>, String> {
+        let invalid = || format!("invalid {self:?} epoch timestamp: {epoch}");
+
+        let (integer, fraction) = epoch.split_once('.').unwrap_or((epoch, ""));
+        if integer.is_empty() || !fraction.bytes().all(|byte| byte.is_ascii_digit()) {
+            return Err(invalid());
+        }
+
+        let exponent = self.nanos_exponent();
+        let integer = integer
+            .parse::<u64>()
+            .ok()
+            .and_then(|integer| integer.checked_mul(10_u64.pow(exponent)))
+            .ok_or_else(invalid)?;
+
+        let fraction = fraction
+            .bytes()
+            .chain(std::iter::repeat(b'0'))
+            .take(exponent as usize)
+            .fold(0, |nanos, digit| nanos * 10 + u64::from(digit - b'0'));
+
+        integer
+            .checked_add(fraction)
+            .map(|nanos| datetime_utc_from_epoch_duration(Duration::from_nanos(nanos)))
+            .ok_or_else(invalid)
+    }
+}
+
+/// Deserialize a `u64` epoch seconds timestamp as a [`DateTime<Utc>`].
+pub fn de_u64_epoch_s_as_datetime_utc<'de, D>(deserializer: D) -> Result<DateTime<Utc>, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+{
+    u64::deserialize(deserializer).map(|epoch| EpochUnit::Seconds.datetime(epoch))
+}
+
+/// Deserialize a `u64` epoch milliseconds timestamp as a [`DateTime<Utc>`].
+pub fn de_u64_epoch_ms_as_datetime_utc<'de, D>(deserializer: D) -> Result<DateTime<Utc>, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+{
+    u64::deserialize(deserializer).map(|epoch| EpochUnit::Millis.datetime(epoch))
+}
+
+/// Deserialize a `u64` epoch nanoseconds timestamp as a [`DateTime<Utc>`].
+pub fn de_u64_epoch_ns_as_datetime_utc<'de, D>(deserializer: D) -> Result<DateTime<Utc>, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+{
+    u64::deserialize(deserializer).map(|epoch| EpochUnit::Nanos.datetime(epoch))
+}
+
+/// Deserialize an optional `u64` epoch milliseconds timestamp (eg/ 1612281600000) as an
+/// `Option<DateTime<Utc>>`.
+pub fn de_option_u64_epoch_ms_as_datetime_utc<'de, D>(
+    deserializer: D,
+) -> Result<Option<DateTime<Utc>>, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+{
+    <Option<u64>>::deserialize(deserializer)
+        .map(|epoch| epoch.map(|epoch| EpochUnit::Millis.datetime(epoch)))
+}
+
+/// Deserialize a decimal `String` epoch seconds timestamp (eg/ "1534614057.321597") as a
+/// [`DateTime<Utc>`].
+pub fn de_str_epoch_s_as_datetime_utc<'de, D>(deserializer: D) -> Result<DateTime<Utc>, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+{
+    de_str_epoch_as_datetime_utc(deserializer, EpochUnit::Seconds)
+}
+
+/// Deserialize a decimal `String` epoch milliseconds timestamp (eg/ "1606292218213.4578") as a
+/// [`DateTime<Utc>`].
+pub fn de_str_epoch_ms_as_datetime_utc<'de, D>(deserializer: D) -> Result<DateTime<Utc>, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+{
+    de_str_epoch_as_datetime_utc(deserializer, EpochUnit::Millis)
+}
+
+/// Deserialize an epoch milliseconds timestamp that may be sent as either a decimal `String`
+/// (eg/ "1583127900000") or a `u64` (eg/ 1583127900000) as a [`DateTime<Utc>`].
+pub fn de_str_or_u64_epoch_ms_as_datetime_utc<'de, D>(
+    deserializer: D,
+) -> Result<DateTime<Utc>, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Epoch<'a> {
+        Integer(u64),
+        Str(&'a str),
+    }
+
+    match Epoch::deserialize(deserializer)? {
+        Epoch::Integer(epoch) => Ok(EpochUnit::Millis.datetime(epoch)),
+        Epoch::Str(epoch) => EpochUnit::Millis
+            .parse(epoch)
+            .map_err(serde::de::Error::custom),
+    }
+}
+
+/// Deserialize an optional decimal `String` epoch milliseconds timestamp (eg/ "1673280000000") as
+/// an `Option<DateTime<Utc>>`, where an empty `String` (eg/ OKX) is also `None`.
+pub fn de_option_str_epoch_ms_as_datetime_utc<'de, D>(
+    deserializer: D,
+) -> Result<Option<DateTime<Utc>>, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+{
+    <Option<&str>>::deserialize(deserializer)?
+        .filter(|epoch| !epoch.is_empty())
+        .map(|epoch| {
+            EpochUnit::Millis
+                .parse(epoch)
+                .map_err(serde::de::Error::custom)
+        })
+        .transpose()
+}
+
+/// Deserialize a decimal `String` epoch timestamp in the provided [`EpochUnit`] as a
+/// [`DateTime<Utc>`].
+fn de_str_epoch_as_datetime_utc<'de, D>(
+    deserializer: D,
+    unit: EpochUnit,
+) -> Result<DateTime<Utc>, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+{
+    let epoch = <&str>::deserialize(deserializer)?;
+    unit.parse(epoch).map_err(serde::de::Error::custom)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_epoch_unit_parse() {
+        struct TestCase {
+            unit: EpochUnit,
+            input: &'static str,
+            expected: Result<Duration, ()>,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: Kraken seconds w/ microsecond fraction is parsed exactly
+                unit: EpochUnit::Seconds,
+                input: "1534614057.321597",
+                expected: Ok(Duration::new(1534614057, 321597000)),
+            },
+            TestCase {
+                // TC1: Gate.io float millis w/ sub-millisecond fraction
+                unit: EpochUnit::Millis,
+                input: "1606292218213.4578",
+                expected: Ok(Duration::new(1606292218, 213457800)),
+            },
+            TestCase {
+                // TC2: integer seconds w/o fraction
+                unit: EpochUnit::Seconds,
+                input: "1606292580",
+                expected: Ok(Duration::from_secs(1606292580)),
+            },
+            TestCase {
+                // TC3: KuCoin nanoseconds w/ trailing zeros
+                unit: EpochUnit::Nanos,
+                input: "1545896669145000000",
+                expected: Ok(Duration::from_millis(1545896669145)),
+            },
+            TestCase {
+                // TC4: precision beyond nanoseconds is truncated
+                unit: EpochUnit::Seconds,
+                input: "1.0000000019",
+                expected: Ok(Duration::new(1, 1)),
+            },
+            TestCase {
+                // TC5: invalid non-numeric fraction
+                unit: EpochUnit::Seconds,
+                input: "1534614057.32a",
+                expected: Err(()),
+            },
+            TestCase {
+                // TC6: invalid empty integer part
+                unit: EpochUnit::Millis,
+                input: ".5",
+                expected: Err(()),
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = test.unit.parse(test.input);
+            match (actual, test.expected) {
+                (Ok(actual), Ok(expected)) => {
+                    assert_eq!(
+                        actual,
+                        datetime_utc_from_epoch_duration(expected),
+                        "TC{index} failed"
+                    )
+                }
+                (Err(_), Err(_)) => {
+                    // Test passed
+                }
+                (actual, expected) => {
+                    // Test failed
+                    panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_epoch_unit_datetime() {
+        assert_eq!(
+            EpochUnit::Nanos.datetime(1545896669145000000),
+            EpochUnit::Millis.datetime(1545896669145)
+        );
+        assert_eq!(
+            EpochUnit::Seconds.datetime(1545896669),
+            EpochUnit::Micros.datetime(1545896669000000)
+        );
+    }
+}
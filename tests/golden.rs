@@ -0,0 +1,353 @@
+//! Integration tests that drive full [`MarketStream`](barter_data::MarketStream) pipelines
+//! (subscriber -> validator -> transformer -> stream) against a mock exchange server replaying
+//! recorded golden sessions from `tests/golden/<session>.json`.
+//!
+//! Each golden session contains:
+//! - `requests`: subscription payloads the exchange expects to receive (a value of `"<any>"`
+//!   matches any value, eg/ for timestamps).
+//! - `frames`: exchange messages replayed in order once the subscription requests are received.
+//! - `expected`: exact normalised [`MarketEvent`] output sequence, excluding the non-deterministic
+//!   `received_time`.
+//!
+//! Run with `GOLDEN_BLESS=1` to (re)record the `expected` output of every golden session from the
+//! current normalised output.
+
+use barter_data::{
+    event::MarketEvent,
+    exchange::{
+        binance::spot::BinanceSpot, bitfinex::Bitfinex, bitmex::Bitmex, bybit::spot::BybitSpot,
+        coinbase::Coinbase, gateio::spot::GateioSpot, kraken::Kraken, okx::Okx, Connector,
+        StreamSelector,
+    },
+    init_subscribed,
+    streams::options::StreamOptions,
+    subscriber::WebSocketSubscriber,
+    subscription::{
+        book::{OrderBooksL1, OrderBooksL2},
+        trade::PublicTrades,
+        Subscription, SubscriptionKind,
+    },
+    transformer::ExchangeTransformer,
+    ExchangeWsStream, Identifier,
+};
+use barter_integration::model::instrument::{kind::InstrumentKind, Instrument};
+use futures::{SinkExt, StreamExt};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::time::Duration;
+use tokio::net::TcpListener;
+use tokio_tungstenite::tungstenite::Message;
+
+/// Maximum [`Duration`] to wait for each expected [`MarketEvent`].
+const EVENT_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// [`Duration`] without a [`MarketEvent`] after which a blessed session is considered complete.
+const BLESS_IDLE_TIMEOUT: Duration = Duration::from_millis(500);
+
+/// Recorded exchange session replayed by the mock exchange server.
+#[derive(Debug, Deserialize, Serialize)]
+struct GoldenSession {
+    requests: Vec<Value>,
+    frames: Vec<Value>,
+    expected: Vec<Value>,
+}
+
+impl GoldenSession {
+    fn path(name: &str) -> String {
+        format!("{}/tests/golden/{name}.json", env!("CARGO_MANIFEST_DIR"))
+    }
+
+    fn load(name: &str) -> Self {
+        let path = Self::path(name);
+        let session = std::fs::read_to_string(&path)
+            .unwrap_or_else(|error| panic!("failed to read golden session {path}: {error}"));
+        serde_json::from_str(&session)
+            .unwrap_or_else(|error| panic!("failed to parse golden session {path}: {error}"))
+    }
+
+    fn bless(mut self, name: &str, expected: Vec<Value>) {
+        self.expected = expected;
+        let session = serde_json::to_string_pretty(&self).unwrap();
+        std::fs::write(Self::path(name), session + "\n").unwrap();
+    }
+}
+
+/// Spawn a mock exchange server that replays the [`GoldenSession`] to the first connection,
+/// returning the WebSocket url and a receiver of the subscription requests received.
+async fn spawn_mock_exchange(
+    session: &GoldenSession,
+) -> (url::Url, tokio::sync::oneshot::Receiver<Vec<Value>>) {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = url::Url::parse(&format!("ws://{}", listener.local_addr().unwrap())).unwrap();
+
+    let expected_requests = session.requests.len();
+    let frames = session.frames.clone();
+
+    let (requests_tx, requests_rx) = tokio::sync::oneshot::channel();
+
+    tokio::spawn(async move {
+        let (stream, _) = listener.accept().await.unwrap();
+        let mut websocket = tokio_tungstenite::accept_async(stream).await.unwrap();
+
+        // Receive subscription requests
+        let mut requests = Vec::with_capacity(expected_requests);
+        while requests.len() < expected_requests {
+            match websocket.next().await {
+                Some(Ok(Message::Text(request))) => {
+                    requests.push(serde_json::from_str(&request).unwrap())
+                }
+                Some(Ok(_)) => continue,
+                _ => break,
+            }
+        }
+        let _ = requests_tx.send(requests);
+
+        // Replay recorded exchange frames
+        for frame in frames {
+            websocket
+                .send(Message::Text(frame.to_string()))
+                .await
+                .unwrap();
+        }
+
+        // Keep connection open until the client disconnects, ignoring keepalive pings
+        while let Some(Ok(_)) = websocket.next().await {}
+    });
+
+    (url, requests_rx)
+}
+
+/// Determine if the actual JSON [`Value`] matches the expected golden [`Value`].
+fn matches(expected: &Value, actual: &Value) -> bool {
+    match (expected, actual) {
+        (Value::String(any), _) if any == "<any>" => true,
+        (Value::Object(expected), Value::Object(actual)) => {
+            expected.len() == actual.len()
+                && expected.iter().all(|(key, expected)| {
+                    actual
+                        .get(key)
+                        .is_some_and(|actual| matches(expected, actual))
+                })
+        }
+        (Value::Array(expected), Value::Array(actual)) => {
+            expected.len() == actual.len()
+                && expected
+                    .iter()
+                    .zip(actual)
+                    .all(|(expected, actual)| matches(expected, actual))
+        }
+        (expected, actual) => expected == actual,
+    }
+}
+
+/// Run the full [`MarketStream`](barter_data::MarketStream) pipeline for the provided
+/// [`Subscription`]s against the named [`GoldenSession`], asserting the exact normalised output.
+async fn run_golden_session<Exchange, Kind, Transformer>(
+    name: &str,
+    subscriptions: Vec<Subscription<Exchange, Instrument, Kind>>,
+) where
+    Exchange: Connector
+        + StreamSelector<Instrument, Kind, Stream = ExchangeWsStream<Transformer>>
+        + Send
+        + Sync,
+    Kind: SubscriptionKind + Send + Sync,
+    Kind::Event: Serialize + Send,
+    Transformer: ExchangeTransformer<Exchange, Instrument, Kind> + Send,
+    Subscription<Exchange, Instrument, Kind>:
+        Identifier<Exchange::Channel> + Identifier<Exchange::Market>,
+{
+    let session = GoldenSession::load(name);
+    let (url, server) = spawn_mock_exchange(&session).await;
+
+    // Subscribe & validate via the mock exchange, then construct the MarketStream
+    let options = StreamOptions::default();
+    let (websocket, map, buffered, rejections) =
+        WebSocketSubscriber::subscribe_to(url, &subscriptions, &options)
+            .await
+            .unwrap_or_else(|error| panic!("{name}: failed to subscribe: {error}"));
+    let mut stream = init_subscribed::<Exchange, Instrument, Kind, Transformer>(
+        websocket, map, buffered, rejections, &options,
+    )
+    .await
+    .unwrap_or_else(|error| panic!("{name}: failed to init MarketStream: {error}"));
+
+    // Collect exactly the expected number of normalised events, or every event if blessing
+    let bless = std::env::var_os("GOLDEN_BLESS").is_some();
+    let mut actual = Vec::with_capacity(session.expected.len());
+    while bless || actual.len() < session.expected.len() {
+        let timeout = if bless {
+            BLESS_IDLE_TIMEOUT
+        } else {
+            EVENT_TIMEOUT
+        };
+
+        let event: MarketEvent<Instrument, Kind::Event> =
+            match tokio::time::timeout(timeout, stream.next()).await {
+                Ok(Some(Ok(event))) => event,
+                Ok(Some(Err(error))) => panic!("{name}: unexpected error: {error}"),
+                Err(_) if bless => break,
+                Err(_) => panic!("{name}: timed out waiting for event {}", actual.len()),
+                Ok(None) => panic!("{name}: stream ended at event {}", actual.len()),
+            };
+
+        let mut event = serde_json::to_value(event).unwrap();
+        event.as_object_mut().unwrap().remove("received_time");
+        actual.push(event);
+    }
+
+    // Assert the subscription requests sent to the mock exchange
+    let requests = server.await.unwrap();
+    assert!(
+        matches(
+            &Value::Array(session.requests.clone()),
+            &Value::Array(requests.clone())
+        ),
+        "{name}: unexpected subscription requests: {requests:#?}"
+    );
+
+    if bless {
+        session.bless(name, actual);
+        return;
+    }
+
+    for (index, (expected, actual)) in session.expected.iter().zip(&actual).enumerate() {
+        assert!(
+            matches(expected, actual),
+            "{name}: event {index} mismatch\nExpected: {expected:#}\nActual: {actual:#}"
+        );
+    }
+}
+
+fn instrument(base: &str, quote: &str, kind: InstrumentKind) -> Instrument {
+    Instrument::from((base, quote, kind))
+}
+
+#[tokio::test]
+async fn golden_binance_spot_trades() {
+    run_golden_session(
+        "binance_spot_trades",
+        vec![Subscription::new(
+            BinanceSpot::default(),
+            instrument("eth", "usdt", InstrumentKind::Spot),
+            PublicTrades,
+        )],
+    )
+    .await
+}
+
+#[tokio::test]
+async fn golden_bitfinex_trades() {
+    run_golden_session(
+        "bitfinex_trades",
+        vec![Subscription::new(
+            Bitfinex,
+            instrument("btc", "usd", InstrumentKind::Spot),
+            PublicTrades,
+        )],
+    )
+    .await
+}
+
+#[tokio::test]
+async fn golden_bitmex_trades() {
+    run_golden_session(
+        "bitmex_trades",
+        vec![Subscription::new(
+            Bitmex,
+            instrument("xbt", "usd", InstrumentKind::Perpetual),
+            PublicTrades,
+        )],
+    )
+    .await
+}
+
+#[tokio::test]
+async fn golden_bybit_spot_trades() {
+    run_golden_session(
+        "bybit_spot_trades",
+        vec![Subscription::new(
+            BybitSpot::default(),
+            instrument("btc", "usdt", InstrumentKind::Spot),
+            PublicTrades,
+        )],
+    )
+    .await
+}
+
+#[tokio::test]
+async fn golden_bybit_spot_order_books_l1() {
+    run_golden_session(
+        "bybit_spot_order_books_l1",
+        vec![Subscription::new(
+            BybitSpot::default(),
+            instrument("btc", "usdt", InstrumentKind::Spot),
+            OrderBooksL1,
+        )],
+    )
+    .await
+}
+
+#[tokio::test]
+async fn golden_bybit_spot_order_books_l2() {
+    run_golden_session(
+        "bybit_spot_order_books_l2",
+        vec![Subscription::new(
+            BybitSpot::default(),
+            instrument("btc", "usdt", InstrumentKind::Spot),
+            OrderBooksL2::default(),
+        )],
+    )
+    .await
+}
+
+#[tokio::test]
+async fn golden_coinbase_trades() {
+    run_golden_session(
+        "coinbase_trades",
+        vec![Subscription::new(
+            Coinbase,
+            instrument("btc", "usd", InstrumentKind::Spot),
+            PublicTrades,
+        )],
+    )
+    .await
+}
+
+#[tokio::test]
+async fn golden_gateio_spot_trades() {
+    run_golden_session(
+        "gateio_spot_trades",
+        vec![Subscription::new(
+            GateioSpot::default(),
+            instrument("btc", "usdt", InstrumentKind::Spot),
+            PublicTrades,
+        )],
+    )
+    .await
+}
+
+#[tokio::test]
+async fn golden_kraken_trades() {
+    run_golden_session(
+        "kraken_trades",
+        vec![Subscription::new(
+            Kraken,
+            instrument("xbt", "usd", InstrumentKind::Spot),
+            PublicTrades,
+        )],
+    )
+    .await
+}
+
+#[tokio::test]
+async fn golden_okx_trades() {
+    run_golden_session(
+        "okx_trades",
+        vec![Subscription::new(
+            Okx,
+            instrument("btc", "usdt", InstrumentKind::Spot),
+            PublicTrades,
+        )],
+    )
+    .await
+}